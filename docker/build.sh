@@ -68,6 +68,7 @@ mkdir --verbose "zinc-${VERSION_ZINC}-macos"
 mv --verbose --force \
     "target/${TARGET_MACOS}/release/zargo" \
     "target/${TARGET_MACOS}/release/znc" \
+    "target/${TARGET_MACOS}/release/zinc-lsp" \
     "target/${TARGET_MACOS}/release/zvm" \
     "zinc-${VERSION_ZINC}-macos"
 zip --verbose -r \
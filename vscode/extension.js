@@ -0,0 +1,33 @@
+//
+// The Zinc language server client.
+//
+
+const vscode = require('vscode');
+const languageClient = require('vscode-languageclient');
+
+let client = null;
+
+function activate(context) {
+    const path = vscode.workspace.getConfiguration('zinc').get('languageServer.path');
+    if (!path) {
+        return;
+    }
+
+    const serverOptions = {
+        command: path,
+    };
+    const clientOptions = {
+        documentSelector: [{ scheme: 'file', language: 'zn' }],
+    };
+
+    client = new languageClient.LanguageClient('zinc-lsp', 'Zinc Language Server', serverOptions, clientOptions);
+    context.subscriptions.push(client.start());
+}
+
+function deactivate() {
+    if (client) {
+        return client.stop();
+    }
+}
+
+module.exports = { activate, deactivate };
@@ -3,16 +3,15 @@
 //!
 
 use std::collections::HashMap;
-use std::time::Duration;
 
 use num_old::BigUint;
 use num_old::Zero;
 
-use zksync::provider::Provider;
-
 use crate::database::client::Client as DatabaseClient;
 use crate::database::model;
 use crate::error::Error;
+use crate::network::wallet::Wallet;
+use crate::network::Network;
 use crate::storage::keeper::Keeper as StorageKeeper;
 use crate::storage::Storage;
 
@@ -36,7 +35,7 @@ pub struct Contract {
     pub instance: String,
 
     /// The contract wallet.
-    pub wallet: zksync::Wallet<zksync_eth_signer::PrivateKeySigner, zksync::RpcProvider>,
+    pub wallet: Wallet,
     /// The pre-built contract ready to be called.
     pub build: zinc_types::Contract,
     /// The contract storage.
@@ -48,7 +47,7 @@ impl Contract {
     /// Loads a contract from the database.
     ///
     pub async fn new(
-        network: zksync::Network,
+        network: Network,
         postgresql: DatabaseClient,
         eth_address: zksync_types::Address,
     ) -> Result<Self, Error> {
@@ -69,14 +68,7 @@ impl Contract {
         let eth_private_key =
             zinc_types::private_key_from_slice(contract.eth_private_key.as_slice());
 
-        let provider = zksync::RpcProvider::new(network);
-        let wallet_credentials = zksync::WalletCredentials::from_eth_signer(
-            eth_address,
-            zksync_eth_signer::PrivateKeySigner::new(eth_private_key),
-            network,
-        )
-        .await?;
-        let wallet = zksync::Wallet::new(provider, wallet_credentials).await?;
+        let wallet = Wallet::new(network, eth_address, eth_private_key).await?;
 
         let application = zinc_types::Application::try_from_slice(project.bytecode.as_slice())
             .expect(zinc_const::panic::VALIDATED_DURING_DATABASE_POPULATION);
@@ -132,7 +124,7 @@ impl Contract {
     ) -> Result<zinc_vm::ContractOutput, Error> {
         let contract_build = self.build.clone();
         let contract_storage_keeper =
-            StorageKeeper::new(postgresql.clone(), self.wallet.provider.network());
+            StorageKeeper::new(postgresql.clone(), self.wallet.network.clone());
 
         let mut storages = HashMap::with_capacity(1);
         storages.insert(self.eth_address, self.storage.clone().into_build());
@@ -179,13 +171,7 @@ impl Contract {
             let nonce = match nonces.get_mut(&self.eth_address) {
                 Some(nonce) => nonce,
                 None => {
-                    let nonce = self
-                        .wallet
-                        .provider
-                        .account_info(self.eth_address)
-                        .await?
-                        .committed
-                        .nonce;
+                    let nonce = self.wallet.account().await?.nonce;
                     nonces.entry(self.eth_address).or_insert(nonce)
                 }
             };
@@ -197,14 +183,13 @@ impl Contract {
             let amount = BigUint::zero();
             let fee = self
                 .wallet
-                .provider
-                .get_tx_fee(
+                .network
+                .tx_fee(
                     zksync_types::TxFeeTypes::Transfer,
                     initializer.eth_address,
                     token.id,
                 )
-                .await?
-                .total_fee;
+                .await?;
 
             log::info!(
                 "[{}] Sending {} {} from {} to {} with fee {} {}",
@@ -218,37 +203,31 @@ impl Contract {
                 token.symbol,
             );
 
-            let (transfer, signature) = self
-                .wallet
-                .signer
-                .sign_transfer(token, amount, fee, initializer.eth_address, *nonce)
-                .await?;
-            initial_deposit_transactions.push(zinc_types::Transaction::new(
-                zksync_types::ZkSyncTx::Transfer(Box::new(transfer)),
-                signature,
-            ));
+            initial_deposit_transactions.push(
+                self.wallet
+                    .sign_transfer(token, amount, fee, initializer.eth_address, *nonce)
+                    .await?,
+            );
 
             *nonce += 1;
         }
 
-        self.execute_batch(initial_deposit_transactions, 10, 200)
+        self.wallet
+            .network
+            .execute_batch(initial_deposit_transactions)
             .await?;
 
         let mut created_instances = HashMap::with_capacity(initializers.len());
         for initializer in initializers.into_iter() {
-            let wallet_credentials = zksync::WalletCredentials::from_eth_signer(
+            let mut wallet = Wallet::new(
+                self.wallet.network.clone(),
                 initializer.eth_address,
-                zksync_eth_signer::PrivateKeySigner::new(initializer.eth_private_key),
-                self.wallet.provider.network(),
+                initializer.eth_private_key,
             )
             .await?;
-            let mut wallet =
-                zksync::Wallet::new(self.wallet.provider.clone(), wallet_credentials).await?;
             let nonce = nonces.entry(initializer.eth_address).or_insert(0);
 
-            let account_id = zksync::utils::wait_for_account_id(&mut wallet, 10_000)
-                .await
-                .ok_or(Error::AccountIdNotFound)?;
+            let account_id = wallet.wait_for_account_id().await?;
 
             let token = wallet
                 .tokens
@@ -262,14 +241,11 @@ impl Contract {
                     .expect(zinc_const::panic::DATA_CONVERSION),
             );
 
-            let change_pubkey = wallet
-                .signer
-                .sign_change_pubkey_tx(*nonce, false, token, BigUint::zero())
-                .await?;
-            transactions.push(zinc_types::Transaction::new(
-                zksync_types::ZkSyncTx::ChangePubKey(Box::new(change_pubkey)),
-                None,
-            ));
+            transactions.push(
+                wallet
+                    .sign_change_pubkey(token, BigUint::zero(), *nonce)
+                    .await?,
+            );
 
             *nonce += 1;
 
@@ -324,24 +300,17 @@ impl Contract {
                 }
             };
 
-            let wallet_credentials = zksync::WalletCredentials::from_eth_signer(
+            let wallet = Wallet::new(
+                self.wallet.network.clone(),
                 transfer.sender,
-                zksync_eth_signer::PrivateKeySigner::new(eth_private_key),
-                self.wallet.provider.network(),
+                eth_private_key,
             )
             .await?;
-            let wallet =
-                zksync::Wallet::new(self.wallet.provider.clone(), wallet_credentials).await?;
 
             let nonce = match nonces.get_mut(&transfer.sender) {
                 Some(nonce) => nonce,
                 None => {
-                    let nonce = wallet
-                        .provider
-                        .account_info(transfer.sender)
-                        .await?
-                        .committed
-                        .nonce;
+                    let nonce = wallet.account().await?.nonce;
                     nonces.entry(transfer.sender).or_insert(nonce)
                 }
             };
@@ -367,74 +336,16 @@ impl Contract {
                     .expect(zinc_const::panic::DATA_CONVERSION),
             );
 
-            let (transfer, signature) = wallet
-                .signer
-                .sign_transfer(token, amount, fee, transfer.recipient, *nonce)
-                .await?;
-            transactions.push(zinc_types::Transaction::new(
-                zksync_types::ZkSyncTx::Transfer(Box::new(transfer)),
-                signature,
-            ));
+            transactions.push(
+                wallet
+                    .sign_transfer(token, amount, fee, transfer.recipient, *nonce)
+                    .await?,
+            );
 
             *nonce += 1;
         }
 
-        self.execute_batch(transactions, 10, 200).await?;
-
-        Ok(())
-    }
-
-    ///
-    /// Executes a batch transaction.
-    ///
-    async fn execute_batch(
-        &self,
-        transactions: Vec<zinc_types::Transaction>,
-        commit_timeout_secs: u64,
-        polling_interval_millis: u64,
-    ) -> Result<(), Error> {
-        let handles: Vec<zksync::operations::SyncTransactionHandle<zksync::RpcProvider>> = self
-            .wallet
-            .provider
-            .send_txs_batch(
-                transactions
-                    .into_iter()
-                    .map(|transaction| {
-                        (
-                            transaction.tx,
-                            transaction
-                                .ethereum_signature
-                                .map(|signature| signature.signature),
-                        )
-                    })
-                    .collect(),
-                None,
-            )
-            .await?
-            .into_iter()
-            .map(|tx_hash| {
-                let mut handle = zksync::operations::SyncTransactionHandle::new(
-                    tx_hash,
-                    self.wallet.provider.clone(),
-                )
-                .commit_timeout(Duration::from_secs(commit_timeout_secs));
-                handle
-                    .polling_interval(Duration::from_millis(polling_interval_millis))
-                    .expect(zinc_const::panic::DATA_CONVERSION);
-                handle
-            })
-            .collect();
-
-        if let Some(handle) = handles.last() {
-            let tx_info = handle.wait_for_commit().await?;
-            if !tx_info.success.unwrap_or_default() {
-                return Err(Error::TransferFailure(
-                    tx_info
-                        .fail_reason
-                        .unwrap_or_else(|| "Unknown error".to_owned()),
-                ));
-            }
-        }
+        self.wallet.network.execute_batch(transactions).await?;
 
         Ok(())
     }
@@ -45,7 +45,8 @@ pub async fn handle(
     let network = app_data
         .read()
         .expect(zinc_const::panic::SYNCHRONIZATION)
-        .network;
+        .network
+        .clone();
 
     log::info!("[{}] Calling method `{}`", log_id, query.method);
 
@@ -68,7 +69,7 @@ pub async fn handle(
     let output = contract
         .run_method(
             query.method,
-            (&body.transaction).try_to_msg(&contract.wallet)?,
+            (&body.transaction).try_to_msg(&contract.wallet.tokens)?,
             arguments,
             postgresql.clone(),
         )
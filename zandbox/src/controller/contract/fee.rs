@@ -8,8 +8,6 @@ use num::BigInt;
 use num_old::BigUint;
 use num_old::Zero;
 
-use zksync::provider::Provider;
-
 use crate::contract::Contract;
 use crate::error::Error;
 use crate::response::Response;
@@ -42,7 +40,8 @@ pub async fn handle(
     let network = app_data
         .read()
         .expect(zinc_const::panic::SYNCHRONIZATION)
-        .network;
+        .network
+        .clone();
 
     log::info!(
         "[{}] Calculating the fee for method `{}`",
@@ -69,7 +68,7 @@ pub async fn handle(
     let output = contract
         .run_method(
             query.method,
-            (&body.transaction).try_to_msg(&contract.wallet)?,
+            (&body.transaction).try_to_msg(&contract.wallet.tokens)?,
             arguments,
             postgresql,
         )
@@ -87,28 +86,26 @@ pub async fn handle(
     for initializer in output.initializers.into_iter() {
         fee += contract
             .wallet
-            .provider
-            .get_tx_fee(
+            .network
+            .tx_fee(
                 zksync_types::TxFeeTypes::ChangePubKey {
                     onchain_pubkey_auth: false,
                 },
                 initializer.eth_address,
                 token.id,
             )
-            .await?
-            .total_fee;
+            .await?;
     }
     for transfer in output.transfers.into_iter() {
         fee += contract
             .wallet
-            .provider
-            .get_tx_fee(
+            .network
+            .tx_fee(
                 zksync_types::TxFeeTypes::Transfer,
                 transfer.recipient,
                 token.id,
             )
-            .await?
-            .total_fee;
+            .await?;
     }
     log::info!(
         "[{}] The total fee is {} {}",
@@ -2,16 +2,15 @@
 //! The contract resource POST method `initialize` module.
 //!
 
-use std::time::Duration;
-
 use actix_web::http::StatusCode;
 use actix_web::web;
-
-use zksync::provider::Provider;
+use num_old::BigUint;
+use num_old::Zero;
 
 use crate::database::error::Error as DatabaseError;
 use crate::database::model;
 use crate::error::Error;
+use crate::network::Network;
 use crate::response::Response;
 
 ///
@@ -41,7 +40,8 @@ pub async fn handle(
     let network = app_data
         .read()
         .expect(zinc_const::panic::SYNCHRONIZATION)
-        .network;
+        .network
+        .clone();
 
     log::info!("[{}] Unlocking sequence started", log_id);
 
@@ -79,65 +79,33 @@ pub async fn handle(
         _ => panic!(zinc_const::panic::VALUE_ALWAYS_EXISTS),
     };
 
-    let tx_info = contract
-        .wallet
-        .provider
-        .send_tx(
-            body.transaction.tx,
-            body.transaction
-                .ethereum_signature
-                .map(|signature| signature.signature),
-        )
-        .await
-        .map(|tx_hash| {
-            let mut handle = zksync::operations::SyncTransactionHandle::new(
-                tx_hash,
-                contract.wallet.provider.clone(),
-            )
-            .commit_timeout(Duration::from_secs(10));
-            handle
-                .polling_interval(Duration::from_millis(200))
-                .expect(zinc_const::panic::DATA_CONVERSION);
-            handle
-        })?
-        .wait_for_commit()
-        .await?;
-    if !tx_info.success.unwrap_or_default() {
-        return Err(Error::TransferFailure(
-            tx_info
-                .fail_reason
-                .unwrap_or_else(|| "Unknown error".to_owned()),
-        ));
-    }
+    network.execute(body.transaction).await?;
 
-    let account_id = zksync::utils::wait_for_account_id(&mut contract.wallet, 10_000)
-        .await
-        .ok_or(Error::AccountIdNotFound)?;
+    let account_id = contract.wallet.wait_for_account_id().await?;
 
     log::info!("[{}] Sending the change-pubkey transaction", log_id);
-    let mut change_pubkey = contract.wallet.start_change_pubkey();
-    if let zksync::Network::Rinkeby = network {
-        change_pubkey = change_pubkey.fee(0u64);
+    let fee_token = contract
+        .wallet
+        .tokens
+        .resolve(fee_token_id.into())
+        .ok_or_else(|| Error::TokenNotFound(fee_token_id.to_string()))?;
+    let fee = if let Network::ZkSync(zksync::Network::Rinkeby) = network {
+        BigUint::zero()
     } else {
-        change_pubkey =
-            change_pubkey.fee(zinc_types::num_compat_backward(contract.change_pubkey_fee));
-    }
-    let mut handle = change_pubkey
-        .fee_token(fee_token_id)?
-        .send()
-        .await?
-        .commit_timeout(Duration::from_secs(10));
-    handle
-        .polling_interval(Duration::from_millis(200))
-        .expect(zinc_const::panic::DATA_CONVERSION);
-    let tx_info = handle.wait_for_commit().await?;
-    if !tx_info.success.unwrap_or_default() {
-        return Err(Error::ChangePubkey(
-            tx_info
-                .fail_reason
-                .unwrap_or_else(|| "Unknown error".to_owned()),
-        ));
-    }
+        zinc_types::num_compat_backward(contract.change_pubkey_fee)
+    };
+    let nonce = contract.wallet.account().await?.nonce;
+    let change_pubkey = contract
+        .wallet
+        .sign_change_pubkey(fee_token, fee, nonce)
+        .await?;
+    network
+        .execute(change_pubkey)
+        .await
+        .map_err(|error| match error {
+            Error::TransferFailure(reason) => Error::ChangePubkey(reason),
+            error => error,
+        })?;
 
     {
         let mut transaction = postgresql.new_transaction().await?;
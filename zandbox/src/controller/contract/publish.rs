@@ -34,7 +34,8 @@ pub async fn handle(
     let network = app_data
         .read()
         .expect(zinc_const::panic::SYNCHRONIZATION)
-        .network;
+        .network
+        .clone();
 
     log::info!("[{}] Initializing a locked contract", log_id);
 
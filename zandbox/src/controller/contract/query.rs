@@ -38,7 +38,8 @@ pub async fn handle(
     let network = app_data
         .read()
         .expect(zinc_const::panic::SYNCHRONIZATION)
-        .network;
+        .network
+        .clone();
 
     let contract = Contract::new(network, postgresql.clone(), query.address).await?;
 
@@ -6,6 +6,7 @@ pub(crate) mod contract;
 pub(crate) mod controller;
 pub(crate) mod database;
 pub(crate) mod error;
+pub(crate) mod network;
 pub(crate) mod response;
 pub(crate) mod shared_data;
 pub(crate) mod storage;
@@ -13,6 +14,7 @@ pub(crate) mod storage;
 pub use self::controller::configure;
 pub use self::database::client::Client as DatabaseClient;
 pub use self::error::Error;
+pub use self::network::Network;
 pub use self::shared_data::SharedData;
 
 ///
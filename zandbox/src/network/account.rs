@@ -0,0 +1,37 @@
+//!
+//! The network account state.
+//!
+
+use std::collections::HashMap;
+
+use num_old::BigUint;
+
+///
+/// The committed state of a network account.
+///
+#[derive(Debug, Clone)]
+pub struct Account {
+    /// The account ID, which is assigned after the first deposit to the account.
+    pub id: Option<zksync_types::AccountId>,
+    /// The account nonce.
+    pub nonce: zksync_types::Nonce,
+    /// The account balances, where the key is the token symbol.
+    pub balances: HashMap<String, BigUint>,
+}
+
+impl Account {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        id: Option<zksync_types::AccountId>,
+        nonce: zksync_types::Nonce,
+        balances: HashMap<String, BigUint>,
+    ) -> Self {
+        Self {
+            id,
+            nonce,
+            balances,
+        }
+    }
+}
@@ -0,0 +1,78 @@
+//!
+//! The in-memory ledger account.
+//!
+
+use std::collections::BTreeMap;
+
+use num_old::BigUint;
+use num_old::Zero;
+
+use crate::network::ledger::error::Error;
+
+///
+/// The in-memory ledger account.
+///
+#[derive(Debug, Clone)]
+pub struct Account {
+    /// The account ETH address.
+    pub address: zksync_types::Address,
+    /// The account nonce.
+    pub nonce: zksync_types::Nonce,
+    /// Whether the account public key is set, which allows the account to send transactions.
+    pub is_unlocked: bool,
+    /// The account balances, where the key is the token ID.
+    pub balances: BTreeMap<zksync_types::TokenId, BigUint>,
+}
+
+impl Account {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(address: zksync_types::Address, is_unlocked: bool) -> Self {
+        Self {
+            address,
+            nonce: 0,
+            is_unlocked,
+            balances: BTreeMap::new(),
+        }
+    }
+
+    ///
+    /// Returns the account balance of the token.
+    ///
+    pub fn balance(&self, token_id: zksync_types::TokenId) -> BigUint {
+        self.balances
+            .get(&token_id)
+            .cloned()
+            .unwrap_or_else(BigUint::zero)
+    }
+
+    ///
+    /// Adds `amount` to the account balance of the token.
+    ///
+    pub fn deposit(&mut self, token_id: zksync_types::TokenId, amount: &BigUint) {
+        *self.balances.entry(token_id).or_insert_with(BigUint::zero) += amount;
+    }
+
+    ///
+    /// Subtracts `amount` from the account balance of the token.
+    ///
+    pub fn withdraw(
+        &mut self,
+        token_id: zksync_types::TokenId,
+        amount: &BigUint,
+    ) -> Result<(), Error> {
+        let available = self.balance(token_id);
+        if &available < amount {
+            return Err(Error::InsufficientBalance {
+                address: self.address,
+                token_id,
+                required: amount.to_owned(),
+                available,
+            });
+        }
+
+        self.balances.insert(token_id, available - amount);
+        Ok(())
+    }
+}
@@ -0,0 +1,59 @@
+//!
+//! The in-memory ledger error.
+//!
+
+use num_old::BigUint;
+use thiserror::Error;
+
+///
+/// The in-memory ledger transaction error.
+///
+#[derive(Debug, Error)]
+pub enum Error {
+    /// The sender account does not exist.
+    #[error("account {0:?} does not exist")]
+    AccountNotFound(zksync_types::Address),
+    /// The transaction account ID does not match the sender address.
+    #[error("account {address:?} has ID {expected}, but the transaction specifies {found}")]
+    AccountIdMismatch {
+        /// The sender address.
+        address: zksync_types::Address,
+        /// The sender account ID.
+        expected: zksync_types::AccountId,
+        /// The transaction account ID.
+        found: zksync_types::AccountId,
+    },
+    /// The sender account public key has not been set yet.
+    #[error("account {0:?} is locked, as its public key is not set")]
+    AccountLocked(zksync_types::Address),
+    /// The transaction nonce does not match the sender account one.
+    #[error("account {address:?} has nonce {expected}, but the transaction specifies {found}")]
+    NonceMismatch {
+        /// The sender address.
+        address: zksync_types::Address,
+        /// The sender account nonce.
+        expected: zksync_types::Nonce,
+        /// The transaction nonce.
+        found: zksync_types::Nonce,
+    },
+    /// The token is not supported by the ledger.
+    #[error("token ID {0} is not supported")]
+    TokenNotFound(zksync_types::TokenId),
+    /// The sender balance is less than the transaction amount with fee.
+    #[error(
+        "account {address:?} has {available} of token ID {token_id}, but {required} is required"
+    )]
+    InsufficientBalance {
+        /// The sender address.
+        address: zksync_types::Address,
+        /// The transaction token ID.
+        token_id: zksync_types::TokenId,
+        /// The required amount.
+        required: BigUint,
+        /// The available amount.
+        available: BigUint,
+    },
+    /// The transaction type is not supported by the ledger.
+    #[error("{0} transactions are not supported")]
+    UnsupportedTransaction(&'static str),
+}
@@ -0,0 +1,29 @@
+//!
+//! The in-memory ledger genesis state.
+//!
+
+///
+/// The tokens supported by the ledger, where the first element is the symbol and the second
+/// one is the number of decimals.
+///
+/// The token ID is the index in the array.
+///
+pub const TOKENS: [(&str, u8); 2] = [("ETH", 18), ("DAI", 18)];
+
+///
+/// The unlocked accounts, which are funded with `BALANCE` of each token.
+///
+/// These are the accounts used by the integration tests, so the ledger can replace the local
+/// zkSync node prepared with `zinc-tester/accounts.sh`.
+///
+pub const ACCOUNTS: [&str; 4] = [
+    "36615Cf349d7F6344891B1e7CA7C72883F5dc049",
+    "a61464658AfeAf65CccaaFD3a512b69A83B77618",
+    "0D43eB5B8a47bA8900d84AA36656c92024e9772e",
+    "A13c10C0D5bd6f79041B9835c63f91de35A15883",
+];
+
+///
+/// The initial balance of each genesis account in each token, in whole token units.
+///
+pub const BALANCE: u64 = 100;
@@ -0,0 +1,331 @@
+//!
+//! The in-memory ledger.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod account;
+pub mod error;
+pub mod genesis;
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use num_old::BigUint;
+use num_old::Zero;
+
+use crate::network::account::Account as NetworkAccount;
+
+use self::account::Account;
+use self::error::Error;
+
+///
+/// The deterministic in-memory ledger.
+///
+/// The ledger replaces the zkSync node for local development and integration testing.
+/// It keeps the tokens, accounts, balances, and nonces, and executes the transfer and
+/// change-pubkey transactions with the zkSync semantics:
+/// 1. The account IDs are assigned in the order of the accounts creation.
+/// 2. An account is created by the first transfer to it, and must set its public key
+///    before sending transfers.
+/// 3. The transaction nonce must match the sender account one.
+/// 4. The sender balance must cover the transaction amount and fee.
+/// 5. A batch is committed or rejected as a whole, and is committed immediately.
+///
+/// The transaction signatures are not verified.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Ledger {
+    /// The ledger state shared between the server workers.
+    state: Arc<Mutex<State>>,
+}
+
+///
+/// The in-memory ledger state.
+///
+#[derive(Debug, Clone)]
+struct State {
+    /// The supported tokens, where the key is the token ID.
+    tokens: BTreeMap<zksync_types::TokenId, zksync_types::Token>,
+    /// The accounts, where the index is the account ID.
+    accounts: Vec<Account>,
+    /// The account IDs, where the key is the account ETH address.
+    account_ids: HashMap<zksync_types::Address, zksync_types::AccountId>,
+}
+
+impl Ledger {
+    ///
+    /// Returns the supported tokens, where the key is the token symbol.
+    ///
+    pub fn tokens(&self) -> HashMap<String, zksync_types::Token> {
+        self.state
+            .lock()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .tokens
+            .values()
+            .map(|token| (token.symbol.clone(), token.clone()))
+            .collect()
+    }
+
+    ///
+    /// Returns the state of the account with the `address`.
+    ///
+    /// A non-existent account has no ID, zero nonce, and no balances.
+    ///
+    pub fn account(&self, address: zksync_types::Address) -> NetworkAccount {
+        let state = self.state.lock().expect(zinc_const::panic::SYNCHRONIZATION);
+
+        let account_id = match state.account_ids.get(&address).copied() {
+            Some(account_id) => account_id,
+            None => return NetworkAccount::new(None, 0, HashMap::new()),
+        };
+        let account = &state.accounts[account_id as usize];
+
+        let balances = account
+            .balances
+            .iter()
+            .filter(|(_token_id, balance)| !balance.is_zero())
+            .map(|(token_id, balance)| (state.tokens[token_id].symbol.clone(), balance.clone()))
+            .collect();
+
+        NetworkAccount::new(Some(account_id), account.nonce, balances)
+    }
+
+    ///
+    /// Returns the fee for a transaction of `fee_type` sent to `address` in the token.
+    ///
+    /// The fee is deterministic and depends on the number of the zkSync block chunks taken by
+    /// the transaction. Returns `None` if the token is not supported.
+    ///
+    pub fn tx_fee(
+        &self,
+        fee_type: zksync_types::TxFeeTypes,
+        address: zksync_types::Address,
+        token_id: zksync_types::TokenId,
+    ) -> Option<BigUint> {
+        let state = self.state.lock().expect(zinc_const::panic::SYNCHRONIZATION);
+
+        let token = state.tokens.get(&token_id)?;
+        let chunks = match fee_type {
+            zksync_types::TxFeeTypes::Transfer if state.account_ids.contains_key(&address) => {
+                State::TRANSFER_CHUNKS
+            }
+            zksync_types::TxFeeTypes::Transfer => State::TRANSFER_TO_NEW_CHUNKS,
+            zksync_types::TxFeeTypes::ChangePubKey { .. } => State::CHANGE_PUBKEY_CHUNKS,
+            _ => State::WITHDRAW_CHUNKS,
+        };
+
+        Some(
+            BigUint::from(chunks)
+                * num_old::pow(
+                    BigUint::from(10u64),
+                    token
+                        .decimals
+                        .saturating_sub(State::CHUNK_FEE_PRECISION)
+                        .into(),
+                ),
+        )
+    }
+
+    ///
+    /// Executes and commits the transactions.
+    ///
+    /// If any transaction fails, the ledger state remains unchanged.
+    ///
+    pub fn execute(&self, transactions: Vec<zksync_types::ZkSyncTx>) -> Result<(), Error> {
+        let mut state = self.state.lock().expect(zinc_const::panic::SYNCHRONIZATION);
+
+        let mut pending = state.clone();
+        for transaction in transactions.into_iter() {
+            pending.apply(transaction)?;
+        }
+        *state = pending;
+
+        Ok(())
+    }
+}
+
+impl State {
+    /// The ID of the account, which collects the transaction fees.
+    const FEE_ACCOUNT_ID: zksync_types::AccountId = 0;
+
+    /// The number of chunks taken by a transfer to an existing account.
+    const TRANSFER_CHUNKS: u64 = 2;
+    /// The number of chunks taken by a transfer to a new account.
+    const TRANSFER_TO_NEW_CHUNKS: u64 = 6;
+    /// The number of chunks taken by a change-pubkey transaction.
+    const CHANGE_PUBKEY_CHUNKS: u64 = 6;
+    /// The number of chunks taken by a withdrawal.
+    const WITHDRAW_CHUNKS: u64 = 6;
+    /// The fee for a chunk is 10^-5 of the token unit.
+    const CHUNK_FEE_PRECISION: u8 = 5;
+
+    ///
+    /// Applies the transaction to the state.
+    ///
+    fn apply(&mut self, transaction: zksync_types::ZkSyncTx) -> Result<(), Error> {
+        match transaction {
+            zksync_types::ZkSyncTx::Transfer(transfer) => self.transfer(*transfer),
+            zksync_types::ZkSyncTx::ChangePubKey(change_pubkey) => {
+                self.change_pubkey(*change_pubkey)
+            }
+            zksync_types::ZkSyncTx::Withdraw(..) => Err(Error::UnsupportedTransaction("Withdraw")),
+            zksync_types::ZkSyncTx::Close(..) => Err(Error::UnsupportedTransaction("Close")),
+            zksync_types::ZkSyncTx::ForcedExit(..) => {
+                Err(Error::UnsupportedTransaction("ForcedExit"))
+            }
+        }
+    }
+
+    ///
+    /// Moves the amount from the sender to the recipient, creating the latter if it does not
+    /// exist, and the fee to the fee account.
+    ///
+    fn transfer(&mut self, transfer: zksync_types::tx::Transfer) -> Result<(), Error> {
+        self.check_token(transfer.token)?;
+
+        let sender = self.sender(transfer.from, transfer.account_id, transfer.nonce)?;
+        if !sender.is_unlocked {
+            return Err(Error::AccountLocked(transfer.from));
+        }
+        sender.withdraw(transfer.token, &(&transfer.amount + &transfer.fee))?;
+        sender.nonce += 1;
+
+        let recipient_id = self.get_or_create(transfer.to, false);
+        self.accounts[recipient_id as usize].deposit(transfer.token, &transfer.amount);
+        self.accounts[Self::FEE_ACCOUNT_ID as usize].deposit(transfer.token, &transfer.fee);
+
+        Ok(())
+    }
+
+    ///
+    /// Unlocks the account, moving the fee to the fee account.
+    ///
+    fn change_pubkey(
+        &mut self,
+        change_pubkey: zksync_types::tx::ChangePubKey,
+    ) -> Result<(), Error> {
+        self.check_token(change_pubkey.fee_token)?;
+
+        let account = self.sender(
+            change_pubkey.account,
+            change_pubkey.account_id,
+            change_pubkey.nonce,
+        )?;
+        account.withdraw(change_pubkey.fee_token, &change_pubkey.fee)?;
+        account.nonce += 1;
+        account.is_unlocked = true;
+
+        self.accounts[Self::FEE_ACCOUNT_ID as usize]
+            .deposit(change_pubkey.fee_token, &change_pubkey.fee);
+
+        Ok(())
+    }
+
+    ///
+    /// Checks whether the token is supported.
+    ///
+    fn check_token(&self, token_id: zksync_types::TokenId) -> Result<(), Error> {
+        if self.tokens.contains_key(&token_id) {
+            Ok(())
+        } else {
+            Err(Error::TokenNotFound(token_id))
+        }
+    }
+
+    ///
+    /// Returns the transaction sender account, checking its ID and nonce.
+    ///
+    fn sender(
+        &mut self,
+        address: zksync_types::Address,
+        account_id: zksync_types::AccountId,
+        nonce: zksync_types::Nonce,
+    ) -> Result<&mut Account, Error> {
+        let expected_id = self
+            .account_ids
+            .get(&address)
+            .copied()
+            .ok_or(Error::AccountNotFound(address))?;
+        if account_id != expected_id {
+            return Err(Error::AccountIdMismatch {
+                address,
+                expected: expected_id,
+                found: account_id,
+            });
+        }
+
+        let account = &mut self.accounts[expected_id as usize];
+        if nonce != account.nonce {
+            return Err(Error::NonceMismatch {
+                address,
+                expected: account.nonce,
+                found: nonce,
+            });
+        }
+
+        Ok(account)
+    }
+
+    ///
+    /// Returns the ID of the account with the `address`, creating the account if it does not
+    /// exist.
+    ///
+    fn get_or_create(
+        &mut self,
+        address: zksync_types::Address,
+        is_unlocked: bool,
+    ) -> zksync_types::AccountId {
+        if let Some(account_id) = self.account_ids.get(&address) {
+            return *account_id;
+        }
+
+        let account_id = self.accounts.len() as zksync_types::AccountId;
+        self.accounts.push(Account::new(address, is_unlocked));
+        self.account_ids.insert(address, account_id);
+        account_id
+    }
+}
+
+impl Default for State {
+    fn default() -> Self {
+        let tokens: BTreeMap<zksync_types::TokenId, zksync_types::Token> = genesis::TOKENS
+            .iter()
+            .enumerate()
+            .map(|(index, (symbol, decimals))| {
+                let id = index as zksync_types::TokenId;
+                let token = zksync_types::Token {
+                    id,
+                    address: zksync_types::Address::from_low_u64_be(index as u64),
+                    symbol: (*symbol).to_owned(),
+                    decimals: *decimals,
+                };
+                (id, token)
+            })
+            .collect();
+
+        let mut state = Self {
+            tokens,
+            accounts: Vec::with_capacity(genesis::ACCOUNTS.len() + 1),
+            account_ids: HashMap::with_capacity(genesis::ACCOUNTS.len() + 1),
+        };
+
+        state.get_or_create(zksync_types::Address::zero(), false);
+        for address in genesis::ACCOUNTS.iter() {
+            let address: zksync_types::Address =
+                address.parse().expect(zinc_const::panic::DATA_CONVERSION);
+            let account_id = state.get_or_create(address, true);
+
+            for (token_id, token) in state.tokens.iter() {
+                let balance = BigUint::from(genesis::BALANCE)
+                    * num_old::pow(BigUint::from(10u64), token.decimals.into());
+                state.accounts[account_id as usize].deposit(*token_id, &balance);
+            }
+        }
+
+        state
+    }
+}
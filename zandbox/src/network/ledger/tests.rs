@@ -0,0 +1,213 @@
+//!
+//! The in-memory ledger tests.
+//!
+
+use num_old::BigUint;
+
+use crate::network::ledger::error::Error;
+use crate::network::ledger::genesis;
+use crate::network::ledger::Ledger;
+
+/// The ETH token ID.
+const TOKEN_ID: zksync_types::TokenId = 0;
+
+/// The ETH token symbol.
+const TOKEN_SYMBOL: &str = "ETH";
+
+fn genesis_address(index: usize) -> zksync_types::Address {
+    genesis::ACCOUNTS[index]
+        .parse()
+        .expect(zinc_const::panic::TEST_DATA_VALID)
+}
+
+fn new_address() -> zksync_types::Address {
+    zksync_types::Address::from_low_u64_be(0xdead_beef)
+}
+
+fn transfer(
+    ledger: &Ledger,
+    from: zksync_types::Address,
+    to: zksync_types::Address,
+    amount: u64,
+    fee: u64,
+    nonce: zksync_types::Nonce,
+) -> zksync_types::ZkSyncTx {
+    let account_id = ledger.account(from).id.unwrap_or_default();
+
+    zksync_types::ZkSyncTx::Transfer(Box::new(zksync_types::tx::Transfer::new(
+        account_id,
+        from,
+        to,
+        TOKEN_ID,
+        BigUint::from(amount),
+        BigUint::from(fee),
+        nonce,
+        None,
+    )))
+}
+
+fn change_pubkey(
+    ledger: &Ledger,
+    address: zksync_types::Address,
+    fee: u64,
+    nonce: zksync_types::Nonce,
+) -> zksync_types::ZkSyncTx {
+    let account_id = ledger
+        .account(address)
+        .id
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    zksync_types::ZkSyncTx::ChangePubKey(Box::new(zksync_types::tx::ChangePubKey::new(
+        account_id,
+        address,
+        zksync_types::tx::PubKeyHash::default(),
+        TOKEN_ID,
+        BigUint::from(fee),
+        nonce,
+        None,
+        None,
+    )))
+}
+
+fn balance(ledger: &Ledger, address: zksync_types::Address) -> BigUint {
+    ledger
+        .account(address)
+        .balances
+        .get(TOKEN_SYMBOL)
+        .cloned()
+        .unwrap_or_default()
+}
+
+#[test]
+fn ok_transfer_creates_account() {
+    let ledger = Ledger::default();
+    let sender = genesis_address(0);
+    let recipient = new_address();
+    let sender_balance = balance(&ledger, sender);
+
+    assert_eq!(ledger.account(recipient).id, None);
+
+    ledger
+        .execute(vec![transfer(&ledger, sender, recipient, 100, 1, 0)])
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let account = ledger.account(recipient);
+    assert_eq!(
+        account.id,
+        Some(genesis::ACCOUNTS.len() as zksync_types::AccountId + 1)
+    );
+    assert_eq!(account.nonce, 0);
+    assert_eq!(balance(&ledger, recipient), BigUint::from(100u64));
+
+    assert_eq!(ledger.account(sender).nonce, 1);
+    assert_eq!(
+        balance(&ledger, sender),
+        sender_balance - BigUint::from(101u64)
+    );
+}
+
+#[test]
+fn ok_change_pubkey_unlocks_account() {
+    let ledger = Ledger::default();
+    let sender = genesis_address(0);
+    let recipient = new_address();
+
+    ledger
+        .execute(vec![transfer(&ledger, sender, recipient, 100, 1, 0)])
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    ledger
+        .execute(vec![change_pubkey(&ledger, recipient, 10, 0)])
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    ledger
+        .execute(vec![transfer(&ledger, recipient, sender, 50, 10, 1)])
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(ledger.account(recipient).nonce, 2);
+    assert_eq!(balance(&ledger, recipient), BigUint::from(30u64));
+}
+
+#[test]
+fn error_account_locked() {
+    let ledger = Ledger::default();
+    let sender = genesis_address(0);
+    let recipient = new_address();
+
+    ledger
+        .execute(vec![transfer(&ledger, sender, recipient, 100, 1, 0)])
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let result = ledger.execute(vec![transfer(&ledger, recipient, sender, 50, 1, 0)]);
+
+    assert!(matches!(result, Err(Error::AccountLocked(address)) if address == recipient));
+}
+
+#[test]
+fn error_nonce_mismatch() {
+    let ledger = Ledger::default();
+    let sender = genesis_address(0);
+
+    let result = ledger.execute(vec![transfer(
+        &ledger,
+        sender,
+        genesis_address(1),
+        100,
+        1,
+        1,
+    )]);
+
+    assert!(matches!(
+        result,
+        Err(Error::NonceMismatch {
+            address,
+            expected: 0,
+            found: 1,
+        }) if address == sender
+    ));
+}
+
+#[test]
+fn error_insufficient_balance() {
+    let ledger = Ledger::default();
+    let sender = genesis_address(0);
+    let recipient = new_address();
+
+    ledger
+        .execute(vec![transfer(&ledger, sender, recipient, 100, 0, 0)])
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    ledger
+        .execute(vec![change_pubkey(&ledger, recipient, 0, 0)])
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let result = ledger.execute(vec![transfer(&ledger, recipient, sender, 100, 1, 1)]);
+
+    assert!(matches!(
+        result,
+        Err(Error::InsufficientBalance {
+            address,
+            token_id: TOKEN_ID,
+            ref required,
+            ref available,
+        }) if address == recipient
+            && *required == BigUint::from(101u64)
+            && *available == BigUint::from(100u64)
+    ));
+}
+
+#[test]
+fn error_batch_leaves_state_unchanged() {
+    let ledger = Ledger::default();
+    let sender = genesis_address(0);
+    let recipient = new_address();
+    let sender_balance = balance(&ledger, sender);
+
+    let result = ledger.execute(vec![
+        transfer(&ledger, sender, recipient, 100, 1, 0),
+        transfer(&ledger, sender, recipient, 100, 1, 0),
+    ]);
+
+    assert!(matches!(result, Err(Error::NonceMismatch { .. })));
+
+    assert_eq!(ledger.account(recipient).id, None);
+    assert_eq!(ledger.account(sender).nonce, 0);
+    assert_eq!(balance(&ledger, sender), sender_balance);
+}
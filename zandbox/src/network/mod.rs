@@ -0,0 +1,276 @@
+//!
+//! The Zandbox network.
+//!
+
+pub mod account;
+pub mod ledger;
+pub mod wallet;
+
+use std::fmt;
+use std::str::FromStr;
+use std::time::Duration;
+use std::time::Instant;
+
+use num_old::BigUint;
+
+use zksync::provider::Provider;
+use zksync::tokens_cache::TokensCache;
+
+use crate::error::Error;
+
+use self::account::Account;
+use self::ledger::Ledger;
+
+///
+/// The network, where the contract transactions are executed.
+///
+#[derive(Debug, Clone)]
+pub enum Network {
+    /// The zkSync network accessed via its JSON RPC.
+    ZkSync(zksync::Network),
+    /// The deterministic in-memory ledger, which does not require a running zkSync node.
+    Ledger(Ledger),
+}
+
+impl Network {
+    /// The network identifier, which enables the in-memory ledger.
+    pub const LEDGER: &'static str = "ledger";
+
+    /// The zkSync transaction commit timeout.
+    const COMMIT_TIMEOUT_SECS: u64 = 10;
+    /// The zkSync account ID waiting timeout.
+    const ACCOUNT_ID_TIMEOUT_SECS: u64 = 10;
+    /// The zkSync transaction status polling interval.
+    const POLLING_INTERVAL_MILLIS: u64 = 200;
+
+    ///
+    /// Returns the zkSync network the wallet credentials are derived for.
+    ///
+    /// The ledger mimics the local zkSync node, so its accounts are the same as in `localhost`.
+    ///
+    pub fn zksync(&self) -> zksync::Network {
+        match self {
+            Self::ZkSync(network) => *network,
+            Self::Ledger(_) => zksync::Network::Localhost,
+        }
+    }
+
+    ///
+    /// Returns the tokens supported by the network.
+    ///
+    pub async fn tokens(&self) -> Result<TokensCache, zksync::error::ClientError> {
+        let tokens = match self {
+            Self::ZkSync(network) => zksync::RpcProvider::new(*network).tokens().await?,
+            Self::Ledger(ledger) => ledger.tokens(),
+        };
+
+        Ok(TokensCache::new(tokens))
+    }
+
+    ///
+    /// Returns the committed state of the account with the `address`.
+    ///
+    pub async fn account(
+        &self,
+        address: zksync_types::Address,
+    ) -> Result<Account, zksync::error::ClientError> {
+        match self {
+            Self::ZkSync(network) => {
+                let account_info = zksync::RpcProvider::new(*network)
+                    .account_info(address)
+                    .await?;
+
+                Ok(Account::new(
+                    account_info.id,
+                    account_info.committed.nonce,
+                    account_info
+                        .committed
+                        .balances
+                        .into_iter()
+                        .map(|(symbol, balance)| (symbol, balance.0))
+                        .collect(),
+                ))
+            }
+            Self::Ledger(ledger) => Ok(ledger.account(address)),
+        }
+    }
+
+    ///
+    /// Returns the fee for a transaction of `fee_type` sent to `address` in `token_id`.
+    ///
+    pub async fn tx_fee(
+        &self,
+        fee_type: zksync_types::TxFeeTypes,
+        address: zksync_types::Address,
+        token_id: zksync_types::TokenId,
+    ) -> Result<BigUint, zksync::error::ClientError> {
+        match self {
+            Self::ZkSync(network) => Ok(zksync::RpcProvider::new(*network)
+                .get_tx_fee(fee_type, address, token_id)
+                .await?
+                .total_fee),
+            Self::Ledger(ledger) => ledger
+                .tx_fee(fee_type, address, token_id)
+                .ok_or(zksync::error::ClientError::UnknownToken),
+        }
+    }
+
+    ///
+    /// Executes a single transaction and waits for it to be committed.
+    ///
+    pub async fn execute(&self, transaction: zinc_types::Transaction) -> Result<(), Error> {
+        match self {
+            Self::ZkSync(network) => {
+                let provider = zksync::RpcProvider::new(*network);
+
+                let tx_hash = provider
+                    .send_tx(
+                        transaction.tx,
+                        transaction
+                            .ethereum_signature
+                            .map(|signature| signature.signature),
+                    )
+                    .await?;
+                let mut handle = zksync::operations::SyncTransactionHandle::new(tx_hash, provider)
+                    .commit_timeout(Duration::from_secs(Self::COMMIT_TIMEOUT_SECS));
+                handle
+                    .polling_interval(Duration::from_millis(Self::POLLING_INTERVAL_MILLIS))
+                    .expect(zinc_const::panic::DATA_CONVERSION);
+
+                let tx_info = handle.wait_for_commit().await?;
+                if !tx_info.success.unwrap_or_default() {
+                    return Err(Error::TransferFailure(
+                        tx_info
+                            .fail_reason
+                            .unwrap_or_else(|| "Unknown error".to_owned()),
+                    ));
+                }
+
+                Ok(())
+            }
+            Self::Ledger(ledger) => ledger
+                .execute(vec![transaction.tx])
+                .map_err(|error| Error::TransferFailure(error.to_string())),
+        }
+    }
+
+    ///
+    /// Executes a batch of transactions and waits for it to be committed.
+    ///
+    /// The batch is committed or rejected as a whole.
+    ///
+    pub async fn execute_batch(
+        &self,
+        transactions: Vec<zinc_types::Transaction>,
+    ) -> Result<(), Error> {
+        match self {
+            Self::ZkSync(network) => {
+                let provider = zksync::RpcProvider::new(*network);
+
+                let handles: Vec<zksync::operations::SyncTransactionHandle<zksync::RpcProvider>> =
+                    provider
+                        .send_txs_batch(
+                            transactions
+                                .into_iter()
+                                .map(|transaction| {
+                                    (
+                                        transaction.tx,
+                                        transaction
+                                            .ethereum_signature
+                                            .map(|signature| signature.signature),
+                                    )
+                                })
+                                .collect(),
+                            None,
+                        )
+                        .await?
+                        .into_iter()
+                        .map(|tx_hash| {
+                            let mut handle = zksync::operations::SyncTransactionHandle::new(
+                                tx_hash,
+                                provider.clone(),
+                            )
+                            .commit_timeout(Duration::from_secs(Self::COMMIT_TIMEOUT_SECS));
+                            handle
+                                .polling_interval(Duration::from_millis(
+                                    Self::POLLING_INTERVAL_MILLIS,
+                                ))
+                                .expect(zinc_const::panic::DATA_CONVERSION);
+                            handle
+                        })
+                        .collect();
+
+                if let Some(handle) = handles.last() {
+                    let tx_info = handle.wait_for_commit().await?;
+                    if !tx_info.success.unwrap_or_default() {
+                        return Err(Error::TransferFailure(
+                            tx_info
+                                .fail_reason
+                                .unwrap_or_else(|| "Unknown error".to_owned()),
+                        ));
+                    }
+                }
+
+                Ok(())
+            }
+            Self::Ledger(ledger) => ledger
+                .execute(
+                    transactions
+                        .into_iter()
+                        .map(|transaction| transaction.tx)
+                        .collect(),
+                )
+                .map_err(|error| Error::TransferFailure(error.to_string())),
+        }
+    }
+
+    ///
+    /// Waits for the account with the `address` to get its ID, which happens after the first
+    /// deposit to the account is committed.
+    ///
+    pub async fn wait_for_account_id(
+        &self,
+        address: zksync_types::Address,
+    ) -> Result<zksync_types::AccountId, Error> {
+        match self {
+            Self::ZkSync(_) => {
+                let started_at = Instant::now();
+                loop {
+                    if let Some(account_id) = self.account(address).await?.id {
+                        return Ok(account_id);
+                    }
+
+                    if started_at.elapsed() >= Duration::from_secs(Self::ACCOUNT_ID_TIMEOUT_SECS) {
+                        return Err(Error::AccountIdNotFound);
+                    }
+
+                    tokio::time::delay_for(Duration::from_millis(Self::POLLING_INTERVAL_MILLIS))
+                        .await;
+                }
+            }
+            Self::Ledger(ledger) => ledger.account(address).id.ok_or(Error::AccountIdNotFound),
+        }
+    }
+}
+
+impl FromStr for Network {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            Self::LEDGER => Ok(Self::Ledger(Ledger::default())),
+            value => zksync::Network::from_str(value)
+                .map(Self::ZkSync)
+                .map_err(|_| value.to_owned()),
+        }
+    }
+}
+
+impl fmt::Display for Network {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ZkSync(network) => write!(f, "{}", network),
+            Self::Ledger(_) => write!(f, "{}", Self::LEDGER),
+        }
+    }
+}
@@ -0,0 +1,119 @@
+//!
+//! The network wallet.
+//!
+
+use num_old::BigUint;
+
+use zksync::signer::Signer;
+use zksync::tokens_cache::TokensCache;
+use zksync_eth_signer::PrivateKeySigner;
+
+use crate::error::Error;
+use crate::network::account::Account;
+use crate::network::Network;
+
+///
+/// The wallet, which signs the transactions with an account private key.
+///
+/// Unlike the zkSync SDK wallet, the transactions are signed locally and executed by the
+/// network, so the same wallet works with both zkSync and the in-memory ledger.
+///
+#[derive(Debug)]
+pub struct Wallet {
+    /// The account ETH address.
+    pub eth_address: zksync_types::Address,
+    /// The network, where the transactions are executed.
+    pub network: Network,
+    /// The transaction signer.
+    pub signer: Signer<PrivateKeySigner>,
+    /// The tokens supported by the network.
+    pub tokens: TokensCache,
+}
+
+impl Wallet {
+    ///
+    /// Initializes the wallet of the account with the `eth_address`.
+    ///
+    pub async fn new(
+        network: Network,
+        eth_address: zksync_types::Address,
+        eth_private_key: zksync_types::H256,
+    ) -> Result<Self, zksync::error::ClientError> {
+        let wallet_credentials = zksync::WalletCredentials::from_eth_signer(
+            eth_address,
+            PrivateKeySigner::new(eth_private_key),
+            network.zksync(),
+        )
+        .await?;
+
+        let mut signer = Signer::with_credentials(wallet_credentials);
+        signer.set_account_id(network.account(eth_address).await?.id);
+
+        let tokens = network.tokens().await?;
+
+        Ok(Self {
+            eth_address,
+            network,
+            signer,
+            tokens,
+        })
+    }
+
+    ///
+    /// Returns the committed state of the wallet account.
+    ///
+    pub async fn account(&self) -> Result<Account, zksync::error::ClientError> {
+        self.network.account(self.eth_address).await
+    }
+
+    ///
+    /// Waits for the wallet account to get its ID and sets it to the signer.
+    ///
+    pub async fn wait_for_account_id(&mut self) -> Result<zksync_types::AccountId, Error> {
+        let account_id = self.network.wait_for_account_id(self.eth_address).await?;
+        self.signer.set_account_id(Some(account_id));
+        Ok(account_id)
+    }
+
+    ///
+    /// Signs a transfer of `amount` of `token` to `recipient`.
+    ///
+    pub async fn sign_transfer(
+        &self,
+        token: zksync_types::Token,
+        amount: BigUint,
+        fee: BigUint,
+        recipient: zksync_types::Address,
+        nonce: zksync_types::Nonce,
+    ) -> Result<zinc_types::Transaction, Error> {
+        let (transfer, signature) = self
+            .signer
+            .sign_transfer(token, amount, fee, recipient, nonce)
+            .await?;
+
+        Ok(zinc_types::Transaction::new(
+            zksync_types::ZkSyncTx::Transfer(Box::new(transfer)),
+            signature,
+        ))
+    }
+
+    ///
+    /// Signs a change-pubkey transaction, which unlocks the wallet account.
+    ///
+    pub async fn sign_change_pubkey(
+        &self,
+        token: zksync_types::Token,
+        fee: BigUint,
+        nonce: zksync_types::Nonce,
+    ) -> Result<zinc_types::Transaction, Error> {
+        let change_pubkey = self
+            .signer
+            .sign_change_pubkey_tx(nonce, false, token, fee)
+            .await?;
+
+        Ok(zinc_types::Transaction::new(
+            zksync_types::ZkSyncTx::ChangePubKey(Box::new(change_pubkey)),
+            None,
+        ))
+    }
+}
@@ -4,12 +4,12 @@
 
 use std::collections::HashMap;
 
-use zksync::provider::Provider;
-
 use zinc_vm::Bn256;
 use zinc_vm::ContractInput;
 
 use crate::error::Error;
+use crate::network::wallet::Wallet;
+use crate::network::Network;
 use crate::storage::Storage;
 
 ///
@@ -41,7 +41,7 @@ pub struct LockedContract {
     /// The contract storage.
     pub storage: Storage,
     /// The contract wallet.
-    pub wallet: zksync::Wallet<zksync_eth_signer::PrivateKeySigner, zksync::RpcProvider>,
+    pub wallet: Wallet,
 
     /// The token used for paying for changing the public key.
     pub change_pubkey_fee_token: zksync_types::Token,
@@ -55,7 +55,7 @@ impl LockedContract {
     ///
     #[allow(clippy::too_many_arguments)]
     pub async fn new(
-        network: zksync::Network,
+        network: Network,
 
         name: String,
         version: semver::Version,
@@ -120,14 +120,7 @@ impl LockedContract {
             .map(Storage::from_build)
             .expect(zinc_const::panic::VALIDATED_DURING_RUNTIME_EXECUTION);
 
-        let provider = zksync::RpcProvider::new(network);
-        let wallet_credentials = zksync::WalletCredentials::from_eth_signer(
-            eth_address,
-            zksync_eth_signer::PrivateKeySigner::new(eth_private_key),
-            network,
-        )
-        .await?;
-        let wallet = zksync::Wallet::new(provider, wallet_credentials).await?;
+        let wallet = Wallet::new(network, eth_address, eth_private_key).await?;
 
         let change_pubkey_fee_token = wallet
             .tokens
@@ -136,16 +129,15 @@ impl LockedContract {
 
         let change_pubkey_fee = zinc_types::num_compat_forward(
             wallet
-                .provider
-                .get_tx_fee(
+                .network
+                .tx_fee(
                     zksync_types::TxFeeTypes::ChangePubKey {
                         onchain_pubkey_auth: false,
                     },
                     eth_address,
                     change_pubkey_fee_token.id,
                 )
-                .await?
-                .total_fee,
+                .await?,
         );
 
         Ok(Self {
@@ -10,6 +10,7 @@ use std::sync::RwLock;
 use actix_web::web::Data;
 
 use crate::database::client::Client as DatabaseClient;
+use crate::network::Network;
 
 use self::locked_contract::LockedContract;
 
@@ -19,8 +20,8 @@ use self::locked_contract::LockedContract;
 pub struct SharedData {
     /// The PostgreSQL asynchronous client.
     pub postgresql: DatabaseClient,
-    /// The zkSync network identifier.
-    pub network: zksync::Network,
+    /// The network, where the contract transactions are executed.
+    pub network: Network,
     /// The contracts waiting to be unlocked by `initialize` endpoint.
     pub locked_contracts: HashMap<zksync_types::Address, LockedContract>,
 }
@@ -31,7 +32,7 @@ impl SharedData {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(postgresql: DatabaseClient, network: zksync::Network) -> Self {
+    pub fn new(postgresql: DatabaseClient, network: Network) -> Self {
         Self {
             postgresql,
             network,
@@ -7,20 +7,22 @@ use num::BigInt;
 use crate::database::client::Client as DatabaseClient;
 use crate::database::error::Error as DatabaseError;
 use crate::database::model;
+use crate::network::wallet::Wallet;
+use crate::network::Network;
 use crate::storage::Storage;
 
 pub struct Keeper {
     /// The PostgreSQL asynchronous client.
     pub postgresql: DatabaseClient,
-    /// The zkSync network identifier.
-    pub network: zksync::Network,
+    /// The network, where the contract transactions are executed.
+    pub network: Network,
 }
 
 impl Keeper {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(postgresql: DatabaseClient, network: zksync::Network) -> Self {
+    pub fn new(postgresql: DatabaseClient, network: Network) -> Self {
         Self {
             postgresql,
             network,
@@ -79,13 +81,11 @@ impl zinc_vm::IContractStorageKeeper for Keeper {
         let eth_private_key =
             zinc_types::private_key_from_slice(contract.eth_private_key.as_slice());
 
-        let provider = zksync::RpcProvider::new(self.network);
-        let wallet_credentials = runtime.block_on(zksync::WalletCredentials::from_eth_signer(
+        let wallet = runtime.block_on(Wallet::new(
+            self.network.clone(),
             eth_address,
-            zksync_eth_signer::PrivateKeySigner::new(eth_private_key),
-            self.network,
+            eth_private_key,
         ))?;
-        let wallet = runtime.block_on(zksync::Wallet::new(provider, wallet_credentials))?;
 
         let storage = runtime.block_on(Storage::new_with_data(
             fields,
@@ -5,6 +5,7 @@
 pub mod keeper;
 
 use crate::database::model;
+use crate::network::wallet::Wallet;
 
 ///
 /// The Zandbox contract storage wrapper.
@@ -38,13 +39,13 @@ impl Storage {
     /// Populates the storage with the database data and data from other sources.
     ///
     /// The `address` field at the index `0` is taken from the Zandbox in-memory cache.
-    /// The `balances` field at the index `1` is populated from the zkSync account info.
+    /// The `balances` field at the index `1` is populated from the network account info.
     ///
     pub async fn new_with_data(
         database_fields: Vec<model::field::select::Output>,
         types: &[zinc_types::ContractFieldType],
         address: zksync_types::Address,
-        wallet: &zksync::Wallet<zksync_eth_signer::PrivateKeySigner, zksync::RpcProvider>,
+        wallet: &Wallet,
     ) -> Result<Self, zksync::error::ClientError> {
         let mut fields = Vec::with_capacity(database_fields.len());
 
@@ -61,14 +62,14 @@ impl Storage {
             true,
         ));
 
-        let account_info = wallet.account_info().await?;
-        let mut balances = Vec::with_capacity(account_info.committed.balances.len());
-        for (symbol, balance) in account_info.committed.balances.into_iter() {
+        let account = wallet.account().await?;
+        let mut balances = Vec::with_capacity(account.balances.len());
+        for (symbol, balance) in account.balances.into_iter() {
             let token = wallet
                 .tokens
                 .resolve(zksync_types::TokenLike::Symbol(symbol))
                 .ok_or(zksync::error::ClientError::UnknownToken)?;
-            balances.push((token.address, balance.0.to_string()));
+            balances.push((token.address, balance.to_string()));
         }
         balances.sort_by_key(|(address, _balance)| *address);
         fields.push(zinc_types::ContractFieldValue::new(
@@ -29,7 +29,7 @@ pub struct Arguments {
     #[structopt(short = "d", long = "postgresql")]
     pub postgresql_uri: String,
 
-    /// The zkSync network identifier.
+    /// The zkSync network identifier, or `ledger` to use the in-memory ledger.
     #[structopt(short = "n", long = "network")]
     pub network: String,
 }
@@ -24,8 +24,11 @@ async fn main() -> anyhow::Result<()> {
 
     log::info!("Zandbox server started");
 
-    let network = zksync::Network::from_str(args.network.as_str())
+    let network = zandbox::Network::from_str(args.network.as_str())
         .map_err(|network| anyhow::anyhow!(format!("Invalid network `{}`", network)))?;
+    if let zandbox::Network::Ledger(_) = network {
+        log::info!("Using the in-memory ledger instead of zkSync");
+    }
 
     log::info!("Initializing the PostgreSQL client");
     let postgresql = zandbox::DatabaseClient::new(args.postgresql_uri.as_str()).await?;
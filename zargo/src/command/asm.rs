@@ -0,0 +1,139 @@
+//!
+//! The Zargo package manager `asm` subcommand.
+//!
+
+use std::convert::TryFrom;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use structopt::StructOpt;
+
+use crate::error::Error;
+use crate::executable::compiler::Compiler;
+use crate::executable::virtual_machine::VirtualMachine;
+use crate::http::downloader::Downloader;
+use crate::http::Client as HttpClient;
+use crate::network::Network;
+use crate::project::target::deps::Directory as TargetDependenciesDirectory;
+use crate::project::target::Directory as TargetDirectory;
+
+///
+/// The Zargo package manager `asm` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(about = "Builds the project and prints its bytecode instructions")]
+pub struct Command {
+    /// Prints more logs, if passed several times.
+    #[structopt(short = "v", long = "verbose", parse(from_occurrences))]
+    pub verbosity: usize,
+
+    /// Suppresses output, if set.
+    #[structopt(short = "q", long = "quiet")]
+    pub quiet: bool,
+
+    /// The path to the Zinc project manifest file.
+    #[structopt(
+        long = "manifest-path",
+        parse(from_os_str),
+        default_value = "./Zargo.toml"
+    )]
+    pub manifest_path: PathBuf,
+
+    /// Uses the release build.
+    #[structopt(long = "release")]
+    pub is_release: bool,
+
+    /// Sets the network name, where the dependencies must be downloaded from.
+    #[structopt(long = "network", default_value = "localhost")]
+    pub network: String,
+
+    /// Builds the project with the dependencies downloaded before, without accessing Zandbox.
+    #[structopt(long = "offline")]
+    pub offline: bool,
+}
+
+impl Command {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        verbosity: usize,
+        quiet: bool,
+        manifest_path: PathBuf,
+        is_release: bool,
+        network: Option<String>,
+    ) -> Self {
+        Self {
+            verbosity,
+            quiet,
+            manifest_path,
+            is_release,
+            network: network
+                .unwrap_or_else(|| Network::from(zksync::Network::Localhost).to_string()),
+            offline: false,
+        }
+    }
+
+    ///
+    /// Executes the command.
+    ///
+    pub async fn execute(self) -> anyhow::Result<()> {
+        let manifest = zinc_project::Manifest::try_from(&self.manifest_path)?;
+
+        let mut manifest_path = self.manifest_path.clone();
+        if manifest_path.is_file() {
+            manifest_path.pop();
+        }
+
+        TargetDirectory::create(&manifest_path, self.is_release)?;
+        let target_directory_path = TargetDirectory::path(&manifest_path, self.is_release);
+        let mut binary_path = target_directory_path;
+        binary_path.push(format!(
+            "{}.{}",
+            zinc_const::file_name::BINARY,
+            zinc_const::extension::BINARY
+        ));
+
+        TargetDependenciesDirectory::create(&manifest_path)?;
+
+        if !self.offline {
+            if let Some(dependencies) = manifest.dependencies {
+                let network = zksync::Network::from_str(self.network.as_str())
+                    .map(Network::from)
+                    .map_err(Error::NetworkInvalid)?;
+                let url = network
+                    .try_into_url()
+                    .map_err(Error::NetworkUnimplemented)?;
+                let http_client = HttpClient::new(url);
+                let mut downloader = Downloader::new(&http_client, &manifest_path);
+                downloader
+                    .download_dependency_list(Some(manifest_path.clone()), dependencies)
+                    .await?;
+            }
+        }
+
+        if self.is_release {
+            Compiler::build_release(
+                self.verbosity,
+                self.quiet,
+                manifest.project.name.as_str(),
+                &manifest.project.version,
+                &manifest_path,
+                false,
+            )?;
+        } else {
+            Compiler::build_debug(
+                self.verbosity,
+                self.quiet,
+                manifest.project.name.as_str(),
+                &manifest.project.version,
+                &manifest_path,
+                false,
+            )?;
+        }
+
+        VirtualMachine::disassemble(self.verbosity, self.quiet, &binary_path)?;
+
+        Ok(())
+    }
+}
@@ -47,6 +47,10 @@ pub struct Command {
     /// Sets the network name, where the contract must be published to.
     #[structopt(long = "network", default_value = "localhost")]
     pub network: String,
+
+    /// Builds the project with the dependencies downloaded before, without accessing Zandbox.
+    #[structopt(long = "offline")]
+    pub offline: bool,
 }
 
 impl Command {
@@ -67,6 +71,7 @@ impl Command {
             is_release,
             network: network
                 .unwrap_or_else(|| Network::from(zksync::Network::Localhost).to_string()),
+            offline: false,
         }
     }
 
@@ -93,16 +98,20 @@ impl Command {
 
         DataDirectory::create(&manifest_path)?;
 
-        if let Some(dependencies) = manifest.dependencies {
-            let network = zksync::Network::from_str(self.network.as_str())
-                .map(Network::from)
-                .map_err(Error::NetworkInvalid)?;
-            let url = network
-                .try_into_url()
-                .map_err(Error::NetworkUnimplemented)?;
-            let http_client = HttpClient::new(url);
-            let mut downloader = Downloader::new(&http_client, &manifest_path);
-            downloader.download_dependency_list(dependencies).await?;
+        if !self.offline {
+            if let Some(dependencies) = manifest.dependencies {
+                let network = zksync::Network::from_str(self.network.as_str())
+                    .map(Network::from)
+                    .map_err(Error::NetworkInvalid)?;
+                let url = network
+                    .try_into_url()
+                    .map_err(Error::NetworkUnimplemented)?;
+                let http_client = HttpClient::new(url);
+                let mut downloader = Downloader::new(&http_client, &manifest_path);
+                downloader
+                    .download_dependency_list(Some(manifest_path.clone()), dependencies)
+                    .await?;
+            }
         }
 
         if self.is_release {
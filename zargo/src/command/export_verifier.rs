@@ -0,0 +1,99 @@
+//!
+//! The Zargo package manager `export-verifier` subcommand.
+//!
+
+use std::convert::TryFrom;
+use std::path::PathBuf;
+
+use structopt::StructOpt;
+
+use crate::error::Error;
+use crate::executable::virtual_machine::VirtualMachine;
+use crate::project::data::Directory as DataDirectory;
+
+///
+/// The Zargo package manager `export-verifier` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(about = "Generates the Solidity verifier contract from the verifying key")]
+pub struct Command {
+    /// Prints more logs, if passed several times.
+    #[structopt(short = "v", long = "verbose", parse(from_occurrences))]
+    pub verbosity: usize,
+
+    /// Suppresses output, if set.
+    #[structopt(short = "q", long = "quiet")]
+    pub quiet: bool,
+
+    /// The path to the Zinc project manifest file.
+    #[structopt(
+        long = "manifest-path",
+        parse(from_os_str),
+        default_value = "./Zargo.toml"
+    )]
+    pub manifest_path: PathBuf,
+
+    /// The path to the Solidity contract file to write. Defaults to `data/verifier.sol`.
+    #[structopt(long = "output", parse(from_os_str))]
+    pub verifier_path: Option<PathBuf>,
+}
+
+impl Command {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        verbosity: usize,
+        quiet: bool,
+        manifest_path: PathBuf,
+        verifier_path: Option<PathBuf>,
+    ) -> Self {
+        Self {
+            verbosity,
+            quiet,
+            manifest_path,
+            verifier_path,
+        }
+    }
+
+    ///
+    /// Executes the command.
+    ///
+    pub fn execute(self) -> anyhow::Result<()> {
+        let manifest = zinc_project::Manifest::try_from(&self.manifest_path)?;
+
+        if let zinc_project::ProjectType::Library = manifest.project.r#type {
+            anyhow::bail!(Error::CannotExportLibraryVerifier);
+        }
+
+        let mut manifest_path = self.manifest_path.clone();
+        if manifest_path.is_file() {
+            manifest_path.pop();
+        }
+
+        let data_directory_path = DataDirectory::path(&manifest_path);
+        let mut verifying_key_path = data_directory_path.clone();
+        verifying_key_path.push(zinc_const::file_name::VERIFYING_KEY.to_owned());
+        let verifier_path = match self.verifier_path {
+            Some(verifier_path) => verifier_path,
+            None => {
+                let mut verifier_path = data_directory_path;
+                verifier_path.push(format!(
+                    "{}.{}",
+                    zinc_const::file_name::VERIFIER,
+                    zinc_const::extension::SOLIDITY,
+                ));
+                verifier_path
+            }
+        };
+
+        VirtualMachine::export_verifier(
+            self.verbosity,
+            self.quiet,
+            &verifying_key_path,
+            &verifier_path,
+        )?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,130 @@
+//!
+//! The Zargo package manager `fmt` subcommand.
+//!
+
+use std::convert::TryFrom;
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use colored::Colorize;
+use structopt::StructOpt;
+
+use crate::error::Error;
+use crate::project::src::Directory as SourceDirectory;
+
+///
+/// The Zargo package manager `fmt` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(about = "Formats the project source code")]
+pub struct Command {
+    /// Prints more logs, if passed several times.
+    #[structopt(short = "v", long = "verbose", parse(from_occurrences))]
+    pub verbosity: usize,
+
+    /// Suppresses output, if set.
+    #[structopt(short = "q", long = "quiet")]
+    pub quiet: bool,
+
+    /// The path to the Zinc project manifest file.
+    #[structopt(
+        long = "manifest-path",
+        parse(from_os_str),
+        default_value = "./Zargo.toml"
+    )]
+    pub manifest_path: PathBuf,
+
+    /// Only checks whether the source code is formatted, without writing the files.
+    #[structopt(long = "check")]
+    pub is_check: bool,
+}
+
+impl Command {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(verbosity: usize, quiet: bool, manifest_path: PathBuf, is_check: bool) -> Self {
+        Self {
+            verbosity,
+            quiet,
+            manifest_path,
+            is_check,
+        }
+    }
+
+    ///
+    /// Executes the command.
+    ///
+    pub fn execute(self) -> anyhow::Result<()> {
+        let _manifest = zinc_project::Manifest::try_from(&self.manifest_path)?;
+
+        let mut manifest_path = self.manifest_path;
+        if manifest_path.is_file() {
+            manifest_path.pop();
+        }
+
+        let mut paths = Vec::new();
+        Self::collect(SourceDirectory::path(&manifest_path), &mut paths)?;
+        paths.sort();
+
+        let mut unformatted = Vec::new();
+        for (index, path) in paths.into_iter().enumerate() {
+            let code =
+                fs::read_to_string(&path).with_context(|| path.to_string_lossy().to_string())?;
+
+            let formatted = zinc_syntax::Formatter::format(code.as_str(), index)
+                .map_err(|error| Error::Formatting(path.to_owned(), error.to_string()))?;
+            if formatted == code {
+                continue;
+            }
+
+            if self.is_check {
+                if !self.quiet {
+                    eprintln!("{} {}", "Unformatted".bright_red(), path.to_string_lossy());
+                }
+                unformatted.push(path);
+            } else {
+                fs::write(&path, formatted).with_context(|| path.to_string_lossy().to_string())?;
+                if !self.quiet {
+                    eprintln!(
+                        "   {} {}",
+                        "Formatted".bright_green(),
+                        path.to_string_lossy()
+                    );
+                }
+            }
+        }
+
+        if !unformatted.is_empty() {
+            anyhow::bail!(Error::FormattingRequired(unformatted.len()));
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Collects the source code files of the `directory` and its subdirectories.
+    ///
+    fn collect(directory: PathBuf, paths: &mut Vec<PathBuf>) -> anyhow::Result<()> {
+        for entry in
+            fs::read_dir(&directory).with_context(|| directory.to_string_lossy().to_string())?
+        {
+            let path = entry
+                .with_context(|| directory.to_string_lossy().to_string())?
+                .path();
+
+            if path.is_dir() {
+                Self::collect(path, paths)?;
+                continue;
+            }
+
+            match path.extension() {
+                Some(extension) if extension == zinc_const::extension::SOURCE => paths.push(path),
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
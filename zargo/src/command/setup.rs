@@ -82,6 +82,7 @@ impl Command {
             manifest_path.pop();
         }
 
+        DataDirectory::create(&manifest_path)?;
         let data_directory_path = DataDirectory::path(&manifest_path);
         let mut proving_key_path = data_directory_path.clone();
         proving_key_path.push(zinc_const::file_name::PROVING_KEY);
@@ -3,6 +3,7 @@
 //!
 
 use std::convert::TryFrom;
+use std::ffi::OsString;
 use std::path::PathBuf;
 use std::str::FromStr;
 
@@ -42,6 +43,34 @@ pub struct Command {
     /// Sets the network name, where the contract must be published to.
     #[structopt(long = "network", default_value = "localhost")]
     pub network: String,
+
+    /// Builds the project with the dependencies downloaded before, without accessing Zandbox.
+    #[structopt(long = "offline")]
+    pub offline: bool,
+
+    /// Runs only the tests whose names contain any of the filters.
+    #[structopt(name = "FILTER")]
+    pub filters: Vec<String>,
+
+    /// Makes the filters match the exact test names.
+    #[structopt(long = "exact")]
+    pub is_exact: bool,
+
+    /// Runs the tests marked with the `ignore` attribute as well.
+    #[structopt(long = "include-ignored")]
+    pub include_ignored: bool,
+
+    /// The number of threads to run the tests in. Defaults to the number of CPUs.
+    #[structopt(long = "test-threads")]
+    pub threads: Option<usize>,
+
+    /// The path to the JSON report file.
+    #[structopt(long = "json")]
+    pub json_path: Option<PathBuf>,
+
+    /// The path to the JUnit XML report file.
+    #[structopt(long = "junit")]
+    pub junit_path: Option<PathBuf>,
 }
 
 impl Command {
@@ -60,6 +89,13 @@ impl Command {
             manifest_path,
             network: network
                 .unwrap_or_else(|| Network::from(zksync::Network::Localhost).to_string()),
+            offline: false,
+            filters: vec![],
+            is_exact: false,
+            include_ignored: false,
+            threads: None,
+            json_path: None,
+            junit_path: None,
         }
     }
 
@@ -85,16 +121,20 @@ impl Command {
 
         TargetDependenciesDirectory::create(&manifest_path)?;
 
-        if let Some(dependencies) = manifest.dependencies {
-            let network = zksync::Network::from_str(self.network.as_str())
-                .map(Network::from)
-                .map_err(Error::NetworkInvalid)?;
-            let url = network
-                .try_into_url()
-                .map_err(Error::NetworkUnimplemented)?;
-            let http_client = HttpClient::new(url);
-            let mut downloader = Downloader::new(&http_client, &manifest_path);
-            downloader.download_dependency_list(dependencies).await?;
+        if !self.offline {
+            if let Some(dependencies) = manifest.dependencies {
+                let network = zksync::Network::from_str(self.network.as_str())
+                    .map(Network::from)
+                    .map_err(Error::NetworkInvalid)?;
+                let url = network
+                    .try_into_url()
+                    .map_err(Error::NetworkUnimplemented)?;
+                let http_client = HttpClient::new(url);
+                let mut downloader = Downloader::new(&http_client, &manifest_path);
+                downloader
+                    .download_dependency_list(Some(manifest_path.clone()), dependencies)
+                    .await?;
+            }
         }
 
         Compiler::build_release(
@@ -106,8 +146,45 @@ impl Command {
             true,
         )?;
 
-        VirtualMachine::test(self.verbosity, self.quiet, &binary_path)?;
+        VirtualMachine::test(
+            self.verbosity,
+            self.quiet,
+            &binary_path,
+            self.virtual_machine_arguments(),
+        )?;
 
         Ok(())
     }
+
+    ///
+    /// Returns the virtual machine `test` subcommand arguments, which select and report the tests.
+    ///
+    fn virtual_machine_arguments(&self) -> Vec<OsString> {
+        let mut arguments: Vec<OsString> = Vec::new();
+
+        if self.is_exact {
+            arguments.push("--exact".into());
+        }
+        if self.include_ignored {
+            arguments.push("--include-ignored".into());
+        }
+        if let Some(threads) = self.threads {
+            arguments.push("--test-threads".into());
+            arguments.push(threads.to_string().into());
+        }
+        if let Some(ref json_path) = self.json_path {
+            arguments.push("--json".into());
+            arguments.push(json_path.into());
+        }
+        if let Some(ref junit_path) = self.junit_path {
+            arguments.push("--junit".into());
+            arguments.push(junit_path.into());
+        }
+        if !self.filters.is_empty() {
+            arguments.push("--".into());
+            arguments.extend(self.filters.iter().map(OsString::from));
+        }
+
+        arguments
+    }
 }
@@ -82,6 +82,15 @@ impl Command {
 
         let manifest = zinc_project::Manifest::try_from(&self.manifest_path)?;
 
+        if let Some(dependencies) = manifest.dependencies.as_ref() {
+            if let Some((name, _dependency)) = dependencies
+                .iter()
+                .find(|(_name, dependency)| dependency.path().is_some())
+            {
+                anyhow::bail!(Error::PathDependencyUnsupported(name.to_owned()));
+            }
+        }
+
         let mut manifest_path = self.manifest_path;
         if manifest_path.is_file() {
             manifest_path.pop();
@@ -125,7 +134,9 @@ impl Command {
                 .map_err(Error::NetworkUnimplemented)?;
             let http_client = HttpClient::new(url);
             let mut downloader = Downloader::new(&http_client, &manifest_path);
-            downloader.download_dependency_list(dependencies).await?;
+            downloader
+                .download_dependency_list(None, dependencies)
+                .await?;
         }
 
         Compiler::build_release(
@@ -61,6 +61,10 @@ pub enum Error {
     #[error("contract method to call must be specified")]
     MethodMissing,
 
+    /// The library has no entry points to verify.
+    #[error("libraries have no entry points, so their proofs cannot be verified")]
+    CannotExportLibraryVerifier,
+
     /// The input file section is missing.
     #[error("input file data must contain section `{0}`")]
     MissingInputSection(String),
@@ -97,11 +101,23 @@ pub enum Error {
     #[error("contract project downloading request: {0}")]
     ContractProjectDownloading(String),
 
+    /// The source code file cannot be formatted.
+    #[error("file {0:?}: {1}")]
+    Formatting(std::path::PathBuf, String),
+
+    /// The source code files are not formatted. Only in the `--check` mode.
+    #[error("{0} file(s) must be formatted, run `zargo fmt`")]
+    FormattingRequired(usize),
+
     /// The dependency requires different version of the compiler.
     #[error("project {0}: compiler version mismatch: expected {1}, found {2}")]
     CompilerVersionMismatch(String, String, String),
 
-    /// The command is temporarily unavailable.
-    #[error("the proof verification is temporarily unavailable")]
-    ProofVerificationUnavailable,
+    /// The dependency with a matching version has not been found on the Zandbox server.
+    #[error("dependency `{0}` with version `{1}` not found")]
+    DependencyNotFound(String, String),
+
+    /// The path dependency in a project, which is uploaded to the Zandbox server.
+    #[error("path dependency `{0}` is not allowed in projects uploaded to Zandbox")]
+    PathDependencyUnsupported(String),
 }
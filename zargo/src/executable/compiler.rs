@@ -86,7 +86,8 @@ impl Compiler {
             } else {
                 vec![]
             })
-            .arg("--opt-dfe")
+            .arg("--opt-level")
+            .arg("2")
             .spawn()
             .with_context(|| zinc_const::app_name::COMPILER)?;
 
@@ -2,6 +2,7 @@
 //! The compiler executable.
 //!
 
+use std::ffi::OsString;
 use std::io::Write;
 use std::path::PathBuf;
 use std::process;
@@ -119,10 +120,13 @@ impl VirtualMachine {
     ///
     /// Executes the virtual machine `test` subcommand.
     ///
+    /// The `arguments` select the tests and set the report files.
+    ///
     pub fn test(
         verbosity: usize,
         quiet: bool,
         binary_path: &PathBuf,
+        arguments: Vec<OsString>,
     ) -> anyhow::Result<ExitStatus> {
         let mut process = process::Command::new(zinc_const::app_name::VIRTUAL_MACHINE)
             .args(vec!["-v"; verbosity])
@@ -130,6 +134,7 @@ impl VirtualMachine {
             .arg("test")
             .arg("--binary")
             .arg(binary_path)
+            .args(arguments)
             .spawn()
             .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
 
@@ -144,13 +149,287 @@ impl VirtualMachine {
         Ok(status)
     }
 
+    ///
+    /// Executes the virtual machine `disasm` subcommand.
+    ///
+    pub fn disassemble(verbosity: usize, quiet: bool, binary_path: &PathBuf) -> anyhow::Result<()> {
+        let mut process = process::Command::new(zinc_const::app_name::VIRTUAL_MACHINE)
+            .args(vec!["-v"; verbosity])
+            .args(if quiet { vec!["--quiet"] } else { vec![] })
+            .arg("disasm")
+            .arg("--binary")
+            .arg(binary_path)
+            .spawn()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
+
+        let status = process
+            .wait()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
+
+        if !status.success() {
+            anyhow::bail!(Error::SubprocessFailure(status));
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Executes the virtual machine `profile` subcommand for circuit.
+    ///
+    pub fn profile_circuit(
+        verbosity: usize,
+        quiet: bool,
+        binary_path: &PathBuf,
+        input_path: &PathBuf,
+        folded_path: &PathBuf,
+    ) -> anyhow::Result<()> {
+        if !quiet {
+            eprintln!(
+                "   {} `{}` {}",
+                "Profiling".bright_green(),
+                binary_path.to_string_lossy(),
+                if verbosity > 0 {
+                    format!("-{}", "v".repeat(verbosity))
+                } else {
+                    String::new()
+                },
+            );
+        }
+
+        let mut process = process::Command::new(zinc_const::app_name::VIRTUAL_MACHINE)
+            .args(vec!["-v"; verbosity])
+            .args(if quiet { vec!["--quiet"] } else { vec![] })
+            .arg("profile")
+            .arg("--binary")
+            .arg(binary_path)
+            .arg("--input")
+            .arg(input_path)
+            .arg("--folded")
+            .arg(folded_path)
+            .spawn()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
+
+        let status = process
+            .wait()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
+
+        if !status.success() {
+            anyhow::bail!(Error::SubprocessFailure(status));
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Executes the virtual machine `profile` subcommand for contract.
+    ///
+    pub fn profile_contract(
+        verbosity: usize,
+        quiet: bool,
+        binary_path: &PathBuf,
+        input_path: &PathBuf,
+        folded_path: &PathBuf,
+        method: &str,
+    ) -> anyhow::Result<()> {
+        if !quiet {
+            eprintln!(
+                "   {} `{}` {}",
+                "Profiling".bright_green(),
+                binary_path.to_string_lossy(),
+                if verbosity > 0 {
+                    format!("-{}", "v".repeat(verbosity))
+                } else {
+                    String::new()
+                },
+            );
+        }
+
+        let mut process = process::Command::new(zinc_const::app_name::VIRTUAL_MACHINE)
+            .args(vec!["-v"; verbosity])
+            .args(if quiet { vec!["--quiet"] } else { vec![] })
+            .arg("profile")
+            .arg("--binary")
+            .arg(binary_path)
+            .arg("--input")
+            .arg(input_path)
+            .arg("--folded")
+            .arg(folded_path)
+            .arg("--method")
+            .arg(method)
+            .spawn()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
+
+        let status = process
+            .wait()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
+
+        if !status.success() {
+            anyhow::bail!(Error::SubprocessFailure(status));
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Executes the virtual machine `debug` subcommand for circuit.
+    ///
+    pub fn debug_circuit(
+        verbosity: usize,
+        quiet: bool,
+        binary_path: &PathBuf,
+        input_path: &PathBuf,
+        breakpoints: &[String],
+    ) -> anyhow::Result<()> {
+        if !quiet {
+            eprintln!(
+                "   {} `{}` {}",
+                "Debugging".bright_green(),
+                binary_path.to_string_lossy(),
+                if verbosity > 0 {
+                    format!("-{}", "v".repeat(verbosity))
+                } else {
+                    String::new()
+                },
+            );
+        }
+
+        let mut process = process::Command::new(zinc_const::app_name::VIRTUAL_MACHINE)
+            .args(vec!["-v"; verbosity])
+            .args(if quiet { vec!["--quiet"] } else { vec![] })
+            .arg("debug")
+            .arg("--binary")
+            .arg(binary_path)
+            .arg("--input")
+            .arg(input_path)
+            .args(
+                breakpoints
+                    .iter()
+                    .flat_map(|breakpoint| vec!["--break", breakpoint.as_str()]),
+            )
+            .spawn()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
+
+        let status = process
+            .wait()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
+
+        if !status.success() {
+            anyhow::bail!(Error::SubprocessFailure(status));
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Executes the virtual machine `debug` subcommand for contract.
+    ///
+    pub fn debug_contract(
+        verbosity: usize,
+        quiet: bool,
+        binary_path: &PathBuf,
+        input_path: &PathBuf,
+        method: &str,
+        breakpoints: &[String],
+    ) -> anyhow::Result<()> {
+        if !quiet {
+            eprintln!(
+                "   {} `{}` {}",
+                "Debugging".bright_green(),
+                binary_path.to_string_lossy(),
+                if verbosity > 0 {
+                    format!("-{}", "v".repeat(verbosity))
+                } else {
+                    String::new()
+                },
+            );
+        }
+
+        let mut process = process::Command::new(zinc_const::app_name::VIRTUAL_MACHINE)
+            .args(vec!["-v"; verbosity])
+            .args(if quiet { vec!["--quiet"] } else { vec![] })
+            .arg("debug")
+            .arg("--binary")
+            .arg(binary_path)
+            .arg("--input")
+            .arg(input_path)
+            .arg("--method")
+            .arg(method)
+            .args(
+                breakpoints
+                    .iter()
+                    .flat_map(|breakpoint| vec!["--break", breakpoint.as_str()]),
+            )
+            .spawn()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
+
+        let status = process
+            .wait()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
+
+        if !status.success() {
+            anyhow::bail!(Error::SubprocessFailure(status));
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Executes the virtual machine `debug` subcommand for unit test.
+    ///
+    pub fn debug_test(
+        verbosity: usize,
+        quiet: bool,
+        binary_path: &PathBuf,
+        name: &str,
+        breakpoints: &[String],
+    ) -> anyhow::Result<()> {
+        if !quiet {
+            eprintln!(
+                "   {} `{}` {}",
+                "Debugging".bright_green(),
+                binary_path.to_string_lossy(),
+                if verbosity > 0 {
+                    format!("-{}", "v".repeat(verbosity))
+                } else {
+                    String::new()
+                },
+            );
+        }
+
+        let mut process = process::Command::new(zinc_const::app_name::VIRTUAL_MACHINE)
+            .args(vec!["-v"; verbosity])
+            .args(if quiet { vec!["--quiet"] } else { vec![] })
+            .arg("debug")
+            .arg("--binary")
+            .arg(binary_path)
+            .arg("--test")
+            .arg(name)
+            .args(
+                breakpoints
+                    .iter()
+                    .flat_map(|breakpoint| vec!["--break", breakpoint.as_str()]),
+            )
+            .spawn()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
+
+        let status = process
+            .wait()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
+
+        if !status.success() {
+            anyhow::bail!(Error::SubprocessFailure(status));
+        }
+
+        Ok(())
+    }
+
     ///
     /// Executes the virtual machine `setup` subcommand for circuit.
     ///
     pub fn setup_circuit(
-        _verbosity: usize,
+        verbosity: usize,
         quiet: bool,
-        _binary_path: &PathBuf,
+        binary_path: &PathBuf,
         proving_key_path: &PathBuf,
         verifying_key_path: &PathBuf,
     ) -> anyhow::Result<()> {
@@ -163,17 +442,26 @@ impl VirtualMachine {
             );
         }
 
-        let mut proving_key = std::fs::File::create(proving_key_path)
-            .with_context(|| proving_key_path.to_string_lossy().to_string())?;
-        proving_key
-            .write_all(vec![0u8; 8192].as_slice())
-            .with_context(|| proving_key_path.to_string_lossy().to_string())?;
+        let mut child = process::Command::new(zinc_const::app_name::VIRTUAL_MACHINE)
+            .args(vec!["-v"; verbosity])
+            .args(if quiet { vec!["--quiet"] } else { vec![] })
+            .arg("setup")
+            .arg("--binary")
+            .arg(binary_path)
+            .arg("--proving-key")
+            .arg(proving_key_path)
+            .arg("--verifying-key")
+            .arg(verifying_key_path)
+            .spawn()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
+
+        let status = child
+            .wait()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
 
-        let mut verifying_key = std::fs::File::create(verifying_key_path)
-            .with_context(|| verifying_key_path.to_string_lossy().to_string())?;
-        verifying_key
-            .write_all(vec![0u8; 1024].as_slice())
-            .with_context(|| verifying_key_path.to_string_lossy().to_string())?;
+        if !status.success() {
+            anyhow::bail!(Error::SubprocessFailure(status));
+        }
 
         Ok(())
     }
@@ -182,10 +470,10 @@ impl VirtualMachine {
     /// Executes the virtual machine `setup` subcommand for contract.
     ///
     pub fn setup_contract(
-        _verbosity: usize,
+        verbosity: usize,
         quiet: bool,
-        _binary_path: &PathBuf,
-        _method: &str,
+        binary_path: &PathBuf,
+        method: &str,
         proving_key_path: &PathBuf,
         verifying_key_path: &PathBuf,
     ) -> anyhow::Result<()> {
@@ -198,17 +486,28 @@ impl VirtualMachine {
             );
         }
 
-        let mut proving_key = std::fs::File::create(proving_key_path)
-            .with_context(|| proving_key_path.to_string_lossy().to_string())?;
-        proving_key
-            .write_all(vec![0u8; 8192].as_slice())
-            .with_context(|| proving_key_path.to_string_lossy().to_string())?;
+        let mut child = process::Command::new(zinc_const::app_name::VIRTUAL_MACHINE)
+            .args(vec!["-v"; verbosity])
+            .args(if quiet { vec!["--quiet"] } else { vec![] })
+            .arg("setup")
+            .arg("--binary")
+            .arg(binary_path)
+            .arg("--proving-key")
+            .arg(proving_key_path)
+            .arg("--verifying-key")
+            .arg(verifying_key_path)
+            .arg("--method")
+            .arg(method)
+            .spawn()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
+
+        let status = child
+            .wait()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
 
-        let mut verifying_key = std::fs::File::create(verifying_key_path)
-            .with_context(|| verifying_key_path.to_string_lossy().to_string())?;
-        verifying_key
-            .write_all(vec![0u8; 1024].as_slice())
-            .with_context(|| verifying_key_path.to_string_lossy().to_string())?;
+        if !status.success() {
+            anyhow::bail!(Error::SubprocessFailure(status));
+        }
 
         Ok(())
     }
@@ -397,6 +696,111 @@ impl VirtualMachine {
         Ok(())
     }
 
+    ///
+    /// Executes the virtual machine `export-verifier` subcommand.
+    ///
+    pub fn export_verifier(
+        verbosity: usize,
+        quiet: bool,
+        verifying_key_path: &PathBuf,
+        verifier_path: &PathBuf,
+    ) -> anyhow::Result<()> {
+        if !quiet {
+            eprintln!(
+                "   {} `{}` from `{}`",
+                "Exporting".bright_green(),
+                verifier_path.to_string_lossy(),
+                verifying_key_path.to_string_lossy(),
+            );
+        }
+
+        let mut child = process::Command::new(zinc_const::app_name::VIRTUAL_MACHINE)
+            .args(vec!["-v"; verbosity])
+            .args(if quiet { vec!["--quiet"] } else { vec![] })
+            .arg("export-verifier")
+            .arg("--verifying-key")
+            .arg(verifying_key_path)
+            .arg("--output")
+            .arg(verifier_path)
+            .spawn()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
+
+        let status = child
+            .wait()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
+
+        if !status.success() {
+            anyhow::bail!(Error::SubprocessFailure(status));
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Executes the virtual machine `calldata` subcommand.
+    ///
+    pub fn calldata_circuit(
+        verbosity: usize,
+        quiet: bool,
+        binary_path: &PathBuf,
+        output_path: &PathBuf,
+    ) -> anyhow::Result<()> {
+        let mut child = process::Command::new(zinc_const::app_name::VIRTUAL_MACHINE)
+            .args(vec!["-v"; verbosity])
+            .args(if quiet { vec!["--quiet"] } else { vec![] })
+            .arg("calldata")
+            .arg("--binary")
+            .arg(binary_path)
+            .arg("--output")
+            .arg(output_path)
+            .spawn()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
+
+        let status = child
+            .wait()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
+
+        if !status.success() {
+            anyhow::bail!(Error::SubprocessFailure(status));
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Executes the virtual machine `calldata` subcommand.
+    ///
+    pub fn calldata_contract(
+        verbosity: usize,
+        quiet: bool,
+        binary_path: &PathBuf,
+        output_path: &PathBuf,
+        method: &str,
+    ) -> anyhow::Result<()> {
+        let mut child = process::Command::new(zinc_const::app_name::VIRTUAL_MACHINE)
+            .args(vec!["-v"; verbosity])
+            .args(if quiet { vec!["--quiet"] } else { vec![] })
+            .arg("calldata")
+            .arg("--binary")
+            .arg(binary_path)
+            .arg("--output")
+            .arg(output_path)
+            .arg("--method")
+            .arg(method)
+            .spawn()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
+
+        let status = child
+            .wait()
+            .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
+
+        if !status.success() {
+            anyhow::bail!(Error::SubprocessFailure(status));
+        }
+
+        Ok(())
+    }
+
     ///
     /// Executes the virtual machine `prove` and `verify` subcommands for circuit.
     ///
@@ -432,9 +836,14 @@ impl VirtualMachine {
             .arg(input_path)
             .arg("--output")
             .arg(output_path)
+            .stderr(Stdio::inherit())
             .output()
             .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
 
+        if !prover_output.status.success() {
+            anyhow::bail!(Error::SubprocessFailure(prover_output.status));
+        }
+
         if !quiet {
             eprintln!(
                 "   {} `{}` with `{}`",
@@ -512,9 +921,14 @@ impl VirtualMachine {
             .arg(output_path)
             .arg("--method")
             .arg(method)
+            .stderr(Stdio::inherit())
             .output()
             .with_context(|| zinc_const::app_name::VIRTUAL_MACHINE)?;
 
+        if !prover_output.status.success() {
+            anyhow::bail!(Error::SubprocessFailure(prover_output.status));
+        }
+
         if !quiet {
             eprintln!(
                 "   {} `{}` with `{}`",
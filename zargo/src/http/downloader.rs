@@ -4,9 +4,11 @@
 
 use std::collections::HashMap;
 use std::collections::HashSet;
+use std::convert::TryFrom;
 use std::fs;
 use std::path::PathBuf;
 
+use anyhow::Context;
 use async_recursion::async_recursion;
 use colored::Colorize;
 
@@ -16,13 +18,24 @@ use crate::http::Client as HttpClient;
 ///
 /// The Zandbox dependency downloader.
 ///
+/// The dependency versions are resolved in the following order:
+/// 1. The version locked in the project lockfile, if it satisfies the requirement.
+/// 2. The highest matching version already downloaded to the dependency directory.
+/// 3. The highest matching version uploaded to the Zandbox server.
+///
 pub struct Downloader<'a> {
     /// The HTTP client reference.
     client: &'a HttpClient,
     /// The path to the directory where the dependencies must be downloaded to.
     directory: PathBuf,
+    /// The project lockfile, if it exists.
+    lockfile: Option<zinc_project::Lockfile>,
+    /// The Zandbox projects metadata, which is requested only if a dependency must be resolved.
+    metadata: Option<Vec<zinc_project::ManifestProject>>,
     /// The downloaded dependencies set to prevent downloading the same project multiple times.
     downloads: HashSet<(String, semver::Version)>,
+    /// The visited path dependencies set to prevent infinite recursion on cycles.
+    paths: HashSet<PathBuf>,
 }
 
 impl<'a> Downloader<'a> {
@@ -32,11 +45,18 @@ impl<'a> Downloader<'a> {
     ///
     /// A shortcut constructor.
     ///
+    /// A malformed lockfile is ignored here, since it is reported by the compiler.
+    ///
     pub fn new(client: &'a HttpClient, directory: &PathBuf) -> Self {
         Self {
             client,
             directory: directory.to_owned(),
+            lockfile: zinc_project::Lockfile::try_from_project(directory)
+                .ok()
+                .flatten(),
+            metadata: None,
             downloads: HashSet::with_capacity(Self::DOWNLOADS_INITIAL_CAPACITY),
+            paths: HashSet::with_capacity(Self::DOWNLOADS_INITIAL_CAPACITY),
         }
     }
 
@@ -72,7 +92,7 @@ impl<'a> Downloader<'a> {
 
         self.downloads.insert((name, version));
         if let Some(dependencies) = response.project.manifest.dependencies {
-            self.download_dependency_list(dependencies).await?;
+            self.download_dependency_list(None, dependencies).await?;
         }
 
         Ok(())
@@ -81,12 +101,49 @@ impl<'a> Downloader<'a> {
     ///
     /// Downloads a dependency list.
     ///
+    /// `base_path` is the dependent project directory, which the path dependencies are
+    /// relative to. It is `None` for the projects downloaded from Zandbox, which cannot have
+    /// path dependencies.
+    ///
+    #[async_recursion]
     pub async fn download_dependency_list(
         &mut self,
-        dependencies: HashMap<String, semver::Version>,
+        base_path: Option<PathBuf>,
+        dependencies: HashMap<String, zinc_project::ManifestDependency>,
     ) -> anyhow::Result<()> {
-        for (name, version) in dependencies.into_iter() {
-            self.download_dependency(name, version).await?;
+        for (name, dependency) in dependencies.into_iter() {
+            match dependency.path() {
+                Some(path) => {
+                    let base_path = base_path
+                        .as_ref()
+                        .ok_or_else(|| Error::PathDependencyUnsupported(name.clone()))?;
+
+                    let mut project_path = base_path.to_owned();
+                    project_path.push(path);
+                    self.download_path_dependency(project_path).await?;
+                }
+                None => self.download_dependency(name, dependency).await?,
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Downloads the dependencies of a local project.
+    ///
+    #[async_recursion]
+    pub async fn download_path_dependency(&mut self, project_path: PathBuf) -> anyhow::Result<()> {
+        let canonical_path = fs::canonicalize(&project_path)
+            .with_context(|| project_path.to_string_lossy().to_string())?;
+        if !self.paths.insert(canonical_path) {
+            return Ok(());
+        }
+
+        let manifest = zinc_project::Manifest::try_from(&project_path)?;
+        if let Some(dependencies) = manifest.dependencies {
+            self.download_dependency_list(Some(project_path), dependencies)
+                .await?;
         }
 
         Ok(())
@@ -99,8 +156,9 @@ impl<'a> Downloader<'a> {
     pub async fn download_dependency(
         &mut self,
         name: String,
-        version: semver::Version,
+        dependency: zinc_project::ManifestDependency,
     ) -> anyhow::Result<()> {
+        let version = self.resolve(name.as_str(), &dependency).await?;
         if self.downloads.contains(&(name.clone(), version.clone())) {
             return Ok(());
         }
@@ -110,6 +168,13 @@ impl<'a> Downloader<'a> {
         dependency_path.push(zinc_const::directory::TARGET_DEPS);
         dependency_path.push(dependency_name.as_str());
         if dependency_path.exists() {
+            self.downloads.insert((name, version));
+
+            let manifest = zinc_project::Manifest::try_from(&dependency_path)?;
+            if let Some(dependencies) = manifest.dependencies {
+                self.download_dependency_list(None, dependencies).await?;
+            }
+
             return Ok(());
         }
 
@@ -143,9 +208,69 @@ impl<'a> Downloader<'a> {
 
         self.downloads.insert((name, version));
         if let Some(dependencies) = response.project.manifest.dependencies {
-            self.download_dependency_list(dependencies).await?;
+            self.download_dependency_list(None, dependencies).await?;
         }
 
         Ok(())
     }
+
+    ///
+    /// Resolves the dependency version, requesting the Zandbox projects metadata only if there
+    /// is no suitable version locked or downloaded.
+    ///
+    async fn resolve(
+        &mut self,
+        name: &str,
+        dependency: &zinc_project::ManifestDependency,
+    ) -> anyhow::Result<semver::Version> {
+        if let Some(package) = self
+            .lockfile
+            .as_ref()
+            .and_then(|lockfile| lockfile.get(name, dependency))
+        {
+            return Ok(package.version.to_owned());
+        }
+
+        let mut dependencies_path = self.directory.clone();
+        dependencies_path.push(zinc_const::directory::TARGET_DEPS);
+        let prefix = format!("{}-", name);
+        if dependencies_path.exists() {
+            let downloaded = fs::read_dir(&dependencies_path)?
+                .filter_map(|entry| entry.ok())
+                .filter_map(|entry| {
+                    entry
+                        .file_name()
+                        .to_string_lossy()
+                        .strip_prefix(prefix.as_str())
+                        .and_then(|version| semver::Version::parse(version).ok())
+                })
+                .filter(|version| dependency.matches(version))
+                .max();
+            if let Some(version) = downloaded {
+                return Ok(version);
+            }
+        }
+
+        if self.metadata.is_none() {
+            self.metadata = Some(self.client.metadata().await?.projects);
+        }
+
+        self.metadata
+            .as_ref()
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS)
+            .iter()
+            .filter(|project| project.name == name && dependency.matches(&project.version))
+            .map(|project| project.version.to_owned())
+            .max()
+            .ok_or_else(|| {
+                Error::DependencyNotFound(
+                    name.to_owned(),
+                    dependency
+                        .version()
+                        .map(|version| version.to_string())
+                        .unwrap_or_else(|| "*".to_owned()),
+                )
+                .into()
+            })
+    }
 }
@@ -12,8 +12,10 @@ pub(crate) mod transaction;
 
 pub use self::command::build::Command as BuildCommand;
 pub use self::command::call::Command as CallCommand;
+pub use self::command::calldata::Command as CalldataCommand;
 pub use self::command::clean::Command as CleanCommand;
 pub use self::command::download::Command as DownloadCommand;
+pub use self::command::export_verifier::Command as ExportVerifierCommand;
 pub use self::command::init::Command as InitCommand;
 pub use self::command::new::Command as NewCommand;
 pub use self::command::proof_check::Command as ProofCheckCommand;
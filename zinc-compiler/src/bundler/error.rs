@@ -29,4 +29,54 @@ pub enum Error {
         /// The child project type.
         child_type: String,
     },
+    /// The dependency with a matching version has not been found in the dependency directory.
+    #[error("dependency `{name}` with version `{requirement}` has not been downloaded")]
+    DependencyNotFound {
+        /// The dependency name.
+        name: String,
+        /// The dependency version requirement.
+        requirement: String,
+    },
+    /// The dependency project name does not match the name in the dependent manifest.
+    #[error("dependency `{expected}` points to the project `{found}`")]
+    DependencyNameMismatch {
+        /// The dependency name in the dependent manifest.
+        expected: String,
+        /// The dependency project name.
+        found: String,
+    },
+    /// The dependency project version does not satisfy the requirement.
+    #[error(
+        "dependency `{name}` version `{found}` does not match the requirement `{requirement}`"
+    )]
+    DependencyVersionMismatch {
+        /// The dependency name.
+        name: String,
+        /// The dependency version requirement.
+        requirement: String,
+        /// The dependency project version.
+        found: String,
+    },
+    /// A project uploaded to Zandbox depends on a local project.
+    #[error(
+        "project `{parent}` is uploaded to Zandbox and cannot have the path dependency `{name}`"
+    )]
+    PathDependencyInUploaded {
+        /// The uploaded project identifier.
+        parent: String,
+        /// The path dependency name.
+        name: String,
+    },
+    /// The dependency source code does not match the checksum in the lockfile.
+    #[error("project `{project}` source code does not match the checksum in `Zargo.lock`")]
+    ChecksumMismatch {
+        /// The dependency project identifier.
+        project: String,
+    },
+    /// Some warnings have been reported, whose lints are denied with the `-D` option.
+    #[error("could not compile due to {count} denied warning(s)")]
+    WarningsDenied {
+        /// The number of the denied warnings.
+        count: usize,
+    },
 }
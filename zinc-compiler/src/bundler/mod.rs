@@ -8,13 +8,20 @@ pub mod error;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::convert::TryFrom;
+use std::fs;
 use std::path::PathBuf;
 use std::rc::Rc;
 
 use anyhow::Context;
 
+use crate::diagnostic::level::Level as DiagnosticLevel;
+use crate::diagnostic::Diagnostic;
+use crate::generator::zinc_vm::optimizer::level::Level as OptimizationLevel;
 use crate::generator::zinc_vm::State as ZincVMState;
+use crate::semantic::scope::analysis::Analysis;
 use crate::semantic::scope::Scope;
+use crate::semantic::warning::lint::Lint;
+use crate::semantic::warning::Warning;
 use crate::source::Source;
 
 use self::dependency::Dependency;
@@ -23,14 +30,33 @@ use self::error::Error;
 ///
 /// The Zinc compiler bundler.
 ///
+/// Resolves the dependency versions, compiles the dependency tree, and records the resolved
+/// packages to the project lockfile.
+///
+/// The Zandbox dependencies are resolved from the dependency directory, where they are
+/// downloaded by `zargo`. The version locked in the lockfile is preferred, if it satisfies the
+/// requirement and has been downloaded, and the highest matching version is used otherwise.
+/// The path dependencies are compiled right from their project directories.
+///
 pub struct Bundler {
     /// The main project path.
     project_path: PathBuf,
     /// The dependency directory path.
     dependencies_directory_path: PathBuf,
 
-    /// The optimization flag.
-    optimize_dead_function_elimination: bool,
+    /// The bytecode optimization level.
+    optimization_level: OptimizationLevel,
+    /// The lints, whose warnings are not reported.
+    allowed_lints: Vec<Lint>,
+    /// The lints, whose warnings are reported as errors and fail the build.
+    denied_lints: Vec<Lint>,
+    /// The warnings reported during the build.
+    warnings: Vec<Diagnostic>,
+
+    /// The project lockfile read before the build.
+    lockfile: Option<zinc_project::Lockfile>,
+    /// The resolved dependency packages.
+    packages: Vec<zinc_project::LockfilePackage>,
 
     /// The compiled dependency modules cache.
     cache: HashMap<(String, semver::Version), Dependency>,
@@ -52,13 +78,19 @@ impl Bundler {
     pub fn new(
         project_path: PathBuf,
         dependencies_directory_path: PathBuf,
-        optimize_dead_function_elimination: bool,
+        optimization_level: OptimizationLevel,
     ) -> Self {
         Self {
             project_path,
             dependencies_directory_path,
 
-            optimize_dead_function_elimination,
+            optimization_level,
+            allowed_lints: vec![],
+            denied_lints: vec![],
+            warnings: vec![],
+
+            lockfile: None,
+            packages: Vec::with_capacity(Self::DEPENDENCIES_INITIAL_CAPACITY),
 
             cache: HashMap::with_capacity(Self::DEPENDENCIES_INITIAL_CAPACITY),
             node_indexes: HashMap::with_capacity(Self::NODE_INDEXES_INITIAL_CAPACITY),
@@ -66,17 +98,47 @@ impl Bundler {
         }
     }
 
+    ///
+    /// Sets the lints, whose warnings are not reported or are reported as errors.
+    ///
+    /// If a lint is both allowed and denied, the option mentioning it explicitly takes precedence
+    /// over the `warnings` group.
+    ///
+    pub fn with_lints(mut self, allowed_lints: Vec<Lint>, denied_lints: Vec<Lint>) -> Self {
+        self.allowed_lints = allowed_lints;
+        self.denied_lints = denied_lints;
+        self
+    }
+
+    ///
+    /// Returns the warnings reported during the build, including the denied ones.
+    ///
+    pub fn take_warnings(&mut self) -> Vec<Diagnostic> {
+        std::mem::take(&mut self.warnings)
+    }
+
     ///
     /// Compiles the project source code with its entire dependency tree.
     ///
+    /// The lockfile is updated, if the resolved dependencies have changed.
+    ///
+    /// The warnings are saved to be taken by the caller. If some of them are denied, the build
+    /// fails.
+    ///
     pub fn bundle(&mut self) -> anyhow::Result<zinc_types::Build> {
         let manifest = zinc_project::Manifest::try_from(&self.project_path)
             .with_context(|| self.project_path.to_string_lossy().to_string())?;
+        self.lockfile = zinc_project::Lockfile::try_from_project(&self.project_path)?;
 
-        let node_index = self.graph.add_node(manifest.project.clone());
+        let node_index = self.node_index(&manifest.project);
 
         let dependencies = match manifest.dependencies {
-            Some(ref dependencies) => self.compile_list(node_index, &dependencies)?,
+            Some(ref dependencies) => self.compile_list(
+                node_index,
+                &self.project_path.to_owned(),
+                Some(PathBuf::new()),
+                dependencies,
+            )?,
             None => HashMap::new(),
         };
 
@@ -84,66 +146,343 @@ impl Bundler {
         source_directory_path.push(zinc_const::directory::SOURCE);
 
         let source = Source::try_from_entry(&source_directory_path)?;
-        let state = source.compile(manifest, dependencies)?;
-        let application =
-            ZincVMState::unwrap_rc(state).into_application(self.optimize_dead_function_elimination);
+        let (state, warnings) = source.compile(manifest, Self::scopes(dependencies))?;
+        self.report(warnings)?;
+        let application = ZincVMState::unwrap_rc(state).into_application(self.optimization_level);
+
+        self.write_lockfile()?;
 
         Ok(application.into_build())
     }
 
     ///
-    /// Compiles a dependency and stores its scope in the bundler instance cache.
+    /// Analyzes the project source code for the tooling, going on after errors.
+    ///
+    /// The dependencies are compiled as usual, since they are not edited by the developer.
+    /// The lockfile is not written.
+    ///
+    pub fn check(&mut self) -> anyhow::Result<Analysis> {
+        let manifest = zinc_project::Manifest::try_from(&self.project_path)
+            .with_context(|| self.project_path.to_string_lossy().to_string())?;
+        self.lockfile = zinc_project::Lockfile::try_from_project(&self.project_path)?;
+
+        let node_index = self.node_index(&manifest.project);
+
+        let dependencies = match manifest.dependencies {
+            Some(ref dependencies) => self.compile_list(
+                node_index,
+                &self.project_path.to_owned(),
+                Some(PathBuf::new()),
+                dependencies,
+            )?,
+            None => HashMap::new(),
+        };
+
+        let mut source_directory_path = self.project_path.to_owned();
+        source_directory_path.push(zinc_const::directory::SOURCE);
+
+        let source = Source::try_from_entry(&source_directory_path)?;
+        Ok(source.check(manifest.project, Self::scopes(dependencies)))
+    }
+
+    ///
+    /// Compiles a dependency list and stores the dependencies in the bundler instance cache.
+    ///
+    /// `parent_path` is the dependent project directory, which the path dependencies are
+    /// relative to. `parent_location` is the same directory relative to the main project root,
+    /// which is `None` for the projects downloaded from Zandbox.
     ///
     fn compile_list(
         &mut self,
         parent_node_index: petgraph::graph::NodeIndex,
-        dependencies: &HashMap<String, semver::Version>,
-    ) -> anyhow::Result<HashMap<String, Rc<RefCell<Scope>>>> {
+        parent_path: &PathBuf,
+        parent_location: Option<PathBuf>,
+        dependencies: &HashMap<String, zinc_project::ManifestDependency>,
+    ) -> anyhow::Result<HashMap<String, Dependency>> {
         let mut compiled = HashMap::with_capacity(dependencies.len());
 
-        for (name, version) in dependencies.iter() {
-            let scope = match self.cache.get(&(name.clone(), version.clone())) {
+        for (name, dependency) in dependencies.iter() {
+            let (path, location) = match dependency.path() {
+                Some(path) => {
+                    let parent_location = match parent_location {
+                        Some(ref parent_location) => parent_location,
+                        None => {
+                            let parent = &self.graph[parent_node_index];
+                            anyhow::bail!(Error::PathDependencyInUploaded {
+                                parent: format!("{}-{}", parent.name, parent.version),
+                                name: name.to_owned(),
+                            });
+                        }
+                    };
+
+                    let mut location = parent_location.to_owned();
+                    location.push(path);
+                    let mut project_path = parent_path.to_owned();
+                    project_path.push(path);
+
+                    (project_path, Some(location))
+                }
+                None => {
+                    let version = self.resolve(name.as_str(), dependency)?;
+
+                    let mut project_path = self.dependencies_directory_path.to_owned();
+                    project_path.push(format!("{}-{}", name, version));
+
+                    (project_path, None)
+                }
+            };
+
+            let manifest = zinc_project::Manifest::try_from(&path)
+                .with_context(|| path.to_string_lossy().to_string())?;
+            if &manifest.project.name != name {
+                anyhow::bail!(Error::DependencyNameMismatch {
+                    expected: name.to_owned(),
+                    found: manifest.project.name,
+                });
+            }
+            if !dependency.matches(&manifest.project.version) {
+                anyhow::bail!(Error::DependencyVersionMismatch {
+                    name: name.to_owned(),
+                    requirement: Self::requirement(dependency),
+                    found: manifest.project.version.to_string(),
+                });
+            }
+
+            let key = (name.to_owned(), manifest.project.version.to_owned());
+            let dependency = match self.cache.get(&key) {
                 Some(dependency) => {
                     self.graph
                         .add_edge(parent_node_index, dependency.node_index, ());
                     self.check_dependency(parent_node_index, dependency.node_index)?;
 
-                    dependency.scope.to_owned()
+                    dependency.to_owned()
                 }
                 None => {
-                    let mut path = self.dependencies_directory_path.to_owned();
-                    path.push(format!("{}-{}", name, version));
-
-                    let manifest = zinc_project::Manifest::try_from(&path)
-                        .with_context(|| path.to_string_lossy().to_string())?;
-
                     let node_index = self.node_index(&manifest.project);
                     self.graph.add_edge(parent_node_index, node_index, ());
                     self.check_dependency(parent_node_index, node_index)?;
 
                     let dependencies = match manifest.dependencies {
-                        Some(dependencies) => self.compile_list(node_index, &dependencies)?,
+                        Some(ref dependencies) => {
+                            self.compile_list(node_index, &path, location.clone(), dependencies)?
+                        }
                         None => HashMap::new(),
                     };
 
                     let mut source_directory_path = path.clone();
                     source_directory_path.push(zinc_const::directory::SOURCE);
+
+                    let checksum = zinc_project::Source::try_from_path(
+                        &source_directory_path,
+                        &source_directory_path,
+                        true,
+                    )?
+                    .checksum();
+                    self.check_checksum(&manifest.project, location.is_some(), checksum.as_str())?;
+                    self.packages.push(zinc_project::LockfilePackage::new(
+                        manifest.project.name.to_owned(),
+                        manifest.project.version.to_owned(),
+                        location,
+                        checksum,
+                        dependencies
+                            .values()
+                            .map(|dependency| {
+                                format!(
+                                    "{} {}",
+                                    dependency.project.name, dependency.project.version
+                                )
+                            })
+                            .collect(),
+                    ));
+
                     let source = Source::try_from_entry(&source_directory_path)?;
-                    let scope = source.modularize(manifest.project.clone(), dependencies)?;
+                    let scope =
+                        source.modularize(manifest.project.clone(), Self::scopes(dependencies))?;
 
-                    let dependency = Dependency::new(manifest.project, scope.clone(), node_index);
-                    self.cache
-                        .insert((name.to_owned(), version.to_owned()), dependency.clone());
-                    dependency.scope
+                    let dependency = Dependency::new(manifest.project, scope, node_index);
+                    self.cache.insert(key, dependency.clone());
+                    dependency
                 }
             };
 
-            compiled.insert(name.to_owned(), scope);
+            compiled.insert(name.to_owned(), dependency);
         }
 
         Ok(compiled)
     }
 
+    ///
+    /// Resolves the version of the Zandbox dependency named `name` among the downloaded ones.
+    ///
+    /// The locked version is preferred, and the highest matching version is chosen otherwise.
+    ///
+    fn resolve(
+        &self,
+        name: &str,
+        dependency: &zinc_project::ManifestDependency,
+    ) -> anyhow::Result<semver::Version> {
+        let prefix = format!("{}-", name);
+
+        let mut versions = Vec::new();
+        if self.dependencies_directory_path.exists() {
+            for entry in fs::read_dir(&self.dependencies_directory_path).with_context(|| {
+                self.dependencies_directory_path
+                    .to_string_lossy()
+                    .to_string()
+            })? {
+                let entry = entry.with_context(|| {
+                    self.dependencies_directory_path
+                        .to_string_lossy()
+                        .to_string()
+                })?;
+
+                let file_name = entry.file_name().to_string_lossy().to_string();
+                let version = match file_name
+                    .strip_prefix(prefix.as_str())
+                    .and_then(|version| semver::Version::parse(version).ok())
+                {
+                    Some(version) => version,
+                    None => continue,
+                };
+
+                if dependency.matches(&version) {
+                    versions.push(version);
+                }
+            }
+        }
+
+        let locked = self
+            .lockfile
+            .as_ref()
+            .and_then(|lockfile| lockfile.get(name, dependency))
+            .map(|package| package.version.to_owned())
+            .filter(|version| versions.contains(version));
+
+        locked
+            .or_else(|| versions.into_iter().max())
+            .ok_or_else(|| {
+                Error::DependencyNotFound {
+                    name: name.to_owned(),
+                    requirement: Self::requirement(dependency),
+                }
+                .into()
+            })
+    }
+
+    ///
+    /// Checks the dependency source code checksum against the locked one.
+    ///
+    /// The path dependencies are edited along with the main project, so their checksums are
+    /// only updated in the lockfile.
+    ///
+    fn check_checksum(
+        &self,
+        project: &zinc_project::ManifestProject,
+        is_path: bool,
+        checksum: &str,
+    ) -> anyhow::Result<()> {
+        if is_path {
+            return Ok(());
+        }
+
+        let locked = self
+            .lockfile
+            .as_ref()
+            .and_then(|lockfile| lockfile.find(project.name.as_str(), &project.version));
+        match locked {
+            Some(package) if package.path.is_none() && package.checksum != checksum => {
+                anyhow::bail!(Error::ChecksumMismatch {
+                    project: format!("{}-{}", project.name, project.version),
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    ///
+    /// Writes the resolved packages to the project lockfile, if they have changed.
+    ///
+    fn write_lockfile(&mut self) -> anyhow::Result<()> {
+        let lockfile = zinc_project::Lockfile::new(self.packages.drain(..).collect());
+
+        let is_changed = match self.lockfile {
+            Some(ref old) => old != &lockfile,
+            None => !lockfile.packages.is_empty(),
+        };
+        if is_changed {
+            lockfile.write_to(&self.project_path)?;
+        }
+
+        self.lockfile = Some(lockfile);
+
+        Ok(())
+    }
+
+    ///
+    /// Converts the `warnings` into diagnostics, skipping the allowed ones.
+    ///
+    /// Returns an error if some of the warnings are denied.
+    ///
+    fn report(&mut self, warnings: Vec<Warning>) -> anyhow::Result<()> {
+        let mut denied_count = 0;
+        for warning in warnings.into_iter() {
+            let level = match self.lint_level(warning.lint()) {
+                Some(level) => level,
+                None => continue,
+            };
+            if level == DiagnosticLevel::Error {
+                denied_count += 1;
+            }
+
+            self.warnings.push(warning.diagnostic(level));
+        }
+
+        if denied_count > 0 {
+            anyhow::bail!(Error::WarningsDenied {
+                count: denied_count
+            });
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Returns the diagnostic level of the `lint` warnings, or `None` if the lint is allowed.
+    ///
+    fn lint_level(&self, lint: Lint) -> Option<DiagnosticLevel> {
+        if self.denied_lints.contains(&lint) {
+            Some(DiagnosticLevel::Error)
+        } else if self.allowed_lints.contains(&lint) {
+            None
+        } else if self.denied_lints.contains(&Lint::Warnings) {
+            Some(DiagnosticLevel::Error)
+        } else if self.allowed_lints.contains(&Lint::Warnings) {
+            None
+        } else {
+            Some(DiagnosticLevel::Warning)
+        }
+    }
+
+    ///
+    /// Extracts the scopes from the compiled dependencies.
+    ///
+    fn scopes(dependencies: HashMap<String, Dependency>) -> HashMap<String, Rc<RefCell<Scope>>> {
+        dependencies
+            .into_iter()
+            .map(|(name, dependency)| (name, dependency.scope))
+            .collect()
+    }
+
+    ///
+    /// Returns the dependency version requirement string for the error messages.
+    ///
+    fn requirement(dependency: &zinc_project::ManifestDependency) -> String {
+        dependency
+            .version()
+            .map(|version| version.to_string())
+            .unwrap_or_else(|| "*".to_owned())
+    }
+
     ///
     /// Checks the dependencies for validity:
     ///
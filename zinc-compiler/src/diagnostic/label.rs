@@ -0,0 +1,167 @@
+//!
+//! The compiler diagnostic label.
+//!
+
+use std::fmt;
+
+use colored::Colorize;
+use serde::Serialize;
+
+use zinc_lexical::FILE_INDEX;
+
+use crate::diagnostic::level::Level;
+use crate::diagnostic::span::Span;
+
+///
+/// The compiler diagnostic label, which points to a span of the source code.
+///
+/// The lines and columns start from 1. The end column is exclusive.
+///
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Label {
+    /// The source code file path.
+    pub file: String,
+    /// The span start line.
+    pub line_start: usize,
+    /// The span start column.
+    pub column_start: usize,
+    /// The span end line.
+    pub line_end: usize,
+    /// The span end column.
+    pub column_end: usize,
+    /// Whether the label points to the error itself rather than to a related place.
+    pub is_primary: bool,
+    /// The label message, e.g. `first declared here`.
+    pub message: Option<String>,
+    /// The spanned source code lines, which are rendered in the human-readable format.
+    #[serde(skip)]
+    pub lines: Vec<String>,
+}
+
+impl Label {
+    ///
+    /// Creates a label pointing to the error itself.
+    ///
+    pub fn primary(span: Span, message: Option<String>) -> Self {
+        Self::new(span, true, message)
+    }
+
+    ///
+    /// Creates a label pointing to a related place.
+    ///
+    pub fn secondary(span: Span, message: Option<String>) -> Self {
+        Self::new(span, false, message)
+    }
+
+    ///
+    /// Resolves the `span` file path and source code lines from the global file index.
+    ///
+    fn new(span: Span, is_primary: bool, message: Option<String>) -> Self {
+        let (file, lines) = if span.start.line == 0 {
+            (span.start.to_string(), vec![])
+        } else {
+            let index = FILE_INDEX
+                .inner
+                .read()
+                .expect(zinc_const::panic::SYNCHRONIZATION);
+            match index.get(&span.start.file) {
+                Some(data) => (
+                    data.path.to_string_lossy().to_string(),
+                    data.code
+                        .lines()
+                        .skip(span.start.line - 1)
+                        .take(span.end.line.saturating_sub(span.start.line) + 1)
+                        .map(|line| line.to_owned())
+                        .collect(),
+                ),
+                None => (span.start.to_string(), vec![]),
+            }
+        };
+
+        Self {
+            file,
+            line_start: span.start.line,
+            column_start: span.start.column,
+            line_end: span.end.line,
+            column_end: span.end.column,
+            is_primary,
+            message,
+            lines,
+        }
+    }
+
+    ///
+    /// Renders the label with the source code snippet and underline markers.
+    ///
+    /// The primary labels are underlined with carets, and the secondary ones with dashes.
+    ///
+    pub fn render(
+        &self,
+        f: &mut fmt::Formatter<'_>,
+        level: Level,
+        gutter_width: usize,
+    ) -> fmt::Result {
+        let gutter = " ".repeat(gutter_width);
+        let arrow = if self.is_primary { "-->" } else { ":::" };
+
+        if self.line_start == 0 {
+            return writeln!(f, "{} {} {}", gutter, arrow.bright_cyan(), self.file);
+        }
+
+        writeln!(
+            f,
+            "{} {} {}:{}:{}",
+            gutter,
+            arrow.bright_cyan(),
+            self.file,
+            self.line_start,
+            self.column_start
+        )?;
+        writeln!(f, "{} {}", gutter, "|".bright_cyan())?;
+        for (index, line) in self.lines.iter().enumerate() {
+            writeln!(
+                f,
+                "{} {} {}",
+                format!("{:>width$}", self.line_start + index, width = gutter_width).bright_cyan(),
+                "|".bright_cyan(),
+                line,
+            )?;
+        }
+
+        let marker = if self.is_primary { "^" } else { "-" };
+        let underline = if self.line_start == self.line_end {
+            format!(
+                "{}{}",
+                " ".repeat(self.column_start.saturating_sub(1)),
+                marker.repeat(self.column_end.saturating_sub(self.column_start).max(1))
+            )
+        } else {
+            format!(
+                "{}{}",
+                "_".repeat(self.column_end.saturating_sub(2)),
+                marker
+            )
+        };
+        let underline = if self.is_primary {
+            level.colorize(underline.as_str())
+        } else {
+            underline.bright_blue()
+        };
+
+        match self.message {
+            Some(ref message) => writeln!(
+                f,
+                "{} {} {} {}",
+                gutter,
+                "|".bright_cyan(),
+                underline,
+                if self.is_primary {
+                    level.colorize(message.as_str())
+                } else {
+                    message.bright_blue()
+                }
+            ),
+            None => writeln!(f, "{} {} {}", gutter, "|".bright_cyan(), underline),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+//!
+//! The compiler diagnostic level.
+//!
+
+use std::fmt;
+
+use colored::ColoredString;
+use colored::Colorize;
+use serde::Serialize;
+
+///
+/// The compiler diagnostic level.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Level {
+    /// The compilation cannot be continued.
+    Error,
+    /// The code is likely to be a mistake, but the compilation can be continued.
+    Warning,
+}
+
+impl Level {
+    ///
+    /// Paints the `text` with the level color.
+    ///
+    pub fn colorize(self, text: &str) -> ColoredString {
+        match self {
+            Self::Error => text.bright_red(),
+            Self::Warning => text.bright_yellow(),
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Error => write!(f, "error"),
+            Self::Warning => write!(f, "warning"),
+        }
+    }
+}
@@ -0,0 +1,32 @@
+//!
+//! The compiler diagnostic message format.
+//!
+
+use std::str::FromStr;
+
+///
+/// The compiler diagnostic message format.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub enum MessageFormat {
+    /// The human-readable text with the source code snippets.
+    #[default]
+    Human,
+    /// The JSON objects, one per line.
+    Json,
+}
+
+impl FromStr for MessageFormat {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "human" => Ok(Self::Human),
+            "json" => Ok(Self::Json),
+            another => Err(format!(
+                "invalid message format `{}`, expected `human` or `json`",
+                another
+            )),
+        }
+    }
+}
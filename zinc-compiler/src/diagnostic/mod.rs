@@ -0,0 +1,184 @@
+//!
+//! The compiler diagnostic.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod label;
+pub mod level;
+pub mod message_format;
+pub mod span;
+
+use std::fmt;
+
+use colored::Colorize;
+use serde::Serialize;
+
+use zinc_lexical::Location;
+
+use crate::source::error::Error as SourceError;
+
+use self::label::Label;
+use self::level::Level;
+use self::span::Span;
+
+///
+/// The compiler diagnostic, which is rendered either as the human-readable text with the source
+/// code snippets, or as JSON for the editors and CI tools.
+///
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Diagnostic {
+    /// The diagnostic severity.
+    pub level: Level,
+    /// The error code, if it is available.
+    pub code: Option<usize>,
+    /// The diagnostic message.
+    pub message: String,
+    /// The source code labels. The first one is the primary label.
+    pub labels: Vec<Label>,
+    /// The help note, which suggests how to fix the issue.
+    pub help: Option<String>,
+}
+
+impl Diagnostic {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(level: Level, code: Option<usize>, message: String) -> Self {
+        Self {
+            level,
+            code,
+            message,
+            labels: Vec::with_capacity(2),
+            help: None,
+        }
+    }
+
+    ///
+    /// Creates an error pointing to the token at `location`.
+    ///
+    pub fn line(
+        message: &str,
+        code: Option<usize>,
+        location: Location,
+        help: Option<&str>,
+    ) -> Self {
+        Self::new(Level::Error, code, message.to_owned())
+            .with_label(Label::primary(Span::token(location), None))
+            .with_help(help)
+    }
+
+    ///
+    /// Creates an error with a secondary `reference` label, which helps the user to fix the
+    /// error more easily.
+    ///
+    pub fn with_reference(
+        message: &str,
+        code: Option<usize>,
+        location: Location,
+        reference: Option<Location>,
+        reference_message: &str,
+        help: Option<&str>,
+    ) -> Self {
+        let mut diagnostic = Self::line(message, code, location, help);
+        if let Some(reference) = reference {
+            diagnostic = diagnostic.with_label(Label::secondary(
+                Span::token(reference),
+                Some(reference_message.to_owned()),
+            ));
+        }
+        diagnostic
+    }
+
+    ///
+    /// Creates an error enclosing the source code between `start` and `end`.
+    ///
+    pub fn range(
+        message: &str,
+        code: Option<usize>,
+        start: Location,
+        end: Location,
+        help: Option<&str>,
+    ) -> Self {
+        Self::new(Level::Error, code, message.to_owned())
+            .with_label(Label::primary(Span::new(start, end), None))
+            .with_help(help)
+    }
+
+    ///
+    /// Appends a source code label.
+    ///
+    pub fn with_label(mut self, label: Label) -> Self {
+        self.labels.push(label);
+        self
+    }
+
+    ///
+    /// Sets the help note.
+    ///
+    pub fn with_help(mut self, help: Option<&str>) -> Self {
+        self.help = help.map(|help| help.to_owned());
+        self
+    }
+}
+
+impl From<&anyhow::Error> for Diagnostic {
+    ///
+    /// Extracts the compiler diagnostic from the `error`.
+    ///
+    /// The errors, which are not related to the source code, are wrapped into a diagnostic without
+    /// labels, so the consumers always get the same structure.
+    ///
+    fn from(error: &anyhow::Error) -> Self {
+        let diagnostic =
+            error
+                .chain()
+                .find_map(|error| match error.downcast_ref::<SourceError>() {
+                    Some(SourceError::Compiling(diagnostic)) => Some(diagnostic.to_owned()),
+                    _ => None,
+                });
+
+        diagnostic.unwrap_or_else(|| Self::new(Level::Error, None, format!("{:#}", error)))
+    }
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gutter_width = self
+            .labels
+            .iter()
+            .map(|label| label.line_end.to_string().len())
+            .max()
+            .unwrap_or_default();
+
+        let header = match self.code {
+            Some(code) => format!("{}[{:04}]", self.level, code),
+            None => self.level.to_string(),
+        };
+        writeln!(f)?;
+        writeln!(
+            f,
+            "{}: {}",
+            self.level.colorize(header.as_str()),
+            self.message.bright_white()
+        )?;
+
+        for label in self.labels.iter() {
+            label.render(f, self.level, gutter_width)?;
+        }
+
+        if let Some(ref help) = self.help {
+            writeln!(
+                f,
+                "{} {} {}: {}",
+                " ".repeat(gutter_width),
+                "=".bright_cyan(),
+                "help".bright_white(),
+                help.bright_blue()
+            )?;
+        }
+
+        Ok(())
+    }
+}
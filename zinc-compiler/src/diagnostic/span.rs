@@ -0,0 +1,86 @@
+//!
+//! The compiler diagnostic span.
+//!
+
+use zinc_lexical::Location;
+use zinc_lexical::FILE_INDEX;
+
+///
+/// The source code span, which is enclosed by its start and end locations.
+///
+/// The end location is exclusive, that is, it points to the character after the span.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Span {
+    /// The span start location.
+    pub start: Location,
+    /// The span end location.
+    pub end: Location,
+}
+
+impl Span {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(start: Location, end: Location) -> Self {
+        Self { start, end }
+    }
+
+    ///
+    /// Creates a span covering the token starting at `location`.
+    ///
+    /// The syntax tree only stores the start locations, so the token end is found by scanning
+    /// the source code line. Identifiers, literals and string literals are covered entirely,
+    /// whereas other tokens are covered by their first character.
+    ///
+    pub fn token(location: Location) -> Self {
+        if location.line == 0 {
+            return Self::new(location, location);
+        }
+
+        let length = FILE_INDEX
+            .inner
+            .read()
+            .expect(zinc_const::panic::SYNCHRONIZATION)
+            .get(&location.file)
+            .and_then(|data| {
+                data.code
+                    .lines()
+                    .nth(location.line - 1)
+                    .map(|line| Self::token_length(line, location.column))
+            })
+            .unwrap_or(1);
+
+        Self::new(location, location.shifted_right(length))
+    }
+
+    ///
+    /// Returns the length of the token starting at `column` in the source code `line`.
+    ///
+    fn token_length(line: &str, column: usize) -> usize {
+        let mut characters = line.chars().skip(column.saturating_sub(1));
+
+        match characters.next() {
+            Some(character) if Self::is_word_character(character) => {
+                1 + characters
+                    .take_while(|character| Self::is_word_character(*character))
+                    .count()
+            }
+            Some('"') => {
+                let rest: Vec<char> = characters.collect();
+                match rest.iter().position(|character| *character == '"') {
+                    Some(position) => position + 2,
+                    None => rest.len() + 1,
+                }
+            }
+            _ => 1,
+        }
+    }
+
+    ///
+    /// Checks whether the character may be a part of an identifier or literal.
+    ///
+    fn is_word_character(character: char) -> bool {
+        character.is_alphanumeric() || character == '_'
+    }
+}
@@ -0,0 +1,104 @@
+//!
+//! The compiler diagnostic tests.
+//!
+
+use crate::diagnostic::level::Level;
+
+#[test]
+fn ok_labels_primary_and_secondary() {
+    let input = r#"
+fn main() {
+    let result = 42;
+    let result = 64;
+}
+"#;
+
+    let diagnostic = crate::semantic::tests::compile_entry(input)
+        .expect_err(zinc_const::panic::TEST_DATA_VALID)
+        .diagnostic();
+
+    assert_eq!(diagnostic.level, Level::Error);
+    assert_eq!(diagnostic.labels.len(), 2);
+
+    let primary = &diagnostic.labels[0];
+    assert!(primary.is_primary);
+    assert_eq!(
+        (
+            primary.line_start,
+            primary.column_start,
+            primary.line_end,
+            primary.column_end
+        ),
+        (4, 9, 4, 15)
+    );
+    assert_eq!(primary.lines, vec!["    let result = 64;".to_owned()]);
+
+    let secondary = &diagnostic.labels[1];
+    assert!(!secondary.is_primary);
+    assert_eq!((secondary.line_start, secondary.column_start), (3, 9));
+    assert_eq!(secondary.message.as_deref(), Some("first declared here"));
+}
+
+#[test]
+fn ok_span_string_literal() {
+    let input = r#"
+fn main() {
+    let value: u8 = "text";
+}
+"#;
+
+    let diagnostic = crate::semantic::tests::compile_entry(input)
+        .expect_err(zinc_const::panic::TEST_DATA_VALID)
+        .diagnostic();
+
+    let primary = &diagnostic.labels[0];
+    assert_eq!((primary.column_start, primary.column_end), (21, 27));
+}
+
+#[test]
+fn ok_render_human() {
+    colored::control::set_override(false);
+
+    let input = r#"
+fn main() {
+    let value: u8 = true;
+}
+"#;
+
+    let rendered = crate::semantic::tests::compile_entry(input)
+        .expect_err(zinc_const::panic::TEST_DATA_VALID)
+        .format();
+
+    assert!(
+        rendered.contains("3 |     let value: u8 = true;"),
+        "{}",
+        rendered
+    );
+    assert!(
+        rendered.contains("  |                     ^^^^"),
+        "{}",
+        rendered
+    );
+}
+
+#[test]
+fn ok_render_json() {
+    let input = r#"
+fn main() {
+    let value: u8 = true;
+}
+"#;
+
+    let diagnostic = crate::semantic::tests::compile_entry(input)
+        .expect_err(zinc_const::panic::TEST_DATA_VALID)
+        .diagnostic();
+
+    let json = serde_json::to_value(&diagnostic).expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(json["level"], "error");
+    assert_eq!(json["labels"][0]["line_start"], 3);
+    assert_eq!(json["labels"][0]["column_start"], 21);
+    assert_eq!(json["labels"][0]["column_end"], 25);
+    assert_eq!(json["labels"][0]["is_primary"], true);
+    assert!(json["labels"][0].get("lines").is_none());
+}
@@ -2,14 +2,11 @@
 //! The Zinc compiler error.
 //!
 
-use colored::Colorize;
-
 use zinc_lexical::Error as LexicalError;
-use zinc_lexical::Location;
-use zinc_lexical::FILE_INDEX;
 use zinc_syntax::Error as SyntaxError;
 use zinc_syntax::ParsingError;
 
+use crate::diagnostic::Diagnostic;
 use crate::semantic::casting::error::Error as CastingError;
 use crate::semantic::error::Error as SemanticError;
 
@@ -31,14 +28,21 @@ impl Error {
     /// Formats the compiler error into the user-friendly readable output.
     ///
     pub fn format(self) -> String {
+        self.diagnostic().to_string()
+    }
+
+    ///
+    /// Converts the compiler error into a diagnostic with the source code labels.
+    ///
+    pub fn diagnostic(self) -> Diagnostic {
         let code = self.code();
 
         match self {
             Self::Lexical(LexicalError::UnterminatedBlockComment { start, end }) => {
-                Self::format_range("unterminated block comment", code,start, end, None)
+                Diagnostic::range("unterminated block comment", code,start, end, None)
             }
             Self::Lexical(LexicalError::UnterminatedDoubleQuoteString { start, end }) => {
-                Self::format_range(
+                Diagnostic::range(
                     "unterminated double quote string",
                     code,
                     start,
@@ -50,7 +54,7 @@ impl Error {
                               location,
                               expected,
                               found,
-                          }) => Self::format_line( format!(
+                          }) => Diagnostic::line( format!(
                     "expected one of binary symbols {} or '_', found `{}`",
                     expected, found
                 )
@@ -62,7 +66,7 @@ impl Error {
                               location,
                               expected,
                               found,
-                          }) => Self::format_line( format!(
+                          }) => Diagnostic::line( format!(
                     "expected one of octal symbols {} or '_', found `{}`",
                     expected, found
                 )
@@ -74,7 +78,7 @@ impl Error {
                 location,
                 expected,
                 found,
-            }) => Self::format_line( format!(
+            }) => Diagnostic::line( format!(
                     "expected one of decimal symbols {} or '_', found `{}`",
                     expected, found
                 )
@@ -86,7 +90,7 @@ impl Error {
                 location,
                 expected,
                 found,
-            }) => Self::format_line( format!(
+            }) => Diagnostic::line( format!(
                     "expected one of hexadecimal symbols {} or '_', found `{}`",
                     expected, found
                 )
@@ -94,19 +98,44 @@ impl Error {
                                      code,location,
                 None,
             ),
-            Self::Lexical(LexicalError::InvalidCharacter { location, found }) => Self::format_line( format!("invalid character `{}`", found).as_str(),
+            Self::Lexical(LexicalError::ExpectedAsciiCharacter { location, found }) => {
+                Diagnostic::line(
+                    format!("expected an ASCII character in the byte string, found `{}`", found)
+                        .as_str(),
+                    code,
+                    location,
+                    Some("use a `hex\"...\"` literal for non-ASCII bytes"),
+                )
+            }
+            Self::Lexical(LexicalError::UnknownCharacterEscape { location, found }) => {
+                Diagnostic::line(
+                    format!("unknown character escape `\\{}` in the byte string", found).as_str(),
+                    code,
+                    location,
+                    Some("the supported escapes are `\\n`, `\\r`, `\\t`, `\\0`, `\\\\`, `\\\"` and `\\xNN`"),
+                )
+            }
+            Self::Lexical(LexicalError::UnpairedHexadecimalDigit { location }) => {
+                Diagnostic::line(
+                    "unpaired hexadecimal digit",
+                    code,
+                    location,
+                    Some("each byte must be written with exactly two hexadecimal digits"),
+                )
+            }
+            Self::Lexical(LexicalError::InvalidCharacter { location, found }) => Diagnostic::line( format!("invalid character `{}`", found).as_str(),
                                                                                                     code,location,
                 None,
             ),
             Self::Lexical(LexicalError::UnexpectedEnd { location }) => {
-                Self::format_line( "unexpected end of input", code, location, None)
+                Diagnostic::line( "unexpected end of input", code, location, None)
             }
             Self::Syntax(SyntaxError::ExpectedOneOf {
                 location,
                 expected,
                 found,
                 help,
-            }) => Self::format_line( format!("expected one of {}, found `{}`", expected, found).as_str(),
+            }) => Diagnostic::line( format!("expected one of {}, found `{}`", expected, found).as_str(),
                 code,location,
                 help,
             ),
@@ -115,7 +144,7 @@ impl Error {
                 expected,
                 found,
                 help,
-            }) => Self::format_line( format!(
+            }) => Diagnostic::line( format!(
                     "expected one of {} or an operator, found `{}`",
                     expected, found
                 )
@@ -127,7 +156,7 @@ impl Error {
                 location,
                 found,
                 help,
-            }) => Self::format_line( format!("expected identifier, found `{}`", found).as_str(),
+            }) => Diagnostic::line( format!("expected identifier, found `{}`", found).as_str(),
                 code,location,
                 help,
             ),
@@ -135,7 +164,7 @@ impl Error {
                 location,
                 found,
                 help,
-            }) => Self::format_line( format!("expected keyword `mut` or identifier, found `{}`", found).as_str(),
+            }) => Diagnostic::line( format!("expected keyword `mut` or identifier, found `{}`", found).as_str(),
                 code,location,
                 help,
             ),
@@ -143,7 +172,7 @@ impl Error {
                 location,
                 found,
                 help,
-            }) => Self::format_line( format!("expected field identifier, found `{}`", found).as_str(),
+            }) => Diagnostic::line( format!("expected field identifier, found `{}`", found).as_str(),
                 code,location,
                 help,
             ),
@@ -151,7 +180,7 @@ impl Error {
                 location,
                 found,
                 help,
-            }) => Self::format_line( format!("expected type, found `{}`", found).as_str(),
+            }) => Diagnostic::line( format!("expected type, found `{}`", found).as_str(),
                 code,location,
                 help,
             ),
@@ -159,7 +188,7 @@ impl Error {
                 location,
                 found,
                 help,
-            }) => Self::format_line( format!(
+            }) => Diagnostic::line( format!(
                     "expected `:` with type or `=` with value, found `{}`",
                     found
                 )
@@ -171,36 +200,36 @@ impl Error {
                 location,
                 found,
                 help,
-            }) => Self::format_line( format!("expected `=` with value, found `{}`", found).as_str(),
+            }) => Diagnostic::line( format!("expected `=` with value, found `{}`", found).as_str(),
                 code,location,
                 help,
             ),
             Self::Syntax(SyntaxError::ExpectedExpressionOrOperand { location, found }) => {
-                Self::format_line( format!("expected expression or operand, found `{}`", found).as_str(),
+                Diagnostic::line( format!("expected expression or operand, found `{}`", found).as_str(),
                     code,location,
                 None,
                 )
             }
             Self::Syntax(SyntaxError::ExpectedLiteral { location, found }) => {
-                Self::format_line( format!("expected literal, found `{}`", found).as_str(),
+                Diagnostic::line( format!("expected literal, found `{}`", found).as_str(),
                                    code,location,
                                    None,
                 )
             }
             Self::Syntax(SyntaxError::ExpectedIntegerLiteral { location, found }) => {
-                Self::format_line( format!("expected integer literal, found `{}`", found).as_str(),
+                Diagnostic::line( format!("expected integer literal, found `{}`", found).as_str(),
                     code,location,
                 None,
                 )
             }
             Self::Syntax(SyntaxError::ExpectedBindingPattern { location, found }) => {
-                Self::format_line( format!("expected identifier or `_`, found `{}`", found).as_str(),
+                Diagnostic::line( format!("expected identifier or `_`, found `{}`", found).as_str(),
                     code,location,
                 None,
                 )
             }
             Self::Syntax(SyntaxError::ExpectedMatchPattern { location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "expected identifier, boolean or integer literal, path, or `_`, found `{}`",
                         found
                     )
@@ -211,32 +240,32 @@ impl Error {
             }
 
             Self::Semantic(SemanticError::InvalidInteger { location, inner: zinc_math::Error::NumberParsing(inner) }) => {
-                Self::format_line(format!("The number parsing error: {}", inner).as_str(),
+                Diagnostic::line(format!("The number parsing error: {}", inner).as_str(),
                                   code,location,
                 None,
                 )
             }
             Self::Semantic(SemanticError::InvalidInteger { location, inner: zinc_math::Error::ExponentParsing(inner) }) => {
-                Self::format_line(format!("The exponent value parsing error: {}", inner).as_str(),
+                Diagnostic::line(format!("The exponent value parsing error: {}", inner).as_str(),
                                   code,location,
                 None,
                 )
             }
             Self::Semantic(SemanticError::InvalidInteger { location, inner: zinc_math::Error::Overflow { value, is_signed, bitlength } }) => {
-                Self::format_line( format!("`{}` is larger than `{}` bits with sign `{}`", value, bitlength, is_signed).as_str(),
+                Diagnostic::line( format!("`{}` is larger than `{}` bits with sign `{}`", value, bitlength, is_signed).as_str(),
                                    code,location,
                 None,
                 )
             }
             Self::Semantic(SemanticError::InvalidInteger { location, inner: zinc_math::Error::ExponentTooSmall(exponent) }) => {
-                Self::format_line(format!("The exponent value `{}` is too small", exponent).as_str(),
+                Diagnostic::line(format!("The exponent value `{}` is too small", exponent).as_str(),
                                   code, location,
                                    Some("the exponent value must be equal or greater than the number of fractional digits"),
                 )
             }
 
             Self::Semantic(SemanticError::OperatorAssignmentFirstOperandExpectedPlace{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the assignment operator `=` expected a memory place as the first operand, found `{}`",
                         found,
                     )
@@ -246,7 +275,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorAssignmentSecondOperandExpectedEvaluable{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the assignment operator `=` expected a value as the second operand, found `{}`",
                         found,
                     )
@@ -256,7 +285,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorAssignmentBitwiseOrFirstOperandExpectedPlace{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the assignment bitwise OR operator `|=` expected a memory place as the first operand, found `{}`",
                         found,
                     )
@@ -266,7 +295,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorAssignmentBitwiseOrSecondOperandExpectedEvaluable{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the assignment bitwise OR operator `|=` expected a value as the second operand, found `{}`",
                         found,
                     )
@@ -276,7 +305,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorAssignmentBitwiseXorFirstOperandExpectedPlace{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the assignment bitwise XOR operator `^=` expected a memory place as the first operand, found `{}`",
                         found,
                     )
@@ -286,7 +315,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorAssignmentBitwiseXorSecondOperandExpectedEvaluable{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the assignment bitwise XOR operator `^=` expected a value as the second operand, found `{}`",
                         found,
                     )
@@ -296,7 +325,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorAssignmentBitwiseAndFirstOperandExpectedPlace{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the assignment bitwise AND operator `&=` expected a memory place as the first operand, found `{}`",
                         found,
                     )
@@ -306,7 +335,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorAssignmentBitwiseAndSecondOperandExpectedEvaluable{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the assignment bitwise AND operator `&=` expected a value as the second operand, found `{}`",
                         found,
                     )
@@ -316,7 +345,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorAssignmentBitwiseShiftLeftFirstOperandExpectedPlace{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the assignment bitwise shift left operator `<<=` expected a memory place as the first operand, found `{}`",
                         found,
                     )
@@ -326,7 +355,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorAssignmentBitwiseShiftLeftSecondOperandExpectedEvaluable{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the assignment bitwise shift left operator `<<=` expected a value as the second operand, found `{}`",
                         found,
                     )
@@ -336,7 +365,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorAssignmentBitwiseShiftRightFirstOperandExpectedPlace{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the assignment bitwise shift right operator `>>=` expected a memory place as the first operand, found `{}`",
                         found,
                     )
@@ -346,7 +375,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorAssignmentBitwiseShiftRightSecondOperandExpectedEvaluable{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the assignment bitwise shift right operator `>>=` expected a value as the second operand, found `{}`",
                         found,
                     )
@@ -356,7 +385,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorAssignmentAdditionFirstOperandExpectedPlace{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the assignment operator `+=` expected a memory place as the first operand, found `{}`",
                         found,
                     )
@@ -366,7 +395,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorAssignmentAdditionSecondOperandExpectedEvaluable{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the assignment operator `+=` expected a value as the second operand, found `{}`",
                         found,
                     )
@@ -376,7 +405,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorAssignmentSubtractionFirstOperandExpectedPlace{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the assignment operator `-=` expected a memory place as the first operand, found `{}`",
                         found,
                     )
@@ -386,7 +415,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorAssignmentSubtractionSecondOperandExpectedEvaluable{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the assignment operator `-=` expected a value as the second operand, found `{}`",
                         found,
                     )
@@ -396,7 +425,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorAssignmentMultiplicationFirstOperandExpectedPlace{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the assignment operator `*=` expected a memory place as the first operand, found `{}`",
                         found,
                     )
@@ -406,7 +435,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorAssignmentMultiplicationSecondOperandExpectedEvaluable{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the assignment operator `*=` expected a value as the second operand, found `{}`",
                         found,
                     )
@@ -416,7 +445,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorAssignmentDivisionFirstOperandExpectedPlace{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the assignment operator `/=` expected a memory place as the first operand, found `{}`",
                         found,
                     )
@@ -426,7 +455,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorAssignmentDivisionSecondOperandExpectedEvaluable{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the assignment operator `/=` expected a value as the second operand, found `{}`",
                         found,
                     )
@@ -436,7 +465,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorAssignmentRemainderFirstOperandExpectedPlace{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the assignment operator `%=` expected a memory place as the first operand, found `{}`",
                         found,
                     )
@@ -446,7 +475,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorAssignmentRemainderSecondOperandExpectedEvaluable{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the assignment operator `%=` expected a value as the second operand, found `{}`",
                         found,
                     )
@@ -457,7 +486,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorRangeInclusiveFirstOperandExpectedConstant{ location, found }) |
             Self::Semantic(SemanticError::OperatorRangeInclusiveFirstOperandExpectedInteger{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the inclusive range operator `..=` expected an integer constant as the first operand, found `{}`",
                         found,
                     )
@@ -468,7 +497,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorRangeInclusiveSecondOperandExpectedConstant{ location, found }) |
             Self::Semantic(SemanticError::OperatorRangeInclusiveSecondOperandExpectedInteger{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the inclusive range operator `..=` expected an integer constant as the second operand, found `{}`",
                         found,
                     )
@@ -479,7 +508,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorRangeFirstOperandExpectedConstant{ location, found }) |
             Self::Semantic(SemanticError::OperatorRangeFirstOperandExpectedInteger{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the range operator `..` expected an integer constant as the first operand, found `{}`",
                         found,
                     )
@@ -490,7 +519,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorRangeSecondOperandExpectedConstant{ location, found }) |
             Self::Semantic(SemanticError::OperatorRangeSecondOperandExpectedInteger{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the range operator `..` expected an integer constant as the second operand, found `{}`",
                         found,
                     )
@@ -501,7 +530,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorOrFirstOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorOrFirstOperandExpectedBoolean{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the OR operator `||` expected a boolean as the first operand, found `{}`",
                         found,
                     )
@@ -512,7 +541,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorOrSecondOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorOrSecondOperandExpectedBoolean{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the OR operator `||` expected a boolean as the second operand, found `{}`",
                         found,
                     )
@@ -523,7 +552,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorXorFirstOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorXorFirstOperandExpectedBoolean{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the XOR operator `^^` expected a boolean as the first operand, found `{}`",
                         found,
                     )
@@ -534,7 +563,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorXorSecondOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorXorSecondOperandExpectedBoolean{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the XOR operator `^^` expected a boolean as the second operand, found `{}`",
                         found,
                     )
@@ -545,7 +574,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorAndFirstOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorAndFirstOperandExpectedBoolean{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the AND operator `&&` expected a boolean as the first operand, found `{}`",
                         found,
                     )
@@ -556,7 +585,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorAndSecondOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorAndSecondOperandExpectedBoolean{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the AND operator `&&` expected a boolean as the second operand, found `{}`",
                         found,
                     )
@@ -566,9 +595,9 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorEqualsFirstOperandExpectedEvaluable{ location, found }) |
-            Self::Semantic(SemanticError::OperatorEqualsFirstOperandExpectedPrimitiveType{ location, found }) => {
-                Self::format_line( format!(
-                        "the equals operator `==` expected a unit, boolean or integer as the first operand, found `{}`",
+            Self::Semantic(SemanticError::OperatorEqualsFirstOperandExpectedComparable{ location, found }) => {
+                Diagnostic::line( format!(
+                        "the equals operator `==` expected a comparable value as the first operand, found `{}`",
                         found,
                     )
                         .as_str(),
@@ -580,7 +609,7 @@ impl Error {
             Self::Semantic(SemanticError::OperatorEqualsSecondOperandExpectedUnit{ location, found }) |
             Self::Semantic(SemanticError::OperatorEqualsSecondOperandExpectedBoolean{ location, found }) |
             Self::Semantic(SemanticError::OperatorEqualsSecondOperandExpectedInteger{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the equals operator `==` expected a unit, boolean or integer as the second operand, found `{}`",
                         found,
                     )
@@ -590,8 +619,8 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorEqualsTypesMismatch { location, first, second }) => {
-                Self::format_line( format!(
-                    "the equals operator `==` expected two integers of the same type, found `{}` and `{}`",
+                Diagnostic::line( format!(
+                    "the equals operator `==` expected two values of the same type, found `{}` and `{}`",
                     first, second,
                 )
                                        .as_str(),
@@ -600,9 +629,9 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorNotEqualsFirstOperandExpectedEvaluable{ location, found }) |
-            Self::Semantic(SemanticError::OperatorNotEqualsFirstOperandExpectedPrimitiveType{ location, found }) => {
-                Self::format_line( format!(
-                        "the not equals operator `!=` expected a boolean or integer as the first operand, found `{}`",
+            Self::Semantic(SemanticError::OperatorNotEqualsFirstOperandExpectedComparable{ location, found }) => {
+                Diagnostic::line( format!(
+                        "the not equals operator `!=` expected a comparable value as the first operand, found `{}`",
                         found,
                     )
                         .as_str(),
@@ -614,7 +643,7 @@ impl Error {
             Self::Semantic(SemanticError::OperatorNotEqualsSecondOperandExpectedUnit{ location, found }) |
             Self::Semantic(SemanticError::OperatorNotEqualsSecondOperandExpectedBoolean{ location, found }) |
             Self::Semantic(SemanticError::OperatorNotEqualsSecondOperandExpectedInteger{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the not equals operator `!=` expected a boolean or integer as the second operand, found `{}`",
                         found,
                     )
@@ -624,8 +653,8 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorNotEqualsTypesMismatch { location, first, second }) => {
-                Self::format_line( format!(
-                    "the not equals operator `!=` expected two integers of the same type, found `{}` and `{}`",
+                Diagnostic::line( format!(
+                    "the not equals operator `!=` expected two values of the same type, found `{}` and `{}`",
                     first, second,
                 )
                                        .as_str(),
@@ -635,7 +664,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorGreaterEqualsFirstOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorGreaterEqualsFirstOperandExpectedInteger{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the greater equals operator `>=` expected an integer as the first operand, found `{}`",
                         found,
                     )
@@ -646,7 +675,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorGreaterEqualsSecondOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorGreaterEqualsSecondOperandExpectedInteger{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the greater equals operator `>=` expected an integer as the second operand, found `{}`",
                         found,
                     )
@@ -656,7 +685,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorGreaterEqualsTypesMismatch { location, first, second }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "the greater equals operator `>=` expected two integers of the same type, found `{}` and `{}`",
                     first, second,
                 )
@@ -667,7 +696,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorLesserEqualsFirstOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorLesserEqualsFirstOperandExpectedInteger{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the lesser equals operator `<=` expected an integer as the first operand, found `{}`",
                         found,
                     )
@@ -678,7 +707,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorLesserEqualsSecondOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorLesserEqualsSecondOperandExpectedInteger{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the lesser equals operator `<=` expected an integer as the second operand, found `{}`",
                         found,
                     )
@@ -688,7 +717,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorLesserEqualsTypesMismatch { location, first, second }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "the lesser equals operator `<=` expected two integers of the same type, found `{}` and `{}`",
                     first, second,
                 )
@@ -699,7 +728,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorGreaterFirstOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorGreaterFirstOperandExpectedInteger{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the greater operator `>` expected an integer as the first operand, found `{}`",
                         found,
                     )
@@ -710,7 +739,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorGreaterSecondOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorGreaterSecondOperandExpectedInteger{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the greater operator `>` expected an integer as the second operand, found `{}`",
                         found,
                     )
@@ -720,7 +749,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorGreaterTypesMismatch { location, first, second }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "the greater operator `>` expected two integers of the same type, found `{}` and `{}`",
                     first, second,
                 )
@@ -731,7 +760,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorLesserFirstOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorLesserFirstOperandExpectedInteger{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the lesser operator `<` expected an integer as the first operand, found `{}`",
                         found,
                     )
@@ -742,7 +771,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorLesserSecondOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorLesserSecondOperandExpectedInteger{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the lesser operator `<` expected an integer as the second operand, found `{}`",
                         found,
                     )
@@ -752,7 +781,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorLesserTypesMismatch { location, first, second }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "the lesser operator `<` expected two integers of the same type, found `{}` and `{}`",
                     first, second,
                 )
@@ -763,7 +792,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorBitwiseOrFirstOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorBitwiseOrFirstOperandExpectedInteger{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the bitwise OR operator `|` expected an integer as the first operand, found `{}`",
                         found,
                     )
@@ -774,7 +803,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorBitwiseOrSecondOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorBitwiseOrSecondOperandExpectedInteger{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the bitwise OR operator `|` expected an integer as the second operand, found `{}`",
                         found,
                     )
@@ -784,7 +813,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorBitwiseOrTypesMismatch { location, first, second }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "the bitwise OR operator `|` expected two integers of the same type, found `{}` and `{}`",
                     first, second,
                 )
@@ -795,7 +824,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorBitwiseXorFirstOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorBitwiseXorFirstOperandExpectedInteger{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the bitwise XOR operator `^` expected an integer as the first operand, found `{}`",
                         found,
                     )
@@ -806,7 +835,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorBitwiseXorSecondOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorBitwiseXorSecondOperandExpectedInteger{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the bitwise XOR operator `^` expected an integer as the second operand, found `{}`",
                         found,
                     )
@@ -816,7 +845,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorBitwiseXorTypesMismatch { location, first, second }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "the bitwise XOR operator `^` expected two integers of the same type, found `{}` and `{}`",
                     first, second,
                 )
@@ -827,7 +856,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorBitwiseAndFirstOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorBitwiseAndFirstOperandExpectedInteger{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the bitwise AND operator `&` expected an integer as the first operand, found `{}`",
                         found,
                     )
@@ -838,7 +867,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorBitwiseAndSecondOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorBitwiseAndSecondOperandExpectedInteger{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the bitwise AND operator `&` expected an integer as the second operand, found `{}`",
                         found,
                     )
@@ -848,7 +877,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorBitwiseAndTypesMismatch { location, first, second }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "the bitwise AND operator `&` expected two integers of the same type, found `{}` and `{}`",
                     first, second,
                 )
@@ -859,7 +888,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorBitwiseShiftLeftFirstOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorBitwiseShiftLeftFirstOperandExpectedInteger{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the bitwise shift left operator `<<` expected an integer as the first operand, found `{}`",
                         found,
                     )
@@ -871,7 +900,7 @@ impl Error {
             Self::Semantic(SemanticError::OperatorBitwiseShiftLeftSecondOperandExpectedConstant{ location, found }) |
             Self::Semantic(SemanticError::OperatorBitwiseShiftLeftSecondOperandExpectedInteger{ location, found }) |
             Self::Semantic(SemanticError::OperatorBitwiseShiftLeftSecondOperatorExpectedUnsigned { location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the bitwise shift left operator `<<` expected an unsigned integer constant as the second operand, found `{}`",
                         found,
                     )
@@ -882,7 +911,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorBitwiseShiftRightFirstOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorBitwiseShiftRightFirstOperandExpectedInteger{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the bitwise shift right operator `>>` expected an integer as the first operand, found `{}`",
                         found,
                     )
@@ -894,7 +923,7 @@ impl Error {
             Self::Semantic(SemanticError::OperatorBitwiseShiftRightSecondOperandExpectedConstant{ location, found }) |
             Self::Semantic(SemanticError::OperatorBitwiseShiftRightSecondOperandExpectedInteger{ location, found }) |
             Self::Semantic(SemanticError::OperatorBitwiseShiftRightSecondOperatorExpectedUnsigned { location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the bitwise shift right operator `>>` expected an unsigned integer constant as the second operand, found `{}`",
                         found,
                     )
@@ -904,20 +933,20 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorBitwiseSignedOperandForbidden { location }) => {
-                Self::format_line( "the bitwise operators are forbidden for the signed integer types",
+                Diagnostic::line( "the bitwise operators are forbidden for the signed integer types",
                                    code,location,
                 None,
                 )
             }
             Self::Semantic(SemanticError::OperatorBitwiseFieldOperandForbidden { location }) => {
-                Self::format_line( "the bitwise operators are forbidden for the `field` type",
+                Diagnostic::line( "the bitwise operators are forbidden for the `field` type",
                                    code,location,
                 None,
                 )
             }
             Self::Semantic(SemanticError::OperatorAdditionFirstOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorAdditionFirstOperandExpectedInteger{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the addition operator `+` expected an integer as the first operand, found `{}`",
                         found,
                     )
@@ -928,7 +957,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorAdditionSecondOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorAdditionSecondOperandExpectedInteger{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the addition operator `+` expected an integer as the second operand, found `{}`",
                         found,
                     )
@@ -938,7 +967,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorAdditionTypesMismatch { location, first, second }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "the addition operator `+` expected two integers of the same type, found `{}` and `{}`",
                     first, second,
                 )
@@ -948,7 +977,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorAdditionOverflow { location, value, r#type }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "the addition operator `+` overflow, as the value `{}` cannot be represeneted by type `{}`",
                     value, r#type,
                 )
@@ -959,7 +988,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorSubtractionFirstOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorSubtractionFirstOperandExpectedInteger{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the subtraction operator `-` expected an integer as the first operand, found `{}`",
                         found,
                     )
@@ -970,7 +999,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorSubtractionSecondOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorSubtractionSecondOperandExpectedInteger{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the subtraction operator `-` expected an integer as the second operand, found `{}`",
                         found,
                     )
@@ -980,7 +1009,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorSubtractionTypesMismatch { location, first, second }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "the subtraction operator `-` expected two integers of the same type, found `{}` and `{}`",
                     first, second,
                 )
@@ -990,7 +1019,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorSubtractionOverflow { location, value, r#type }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "the subtraction operator `-` overflow, as the value `{}` cannot be represeneted by type `{}`",
                     value, r#type,
                 )
@@ -1001,7 +1030,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorMultiplicationFirstOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorMultiplicationFirstOperandExpectedInteger{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the multiplication operator `*` expected an integer as the first operand, found `{}`",
                         found,
                     )
@@ -1012,7 +1041,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorMultiplicationSecondOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorMultiplicationSecondOperandExpectedInteger{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the multiplication operator `*` expected an integer as the second operand, found `{}`",
                         found,
                     )
@@ -1022,7 +1051,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorMultiplicationTypesMismatch { location, first, second }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "the multiplication operator `*` expected two integers of the same type, found `{}` and `{}`",
                     first, second,
                 )
@@ -1032,7 +1061,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorMultiplicationOverflow { location, value, r#type }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "the multiplication operator `*` overflow, as the value `{}` cannot be represeneted by type `{}`",
                     value, r#type,
                 )
@@ -1043,7 +1072,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorDivisionFirstOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorDivisionFirstOperandExpectedInteger{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the division operator `/` expected an integer as the first operand, found `{}`",
                         found,
                     )
@@ -1054,7 +1083,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorDivisionSecondOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorDivisionSecondOperandExpectedInteger{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the division operator `/` expected an integer as the second operand, found `{}`",
                         found,
                     )
@@ -1064,7 +1093,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorDivisionTypesMismatch { location, first, second }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "the division operator `/` expected two integers of the same type, found `{}` and `{}`",
                     first, second,
                 )
@@ -1074,7 +1103,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorDivisionOverflow { location, value, r#type }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "the division operator `/` overflow, as the value `{}` cannot be represeneted by type `{}`",
                     value, r#type,
                 )
@@ -1084,20 +1113,20 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorDivisionFieldOperandForbidden { location }) => {
-                Self::format_line( "the division operator `/` is forbidden for the `field` type",
+                Diagnostic::line( "the division operator `/` is forbidden for the `field` type",
                                    code, location,
                                    Some("for inversion consider using `std::ff::invert`"),
                 )
             }
             Self::Semantic(SemanticError::OperatorDivisionByZero { location }) => {
-                Self::format_line( "division by zero",
+                Diagnostic::line( "division by zero",
                                    code,location,
                 None,
                 )
             }
             Self::Semantic(SemanticError::OperatorRemainderFirstOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorRemainderFirstOperandExpectedInteger{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the remainder operator `%` expected an integer as the first operand, found `{}`",
                         found,
                     )
@@ -1108,7 +1137,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorRemainderSecondOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorRemainderSecondOperandExpectedInteger{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the remainder operator `%` expected an integer as the second operand, found `{}`",
                         found,
                     )
@@ -1118,7 +1147,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorRemainderTypesMismatch { location, first, second }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "the remainder operator `%` expected two integers of the same type, found `{}` and `{}`",
                     first, second,
                 )
@@ -1128,7 +1157,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorRemainderOverflow { location, value, r#type }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "the remainder operator `%` overflow, as the value `{}` cannot be represeneted by type `{}`",
                     value, r#type,
                 )
@@ -1138,19 +1167,19 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorRemainderFieldOperandForbidden { location }) => {
-                Self::format_line( "the remainder operator `%` is forbidden for the `field` type",
+                Diagnostic::line( "the remainder operator `%` is forbidden for the `field` type",
                                    code, location,
                                    Some("`field` type values cannot be used to get a remainder"),
                 )
             }
             Self::Semantic(SemanticError::OperatorRemainderOfDivisionByZero { location }) => {
-                Self::format_line( "remainder of division by zero",
+                Diagnostic::line( "remainder of division by zero",
                                    code,location,
                 None,
                 )
             }
             Self::Semantic(SemanticError::OperatorCastingFirstOperandExpectedEvaluable{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the casting operator `as` expected a value as the first operand, found `{}`",
                         found,
                     )
@@ -1160,7 +1189,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorCastingSecondOperandExpectedType{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the casting operator `as` expected a type as the second operand, found `{}`",
                         found,
                     )
@@ -1171,18 +1200,19 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorCastingTypesMismatch { location, inner: CastingError::CastingFromInvalidType { from, to }, reference }) |
             Self::Semantic(SemanticError::OperatorCastingTypesMismatch { location, inner: CastingError::CastingToInvalidType { from, to }, reference }) => {
-                Self::format_line_with_reference(format!(
+                Diagnostic::with_reference(format!(
                         "cannot cast from `{}` to `{}`",
                         from, to,
                     )
                         .as_str(),
                     code, location,
                                    Some(reference),
+                                   "the target type is specified here",
                     Some("only integer values can be casted to an integer with different bitlength or field element"),
                 )
             }
             Self::Semantic(SemanticError::OperatorCastingOverflow { location, value, r#type }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "the casting operator `as` overflow, as the value `{}` cannot be represeneted by type `{}`",
                     value, r#type,
                 )
@@ -1193,7 +1223,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorNotExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorNotExpectedBoolean{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the NOT operator `!` expected a boolean, found `{}`",
                         found,
                     )
@@ -1204,7 +1234,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorBitwiseNotExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorBitwiseNotExpectedInteger{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the bitwise NOT operator `~` expected an integer, found `{}`",
                         found,
                     )
@@ -1215,7 +1245,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorNegationExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorNegationExpectedInteger{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the negation operator `-` expected an integer, found `{}`",
                         found,
                     )
@@ -1225,7 +1255,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorNegationOverflow { location, value, r#type }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "the negation operator `-` overflow, as the value `{}` cannot be represeneted by type `{}`",
                     value, r#type,
                 )
@@ -1235,14 +1265,14 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorNegationFieldOperandForbidden { location }) => {
-                Self::format_line( "the negation operator `-` is forbidden for the `field` type",
+                Diagnostic::line( "the negation operator `-` is forbidden for the `field` type",
                                    code, location,
                                    Some("`field` type values cannot be negative"),
                 )
             }
             Self::Semantic(SemanticError::OperatorIndexFirstOperandExpectedPlaceOrEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorIndexFirstOperandExpectedArray{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the index operator `[]` expected an array as the first operand, found `{}`",
                         found,
                     )
@@ -1253,7 +1283,7 @@ impl Error {
             }
             Self::Semantic(SemanticError::OperatorIndexSecondOperandExpectedEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorIndexSecondOperandExpectedIntegerOrRange{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the index operator `[]` expected an integer or range as the second operand, found `{}`",
                         found,
                     )
@@ -1262,10 +1292,30 @@ impl Error {
                 None,
                 )
             }
+            Self::Semantic(SemanticError::OperatorIndexVecExpectedPlace{ location, found }) => {
+                Diagnostic::line( format!(
+                        "the index operator `[]` expected a vector variable or field as the first operand, found `{}`",
+                        found,
+                    )
+                        .as_str(),
+                    code,location,
+                Some("consider binding the vector to a variable before indexing it"),
+                )
+            }
+            Self::Semantic(SemanticError::OperatorIndexVecExpectedInteger{ location, found }) => {
+                Diagnostic::line( format!(
+                        "the index operator `[]` expected an integer as the vector index, found `{}`",
+                        found,
+                    )
+                        .as_str(),
+                    code,location,
+                Some("vectors cannot be sliced with ranges"),
+                )
+            }
             Self::Semantic(SemanticError::OperatorDotFirstOperandExpectedPlaceOrEvaluable{ location, found }) |
             Self::Semantic(SemanticError::OperatorDotFirstOperandExpectedTuple{ location, found }) |
             Self::Semantic(SemanticError::OperatorDotFirstOperandExpectedInstance { location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the field access operator `.` expected a tuple or object instance as the first operand, found `{}`",
                         found,
                     )
@@ -1275,7 +1325,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorDotSecondOperandExpectedIdentifier { location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the field access operator `.` expected a tuple or object instance field identifier as the second operand, found `{}`",
                         found,
                     )
@@ -1285,7 +1335,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorPathFirstOperandExpectedPath{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the path resolution operator `::` expected an item identifier as the first operand, found `{}`",
                         found,
                     )
@@ -1295,7 +1345,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorPathSecondOperandExpectedIdentifier { location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "the path resolution operator `::` expected an item identifier as the second operand, found `{}`",
                         found,
                     )
@@ -1305,7 +1355,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorStructureFirstOperandExpectedType{ location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "the path must point to a structure type, found `{}`",
                     found,
                 )
@@ -1315,7 +1365,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::OperatorStructureSecondOperandExpectedLiteral { location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "the structure type expected a structure literal, found `{}`",
                     found,
                 )
@@ -1325,7 +1375,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::ArrayPushingInvalidType { location, expected, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "expected `{}`, found `{}`",
                         expected, found,
                     )
@@ -1335,7 +1385,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::ArrayIndexOutOfRange { location, index, size }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "index `{}` is out of range of the array of size {}",
                         index, size,
                     )
@@ -1345,7 +1395,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::ArraySliceStartOutOfRange { location, start }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "left slice bound `{}` is negative",
                         start,
                     )
@@ -1355,7 +1405,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::ArraySliceEndOutOfRange { location, end, size }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "right slice bound `{}` is out of range of the array of size {}",
                         end, size,
                     )
@@ -1365,7 +1415,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::ArraySliceEndLesserThanStart { location, start, end }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "left slice bound `{}` is greater than right slice bound `{}`",
                         start, end,
                     )
@@ -1376,7 +1426,7 @@ impl Error {
             }
 
             Self::Semantic(SemanticError::TupleFieldOutOfRange { location, r#type, field_index }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "`{}` has no field with index `{}`",
                     r#type, field_index,
                 )
@@ -1387,7 +1437,7 @@ impl Error {
             }
 
             Self::Semantic(SemanticError::StructureNotInitialized { location, r#type }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "`{}` must be initialized with a structure literal",
                     r#type,
                 )
@@ -1397,7 +1447,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::StructureFieldDoesNotExist { location, r#type, field_name }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "field or method `{}` does not exist in `{}`",
                         field_name, r#type,
                     )
@@ -1407,7 +1457,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::StructureFieldExpected { location, r#type, position, expected, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "`{}` expected field `{}` at position {}, found `{}`",
                     r#type, expected, position, found,
                 )
@@ -1417,7 +1467,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::StructureFieldInvalidType { location, r#type, field_name, expected, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "field `{}` of `{}` expected type `{}`, found `{}`",
                     field_name, r#type, expected, found,
                 )
@@ -1427,7 +1477,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::StructureFieldCount { location, r#type, expected, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "`{}` expected {} fields, found {}",
                     r#type, expected, found,
                 )
@@ -1438,27 +1488,61 @@ impl Error {
             }
 
             Self::Semantic(SemanticError::MutatingWithDifferentType { location, expected, found }) => {
-                Self::format_line( format!("expected `{}`, found `{}`", expected, found).as_str(),
+                Diagnostic::line( format!("expected `{}`, found `{}`", expected, found).as_str(),
                     code,location,
                 None,
                 )
             }
             Self::Semantic(SemanticError::MutatingImmutableMemory { location, name, reference }) => {
-                Self::format_line_with_reference(format!("cannot assign twice to immutable variable `{}`", name).as_str(),
+                Diagnostic::with_reference(format!("cannot assign twice to immutable variable `{}`", name).as_str(),
                     code, location,
                     reference,
+                    format!("first assignment to `{}`", name).as_str(),
                     Some(format!("make this variable mutable: `mut {}`", name).as_str()),
                 )
             }
             Self::Semantic(SemanticError::MutatingImmutableContractField { location, name }) => {
-                Self::format_line(format!("cannot mutate the immutable contract storage field `{}`", name).as_str(),
+                Diagnostic::line(format!("cannot mutate the immutable contract storage field `{}`", name).as_str(),
                                                  code, location,
                                    Some("such fields cannot be changed by the contract logic"),
                 )
             }
 
+            Self::Semantic(SemanticError::ReferenceOutsideFunctionArgument { location }) => {
+                Diagnostic::line("mutable references are only allowed as function arguments",
+                    code, location,
+                    Some("pass the reference directly to a function, e.g. `update(&mut data)`"),
+                )
+            }
+            Self::Semantic(SemanticError::ReferenceExpectedPlace { location, found }) => {
+                Diagnostic::line(format!("expected a variable or its element after `&mut`, found `{}`", found).as_str(),
+                    code, location,
+                    None,
+                )
+            }
+            Self::Semantic(SemanticError::ReferenceToImmutableMemory { location, name, reference }) => {
+                Diagnostic::with_reference(format!("cannot borrow immutable variable `{}` as mutable", name).as_str(),
+                    code, location,
+                    reference,
+                    format!("`{}` is declared here", name).as_str(),
+                    Some(format!("make this variable mutable: `mut {}`", name).as_str()),
+                )
+            }
+            Self::Semantic(SemanticError::ReferenceRuntimeIndex { location }) => {
+                Diagnostic::line("cannot borrow an array element indexed with a runtime value",
+                    code, location,
+                    Some("only constant indexes and ranges can be borrowed, or pass the whole array"),
+                )
+            }
+            Self::Semantic(SemanticError::ReferenceContractStorage { location }) => {
+                Diagnostic::line("cannot borrow the contract storage",
+                    code, location,
+                    Some("use a `mut self` method to change the contract storage"),
+                )
+            }
+
             Self::Semantic(SemanticError::TypeAliasExpectedType { location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "expected type, found `{}`",
                         found
                     )
@@ -1468,7 +1552,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::TypeInstantiationForbidden { location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "type `{}` cannot be instantiated beyond the contract storage",
                     found,
                 )
@@ -1478,7 +1562,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::TypeDuplicateField { location, r#type, field_name }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "`{}` has a duplicate field `{}`",
                     r#type, field_name,
                 )
@@ -1488,7 +1572,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::TypeDuplicateVariantValue { location, r#type, variant_name, variant_value }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "`{}` has a duplicate variant `{}` with value `{}`",
                     r#type, variant_name, variant_value,
                 )
@@ -1498,7 +1582,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::TypeUnexpectedGenerics { location, r#type }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "type `{}` got unexpected generics",
                     r#type
                 )
@@ -1508,7 +1592,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::TypeExpectedGenerics { location, r#type, expected }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "structure `{}` expected {} generic arguments",
                     r#type, expected,
                 )
@@ -1518,8 +1602,8 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::TypeInvalidGenericsNumber { location, r#type, expected, found }) => {
-                Self::format_line( format!(
-                    "structure `{}` expected {} generic arguments, found {}",
+                Diagnostic::line( format!(
+                    "`{}` expected {} generic arguments, found {}",
                     r#type, expected, found,
                 )
                                        .as_str(),
@@ -1527,20 +1611,123 @@ impl Error {
                 None,
                 )
             }
+            Self::Semantic(SemanticError::TypeGenericParameterNotInferred { location, r#type, name }) => {
+                Diagnostic::line( format!(
+                    "cannot infer the generic parameter `{}` of `{}`",
+                    name, r#type,
+                )
+                                       .as_str(),
+                                   code,location,
+                Some("generic parameters are inferred from the argument or field types, e.g. `a: T` or `[u8; N]`, or specified explicitly, e.g. `identity::<u8>(42)`"),
+                )
+            }
+            Self::Semantic(SemanticError::TypeGenericConstantExpectedInteger { location, r#type, name, found }) => {
+                Diagnostic::line( format!(
+                    "the constant generic parameter `{}` of `{}` must be an integer, found `{}`",
+                    name, r#type, found,
+                )
+                                       .as_str(),
+                                   code,location,
+                Some("constant generic parameters are usually array sizes, e.g. `const N: u8`"),
+                )
+            }
+            Self::Semantic(SemanticError::TypeGenericConstantUnavailable { location, r#type, name }) => {
+                Diagnostic::line( format!(
+                    "structure `{}` cannot have the constant generic parameter `{}`",
+                    r#type, name,
+                )
+                                       .as_str(),
+                                   code,location,
+                Some("constant generic parameters are only available for functions"),
+                )
+            }
+            Self::Semantic(SemanticError::TypeGenericExpectedType { location, r#type, found }) => {
+                Diagnostic::line( format!(
+                    "`{}` expected a type generic argument, found `{}`",
+                    r#type, found,
+                )
+                                       .as_str(),
+                                   code,location,
+                None,
+                )
+            }
+            Self::Semantic(SemanticError::TypeGenericExpectedConstant { location, r#type, found }) => {
+                Diagnostic::line( format!(
+                    "`{}` expected a constant generic argument, found `{}`",
+                    r#type, found,
+                )
+                                       .as_str(),
+                                   code,location,
+                Some("constant generic arguments are integer literals or constants, e.g. `Vec<u8, 16>`"),
+                )
+            }
+            Self::Semantic(SemanticError::TypeGenericParameterUnused { location, r#type, name }) => {
+                Diagnostic::line( format!(
+                    "the type parameter `{}` of `{}` is not used in its signature",
+                    name, r#type,
+                )
+                                       .as_str(),
+                                   code,location,
+                Some("use the type parameter in an argument, field, or return type, e.g. `fn default<T>() -> T`"),
+                )
+            }
+            Self::Semantic(SemanticError::TypeGenericRecursive { location, r#type, reference }) => {
+                Diagnostic::with_reference(format!(
+                        "generic item `{}` is instantiated recursively",
+                        r#type
+                    )
+                        .as_str(),
+                    code, location,
+                    Some(reference),
+                    "the generic item is declared here",
+                    Some("structures cannot contain themselves, and functions cannot call themselves"),
+                )
+            }
+            Self::Semantic(SemanticError::TypeVariantExpectedUnit { location, variant }) => {
+                Diagnostic::line( format!(
+                    "enumeration variant `{}` carries data and cannot be used without it",
+                    variant,
+                )
+                                       .as_str(),
+                                   code,location,
+                Some("consider initializing the variant with its data as declared"),
+                )
+            }
+            Self::Semantic(SemanticError::TypeVariantExpectedTuple { location, variant }) => {
+                Diagnostic::line( format!(
+                    "enumeration variant `{}` is not a tuple-like one",
+                    variant,
+                )
+                                       .as_str(),
+                                   code,location,
+                Some("consider using the variant as declared"),
+                )
+            }
+            Self::Semantic(SemanticError::TypeVariantExpectedStructure { location, variant }) => {
+                Diagnostic::line( format!(
+                    "enumeration variant `{}` is not a structure-like one",
+                    variant,
+                )
+                                       .as_str(),
+                                   code,location,
+                Some("consider using the variant as declared"),
+                )
+            }
 
             Self::Semantic(SemanticError::FunctionArgumentCount { location, function, expected, found, reference }) => {
-                Self::format_line_with_reference( format!(
+                Diagnostic::with_reference( format!(
                         "function `{}` expected {} arguments, found {}",
                         function, expected, found
                     )
                         .as_str(),
                                                   code, location,
                     reference,
+                    "the arguments are passed here",
                     None,
                 )
             }
             Self::Semantic(SemanticError::FunctionDebugArgumentCount { location, expected, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "the `dbg!` function expected {} arguments, but got {}",
                     expected, found,
                 )
@@ -1550,7 +1737,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::FunctionArgumentType { location, function, name, position, expected, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "function `{}` expected type `{}` as the argument `{}` (#{}), found `{}`",
                         function, expected, name, position, found
                     )
@@ -1560,7 +1747,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::FunctionArgumentConstantness { location, function, name, position, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "function `{}` expected a constant as the argument `{}` (#{}), found a non-constant of type `{}`",
                         function, name, position, found
                     )
@@ -1570,7 +1757,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::FunctionArgumentNotEvaluable { location, function, position, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "function `{}` expected a value as the argument #{}, found `{}`",
                         function, position, found
                     )
@@ -1580,18 +1767,19 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::FunctionReturnType { location, function, expected, found, reference }) => {
-                Self::format_line_with_reference(format!(
+                Diagnostic::with_reference(format!(
                         "function `{}` must return a value of type `{}`, found `{}`",
                         function, expected, found
                     )
                         .as_str(),
                     code, location,
                                    Some(reference),
+                                   "the return type is specified here",
                     None,
                 )
             }
             Self::Semantic(SemanticError::FunctionNonCallable { location, name }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "attempt to call a non-callable item `{}`",
                         name
                     )
@@ -1601,7 +1789,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::FunctionCallMutableFromImmutable { location, function }) => {
-                Self::format_line(format!(
+                Diagnostic::line(format!(
                     "the mutable method `{}` was called with an immutable instance",
                     function,
                 )
@@ -1610,8 +1798,60 @@ impl Error {
                                    Some("consider making the instance mutable"),
                 )
             }
+            Self::Semantic(SemanticError::FunctionArgumentExpectedReference { location, function, name, position }) => {
+                Diagnostic::line(format!(
+                    "function `{}` expected a mutable reference as the argument `{}` (#{}), found a value",
+                    function, name, position,
+                )
+                    .as_str(),
+                    code, location,
+                    Some("pass the argument with `&mut`"),
+                )
+            }
+            Self::Semantic(SemanticError::FunctionArgumentUnexpectedReference { location, function, name, position }) => {
+                Diagnostic::line(format!(
+                    "function `{}` expected a value as the argument `{}` (#{}), found a mutable reference",
+                    function, name, position,
+                )
+                    .as_str(),
+                    code, location,
+                    Some("remove the `&mut`"),
+                )
+            }
+            Self::Semantic(SemanticError::FunctionArgumentReferenceAliased { location, function, position, reference }) => {
+                Diagnostic::with_reference(format!(
+                    "function `{}` argument #{} borrows the memory, which is already borrowed mutably by another argument",
+                    function, position,
+                )
+                    .as_str(),
+                    code, location,
+                    reference,
+                    "the memory is first borrowed here",
+                    Some("pass disjoint fields or elements, or call the function twice"),
+                )
+            }
+            Self::Semantic(SemanticError::FunctionReferenceArgumentForbidden { location, function }) => {
+                Diagnostic::line(format!(
+                    "function `{}` cannot take mutable reference arguments",
+                    function,
+                )
+                    .as_str(),
+                    code, location,
+                    Some("only the ordinary runtime functions and methods can take `&mut` arguments"),
+                )
+            }
+            Self::Semantic(SemanticError::FunctionGenericsUnavailable { location, function }) => {
+                Diagnostic::line(format!(
+                    "function `{}` cannot have generic parameters",
+                    function,
+                )
+                                                     .as_str(),
+                                                 code, location,
+                                   Some("only runtime functions declared in modules can be generic, except for `main` and unit tests"),
+                )
+            }
             Self::Semantic(SemanticError::FunctionUnexpectedExclamationMark { location, function }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "attempt to call the `{}` function with an unexpected `!` specifier",
                         function
                     )
@@ -1621,7 +1861,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::FunctionExpectedExclamationMark { location, function }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "attempt to call the `{}` function without `!` specifier",
                         function
                     )
@@ -1631,7 +1871,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::FunctionStdlibArrayTruncatingToBiggerSize { location, from, to }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "attempt to truncate an array from size `{}` to bigger size `{}`",
                         from, to,
                     )
@@ -1641,7 +1881,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::FunctionStdlibArrayPaddingToLesserSize { location, from, to }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "attempt to pad an array from size `{}` to lesser size `{}`",
                         from, to,
                     )
@@ -1651,7 +1891,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::FunctionStdlibArrayNewLengthInvalid { location, value }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "new array length `{}` cannot act as an index",
                         value,
                     )
@@ -1660,9 +1900,19 @@ impl Error {
                                    Some("array indexes cannot be greater than maximum of `u64`"),
                 )
             }
+            Self::Semantic(SemanticError::FunctionStdlibVecLengthExceedsCapacity { location, length, capacity }) => {
+                Diagnostic::line( format!(
+                        "vector length `{}` exceeds its capacity `{}`",
+                        length, capacity,
+                    )
+                        .as_str(),
+                    code, location,
+                                   Some("the vector capacity is the initial array size"),
+                )
+            }
 
             Self::Semantic(SemanticError::UnitTestCallForbidden { location, function }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "unit test function `{}` cannot be called",
                     function,
                 )
@@ -1672,7 +1922,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::UnitTestBeyondModuleScope { location, function }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "unit test function `{}` must be declared at the module root scope",
                     function,
                 )
@@ -1682,7 +1932,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::UnitTestPublicForbidden { location, function }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "unit test function `{}` cannot be declared as public",
                     function,
                 )
@@ -1692,7 +1942,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::UnitTestConstantForbidden { location, function }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "unit test function `{}` cannot be declared as constant",
                     function,
                 )
@@ -1702,7 +1952,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::UnitTestCannotHaveArguments { location, function }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "unit test function `{}` cannot accept arguments",
                     function,
                 )
@@ -1712,7 +1962,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::UnitTestCannotReturnValue { location, function }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "unit test function `{}` cannot return a value",
                     function,
                 )
@@ -1723,7 +1973,7 @@ impl Error {
             }
 
             Self::Semantic(SemanticError::ScopeItemUndeclared { location, name }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "cannot find item `{}` in this scope",
                     name
                 )
@@ -1733,18 +1983,19 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::ScopeItemRedeclared { location, name, reference }) => {
-                Self::format_line_with_reference(format!(
+                Diagnostic::with_reference(format!(
                     "item `{}` already declared here",
                     name
                 )
                                                      .as_str(),
                                                  code, location,
                                                  reference,
+                                                 "first declared here",
                                                  Some("consider giving the latter item another name"),
                 )
             }
             Self::Semantic(SemanticError::ScopeExpectedNamespace { location, name }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "item `{}` is not a namespace",
                     name
                 )
@@ -1754,113 +2005,174 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::ScopeContractRedeclared { location, reference }) => {
-                Self::format_line_with_reference("another contract is already declared here",
+                Diagnostic::with_reference("another contract is already declared here",
                                                  code, location,
                                    Some(reference),
+                                   "first declared here",
                                                  Some("only one contract may be declared in the project"),
                 )
             }
             Self::Semantic(SemanticError::ScopeReferenceLoop { location }) => {
-                Self::format_line( "reference loop detected",
+                Diagnostic::line( "reference loop detected",
                                    code, location,
                                    Some("consider removing circular references between the items"),
                 )
             }
 
             Self::Semantic(SemanticError::ExpressionNonConstantElement { location, found }) => {
-                Self::format_line( format!("attempt to use a non-constant value `{}` in a constant expression", found).as_str(),
+                Diagnostic::line( format!("attempt to use a non-constant value `{}` in a constant expression", found).as_str(),
                     code,location,
                 None,
                 )
             }
             Self::Semantic(SemanticError::ContractStorageFieldWithoutInstance { location, found }) => {
-                Self::format_line( format!("attempt to access the contract storage field `{}` without an instance", found).as_str(),
+                Diagnostic::line( format!("attempt to access the contract storage field `{}` without an instance", found).as_str(),
                                    code, location,
                                    Some(format!("consider accessing the field via a contract instance, e.g. `self.{}`", found).as_str()),
                 )
             }
 
             Self::Semantic(SemanticError::ConditionalExpectedBooleanCondition { location, found }) => {
-                Self::format_line( format!("expected `bool`, found `{}`", found).as_str(),
+                Diagnostic::line( format!("expected `bool`, found `{}`", found).as_str(),
                                    code,location,
                 None,
                 )
             }
             Self::Semantic(SemanticError::ConditionalBranchTypesMismatch { location, expected, found, reference }) => {
-                Self::format_line_with_reference(format!("if and else branches return incompatible types `{}` and `{}`", expected, found).as_str(),
+                Diagnostic::with_reference(format!("if and else branches return incompatible types `{}` and `{}`", expected, found).as_str(),
                                                  code, location,
                                    Some(reference),
+                                   "the other branch is here",
                                                  None,
                 )
             }
 
             Self::Semantic(SemanticError::MatchScrutineeInvalidType { location, found }) => {
-                Self::format_line( format!("match scrutinee expected a boolean or integer expression, found `{}`", found).as_str(),
+                Diagnostic::line( format!("match scrutinee expected a boolean or integer expression, found `{}`", found).as_str(),
                     code,location,
                 None,
                 )
             }
             Self::Semantic(SemanticError::MatchNotExhausted { location }) => {
-                Self::format_line( "match expression must be exhaustive",
+                Diagnostic::line( "match expression must be exhaustive",
                     code, location,
                                    Some("ensure that all possible cases are being handled, possibly by adding wildcards or more match arms"),
                 )
             }
             Self::Semantic(SemanticError::MatchLessThanTwoBranches { location }) => {
-                Self::format_line( "match expression must have at least two branches",
+                Diagnostic::line( "match expression must have at least two branches",
                     code, location,
                                    Some("consider adding some branches to make the expression useful"),
                 )
             }
-            Self::Semantic(SemanticError::MatchBranchUnreachable { location }) => {
-                Self::format_line( "match expression branch is unreachable",
-                    code, location,
-                                   Some("consider removing the branch or moving it above the branch with a wildcard or irrefutable binding"),
-                )
-            }
             Self::Semantic(SemanticError::MatchBranchPatternPathExpectedConstant { location, found }) => {
-                Self::format_line( format!("expected path to a constant, found `{}`", found).as_str(),
+                Diagnostic::line( format!("expected path to a constant, found `{}`", found).as_str(),
                     code,location,
                 None,
                 )
             }
             Self::Semantic(SemanticError::MatchBranchPatternInvalidType { location, expected, found, reference }) => {
-                Self::format_line_with_reference(format!("expected `{}`, found `{}`", expected, found).as_str(),
+                Diagnostic::with_reference(format!("expected `{}`, found `{}`", expected, found).as_str(),
                     code, location,
                                    Some(reference),
+                                   "the other branch is here",
                     Some("all branch patterns must be compatible with the type of the expression being matched"),
                 )
             }
             Self::Semantic(SemanticError::MatchBranchExpressionInvalidType { location, expected, found, reference }) => {
-                Self::format_line_with_reference(format!("expected `{}`, found `{}`", expected, found).as_str(),
+                Diagnostic::with_reference(format!("expected `{}`, found `{}`", expected, found).as_str(),
                     code, location,
                                    Some(reference),
+                                   "the first branch is here",
                     Some("all branches must return the type returned by the first branch"),
                 )
             }
             Self::Semantic(SemanticError::MatchBranchDuplicate { location, reference }) => {
-                Self::format_line_with_reference("match expression contains a duplicate branch pattern",
+                Diagnostic::with_reference("match expression contains a duplicate branch pattern",
                     code, location,
                                    Some(reference),
+                                   "first occurrence is here",
                     Some("each pattern may occur only once"),
                 )
             }
+            Self::Semantic(SemanticError::MatchBranchPatternElementCount { location, r#type, expected, found }) => {
+                Diagnostic::line( format!(
+                    "`{}` pattern expected {} elements, found {}",
+                    r#type, expected, found,
+                )
+                                       .as_str(),
+                                   code, location,
+                Some("consider matching each element, possibly with a wildcard `_`"),
+                )
+            }
+            Self::Semantic(SemanticError::MatchBranchPatternFieldMissing { location, r#type, field_name }) => {
+                Diagnostic::line( format!(
+                    "`{}` pattern does not mention field `{}`",
+                    r#type, field_name,
+                )
+                                       .as_str(),
+                                   code, location,
+                Some("consider ignoring the remaining fields with `..`"),
+                )
+            }
+            Self::Semantic(SemanticError::MatchBranchPatternFieldDuplicate { location, field_name, reference }) => {
+                Diagnostic::with_reference( format!(
+                    "field `{}` is bound more than once in the same pattern",
+                    field_name,
+                )
+                                       .as_str(),
+                    code, location,
+                                   Some(reference),
+                                   "first bound here",
+                    Some("each field may occur only once"),
+                )
+            }
+            Self::Semantic(SemanticError::MatchBranchPatternRangeEmpty { location }) => {
+                Diagnostic::line( "range pattern does not match any value",
+                    code, location,
+                Some("the range start must be less than the exclusive end or not greater than the inclusive one"),
+                )
+            }
+            Self::Semantic(SemanticError::MatchBranchPatternAlternativeBindingMissing { location, name, reference }) => {
+                Diagnostic::with_reference( format!(
+                    "variable `{}` is not bound in all alternatives",
+                    name,
+                )
+                                       .as_str(),
+                    code, location,
+                                   Some(reference),
+                                   "variable is bound here",
+                    Some("each alternative must bind the same variables"),
+                )
+            }
+            Self::Semantic(SemanticError::MatchBranchGuardExpectedBooleanCondition { location, found }) => {
+                Diagnostic::line( format!("expected `bool`, found `{}`", found).as_str(),
+                    code,location,
+                None,
+                )
+            }
 
             Self::Semantic(SemanticError::ForStatementWhileExpectedBooleanCondition { location, found }) => {
-                Self::format_line( format!("expected `bool`, found `{}`", found).as_str(),
+                Diagnostic::line( format!("expected `bool`, found `{}`", found).as_str(),
                     code,location,
                 None,
                 )
             }
-            Self::Semantic(SemanticError::ForStatementBoundsExpectedConstantRangeExpression { location, found }) => {
-                Self::format_line( format!("expected a constant range expression, found `{}`", found).as_str(),
+            Self::Semantic(SemanticError::ForStatementBoundsExpectedConstantRangeOrArray { location, found }) => {
+                Diagnostic::line( format!("expected a constant range expression or an array, found `{}`", found).as_str(),
+                    code, location,
+                                   Some("only constant ranges and arrays allowed, e.g. `for i in 0..42 { ... }` or `for item in array { ... }`"),
+                )
+            }
+            Self::Semantic(SemanticError::ForStatementZipArraySizeMismatch { location, expected, found }) => {
+                Diagnostic::line( format!("expected an array of size {}, found one of size {}", expected, found).as_str(),
                     code, location,
-                                   Some("only constant ranges allowed, e.g. `for i in 0..42 { ... }`"),
+                                   Some("only arrays of the same size can be zipped"),
                 )
             }
 
             Self::Semantic(SemanticError::ImplStatementExpectedStructureOrEnumeration { location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "`impl` expected a type with namespace, found `{}`",
                     found
                 )
@@ -1869,9 +2181,129 @@ impl Error {
                                    Some("only structures and enumerations can have an implementation"),
                 )
             }
+            Self::Semantic(SemanticError::ImplStatementExpectedTrait { location, found }) => {
+                Diagnostic::line( format!(
+                    "`impl ... for` expected a trait, found `{}`",
+                    found
+                )
+                                       .as_str(),
+                                   code, location,
+                                   Some("only traits can be implemented for a type, e.g. `impl Hashable for Data { ... }`"),
+                )
+            }
+            Self::Semantic(SemanticError::ImplStatementTraitMethodMissing { location, r#trait, method, reference }) => {
+                Diagnostic::with_reference(format!(
+                        "method `{}` of trait `{}` is not implemented",
+                        method, r#trait
+                    )
+                        .as_str(),
+                    code, location,
+                    Some(reference),
+                    "the method is declared in the trait here",
+                    Some("consider implementing all the methods declared in the trait"),
+                )
+            }
+            Self::Semantic(SemanticError::ImplStatementTraitItemUnknown { location, r#trait, name }) => {
+                Diagnostic::line( format!(
+                        "item `{}` is not a member of trait `{}`",
+                        name, r#trait
+                    )
+                        .as_str(),
+                    code, location,
+                    Some("consider moving the item to an inherent `impl` block of the type"),
+                )
+            }
+            Self::Semantic(SemanticError::ImplStatementTraitMethodSignatureMismatch { location, r#trait, method, expected, found, reference }) => {
+                Diagnostic::with_reference(format!(
+                        "method `{}` of trait `{}` must have signature `{}`, found `{}`",
+                        method, r#trait, expected, found
+                    )
+                        .as_str(),
+                    code, location,
+                    Some(reference),
+                    "the method is declared in the trait here",
+                    None,
+                )
+            }
+            Self::Semantic(SemanticError::TraitUsedAsExpression { location, name }) => {
+                Diagnostic::line( format!(
+                        "trait `{}` cannot be used as an expression",
+                        name
+                    )
+                        .as_str(),
+                    code, location,
+                    Some("traits can be only implemented for types, e.g. `impl Hashable for Data { ... }`"),
+                )
+            }
+            Self::Semantic(SemanticError::ImplStatementTraitMethodConflict { location, method, r#trait, other, reference }) => {
+                let describe = |r#trait: Option<String>| match r#trait {
+                    Some(r#trait) => format!("trait `{}`", r#trait),
+                    None => "the inherent implementation".to_owned(),
+                };
+
+                Diagnostic::with_reference(format!(
+                        "method `{}` of {} conflicts with the method of {}",
+                        method, describe(r#trait), describe(other)
+                    )
+                        .as_str(),
+                    code, location,
+                    Some(reference),
+                    "the conflicting method is declared here",
+                    Some("the methods of all implementations share the type namespace, so consider renaming one of them"),
+                )
+            }
+            Self::Semantic(SemanticError::TraitUsedAsNamespace { location, name }) => {
+                Diagnostic::line( format!(
+                        "trait `{}` cannot be used as a namespace",
+                        name
+                    )
+                        .as_str(),
+                    code, location,
+                    Some("call the method via the implementing type instead, e.g. `Data::hash(value)` or `value.hash()`"),
+                )
+            }
+            Self::Semantic(SemanticError::ImplStatementGenericTraitUnavailable { location, r#trait, found }) => {
+                Diagnostic::line( format!(
+                        "generic structure `{}` cannot implement trait `{}`",
+                        found, r#trait
+                    )
+                        .as_str(),
+                    code, location,
+                    Some("trait implementations of generic structures are not supported yet, so consider using an inherent `impl` block"),
+                )
+            }
+
+            Self::Semantic(SemanticError::ReturnStatementBeyondFunction { location }) => {
+                Diagnostic::line( "`return` statement is only allowed in function bodies",
+                    code, location,
+                    None,
+                )
+            }
+            Self::Semantic(SemanticError::ReturnStatementInConstantFunction { location, function }) => {
+                Diagnostic::line( format!(
+                        "`return` statement is not allowed in the constant function `{}`",
+                        function
+                    )
+                        .as_str(),
+                    code, location,
+                    Some("constant functions are evaluated at compile time and must return their last expression"),
+                )
+            }
+            Self::Semantic(SemanticError::BreakStatementBeyondLoop { location }) => {
+                Diagnostic::line( "`break` statement is only allowed in `for` loop bodies",
+                    code, location,
+                    None,
+                )
+            }
+            Self::Semantic(SemanticError::ContinueStatementBeyondLoop { location }) => {
+                Diagnostic::line( "`continue` statement is only allowed in `for` loop bodies",
+                    code, location,
+                    None,
+                )
+            }
 
             Self::Semantic(SemanticError::UseStatementExpectedPath { location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "`use` expected an item path, but got `{}`",
                         found
                     )
@@ -1882,7 +2314,7 @@ impl Error {
             }
 
             Self::Semantic(SemanticError::AttributeUnknown { location, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "attribute `{}` is unknown",
                     found
                 )
@@ -1892,43 +2324,50 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::AttributeEmpty { location, }) => {
-                Self::format_line(
+                Diagnostic::line(
                     "attribute is empty",
                                    code, location,
                                    Some("consider adding an attribute element, e.g. `#[test]`"),
                 )
             }
             Self::Semantic(SemanticError::AttributeElementsCount { location, name, expected, found }) => {
-                Self::format_line(
+                Diagnostic::line(
                     format!("attribute `{}` expected {} elements, found {}", name, expected, found).as_str(),
                     code, location,
                     None,
                 )
             }
             Self::Semantic(SemanticError::AttributeExpectedElement { location, name, position, expected, found }) => {
-                Self::format_line(
+                Diagnostic::line(
                     format!("attribute `{}` expected element `{}` at position {}, found `{}`", name, expected, position, found).as_str(),
                     code, location,
                     None,
                 )
             }
             Self::Semantic(SemanticError::AttributeExpectedIntegerLiteral { location, name }) => {
-                Self::format_line(
+                Diagnostic::line(
                     format!("attribute `{}` expected an integer literal", name).as_str(),
                     code, location,
                     None,
                 )
             }
             Self::Semantic(SemanticError::AttributeExpectedNested { location, name }) => {
-                Self::format_line(
+                Diagnostic::line(
                     format!("attribute `{}` expected a nested element", name).as_str(),
                     code, location,
                     Some(format!("consider passing the required elements, e.g. `{}(value = 42)`", name).as_str()),
                 )
             }
+            Self::Semantic(SemanticError::AttributeUnknownLint { location, found }) => {
+                Diagnostic::line(
+                    format!("lint `{}` is unknown", found).as_str(),
+                    code, location,
+                    Some("see the reference to get the list of available lints"),
+                )
+            }
 
             Self::Semantic(SemanticError::BindingTypeRequired { location, identifier }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "type is required for binding `{}`",
                     identifier
                 )
@@ -1938,7 +2377,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::BindingExpectedTuple { location, expected, found }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                     "expected a tuple with {} elements, found `{}`",
                     expected, found
                 )
@@ -1948,7 +2387,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::BindingSelfNotFirstMethodArgument { location, name, position }) => {
-                Self::format_line(format!(
+                Diagnostic::line(format!(
                     "expected the `{}` binding to be at the first position, but found at the position #`{}`",
                     name,
                     position,
@@ -1959,7 +2398,7 @@ impl Error {
                 )
             }
             Self::Semantic(SemanticError::BindingFunctionArgumentDestructuringUnavailable { location }) => {
-                Self::format_line(
+                Diagnostic::line(
                     "tuple function argument destructuring is not implemented yet",
                     code, location,
                                    Some("consider passing the arguments separately for now"),
@@ -1967,32 +2406,33 @@ impl Error {
             }
 
             Self::Semantic(SemanticError::EntryPointAmbiguous { main, contract }) => {
-                Self::format_line_with_reference("the entry file contains both the `main` function and contract definition",
+                Diagnostic::with_reference("the entry file contains both the `main` function and contract definition",
                                                  code, main,
                     Some(contract),
+                    "the contract is declared here",
                     Some("consider choosing between the circuit and contract project type"),
                 )
             }
             Self::Semantic(SemanticError::EntryPointConstant { location }) => {
-                Self::format_line( "the entry point cannot be constant",
+                Diagnostic::line( "the entry point cannot be constant",
                     code, location,
                                    Some("consider removing the `const` modifier"),
                 )
             }
             Self::Semantic(SemanticError::FunctionMainBeyondEntry { location }) => {
-                Self::format_line( "the `main` function is declared beyond the `main.zn` entry file",
+                Diagnostic::line( "the `main` function is declared beyond the `main.zn` entry file",
                     code, location,
                                    Some("the `main` function may be declared only in the entry file"),
                 )
             }
             Self::Semantic(SemanticError::ContractBeyondEntry { location }) => {
-                Self::format_line( "contract is declared beyond the entry file",
+                Diagnostic::line( "contract is declared beyond the entry file",
                     code, location,
                                    Some("contracts may be declared only once in the entry file"),
                 )
             }
             Self::Semantic(SemanticError::ModuleFileNotFound { location, name }) => {
-                Self::format_line( format!(
+                Diagnostic::line( format!(
                         "file not found for module `{}`",
                         name
                     )
@@ -2014,206 +2454,6 @@ impl Error {
             Self::Semantic(inner) => Some(inner.code()),
         }
     }
-
-    ///
-    /// Formats an error `message` with an optional `help` message.
-    ///
-    /// The error has a location, that is, points to a specific place in the source code.
-    ///
-    fn format_line(
-        message: &str,
-        code: Option<usize>,
-        location: Location,
-        help: Option<&str>,
-    ) -> String {
-        let index = FILE_INDEX
-            .inner
-            .read()
-            .expect(zinc_const::panic::SYNCHRONIZATION);
-        let context = index
-            .get(&location.file)
-            .expect(zinc_const::panic::VALIDATED_DURING_SOURCE_CODE_MAPPING)
-            .code
-            .lines()
-            .collect::<Vec<&str>>();
-        let line_number_length = location.line.to_string().len();
-        let mut strings = Vec::with_capacity(8);
-        strings.push(String::new());
-        let code = match code {
-            Some(code) => format!("error[{:04}]", code),
-            None => "error".to_owned(),
-        };
-        strings.push(format!("{}: {}", code.bright_red(), message.bright_white()));
-        strings.push(format!(" {} {}", "-->".bright_cyan(), location));
-        strings.push(format!(
-            "{}{}",
-            " ".repeat(line_number_length + 1),
-            "|".bright_cyan()
-        ));
-        if let Some(line) = context.get(location.line - 1) {
-            strings.push(format!(
-                "{}{}",
-                (location.line.to_string() + " | ").bright_cyan(),
-                line
-            ));
-        }
-        strings.push(format!(
-            "{}{} {}{}",
-            " ".repeat(line_number_length + 1),
-            "|".bright_cyan(),
-            "_".repeat(location.column - 1).bright_red(),
-            "^".bright_red()
-        ));
-        if let Some(help) = help {
-            strings.push(format!("{}: {}", "help".bright_white(), help.bright_blue()));
-        }
-        strings.push(String::new());
-        strings.join("\n")
-    }
-
-    ///
-    /// Formats an error `message` with an optional `help` message.
-    ///
-    /// The error has a second location reference, which helps the user to fix the error more easily.
-    ///
-    fn format_line_with_reference(
-        message: &str,
-        code: Option<usize>,
-        location: Location,
-        reference: Option<Location>,
-        help: Option<&str>,
-    ) -> String {
-        let index = FILE_INDEX
-            .inner
-            .read()
-            .expect(zinc_const::panic::SYNCHRONIZATION);
-        let context = index
-            .get(&location.file)
-            .expect(zinc_const::panic::VALIDATED_DURING_SOURCE_CODE_MAPPING)
-            .code
-            .lines()
-            .collect::<Vec<&str>>();
-        let line_number_length = location.line.to_string().len();
-        let mut strings = Vec::with_capacity(11);
-        strings.push(String::new());
-        let code = match code {
-            Some(code) => format!("error[{:04}]", code),
-            None => "error".to_owned(),
-        };
-        strings.push(format!("{}: {}", code.bright_red(), message.bright_white()));
-        if let Some(reference) = reference {
-            let context = index
-                .get(&reference.file)
-                .expect(zinc_const::panic::VALIDATED_DURING_SOURCE_CODE_MAPPING)
-                .code
-                .lines()
-                .collect::<Vec<&str>>();
-            let line_number_length = reference.line.to_string().len();
-            strings.push(format!(
-                "{}{}",
-                " ".repeat(line_number_length + 1),
-                "|".bright_cyan()
-            ));
-            if let Some(line) = context.get(reference.line - 1) {
-                strings.push(format!(
-                    "{}{}",
-                    (reference.line.to_string() + " | ").bright_cyan(),
-                    line
-                ));
-            }
-            strings.push(format!(
-                "{}{} {}{}",
-                " ".repeat(line_number_length + 1),
-                "|".bright_cyan(),
-                "_".repeat(reference.column - 1).bright_red(),
-                "^".bright_red()
-            ));
-        }
-        strings.push(format!(" {} {}", "-->".bright_cyan(), location));
-        strings.push(format!(
-            "{}{}",
-            " ".repeat(line_number_length + 1),
-            "|".bright_cyan()
-        ));
-        if let Some(line) = context.get(location.line - 1) {
-            strings.push(format!(
-                "{}{}",
-                (location.line.to_string() + " | ").bright_cyan(),
-                line
-            ));
-        }
-        strings.push(format!(
-            "{}{} {}{}",
-            " ".repeat(line_number_length + 1),
-            "|".bright_cyan(),
-            "_".repeat(location.column - 1).bright_red(),
-            "^".bright_red()
-        ));
-        if let Some(help) = help {
-            strings.push(format!("{}: {}", "help".bright_white(), help.bright_blue()));
-        }
-        strings.push(String::new());
-        strings.join("\n")
-    }
-
-    ///
-    /// Formats an error `message` with an optional `help` message.
-    ///
-    /// The error has two location bounds, which enclose the erroneous part of the source code.
-    ///
-    fn format_range(
-        message: &'static str,
-        code: Option<usize>,
-        start: Location,
-        end: Location,
-        help: Option<&str>,
-    ) -> String {
-        let index = FILE_INDEX
-            .inner
-            .read()
-            .expect(zinc_const::panic::SYNCHRONIZATION);
-        let context = index
-            .get(&start.file)
-            .expect(zinc_const::panic::VALIDATED_DURING_SOURCE_CODE_MAPPING)
-            .code
-            .lines()
-            .collect::<Vec<&str>>();
-        let line_number_length = end.line.to_string().len();
-        let mut strings = Vec::with_capacity(8 + end.line - start.line);
-        strings.push(String::new());
-        let code = match code {
-            Some(code) => format!("error[{:04}]", code),
-            None => "error".to_owned(),
-        };
-        strings.push(format!("{}: {}", code.bright_red(), message.bright_white()));
-        strings.push(format!(" {} {}", "-->".bright_cyan(), start));
-        strings.push(format!(
-            "{}{}",
-            " ".repeat(line_number_length + 1),
-            "|".bright_cyan()
-        ));
-        for line_number in start.line..=end.line {
-            if let Some(line) = context.get(line_number - 1) {
-                strings.push(format!(
-                    "{}{}",
-                    (line_number.to_string() + " | ").bright_cyan(),
-                    line
-                ));
-            }
-        }
-        strings.push(format!(
-            "{}{} {}{}",
-            " ".repeat(line_number_length + 1),
-            "|".bright_cyan(),
-            "_".repeat(end.column - 1).bright_red(),
-            "^".bright_red()
-        ));
-        if let Some(help) = help {
-            strings.push(format!("{}: {}", "help".bright_white(), help.bright_blue()));
-        }
-        strings.push(String::new());
-        strings.join("\n")
-    }
 }
 
 impl From<LexicalError> for Error {
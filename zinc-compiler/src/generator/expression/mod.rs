@@ -14,10 +14,12 @@ use num::One;
 use num::Zero;
 
 use zinc_lexical::Location;
+use zinc_syntax::Identifier;
 use zinc_types::Instruction;
 use zinc_types::LibraryFunctionIdentifier;
 
 use crate::generator::expression::operand::constant::integer::Integer as IntegerConstant;
+use crate::generator::expression::operand::constant::Constant;
 use crate::generator::expression::operand::place::Place;
 use crate::generator::r#type::contract_field::ContractField;
 use crate::generator::r#type::Type;
@@ -81,6 +83,16 @@ impl Expression {
         self.elements.extend(expression.elements);
     }
 
+    ///
+    /// Sets the result type of the enclosing branching expression, if the expression is a
+    /// single diverging block, e.g. a `match` branch ending with a `return` statement.
+    ///
+    pub fn set_diverging_type(&mut self, r#type: Type) {
+        if let [Element::Operand(Operand::Block(ref mut block))] = self.elements.as_mut_slice() {
+            block.set_diverging_type(r#type);
+        }
+    }
+
     ///
     /// Translates an assignment operator into the bytecode.
     ///
@@ -119,6 +131,37 @@ impl Expression {
                     Some(location),
                 );
             }
+            MemoryType::Reference => {
+                let is_indexed = !place.elements.is_empty();
+                let element_size = place.element_size;
+                let total_size = place.total_size;
+                let address = state
+                    .borrow()
+                    .get_variable_address(place.identifier.name.as_str())
+                    .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+
+                state.borrow_mut().push_instruction(
+                    Instruction::Load(zinc_types::Load::new(address, 1)),
+                    Some(location),
+                );
+                if is_indexed {
+                    place.write_to_zinc_vm(state.clone());
+                }
+
+                expression.write_to_zinc_vm(state.clone());
+
+                state.borrow_mut().push_instruction(
+                    if is_indexed {
+                        Instruction::StoreIndirectByIndex(zinc_types::StoreIndirectByIndex::new(
+                            element_size,
+                            total_size,
+                        ))
+                    } else {
+                        Instruction::StoreIndirect(zinc_types::StoreIndirect::new(total_size))
+                    },
+                    Some(location),
+                );
+            }
             MemoryType::ContractStorage { .. } => {
                 let element_size = place.element_size;
                 let total_size = place.total_size;
@@ -275,6 +318,72 @@ impl Expression {
                     Some(location),
                 );
             }
+            MemoryType::Reference => {
+                let is_indexed = !place.elements.is_empty();
+                let address = state
+                    .borrow()
+                    .get_variable_address(place.identifier.name.as_str())
+                    .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+                let element_size = place.element_size;
+                let total_size = place.total_size;
+
+                if is_indexed {
+                    let index_address = state.borrow_mut().define_variable(None, 1);
+                    place.write_to_zinc_vm(state.clone());
+                    state.borrow_mut().push_instruction(
+                        Instruction::Store(zinc_types::Store::new(index_address, 1)),
+                        Some(location),
+                    );
+
+                    for _ in 0..2 {
+                        state.borrow_mut().push_instruction(
+                            Instruction::Load(zinc_types::Load::new(address, 1)),
+                            Some(location),
+                        );
+                        state.borrow_mut().push_instruction(
+                            Instruction::Load(zinc_types::Load::new(index_address, 1)),
+                            Some(location),
+                        );
+                    }
+                    state.borrow_mut().push_instruction(
+                        Instruction::LoadIndirectByIndex(zinc_types::LoadIndirectByIndex::new(
+                            element_size,
+                            total_size,
+                        )),
+                        Some(location),
+                    );
+                } else {
+                    state.borrow_mut().push_instruction(
+                        Instruction::Load(zinc_types::Load::new(address, 1)),
+                        Some(location),
+                    );
+                    state
+                        .borrow_mut()
+                        .push_instruction(Instruction::Copy(zinc_types::Copy), Some(location));
+                    state.borrow_mut().push_instruction(
+                        Instruction::LoadIndirect(zinc_types::LoadIndirect::new(total_size)),
+                        Some(location),
+                    );
+                }
+
+                expression.write_to_zinc_vm(state.clone());
+
+                state
+                    .borrow_mut()
+                    .push_instruction(operation, Some(location));
+
+                state.borrow_mut().push_instruction(
+                    if is_indexed {
+                        Instruction::StoreIndirectByIndex(zinc_types::StoreIndirectByIndex::new(
+                            element_size,
+                            total_size,
+                        ))
+                    } else {
+                        Instruction::StoreIndirect(zinc_types::StoreIndirect::new(total_size))
+                    },
+                    Some(location),
+                );
+            }
             MemoryType::ContractStorage { .. } => {
                 let element_size = place.element_size;
                 let total_size = place.total_size;
@@ -401,6 +510,84 @@ impl Expression {
             .push_instruction(instruction, Some(location));
     }
 
+    ///
+    /// Translates an element-wise comparison of two compound operands of `size` into the bytecode.
+    ///
+    /// The operands are moved to a temporary data stack area, and their scalar elements are
+    /// compared pairwise. The comparison results are folded with the `&&` operator, so `size`
+    /// scalar comparisons and `size - 1` conjunctions are emitted. The `!=` result is the negated
+    /// `==` one.
+    ///
+    fn equals_compound(
+        state: Rc<RefCell<ZincVMState>>,
+        size: usize,
+        is_negated: bool,
+        location: Location,
+    ) {
+        match size {
+            0 => {
+                state.borrow_mut().push_instruction(
+                    Instruction::Push(zinc_types::Push::new(
+                        if is_negated {
+                            BigInt::zero()
+                        } else {
+                            BigInt::one()
+                        },
+                        zinc_types::ScalarType::Boolean,
+                    )),
+                    Some(location),
+                );
+            }
+            1 => Self::binary(
+                state,
+                if is_negated {
+                    Instruction::Ne(zinc_types::Ne)
+                } else {
+                    Instruction::Eq(zinc_types::Eq)
+                },
+                location,
+            ),
+            size => {
+                let address_1 = state.borrow_mut().define_variable(None, size);
+                let address_2 = state.borrow_mut().define_variable(None, size);
+
+                state.borrow_mut().push_instruction(
+                    Instruction::Store(zinc_types::Store::new(address_2, size)),
+                    Some(location),
+                );
+                state.borrow_mut().push_instruction(
+                    Instruction::Store(zinc_types::Store::new(address_1, size)),
+                    Some(location),
+                );
+
+                for index in 0..size {
+                    state.borrow_mut().push_instruction(
+                        Instruction::Load(zinc_types::Load::new(address_1 + index, 1)),
+                        Some(location),
+                    );
+                    state.borrow_mut().push_instruction(
+                        Instruction::Load(zinc_types::Load::new(address_2 + index, 1)),
+                        Some(location),
+                    );
+                    state
+                        .borrow_mut()
+                        .push_instruction(Instruction::Eq(zinc_types::Eq), Some(location));
+                    if index > 0 {
+                        state
+                            .borrow_mut()
+                            .push_instruction(Instruction::And(zinc_types::And), Some(location));
+                    }
+                }
+
+                if is_negated {
+                    state
+                        .borrow_mut()
+                        .push_instruction(Instruction::Not(zinc_types::Not), Some(location));
+                }
+            }
+        }
+    }
+
     ///
     /// Translates an unary operator into the bytecode.
     ///
@@ -466,6 +653,327 @@ impl Expression {
         );
     }
 
+    ///
+    /// Translates the `std::collections::Vec` comparison into the bytecode.
+    ///
+    /// The lengths must be equal, and each element must be either equal or beyond the length of
+    /// the first operand, since the data array elements beyond the length are not meaningful.
+    ///
+    fn equals_vec(
+        state: Rc<RefCell<ZincVMState>>,
+        element_size: usize,
+        capacity: usize,
+        is_negated: bool,
+        location: Location,
+    ) {
+        let size = element_size * capacity + 1;
+        let address_1 = state.borrow_mut().define_variable(None, size);
+        let address_2 = state.borrow_mut().define_variable(None, size);
+
+        state.borrow_mut().push_instruction(
+            Instruction::Store(zinc_types::Store::new(address_2, size)),
+            Some(location),
+        );
+        state.borrow_mut().push_instruction(
+            Instruction::Store(zinc_types::Store::new(address_1, size)),
+            Some(location),
+        );
+
+        let length_1 = address_1 + size - 1;
+        let length_2 = address_2 + size - 1;
+        state.borrow_mut().push_instruction(
+            Instruction::Load(zinc_types::Load::new(length_1, 1)),
+            Some(location),
+        );
+        state.borrow_mut().push_instruction(
+            Instruction::Load(zinc_types::Load::new(length_2, 1)),
+            Some(location),
+        );
+        state
+            .borrow_mut()
+            .push_instruction(Instruction::Eq(zinc_types::Eq), Some(location));
+
+        for index in 0..capacity {
+            for slot in 0..element_size {
+                let offset = index * element_size + slot;
+                state.borrow_mut().push_instruction(
+                    Instruction::Load(zinc_types::Load::new(address_1 + offset, 1)),
+                    Some(location),
+                );
+                state.borrow_mut().push_instruction(
+                    Instruction::Load(zinc_types::Load::new(address_2 + offset, 1)),
+                    Some(location),
+                );
+                state
+                    .borrow_mut()
+                    .push_instruction(Instruction::Eq(zinc_types::Eq), Some(location));
+                if slot > 0 {
+                    state
+                        .borrow_mut()
+                        .push_instruction(Instruction::And(zinc_types::And), Some(location));
+                }
+            }
+
+            IntegerConstant::new(BigInt::from(index), false, zinc_const::bitlength::INDEX)
+                .write_to_zinc_vm(state.clone());
+            state.borrow_mut().push_instruction(
+                Instruction::Load(zinc_types::Load::new(length_1, 1)),
+                Some(location),
+            );
+            state
+                .borrow_mut()
+                .push_instruction(Instruction::Ge(zinc_types::Ge), Some(location));
+            state
+                .borrow_mut()
+                .push_instruction(Instruction::Or(zinc_types::Or), Some(location));
+            state
+                .borrow_mut()
+                .push_instruction(Instruction::And(zinc_types::And), Some(location));
+        }
+
+        if is_negated {
+            state
+                .borrow_mut()
+                .push_instruction(Instruction::Not(zinc_types::Not), Some(location));
+        }
+    }
+
+    ///
+    /// Translates a `std::collections::Vec::new()` function call into the bytecode.
+    ///
+    /// The empty vector is the zero-filled data array followed by the zero length.
+    ///
+    fn call_vec_new(state: Rc<RefCell<ZincVMState>>, r#type: Option<Type>) {
+        if let Some(r#type) = r#type {
+            state.borrow_mut().push_default(r#type);
+        }
+    }
+
+    ///
+    /// Translates a `std::collections::Vec::from_array(...)` function call into the bytecode.
+    ///
+    /// The array is already the vector data, so only the length on the top of the evaluation
+    /// stack is cast to `u64` and checked against the capacity, if it is not constant.
+    ///
+    fn call_vec_from_array(
+        state: Rc<RefCell<ZincVMState>>,
+        capacity: usize,
+        is_checked: bool,
+        location: Location,
+    ) {
+        state.borrow_mut().push_instruction(
+            Instruction::Cast(zinc_types::Cast::new(zinc_types::ScalarType::Integer(
+                zinc_types::IntegerType::new(false, zinc_const::bitlength::INDEX),
+            ))),
+            Some(location),
+        );
+
+        if is_checked {
+            state
+                .borrow_mut()
+                .push_instruction(Instruction::Copy(zinc_types::Copy), Some(location));
+            IntegerConstant::new(BigInt::from(capacity), false, zinc_const::bitlength::INDEX)
+                .write_to_zinc_vm(state.clone());
+            state
+                .borrow_mut()
+                .push_instruction(Instruction::Le(zinc_types::Le), Some(location));
+            state.borrow_mut().push_instruction(
+                Instruction::Require(zinc_types::Require::new(Some(
+                    "vector length exceeds its capacity".to_owned(),
+                ))),
+                Some(location),
+            );
+        }
+    }
+
+    ///
+    /// Translates a `std::collections::Vec::push(...)` function call into the bytecode.
+    ///
+    /// The pushed value is moved from the evaluation stack to a temporary variable, since the
+    /// assignment expects the element index to be evaluated before the value.
+    ///
+    fn call_vec_push(
+        state: Rc<RefCell<ZincVMState>>,
+        element: Place,
+        length: Place,
+        capacity: usize,
+        location: Location,
+    ) {
+        let value_size = element.element_size;
+        let value_name = format!("$push_{}_{}", location.line, location.column);
+        let value_address = state
+            .borrow_mut()
+            .define_variable(Some(value_name.clone()), value_size);
+        state.borrow_mut().push_instruction(
+            Instruction::Store(zinc_types::Store::new(value_address, value_size)),
+            Some(location),
+        );
+
+        Operand::Place(length.clone()).write_to_zinc_vm(state.clone());
+        IntegerConstant::new(BigInt::from(capacity), false, zinc_const::bitlength::INDEX)
+            .write_to_zinc_vm(state.clone());
+        state
+            .borrow_mut()
+            .push_instruction(Instruction::Lt(zinc_types::Lt), Some(location));
+        state.borrow_mut().push_instruction(
+            Instruction::Require(zinc_types::Require::new(Some(
+                "vector capacity exceeded".to_owned(),
+            ))),
+            Some(location),
+        );
+
+        let mut value = Self::new();
+        value.push_operand(Operand::Place(Place {
+            identifier: Identifier::new(location, value_name),
+            element_size: value_size,
+            total_size: value_size,
+            elements: vec![],
+            memory_type: MemoryType::Stack,
+        }));
+        Self::assignment(state.clone(), element, value, location);
+
+        let mut one = Self::new();
+        one.push_operand(Operand::Constant(Constant::Integer(IntegerConstant::new(
+            BigInt::one(),
+            false,
+            zinc_const::bitlength::INDEX,
+        ))));
+        Self::assignment_with_operation(
+            state,
+            length,
+            one,
+            Instruction::Add(zinc_types::Add),
+            location,
+        );
+    }
+
+    ///
+    /// Translates a `std::collections::Vec::pop()` function call into the bytecode.
+    ///
+    /// The popped element is left on the evaluation stack, and its slot is overwritten with the
+    /// zero value, so the removed element does not linger in the vector data.
+    ///
+    fn call_vec_pop(
+        state: Rc<RefCell<ZincVMState>>,
+        element: Place,
+        element_type: Option<Type>,
+        length: Place,
+        location: Location,
+    ) {
+        Operand::Place(length.clone()).write_to_zinc_vm(state.clone());
+        IntegerConstant::new(BigInt::zero(), false, zinc_const::bitlength::INDEX)
+            .write_to_zinc_vm(state.clone());
+        state
+            .borrow_mut()
+            .push_instruction(Instruction::Gt(zinc_types::Gt), Some(location));
+        state.borrow_mut().push_instruction(
+            Instruction::Require(zinc_types::Require::new(Some(
+                "pop from an empty vector".to_owned(),
+            ))),
+            Some(location),
+        );
+
+        Operand::Place(element.clone()).write_to_zinc_vm(state.clone());
+
+        if let Some(element_type) = element_type {
+            let zero_size = element.element_size;
+            let zero_name = format!("$pop_{}_{}", location.line, location.column);
+            let zero_address = state
+                .borrow_mut()
+                .define_variable(Some(zero_name.clone()), zero_size);
+            state.borrow_mut().push_default(element_type);
+            state.borrow_mut().push_instruction(
+                Instruction::Store(zinc_types::Store::new(zero_address, zero_size)),
+                Some(location),
+            );
+
+            let mut zero = Self::new();
+            zero.push_operand(Operand::Place(Place {
+                identifier: Identifier::new(location, zero_name),
+                element_size: zero_size,
+                total_size: zero_size,
+                elements: vec![],
+                memory_type: MemoryType::Stack,
+            }));
+            Self::assignment(state.clone(), element, zero, location);
+        }
+
+        let mut one = Self::new();
+        one.push_operand(Operand::Constant(Constant::Integer(IntegerConstant::new(
+            BigInt::one(),
+            false,
+            zinc_const::bitlength::INDEX,
+        ))));
+        Self::assignment_with_operation(
+            state,
+            length,
+            one,
+            Instruction::Sub(zinc_types::Sub),
+            location,
+        );
+    }
+
+    ///
+    /// Translates a vector index bounds check into the bytecode.
+    ///
+    /// The index on the top of the evaluation stack is copied and compared with the length.
+    ///
+    fn vec_bounds_check(state: Rc<RefCell<ZincVMState>>, length: Place, location: Location) {
+        state
+            .borrow_mut()
+            .push_instruction(Instruction::Copy(zinc_types::Copy), Some(location));
+        Operand::Place(length).write_to_zinc_vm(state.clone());
+        state
+            .borrow_mut()
+            .push_instruction(Instruction::Lt(zinc_types::Lt), Some(location));
+        state.borrow_mut().push_instruction(
+            Instruction::Require(zinc_types::Require::new(Some(
+                "index out of bounds".to_owned(),
+            ))),
+            Some(location),
+        );
+    }
+
+    ///
+    /// Translates a data enumeration variant constructor into the bytecode.
+    ///
+    /// The variant payload is moved from the evaluation stack to a temporary variable, and then
+    /// written back after the tag and the zero-filled payloads of the preceding variants.
+    ///
+    fn enumeration_variant(
+        state: Rc<RefCell<ZincVMState>>,
+        tag: IntegerConstant,
+        prefix: Vec<Type>,
+        size: usize,
+        suffix: Vec<Type>,
+        location: Location,
+    ) {
+        let address = if size > 0 {
+            let address = state.borrow_mut().define_variable(None, size);
+            state.borrow_mut().push_instruction(
+                Instruction::Store(zinc_types::Store::new(address, size)),
+                Some(location),
+            );
+            Some(address)
+        } else {
+            None
+        };
+
+        tag.write_to_zinc_vm(state.clone());
+        for r#type in prefix.into_iter() {
+            state.borrow_mut().push_default(r#type);
+        }
+        if let Some(address) = address {
+            state.borrow_mut().push_instruction(
+                Instruction::Load(zinc_types::Load::new(address, size)),
+                Some(location),
+            );
+        }
+        for r#type in suffix.into_iter() {
+            state.borrow_mut().push_default(r#type);
+        }
+    }
+
     ///
     /// Translates a standard library function call into the bytecode.
     ///
@@ -671,6 +1179,20 @@ impl IBytecodeWritable for Expression {
                     Operator::NotEquals { .. } => {
                         Self::binary(state.clone(), Instruction::Ne(zinc_types::Ne), location)
                     }
+                    Operator::EqualsCompound { size } => {
+                        Self::equals_compound(state.clone(), size, false, location)
+                    }
+                    Operator::NotEqualsCompound { size } => {
+                        Self::equals_compound(state.clone(), size, true, location)
+                    }
+                    Operator::EqualsVec {
+                        element_size,
+                        capacity,
+                    } => Self::equals_vec(state.clone(), element_size, capacity, false, location),
+                    Operator::NotEqualsVec {
+                        element_size,
+                        capacity,
+                    } => Self::equals_vec(state.clone(), element_size, capacity, true, location),
                     Operator::GreaterEquals { .. } => {
                         Self::binary(state.clone(), Instruction::Ge(zinc_types::Ge), location)
                     }
@@ -834,6 +1356,39 @@ impl IBytecodeWritable for Expression {
                         output_size,
                         location,
                     ),
+
+                    Operator::CallVecNew { r#type } => Self::call_vec_new(state.clone(), r#type),
+                    Operator::CallVecFromArray {
+                        capacity,
+                        is_checked,
+                    } => Self::call_vec_from_array(state.clone(), capacity, is_checked, location),
+                    Operator::CallVecPush {
+                        element,
+                        length,
+                        capacity,
+                    } => Self::call_vec_push(state.clone(), element, length, capacity, location),
+                    Operator::CallVecPop {
+                        element,
+                        element_type,
+                        length,
+                    } => Self::call_vec_pop(state.clone(), element, element_type, length, location),
+                    Operator::VecBoundsCheck { length } => {
+                        Self::vec_bounds_check(state.clone(), length, location)
+                    }
+
+                    Operator::EnumerationVariant {
+                        tag,
+                        prefix,
+                        size,
+                        suffix,
+                    } => Self::enumeration_variant(
+                        state.clone(),
+                        tag,
+                        prefix,
+                        size,
+                        suffix,
+                        location,
+                    ),
                 },
             }
         }
@@ -4,6 +4,7 @@
 
 use crate::generator::expression::operand::block::Expression as BlockExpression;
 use crate::generator::expression::Expression as GeneratorExpression;
+use crate::generator::r#type::Type;
 use crate::generator::statement::Statement;
 
 ///
@@ -11,18 +12,23 @@ use crate::generator::statement::Statement;
 ///
 #[derive(Debug, Default, Clone)]
 pub struct Builder {
-    /// The block statements.
-    statements: Vec<Statement>,
+    /// The block statements with flags, whether they contain early exit statements.
+    statements: Vec<(Statement, bool)>,
     /// The optional block expressions, whose type is defaulted to `()` if unset.
     expression: Option<GeneratorExpression>,
+    /// The block expression type, which is required if the expression can be skipped.
+    expression_type: Option<Type>,
 }
 
 impl Builder {
     ///
     /// Pushes a block statement.
     ///
-    pub fn push_statement(&mut self, value: Statement) {
-        self.statements.push(value);
+    /// If `is_exiting` is set, the statement contains some `return`, `break`, or `continue`
+    /// statements, and the subsequent ones must be guarded.
+    ///
+    pub fn push_statement(&mut self, value: Statement, is_exiting: bool) {
+        self.statements.push((value, is_exiting));
     }
 
     ///
@@ -32,10 +38,26 @@ impl Builder {
         self.expression = Some(value);
     }
 
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_expression_type(&mut self, value: Type) {
+        self.expression_type = Some(value);
+    }
+
+    ///
+    /// Checks if some of the pushed statements contain early exit statements.
+    ///
+    pub fn is_exiting(&self) -> bool {
+        self.statements
+            .iter()
+            .any(|(_statement, is_exiting)| *is_exiting)
+    }
+
     ///
     /// Finilizes the builder and returns the built item.
     ///
     pub fn finish(self) -> BlockExpression {
-        BlockExpression::new(self.statements, self.expression)
+        BlockExpression::new(self.statements, self.expression, self.expression_type)
     }
 }
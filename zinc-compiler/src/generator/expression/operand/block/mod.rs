@@ -7,7 +7,12 @@ pub mod builder;
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use zinc_lexical::Location;
+use zinc_types::Instruction;
+
 use crate::generator::expression::Expression as GeneratorExpression;
+use crate::generator::r#type::Type;
+use crate::generator::statement::r#return::Statement as ReturnStatement;
 use crate::generator::statement::Statement;
 use crate::generator::zinc_vm::State as ZincVMState;
 use crate::generator::IBytecodeWritable;
@@ -17,31 +22,104 @@ use crate::generator::IBytecodeWritable;
 ///
 #[derive(Debug, Clone)]
 pub struct Expression {
-    /// The block statements.
-    statements: Vec<Statement>,
+    /// The block statements with flags, whether they contain early exit statements.
+    statements: Vec<(Statement, bool)>,
     /// The optional block expressions, whose type is defaulted to `()` if unset.
     expression: Option<GeneratorExpression>,
+    /// The block expression type, which is set if the expression can be skipped after an early exit.
+    expression_type: Option<Type>,
 }
 
 impl Expression {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new(statements: Vec<Statement>, expression: Option<GeneratorExpression>) -> Self {
+    pub fn new(
+        statements: Vec<(Statement, bool)>,
+        expression: Option<GeneratorExpression>,
+        expression_type: Option<Type>,
+    ) -> Self {
         Self {
             statements,
             expression,
+            expression_type,
         }
     }
+
+    ///
+    /// Converts the block result expression into a `return` statement.
+    ///
+    /// Used for function bodies with early `return` statements, where the result is written
+    /// to the function result slot instead of the evaluation stack.
+    ///
+    pub fn set_returning(&mut self, location: Location) {
+        if let Some(expression) = self.expression.take() {
+            self.statements.push((
+                Statement::Return(ReturnStatement::new(location, Some(expression))),
+                false,
+            ));
+        }
+        self.expression_type = None;
+    }
+
+    ///
+    /// Sets the result type of the enclosing branching expression, if the block diverges.
+    ///
+    /// The diverging block pushes the default value of the type instead of its result,
+    /// so the evaluation stacks of all branches have the same layout.
+    ///
+    pub fn set_diverging_type(&mut self, r#type: Type) {
+        if self.is_diverging() {
+            self.expression_type = Some(r#type);
+        }
+    }
+
+    ///
+    /// Checks if the block has no result expression and ends with an early exit statement.
+    ///
+    fn is_diverging(&self) -> bool {
+        self.expression.is_none()
+            && matches!(
+                self.statements.last(),
+                Some((Statement::Return(_), _))
+                    | Some((Statement::Break(_), _))
+                    | Some((Statement::Continue(_), _))
+            )
+    }
 }
 
 impl IBytecodeWritable for Expression {
     fn write_to_zinc_vm(self, state: Rc<RefCell<ZincVMState>>) {
-        for statement in self.statements.into_iter() {
+        let mut guards_count = 0;
+        let mut statements = self.statements.into_iter().peekable();
+        while let Some((statement, is_exiting)) = statements.next() {
             statement.write_to_zinc_vm(state.clone());
+
+            let is_followed = statements.peek().is_some() || self.expression.is_some();
+            if is_exiting && is_followed {
+                state.borrow_mut().push_early_exit_guard();
+                guards_count += 1;
+            }
         }
-        if let Some(expression) = self.expression {
-            expression.write_to_zinc_vm(state);
+
+        match (self.expression, self.expression_type.as_ref()) {
+            (Some(expression), _) => expression.write_to_zinc_vm(state.clone()),
+            (None, Some(expression_type)) => {
+                state.borrow_mut().push_default(expression_type.to_owned())
+            }
+            (None, None) => {}
+        }
+
+        for _ in 0..guards_count {
+            if let Some(ref expression_type) = self.expression_type {
+                state
+                    .borrow_mut()
+                    .push_instruction(Instruction::Else(zinc_types::Else), None);
+                state.borrow_mut().push_default(expression_type.to_owned());
+            }
+            state
+                .borrow_mut()
+                .push_instruction(Instruction::EndIf(zinc_types::EndIf), None);
         }
     }
 }
@@ -3,6 +3,7 @@
 //!
 
 use crate::generator::expression::operand::constant::Constant;
+use crate::generator::expression::operand::r#match::pattern::Pattern;
 use crate::generator::expression::operand::r#match::Expression as MatchExpression;
 use crate::generator::expression::Expression as GeneratorExpression;
 use crate::generator::r#type::Type;
@@ -19,12 +20,15 @@ pub struct Builder {
     scrutinee: Option<GeneratorExpression>,
     /// The scrutinee (matched) expression type.
     scrutinee_type: Option<Type>,
-    /// The branches ordered array, where each branch consists of a pattern and result expression.
-    branches: Vec<(Constant, GeneratorExpression)>,
+    /// The branches ordered array, where each branch consists of a pattern, optional guard
+    /// condition, and result expression.
+    branches: Vec<(Pattern, Option<GeneratorExpression>, GeneratorExpression)>,
     /// The binding branch, which is the last fallback branch.
     binding_branch: Option<(GeneratorExpression, String)>,
     /// The wildcard `_` branch, which is the last fallback branch. Ignored if `binding_branch` is set.
     wildcard_branch: Option<GeneratorExpression>,
+    /// The destructuring fallback branch. Ignored if `binding_branch` or `wildcard_branch` is set.
+    fallback_branch: Option<(Pattern, GeneratorExpression)>,
 }
 
 impl Builder {
@@ -47,7 +51,28 @@ impl Builder {
     /// Pushes a branch, which consists of a `pattern` and `expression`.
     ///
     pub fn push_branch(&mut self, pattern: Constant, expression: GeneratorExpression) {
-        self.branches.push((pattern, expression));
+        self.branches
+            .push((Pattern::from(pattern), None, expression));
+    }
+
+    ///
+    /// Pushes a destructuring branch, which consists of a `pattern` and `expression`.
+    ///
+    pub fn push_pattern_branch(&mut self, pattern: Pattern, expression: GeneratorExpression) {
+        self.branches.push((pattern, None, expression));
+    }
+
+    ///
+    /// Pushes a destructuring branch, which is taken only if the `guard` condition is true
+    /// after binding the `pattern` variables.
+    ///
+    pub fn push_guarded_branch(
+        &mut self,
+        pattern: Pattern,
+        guard: GeneratorExpression,
+        expression: GeneratorExpression,
+    ) {
+        self.branches.push((pattern, Some(guard), expression));
     }
 
     ///
@@ -64,6 +89,37 @@ impl Builder {
         self.wildcard_branch = Some(value);
     }
 
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_fallback_branch(&mut self, pattern: Pattern, expression: GeneratorExpression) {
+        self.fallback_branch = Some((pattern, expression));
+    }
+
+    ///
+    /// Sets the `match` expression result type, which is pushed by the diverging branches.
+    ///
+    pub fn set_diverging_type(&mut self, r#type: Type) {
+        let branches = self
+            .branches
+            .iter_mut()
+            .map(|(_pattern, _guard, expression)| expression)
+            .chain(
+                self.binding_branch
+                    .iter_mut()
+                    .map(|(expression, _name)| expression),
+            )
+            .chain(self.wildcard_branch.iter_mut())
+            .chain(
+                self.fallback_branch
+                    .iter_mut()
+                    .map(|(_pattern, expression)| expression),
+            );
+        for expression in branches {
+            expression.set_diverging_type(r#type.clone());
+        }
+    }
+
     ///
     /// Finilizes the builder and returns the built item.
     ///
@@ -91,32 +147,31 @@ impl Builder {
             )
         });
 
-        match self.binding_branch.take() {
-            Some(binding_branch) => MatchExpression::new(
-                location,
-                scrutinee,
-                scrutinee_type,
-                self.branches,
-                Some(binding_branch),
-                None,
-            ),
-            None => {
-                let wildcard_branch = self.wildcard_branch.take().unwrap_or_else(|| {
-                    panic!(
-                        "{}{}",
-                        zinc_const::panic::BUILDER_REQUIRES_VALUE,
-                        "wildcard branch"
-                    )
-                });
-                MatchExpression::new(
-                    location,
-                    scrutinee,
-                    scrutinee_type,
-                    self.branches,
-                    None,
-                    Some(wildcard_branch),
-                )
+        let fallback_branch = match (
+            self.binding_branch.take(),
+            self.wildcard_branch.take(),
+            self.fallback_branch.take(),
+        ) {
+            (Some((expression, name)), _, _) => {
+                let mut pattern = Pattern::default();
+                pattern.push_binding(name, 0, scrutinee_type.size());
+                (pattern, expression)
             }
-        }
+            (None, Some(expression), _) => (Pattern::default(), expression),
+            (None, None, Some(fallback_branch)) => fallback_branch,
+            (None, None, None) => panic!(
+                "{}{}",
+                zinc_const::panic::BUILDER_REQUIRES_VALUE,
+                "fallback branch"
+            ),
+        };
+
+        MatchExpression::new(
+            location,
+            scrutinee,
+            scrutinee_type,
+            self.branches,
+            fallback_branch,
+        )
     }
 }
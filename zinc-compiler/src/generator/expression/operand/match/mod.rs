@@ -3,19 +3,22 @@
 //!
 
 pub mod builder;
+pub mod pattern;
 
 use std::cell::RefCell;
 use std::rc::Rc;
 
 use zinc_types::Instruction;
 
-use crate::generator::expression::operand::constant::Constant;
+use crate::generator::expression::operand::constant::boolean::Boolean as BooleanConstant;
 use crate::generator::expression::Expression as GeneratorExpression;
 use crate::generator::r#type::Type;
 use crate::generator::zinc_vm::State as ZincVMState;
 use crate::generator::IBytecodeWritable;
 use zinc_lexical::Location;
 
+use self::pattern::Pattern;
+
 ///
 /// The match expression.
 ///
@@ -27,12 +30,12 @@ pub struct Expression {
     scrutinee: GeneratorExpression,
     /// The scrutinee (matched) expression type.
     scrutinee_type: Type,
-    /// The branches ordered array, where each branch consists of a pattern and result expression.
-    branches: Vec<(Constant, GeneratorExpression)>,
-    /// The binding branch, which is the last fallback branch.
-    binding_branch: Option<(GeneratorExpression, String)>,
-    /// The wildcard `_` branch, which is the last fallback branch. Ignored if `binding_branch` is set.
-    wildcard_branch: Option<GeneratorExpression>,
+    /// The branches ordered array, where each branch consists of a pattern, optional guard
+    /// condition, and result expression.
+    branches: Vec<(Pattern, Option<GeneratorExpression>, GeneratorExpression)>,
+    /// The fallback branch, which is taken if no other branch matches, so its pattern
+    /// conditions are not checked.
+    fallback_branch: (Pattern, GeneratorExpression),
 }
 
 impl Expression {
@@ -43,17 +46,15 @@ impl Expression {
         location: Location,
         scrutinee: GeneratorExpression,
         scrutinee_type: Type,
-        branches: Vec<(Constant, GeneratorExpression)>,
-        binding_branch: Option<(GeneratorExpression, String)>,
-        wildcard_branch: Option<GeneratorExpression>,
+        branches: Vec<(Pattern, Option<GeneratorExpression>, GeneratorExpression)>,
+        fallback_branch: (Pattern, GeneratorExpression),
     ) -> Self {
         Self {
             location,
             scrutinee,
             scrutinee_type,
             branches,
-            binding_branch,
-            wildcard_branch,
+            fallback_branch,
         }
     }
 }
@@ -63,14 +64,7 @@ impl IBytecodeWritable for Expression {
         let branch_count = self.branches.len();
         let scrutinee_size = self.scrutinee_type.size();
 
-        let (binding_branch, binding_name) = match self.binding_branch {
-            Some((binding_branch, binding_name)) => (Some(binding_branch), Some(binding_name)),
-            None => (None, None),
-        };
-
-        let scrutinee_address = state
-            .borrow_mut()
-            .define_variable(binding_name, scrutinee_size);
+        let scrutinee_address = state.borrow_mut().define_variable(None, scrutinee_size);
 
         self.scrutinee.write_to_zinc_vm(state.clone());
         state.borrow_mut().push_instruction(
@@ -78,34 +72,58 @@ impl IBytecodeWritable for Expression {
             Some(self.location),
         );
 
-        for (branch_pattern, branch_expression) in self.branches.into_iter() {
-            state.borrow_mut().push_instruction(
-                Instruction::Load(zinc_types::Load::new(scrutinee_address, scrutinee_size)),
-                Some(self.location),
-            );
-            branch_pattern.write_to_zinc_vm(state.clone());
-            state
-                .borrow_mut()
-                .push_instruction(Instruction::Eq(zinc_types::Eq), Some(self.location));
-            state
-                .borrow_mut()
-                .push_instruction(Instruction::If(zinc_types::If), Some(self.location));
+        for (branch_pattern, branch_guard, branch_expression) in self.branches.into_iter() {
+            match branch_guard {
+                Some(guard) => {
+                    let flag_address = state.borrow_mut().define_variable(None, 1);
+                    BooleanConstant::new(false).write_to_zinc_vm(state.clone());
+                    state.borrow_mut().push_instruction(
+                        Instruction::Store(zinc_types::Store::new(flag_address, 1)),
+                        Some(self.location),
+                    );
+
+                    branch_pattern.write_condition(state.clone(), scrutinee_address, self.location);
+                    state
+                        .borrow_mut()
+                        .push_instruction(Instruction::If(zinc_types::If), Some(self.location));
+                    branch_pattern.write_bindings(state.clone(), scrutinee_address, self.location);
+                    guard.write_to_zinc_vm(state.clone());
+                    state.borrow_mut().push_instruction(
+                        Instruction::Store(zinc_types::Store::new(flag_address, 1)),
+                        Some(self.location),
+                    );
+                    state.borrow_mut().push_instruction(
+                        Instruction::EndIf(zinc_types::EndIf),
+                        Some(self.location),
+                    );
+
+                    state.borrow_mut().push_instruction(
+                        Instruction::Load(zinc_types::Load::new(flag_address, 1)),
+                        Some(self.location),
+                    );
+                    state
+                        .borrow_mut()
+                        .push_instruction(Instruction::If(zinc_types::If), Some(self.location));
+                }
+                None => {
+                    branch_pattern.write_condition(state.clone(), scrutinee_address, self.location);
+                    state
+                        .borrow_mut()
+                        .push_instruction(Instruction::If(zinc_types::If), Some(self.location));
+                    branch_pattern.write_bindings(state.clone(), scrutinee_address, self.location);
+                }
+            }
             branch_expression.write_to_zinc_vm(state.clone());
             state
                 .borrow_mut()
                 .push_instruction(Instruction::Else(zinc_types::Else), Some(self.location));
         }
 
-        if let Some(binding_branch) = binding_branch {
-            binding_branch.write_to_zinc_vm(state.clone());
-        } else if let Some(wildcard_branch) = self.wildcard_branch {
-            wildcard_branch.write_to_zinc_vm(state.clone());
-        }
+        let (fallback_pattern, fallback_expression) = self.fallback_branch;
+        fallback_pattern.write_bindings(state.clone(), scrutinee_address, self.location);
+        fallback_expression.write_to_zinc_vm(state.clone());
 
-        state
-            .borrow_mut()
-            .push_instruction(Instruction::EndIf(zinc_types::EndIf), Some(self.location));
-        for _ in 0..branch_count - 1 {
+        for _ in 0..branch_count {
             state
                 .borrow_mut()
                 .push_instruction(Instruction::EndIf(zinc_types::EndIf), Some(self.location));
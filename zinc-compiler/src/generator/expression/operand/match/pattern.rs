@@ -0,0 +1,269 @@
+//!
+//! The generator expression match operand pattern.
+//!
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use zinc_lexical::Location;
+use zinc_types::Instruction;
+
+use crate::generator::expression::operand::constant::boolean::Boolean as BooleanConstant;
+use crate::generator::expression::operand::constant::Constant;
+use crate::generator::zinc_vm::State as ZincVMState;
+use crate::generator::IBytecodeWritable;
+
+///
+/// The match branch pattern, which is lowered to the scrutinee scalar comparisons and bindings.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Pattern {
+    /// The conditions, which the scrutinee must satisfy to match the pattern.
+    conditions: Vec<Condition>,
+    /// The variables, which are bound to the scrutinee parts at the specified offsets
+    /// with the specified sizes.
+    bindings: Vec<(String, usize, usize)>,
+}
+
+///
+/// The match pattern condition on the scrutinee scalars.
+///
+#[derive(Debug, Clone)]
+pub enum Condition {
+    /// The scrutinee scalar at `offset` must be equal to `constant`.
+    Equal {
+        /// The scalar offset within the scrutinee.
+        offset: usize,
+        /// The constant to compare with.
+        constant: Constant,
+    },
+    /// The scrutinee scalar at `offset` must be within the inclusive range.
+    Range {
+        /// The scalar offset within the scrutinee.
+        offset: usize,
+        /// The range start constant.
+        start: Constant,
+        /// The inclusive range end constant.
+        end: Constant,
+    },
+    /// The scrutinee must match at least one of the alternatives.
+    Alternatives(Vec<Pattern>),
+}
+
+impl Pattern {
+    ///
+    /// Pushes a condition, which requires the scrutinee scalar at `offset` to be equal to `constant`.
+    ///
+    pub fn push_condition(&mut self, offset: usize, constant: Constant) {
+        self.conditions.push(Condition::Equal { offset, constant });
+    }
+
+    ///
+    /// Pushes a condition, which requires the scrutinee scalar at `offset` to be within
+    /// the inclusive range from `start` to `end`.
+    ///
+    pub fn push_range_condition(&mut self, offset: usize, start: Constant, end: Constant) {
+        self.conditions
+            .push(Condition::Range { offset, start, end });
+    }
+
+    ///
+    /// Pushes a condition, which requires the scrutinee to match any of the `alternatives`.
+    ///
+    /// All the alternatives must bind the same variables.
+    ///
+    pub fn push_alternatives(&mut self, alternatives: Vec<Self>) {
+        self.conditions.push(Condition::Alternatives(alternatives));
+    }
+
+    ///
+    /// Pushes a binding of the scrutinee part at `offset` with `size` to the variable `name`.
+    ///
+    pub fn push_binding(&mut self, name: String, offset: usize, size: usize) {
+        self.bindings.push((name, offset, size));
+    }
+
+    ///
+    /// Writes the boolean condition, which is true if the scrutinee at `address` matches
+    /// the pattern.
+    ///
+    pub fn write_condition(
+        &self,
+        state: Rc<RefCell<ZincVMState>>,
+        address: usize,
+        location: Location,
+    ) {
+        if self.conditions.is_empty() {
+            BooleanConstant::new(true).write_to_zinc_vm(state);
+            return;
+        }
+
+        for (index, condition) in self.conditions.iter().enumerate() {
+            match condition {
+                Condition::Equal { offset, constant } => {
+                    state.borrow_mut().push_instruction(
+                        Instruction::Load(zinc_types::Load::new(address + offset, 1)),
+                        Some(location),
+                    );
+                    constant.to_owned().write_to_zinc_vm(state.clone());
+                    state
+                        .borrow_mut()
+                        .push_instruction(Instruction::Eq(zinc_types::Eq), Some(location));
+                }
+                Condition::Range { offset, start, end } => {
+                    state.borrow_mut().push_instruction(
+                        Instruction::Load(zinc_types::Load::new(address + offset, 1)),
+                        Some(location),
+                    );
+                    start.to_owned().write_to_zinc_vm(state.clone());
+                    state
+                        .borrow_mut()
+                        .push_instruction(Instruction::Ge(zinc_types::Ge), Some(location));
+                    state.borrow_mut().push_instruction(
+                        Instruction::Load(zinc_types::Load::new(address + offset, 1)),
+                        Some(location),
+                    );
+                    end.to_owned().write_to_zinc_vm(state.clone());
+                    state
+                        .borrow_mut()
+                        .push_instruction(Instruction::Le(zinc_types::Le), Some(location));
+                    state
+                        .borrow_mut()
+                        .push_instruction(Instruction::And(zinc_types::And), Some(location));
+                }
+                Condition::Alternatives(alternatives) => {
+                    for (index, alternative) in alternatives.iter().enumerate() {
+                        alternative.write_condition(state.clone(), address, location);
+                        if index > 0 {
+                            state
+                                .borrow_mut()
+                                .push_instruction(Instruction::Or(zinc_types::Or), Some(location));
+                        }
+                    }
+                }
+            }
+
+            if index > 0 {
+                state
+                    .borrow_mut()
+                    .push_instruction(Instruction::And(zinc_types::And), Some(location));
+            }
+        }
+    }
+
+    ///
+    /// Copies the bound parts of the scrutinee at `address` to the binding variables.
+    ///
+    pub fn write_bindings(
+        self,
+        state: Rc<RefCell<ZincVMState>>,
+        address: usize,
+        location: Location,
+    ) {
+        let mut binding_addresses = HashMap::new();
+        for (name, size) in self.names().into_iter() {
+            let binding_address = state.borrow_mut().define_variable(Some(name.clone()), size);
+            binding_addresses.insert(name, binding_address);
+        }
+
+        self.write_stores(state, address, &binding_addresses, location);
+    }
+
+    ///
+    /// Returns the names and sizes of the variables bound by the pattern.
+    ///
+    /// The alternatives bind the same variables, so only the first one is inspected.
+    ///
+    fn names(&self) -> Vec<(String, usize)> {
+        let mut names: Vec<(String, usize)> = self
+            .bindings
+            .iter()
+            .map(|(name, _offset, size)| (name.to_owned(), *size))
+            .collect();
+
+        for condition in self.conditions.iter() {
+            if let Condition::Alternatives(alternatives) = condition {
+                if let Some(alternative) = alternatives.first() {
+                    names.extend(alternative.names());
+                }
+            }
+        }
+
+        names
+    }
+
+    ///
+    /// Stores the bound parts of the scrutinee at `address` to the variables at
+    /// `binding_addresses`.
+    ///
+    /// The alternatives are written in the reverse order, each one conditionally except
+    /// for the last, so the variables are bound by the first matching alternative.
+    ///
+    fn write_stores(
+        &self,
+        state: Rc<RefCell<ZincVMState>>,
+        address: usize,
+        binding_addresses: &HashMap<String, usize>,
+        location: Location,
+    ) {
+        for (name, offset, size) in self.bindings.iter() {
+            if *size == 0 {
+                continue;
+            }
+
+            let binding_address = binding_addresses
+                .get(name)
+                .copied()
+                .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+            state.borrow_mut().push_instruction(
+                Instruction::Load(zinc_types::Load::new(address + offset, *size)),
+                Some(location),
+            );
+            state.borrow_mut().push_instruction(
+                Instruction::Store(zinc_types::Store::new(binding_address, *size)),
+                Some(location),
+            );
+        }
+
+        for condition in self.conditions.iter() {
+            if let Condition::Alternatives(alternatives) = condition {
+                if let Some((last, others)) = alternatives.split_last() {
+                    if last.names().is_empty() {
+                        continue;
+                    }
+
+                    last.write_stores(state.clone(), address, binding_addresses, location);
+                    for alternative in others.iter().rev() {
+                        alternative.write_condition(state.clone(), address, location);
+                        state
+                            .borrow_mut()
+                            .push_instruction(Instruction::If(zinc_types::If), Some(location));
+                        alternative.write_stores(
+                            state.clone(),
+                            address,
+                            binding_addresses,
+                            location,
+                        );
+                        state.borrow_mut().push_instruction(
+                            Instruction::EndIf(zinc_types::EndIf),
+                            Some(location),
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl From<Constant> for Pattern {
+    fn from(constant: Constant) -> Self {
+        Self {
+            conditions: vec![Condition::Equal {
+                offset: 0,
+                constant,
+            }],
+            bindings: vec![],
+        }
+    }
+}
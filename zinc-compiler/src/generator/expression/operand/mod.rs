@@ -44,6 +44,8 @@ pub enum Operand {
     Constant(Constant),
     /// The memory descriptor operand.
     Place(Place),
+    /// The mutable reference operand, which is passed to a function argument.
+    Reference(Place),
     /// The array literal expression operand.
     Array(ArrayExpression),
     /// The variable group (usually tuple or structure fields) expression operand.
@@ -91,6 +93,37 @@ impl IBytecodeWritable for Operand {
                         );
                     }
                 }
+                MemoryType::Reference => {
+                    let location = inner.identifier.location;
+                    let element_size = inner.element_size;
+                    let total_size = inner.total_size;
+                    let address = state
+                        .borrow()
+                        .get_variable_address(inner.identifier.name.as_str())
+                        .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+
+                    let is_indexed = !inner.elements.is_empty();
+
+                    state.borrow_mut().push_instruction(
+                        Instruction::Load(zinc_types::Load::new(address, 1)),
+                        Some(location),
+                    );
+                    if is_indexed {
+                        inner.write_to_zinc_vm(state.clone());
+                        state.borrow_mut().push_instruction(
+                            Instruction::LoadIndirectByIndex(zinc_types::LoadIndirectByIndex::new(
+                                element_size,
+                                total_size,
+                            )),
+                            Some(location),
+                        );
+                    } else {
+                        state.borrow_mut().push_instruction(
+                            Instruction::LoadIndirect(zinc_types::LoadIndirect::new(total_size)),
+                            Some(location),
+                        );
+                    }
+                }
                 MemoryType::ContractStorage { .. } => {
                     let location = inner.identifier.location;
                     let element_size = inner.element_size;
@@ -145,6 +178,7 @@ impl IBytecodeWritable for Operand {
                     }
                 }
             },
+            Self::Reference(inner) => inner.write_reference(state),
             Self::Array(inner) => inner.write_to_zinc_vm(state),
             Self::Group(inner) => inner.write_to_zinc_vm(state),
             Self::List(inner) => inner.write_to_zinc_vm(state),
@@ -6,6 +6,7 @@ use std::cell::RefCell;
 use std::rc::Rc;
 
 use num::BigInt;
+use num::ToPrimitive;
 use num::Zero;
 
 use zinc_syntax::Identifier;
@@ -36,6 +37,88 @@ pub struct Place {
     pub memory_type: MemoryType,
 }
 
+impl Place {
+    ///
+    /// Writes the absolute data stack address of the place, which is passed to the functions
+    /// taking mutable references.
+    ///
+    pub fn write_reference(self, state: Rc<RefCell<ZincVMState>>) {
+        let location = self.identifier.location;
+        let offset = self.offset();
+        let address = state
+            .borrow()
+            .get_variable_address(self.identifier.name.as_str())
+            .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+
+        match self.memory_type {
+            MemoryType::Stack => {
+                state.borrow_mut().push_instruction(
+                    Instruction::Reference(zinc_types::Reference::new(
+                        address + offset,
+                        self.element_size,
+                    )),
+                    Some(location),
+                );
+            }
+            MemoryType::Reference => {
+                state.borrow_mut().push_instruction(
+                    Instruction::Load(zinc_types::Load::new(address, 1)),
+                    Some(location),
+                );
+
+                if offset > 0 {
+                    IntegerConstant::new(BigInt::from(offset), false, zinc_const::bitlength::FIELD)
+                        .write_to_zinc_vm(state.clone());
+                    state
+                        .borrow_mut()
+                        .push_instruction(Instruction::Add(zinc_types::Add), Some(location));
+                }
+            }
+            MemoryType::ContractStorage { .. } => {
+                panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS)
+            }
+        }
+    }
+
+    ///
+    /// Calculates the place offset from the variable start address.
+    ///
+    /// Only the places with constant indexes can be referenced, which is checked by the
+    /// semantic analyzer.
+    ///
+    fn offset(&self) -> usize {
+        self.elements
+            .iter()
+            .map(|element| match element {
+                SemanticPlaceElement::IndexConstant { constant, access } => {
+                    constant
+                        .value
+                        .to_usize()
+                        .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS)
+                        * access.element_size
+                }
+                SemanticPlaceElement::IndexRange { start, access, .. } => {
+                    start
+                        .to_usize()
+                        .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS)
+                        * access.element_size
+                }
+                SemanticPlaceElement::IndexRangeInclusive { start, access, .. } => {
+                    start
+                        .to_usize()
+                        .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS)
+                        * access.element_size
+                }
+                SemanticPlaceElement::StackField { access } => access.offset,
+                SemanticPlaceElement::IndexExpression { .. }
+                | SemanticPlaceElement::ContractField { .. } => {
+                    panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS)
+                }
+            })
+            .sum()
+    }
+}
+
 impl IBytecodeWritable for Place {
     fn write_to_zinc_vm(self, state: Rc<RefCell<ZincVMState>>) {
         if !self.elements.is_empty() {
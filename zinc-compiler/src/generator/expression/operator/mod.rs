@@ -4,12 +4,15 @@
 
 use zinc_types::LibraryFunctionIdentifier;
 
+use crate::generator::expression::operand::constant::integer::Integer as IntegerConstant;
 use crate::generator::expression::operand::place::Place;
 use crate::generator::expression::Expression;
 use crate::generator::r#type::contract_field::ContractField;
 use crate::generator::r#type::Type;
 use crate::semantic::element::access::dot::stack_field::StackField as StackFieldAccess;
 use crate::semantic::element::access::index::Index as IndexAccess;
+use crate::semantic::element::r#type::enumeration::payload::Payload as SemanticEnumerationPayload;
+use crate::semantic::element::r#type::enumeration::Enumeration as SemanticEnumerationType;
 use crate::semantic::element::r#type::Type as SemanticType;
 
 ///
@@ -147,6 +150,34 @@ pub enum Operator {
         /// The type to cast the second operand into. Present only for integer literals.
         operand_2_inferred_type: Option<Type>,
     },
+    /// The binary `==` comparison operator applied to compound operands, which are compared
+    /// element-wise.
+    EqualsCompound {
+        /// The flat size of each operand.
+        size: usize,
+    },
+    /// The binary `!=` comparison operator applied to compound operands, which are compared
+    /// element-wise.
+    NotEqualsCompound {
+        /// The flat size of each operand.
+        size: usize,
+    },
+    /// The binary `==` comparison operator applied to `std::collections::Vec` operands, whose
+    /// lengths and elements within the length are compared.
+    EqualsVec {
+        /// The flat size of the vector element.
+        element_size: usize,
+        /// The vector capacity.
+        capacity: usize,
+    },
+    /// The binary `!=` comparison operator applied to `std::collections::Vec` operands, whose
+    /// lengths and elements within the length are compared.
+    NotEqualsVec {
+        /// The flat size of the vector element.
+        element_size: usize,
+        /// The vector capacity.
+        capacity: usize,
+    },
     /// The binary `>=` comparison operator.
     GreaterEquals {
         /// The type to cast the first operand into. Present only for integer literals.
@@ -300,6 +331,55 @@ pub enum Operator {
         /// The function result type size.
         output_size: usize,
     },
+
+    /// The `std::collections::Vec::new` function call operator.
+    CallVecNew {
+        /// The vector type, whose zero value is the empty vector.
+        r#type: Option<Type>,
+    },
+    /// The `std::collections::Vec::from_array` function call operator.
+    CallVecFromArray {
+        /// The vector capacity, that is, the array size.
+        capacity: usize,
+        /// Whether the length must be checked against the capacity at runtime.
+        is_checked: bool,
+    },
+    /// The `std::collections::Vec::push` function call operator.
+    CallVecPush {
+        /// The vector data array element at the vector length.
+        element: Place,
+        /// The vector length.
+        length: Place,
+        /// The vector capacity, which must not be exceeded.
+        capacity: usize,
+    },
+    /// The `std::collections::Vec::pop` function call operator.
+    CallVecPop {
+        /// The vector data array element preceding the vector length.
+        element: Place,
+        /// The vector element type, whose zero value overwrites the popped element.
+        element_type: Option<Type>,
+        /// The vector length.
+        length: Place,
+    },
+    /// The vector index bounds check, which leaves the checked index on the evaluation stack.
+    VecBoundsCheck {
+        /// The vector length.
+        length: Place,
+    },
+
+    /// The data enumeration variant constructor, which wraps the payload on the top of the
+    /// evaluation stack into the enumeration value.
+    EnumerationVariant {
+        /// The variant tag.
+        tag: IntegerConstant,
+        /// The payload types of the preceding variants, which are filled with zeros.
+        prefix: Vec<Type>,
+        /// The variant payload size.
+        size: usize,
+        /// The payload types of the subsequent variants, which are filled with zeros.
+        suffix: Vec<Type>,
+    },
 }
 
 impl Operator {
@@ -332,6 +412,24 @@ impl Operator {
         }
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    /// The operands of `r#type` are compared element-wise. The vectors are compared up to their
+    /// lengths, since the elements beyond the length are not meaningful.
+    ///
+    pub fn equals_compound(r#type: &SemanticType) -> Self {
+        match Type::try_from_semantic(r#type) {
+            Some(Type::Vec { r#type, capacity }) => Self::EqualsVec {
+                element_size: r#type.size(),
+                capacity,
+            },
+            _ => Self::EqualsCompound {
+                size: r#type.size(),
+            },
+        }
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -361,6 +459,24 @@ impl Operator {
         }
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    /// The operands of `r#type` are compared element-wise. The vectors are compared up to their
+    /// lengths, since the elements beyond the length are not meaningful.
+    ///
+    pub fn not_equals_compound(r#type: &SemanticType) -> Self {
+        match Type::try_from_semantic(r#type) {
+            Some(Type::Vec { r#type, capacity }) => Self::NotEqualsVec {
+                element_size: r#type.size(),
+                capacity,
+            },
+            _ => Self::NotEqualsCompound {
+                size: r#type.size(),
+            },
+        }
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -792,6 +908,78 @@ impl Operator {
         }
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn call_vec_new(r#type: &SemanticType) -> Self {
+        Self::CallVecNew {
+            r#type: Type::try_from_semantic(r#type),
+        }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn call_vec_from_array(capacity: usize, is_checked: bool) -> Self {
+        Self::CallVecFromArray {
+            capacity,
+            is_checked,
+        }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn call_vec_push(element: Place, length: Place, capacity: usize) -> Self {
+        Self::CallVecPush {
+            element,
+            length,
+            capacity,
+        }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn call_vec_pop(element: Place, element_type: &SemanticType, length: Place) -> Self {
+        Self::CallVecPop {
+            element,
+            element_type: Type::try_from_semantic(element_type),
+            length,
+        }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn vec_bounds_check(length: Place) -> Self {
+        Self::VecBoundsCheck { length }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn enumeration_variant(enumeration: &SemanticEnumerationType, index: usize) -> Self {
+        let payload_types = |payloads: &[SemanticEnumerationPayload]| -> Vec<Type> {
+            payloads
+                .iter()
+                .flat_map(SemanticEnumerationPayload::types)
+                .filter_map(Type::try_from_semantic)
+                .collect()
+        };
+
+        Self::EnumerationVariant {
+            tag: IntegerConstant::new(
+                enumeration.values[index].to_owned(),
+                false,
+                enumeration.bitlength,
+            ),
+            prefix: payload_types(&enumeration.payloads[..index]),
+            size: enumeration.payloads[index].size(),
+            suffix: payload_types(&enumeration.payloads[index + 1..]),
+        }
+    }
+
     ///
     /// Returns the first operand's integer inferred type, if the type was inferred for the literal
     /// to adopt the other operand's fixed type.
@@ -0,0 +1,50 @@
+//!
+//! The generator `break` statement.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Location;
+use zinc_types::Instruction;
+
+use crate::generator::expression::operand::constant::boolean::Boolean as BooleanConstant;
+use crate::generator::r#type::Type;
+use crate::generator::zinc_vm::State as ZincVMState;
+use crate::generator::IBytecodeWritable;
+
+///
+/// The generator `break` statement.
+///
+/// The loop is not actually left. Instead, the `break` flag is set, which suppresses the side
+/// effects of the rest of the loop body and all the subsequent iterations.
+///
+#[derive(Debug, Clone)]
+pub struct Statement {
+    /// The statement location in the source code.
+    pub location: Location,
+}
+
+impl Statement {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(location: Location) -> Self {
+        Self { location }
+    }
+}
+
+impl IBytecodeWritable for Statement {
+    fn write_to_zinc_vm(self, state: Rc<RefCell<ZincVMState>>) {
+        let (flag_address, _continue_address) = state
+            .borrow()
+            .loop_addresses()
+            .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+
+        BooleanConstant::new(true).write_to_zinc_vm(state.clone());
+        state.borrow_mut().push_instruction(
+            Instruction::Store(zinc_types::Store::new(flag_address, Type::boolean().size())),
+            Some(self.location),
+        );
+    }
+}
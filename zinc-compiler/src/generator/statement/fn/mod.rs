@@ -11,6 +11,7 @@ use zinc_lexical::Location;
 use zinc_types::Instruction;
 
 use crate::generator::expression::operand::block::Expression;
+use crate::generator::expression::operand::constant::boolean::Boolean as BooleanConstant;
 use crate::generator::r#type::Type;
 use crate::generator::zinc_vm::State as ZincVMState;
 use crate::generator::IBytecodeWritable;
@@ -35,6 +36,8 @@ pub struct Statement {
     pub input_arguments: Vec<(String, bool, Type)>,
     /// The function body.
     pub body: Expression,
+    /// Whether the function body contains some `return` statements.
+    pub has_early_return: bool,
     /// The function result type, which defaults to `()` if not specified.
     pub output_type: Type,
     /// The function unique ID, which is assigned during the semantic analysis.
@@ -56,6 +59,7 @@ impl Statement {
         is_mutable: bool,
         bindings: Vec<Binding>,
         body: Expression,
+        has_early_return: bool,
         output_type: SemanticType,
         type_id: usize,
         role: Role,
@@ -64,6 +68,9 @@ impl Statement {
         let input_arguments = bindings
             .into_iter()
             .filter_map(|binding| match Type::try_from_semantic(&binding.r#type) {
+                _ if binding.is_reference => {
+                    Some((binding.identifier.name, binding.is_mutable, Type::field()))
+                }
                 Some(r#type) => Some((binding.identifier.name, binding.is_mutable, r#type)),
                 None => None,
             })
@@ -77,6 +84,7 @@ impl Statement {
             is_mutable,
             input_arguments,
             body,
+            has_early_return,
             output_type,
             type_id,
             role,
@@ -86,7 +94,7 @@ impl Statement {
 }
 
 impl IBytecodeWritable for Statement {
-    fn write_to_zinc_vm(self, state: Rc<RefCell<ZincVMState>>) {
+    fn write_to_zinc_vm(mut self, state: Rc<RefCell<ZincVMState>>) {
         let output_size = self.output_type.size();
 
         match self.role {
@@ -129,7 +137,43 @@ impl IBytecodeWritable for Statement {
             state.borrow_mut().define_variable(Some(name), size);
         }
 
-        self.body.write_to_zinc_vm(state.clone());
+        if self.has_early_return {
+            let flag_address = state
+                .borrow_mut()
+                .define_variable(None, Type::boolean().size());
+            BooleanConstant::new(false).write_to_zinc_vm(state.clone());
+            state.borrow_mut().push_instruction(
+                Instruction::Store(zinc_types::Store::new(flag_address, Type::boolean().size())),
+                Some(self.location),
+            );
+
+            let result_address = state.borrow_mut().define_variable(None, output_size);
+            if output_size > 0 {
+                state.borrow_mut().push_default(self.output_type.clone());
+                state.borrow_mut().push_instruction(
+                    Instruction::Store(zinc_types::Store::new(result_address, output_size)),
+                    Some(self.location),
+                );
+            }
+
+            state.borrow_mut().set_return_addresses(Some((
+                flag_address,
+                result_address,
+                output_size,
+            )));
+            self.body.set_returning(self.location);
+            self.body.write_to_zinc_vm(state.clone());
+            state.borrow_mut().set_return_addresses(None);
+
+            if output_size > 0 {
+                state.borrow_mut().push_instruction(
+                    Instruction::Load(zinc_types::Load::new(result_address, output_size)),
+                    Some(self.location),
+                );
+            }
+        } else {
+            self.body.write_to_zinc_vm(state.clone());
+        }
 
         match self.role {
             Role::ContractConstuctor { project, .. } => {
@@ -13,6 +13,7 @@ use crate::generator::expression::operand::constant::boolean::Boolean as Boolean
 use crate::generator::expression::operand::constant::integer::Integer as IntegerConstant;
 use crate::generator::expression::Expression as GeneratorExpression;
 use crate::generator::r#type::Type;
+use crate::generator::statement::r#let::Statement as DeclarationStatement;
 use crate::generator::zinc_vm::State as ZincVMState;
 use crate::generator::IBytecodeWritable;
 
@@ -26,6 +27,8 @@ use num::One;
 pub struct Statement {
     /// The statement location in the source code.
     pub location: Location,
+    /// The hidden iterated array variables, which are declared before the loop.
+    pub arrays: Vec<DeclarationStatement>,
     /// The initial value, which is assigned to the loop index variable before the first iteration.
     pub initial_value: BigInt,
     /// The number of loop iterations, usually the differece between the range bounds.
@@ -38,8 +41,12 @@ pub struct Statement {
     pub index_variable_is_signed: bool,
     /// The loop index variable bitlength, which is usually allocated to fit the bigger range bound.
     pub index_variable_bitlength: usize,
+    /// The optional item pattern declaration, which is executed at the beginning of each iteration.
+    pub item: Option<DeclarationStatement>,
     /// The optional while condition, which can suppress the loop side effects if false.
     pub while_condition: Option<GeneratorExpression>,
+    /// Whether the loop body contains some `break`, `continue`, or `return` statements.
+    pub has_early_exit: bool,
     /// The loop body.
     pub body: BlockExpression,
 }
@@ -51,31 +58,69 @@ impl Statement {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
         location: Location,
+        arrays: Vec<DeclarationStatement>,
         initial_value: BigInt,
         iterations_count: usize,
         is_reversed: bool,
         index_variable_name: String,
         index_variable_is_signed: bool,
         index_variable_bitlength: usize,
+        item: Option<DeclarationStatement>,
         while_condition: Option<GeneratorExpression>,
+        has_early_exit: bool,
         body: BlockExpression,
     ) -> Self {
         Self {
             location,
+            arrays,
             initial_value,
             iterations_count,
             is_reversed,
             index_variable_name,
             index_variable_is_signed,
             index_variable_bitlength,
+            item,
             while_condition,
+            has_early_exit,
             body,
         }
     }
+
+    ///
+    /// Writes the loop body.
+    ///
+    /// If the loop can be exited early, the body is guarded by the `break` and `return` flags,
+    /// so the iterations after the exit have no side effects.
+    ///
+    fn write_body(
+        body: BlockExpression,
+        exit_addresses: Option<(usize, usize)>,
+        location: Location,
+        state: Rc<RefCell<ZincVMState>>,
+    ) {
+        match exit_addresses {
+            Some((break_address, continue_address)) => {
+                state
+                    .borrow_mut()
+                    .push_loop_addresses((break_address, continue_address));
+                state.borrow_mut().push_early_exit_guard();
+                body.write_to_zinc_vm(state.clone());
+                state
+                    .borrow_mut()
+                    .push_instruction(Instruction::EndIf(zinc_types::EndIf), Some(location));
+                state.borrow_mut().pop_loop_addresses();
+            }
+            None => body.write_to_zinc_vm(state),
+        }
+    }
 }
 
 impl IBytecodeWritable for Statement {
     fn write_to_zinc_vm(self, state: Rc<RefCell<ZincVMState>>) {
+        for array in self.arrays.into_iter() {
+            array.write_to_zinc_vm(state.clone());
+        }
+
         let index_type =
             Type::integer(self.index_variable_is_signed, self.index_variable_bitlength);
         let index_size = index_type.size();
@@ -108,11 +153,44 @@ impl IBytecodeWritable for Statement {
             None
         };
 
+        let exit_addresses = if self.has_early_exit {
+            let mut addresses = Vec::with_capacity(2);
+            for _ in 0..2 {
+                let address = state
+                    .borrow_mut()
+                    .define_variable(None, Type::boolean().size());
+                BooleanConstant::new(false).write_to_zinc_vm(state.clone());
+                state.borrow_mut().push_instruction(
+                    Instruction::Store(zinc_types::Store::new(address, Type::boolean().size())),
+                    Some(self.location),
+                );
+                addresses.push(address);
+            }
+            Some((addresses[0], addresses[1]))
+        } else {
+            None
+        };
+
         state.borrow_mut().push_instruction(
             Instruction::LoopBegin(zinc_types::LoopBegin::new(self.iterations_count)),
             Some(self.location),
         );
 
+        if let Some((_break_address, continue_address)) = exit_addresses {
+            BooleanConstant::new(false).write_to_zinc_vm(state.clone());
+            state.borrow_mut().push_instruction(
+                Instruction::Store(zinc_types::Store::new(
+                    continue_address,
+                    Type::boolean().size(),
+                )),
+                Some(self.location),
+            );
+        }
+
+        if let Some(item) = self.item {
+            item.write_to_zinc_vm(state.clone());
+        }
+
         if let (Some(while_condition), Some(while_allowed_address)) =
             (self.while_condition, while_allowed_address)
         {
@@ -145,12 +223,12 @@ impl IBytecodeWritable for Statement {
             state
                 .borrow_mut()
                 .push_instruction(Instruction::If(zinc_types::If), Some(self.location));
-            self.body.write_to_zinc_vm(state.clone());
+            Self::write_body(self.body, exit_addresses, self.location, state.clone());
             state
                 .borrow_mut()
                 .push_instruction(Instruction::EndIf(zinc_types::EndIf), Some(self.location));
         } else {
-            self.body.write_to_zinc_vm(state.clone());
+            Self::write_body(self.body, exit_addresses, self.location, state.clone());
         }
 
         if self.is_reversed {
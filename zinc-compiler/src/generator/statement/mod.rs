@@ -2,10 +2,13 @@
 //! The generator statement.
 //!
 
+pub mod r#break;
+pub mod r#continue;
 pub mod contract;
 pub mod r#fn;
 pub mod r#for;
 pub mod r#let;
+pub mod r#return;
 
 use std::cell::RefCell;
 use std::rc::Rc;
@@ -15,9 +18,12 @@ use crate::generator::zinc_vm::State as ZincVMState;
 use crate::generator::IBytecodeWritable;
 
 use self::contract::Statement as ContractStatement;
+use self::r#break::Statement as BreakStatement;
+use self::r#continue::Statement as ContinueStatement;
 use self::r#fn::Statement as FnStatement;
 use self::r#for::Statement as ForStatement;
 use self::r#let::Statement as LetStatement;
+use self::r#return::Statement as ReturnStatement;
 
 ///
 /// The generator statement.
@@ -32,6 +38,12 @@ pub enum Statement {
     Contract(ContractStatement),
     /// The `for` statement.
     For(ForStatement),
+    /// The `return` statement.
+    Return(ReturnStatement),
+    /// The `break` statement.
+    Break(BreakStatement),
+    /// The `continue` statement.
+    Continue(ContinueStatement),
     /// The expression statement, which is actually a large class of expression-like statements.
     Expression(Expression),
 }
@@ -43,6 +55,9 @@ impl IBytecodeWritable for Statement {
             Self::Let(inner) => inner.write_to_zinc_vm(state),
             Self::Contract(inner) => inner.write_to_zinc_vm(state),
             Self::For(inner) => inner.write_to_zinc_vm(state),
+            Self::Return(inner) => inner.write_to_zinc_vm(state),
+            Self::Break(inner) => inner.write_to_zinc_vm(state),
+            Self::Continue(inner) => inner.write_to_zinc_vm(state),
             Self::Expression(inner) => inner.write_to_zinc_vm(state),
         }
     }
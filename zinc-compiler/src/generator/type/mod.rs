@@ -6,6 +6,7 @@ pub mod contract_field;
 
 use num::BigInt;
 
+use crate::semantic::element::r#type::enumeration::payload::Payload as SemanticEnumerationPayload;
 use crate::semantic::element::r#type::Type as SemanticType;
 use crate::semantic::scope::intrinsic::IntrinsicTypeId;
 
@@ -65,6 +66,13 @@ pub enum Type {
         /// The ordered contract storage fields array.
         fields: Vec<ContractField>,
     },
+    /// The IR vector type.
+    Vec {
+        /// The vector element type.
+        r#type: Box<Self>,
+        /// The vector capacity.
+        capacity: usize,
+    },
     /// The IR map type.
     Map {
         /// The map key type.
@@ -75,6 +83,9 @@ pub enum Type {
 }
 
 impl Type {
+    /// The data enumeration tag field name, which is followed by the variant payload fields.
+    pub const ENUMERATION_TAG_FIELD: &str = "variant";
+
     ///
     /// A shortcut constructor.
     ///
@@ -171,6 +182,16 @@ impl Type {
         Self::Contract { fields }
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn vec(r#type: Self, capacity: usize) -> Self {
+        Self::Vec {
+            r#type: Box::new(r#type),
+            capacity,
+        }
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -196,6 +217,7 @@ impl Type {
             Self::Tuple { types } => types.iter().map(|r#type| r#type.size()).sum(),
             Self::Structure { fields } => fields.iter().map(|(_name, r#type)| r#type.size()).sum(),
             Self::Contract { fields } => fields.iter().map(|field| field.r#type.size()).sum(),
+            Self::Vec { r#type, capacity } => r#type.size() * capacity + 1,
             Self::Map { .. } => 0,
         }
     }
@@ -244,6 +266,16 @@ impl Type {
                     return Some(Self::map(key_type, value_type));
                 }
 
+                if inner.type_id == IntrinsicTypeId::StdCollectionsVec as usize {
+                    return match inner.fields.first() {
+                        Some((_name, SemanticType::Array(array))) => {
+                            Self::try_from_semantic(&*array.r#type)
+                                .map(|r#type| Self::vec(r#type, array.size))
+                        }
+                        _ => None,
+                    };
+                }
+
                 match inner
                     .fields
                     .iter()
@@ -256,15 +288,55 @@ impl Type {
                     _ => None,
                 }
             }
-            SemanticType::Enumeration(inner) => Some(Self::enumeration(
-                inner.bitlength,
-                inner
-                    .names
-                    .to_owned()
-                    .into_iter()
-                    .zip(inner.values.to_owned())
-                    .collect::<Vec<(String, BigInt)>>(),
-            )),
+            SemanticType::Enumeration(inner) => {
+                let tag = Self::enumeration(
+                    inner.bitlength,
+                    inner
+                        .names
+                        .to_owned()
+                        .into_iter()
+                        .zip(inner.values.to_owned())
+                        .collect::<Vec<(String, BigInt)>>(),
+                );
+                if !inner.is_data() {
+                    return Some(tag);
+                }
+
+                let mut fields = Vec::with_capacity(inner.payloads.len() + 1);
+                fields.push((Self::ENUMERATION_TAG_FIELD.to_owned(), tag));
+                for (name, payload) in inner.names.iter().zip(inner.payloads.iter()) {
+                    let payload = match payload {
+                        SemanticEnumerationPayload::Unit => None,
+                        SemanticEnumerationPayload::Tuple(types) => {
+                            match types
+                                .iter()
+                                .filter_map(Self::try_from_semantic)
+                                .collect::<Vec<Type>>()
+                            {
+                                types if !types.is_empty() => Some(Self::tuple(types)),
+                                _ => None,
+                            }
+                        }
+                        SemanticEnumerationPayload::Structure(fields) => {
+                            match fields
+                                .iter()
+                                .filter_map(|(name, r#type)| {
+                                    Self::try_from_semantic(r#type)
+                                        .map(|r#type| (name.to_owned(), r#type))
+                                })
+                                .collect::<Vec<(String, Type)>>()
+                            {
+                                fields if !fields.is_empty() => Some(Self::structure(fields)),
+                                _ => None,
+                            }
+                        }
+                    };
+                    if let Some(payload) = payload {
+                        fields.push((name.to_owned(), payload));
+                    }
+                }
+                Some(Self::structure(fields))
+            }
             SemanticType::Contract(inner) => {
                 match inner
                     .fields
@@ -325,6 +397,13 @@ impl Into<zinc_types::Type> for Type {
             Self::Contract { fields } => {
                 zinc_types::Type::Contract(fields.into_iter().map(|field| field.into()).collect())
             }
+            Self::Vec { r#type, capacity } => {
+                let element_type: zinc_types::Type = (*r#type).into();
+                zinc_types::Type::Vec {
+                    r#type: Box::new(element_type),
+                    capacity,
+                }
+            }
             Self::Map {
                 key_type,
                 value_type,
@@ -10,6 +10,9 @@ use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
 
+use num::BigInt;
+use num::Zero;
+
 use zinc_lexical::Location;
 use zinc_lexical::FILE_INDEX;
 use zinc_types::Instruction;
@@ -20,6 +23,8 @@ use crate::semantic::analyzer::attribute::Attribute;
 
 use self::entry::Entry;
 use self::optimizer::dead_function_code_elimination::Optimizer as DeadFunctionCodeEliminationOptimizer;
+use self::optimizer::level::Level as OptimizationLevel;
+use self::optimizer::pipeline::Pipeline as OptimizationPipeline;
 use self::unit_test::UnitTest;
 
 ///
@@ -45,6 +50,12 @@ pub struct State {
     variable_addresses: HashMap<String, usize>,
     /// The pointer which is reset at the beginning of each function.
     data_stack_pointer: usize,
+    /// The `return` flag address, result address, and result size of the current function,
+    /// which is set only if the function contains some `return` statements.
+    return_addresses: Option<(usize, usize, usize)>,
+    /// The `break` and `continue` flag addresses of the loops being written, which contain some
+    /// early exit statements.
+    loop_addresses: Vec<(usize, usize)>,
     /// The location pointer used to pass debug information to the VM.
     current_location: Location,
 }
@@ -80,6 +91,8 @@ impl State {
             function_addresses: HashMap::with_capacity(Self::FUNCTION_ADDRESSES_INITIAL_CAPACITY),
             variable_addresses: HashMap::with_capacity(Self::VARIABLE_ADDRESSES_INITIAL_CAPACITY),
             data_stack_pointer: 0,
+            return_addresses: None,
+            loop_addresses: Vec::new(),
             current_location: Location::default(),
         }
     }
@@ -122,6 +135,7 @@ impl State {
         let address = self.instructions.len();
         self.function_addresses.insert(type_id, address);
         self.data_stack_pointer = 0;
+        self.return_addresses = None;
 
         self.instructions
             .push(Instruction::FileMarker(zinc_types::FileMarker::new(
@@ -213,6 +227,86 @@ impl State {
         start_address
     }
 
+    ///
+    /// Sets the `return` flag address, result address, and result size of the current function.
+    ///
+    pub fn set_return_addresses(&mut self, addresses: Option<(usize, usize, usize)>) {
+        self.return_addresses = addresses;
+    }
+
+    ///
+    /// Returns the `return` flag address, result address, and result size of the current function.
+    ///
+    pub fn return_addresses(&self) -> Option<(usize, usize, usize)> {
+        self.return_addresses
+    }
+
+    ///
+    /// Saves the `break` and `continue` flag addresses of the loop being written.
+    ///
+    pub fn push_loop_addresses(&mut self, addresses: (usize, usize)) {
+        self.loop_addresses.push(addresses);
+    }
+
+    ///
+    /// Removes the `break` and `continue` flag addresses of the loop which has been written.
+    ///
+    pub fn pop_loop_addresses(&mut self) {
+        self.loop_addresses
+            .pop()
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+    }
+
+    ///
+    /// Returns the `break` and `continue` flag addresses of the innermost loop being written.
+    ///
+    pub fn loop_addresses(&self) -> Option<(usize, usize)> {
+        self.loop_addresses.last().copied()
+    }
+
+    ///
+    /// Writes the zero value of `r#type`, which is used to initialize the early exit result
+    /// and to substitute the result of the blocks skipped after an early exit.
+    ///
+    pub fn push_default(&mut self, r#type: Type) {
+        let r#type: zinc_types::Type = r#type.into();
+        for scalar_type in r#type.into_flat_scalar_types().into_iter() {
+            self.push_instruction(
+                Instruction::Push(zinc_types::Push::new(BigInt::zero(), scalar_type)),
+                None,
+            );
+        }
+    }
+
+    ///
+    /// Writes the condition, which is true if no early exit has happened in the current function
+    /// and the innermost loop, and opens the conditional block guarding the subsequent code.
+    ///
+    /// The block must be closed with the `EndIf` instruction.
+    ///
+    pub fn push_early_exit_guard(&mut self) {
+        let mut flag_addresses = Vec::with_capacity(3);
+        if let Some((return_address, _result_address, _result_size)) = self.return_addresses {
+            flag_addresses.push(return_address);
+        }
+        if let Some((break_address, continue_address)) = self.loop_addresses() {
+            flag_addresses.push(break_address);
+            flag_addresses.push(continue_address);
+        }
+
+        for (index, address) in flag_addresses.into_iter().enumerate() {
+            self.push_instruction(
+                Instruction::Load(zinc_types::Load::new(address, Type::boolean().size())),
+                None,
+            );
+            if index > 0 {
+                self.push_instruction(Instruction::Or(zinc_types::Or), None);
+            }
+        }
+        self.push_instruction(Instruction::Not(zinc_types::Not), None);
+        self.push_instruction(Instruction::If(zinc_types::If), None);
+    }
+
     ///
     /// Writes the instruction along with its location debug information.
     ///
@@ -250,15 +344,20 @@ impl State {
     /// Converts the compiled application state into a set of byte arrays, which are ready to be
     /// written to the Zinc project build files.
     ///
+    /// The bytecode is optimized according to `optimization_level`.
+    ///
     pub fn into_application(
         mut self,
-        optimize_dead_function_elimination: bool,
+        optimization_level: OptimizationLevel,
     ) -> zinc_types::Application {
+        OptimizationPipeline::new(optimization_level)
+            .optimize(&mut self.instructions, &mut self.function_addresses);
+
         match self.contract_storage.take() {
             Some(storage) => {
                 let storage = storage.into_iter().map(|field| field.into()).collect();
 
-                if optimize_dead_function_elimination {
+                if optimization_level.is_dead_function_elimination_enabled() {
                     let mut entry_ids: Vec<usize> = self
                         .entries
                         .iter()
@@ -329,6 +428,7 @@ impl State {
                 zinc_types::Application::new_contract(
                     self.manifest.project.name,
                     storage,
+                    self.manifest.project.storage_hasher.unwrap_or_default(),
                     methods,
                     unit_tests,
                     self.instructions,
@@ -343,7 +443,7 @@ impl State {
                 let input = entry.input_fields_as_struct().into();
                 let output = entry.output_type.into();
 
-                if optimize_dead_function_elimination {
+                if optimization_level.is_dead_function_elimination_enabled() {
                     let mut entry_ids: Vec<usize> = vec![entry_id];
                     entry_ids.extend(
                         self.unit_tests
@@ -0,0 +1,201 @@
+//!
+//! The bytecode constant folder.
+//!
+
+use num::BigInt;
+use num::One;
+use num::Signed;
+use num::Zero;
+
+use zinc_types::Instruction;
+use zinc_types::Push;
+use zinc_types::ScalarType;
+
+///
+/// The constant folding optimization.
+///
+/// The operators whose operands are pushed as constants right before them are replaced with
+/// their results, which is repeated for the results, so whole constant expressions are folded.
+///
+/// The operations, whose results are out of the type range or which divide by zero, are kept
+/// as is, so the virtual machine will return the same error at runtime.
+///
+pub struct Optimizer {}
+
+impl Optimizer {
+    ///
+    /// Folds the constant operations in the function body.
+    ///
+    /// Returns `true` if the bytecode has been changed.
+    ///
+    pub fn optimize(instructions: &mut [Instruction]) -> bool {
+        let mut is_changed = false;
+
+        for address in 0..instructions.len() {
+            let arity = match instructions[address] {
+                Instruction::Neg(_) | Instruction::Not(_) | Instruction::Cast(_) => 1,
+                Instruction::Add(_)
+                | Instruction::Sub(_)
+                | Instruction::Mul(_)
+                | Instruction::Div(_)
+                | Instruction::Rem(_)
+                | Instruction::And(_)
+                | Instruction::Or(_)
+                | Instruction::Xor(_)
+                | Instruction::Lt(_)
+                | Instruction::Le(_)
+                | Instruction::Eq(_)
+                | Instruction::Ne(_)
+                | Instruction::Ge(_)
+                | Instruction::Gt(_)
+                | Instruction::BitwiseAnd(_)
+                | Instruction::BitwiseOr(_)
+                | Instruction::BitwiseXor(_) => 2,
+                _ => continue,
+            };
+
+            let operands = match super::preceding(instructions, address, arity) {
+                Some(operands) => operands,
+                None => continue,
+            };
+
+            let values: Vec<&Push> = operands
+                .iter()
+                .filter_map(|operand| match instructions[*operand] {
+                    Instruction::Push(ref push) => Some(push),
+                    _ => None,
+                })
+                .collect();
+            if values.len() != arity {
+                continue;
+            }
+
+            let result = match values.as_slice() {
+                [operand] => Self::fold_unary(&instructions[address], operand),
+                [left, right] => Self::fold_binary(&instructions[address], left, right),
+                _ => None,
+            };
+
+            if let Some(result) = result {
+                instructions[operands[0]] = Instruction::Push(result);
+                for operand in operands.into_iter().skip(1) {
+                    instructions[operand] = Instruction::NoOperation(zinc_types::NoOperation);
+                }
+                instructions[address] = Instruction::NoOperation(zinc_types::NoOperation);
+
+                is_changed = true;
+            }
+        }
+
+        is_changed
+    }
+
+    ///
+    /// Computes the unary operator result, if it is foldable.
+    ///
+    fn fold_unary(instruction: &Instruction, operand: &Push) -> Option<Push> {
+        match instruction {
+            Instruction::Neg(_) if operand.scalar_type.is_signed() => {
+                Self::checked(-&operand.value, operand.scalar_type.to_owned())
+            }
+            Instruction::Not(_) if operand.scalar_type == ScalarType::Boolean => {
+                Some(Self::boolean(operand.value.is_zero()))
+            }
+            Instruction::Cast(cast) => match cast.r#type {
+                ScalarType::Boolean if operand.value.is_zero() || operand.value.is_one() => {
+                    Some(Push::new(operand.value.to_owned(), ScalarType::Boolean))
+                }
+                ScalarType::Boolean => None,
+                ref r#type => Self::checked(operand.value.to_owned(), r#type.to_owned()),
+            },
+            _ => None,
+        }
+    }
+
+    ///
+    /// Computes the binary operator result, if it is foldable.
+    ///
+    fn fold_binary(instruction: &Instruction, left: &Push, right: &Push) -> Option<Push> {
+        if left.scalar_type != right.scalar_type {
+            return None;
+        }
+
+        let scalar_type = left.scalar_type.to_owned();
+        let is_integer = matches!(scalar_type, ScalarType::Integer(_));
+        let is_boolean = scalar_type == ScalarType::Boolean;
+        let is_comparable = scalar_type != ScalarType::Field
+            || !(left.value.is_negative() || right.value.is_negative());
+
+        let (left, right) = (&left.value, &right.value);
+        match instruction {
+            Instruction::Add(_) => Self::checked(left + right, scalar_type),
+            Instruction::Sub(_) => Self::checked(left - right, scalar_type),
+            Instruction::Mul(_) => Self::checked(left * right, scalar_type),
+            Instruction::Div(_) if is_integer => zinc_math::euclidean_div_rem(left, right)
+                .and_then(|(quotient, _remainder)| Self::checked(quotient, scalar_type)),
+            Instruction::Rem(_) if is_integer => zinc_math::euclidean_div_rem(left, right)
+                .and_then(|(_quotient, remainder)| Self::checked(remainder, scalar_type)),
+
+            Instruction::And(_) if is_boolean => {
+                Some(Self::boolean(left.is_one() && right.is_one()))
+            }
+            Instruction::Or(_) if is_boolean => {
+                Some(Self::boolean(left.is_one() || right.is_one()))
+            }
+            Instruction::Xor(_) if is_boolean => {
+                Some(Self::boolean(left.is_one() != right.is_one()))
+            }
+
+            Instruction::Lt(_) if is_comparable => Some(Self::boolean(left < right)),
+            Instruction::Le(_) if is_comparable => Some(Self::boolean(left <= right)),
+            Instruction::Eq(_) if is_comparable => Some(Self::boolean(left == right)),
+            Instruction::Ne(_) if is_comparable => Some(Self::boolean(left != right)),
+            Instruction::Ge(_) if is_comparable => Some(Self::boolean(left >= right)),
+            Instruction::Gt(_) if is_comparable => Some(Self::boolean(left > right)),
+
+            Instruction::BitwiseAnd(_) if is_integer && !scalar_type.is_signed() => {
+                Self::checked(left & right, scalar_type)
+            }
+            Instruction::BitwiseOr(_) if is_integer && !scalar_type.is_signed() => {
+                Self::checked(left | right, scalar_type)
+            }
+            Instruction::BitwiseXor(_) if is_integer && !scalar_type.is_signed() => {
+                Self::checked(left ^ right, scalar_type)
+            }
+
+            _ => None,
+        }
+    }
+
+    ///
+    /// Returns the constant of `scalar_type`, if the value is within the type range.
+    ///
+    /// The field values are only folded while they are non-negative and not close to the field
+    /// modulus, so they are never wrapped.
+    ///
+    fn checked(value: BigInt, scalar_type: ScalarType) -> Option<Push> {
+        let is_in_range = match scalar_type {
+            ScalarType::Integer(ref r#type) => value >= r#type.min() && value <= r#type.max(),
+            ScalarType::Field => {
+                !value.is_negative() && value.bits() < (zinc_const::bitlength::FIELD - 1) as u64
+            }
+            ScalarType::Boolean => false,
+        };
+
+        if is_in_range {
+            Some(Push::new(value, scalar_type))
+        } else {
+            None
+        }
+    }
+
+    ///
+    /// Returns the boolean constant.
+    ///
+    fn boolean(value: bool) -> Push {
+        Push::new(
+            if value { BigInt::one() } else { BigInt::zero() },
+            ScalarType::Boolean,
+        )
+    }
+}
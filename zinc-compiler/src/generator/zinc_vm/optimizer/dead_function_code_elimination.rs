@@ -24,12 +24,12 @@ impl Optimizer {
     /// 1. Initialize a call graph and create the function ID to graph node ID mapping.
     ///
     /// 2. Populate the call graph for the current entry. The difference in the graph is caused by
-    /// the first `Call` instruction in the bytecode, which specifies the entry that must be called
-    /// at the beginning of the execution.
+    ///    the first `Call` instruction in the bytecode, which specifies the entry that must be
+    ///    called at the beginning of the execution.
     ///
     /// 3. Replace all the unused function code with `NoOperation` instructions, record the number
-    /// of replaced instructions in order to shift the addresses of functions which appear later
-    /// in the bytecode.
+    ///    of replaced instructions in order to shift the addresses of functions which appear
+    ///    later in the bytecode.
     ///
     /// 4. Filter out the `NoOperation` instructions placed in the previous step.
     ///
@@ -0,0 +1,116 @@
+//!
+//! The bytecode dead store eliminator.
+//!
+
+use std::ops::Range;
+
+use zinc_types::Instruction;
+
+///
+/// The dead store elimination optimization.
+///
+/// There is no instruction which drops values from the evaluation stack, so a `Store` can only be
+/// removed along with the instructions which have pushed its values. Thus, the stores are removed
+/// if their values are written with either constant `Push` instructions or a single `Load`,
+/// and the `Load` is removed as unused as well.
+///
+pub struct Optimizer {}
+
+impl Optimizer {
+    ///
+    /// Eliminates the dead stores in the function body.
+    ///
+    /// A store is dead if it writes the value loaded from the same address, or if no instruction
+    /// of the function reads any of the written cells.
+    ///
+    /// Returns `true` if the bytecode has been changed.
+    ///
+    pub fn optimize(instructions: &mut [Instruction]) -> bool {
+        let mut is_changed = false;
+
+        let reads = Self::reads(instructions);
+
+        for address in 0..instructions.len() {
+            let (store_address, size) = match instructions[address] {
+                Instruction::Store(ref store) if store.size > 0 => (store.address, store.size),
+                _ => continue,
+            };
+
+            let producer = match super::preceding(instructions, address, 1) {
+                Some(operands) => operands[0],
+                None => continue,
+            };
+            if let Instruction::Load(ref load) = instructions[producer] {
+                let is_self_assignment = load.address == store_address && load.size == size;
+                let is_dead = load.size == size
+                    && !reads
+                        .iter()
+                        .any(|range| Self::overlaps(range, store_address, size));
+
+                if is_self_assignment || is_dead {
+                    instructions[producer] = Instruction::NoOperation(zinc_types::NoOperation);
+                    instructions[address] = Instruction::NoOperation(zinc_types::NoOperation);
+                    is_changed = true;
+                }
+
+                continue;
+            }
+
+            if reads
+                .iter()
+                .any(|range| Self::overlaps(range, store_address, size))
+            {
+                continue;
+            }
+
+            let producers = match super::preceding(instructions, address, size) {
+                Some(operands) => operands,
+                None => continue,
+            };
+            if producers
+                .iter()
+                .all(|producer| matches!(instructions[*producer], Instruction::Push(_)))
+            {
+                for producer in producers.into_iter() {
+                    instructions[producer] = Instruction::NoOperation(zinc_types::NoOperation);
+                }
+                instructions[address] = Instruction::NoOperation(zinc_types::NoOperation);
+                is_changed = true;
+            }
+        }
+
+        is_changed
+    }
+
+    ///
+    /// Returns the data stack address ranges read by the function.
+    ///
+    /// The indexed instructions read the whole array, since the index is not known. The referenced
+    /// data is read as a whole as well, since it can be read by the called function.
+    ///
+    fn reads(instructions: &[Instruction]) -> Vec<Range<usize>> {
+        instructions
+            .iter()
+            .filter_map(|instruction| match instruction {
+                Instruction::Load(load) => Some(load.address..load.address + load.size),
+                Instruction::LoadByIndex(load) => {
+                    Some(load.address..load.address + load.total_size)
+                }
+                Instruction::StoreByIndex(store) => {
+                    Some(store.address..store.address + store.total_size)
+                }
+                Instruction::Reference(reference) => {
+                    Some(reference.address..reference.address + reference.size)
+                }
+                _ => None,
+            })
+            .collect()
+    }
+
+    ///
+    /// Checks if the `range` overlaps the `size` cells starting from `address`.
+    ///
+    fn overlaps(range: &Range<usize>, address: usize, size: usize) -> bool {
+        range.start < address + size && address < range.end
+    }
+}
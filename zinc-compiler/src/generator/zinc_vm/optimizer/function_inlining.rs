@@ -0,0 +1,171 @@
+//!
+//! The bytecode function inliner.
+//!
+
+use std::collections::HashMap;
+
+use zinc_types::Instruction;
+
+///
+/// The small function inlining optimization.
+///
+/// Only the small leaf functions without conditional blocks are inlined, and only at the call
+/// sites outside of the caller conditional blocks. The virtual machine merges the data stack
+/// cells written in conditional blocks, so the inlined function frame must not be written there,
+/// since its cells would be merged with the values left from the previous inlined calls.
+///
+pub struct Optimizer {}
+
+impl Optimizer {
+    /// The maximal number of instructions in the inlined function body.
+    const INSTRUCTIONS_LIMIT: usize = 32;
+
+    ///
+    /// Inlines the small functions into their callers.
+    ///
+    /// `functions` are the function type IDs with their bodies, where the `Call` instructions
+    /// still contain the callee type IDs.
+    ///
+    /// The callee frame is placed right after the caller one, so the `Call` instruction is
+    /// replaced with the `Store` of the arguments to the callee frame start, and the callee
+    /// data stack addresses are shifted by the caller frame size. The `Return` instruction
+    /// leaves the result on the evaluation stack as is, so it is removed along with the debug
+    /// markers, which would break the caller location tracking.
+    ///
+    /// Returns `true` if the bytecode has been changed.
+    ///
+    pub fn optimize(functions: &mut [(usize, Vec<Instruction>)]) -> bool {
+        let mut is_changed = false;
+
+        let inlinable: HashMap<usize, Vec<Instruction>> = functions
+            .iter()
+            .filter_map(|(type_id, instructions)| {
+                Self::inlinable_body(instructions.as_slice()).map(|body| (*type_id, body))
+            })
+            .collect();
+        if inlinable.is_empty() {
+            return false;
+        }
+
+        for (_type_id, instructions) in functions.iter_mut() {
+            let frame_size = Self::frame_size(instructions.as_slice());
+
+            let mut depth = 0;
+            let mut result = Vec::with_capacity(instructions.len());
+            for instruction in instructions.drain(..) {
+                match instruction {
+                    Instruction::If(_) => depth += 1,
+                    Instruction::EndIf(_) => depth -= 1,
+                    Instruction::Call(ref call) if depth == 0 => {
+                        if let Some(body) = inlinable.get(&call.address) {
+                            if call.input_size > 0 {
+                                result.push(Instruction::Store(zinc_types::Store::new(
+                                    frame_size,
+                                    call.input_size,
+                                )));
+                            }
+                            result.extend(
+                                body.iter()
+                                    .cloned()
+                                    .map(|instruction| Self::shift(instruction, frame_size)),
+                            );
+
+                            is_changed = true;
+                            continue;
+                        }
+                    }
+                    _ => {}
+                }
+
+                result.push(instruction);
+            }
+            *instructions = result;
+        }
+
+        is_changed
+    }
+
+    ///
+    /// Returns the function body without the debug markers and the final `Return`, if the
+    /// function can be inlined.
+    ///
+    fn inlinable_body(instructions: &[Instruction]) -> Option<Vec<Instruction>> {
+        let mut body: Vec<Instruction> = instructions
+            .iter()
+            .filter(|instruction| !super::is_transparent(instruction))
+            .cloned()
+            .collect();
+
+        match body.pop() {
+            Some(Instruction::Return(_)) => {}
+            _ => return None,
+        }
+
+        if body.len() > Self::INSTRUCTIONS_LIMIT {
+            return None;
+        }
+
+        let is_leaf = body.iter().all(|instruction| {
+            !matches!(
+                instruction,
+                Instruction::Call(_)
+                    | Instruction::Return(_)
+                    | Instruction::If(_)
+                    | Instruction::Else(_)
+                    | Instruction::EndIf(_)
+            )
+        });
+        if !is_leaf {
+            return None;
+        }
+
+        Some(body)
+    }
+
+    ///
+    /// Returns the number of the data stack cells used by the function.
+    ///
+    fn frame_size(instructions: &[Instruction]) -> usize {
+        instructions
+            .iter()
+            .filter_map(|instruction| match instruction {
+                Instruction::Load(inner) => Some(inner.address + inner.size),
+                Instruction::Store(inner) => Some(inner.address + inner.size),
+                Instruction::LoadByIndex(inner) => Some(inner.address + inner.total_size),
+                Instruction::StoreByIndex(inner) => Some(inner.address + inner.total_size),
+                Instruction::Reference(inner) => Some(inner.address + inner.size),
+                _ => None,
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    ///
+    /// Shifts the data stack address of the instruction by `offset`.
+    ///
+    fn shift(instruction: Instruction, offset: usize) -> Instruction {
+        match instruction {
+            Instruction::Load(mut inner) => {
+                inner.address += offset;
+                Instruction::Load(inner)
+            }
+            Instruction::Store(mut inner) => {
+                inner.address += offset;
+                Instruction::Store(inner)
+            }
+            Instruction::LoadByIndex(mut inner) => {
+                inner.address += offset;
+                Instruction::LoadByIndex(inner)
+            }
+            Instruction::StoreByIndex(mut inner) => {
+                inner.address += offset;
+                Instruction::StoreByIndex(inner)
+            }
+            Instruction::Reference(mut inner) => {
+                inner.address += offset;
+                Instruction::Reference(inner)
+            }
+            instruction => instruction,
+        }
+    }
+}
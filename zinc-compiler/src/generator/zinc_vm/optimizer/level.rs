@@ -0,0 +1,72 @@
+//!
+//! The bytecode optimization level.
+//!
+
+use std::fmt;
+use std::str::FromStr;
+
+///
+/// The bytecode optimization level.
+///
+/// The levels are ordered, so each level enables the passes of the previous ones.
+///
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    /// No optimizations, which is used for the debug builds.
+    #[default]
+    None,
+    /// The function-local passes and the dead function code elimination.
+    Basic,
+    /// All the passes including the small function inlining, used for the release builds.
+    Full,
+}
+
+impl Level {
+    ///
+    /// If the function-local passes, that is, the constant folding, redundant instruction
+    /// and dead store elimination, are enabled.
+    ///
+    pub fn is_local_enabled(self) -> bool {
+        self >= Self::Basic
+    }
+
+    ///
+    /// If the dead function code elimination is enabled.
+    ///
+    pub fn is_dead_function_elimination_enabled(self) -> bool {
+        self >= Self::Basic
+    }
+
+    ///
+    /// If the small function inlining is enabled.
+    ///
+    pub fn is_inlining_enabled(self) -> bool {
+        self >= Self::Full
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "0" => Ok(Self::None),
+            "1" => Ok(Self::Basic),
+            "2" => Ok(Self::Full),
+            another => Err(format!(
+                "invalid optimization level `{}`, expected `0`, `1`, or `2`",
+                another
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::None => write!(f, "0"),
+            Self::Basic => write!(f, "1"),
+            Self::Full => write!(f, "2"),
+        }
+    }
+}
@@ -2,4 +2,55 @@
 //! The bytecode optimizers.
 //!
 
+#[cfg(test)]
+mod tests;
+
+pub mod constant_folding;
 pub mod dead_function_code_elimination;
+pub mod dead_store_elimination;
+pub mod function_inlining;
+pub mod level;
+pub mod pipeline;
+pub mod redundant_instruction_elimination;
+
+use zinc_types::Instruction;
+
+///
+/// Checks if the instruction is ignored while matching the instruction sequences.
+///
+/// The debug markers are written between any instructions whose source code locations differ,
+/// and the removed instructions are replaced with `NoOperation` until the end of the pass.
+///
+pub(crate) fn is_transparent(instruction: &Instruction) -> bool {
+    instruction.is_debug() || matches!(instruction, Instruction::NoOperation(_))
+}
+
+///
+/// Returns the addresses of `count` instructions preceding the `address` one in the bytecode
+/// order, skipping the transparent instructions.
+///
+/// Returns `None` if there are not enough instructions before `address`.
+///
+pub(crate) fn preceding(
+    instructions: &[Instruction],
+    address: usize,
+    count: usize,
+) -> Option<Vec<usize>> {
+    let mut addresses = Vec::with_capacity(count);
+    for address in (0..address).rev() {
+        if addresses.len() == count {
+            break;
+        }
+
+        if !is_transparent(&instructions[address]) {
+            addresses.push(address);
+        }
+    }
+
+    if addresses.len() != count {
+        return None;
+    }
+
+    addresses.reverse();
+    Some(addresses)
+}
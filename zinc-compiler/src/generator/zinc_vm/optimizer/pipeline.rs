@@ -0,0 +1,130 @@
+//!
+//! The bytecode optimization pipeline.
+//!
+
+use std::collections::HashMap;
+
+use zinc_types::Instruction;
+
+use super::constant_folding::Optimizer as ConstantFoldingOptimizer;
+use super::dead_store_elimination::Optimizer as DeadStoreEliminationOptimizer;
+use super::function_inlining::Optimizer as FunctionInliningOptimizer;
+use super::level::Level;
+use super::redundant_instruction_elimination::Optimizer as RedundantInstructionEliminationOptimizer;
+
+///
+/// The bytecode optimization pipeline.
+///
+/// Runs the passes enabled by the optimization level over the function bodies, before the
+/// function type IDs in `Call` instructions are replaced with the function addresses.
+///
+/// The dead function code elimination is run separately, since it depends on the application
+/// entries, and also resolves the function addresses.
+///
+pub struct Pipeline {
+    /// The optimization level.
+    level: Level,
+}
+
+impl Pipeline {
+    /// The maximal number of iterations of the passes, which are repeated until the bytecode
+    /// stops changing, since each pass can give more opportunities to the others.
+    const ITERATIONS_LIMIT: usize = 16;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(level: Level) -> Self {
+        Self { level }
+    }
+
+    ///
+    /// Optimizes the bytecode, updating the function addresses.
+    ///
+    pub fn optimize(
+        &self,
+        instructions: &mut Vec<Instruction>,
+        function_addresses: &mut HashMap<usize, usize>,
+    ) {
+        if !self.level.is_local_enabled() && !self.level.is_inlining_enabled() {
+            return;
+        }
+
+        let mut functions = Self::split(instructions, function_addresses);
+
+        self.optimize_local(&mut functions);
+        if self.level.is_inlining_enabled() {
+            for _ in 0..Self::ITERATIONS_LIMIT {
+                if !FunctionInliningOptimizer::optimize(&mut functions) {
+                    break;
+                }
+
+                self.optimize_local(&mut functions);
+            }
+        }
+
+        Self::join(instructions, function_addresses, functions);
+    }
+
+    ///
+    /// Runs the function-local passes until the function bodies stop changing.
+    ///
+    fn optimize_local(&self, functions: &mut [(usize, Vec<Instruction>)]) {
+        if !self.level.is_local_enabled() {
+            return;
+        }
+
+        for (_type_id, instructions) in functions.iter_mut() {
+            for _ in 0..Self::ITERATIONS_LIMIT {
+                let mut is_changed = ConstantFoldingOptimizer::optimize(instructions);
+                is_changed |= RedundantInstructionEliminationOptimizer::optimize(instructions);
+                is_changed |= DeadStoreEliminationOptimizer::optimize(instructions);
+
+                instructions
+                    .retain(|instruction| !matches!(instruction, Instruction::NoOperation(_)));
+
+                if !is_changed {
+                    break;
+                }
+            }
+        }
+    }
+
+    ///
+    /// Splits the bytecode into the function bodies, ordered by their addresses.
+    ///
+    /// The instructions before the first function, if any, are left in `instructions`.
+    ///
+    fn split(
+        instructions: &mut Vec<Instruction>,
+        function_addresses: &HashMap<usize, usize>,
+    ) -> Vec<(usize, Vec<Instruction>)> {
+        let mut addresses: Vec<(usize, usize)> = function_addresses
+            .iter()
+            .map(|(type_id, address)| (*type_id, *address))
+            .collect();
+        addresses.sort_by_key(|(_type_id, address)| *address);
+
+        let mut functions = Vec::with_capacity(addresses.len());
+        for (type_id, address) in addresses.into_iter().rev() {
+            functions.push((type_id, instructions.split_off(address)));
+        }
+        functions.reverse();
+
+        functions
+    }
+
+    ///
+    /// Joins the function bodies back into the bytecode, writing their new addresses.
+    ///
+    fn join(
+        instructions: &mut Vec<Instruction>,
+        function_addresses: &mut HashMap<usize, usize>,
+        functions: Vec<(usize, Vec<Instruction>)>,
+    ) {
+        for (type_id, mut body) in functions.into_iter() {
+            function_addresses.insert(type_id, instructions.len());
+            instructions.append(&mut body);
+        }
+    }
+}
@@ -0,0 +1,209 @@
+//!
+//! The bytecode redundant instruction eliminator.
+//!
+
+use num::ToPrimitive;
+use num::Zero;
+
+use zinc_types::Instruction;
+
+///
+/// The redundant instruction elimination optimization.
+///
+/// Replaces the evaluation stack and indexed data stack instructions, whose operands are known
+/// at compile time, with the simpler ones:
+///
+/// 1. `Copy` of a constant is replaced with the constant `Push`.
+///
+/// 2. `Slice` of the whole array at the zero offset is removed.
+///
+/// 3. `Slice` of a loaded array at a constant offset is replaced with the `Load` of the slice.
+///
+/// 4. `LoadByIndex` and `StoreByIndex` at a constant index are replaced with the `Load` and
+///    `Store` of the element, if the index is within the array bounds.
+///
+/// The indexed instructions and slices synthesize the array access constraints, and the
+/// `StoreByIndex` one also rewrites the whole array, which is merged after conditional blocks.
+///
+pub struct Optimizer {}
+
+impl Optimizer {
+    ///
+    /// Eliminates the redundant instructions in the function body.
+    ///
+    /// Returns `true` if the bytecode has been changed.
+    ///
+    pub fn optimize(instructions: &mut [Instruction]) -> bool {
+        let mut is_changed = false;
+
+        for address in 0..instructions.len() {
+            is_changed |= match instructions[address] {
+                Instruction::Copy(_) => Self::copy(instructions, address),
+                Instruction::Slice(ref slice) => {
+                    let (slice_length, total_size) = (slice.slice_length, slice.total_size);
+                    Self::slice(instructions, address, slice_length, total_size)
+                }
+                Instruction::LoadByIndex(ref load) => {
+                    let (array_address, value_size, total_size) =
+                        (load.address, load.value_size, load.total_size);
+                    Self::load_by_index(
+                        instructions,
+                        address,
+                        array_address,
+                        value_size,
+                        total_size,
+                    )
+                }
+                Instruction::StoreByIndex(ref store) => {
+                    let (array_address, value_size, total_size) =
+                        (store.address, store.value_size, store.total_size);
+                    Self::store_by_index(
+                        instructions,
+                        address,
+                        array_address,
+                        value_size,
+                        total_size,
+                    )
+                }
+                _ => false,
+            };
+        }
+
+        is_changed
+    }
+
+    ///
+    /// Replaces `Push`, `Copy` with `Push`, `Push`.
+    ///
+    fn copy(instructions: &mut [Instruction], address: usize) -> bool {
+        let operand = match super::preceding(instructions, address, 1) {
+            Some(operands) => operands[0],
+            None => return false,
+        };
+
+        let push = match instructions[operand] {
+            Instruction::Push(ref push) => push.to_owned(),
+            _ => return false,
+        };
+
+        instructions[address] = Instruction::Push(push);
+        true
+    }
+
+    ///
+    /// Removes `Push 0`, `Slice n n`, and replaces `Load a n`, `Push k`, `Slice m n` with
+    /// `Load a+k m`.
+    ///
+    fn slice(
+        instructions: &mut [Instruction],
+        address: usize,
+        slice_length: usize,
+        total_size: usize,
+    ) -> bool {
+        let operand = match super::preceding(instructions, address, 1) {
+            Some(operands) => operands[0],
+            None => return false,
+        };
+        let offset = match Self::constant_usize(&instructions[operand]) {
+            Some(offset) => offset,
+            None => return false,
+        };
+
+        if offset.is_zero() && slice_length == total_size {
+            instructions[operand] = Instruction::NoOperation(zinc_types::NoOperation);
+            instructions[address] = Instruction::NoOperation(zinc_types::NoOperation);
+            return true;
+        }
+
+        let array = match super::preceding(instructions, operand, 1) {
+            Some(operands) => operands[0],
+            None => return false,
+        };
+        let array_address = match instructions[array] {
+            Instruction::Load(ref load)
+                if load.size == total_size && offset + slice_length <= total_size =>
+            {
+                load.address
+            }
+            _ => return false,
+        };
+
+        instructions[array] =
+            Instruction::Load(zinc_types::Load::new(array_address + offset, slice_length));
+        instructions[operand] = Instruction::NoOperation(zinc_types::NoOperation);
+        instructions[address] = Instruction::NoOperation(zinc_types::NoOperation);
+        true
+    }
+
+    ///
+    /// Replaces `Push k`, `LoadByIndex a m n` with `Load a+k m`.
+    ///
+    fn load_by_index(
+        instructions: &mut [Instruction],
+        address: usize,
+        array_address: usize,
+        value_size: usize,
+        total_size: usize,
+    ) -> bool {
+        let operand = match super::preceding(instructions, address, 1) {
+            Some(operands) => operands[0],
+            None => return false,
+        };
+
+        match Self::constant_usize(&instructions[operand]) {
+            Some(index) if index + value_size <= total_size => {
+                instructions[operand] = Instruction::NoOperation(zinc_types::NoOperation);
+                instructions[address] =
+                    Instruction::Load(zinc_types::Load::new(array_address + index, value_size));
+                true
+            }
+            _ => false,
+        }
+    }
+
+    ///
+    /// Replaces `Push k`, `<value>`, `StoreByIndex a m n` with `<value>`, `Store a+k m`, where
+    /// the value is written with a single `Push` or `Load` instruction.
+    ///
+    fn store_by_index(
+        instructions: &mut [Instruction],
+        address: usize,
+        array_address: usize,
+        value_size: usize,
+        total_size: usize,
+    ) -> bool {
+        let operands = match super::preceding(instructions, address, 2) {
+            Some(operands) => operands,
+            None => return false,
+        };
+
+        let is_single_value = match instructions[operands[1]] {
+            Instruction::Push(_) => value_size == 1,
+            Instruction::Load(ref load) => load.size == value_size,
+            _ => false,
+        };
+        if !is_single_value {
+            return false;
+        }
+
+        match Self::constant_usize(&instructions[operands[0]]) {
+            Some(index) if index + value_size <= total_size => {
+                instructions[operands[0]] = Instruction::NoOperation(zinc_types::NoOperation);
+                instructions[address] =
+                    Instruction::Store(zinc_types::Store::new(array_address + index, value_size));
+                true
+            }
+            _ => false,
+        }
+    }
+
+    ///
+    /// Returns the pushed constant, if the instruction is a `Push` of a `usize` value.
+    ///
+    fn constant_usize(instruction: &Instruction) -> Option<usize> {
+        match instruction {
+            Instruction::Push(push) => push.value.to_usize(),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,630 @@
+//!
+//! The bytecode optimizer tests.
+//!
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::str::FromStr;
+
+use num::BigInt;
+
+use zinc_types::Instruction;
+
+use crate::generator::module::Module;
+use crate::generator::zinc_vm::State as ZincVMState;
+use crate::generator::IBytecodeWritable;
+use crate::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
+use crate::source::Source;
+
+use super::level::Level;
+use super::pipeline::Pipeline;
+
+fn optimize_function(instructions: Vec<Instruction>, level: Level) -> Vec<Instruction> {
+    let (instructions, _function_addresses) = optimize(vec![(1, instructions)], level);
+    instructions
+}
+
+fn optimize(
+    functions: Vec<(usize, Vec<Instruction>)>,
+    level: Level,
+) -> (Vec<Instruction>, HashMap<usize, usize>) {
+    let mut instructions = Vec::new();
+    let mut function_addresses = HashMap::new();
+    for (type_id, mut body) in functions.into_iter() {
+        function_addresses.insert(type_id, instructions.len());
+        instructions.append(&mut body);
+    }
+
+    Pipeline::new(level).optimize(&mut instructions, &mut function_addresses);
+
+    (instructions, function_addresses)
+}
+
+fn compile(code: &str, level: Level) -> zinc_types::Application {
+    let path = PathBuf::from("test.zn");
+    let source =
+        Source::test(code, path, HashMap::new()).expect(zinc_const::panic::TEST_DATA_VALID);
+    let project = zinc_project::ManifestProject::new(
+        "test".to_owned(),
+        zinc_project::ProjectType::Circuit,
+        semver::Version::new(1, 0, 0),
+    );
+
+    let scope = EntryAnalyzer::define(source, project, HashMap::new(), false)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let state = ZincVMState::new(zinc_project::Manifest::new(
+        "test",
+        zinc_project::ProjectType::Circuit,
+    ))
+    .wrap();
+    Module::new(scope.borrow().get_intermediate()).write_to_zinc_vm(state.clone());
+
+    ZincVMState::unwrap_rc(state).into_application(level)
+}
+
+fn push(value: i64, scalar_type: zinc_types::ScalarType) -> Instruction {
+    Instruction::Push(zinc_types::Push::new(BigInt::from(value), scalar_type))
+}
+
+fn push_field(value: i64) -> Instruction {
+    push(value, zinc_types::ScalarType::Field)
+}
+
+fn push_u8(value: i64) -> Instruction {
+    push(value, zinc_types::IntegerType::U8.into())
+}
+
+fn push_bool(value: bool) -> Instruction {
+    push(value as i64, zinc_types::ScalarType::Boolean)
+}
+
+fn line(line: usize) -> Instruction {
+    Instruction::LineMarker(zinc_types::LineMarker::new(line))
+}
+
+fn load(address: usize, size: usize) -> Instruction {
+    Instruction::Load(zinc_types::Load::new(address, size))
+}
+
+fn store(address: usize, size: usize) -> Instruction {
+    Instruction::Store(zinc_types::Store::new(address, size))
+}
+
+fn call(type_id: usize, input_size: usize) -> Instruction {
+    Instruction::Call(zinc_types::Call::new(type_id, input_size))
+}
+
+fn r#return(output_size: usize) -> Instruction {
+    Instruction::Return(zinc_types::Return::new(output_size))
+}
+
+#[test]
+fn ok_level_parsing() {
+    assert_eq!(Level::from_str("0"), Ok(Level::None));
+    assert_eq!(Level::from_str("1"), Ok(Level::Basic));
+    assert_eq!(Level::from_str("2"), Ok(Level::Full));
+    assert!(Level::from_str("3").is_err());
+    assert!(!Level::None.is_local_enabled());
+    assert!(Level::Basic.is_dead_function_elimination_enabled());
+    assert!(!Level::Basic.is_inlining_enabled());
+    assert!(Level::Full.is_inlining_enabled());
+}
+
+#[test]
+fn ok_level_none() {
+    let instructions = vec![
+        push_u8(2),
+        push_u8(3),
+        Instruction::Add(zinc_types::Add),
+        r#return(1),
+    ];
+
+    let result = optimize_function(instructions.clone(), Level::None);
+
+    assert_eq!(result, instructions);
+}
+
+#[test]
+fn ok_constant_folding_arithmetic() {
+    let instructions = vec![
+        push_u8(2),
+        line(2),
+        push_u8(3),
+        line(3),
+        Instruction::Add(zinc_types::Add),
+        push_u8(4),
+        Instruction::Mul(zinc_types::Mul),
+        r#return(1),
+    ];
+
+    let expected = vec![push_u8(20), line(2), line(3), r#return(1)];
+
+    let result = optimize_function(instructions, Level::Basic);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_constant_folding_index() {
+    let instructions = vec![
+        push_field(0),
+        push_u8(2),
+        Instruction::Cast(zinc_types::Cast::new(zinc_types::ScalarType::Field)),
+        push_field(3),
+        Instruction::Mul(zinc_types::Mul),
+        Instruction::Add(zinc_types::Add),
+        r#return(1),
+    ];
+
+    let expected = vec![push_field(6), r#return(1)];
+
+    let result = optimize_function(instructions, Level::Basic);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_constant_folding_comparison_logical() {
+    let instructions = vec![
+        push_u8(2),
+        push_u8(3),
+        Instruction::Lt(zinc_types::Lt),
+        push_bool(false),
+        Instruction::Not(zinc_types::Not),
+        Instruction::And(zinc_types::And),
+        r#return(1),
+    ];
+
+    let expected = vec![push_bool(true), r#return(1)];
+
+    let result = optimize_function(instructions, Level::Basic);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_constant_folding_division_euclidean() {
+    let i8_type: zinc_types::ScalarType = zinc_types::IntegerType::I8.into();
+    let instructions = vec![
+        push(-7, i8_type.clone()),
+        push(2, i8_type.clone()),
+        Instruction::Div(zinc_types::Div),
+        push(-7, i8_type.clone()),
+        push(2, i8_type.clone()),
+        Instruction::Rem(zinc_types::Rem),
+        r#return(2),
+    ];
+
+    let expected = vec![push(-4, i8_type.clone()), push(1, i8_type), r#return(2)];
+
+    let result = optimize_function(instructions, Level::Basic);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_constant_folding_overflow_kept() {
+    let instructions = vec![
+        push_u8(200),
+        push_u8(100),
+        Instruction::Add(zinc_types::Add),
+        push_u8(1),
+        push_u8(0),
+        Instruction::Div(zinc_types::Div),
+        r#return(2),
+    ];
+
+    let result = optimize_function(instructions.clone(), Level::Basic);
+
+    assert_eq!(result, instructions);
+}
+
+#[test]
+fn ok_constant_folding_mismatched_types_kept() {
+    let instructions = vec![
+        push_u8(2),
+        push_field(3),
+        Instruction::Add(zinc_types::Add),
+        r#return(1),
+    ];
+
+    let result = optimize_function(instructions.clone(), Level::Basic);
+
+    assert_eq!(result, instructions);
+}
+
+#[test]
+fn ok_redundant_slice_identity() {
+    let instructions = vec![
+        load(0, 3),
+        push_field(0),
+        Instruction::Slice(zinc_types::Slice::new(3, 3)),
+        r#return(3),
+    ];
+
+    let expected = vec![load(0, 3), r#return(3)];
+
+    let result = optimize_function(instructions, Level::Basic);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_redundant_slice_load() {
+    let instructions = vec![
+        load(4, 5),
+        push_field(1),
+        Instruction::Slice(zinc_types::Slice::new(2, 5)),
+        r#return(2),
+    ];
+
+    let expected = vec![load(5, 2), r#return(2)];
+
+    let result = optimize_function(instructions, Level::Basic);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_redundant_slice_out_of_bounds_kept() {
+    let instructions = vec![
+        load(4, 5),
+        push_field(4),
+        Instruction::Slice(zinc_types::Slice::new(2, 5)),
+        r#return(2),
+    ];
+
+    let result = optimize_function(instructions.clone(), Level::Basic);
+
+    assert_eq!(result, instructions);
+}
+
+#[test]
+fn ok_redundant_copy_load_by_index() {
+    let instructions = vec![
+        push_field(1),
+        Instruction::Copy(zinc_types::Copy),
+        Instruction::LoadByIndex(zinc_types::LoadByIndex::new(2, 1, 3)),
+        push_field(5),
+        Instruction::Add(zinc_types::Add),
+        Instruction::StoreByIndex(zinc_types::StoreByIndex::new(2, 1, 3)),
+        load(2, 3),
+        r#return(3),
+    ];
+
+    let expected = vec![
+        push_field(1),
+        load(3, 1),
+        push_field(5),
+        Instruction::Add(zinc_types::Add),
+        Instruction::StoreByIndex(zinc_types::StoreByIndex::new(2, 1, 3)),
+        load(2, 3),
+        r#return(3),
+    ];
+
+    let result = optimize_function(instructions, Level::Basic);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_redundant_store_by_index() {
+    let instructions = vec![
+        push_field(2),
+        push_u8(42),
+        Instruction::StoreByIndex(zinc_types::StoreByIndex::new(0, 1, 3)),
+        load(0, 3),
+        r#return(3),
+    ];
+
+    let expected = vec![push_u8(42), store(2, 1), load(0, 3), r#return(3)];
+
+    let result = optimize_function(instructions, Level::Basic);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_dead_store_constant() {
+    let instructions = vec![
+        push_u8(1),
+        push_u8(2),
+        line(2),
+        store(0, 2),
+        push_u8(3),
+        store(2, 1),
+        load(2, 1),
+        r#return(1),
+    ];
+
+    let expected = vec![line(2), push_u8(3), store(2, 1), load(2, 1), r#return(1)];
+
+    let result = optimize_function(instructions, Level::Basic);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_dead_store_load() {
+    let instructions = vec![
+        load(0, 2),
+        store(4, 2),
+        load(2, 2),
+        store(2, 2),
+        load(0, 1),
+        r#return(1),
+    ];
+
+    let expected = vec![load(0, 1), r#return(1)];
+
+    let result = optimize_function(instructions, Level::Basic);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_dead_store_read_by_index_kept() {
+    let instructions = vec![
+        push_u8(1),
+        store(1, 1),
+        load(3, 1),
+        Instruction::LoadByIndex(zinc_types::LoadByIndex::new(0, 1, 3)),
+        r#return(1),
+    ];
+
+    let result = optimize_function(instructions.clone(), Level::Basic);
+
+    assert_eq!(result, instructions);
+}
+
+#[test]
+fn ok_dead_store_referenced_kept() {
+    let instructions = vec![
+        push_u8(1),
+        push_u8(2),
+        store(0, 2),
+        Instruction::Reference(zinc_types::Reference::new(0, 2)),
+        call(2, 1),
+        r#return(0),
+    ];
+
+    let result = optimize_function(instructions.clone(), Level::Basic);
+
+    assert_eq!(result, instructions);
+}
+
+#[test]
+fn ok_inlining() {
+    let functions = vec![
+        (
+            1,
+            vec![
+                push_u8(5),
+                store(0, 1),
+                load(0, 1),
+                load(0, 1),
+                call(2, 2),
+                r#return(1),
+            ],
+        ),
+        (
+            2,
+            vec![
+                Instruction::FileMarker(zinc_types::FileMarker::new("test.zn".to_owned())),
+                Instruction::FunctionMarker(zinc_types::FunctionMarker::new("add".to_owned())),
+                line(8),
+                load(0, 1),
+                load(1, 1),
+                Instruction::Add(zinc_types::Add),
+                r#return(1),
+            ],
+        ),
+    ];
+
+    let expected = vec![
+        push_u8(5),
+        store(0, 1),
+        load(0, 1),
+        load(0, 1),
+        store(1, 2),
+        load(1, 1),
+        load(2, 1),
+        Instruction::Add(zinc_types::Add),
+        r#return(1),
+        Instruction::FileMarker(zinc_types::FileMarker::new("test.zn".to_owned())),
+        Instruction::FunctionMarker(zinc_types::FunctionMarker::new("add".to_owned())),
+        line(8),
+        load(0, 1),
+        load(1, 1),
+        Instruction::Add(zinc_types::Add),
+        r#return(1),
+    ];
+
+    let (result, function_addresses) = optimize(functions, Level::Full);
+
+    assert_eq!(result, expected);
+    assert_eq!(function_addresses.get(&1).copied(), Some(0));
+    assert_eq!(function_addresses.get(&2).copied(), Some(9));
+}
+
+#[test]
+fn ok_inlining_reference() {
+    let functions = vec![
+        (
+            1,
+            vec![
+                push_u8(5),
+                store(0, 1),
+                Instruction::Reference(zinc_types::Reference::new(0, 1)),
+                call(2, 1),
+                load(0, 1),
+                r#return(1),
+            ],
+        ),
+        (
+            2,
+            vec![
+                load(0, 1),
+                push_u8(10),
+                Instruction::StoreIndirect(zinc_types::StoreIndirect::new(1)),
+                r#return(0),
+            ],
+        ),
+    ];
+
+    let expected = vec![
+        push_u8(5),
+        store(0, 1),
+        Instruction::Reference(zinc_types::Reference::new(0, 1)),
+        store(1, 1),
+        load(1, 1),
+        push_u8(10),
+        Instruction::StoreIndirect(zinc_types::StoreIndirect::new(1)),
+        load(0, 1),
+        r#return(1),
+        load(0, 1),
+        push_u8(10),
+        Instruction::StoreIndirect(zinc_types::StoreIndirect::new(1)),
+        r#return(0),
+    ];
+
+    let (result, _function_addresses) = optimize(functions, Level::Full);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_inlining_disabled() {
+    let functions = vec![
+        (1, vec![push_u8(5), call(2, 1), r#return(1)]),
+        (2, vec![load(0, 1), r#return(1)]),
+    ];
+
+    let (result, _function_addresses) = optimize(functions, Level::Basic);
+
+    assert_eq!(
+        result,
+        vec![push_u8(5), call(2, 1), r#return(1), load(0, 1), r#return(1)]
+    );
+}
+
+#[test]
+fn ok_inlining_conditional_call_kept() {
+    let functions = vec![
+        (
+            1,
+            vec![
+                push_bool(true),
+                store(0, 1),
+                load(0, 1),
+                Instruction::If(zinc_types::If),
+                push_u8(5),
+                call(2, 1),
+                Instruction::Else(zinc_types::Else),
+                push_u8(6),
+                Instruction::EndIf(zinc_types::EndIf),
+                r#return(1),
+            ],
+        ),
+        (2, vec![load(0, 1), r#return(1)]),
+    ];
+
+    let (result, _function_addresses) = optimize(functions.clone(), Level::Full);
+
+    let expected: Vec<Instruction> = functions
+        .into_iter()
+        .flat_map(|(_type_id, body)| body.into_iter())
+        .collect();
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_inlining_conditional_callee_kept() {
+    let functions = vec![
+        (1, vec![push_bool(true), call(2, 1), r#return(1)]),
+        (
+            2,
+            vec![
+                load(0, 1),
+                Instruction::If(zinc_types::If),
+                push_u8(1),
+                Instruction::Else(zinc_types::Else),
+                push_u8(2),
+                Instruction::EndIf(zinc_types::EndIf),
+                r#return(1),
+            ],
+        ),
+    ];
+
+    let (result, _function_addresses) = optimize(functions.clone(), Level::Full);
+
+    let expected: Vec<Instruction> = functions
+        .into_iter()
+        .flat_map(|(_type_id, body)| body.into_iter())
+        .collect();
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_application_optimized() {
+    let code = r#"
+fn square(value: u8) -> u8 {
+    value * value
+}
+
+fn unused() -> u8 {
+    42
+}
+
+fn main(input: [u8; 3]) -> u8 {
+    let mut array = input;
+    array[1] += 2 * 3;
+    square(array[1])
+}
+"#;
+
+    let unoptimized = match compile(code, Level::None) {
+        zinc_types::Application::Circuit(circuit) => circuit,
+        _ => panic!("{}", zinc_const::panic::TEST_DATA_VALID),
+    };
+    let optimized = match compile(code, Level::Full) {
+        zinc_types::Application::Circuit(circuit) => circuit,
+        _ => panic!("{}", zinc_const::panic::TEST_DATA_VALID),
+    };
+
+    let count = |instructions: &[Instruction], predicate: fn(&Instruction) -> bool| {
+        instructions
+            .iter()
+            .filter(|instruction| predicate(instruction))
+            .count()
+    };
+
+    assert!(optimized.instructions.len() < unoptimized.instructions.len());
+    assert_eq!(
+        count(optimized.instructions.as_slice(), |instruction| matches!(
+            instruction,
+            Instruction::Call(_)
+        )),
+        0
+    );
+    assert_eq!(
+        count(optimized.instructions.as_slice(), |instruction| matches!(
+            instruction,
+            Instruction::LoadByIndex(_) | Instruction::Copy(_)
+        )),
+        0
+    );
+    assert!(
+        count(unoptimized.instructions.as_slice(), |instruction| matches!(
+            instruction,
+            Instruction::LoadByIndex(_)
+        )) > 0
+    );
+    assert_eq!(
+        optimized.instructions.get(optimized.address),
+        Some(&Instruction::FileMarker(zinc_types::FileMarker::new(
+            "test.zn".to_owned()
+        )))
+    );
+}
@@ -3,18 +3,31 @@
 //!
 
 pub(crate) mod bundler;
+pub(crate) mod diagnostic;
 pub(crate) mod error;
 pub(crate) mod generator;
 pub(crate) mod semantic;
 pub(crate) mod source;
 
 pub use self::bundler::Bundler;
+pub use self::diagnostic::label::Label as DiagnosticLabel;
+pub use self::diagnostic::level::Level as DiagnosticLevel;
+pub use self::diagnostic::message_format::MessageFormat;
+pub use self::diagnostic::span::Span as DiagnosticSpan;
+pub use self::diagnostic::Diagnostic;
 pub use self::error::Error;
 pub use self::generator::module::Module;
+pub use self::generator::zinc_vm::optimizer::level::Level as OptimizationLevel;
 pub use self::generator::zinc_vm::State as ZincVMState;
 pub use self::generator::IBytecodeWritable;
 pub use self::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
+pub use self::semantic::error::Error as SemanticError;
+pub use self::semantic::scope::analysis::reference::Reference as AnalysisReference;
+pub use self::semantic::scope::analysis::Analysis;
+pub use self::semantic::scope::item::Item as ScopeItem;
 pub use self::semantic::scope::Scope;
+pub use self::semantic::warning::lint::Lint;
+pub use self::semantic::warning::Warning as SemanticWarning;
 pub use self::source::directory::Directory as SourceDirectory;
 pub use self::source::error::Error as SourceError;
 pub use self::source::file::File as SourceFile;
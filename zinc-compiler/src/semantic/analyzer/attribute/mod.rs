@@ -6,6 +6,7 @@
 mod tests;
 
 use std::convert::TryFrom;
+use std::str::FromStr;
 
 use zinc_syntax::Attribute as SyntaxAttribute;
 use zinc_syntax::AttributeElementVariant as SyntaxAttributeElementVariant;
@@ -13,6 +14,7 @@ use zinc_syntax::Literal;
 
 use crate::semantic::element::constant::integer::Integer as IntegerConstant;
 use crate::semantic::error::Error;
+use crate::semantic::warning::lint::Lint;
 
 ///
 /// The semantic attribute.
@@ -27,6 +29,8 @@ pub enum Attribute {
     Ignore,
     /// The `#[zksync::msg(...)]` attribute.
     ZksyncMsg(zinc_types::TransactionMsg),
+    /// The `#[allow(...)]` attribute.
+    Allow(Vec<Lint>),
 }
 
 impl Attribute {
@@ -39,6 +43,17 @@ impl Attribute {
             Self::ShouldPanic => true,
             Self::Ignore => true,
             Self::ZksyncMsg { .. } => true,
+            Self::Allow(_) => false,
+        }
+    }
+
+    ///
+    /// Returns the lints allowed by the attribute.
+    ///
+    pub fn allowed_lints(&self) -> &[Lint] {
+        match self {
+            Self::Allow(lints) => lints.as_slice(),
+            _ => &[],
         }
     }
 }
@@ -56,6 +71,28 @@ impl TryFrom<SyntaxAttribute> for Attribute {
             "test" => Self::Test,
             "should_panic" => Self::ShouldPanic,
             "ignore" => Self::Ignore,
+            "allow" => match element.variant {
+                Some(SyntaxAttributeElementVariant::Nested(ref nested)) => {
+                    let mut lints = Vec::with_capacity(nested.len());
+                    for element in nested.iter() {
+                        let name = element.path.to_string();
+                        let lint = Lint::from_str(name.as_str()).map_err(|_error| {
+                            Error::AttributeUnknownLint {
+                                location: element.location,
+                                found: name,
+                            }
+                        })?;
+                        lints.push(lint);
+                    }
+                    Self::Allow(lints)
+                }
+                _ => {
+                    return Err(Error::AttributeExpectedNested {
+                        location: element.location,
+                        name: "allow".to_owned(),
+                    })
+                }
+            },
             "zksync::msg" => match element.variant {
                 Some(SyntaxAttributeElementVariant::Nested(ref mut nested)) => {
                     if nested.len() != zinc_const::contract::TRANSACTION_FIELDS_COUNT {
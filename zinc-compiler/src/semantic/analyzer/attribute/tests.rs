@@ -94,6 +94,62 @@ fn test() {}
     assert_eq!(result, expected);
 }
 
+#[test]
+fn ok_allow() {
+    let input = r#"
+fn main() {}
+
+#[allow(unused_variables, dead_code)]
+fn unused() {
+    let value = 42;
+}
+"#;
+
+    let expected = Ok(vec![]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_unknown_lint_allow() {
+    let input = r#"
+fn main() {}
+
+#[allow(unknown)]
+fn test() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::AttributeUnknownLint {
+        location: Location::test(4, 9),
+        found: "unknown".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_expected_nested_allow() {
+    let input = r#"
+fn main() {}
+
+#[allow]
+fn test() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::AttributeExpectedNested {
+        location: Location::test(4, 3),
+        name: "allow".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn ok_zksync_msg() {
     let input = r#"
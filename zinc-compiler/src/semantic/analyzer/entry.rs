@@ -7,6 +7,7 @@ use std::collections::HashMap;
 use std::rc::Rc;
 
 use crate::semantic::error::Error;
+use crate::semantic::scope::analysis::Analysis;
 use crate::semantic::scope::item::module::Module as ScopeModuleItem;
 use crate::semantic::scope::item::Item as ScopeItem;
 use crate::semantic::scope::Scope;
@@ -27,7 +28,52 @@ impl Analyzer {
         dependencies: HashMap<String, Rc<RefCell<Scope>>>,
         is_dependency_entry: bool,
     ) -> Result<Rc<RefCell<Scope>>, Error> {
-        let entry = ScopeModuleItem::new_entry(module, project, dependencies, is_dependency_entry)?;
+        Self::define_inner(module, project, dependencies, is_dependency_entry, None)
+    }
+
+    ///
+    /// Analyzes the entry module for the tooling, going on with the next items after errors.
+    ///
+    /// Returns the analysis data with the errors, resolved references, and the entry module scope.
+    ///
+    pub fn check(
+        module: Source,
+        project: zinc_project::ManifestProject,
+        dependencies: HashMap<String, Rc<RefCell<Scope>>>,
+    ) -> Analysis {
+        let analysis = Analysis::default().wrap();
+
+        let result =
+            Self::define_inner(module, project, dependencies, false, Some(analysis.clone()));
+
+        let mut analysis = analysis.replace(Analysis::default());
+        match result {
+            Ok(scope) => {
+                analysis.warnings = scope.borrow().take_warnings();
+                analysis.scope = Some(scope);
+            }
+            Err(error) => analysis.push_error(error),
+        }
+        analysis
+    }
+
+    ///
+    /// Defines the entry module, saving the errors to `analysis` if it is set.
+    ///
+    fn define_inner(
+        module: Source,
+        project: zinc_project::ManifestProject,
+        dependencies: HashMap<String, Rc<RefCell<Scope>>>,
+        is_dependency_entry: bool,
+        analysis: Option<Rc<RefCell<Analysis>>>,
+    ) -> Result<Rc<RefCell<Scope>>, Error> {
+        let entry = ScopeModuleItem::new_entry(
+            module,
+            project,
+            dependencies,
+            is_dependency_entry,
+            analysis,
+        )?;
         entry.borrow().define()?;
 
         let entry = entry.borrow();
@@ -40,10 +86,10 @@ impl Analyzer {
             if let (Some(main_location), Some(contract_location)) =
                 (main_function_location, contract_location)
             {
-                return Err(Error::EntryPointAmbiguous {
+                scope.borrow().recover(Err(Error::EntryPointAmbiguous {
                     main: main_location,
                     contract: contract_location,
-                });
+                }))?;
             }
 
             Ok(scope)
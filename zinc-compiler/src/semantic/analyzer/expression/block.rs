@@ -10,12 +10,17 @@ use zinc_syntax::FunctionLocalStatement;
 
 use crate::generator::expression::operand::block::builder::Builder as GeneratorBlockExpressionBuilder;
 use crate::generator::expression::operand::block::Expression as GeneratorBlockExpression;
+use crate::generator::r#type::Type as GeneratorType;
 use crate::generator::statement::Statement as GeneratorStatement;
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
 use crate::semantic::analyzer::rule::Rule as TranslationRule;
+use crate::semantic::analyzer::statement::r#break::Analyzer as BreakStatementAnalyzer;
 use crate::semantic::analyzer::statement::r#const::Analyzer as ConstStatementAnalyzer;
+use crate::semantic::analyzer::statement::r#continue::Analyzer as ContinueStatementAnalyzer;
 use crate::semantic::analyzer::statement::r#for::Analyzer as ForStatementAnalyzer;
 use crate::semantic::analyzer::statement::r#let::Analyzer as LetStatementAnalyzer;
+use crate::semantic::analyzer::statement::r#return::Analyzer as ReturnStatementAnalyzer;
+use crate::semantic::element::r#type::Type;
 use crate::semantic::element::value::unit::Unit as UnitValue;
 use crate::semantic::element::value::Value;
 use crate::semantic::element::Element;
@@ -46,6 +51,8 @@ impl Analyzer {
         scope_stack.push(None, ScopeType::Block);
 
         for statement in block.statements.into_iter() {
+            let early_exits = RefCell::borrow(&scope_stack.top()).early_exits();
+
             let intermediate = match statement {
                 FunctionLocalStatement::Let(statement) => {
                     LetStatementAnalyzer::define(scope_stack.top(), statement)?
@@ -60,6 +67,15 @@ impl Analyzer {
                 FunctionLocalStatement::For(statement) => Some(GeneratorStatement::For(
                     ForStatementAnalyzer::define(scope_stack.top(), statement)?,
                 )),
+                FunctionLocalStatement::Return(statement) => Some(GeneratorStatement::Return(
+                    ReturnStatementAnalyzer::define(scope_stack.top(), statement)?,
+                )),
+                FunctionLocalStatement::Break(statement) => Some(GeneratorStatement::Break(
+                    BreakStatementAnalyzer::define(scope_stack.top(), statement)?,
+                )),
+                FunctionLocalStatement::Continue(statement) => Some(GeneratorStatement::Continue(
+                    ContinueStatementAnalyzer::define(scope_stack.top(), statement)?,
+                )),
                 FunctionLocalStatement::Expression(expression) => {
                     let (_result, expression) =
                         ExpressionAnalyzer::new(scope_stack.top(), rule).analyze(expression)?;
@@ -69,8 +85,9 @@ impl Analyzer {
                 FunctionLocalStatement::Empty(_location) => None,
             };
 
+            let is_exiting = RefCell::borrow(&scope_stack.top()).early_exits() > early_exits;
             if let Some(intermediate) = intermediate {
-                builder.push_statement(intermediate);
+                builder.push_statement(intermediate, is_exiting);
             }
         }
 
@@ -79,6 +96,14 @@ impl Analyzer {
                 let (element, expression) =
                     ExpressionAnalyzer::new(scope_stack.top(), rule).analyze(*expression)?;
                 builder.set_expression(expression);
+                if builder.is_exiting() {
+                    if let Some(r#type) = GeneratorType::try_from_semantic(&Type::from_element(
+                        &element,
+                        scope_stack.top(),
+                    )?) {
+                        builder.set_expression_type(r#type);
+                    }
+                }
                 element
             }
             None => Element::Value(Value::Unit(UnitValue::new(Some(block.location)))),
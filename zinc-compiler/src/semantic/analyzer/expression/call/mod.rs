@@ -7,21 +7,40 @@ pub mod r#type;
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use num::BigInt;
+use num::Zero;
+
 use zinc_lexical::Location;
 
 use crate::generator::expression::element::Element as GeneratorExpressionElement;
+use crate::generator::expression::operand::constant::integer::Integer as GeneratorIntegerConstant;
 use crate::generator::expression::operand::constant::Constant as GeneratorConstant;
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
 use crate::generator::expression::operator::Operator as GeneratorExpressionOperator;
+use crate::generator::expression::Expression as GeneratorExpression;
 use crate::generator::r#type::contract_field::ContractField as GeneratorContractField;
+use crate::generator::r#type::Type as GeneratorType;
+use crate::semantic::element::access::dot::stack_field::StackField as StackFieldAccess;
+use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::place::element::Element as PlaceElement;
+use crate::semantic::element::place::Place;
+use crate::semantic::element::r#type::enumeration::payload::Payload as EnumerationPayload;
+use crate::semantic::element::r#type::enumeration::Enumeration as EnumerationType;
+use crate::semantic::element::r#type::function::intrinsic::require::Function as RequireFunction;
+use crate::semantic::element::r#type::function::intrinsic::vec::Function as VecFunction;
 use crate::semantic::element::r#type::function::intrinsic::Function as IntrinsicFunctionType;
 use crate::semantic::element::r#type::function::Function as FunctionType;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::structure::Structure as StructureType;
 use crate::semantic::element::r#type::Type;
+use crate::semantic::element::value::enumeration::Enumeration as EnumerationValue;
 use crate::semantic::element::value::Value;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
 use crate::semantic::scope::item::Item as ScopeItem;
 use crate::semantic::scope::Scope;
+use crate::semantic::warning::Warning;
 
 use self::r#type::Type as CallType;
 
@@ -48,6 +67,18 @@ impl Analyzer {
         let function = match operand_1 {
             Element::Type(Type::Function(function)) => function,
             Element::Path(path) => match *Scope::resolve_path(scope.clone(), &path)?.borrow() {
+                ScopeItem::Variant(ref variant) if variant.data().is_some() => {
+                    let (enumeration, index) = variant
+                        .data()
+                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+
+                    return Self::variant(
+                        function_location.unwrap_or(location),
+                        enumeration.to_owned(),
+                        index,
+                        operand_2,
+                    );
+                }
                 ScopeItem::Type(ref r#type) => {
                     let r#type = r#type.define()?;
 
@@ -86,21 +117,36 @@ impl Analyzer {
         if let CallType::Method {
             instance,
             is_mutable,
+            identifier,
         } = call_type
         {
             argument_list.arguments.insert(0, *instance);
 
-            if !is_mutable && function.is_mutable() {
-                return Err(Error::FunctionCallMutableFromImmutable {
-                    location,
-                    function: function.identifier(),
-                });
+            if function.is_mutable() {
+                if !is_mutable {
+                    return Err(Error::FunctionCallMutableFromImmutable {
+                        location,
+                        function: function.identifier(),
+                    });
+                }
+
+                if let Some(ref identifier) = identifier {
+                    RefCell::borrow(&scope).mark_mutated(identifier);
+                }
             }
         }
 
+        let function = match function {
+            FunctionType::Generic(function) => function.instantiate(location, &argument_list)?,
+            function => function,
+        };
+
         let mut input_size = 0;
         for element in argument_list.arguments.iter() {
-            input_size += Type::from_element(element, scope.clone())?.size();
+            input_size += match element {
+                Element::Reference(_) => Type::field(None).size(),
+                element => Type::from_element(element, scope.clone())?.size(),
+            };
         }
 
         let (element, intermediate) = match function {
@@ -132,6 +178,18 @@ impl Analyzer {
                         )
                     }
                     IntrinsicFunctionType::Require(function) => {
+                        if let Some(Element::Constant(Constant::Boolean(ref condition))) =
+                            argument_list
+                                .arguments
+                                .get(RequireFunction::ARGUMENT_INDEX_CONDITION)
+                        {
+                            if condition.is_true() {
+                                RefCell::borrow(&scope).warn(Warning::NoopRequire {
+                                    location: function_location.unwrap_or(location),
+                                });
+                            }
+                        }
+
                         let (return_type, message) =
                             function.call(function_location.unwrap_or(location), argument_list)?;
 
@@ -198,6 +256,42 @@ impl Analyzer {
                             },
                         )
                     }
+                    IntrinsicFunctionType::ConvertFromStrBytes(function) => {
+                        if is_called_with_exclamation_mark {
+                            return Err(Error::FunctionUnexpectedExclamationMark {
+                                location: function_location.unwrap_or(location),
+                                function: function.identifier.to_owned(),
+                            });
+                        }
+
+                        let constant =
+                            function.call(function_location.unwrap_or(location), argument_list)?;
+
+                        let intermediate = GeneratorConstant::try_from_semantic(&constant)
+                            .unwrap_or_else(|| GeneratorConstant::Group(vec![]));
+
+                        (
+                            Element::Constant(constant),
+                            GeneratorExpressionElement::Operand(
+                                GeneratorExpressionOperand::Constant(intermediate),
+                            ),
+                        )
+                    }
+                    IntrinsicFunctionType::Vec(function) => {
+                        if is_called_with_exclamation_mark {
+                            return Err(Error::FunctionUnexpectedExclamationMark {
+                                location: function_location.unwrap_or(location),
+                                function: function.identifier().to_owned(),
+                            });
+                        }
+
+                        Self::vec(
+                            scope,
+                            function_location.unwrap_or(location),
+                            function,
+                            argument_list,
+                        )?
+                    }
                     IntrinsicFunctionType::StandardLibrary(function) => {
                         if is_called_with_exclamation_mark {
                             return Err(Error::FunctionUnexpectedExclamationMark {
@@ -283,7 +377,254 @@ impl Analyzer {
                     function: function.identifier,
                 });
             }
+            FunctionType::Generic(_) => {
+                panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS)
+            }
+        };
+
+        Ok((element, intermediate))
+    }
+
+    ///
+    /// Analyzes the `std::collections::Vec` function call.
+    ///
+    /// The vector functions are translated into the data stack and contract storage operations
+    /// on the vector fields, so the instance of the mutating functions must be a memory place.
+    ///
+    fn vec(
+        scope: Rc<RefCell<Scope>>,
+        location: Location,
+        function: VecFunction,
+        argument_list: ArgumentList,
+    ) -> Result<(Element, GeneratorExpressionElement), Error> {
+        let length_type = Type::integer_unsigned(None, zinc_const::bitlength::INDEX);
+
+        match function {
+            VecFunction::New(function) => {
+                let return_type = function.call(location, argument_list)?;
+                let element =
+                    Value::try_from_type(&return_type, false, None).map(Element::Value)?;
+
+                let intermediate = GeneratorExpressionElement::Operator {
+                    location,
+                    operator: GeneratorExpressionOperator::call_vec_new(&return_type),
+                };
+
+                Ok((element, intermediate))
+            }
+            VecFunction::FromArray(function) => {
+                let (element_type, capacity, is_checked) =
+                    function.call(location, argument_list)?;
+
+                let return_type = Scope::resolve_vec(location, scope, element_type, capacity);
+                let element =
+                    Value::try_from_type(&return_type, false, None).map(Element::Value)?;
+
+                let intermediate = GeneratorExpressionElement::Operator {
+                    location,
+                    operator: GeneratorExpressionOperator::call_vec_from_array(
+                        capacity, is_checked,
+                    ),
+                };
+
+                Ok((element, intermediate))
+            }
+            VecFunction::Len(function) => {
+                let intermediate = match function.call(location, argument_list)? {
+                    Element::Place(instance) => {
+                        let (_data, length) = instance.vec_fields();
+
+                        GeneratorExpressionElement::Operand(GeneratorExpressionOperand::Place(
+                            length.into(),
+                        ))
+                    }
+                    instance => {
+                        let r#type = Type::from_element(&instance, scope)?;
+                        let length_offset = r#type.size() - length_type.size();
+
+                        GeneratorExpressionElement::Operator {
+                            location,
+                            operator: GeneratorExpressionOperator::slice(StackFieldAccess::new(
+                                StructureType::VEC_FIELD_LENGTH.to_owned(),
+                                1,
+                                length_offset,
+                                length_type.size(),
+                                r#type.size(),
+                            )),
+                        }
+                    }
+                };
+
+                let element =
+                    Value::try_from_type(&length_type, false, None).map(Element::Value)?;
+
+                Ok((element, intermediate))
+            }
+            VecFunction::Push(function) => {
+                let instance = function.call(location, argument_list)?;
+                let (data, length) = instance.vec_fields();
+                let capacity = match data.r#type {
+                    Type::Array(ref array) => array.size,
+                    _ => panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+                };
+
+                let mut index = GeneratorExpression::new();
+                index.push_operand(GeneratorExpressionOperand::Place(length.clone().into()));
+                let element = Self::vec_element(data, index)?;
+
+                let intermediate = GeneratorExpressionElement::Operator {
+                    location,
+                    operator: GeneratorExpressionOperator::call_vec_push(
+                        element.into(),
+                        length.into(),
+                        capacity,
+                    ),
+                };
+
+                let element =
+                    Value::try_from_type(&Type::unit(None), false, None).map(Element::Value)?;
+
+                Ok((element, intermediate))
+            }
+            VecFunction::Pop(function) => {
+                let instance = function.call(location, argument_list)?;
+                let element_type = instance.vec_element_type();
+                let (data, length) = instance.vec_fields();
+
+                // the index `length - (length > 0) as u64` stays in bounds in the dead branches,
+                // where the empty vector check is skipped
+                let mut index = GeneratorExpression::new();
+                index.push_operand(GeneratorExpressionOperand::Place(length.clone().into()));
+                index.push_operand(GeneratorExpressionOperand::Place(length.clone().into()));
+                index.push_operand(GeneratorExpressionOperand::Constant(
+                    GeneratorConstant::Integer(GeneratorIntegerConstant::new(
+                        BigInt::zero(),
+                        false,
+                        zinc_const::bitlength::INDEX,
+                    )),
+                ));
+                index.push_operator(location, GeneratorExpressionOperator::greater());
+                index.push_operator(
+                    location,
+                    GeneratorExpressionOperator::casting(GeneratorType::integer_unsigned(
+                        zinc_const::bitlength::INDEX,
+                    )),
+                );
+                index.push_operator(location, GeneratorExpressionOperator::subtraction());
+                let element = Self::vec_element(data, index)?;
+
+                let intermediate = GeneratorExpressionElement::Operator {
+                    location,
+                    operator: GeneratorExpressionOperator::call_vec_pop(
+                        element.into(),
+                        &element_type,
+                        length.into(),
+                    ),
+                };
+
+                let element =
+                    Value::try_from_type(&element_type, false, None).map(Element::Value)?;
+
+                Ok((element, intermediate))
+            }
+        }
+    }
+
+    ///
+    /// Returns the vector `data` array element at the runtime `index`.
+    ///
+    fn vec_element(data: Place, index: GeneratorExpression) -> Result<Place, Error> {
+        let index_type = Type::integer_unsigned(None, zinc_const::bitlength::INDEX);
+        let index_value = Value::try_from_type(&index_type, false, None).map(Element::Value)?;
+
+        let (mut element, access) = data.index(index_value)?;
+        element.push_element(PlaceElement::IndexExpression {
+            expression: index,
+            access,
+        });
+
+        Ok(element)
+    }
+
+    ///
+    /// Analyzes the tuple-like enumeration variant initialization, which looks like
+    /// a function call, e.g. `Option::Some(42)`.
+    ///
+    fn variant(
+        location: Location,
+        enumeration: EnumerationType,
+        index: usize,
+        operand_2: Element,
+    ) -> Result<(Element, GeneratorExpressionElement), Error> {
+        let types = match enumeration.payloads[index] {
+            EnumerationPayload::Tuple(ref types) => types,
+            _ => {
+                return Err(Error::TypeVariantExpectedTuple {
+                    location,
+                    variant: enumeration.variant_signature(index),
+                })
+            }
+        };
+
+        let argument_list = match operand_2 {
+            Element::ArgumentList(values) => values,
+            _ => panic!("{}", zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS),
+        };
+
+        let variant = enumeration.variant_name(index);
+        if argument_list.arguments.len() != types.len() {
+            return Err(Error::FunctionArgumentCount {
+                location,
+                function: variant,
+                expected: types.len(),
+                found: argument_list.arguments.len(),
+                reference: Some(argument_list.location),
+            });
+        }
+
+        for (position, (element, expected)) in argument_list
+            .arguments
+            .into_iter()
+            .zip(types.iter())
+            .enumerate()
+        {
+            let element_location = element
+                .location()
+                .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+
+            let r#type = match element {
+                Element::Value(value) => value.r#type(),
+                Element::Constant(constant) => constant.r#type(),
+                element => {
+                    return Err(Error::FunctionArgumentNotEvaluable {
+                        location: element_location,
+                        function: variant,
+                        position: position + 1,
+                        found: element.to_string(),
+                    })
+                }
+            };
+
+            if &r#type != expected {
+                return Err(Error::FunctionArgumentType {
+                    location: element_location,
+                    function: variant,
+                    name: position.to_string(),
+                    position: position + 1,
+                    expected: expected.to_string(),
+                    found: r#type.to_string(),
+                });
+            }
+        }
+
+        let intermediate = GeneratorExpressionElement::Operator {
+            location,
+            operator: GeneratorExpressionOperator::enumeration_variant(&enumeration, index),
         };
+        let element = Element::Value(Value::Enumeration(EnumerationValue::new(
+            Some(location),
+            enumeration,
+        )));
 
         Ok((element, intermediate))
     }
@@ -4,6 +4,8 @@
 
 use std::mem;
 
+use zinc_syntax::Identifier;
+
 use crate::semantic::element::Element;
 
 ///
@@ -21,6 +23,8 @@ pub enum Type {
         instance: Box<Element>,
         /// Whether the instance, for which the method is called, is mutable.
         is_mutable: bool,
+        /// The instance variable identifier, if the instance is a memory place.
+        identifier: Option<Identifier>,
     },
 }
 
@@ -34,10 +38,11 @@ impl Type {
     ///
     /// A shortcut constructor.
     ///
-    pub fn new_method(instance: Element, is_mutable: bool) -> Self {
+    pub fn new_method(instance: Element, is_mutable: bool, identifier: Option<Identifier>) -> Self {
         Self::Method {
             instance: Box::new(instance),
             is_mutable,
+            identifier,
         }
     }
 }
@@ -8,11 +8,20 @@ mod tests;
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use zinc_syntax::BlockExpression;
 use zinc_syntax::ConditionalExpression;
+use zinc_syntax::ExpressionOperand;
+use zinc_syntax::ExpressionTree;
+use zinc_syntax::ExpressionTreeNode;
+use zinc_syntax::MatchExpression;
+use zinc_syntax::MatchPattern;
+use zinc_syntax::MatchPatternVariant;
 
 use crate::generator::expression::operand::conditional::builder::Builder as GeneratorConditionalExpressionBuilder;
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
+use crate::generator::r#type::Type as GeneratorType;
 use crate::semantic::analyzer::expression::block::Analyzer as BlockAnalyzer;
+use crate::semantic::analyzer::expression::r#match::Analyzer as MatchAnalyzer;
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
 use crate::semantic::analyzer::rule::Rule as TranslationRule;
 use crate::semantic::element::constant::unit::Unit as UnitConstant;
@@ -38,9 +47,13 @@ impl Analyzer {
     ///
     pub fn analyze(
         scope: Rc<RefCell<Scope>>,
-        conditional: ConditionalExpression,
+        mut conditional: ConditionalExpression,
         rule: TranslationRule,
     ) -> Result<(Element, Option<GeneratorExpressionOperand>), Error> {
+        if let Some(pattern) = conditional.pattern.take() {
+            return MatchAnalyzer::analyze(scope, Self::r#match(conditional, pattern), rule);
+        }
+
         match rule {
             TranslationRule::Constant => {
                 Self::constant(scope, conditional).map(|element| (element, None))
@@ -97,30 +110,38 @@ impl Analyzer {
         }
         builder.set_condition(condition);
 
+        let is_main_diverging = conditional.main_block.is_diverging();
         scope_stack.push(None, ScopeType::Conditional);
-        let (main_result, main_block) = BlockAnalyzer::analyze(
+        let (main_result, mut main_block) = BlockAnalyzer::analyze(
             scope_stack.top(),
             conditional.main_block,
             TranslationRule::Value,
         )?;
         let main_type = Type::from_element(&main_result, scope_stack.top())?;
         scope_stack.pop();
-        builder.set_main_block(main_block);
 
-        let else_type = if let Some(else_block) = conditional.else_block {
-            scope_stack.push(None, ScopeType::Conditional);
-            let (else_result, else_block) =
-                BlockAnalyzer::analyze(scope_stack.top(), else_block, TranslationRule::Value)?;
-            let else_type = Type::from_element(&else_result, scope_stack.top())?;
-            scope_stack.pop();
-            builder.set_else_block(else_block);
+        let (else_result, else_type, mut else_block, is_else_diverging) = match conditional
+            .else_block
+        {
+            Some(else_block) => {
+                let is_else_diverging = else_block.is_diverging();
+                scope_stack.push(None, ScopeType::Conditional);
+                let (else_result, else_block) =
+                    BlockAnalyzer::analyze(scope_stack.top(), else_block, TranslationRule::Value)?;
+                let else_type = Type::from_element(&else_result, scope_stack.top())?;
+                scope_stack.pop();
 
-            else_type
-        } else {
-            Type::unit(None)
+                (
+                    Some(else_result),
+                    else_type,
+                    Some(else_block),
+                    is_else_diverging,
+                )
+            }
+            None => (None, Type::unit(None), None, false),
         };
 
-        if main_type != else_type {
+        if main_type != else_type && !is_main_diverging && !is_else_diverging {
             return Err(Error::ConditionalBranchTypesMismatch {
                 location: main_expression_location,
                 expected: main_type.to_string(),
@@ -129,7 +150,24 @@ impl Analyzer {
             });
         }
 
-        let element = main_result;
+        // the diverging branch does not take part in the type unification
+        let (element, result_type) = match else_result {
+            Some(else_result) if is_main_diverging && !is_else_diverging => {
+                (else_result, else_type)
+            }
+            _ => (main_result, main_type),
+        };
+
+        if let Some(r#type) = GeneratorType::try_from_semantic(&result_type) {
+            main_block.set_diverging_type(r#type.clone());
+            if let Some(ref mut else_block) = else_block {
+                else_block.set_diverging_type(r#type);
+            }
+        }
+        builder.set_main_block(main_block);
+        if let Some(else_block) = else_block {
+            builder.set_else_block(else_block);
+        }
 
         let intermediate = GeneratorExpressionOperand::Conditional(builder.finish());
 
@@ -248,4 +286,39 @@ impl Analyzer {
 
         Ok(element)
     }
+
+    ///
+    /// Converts the `if let` expression into the equivalent `match` expression, where the
+    /// `else` block is taken by the wildcard branch.
+    ///
+    fn r#match(conditional: ConditionalExpression, pattern: MatchPattern) -> MatchExpression {
+        let location = conditional.location;
+
+        let else_block = conditional
+            .else_block
+            .unwrap_or_else(|| BlockExpression::new(location, vec![], None));
+        let else_location = else_block.location;
+
+        let main_block = ExpressionTree::new(
+            conditional.main_block.location,
+            ExpressionTreeNode::operand(ExpressionOperand::Block(conditional.main_block)),
+        );
+        let else_block = ExpressionTree::new(
+            else_location,
+            ExpressionTreeNode::operand(ExpressionOperand::Block(else_block)),
+        );
+
+        MatchExpression::new(
+            location,
+            *conditional.condition,
+            vec![
+                (pattern, None, main_block),
+                (
+                    MatchPattern::new(else_location, MatchPatternVariant::Wildcard),
+                    None,
+                    else_block,
+                ),
+            ],
+        )
+    }
 }
@@ -54,7 +54,7 @@ impl Analyzer {
         for expression in list.elements.into_iter() {
             let (element, expression) =
                 ExpressionAnalyzer::new(scope.clone(), TranslationRule::Value)
-                    .analyze(expression)?;
+                    .analyze_argument(expression)?;
             arguments.push(element);
 
             builder.push_expression(expression);
@@ -5,11 +5,13 @@
 use std::convert::TryFrom;
 
 use zinc_syntax::BooleanLiteral;
+use zinc_syntax::BytesLiteral;
 use zinc_syntax::IntegerLiteral;
 use zinc_syntax::StringLiteral;
 
 use crate::generator::expression::operand::constant::Constant as GeneratorConstant;
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
+use crate::semantic::element::constant::array::Array as ArrayConstant;
 use crate::semantic::element::constant::boolean::Boolean as BooleanConstant;
 use crate::semantic::element::constant::integer::Integer as IntegerConstant;
 use crate::semantic::element::constant::string::String as StringConstant;
@@ -57,6 +59,23 @@ impl Analyzer {
         Ok((element, intermediate))
     }
 
+    ///
+    /// Analyzes the bytes literal, which is turned into a `[u8; N]` constant array.
+    ///
+    /// Returns the semantic element and the intermediate representation if it is available.
+    ///
+    pub fn bytes(
+        literal: BytesLiteral,
+    ) -> Result<(Element, Option<GeneratorExpressionOperand>), Error> {
+        let constant = Constant::Array(ArrayConstant::from(literal));
+
+        let intermediate = GeneratorConstant::try_from_semantic(&constant)
+            .map(GeneratorExpressionOperand::Constant);
+        let element = Element::Constant(constant);
+
+        Ok((element, intermediate))
+    }
+
     ///
     /// Converts the syntax string literal to a semantic string literal.
     ///
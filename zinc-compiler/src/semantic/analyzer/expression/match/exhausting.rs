@@ -9,6 +9,7 @@ use num::One;
 use num::Zero;
 
 use crate::semantic::element::r#type::enumeration::Enumeration;
+use crate::semantic::element::r#type::Type;
 use zinc_lexical::Location;
 
 ///
@@ -86,3 +87,333 @@ impl Data {
         }
     }
 }
+
+///
+/// The pattern constructor, which is used to check the destructuring `match` exhaustiveness.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Constructor {
+    /// The boolean literal.
+    Boolean(bool),
+    /// The inclusive integer range, where a literal or constant is the range of a single value.
+    Range(BigInt, BigInt),
+    /// The enumeration variant with its index.
+    Variant(usize),
+    /// The only constructor of tuples and structures.
+    Single,
+}
+
+///
+/// The simplified pattern, which is used to check the destructuring `match` exhaustiveness.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Pattern {
+    /// The pattern matching any value, that is, a wildcard or binding.
+    Wildcard,
+    /// The constructor applied to the subpatterns.
+    Constructor {
+        /// The pattern constructor.
+        constructor: Constructor,
+        /// The constructor subpatterns, e.g. the tuple elements.
+        arguments: Vec<Self>,
+    },
+    /// The pattern matching the values matched by any of the alternatives.
+    Alternatives(Vec<Self>),
+}
+
+impl Pattern {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_constructor(constructor: Constructor, arguments: Vec<Self>) -> Self {
+        Self::Constructor {
+            constructor,
+            arguments,
+        }
+    }
+}
+
+///
+/// The destructuring `match` pattern matrix.
+///
+/// A branch is unreachable if its pattern is not useful with respect to the preceding ones,
+/// and the `match` is exhaustive if the wildcard pattern is not useful after all the branches.
+///
+#[derive(Debug, Default)]
+pub struct Matrix {
+    /// The matrix rows, that is, the pattern vectors.
+    rows: Vec<Vec<Pattern>>,
+}
+
+impl Matrix {
+    ///
+    /// Pushes the branch `pattern` to the matrix.
+    ///
+    pub fn push(&mut self, pattern: Pattern) {
+        self.rows.push(vec![pattern]);
+    }
+
+    ///
+    /// Checks if the `pattern` of type `type` matches some value not matched by the matrix.
+    ///
+    pub fn is_useful(&self, pattern: &Pattern, r#type: &Type) -> bool {
+        Self::useful(
+            self.rows.as_slice(),
+            vec![pattern.to_owned()],
+            vec![r#type.to_owned()],
+        )
+    }
+
+    ///
+    /// The usefulness algorithm, which checks whether the pattern `vector` of `types` matches
+    /// some values not matched by the `rows`.
+    ///
+    fn useful(rows: &[Vec<Pattern>], mut vector: Vec<Pattern>, mut types: Vec<Type>) -> bool {
+        if vector.is_empty() {
+            return rows.is_empty();
+        }
+
+        let rows: Vec<Vec<Pattern>> = rows.iter().flat_map(|row| Self::expand(row)).collect();
+        let rows = rows.as_slice();
+
+        let head = vector.remove(0);
+        let head_type = types.remove(0);
+
+        match head {
+            Pattern::Alternatives(alternatives) => alternatives.into_iter().any(|alternative| {
+                Self::useful(
+                    rows,
+                    [vec![alternative], vector.clone()].concat(),
+                    [vec![head_type.clone()], types.clone()].concat(),
+                )
+            }),
+            Pattern::Constructor {
+                constructor: Constructor::Range(start, end),
+                ..
+            } => Self::useful_range(rows, start, end, vector, types),
+            Pattern::Wildcard if Self::bounds(&head_type).is_some() => {
+                let (start, end) =
+                    Self::bounds(&head_type).expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+                Self::useful_range(rows, start, end, vector, types)
+            }
+            Pattern::Constructor {
+                constructor,
+                arguments,
+            } => {
+                let argument_types = Self::constructors(&head_type)
+                    .and_then(|constructors| {
+                        constructors
+                            .into_iter()
+                            .find(|(candidate, _types)| candidate == &constructor)
+                    })
+                    .map(|(_constructor, types)| types)
+                    .unwrap_or_default();
+
+                let rows = Self::specialize(rows, &constructor, arguments.len());
+                Self::useful(
+                    rows.as_slice(),
+                    [arguments, vector].concat(),
+                    [argument_types, types].concat(),
+                )
+            }
+            Pattern::Wildcard => {
+                let used: Vec<&Constructor> = rows
+                    .iter()
+                    .filter_map(|row| match row[0] {
+                        Pattern::Constructor {
+                            ref constructor, ..
+                        } => Some(constructor),
+                        _ => None,
+                    })
+                    .collect();
+
+                match Self::constructors(&head_type) {
+                    Some(constructors)
+                        if constructors
+                            .iter()
+                            .all(|(constructor, _types)| used.contains(&constructor)) =>
+                    {
+                        constructors
+                            .into_iter()
+                            .any(|(constructor, argument_types)| {
+                                let rows =
+                                    Self::specialize(rows, &constructor, argument_types.len());
+                                Self::useful(
+                                    rows.as_slice(),
+                                    [
+                                        vec![Pattern::Wildcard; argument_types.len()],
+                                        vector.clone(),
+                                    ]
+                                    .concat(),
+                                    [argument_types, types.clone()].concat(),
+                                )
+                            })
+                    }
+                    _ => {
+                        let rows: Vec<Vec<Pattern>> = rows
+                            .iter()
+                            .filter(|row| row[0] == Pattern::Wildcard)
+                            .map(|row| row[1..].to_vec())
+                            .collect();
+                        Self::useful(rows.as_slice(), vector, types)
+                    }
+                }
+            }
+        }
+    }
+
+    ///
+    /// Keeps the rows starting with `constructor` or a wildcard, replacing the first pattern
+    /// with its `arity` subpatterns.
+    ///
+    fn specialize(
+        rows: &[Vec<Pattern>],
+        constructor: &Constructor,
+        arity: usize,
+    ) -> Vec<Vec<Pattern>> {
+        rows.iter()
+            .filter_map(|row| match row[0] {
+                Pattern::Constructor {
+                    constructor: ref candidate,
+                    ref arguments,
+                } if candidate == constructor => Some([arguments.as_slice(), &row[1..]].concat()),
+                Pattern::Constructor { .. } => None,
+                Pattern::Wildcard => {
+                    Some([vec![Pattern::Wildcard; arity].as_slice(), &row[1..]].concat())
+                }
+                Pattern::Alternatives(_) => {
+                    panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS)
+                }
+            })
+            .collect()
+    }
+
+    ///
+    /// The usefulness algorithm for the integer range from `start` to `end` at the head
+    /// of the pattern vector.
+    ///
+    /// The range is split by the borders of the row ranges, so each part is either fully
+    /// covered by a row range or does not intersect with it, and then every part is checked
+    /// as a separate constructor.
+    ///
+    fn useful_range(
+        rows: &[Vec<Pattern>],
+        start: BigInt,
+        end: BigInt,
+        vector: Vec<Pattern>,
+        types: Vec<Type>,
+    ) -> bool {
+        let mut borders = vec![start.clone(), end.clone() + BigInt::one()];
+        for row in rows.iter() {
+            if let Pattern::Constructor {
+                constructor: Constructor::Range(ref row_start, ref row_end),
+                ..
+            } = row[0]
+            {
+                for border in &[row_start.to_owned(), row_end + BigInt::one()] {
+                    if border > &start && border <= &end {
+                        borders.push(border.to_owned());
+                    }
+                }
+            }
+        }
+        borders.sort();
+        borders.dedup();
+
+        borders.windows(2).any(|window| {
+            let (part_start, part_end) = (&window[0], &window[1] - BigInt::one());
+            let rows: Vec<Vec<Pattern>> = rows
+                .iter()
+                .filter(|row| match row[0] {
+                    Pattern::Constructor {
+                        constructor: Constructor::Range(ref row_start, ref row_end),
+                        ..
+                    } => row_start <= part_start && &part_end <= row_end,
+                    Pattern::Constructor { .. } => false,
+                    Pattern::Wildcard => true,
+                    Pattern::Alternatives(_) => {
+                        panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS)
+                    }
+                })
+                .map(|row| row[1..].to_vec())
+                .collect();
+            Self::useful(rows.as_slice(), vector.clone(), types.clone())
+        })
+    }
+
+    ///
+    /// Replaces the row starting with alternatives with a row for each alternative.
+    ///
+    fn expand(row: &[Pattern]) -> Vec<Vec<Pattern>> {
+        match row[0] {
+            Pattern::Alternatives(ref alternatives) => alternatives
+                .iter()
+                .flat_map(|alternative| {
+                    Self::expand(&[vec![alternative.to_owned()], row[1..].to_vec()].concat())
+                })
+                .collect(),
+            _ => vec![row.to_owned()],
+        }
+    }
+
+    ///
+    /// Returns the minimal and maximal values of the integer `type`.
+    ///
+    /// The field values are bounded by its bitlength, which is a bit wider than the
+    /// actual range.
+    ///
+    fn bounds(r#type: &Type) -> Option<(BigInt, BigInt)> {
+        match r#type {
+            Type::IntegerUnsigned { bitlength, .. } => Some((
+                BigInt::zero(),
+                (BigInt::one() << *bitlength) - BigInt::one(),
+            )),
+            Type::IntegerSigned { bitlength, .. } => Some((
+                -(BigInt::one() << (*bitlength - 1)),
+                (BigInt::one() << (*bitlength - 1)) - BigInt::one(),
+            )),
+            Type::Field(_) => Some((
+                BigInt::zero(),
+                (BigInt::one() << zinc_const::bitlength::FIELD) - BigInt::one(),
+            )),
+            _ => None,
+        }
+    }
+
+    ///
+    /// Returns all the constructors of `type` with their argument types.
+    ///
+    /// Returns `None` if the constructors cannot be enumerated, e.g. for integers.
+    ///
+    fn constructors(r#type: &Type) -> Option<Vec<(Constructor, Vec<Type>)>> {
+        match r#type {
+            Type::Boolean(_) => Some(vec![
+                (Constructor::Boolean(false), vec![]),
+                (Constructor::Boolean(true), vec![]),
+            ]),
+            Type::Enumeration(inner) => Some(
+                inner
+                    .payloads
+                    .iter()
+                    .enumerate()
+                    .map(|(index, payload)| {
+                        (
+                            Constructor::Variant(index),
+                            payload.types().into_iter().cloned().collect(),
+                        )
+                    })
+                    .collect(),
+            ),
+            Type::Tuple(inner) => Some(vec![(Constructor::Single, inner.types.to_owned())]),
+            Type::Structure(inner) => Some(vec![(
+                Constructor::Single,
+                inner
+                    .fields
+                    .iter()
+                    .map(|(_name, r#type)| r#type.to_owned())
+                    .collect(),
+            )]),
+            _ => None,
+        }
+    }
+}
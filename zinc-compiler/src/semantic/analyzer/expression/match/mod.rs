@@ -6,16 +6,21 @@
 mod tests;
 
 pub mod exhausting;
+pub mod pattern;
 
 use std::cell::RefCell;
 use std::convert::TryFrom;
 use std::rc::Rc;
 
+use zinc_lexical::Location;
+use zinc_syntax::ExpressionTree;
 use zinc_syntax::MatchExpression;
+use zinc_syntax::MatchPattern;
 use zinc_syntax::MatchPatternVariant;
 
 use crate::generator::expression::operand::constant::Constant as GeneratorConstant;
 use crate::generator::expression::operand::r#match::builder::Builder as GeneratorMatchExpressionBuilder;
+use crate::generator::expression::operand::r#match::pattern::Pattern as GeneratorPattern;
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
 use crate::generator::r#type::Type as GeneratorType;
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
@@ -32,8 +37,13 @@ use crate::semantic::error::Error;
 use crate::semantic::scope::r#type::Type as ScopeType;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
+use crate::semantic::warning::Warning;
 
+use self::exhausting::Constructor as ExhaustingConstructor;
 use self::exhausting::Data as ExhaustingData;
+use self::exhausting::Matrix as ExhaustingMatrix;
+use self::exhausting::Pattern as ExhaustingPattern;
+use self::pattern::Translator as PatternTranslator;
 
 ///
 /// The `match` expression semantic analyzer.
@@ -83,7 +93,30 @@ impl Analyzer {
             ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Value)
                 .analyze(r#match.scrutinee)?;
         let scrutinee_type = Type::from_element(&scrutinee_result, scope_stack.top())?;
-        if scrutinee_type.is_scalar() {
+        let is_destructuring = Self::is_destructuring(r#match.branches.as_slice());
+        let is_destructurable = match scrutinee_type {
+            Type::Tuple(_) | Type::Structure(_) => true,
+            Type::Enumeration(ref enumeration) => enumeration.is_data(),
+            _ => false,
+        };
+        if (is_destructuring && scrutinee_type.is_scalar())
+            || (is_destructurable && GeneratorType::try_from_semantic(&scrutinee_type).is_some())
+        {
+            builder.set_scrutinee(
+                scrutinee_expression,
+                GeneratorType::try_from_semantic(&scrutinee_type)
+                    .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+            );
+
+            return Self::destructuring(
+                scope_stack,
+                builder,
+                r#match.branches,
+                scrutinee_type,
+                scrutinee_location,
+                location,
+            );
+        } else if scrutinee_type.is_scalar() {
             builder.set_scrutinee(
                 scrutinee_expression,
                 GeneratorType::try_from_semantic(&scrutinee_type)
@@ -100,19 +133,20 @@ impl Analyzer {
             return Err(Error::MatchLessThanTwoBranches { location });
         }
 
-        let first_branch_expression_location = r#match.branches[0].1.location;
         let mut is_exhausted = false;
         let mut exhausting_data = ExhaustingData::new();
         let mut match_result = None;
 
-        for (pattern, expression) in r#match.branches.into_iter() {
+        for (pattern, _guard, expression) in r#match.branches.into_iter() {
             let pattern_location = pattern.location;
             let expression_location = expression.location;
+            let is_diverging = expression.is_diverging();
 
             if is_exhausted {
-                return Err(Error::MatchBranchUnreachable {
+                RefCell::borrow(&scope_stack.top()).warn(Warning::UnreachableMatchBranch {
                     location: pattern.location,
                 });
+                continue;
             }
 
             let result = match pattern.variant {
@@ -277,32 +311,184 @@ impl Analyzer {
 
                     result
                 }
+                MatchPatternVariant::Range { .. }
+                | MatchPatternVariant::Tuple(_)
+                | MatchPatternVariant::TupleVariant { .. }
+                | MatchPatternVariant::Structure { .. }
+                | MatchPatternVariant::Alternatives(_) => {
+                    panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS)
+                }
             };
 
+            // the diverging branches do not take part in the type unification
+            if is_diverging {
+                continue;
+            }
+
             let result_type = Type::from_element(&result, scope_stack.top())?;
-            if let Some(ref match_result) = match_result {
-                let match_result_type = Type::from_element(match_result, scope_stack.top())?;
-                if result_type != match_result_type {
-                    return Err(Error::MatchBranchExpressionInvalidType {
-                        location: expression_location,
-                        expected: match_result_type.to_string(),
-                        found: result_type.to_string(),
-                        reference: first_branch_expression_location,
-                    });
+            match match_result {
+                Some((ref match_result, reference)) => {
+                    let match_result_type = Type::from_element(match_result, scope_stack.top())?;
+                    if result_type != match_result_type {
+                        return Err(Error::MatchBranchExpressionInvalidType {
+                            location: expression_location,
+                            expected: match_result_type.to_string(),
+                            found: result_type.to_string(),
+                            reference,
+                        });
+                    }
                 }
+                None => match_result = Some((result, expression_location)),
             }
+        }
 
-            if match_result.is_none() {
-                match_result = Some(result);
+        if !is_exhausted {
+            return Err(Error::MatchNotExhausted { location });
+        }
+
+        let element = match match_result.take() {
+            Some((result, _location)) => {
+                if let Some(r#type) = GeneratorType::try_from_semantic(&Type::from_element(
+                    &result,
+                    scope_stack.top(),
+                )?) {
+                    builder.set_diverging_type(r#type);
+                }
+                result
+            }
+            None => Element::Constant(Constant::Unit(UnitConstant::new(location))),
+        };
+
+        let intermediate = GeneratorExpressionOperand::Match(builder.finish());
+
+        Ok((element, intermediate))
+    }
+
+    ///
+    /// Returns the runtime match semantic element and intermediate representation,
+    /// if the scrutinee is destructured by the branch patterns.
+    ///
+    /// The exhaustiveness and branch reachability are checked using the pattern matrix.
+    /// The guarded branches are not pushed to the matrix, since they may not match even
+    /// if their patterns do.
+    ///
+    fn destructuring(
+        mut scope_stack: ScopeStack,
+        mut builder: GeneratorMatchExpressionBuilder,
+        branches: Vec<(MatchPattern, Option<ExpressionTree>, ExpressionTree)>,
+        scrutinee_type: Type,
+        scrutinee_location: Location,
+        location: Location,
+    ) -> Result<(Element, GeneratorExpressionOperand), Error> {
+        if branches.len() < Self::REQUIRED_BRANCHES_COUNT {
+            return Err(Error::MatchLessThanTwoBranches { location });
+        }
+
+        let mut matrix = ExhaustingMatrix::default();
+        let mut match_result = None;
+        let mut last_branch = None;
+
+        for (pattern, guard, expression) in branches.into_iter() {
+            let pattern_location = pattern.location;
+            let expression_location = expression.location;
+            let is_diverging = expression.is_diverging();
+
+            let mut intermediate = GeneratorPattern::default();
+            let mut bindings = Vec::new();
+            let exhausting_pattern = PatternTranslator::new(
+                scope_stack.top(),
+                scrutinee_location,
+                &mut intermediate,
+                &mut bindings,
+            )
+            .translate(pattern, &scrutinee_type, 0)?;
+            if !matrix.is_useful(&exhausting_pattern, &scrutinee_type) {
+                RefCell::borrow(&scope_stack.top()).warn(Warning::UnreachableMatchBranch {
+                    location: pattern_location,
+                });
+                continue;
+            }
+            if guard.is_none() {
+                matrix.push(exhausting_pattern);
+            }
+
+            scope_stack.push(None, ScopeType::Block);
+            for (identifier, r#type) in bindings.into_iter() {
+                Scope::define_variable(scope_stack.top(), identifier, false, r#type)?;
+            }
+            let guard = match guard {
+                Some(guard) => {
+                    let guard_location = guard.location;
+                    let (guard_result, guard) =
+                        ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Value)
+                            .analyze(guard)?;
+                    match Type::from_element(&guard_result, scope_stack.top())? {
+                        Type::Boolean(_) => {}
+                        r#type => {
+                            return Err(Error::MatchBranchGuardExpectedBooleanCondition {
+                                location: guard_location,
+                                found: r#type.to_string(),
+                            });
+                        }
+                    }
+                    Some(guard)
+                }
+                None => None,
+            };
+            let (result, branch) =
+                ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Value)
+                    .analyze(expression)?;
+            scope_stack.pop();
+
+            if let Some((intermediate, guard, branch)) =
+                last_branch.replace((intermediate, guard, branch))
+            {
+                match guard {
+                    Some(guard) => builder.push_guarded_branch(intermediate, guard, branch),
+                    None => builder.push_pattern_branch(intermediate, branch),
+                }
+            }
+
+            // the diverging branches do not take part in the type unification
+            if is_diverging {
+                continue;
+            }
+
+            let result_type = Type::from_element(&result, scope_stack.top())?;
+            match match_result {
+                Some((ref match_result, reference)) => {
+                    let match_result_type = Type::from_element(match_result, scope_stack.top())?;
+                    if result_type != match_result_type {
+                        return Err(Error::MatchBranchExpressionInvalidType {
+                            location: expression_location,
+                            expected: match_result_type.to_string(),
+                            found: result_type.to_string(),
+                            reference,
+                        });
+                    }
+                }
+                None => match_result = Some((result, expression_location)),
             }
         }
 
-        if !is_exhausted {
+        if matrix.is_useful(&ExhaustingPattern::Wildcard, &scrutinee_type) {
             return Err(Error::MatchNotExhausted { location });
         }
+        // the last branch is not guarded, since the guarded branches do not exhaust the matrix
+        if let Some((intermediate, _guard, branch)) = last_branch.take() {
+            builder.set_fallback_branch(intermediate, branch);
+        }
 
         let element = match match_result.take() {
-            Some(result) => result,
+            Some((result, _location)) => {
+                if let Some(r#type) = GeneratorType::try_from_semantic(&Type::from_element(
+                    &result,
+                    scope_stack.top(),
+                )?) {
+                    builder.set_diverging_type(r#type);
+                }
+                result
+            }
             None => Element::Constant(Constant::Unit(UnitConstant::new(location))),
         };
 
@@ -344,19 +530,30 @@ impl Analyzer {
             return Err(Error::MatchLessThanTwoBranches { location });
         }
 
-        let first_branch_expression_location = r#match.branches[0].1.location;
+        if Self::is_destructuring(r#match.branches.as_slice()) {
+            return Self::constant_destructuring(
+                scope_stack,
+                r#match.branches,
+                scrutinee_result,
+                scrutinee_location,
+                location,
+            );
+        }
+
+        let first_branch_expression_location = r#match.branches[0].2.location;
         let mut is_exhausted = false;
         let mut exhausting_data = ExhaustingData::new();
         let mut match_result = None;
 
-        for (pattern, expression) in r#match.branches.into_iter() {
+        for (pattern, _guard, expression) in r#match.branches.into_iter() {
             let pattern_location = pattern.location;
             let expression_location = expression.location;
 
             if is_exhausted {
-                return Err(Error::MatchBranchUnreachable {
+                RefCell::borrow(&scope_stack.top()).warn(Warning::UnreachableMatchBranch {
                     location: pattern.location,
                 });
+                continue;
             }
 
             let result = match pattern.variant {
@@ -571,6 +768,21 @@ impl Analyzer {
 
                     result
                 }
+                variant => {
+                    PatternTranslator::new(
+                        scope_stack.top(),
+                        scrutinee_location,
+                        &mut GeneratorPattern::default(),
+                        &mut vec![],
+                    )
+                    .translate(
+                        MatchPattern::new(pattern_location, variant),
+                        &scrutinee_type,
+                        0,
+                    )?;
+
+                    panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS)
+                }
             };
 
             let result_type = Type::from_element(&result, scope_stack.top())?;
@@ -598,4 +810,178 @@ impl Analyzer {
 
         Ok(element)
     }
+
+    ///
+    /// Returns the constant match semantic element, if the branches have guards or patterns
+    /// which are not supported by the scalar `match`, e.g. ranges and alternatives.
+    ///
+    /// The exhaustiveness and branch reachability are checked using the pattern matrix.
+    ///
+    fn constant_destructuring(
+        mut scope_stack: ScopeStack,
+        branches: Vec<(MatchPattern, Option<ExpressionTree>, ExpressionTree)>,
+        scrutinee_result: Constant,
+        scrutinee_location: Location,
+        location: Location,
+    ) -> Result<Element, Error> {
+        let scrutinee_type = scrutinee_result.r#type();
+
+        let first_branch_expression_location = branches[0].2.location;
+        let mut matrix = ExhaustingMatrix::default();
+        let mut first_result_type = None;
+        let mut match_result = None;
+
+        for (pattern, guard, expression) in branches.into_iter() {
+            let pattern_location = pattern.location;
+            let expression_location = expression.location;
+
+            let mut bindings = Vec::new();
+            let exhausting_pattern = PatternTranslator::new(
+                scope_stack.top(),
+                scrutinee_location,
+                &mut GeneratorPattern::default(),
+                &mut bindings,
+            )
+            .translate(pattern, &scrutinee_type, 0)?;
+            if !matrix.is_useful(&exhausting_pattern, &scrutinee_type) {
+                RefCell::borrow(&scope_stack.top()).warn(Warning::UnreachableMatchBranch {
+                    location: pattern_location,
+                });
+                continue;
+            }
+            let mut is_matched = match_result.is_none()
+                && Self::is_constant_matched(&exhausting_pattern, &scrutinee_result);
+            if guard.is_none() {
+                matrix.push(exhausting_pattern);
+            }
+
+            scope_stack.push(None, ScopeType::Block);
+            for (identifier, _type) in bindings.into_iter() {
+                Scope::define_constant(scope_stack.top(), identifier, scrutinee_result.clone())?;
+            }
+            if let Some(guard) = guard {
+                let guard_location = guard.location;
+                let (guard_result, _) =
+                    ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Constant)
+                        .analyze(guard)?;
+                match guard_result {
+                    Element::Constant(Constant::Boolean(boolean)) => {
+                        is_matched &= boolean.is_true();
+                    }
+                    Element::Constant(constant) => {
+                        return Err(Error::MatchBranchGuardExpectedBooleanCondition {
+                            location: guard_location,
+                            found: constant.r#type().to_string(),
+                        });
+                    }
+                    element => {
+                        return Err(Error::ExpressionNonConstantElement {
+                            location: guard_location,
+                            found: element.to_string(),
+                        });
+                    }
+                }
+            }
+            let (result, _) = ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Constant)
+                .analyze(expression)?;
+            scope_stack.pop();
+
+            let result = match result {
+                Element::Constant(result) => result,
+                element => {
+                    return Err(Error::ExpressionNonConstantElement {
+                        location: expression_location,
+                        found: element.to_string(),
+                    });
+                }
+            };
+
+            let result_type = result.r#type();
+            match first_result_type {
+                Some(ref first_result_type) if &result_type != first_result_type => {
+                    return Err(Error::MatchBranchExpressionInvalidType {
+                        location: expression_location,
+                        expected: first_result_type.to_string(),
+                        found: result_type.to_string(),
+                        reference: first_branch_expression_location,
+                    });
+                }
+                Some(_) => {}
+                None => first_result_type = Some(result_type),
+            }
+
+            if is_matched {
+                match_result = Some(result);
+            }
+        }
+
+        if matrix.is_useful(&ExhaustingPattern::Wildcard, &scrutinee_type) {
+            return Err(Error::MatchNotExhausted { location });
+        }
+
+        let element = Element::Constant(match match_result.take() {
+            Some(result) => result,
+            None => Constant::Unit(UnitConstant::new(location)),
+        });
+
+        Ok(element)
+    }
+
+    ///
+    /// Checks whether the branches must be analyzed with the pattern matrix, that is, some
+    /// of them are guarded or have patterns which are not supported by the scalar `match`.
+    ///
+    fn is_destructuring(
+        branches: &[(MatchPattern, Option<ExpressionTree>, ExpressionTree)],
+    ) -> bool {
+        branches.iter().any(|(pattern, guard, _expression)| {
+            guard.is_some()
+                || matches!(
+                    pattern.variant,
+                    MatchPatternVariant::Range { .. }
+                        | MatchPatternVariant::Tuple(_)
+                        | MatchPatternVariant::TupleVariant { .. }
+                        | MatchPatternVariant::Structure { .. }
+                        | MatchPatternVariant::Alternatives(_)
+                )
+        })
+    }
+
+    ///
+    /// Checks whether the scalar `constant` is matched by the `pattern`.
+    ///
+    fn is_constant_matched(pattern: &ExhaustingPattern, constant: &Constant) -> bool {
+        match (pattern, constant) {
+            (ExhaustingPattern::Wildcard, _) => true,
+            (ExhaustingPattern::Alternatives(alternatives), constant) => alternatives
+                .iter()
+                .any(|alternative| Self::is_constant_matched(alternative, constant)),
+            (
+                ExhaustingPattern::Constructor {
+                    constructor: ExhaustingConstructor::Boolean(expected),
+                    ..
+                },
+                Constant::Boolean(boolean),
+            ) => *expected == boolean.inner,
+            (
+                ExhaustingPattern::Constructor {
+                    constructor: ExhaustingConstructor::Range(start, end),
+                    ..
+                },
+                Constant::Integer(integer),
+            ) => start <= &integer.value && &integer.value <= end,
+            (
+                ExhaustingPattern::Constructor {
+                    constructor: ExhaustingConstructor::Variant(index),
+                    ..
+                },
+                Constant::Integer(integer),
+            ) => integer
+                .enumeration
+                .as_ref()
+                .map(|enumeration| enumeration.values[*index] == integer.value)
+                .unwrap_or_default(),
+            _ => false,
+        }
+    }
 }
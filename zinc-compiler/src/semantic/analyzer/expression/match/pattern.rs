@@ -0,0 +1,623 @@
+//!
+//! The `match` expression destructuring pattern translator.
+//!
+
+use std::cell::RefCell;
+use std::convert::TryFrom;
+use std::rc::Rc;
+
+use num::BigInt;
+use num::One;
+
+use zinc_lexical::Location;
+use zinc_syntax::ExpressionTree;
+use zinc_syntax::Identifier;
+use zinc_syntax::MatchPattern;
+use zinc_syntax::MatchPatternVariant;
+
+use crate::generator::expression::operand::constant::boolean::Boolean as GeneratorBooleanConstant;
+use crate::generator::expression::operand::constant::integer::Integer as GeneratorIntegerConstant;
+use crate::generator::expression::operand::constant::Constant as GeneratorConstant;
+use crate::generator::expression::operand::r#match::pattern::Pattern as GeneratorPattern;
+use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
+use crate::semantic::analyzer::rule::Rule as TranslationRule;
+use crate::semantic::element::constant::boolean::Boolean as BooleanConstant;
+use crate::semantic::element::constant::integer::Integer as IntegerConstant;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::enumeration::payload::Payload as EnumerationPayload;
+use crate::semantic::element::r#type::enumeration::Enumeration as EnumerationType;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error;
+use crate::semantic::scope::item::Item as ScopeItem;
+use crate::semantic::scope::Scope;
+
+use super::exhausting::Constructor;
+use super::exhausting::Pattern;
+
+///
+/// The `match` expression destructuring pattern translator.
+///
+/// Lowers the syntax pattern into the scrutinee scalar comparisons and bindings for the IR,
+/// and into the simplified pattern used to check the exhaustiveness.
+///
+pub struct Translator<'a> {
+    /// The scope, where the pattern paths are resolved.
+    scope: Rc<RefCell<Scope>>,
+    /// The scrutinee expression location, which helps user to find the type errors.
+    reference: Location,
+    /// The IR pattern being built.
+    intermediate: &'a mut GeneratorPattern,
+    /// The variables bound by the pattern.
+    bindings: &'a mut Vec<(Identifier, Type)>,
+}
+
+impl<'a> Translator<'a> {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        scope: Rc<RefCell<Scope>>,
+        reference: Location,
+        intermediate: &'a mut GeneratorPattern,
+        bindings: &'a mut Vec<(Identifier, Type)>,
+    ) -> Self {
+        Self {
+            scope,
+            reference,
+            intermediate,
+            bindings,
+        }
+    }
+
+    ///
+    /// Translates the `pattern`, which matches the scrutinee part of `type` at `offset`.
+    ///
+    pub fn translate(
+        &mut self,
+        pattern: MatchPattern,
+        r#type: &Type,
+        offset: usize,
+    ) -> Result<Pattern, Error> {
+        let location = pattern.location;
+
+        match pattern.variant {
+            MatchPatternVariant::Wildcard => Ok(Pattern::Wildcard),
+            MatchPatternVariant::Binding(identifier) => {
+                self.intermediate
+                    .push_binding(identifier.name.to_owned(), offset, r#type.size());
+                self.bindings.push((identifier, r#type.to_owned()));
+
+                Ok(Pattern::Wildcard)
+            }
+            MatchPatternVariant::BooleanLiteral(boolean) => {
+                let boolean = BooleanConstant::from(boolean);
+                if !matches!(r#type, Type::Boolean(_)) {
+                    return Err(self.invalid_type(location, r#type, Type::boolean(None)));
+                }
+
+                self.intermediate.push_condition(
+                    offset,
+                    GeneratorConstant::Boolean(GeneratorBooleanConstant::from_semantic(&boolean)),
+                );
+
+                Ok(Pattern::new_constructor(
+                    Constructor::Boolean(boolean.inner),
+                    vec![],
+                ))
+            }
+            MatchPatternVariant::IntegerLiteral(integer) => {
+                let constant = IntegerConstant::try_from(&integer)?;
+                self.integer(location, constant, r#type, offset)
+            }
+            MatchPatternVariant::Range {
+                start,
+                end,
+                is_inclusive,
+            } => {
+                let start = IntegerConstant::try_from(&start)?;
+                let end = IntegerConstant::try_from(&end)?;
+                self.range(location, start, end, is_inclusive, r#type, offset)
+            }
+            MatchPatternVariant::Alternatives(alternatives) => {
+                self.alternatives(alternatives, r#type, offset)
+            }
+            MatchPatternVariant::Path(path) => {
+                let item = self.resolve(path)?;
+                let item = RefCell::borrow(&item);
+                match *item {
+                    ScopeItem::Variant(ref variant) => {
+                        let (enumeration, index) = variant
+                            .enumeration()
+                            .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+                        if !enumeration.payloads[index].is_unit() {
+                            return Err(Error::TypeVariantExpectedUnit {
+                                location,
+                                variant: enumeration.variant_signature(index),
+                            });
+                        }
+
+                        self.variant(location, enumeration, index, r#type, offset)?;
+
+                        Ok(Pattern::new_constructor(
+                            Constructor::Variant(index),
+                            vec![],
+                        ))
+                    }
+                    ScopeItem::Constant(ref constant) => match constant.define()? {
+                        Constant::Boolean(boolean) => {
+                            let constant_type = boolean.r#type();
+                            if &constant_type != r#type {
+                                return Err(self.invalid_type(location, r#type, constant_type));
+                            }
+
+                            self.intermediate.push_condition(
+                                offset,
+                                GeneratorConstant::Boolean(
+                                    GeneratorBooleanConstant::from_semantic(&boolean),
+                                ),
+                            );
+
+                            Ok(Pattern::new_constructor(
+                                Constructor::Boolean(boolean.inner),
+                                vec![],
+                            ))
+                        }
+                        Constant::Integer(integer) => {
+                            let constant_type = integer.r#type();
+                            if &constant_type != r#type {
+                                return Err(self.invalid_type(location, r#type, constant_type));
+                            }
+
+                            self.integer(location, integer, r#type, offset)
+                        }
+                        constant => Err(Error::MatchBranchPatternPathExpectedConstant {
+                            location,
+                            found: constant.to_string(),
+                        }),
+                    },
+                    ref item => Err(Error::MatchBranchPatternPathExpectedConstant {
+                        location,
+                        found: item.to_string(),
+                    }),
+                }
+            }
+            MatchPatternVariant::Tuple(elements) => {
+                let types = match r#type {
+                    Type::Tuple(tuple) => tuple.types.to_owned(),
+                    _ => {
+                        return Err(Error::MatchBranchPatternInvalidType {
+                            location,
+                            expected: r#type.to_string(),
+                            found: "tuple".to_owned(),
+                            reference: self.reference,
+                        })
+                    }
+                };
+
+                let arguments =
+                    self.elements(location, r#type.to_string(), elements, types, offset)?;
+
+                Ok(Pattern::new_constructor(Constructor::Single, arguments))
+            }
+            MatchPatternVariant::TupleVariant { path, elements } => {
+                let item = self.resolve(path)?;
+                let item = RefCell::borrow(&item);
+                let (enumeration, index) = match *item {
+                    ScopeItem::Variant(ref variant) => variant
+                        .enumeration()
+                        .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+                    ref item => {
+                        return Err(Error::MatchBranchPatternPathExpectedConstant {
+                            location,
+                            found: item.to_string(),
+                        })
+                    }
+                };
+                let types = match enumeration.payloads[index] {
+                    EnumerationPayload::Tuple(ref types) => types.to_owned(),
+                    _ => {
+                        return Err(Error::TypeVariantExpectedTuple {
+                            location,
+                            variant: enumeration.variant_signature(index),
+                        })
+                    }
+                };
+
+                self.variant(location, enumeration, index, r#type, offset)?;
+                let arguments = self.elements(
+                    location,
+                    enumeration.variant_signature(index),
+                    elements,
+                    types,
+                    offset + enumeration.offset(index),
+                )?;
+
+                Ok(Pattern::new_constructor(
+                    Constructor::Variant(index),
+                    arguments,
+                ))
+            }
+            MatchPatternVariant::Structure {
+                path,
+                fields,
+                is_rest_ignored,
+            } => {
+                let item = self.resolve(path)?;
+                let item = RefCell::borrow(&item);
+                match *item {
+                    ScopeItem::Type(ref item_type) => {
+                        let structure = match item_type.define()? {
+                            Type::Structure(structure) => structure,
+                            item_type => {
+                                return Err(self.invalid_type(location, r#type, item_type));
+                            }
+                        };
+                        let structure_type = Type::Structure(structure.to_owned());
+                        if &structure_type != r#type {
+                            return Err(self.invalid_type(location, r#type, structure_type));
+                        }
+
+                        let arguments = self.fields(
+                            location,
+                            structure.identifier,
+                            fields,
+                            is_rest_ignored,
+                            structure.fields,
+                            offset,
+                        )?;
+
+                        Ok(Pattern::new_constructor(Constructor::Single, arguments))
+                    }
+                    ScopeItem::Variant(ref variant) => {
+                        let (enumeration, index) = variant
+                            .enumeration()
+                            .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+                        let declared = match enumeration.payloads[index] {
+                            EnumerationPayload::Structure(ref fields) => fields.to_owned(),
+                            _ => {
+                                return Err(Error::TypeVariantExpectedStructure {
+                                    location,
+                                    variant: enumeration.variant_signature(index),
+                                })
+                            }
+                        };
+
+                        self.variant(location, enumeration, index, r#type, offset)?;
+                        let arguments = self.fields(
+                            location,
+                            enumeration.variant_name(index),
+                            fields,
+                            is_rest_ignored,
+                            declared,
+                            offset + enumeration.offset(index),
+                        )?;
+
+                        Ok(Pattern::new_constructor(
+                            Constructor::Variant(index),
+                            arguments,
+                        ))
+                    }
+                    ref item => Err(Error::MatchBranchPatternPathExpectedConstant {
+                        location,
+                        found: item.to_string(),
+                    }),
+                }
+            }
+        }
+    }
+
+    ///
+    /// Translates the integer `constant` pattern, which must fit into the scrutinee part `type`.
+    ///
+    fn integer(
+        &mut self,
+        location: Location,
+        constant: IntegerConstant,
+        r#type: &Type,
+        offset: usize,
+    ) -> Result<Pattern, Error> {
+        let (is_signed, bitlength) = self.integer_type(location, r#type, constant.r#type())?;
+
+        let (constant, _intermediate) = constant.cast(is_signed, bitlength)?;
+
+        self.intermediate.push_condition(
+            offset,
+            GeneratorConstant::Integer(GeneratorIntegerConstant::from_semantic(&constant)),
+        );
+
+        Ok(Pattern::new_constructor(
+            Constructor::Range(constant.value.clone(), constant.value),
+            vec![],
+        ))
+    }
+
+    ///
+    /// Translates the integer range pattern, whose bounds must fit into the scrutinee part `type`.
+    ///
+    fn range(
+        &mut self,
+        location: Location,
+        start: IntegerConstant,
+        end: IntegerConstant,
+        is_inclusive: bool,
+        r#type: &Type,
+        offset: usize,
+    ) -> Result<Pattern, Error> {
+        let (is_signed, bitlength) = self.integer_type(location, r#type, start.r#type())?;
+
+        let (start, _intermediate) = start.cast(is_signed, bitlength)?;
+        let (end, _intermediate) = end.cast(is_signed, bitlength)?;
+        let end = if is_inclusive {
+            end.value
+        } else {
+            end.value - BigInt::one()
+        };
+        if start.value > end {
+            return Err(Error::MatchBranchPatternRangeEmpty { location });
+        }
+
+        self.intermediate.push_range_condition(
+            offset,
+            GeneratorConstant::Integer(GeneratorIntegerConstant::new(
+                start.value.clone(),
+                is_signed,
+                bitlength,
+            )),
+            GeneratorConstant::Integer(GeneratorIntegerConstant::new(
+                end.clone(),
+                is_signed,
+                bitlength,
+            )),
+        );
+
+        Ok(Pattern::new_constructor(
+            Constructor::Range(start.value, end),
+            vec![],
+        ))
+    }
+
+    ///
+    /// Translates the pattern `alternatives`, which must bind the same variables of the same types.
+    ///
+    fn alternatives(
+        &mut self,
+        alternatives: Vec<MatchPattern>,
+        r#type: &Type,
+        offset: usize,
+    ) -> Result<Pattern, Error> {
+        let mut intermediates = Vec::with_capacity(alternatives.len());
+        let mut patterns = Vec::with_capacity(alternatives.len());
+        let mut expected: Option<(Location, Vec<(Identifier, Type)>)> = None;
+
+        for alternative in alternatives.into_iter() {
+            let location = alternative.location;
+
+            let mut intermediate = GeneratorPattern::default();
+            let mut bindings = Vec::new();
+            let pattern = Translator::new(
+                self.scope.clone(),
+                self.reference,
+                &mut intermediate,
+                &mut bindings,
+            )
+            .translate(alternative, r#type, offset)?;
+
+            match expected {
+                Some((reference, ref expected)) => {
+                    Self::check_bindings(expected, &bindings, location)?;
+                    Self::check_bindings(&bindings, expected, reference)?;
+                }
+                None => expected = Some((location, bindings)),
+            }
+
+            intermediates.push(intermediate);
+            patterns.push(pattern);
+        }
+
+        if let Some((_location, bindings)) = expected {
+            self.bindings.extend(bindings);
+        }
+        self.intermediate.push_alternatives(intermediates);
+
+        Ok(Pattern::Alternatives(patterns))
+    }
+
+    ///
+    /// Checks whether the alternative at `location` with `bindings` binds all the `expected`
+    /// variables with the same types.
+    ///
+    fn check_bindings(
+        expected: &[(Identifier, Type)],
+        bindings: &[(Identifier, Type)],
+        location: Location,
+    ) -> Result<(), Error> {
+        for (identifier, r#type) in expected.iter() {
+            match bindings
+                .iter()
+                .find(|(binding, _type)| binding.name == identifier.name)
+            {
+                Some((binding, binding_type)) if binding_type != r#type => {
+                    return Err(Error::MatchBranchPatternInvalidType {
+                        location: binding.location,
+                        expected: r#type.to_string(),
+                        found: binding_type.to_string(),
+                        reference: identifier.location,
+                    });
+                }
+                Some(_) => {}
+                None => {
+                    return Err(Error::MatchBranchPatternAlternativeBindingMissing {
+                        location,
+                        name: identifier.name.to_owned(),
+                        reference: identifier.location,
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Returns the signedness and bitlength of the integer scrutinee part `type`.
+    ///
+    fn integer_type(
+        &self,
+        location: Location,
+        r#type: &Type,
+        found: Type,
+    ) -> Result<(bool, usize), Error> {
+        match r#type {
+            Type::IntegerUnsigned { bitlength, .. } => Ok((false, *bitlength)),
+            Type::IntegerSigned { bitlength, .. } => Ok((true, *bitlength)),
+            Type::Field(_) => Ok((false, zinc_const::bitlength::FIELD)),
+            _ => Err(self.invalid_type(location, r#type, found)),
+        }
+    }
+
+    ///
+    /// Checks the enumeration variant pattern type and writes the variant tag condition.
+    ///
+    fn variant(
+        &mut self,
+        location: Location,
+        enumeration: &EnumerationType,
+        index: usize,
+        r#type: &Type,
+        offset: usize,
+    ) -> Result<(), Error> {
+        let enumeration_type = Type::Enumeration(enumeration.to_owned());
+        if &enumeration_type != r#type {
+            return Err(self.invalid_type(location, r#type, enumeration_type));
+        }
+
+        self.intermediate.push_condition(
+            offset,
+            GeneratorConstant::Integer(GeneratorIntegerConstant::new(
+                enumeration.values[index].to_owned(),
+                false,
+                enumeration.bitlength,
+            )),
+        );
+
+        Ok(())
+    }
+
+    ///
+    /// Translates the tuple or tuple-like variant elements, which match the `types`
+    /// starting from `offset`.
+    ///
+    fn elements(
+        &mut self,
+        location: Location,
+        name: String,
+        elements: Vec<MatchPattern>,
+        types: Vec<Type>,
+        mut offset: usize,
+    ) -> Result<Vec<Pattern>, Error> {
+        if elements.len() != types.len() {
+            return Err(Error::MatchBranchPatternElementCount {
+                location,
+                r#type: name,
+                expected: types.len(),
+                found: elements.len(),
+            });
+        }
+
+        let mut arguments = Vec::with_capacity(elements.len());
+        for (element, r#type) in elements.into_iter().zip(types.iter()) {
+            arguments.push(self.translate(element, r#type, offset)?);
+            offset += r#type.size();
+        }
+
+        Ok(arguments)
+    }
+
+    ///
+    /// Translates the structure or structure-like variant fields, which match the `declared`
+    /// fields starting from `offset`.
+    ///
+    /// The fields not mentioned in the pattern are matched with wildcards, if the `..` rest
+    /// pattern is specified.
+    ///
+    fn fields(
+        &mut self,
+        location: Location,
+        name: String,
+        fields: Vec<(Identifier, MatchPattern)>,
+        is_rest_ignored: bool,
+        declared: Vec<(String, Type)>,
+        mut offset: usize,
+    ) -> Result<Vec<Pattern>, Error> {
+        let mut patterns: Vec<Option<(Location, MatchPattern)>> = vec![None; declared.len()];
+        for (identifier, pattern) in fields.into_iter() {
+            let position = declared
+                .iter()
+                .position(|(field, _type)| field == &identifier.name)
+                .ok_or_else(|| Error::StructureFieldDoesNotExist {
+                    location: identifier.location,
+                    r#type: name.clone(),
+                    field_name: identifier.name.to_owned(),
+                })?;
+
+            if let Some((reference, _pattern)) = patterns[position].as_ref() {
+                return Err(Error::MatchBranchPatternFieldDuplicate {
+                    location: identifier.location,
+                    field_name: identifier.name,
+                    reference: *reference,
+                });
+            }
+
+            patterns[position] = Some((identifier.location, pattern));
+        }
+
+        let mut arguments = Vec::with_capacity(declared.len());
+        for (pattern, (field, r#type)) in patterns.into_iter().zip(declared) {
+            match pattern {
+                Some((_location, pattern)) => {
+                    arguments.push(self.translate(pattern, &r#type, offset)?)
+                }
+                None if is_rest_ignored => arguments.push(Pattern::Wildcard),
+                None => {
+                    return Err(Error::MatchBranchPatternFieldMissing {
+                        location,
+                        r#type: name,
+                        field_name: field,
+                    })
+                }
+            }
+            offset += r#type.size();
+        }
+
+        Ok(arguments)
+    }
+
+    ///
+    /// Resolves the pattern `path` to a scope item.
+    ///
+    fn resolve(&self, path: ExpressionTree) -> Result<Rc<RefCell<ScopeItem>>, Error> {
+        let location = path.location;
+
+        match ExpressionAnalyzer::new(self.scope.clone(), TranslationRule::Path).analyze(path)? {
+            (Element::Path(path), _intermediate) => Scope::resolve_path(self.scope.clone(), &path),
+            (element, _intermediate) => Err(Error::MatchBranchPatternPathExpectedConstant {
+                location,
+                found: element.to_string(),
+            }),
+        }
+    }
+
+    ///
+    /// Creates the pattern type mismatch error.
+    ///
+    fn invalid_type(&self, location: Location, expected: &Type, found: Type) -> Error {
+        Error::MatchBranchPatternInvalidType {
+            location,
+            expected: expected.to_string(),
+            found: found.to_string(),
+            reference: self.reference,
+        }
+    }
+}
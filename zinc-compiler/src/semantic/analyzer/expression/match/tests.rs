@@ -11,6 +11,7 @@ use crate::error::Error;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error as SemanticError;
+use crate::semantic::warning::Warning;
 use crate::source::Source;
 
 #[test]
@@ -155,51 +156,51 @@ fn main() {
 }
 
 #[test]
-fn error_branch_unreachable() {
+fn warning_branch_unreachable() {
     let input = r#"
-fn main() {
+fn main() -> u8 {
     let scrutinee = 42;
-    let result = match scrutinee {
+    match scrutinee {
         1 => 10,
         _ => 101,
         2 => 20,
-    };
+    }
 }
 "#;
 
-    let expected = Err(Error::Semantic(SemanticError::MatchBranchUnreachable {
+    let expected = Ok(vec![Warning::UnreachableMatchBranch {
         location: Location::test(7, 9),
-    }));
+    }]);
 
-    let result = crate::semantic::tests::compile_entry(input);
+    let result = crate::semantic::tests::compile_entry_warnings(input);
 
     assert_eq!(result, expected);
 }
 
 #[test]
-fn error_branch_unreachable_exhausted_boolean() {
+fn warning_branch_unreachable_exhausted_boolean() {
     let input = r#"
-fn main() {
+fn main() -> u8 {
     let scrutinee = true;
-    let result = match scrutinee {
+    match scrutinee {
         false => 10,
         true => 101,
         _ => 20,
-    };
+    }
 }
 "#;
 
-    let expected = Err(Error::Semantic(SemanticError::MatchBranchUnreachable {
+    let expected = Ok(vec![Warning::UnreachableMatchBranch {
         location: Location::test(7, 9),
-    }));
+    }]);
 
-    let result = crate::semantic::tests::compile_entry(input);
+    let result = crate::semantic::tests::compile_entry_warnings(input);
 
     assert_eq!(result, expected);
 }
 
 #[test]
-fn error_branch_unreachable_exhausted_enumeration() {
+fn warning_branch_unreachable_exhausted_enumeration() {
     let input = r#"
 enum List {
     One = 1,
@@ -207,22 +208,22 @@ enum List {
     Three = 3,
 }
 
-fn main() {
+fn main() -> u8 {
     let scrutinee = List::One;
-    let result = match scrutinee {
+    match scrutinee {
         List::One => 1,
         List::Two => 2,
         List::Three => 3,
         _ => 4,
-    };
+    }
 }
 "#;
 
-    let expected = Err(Error::Semantic(SemanticError::MatchBranchUnreachable {
+    let expected = Ok(vec![Warning::UnreachableMatchBranch {
         location: Location::test(14, 9),
-    }));
+    }]);
 
-    let result = crate::semantic::tests::compile_entry(input);
+    let result = crate::semantic::tests::compile_entry_warnings(input);
 
     assert_eq!(result, expected);
 }
@@ -400,3 +401,468 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_enumeration_data_variants() {
+    let input = r#"
+enum Shape {
+    Circle(u8),
+    Rect { width: u8, height: u8 },
+    Empty,
+}
+
+fn main() -> u8 {
+    let shape = Shape::Rect { width: 2, height: 3 };
+    match shape {
+        Shape::Circle(radius) => radius * radius * 3,
+        Shape::Rect { width, height } => width * height,
+        Shape::Empty => 0,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_enumeration_data_variants_nested() {
+    let input = r#"
+enum Option {
+    Some(u8),
+    None,
+}
+
+fn main() -> u8 {
+    let value = Option::Some(42);
+    match value {
+        Option::Some(0) => 1,
+        Option::Some(x) => x,
+        Option::None => 0,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_tuple() {
+    let input = r#"
+fn main() -> u8 {
+    let value = (42, true);
+    match value {
+        (0, _) => 1,
+        (_, false) => 2,
+        (x, true) => x,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_structure() {
+    let input = r#"
+struct Point {
+    x: u8,
+    y: u8,
+}
+
+fn main() -> u8 {
+    let point = Point { x: 1, y: 2 };
+    match point {
+        Point { x: 0, .. } => 0,
+        Point { x, y } => x + y,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_not_exhausted_enumeration_data_variants() {
+    let input = r#"
+enum Option {
+    Some(u8),
+    None,
+}
+
+fn main() -> u8 {
+    let value = Option::Some(42);
+    match value {
+        Option::Some(0) => 1,
+        Option::None => 0,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::MatchNotExhausted {
+        location: Location::test(9, 5),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_not_exhausted_tuple() {
+    let input = r#"
+fn main() -> u8 {
+    let value = (42, true);
+    match value {
+        (_, false) => 1,
+        (0, true) => 2,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::MatchNotExhausted {
+        location: Location::test(4, 5),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_branch_unreachable_destructuring() {
+    let input = r#"
+fn main() -> u8 {
+    let value = (42, true);
+    match value {
+        (_, false) => 1,
+        (x, true) => x,
+        (0, true) => 2,
+    }
+}
+"#;
+
+    let expected = Ok(vec![Warning::UnreachableMatchBranch {
+        location: Location::test(7, 9),
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_branch_pattern_element_count() {
+    let input = r#"
+fn main() -> u8 {
+    let value = (42, true);
+    match value {
+        (x, _, _) => x,
+        _ => 0,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::MatchBranchPatternElementCount {
+            location: Location::test(5, 9),
+            r#type: Type::tuple(
+                None,
+                vec![
+                    Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+                    Type::boolean(None),
+                ],
+            )
+            .to_string(),
+            expected: 2,
+            found: 3,
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_branch_pattern_field_missing() {
+    let input = r#"
+struct Point {
+    x: u8,
+    y: u8,
+}
+
+fn main() -> u8 {
+    let point = Point { x: 1, y: 2 };
+    match point {
+        Point { x } => x,
+        _ => 0,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::MatchBranchPatternFieldMissing {
+            location: Location::test(10, 9),
+            r#type: "Point".to_owned(),
+            field_name: "y".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_branch_pattern_field_duplicate() {
+    let input = r#"
+struct Point {
+    x: u8,
+    y: u8,
+}
+
+fn main() -> u8 {
+    let point = Point { x: 1, y: 2 };
+    match point {
+        Point { x, x: z, .. } => x,
+        _ => 0,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::MatchBranchPatternFieldDuplicate {
+            location: Location::test(10, 20),
+            field_name: "x".to_owned(),
+            reference: Location::test(10, 17),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_alternatives() {
+    let input = r#"
+fn main() -> u8 {
+    let value = 42;
+    match value {
+        1 | 2 | 3 => 10,
+        42 => 20,
+        _ => 30,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_alternatives_binding() {
+    let input = r#"
+fn main() -> u8 {
+    let value = (42, true);
+    match value {
+        (x, true) | (x, false) if x > 100 => x,
+        (x, _) => x + 1,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_range_exhaustive() {
+    let input = r#"
+fn main() -> u8 {
+    let value: u8 = 42;
+    match value {
+        0..=99 | 100..=199 => 1,
+        200..=255 => 2,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_range_exclusive() {
+    let input = r#"
+fn main() -> u8 {
+    let value: u8 = 42;
+    match value {
+        0..10 => 1,
+        10..100 => 2,
+        _ => 3,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_guard() {
+    let input = r#"
+fn main() -> u8 {
+    let value: u8 = 42;
+    match value {
+        x if x > 100 => 1,
+        x => x,
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_constant_guard() {
+    let input = r#"
+const VALUE: u8 = match 42 {
+    0..=9 => 1,
+    x if x > 40 => 2,
+    _ => 3,
+};
+
+fn main() -> u8 {
+    VALUE
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_if_let() {
+    let input = r#"
+fn main() -> u8 {
+    let value = (42, true);
+    if let (x, true) = value {
+        x
+    } else {
+        0
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_not_exhausted_range() {
+    let input = r#"
+fn main() -> u8 {
+    let value: u8 = 42;
+    match value {
+        0..=127 => 1,
+        129..=255 => 2,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::MatchNotExhausted {
+        location: Location::test(4, 5),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_not_exhausted_guard() {
+    let input = r#"
+fn main() -> u8 {
+    let value = true;
+    match value {
+        true => 1,
+        false if value => 2,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::MatchNotExhausted {
+        location: Location::test(4, 5),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_branch_pattern_range_empty() {
+    let input = r#"
+fn main() -> u8 {
+    let value: u8 = 42;
+    match value {
+        10..10 => 1,
+        _ => 2,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::MatchBranchPatternRangeEmpty {
+            location: Location::test(5, 9),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_branch_pattern_alternative_binding_missing() {
+    let input = r#"
+fn main() -> u8 {
+    let value = (42, true);
+    match value {
+        (x, true) | (_, false) => 1,
+        _ => 2,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::MatchBranchPatternAlternativeBindingMissing {
+            location: Location::test(5, 21),
+            name: "x".to_owned(),
+            reference: Location::test(5, 10),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_branch_guard_expected_boolean_condition() {
+    let input = r#"
+fn main() -> u8 {
+    let value: u8 = 42;
+    match value {
+        x if x => 1,
+        _ => 2,
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::MatchBranchGuardExpectedBooleanCondition {
+            location: Location::test(5, 14),
+            found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -35,6 +35,9 @@ use std::ops::Shr;
 use std::ops::Sub;
 use std::rc::Rc;
 
+use num::BigInt;
+use num::Signed;
+
 use zinc_lexical::Location;
 use zinc_syntax::ExpressionOperand;
 use zinc_syntax::ExpressionOperator;
@@ -48,12 +51,15 @@ use crate::generator::expression::operand::Operand as GeneratorExpressionOperand
 use crate::generator::expression::operator::Operator as GeneratorExpressionOperator;
 use crate::generator::expression::Expression as GeneratorExpression;
 use crate::semantic::analyzer::rule::Rule as TranslationRule;
+use crate::semantic::casting::Caster;
 use crate::semantic::element::access::dot::Dot as DotAccess;
 use crate::semantic::element::constant::unit::Unit as UnitConstant;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::place::element::Element as PlaceElement;
+use crate::semantic::element::place::memory_type::MemoryType;
 use crate::semantic::element::place::Place;
 use crate::semantic::element::r#type::function::Function as FunctionType;
+use crate::semantic::element::r#type::i_typed::ITyped;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::value::unit::Unit as UnitValue;
 use crate::semantic::element::value::Value;
@@ -61,6 +67,7 @@ use crate::semantic::element::Element;
 use crate::semantic::error::Error;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
+use crate::semantic::warning::Warning;
 
 use self::array::Analyzer as ArrayAnalyzer;
 use self::block::Analyzer as BlockAnalyzer;
@@ -121,7 +128,22 @@ impl Analyzer {
     /// Is used either as an outer interface method, or for getting the array index 2nd operand
     /// subtree, assignment operands and so on.
     ///
-    pub fn analyze(
+    pub fn analyze(self, tree: ExpressionTree) -> Result<(Element, GeneratorExpression), Error> {
+        let location = tree.location;
+
+        let (element, intermediate) = self.analyze_argument(tree)?;
+        if let Element::Reference(_) = element {
+            return Err(Error::ReferenceOutsideFunctionArgument { location });
+        }
+
+        Ok((element, intermediate))
+    }
+
+    ///
+    /// Analyzes the function argument expression `tree`, which, unlike other expressions,
+    /// is allowed to be a mutable reference.
+    ///
+    pub fn analyze_argument(
         mut self,
         tree: ExpressionTree,
     ) -> Result<(Element, GeneratorExpression), Error> {
@@ -515,11 +537,25 @@ impl Analyzer {
                     self.unary(Element::neg, tree.location, intermediate_1)?;
                 }
 
+                ExpressionOperator::Reference => {
+                    self.left_local(tree.left, operator, rule)?;
+
+                    let (operand, _) = Self::evaluate(
+                        self.scope_stack.top(),
+                        self.evaluation_stack.pop(),
+                        TranslationRule::Place,
+                    )?;
+                    let (element, intermediate) =
+                        Self::reference(self.scope_stack.top(), operand, tree.location)?;
+
+                    return Ok((element, Some(intermediate)));
+                }
+
                 ExpressionOperator::Index => {
                     self.left_local(tree.left, operator, rule)?;
                     let intermediate_2 = self.right_separate(tree.right, operator, rule)?;
 
-                    let intermediate = self.index(intermediate_2)?;
+                    let intermediate = self.index(intermediate_2, tree.location)?;
                     if let Some(intermediate) = intermediate {
                         self.intermediate.push_operator(tree.location, intermediate);
                     }
@@ -568,7 +604,10 @@ impl Analyzer {
                     self.left_local(tree.left, operator, rule)?;
                     self.right_local(tree.right, operator, rule)?;
 
-                    self.structure()?;
+                    let intermediate = self.structure()?;
+                    if let Some(intermediate) = intermediate {
+                        self.intermediate.push_operator(tree.location, intermediate);
+                    }
                 }
             },
         }
@@ -714,6 +753,7 @@ impl Analyzer {
                 reference: item_location,
             });
         }
+        RefCell::borrow(&self.scope_stack.top()).mark_mutated(&place.identifier);
         if place.r#type != r#type {
             return Err(Error::MutatingWithDifferentType {
                 location: place.identifier.location,
@@ -846,6 +886,17 @@ impl Analyzer {
             self.rule,
         )?;
 
+        if let (Element::Value(ref value), Element::Type(ref to)) = (&operand_1, &operand_2) {
+            let from = value.r#type();
+            if Caster::is_truncating(&from, to) {
+                RefCell::borrow(&self.scope_stack.top()).warn(Warning::TruncatingCast {
+                    location,
+                    from: from.to_string(),
+                    to: to.to_string(),
+                });
+            }
+        }
+
         let (result, operator) = Element::cast(operand_1, operand_2)?;
         self.evaluation_stack.push(StackElement::Evaluated(result));
 
@@ -890,6 +941,7 @@ impl Analyzer {
     fn index(
         &mut self,
         expression: GeneratorExpression,
+        location: Location,
     ) -> Result<Option<GeneratorExpressionOperator>, Error> {
         let (operand_2, _) = Self::evaluate(
             self.scope_stack.top(),
@@ -902,6 +954,19 @@ impl Analyzer {
             TranslationRule::Place,
         )?;
 
+        match operand_1 {
+            Element::Place(place) if place.r#type.is_vec() => {
+                return self.vec_index(place, operand_2, expression);
+            }
+            Element::Value(ref value) if value.r#type().is_vec() => {
+                return Err(Error::OperatorIndexVecExpectedPlace {
+                    location: value.location().unwrap_or(location),
+                    found: operand_1.to_string(),
+                });
+            }
+            _ => {}
+        }
+
         let (result, access) = Element::index(operand_1, operand_2.clone())?;
 
         match result {
@@ -943,6 +1008,62 @@ impl Analyzer {
         }
     }
 
+    ///
+    /// Analyzes the `std::collections::Vec` index operation.
+    ///
+    /// The index is always checked against the vector length at runtime, so the data array
+    /// element is accessed via the index expression, which performs the check.
+    ///
+    fn vec_index(
+        &mut self,
+        place: Place,
+        index: Element,
+        mut expression: GeneratorExpression,
+    ) -> Result<Option<GeneratorExpressionOperator>, Error> {
+        let location = place.identifier.location;
+
+        match index {
+            Element::Value(Value::Integer(_)) => {}
+            Element::Constant(Constant::Integer(ref integer)) => {
+                let capacity = match place.r#type {
+                    Type::Structure(ref structure) => match structure.fields.first() {
+                        Some((_name, Type::Array(array))) => array.size,
+                        _ => panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+                    },
+                    _ => panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+                };
+
+                if integer.value.is_negative() || integer.value >= BigInt::from(capacity) {
+                    return Err(Error::ArrayIndexOutOfRange {
+                        location: integer.location,
+                        index: integer.value.to_string(),
+                        size: capacity,
+                    });
+                }
+            }
+            element => {
+                return Err(Error::OperatorIndexVecExpectedInteger {
+                    location: element.location().unwrap_or(location),
+                    found: element.to_string(),
+                })
+            }
+        }
+
+        let (data, length) = place.vec_fields();
+        let (mut element, access) = data.index(index)?;
+
+        expression.push_operator(
+            location,
+            GeneratorExpressionOperator::vec_bounds_check(length.into()),
+        );
+        element.push_element(PlaceElement::IndexExpression { expression, access });
+
+        self.evaluation_stack
+            .push(StackElement::Evaluated(Element::Place(element)));
+
+        Ok(None)
+    }
+
     ///
     /// Analyzes the tuple or structure field access operation.
     ///
@@ -992,31 +1113,116 @@ impl Analyzer {
                 }
             },
             DotAccess::Method { instance } => {
-                let (instance, is_mutable) = if let Element::Place(instance) = *instance {
-                    let is_mutable = instance.is_mutable;
+                let is_reference_method = match result {
+                    Element::Type(Type::Function(ref function)) => function.is_reference_method(),
+                    _ => false,
+                };
 
-                    let (instance, intermedidate) = Self::evaluate(
-                        self.scope_stack.top(),
-                        StackElement::Evaluated(Element::Place(instance)),
-                        TranslationRule::Value,
-                    )?;
-                    if let Some(intermediate) = intermedidate {
-                        self.intermediate.push_operand(intermediate);
+                let (instance, is_mutable, identifier) = match *instance {
+                    Element::Place(instance) if instance.r#type.is_vec() => {
+                        let is_mutable = instance.is_mutable;
+                        let identifier = instance.identifier.to_owned();
+
+                        (Element::Place(instance), is_mutable, Some(identifier))
                     }
+                    Element::Place(instance) if is_reference_method && instance.is_mutable => {
+                        let identifier = instance.identifier.to_owned();
+
+                        let (instance, intermediate) = Self::reference(
+                            self.scope_stack.top(),
+                            Element::Place(instance),
+                            identifier.location,
+                        )?;
+                        self.intermediate.push_operand(intermediate);
 
-                    (instance, is_mutable)
-                } else {
-                    (*instance, true)
+                        (instance, true, Some(identifier))
+                    }
+                    Element::Place(instance) => {
+                        let is_mutable = instance.is_mutable;
+                        let identifier = instance.identifier.to_owned();
+
+                        let (instance, intermedidate) = Self::evaluate(
+                            self.scope_stack.top(),
+                            StackElement::Evaluated(Element::Place(instance)),
+                            TranslationRule::Value,
+                        )?;
+                        if let Some(intermediate) = intermedidate {
+                            self.intermediate.push_operand(intermediate);
+                        }
+
+                        (instance, is_mutable, Some(identifier))
+                    }
+                    instance => (instance, true, None),
                 };
 
                 self.evaluation_stack.push(StackElement::Evaluated(result));
-                self.next_call_type = CallType::new_method(instance, is_mutable);
+                self.next_call_type = CallType::new_method(instance, is_mutable, identifier);
 
                 Ok(None)
             }
         }
     }
 
+    ///
+    /// Analyzes the mutable reference operation.
+    ///
+    /// Only the mutable data stack places with constant indexes can be referenced, since the
+    /// reference is an absolute data stack address known at compile time.
+    ///
+    fn reference(
+        scope: Rc<RefCell<Scope>>,
+        operand: Element,
+        location: Location,
+    ) -> Result<(Element, GeneratorExpressionOperand), Error> {
+        let place = match operand {
+            Element::Place(place) => place,
+            element => {
+                return Err(Error::ReferenceExpectedPlace {
+                    location: element.location().unwrap_or(location),
+                    found: element.to_string(),
+                })
+            }
+        };
+
+        if let MemoryType::ContractStorage { .. } = place.memory_type {
+            return Err(Error::ReferenceContractStorage {
+                location: place.identifier.location,
+            });
+        }
+        if let Type::Contract(_) = place.r#type {
+            return Err(Error::ReferenceContractStorage {
+                location: place.identifier.location,
+            });
+        }
+        if place
+            .elements
+            .iter()
+            .any(|element| matches!(element, PlaceElement::IndexExpression { .. }))
+        {
+            return Err(Error::ReferenceRuntimeIndex {
+                location: place.identifier.location,
+            });
+        }
+        if !place.is_mutable {
+            let item_location = scope
+                .borrow()
+                .resolve_item(&place.identifier, true)?
+                .borrow()
+                .location();
+
+            return Err(Error::ReferenceToImmutableMemory {
+                location: place.identifier.location,
+                name: place.identifier.name,
+                reference: item_location,
+            });
+        }
+        RefCell::borrow(&scope).mark_mutated(&place.identifier);
+
+        let intermediate = GeneratorExpressionOperand::Reference(place.clone().into());
+
+        Ok((Element::Reference(place), intermediate))
+    }
+
     ///
     /// Analyzes the function call operation.
     ///
@@ -1072,7 +1278,9 @@ impl Analyzer {
     ///
     /// Analyzes the structure initialization operation.
     ///
-    fn structure(&mut self) -> Result<(), Error> {
+    /// Returns the enumeration variant constructor, if the structure-like variant is initialized.
+    ///
+    fn structure(&mut self) -> Result<Option<GeneratorExpressionOperator>, Error> {
         let (operand_2, _) = Self::evaluate(
             self.scope_stack.top(),
             self.evaluation_stack.pop(),
@@ -1084,10 +1292,10 @@ impl Analyzer {
             TranslationRule::Type,
         )?;
 
-        let result = Element::structure(operand_1, operand_2, self.scope_stack.top())?;
+        let (result, operator) = Element::structure(operand_1, operand_2, self.scope_stack.top())?;
         self.evaluation_stack.push(StackElement::Evaluated(result));
 
-        Ok(())
+        Ok(operator)
     }
 
     ///
@@ -1109,13 +1317,16 @@ impl Analyzer {
                 ExpressionOperand::LiteralString(inner) => {
                     Ok((LiteralAnalyzer::string(inner)?, None))
                 }
+                ExpressionOperand::LiteralBytes(inner) => LiteralAnalyzer::bytes(inner),
                 ExpressionOperand::TupleIndex(inner) => {
                     Ok((TupleIndexAnalyzer::integer(inner)?, None))
                 }
                 ExpressionOperand::Identifier(inner) => {
                     IdentifierAnalyzer::analyze(scope, inner, rule)
                 }
-                ExpressionOperand::Type(inner) => Ok((TypeAnalyzer::analyze(scope, inner)?, None)),
+                ExpressionOperand::Type(inner) | ExpressionOperand::GenericPath(inner) => {
+                    Ok((TypeAnalyzer::analyze(scope, inner)?, None))
+                }
                 ExpressionOperand::Array(inner) => ArrayAnalyzer::analyze(scope, inner, rule),
                 ExpressionOperand::Tuple(inner) => TupleAnalyzer::analyze(scope, inner, rule),
                 ExpressionOperand::Structure(inner) => {
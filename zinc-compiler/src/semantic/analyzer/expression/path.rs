@@ -5,15 +5,24 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use zinc_lexical::Location;
+
 use crate::generator::expression::operand::constant::Constant as GeneratorConstant;
+use crate::generator::expression::operand::group::builder::Builder as GeneratorGroupExpressionBuilder;
 use crate::generator::expression::operand::Operand as GeneratorExpressionOperand;
+use crate::generator::expression::operator::Operator as GeneratorExpressionOperator;
+use crate::generator::expression::Expression as GeneratorExpression;
 use crate::generator::r#type::Type as GeneratorType;
 use crate::semantic::analyzer::rule::Rule as TranslationRule;
 use crate::semantic::element::path::Path;
-use crate::semantic::element::place::memory_type::MemoryType;
 use crate::semantic::element::place::Place;
+use crate::semantic::element::r#type::enumeration::Enumeration as EnumerationType;
+use crate::semantic::element::r#type::function::intrinsic::vec::Function as VecFunction;
+use crate::semantic::element::r#type::function::intrinsic::Function as IntrinsicFunctionType;
+use crate::semantic::element::r#type::function::Function as FunctionType;
 use crate::semantic::element::r#type::i_typed::ITyped;
 use crate::semantic::element::r#type::Type;
+use crate::semantic::element::value::enumeration::Enumeration as EnumerationValue;
 use crate::semantic::element::value::Value;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
@@ -43,8 +52,8 @@ impl Translator {
                     Element::Place(Place::new(
                         path_last_identifier,
                         variable.r#type.to_owned(),
-                        variable.is_mutable,
-                        MemoryType::Stack,
+                        variable.is_mutable || variable.is_reference,
+                        variable.memory_type(),
                     )),
                     None,
                 )),
@@ -60,6 +69,10 @@ impl Translator {
                     ))
                 }
                 ScopeItem::Variant(ref variant) => {
+                    if let Some((enumeration, index)) = variant.data() {
+                        return Self::data_variant(location, enumeration, index);
+                    }
+
                     let mut constant = variant.constant.to_owned();
                     constant.set_location(location);
 
@@ -81,6 +94,10 @@ impl Translator {
                     location,
                     found: field.identifier.to_owned(),
                 }),
+                ScopeItem::Trait(ref r#trait) => Err(Error::TraitUsedAsExpression {
+                    location,
+                    name: r#trait.identifier.to_owned(),
+                }),
             },
             TranslationRule::Value => match *Scope::resolve_path(scope, &path)?.borrow() {
                 ScopeItem::Variable(ref variable) => {
@@ -93,8 +110,8 @@ impl Translator {
                             Place::new(
                                 path_last_identifier,
                                 r#type,
-                                variable.is_mutable,
-                                MemoryType::Stack,
+                                variable.is_mutable || variable.is_reference,
+                                variable.memory_type(),
                             )
                             .into()
                         })
@@ -113,6 +130,10 @@ impl Translator {
                     Ok((element, intermediate))
                 }
                 ScopeItem::Variant(ref variant) => {
+                    if let Some((enumeration, index)) = variant.data() {
+                        return Self::data_variant(location, enumeration, index);
+                    }
+
                     let mut constant = variant.constant.to_owned();
                     constant.set_location(location);
 
@@ -143,6 +164,10 @@ impl Translator {
                     location,
                     found: field.identifier.to_owned(),
                 }),
+                ScopeItem::Trait(ref r#trait) => Err(Error::TraitUsedAsExpression {
+                    location,
+                    name: r#trait.identifier.to_owned(),
+                }),
             },
             TranslationRule::Constant => match *Scope::resolve_path(scope, &path)?.borrow() {
                 ScopeItem::Constant(ref constant) => {
@@ -155,7 +180,7 @@ impl Translator {
                     let element = Element::Constant(constant);
                     Ok((element, intermediate))
                 }
-                ScopeItem::Variant(ref variant) => {
+                ScopeItem::Variant(ref variant) if variant.data().is_none() => {
                     let mut constant = variant.constant.to_owned();
                     constant.set_location(location);
 
@@ -183,6 +208,13 @@ impl Translator {
                         r#type.set_location(location);
                     }
 
+                    if let Type::Function(FunctionType::Intrinsic(IntrinsicFunctionType::Vec(
+                        VecFunction::New(ref mut function),
+                    ))) = r#type
+                    {
+                        function.r#type = path.base.to_owned().map(Box::new);
+                    }
+
                     Ok((Element::Type(r#type), None))
                 }
                 _ => Ok((Element::Path(path), None)),
@@ -191,4 +223,39 @@ impl Translator {
             TranslationRule::Field => Ok((Element::Identifier(path_last_identifier), None)),
         }
     }
+    ///
+    /// Translates a unit variant of an enumeration with data variants.
+    ///
+    /// Such variants are not constants, so the enumeration value is constructed at runtime.
+    ///
+    fn data_variant(
+        location: Location,
+        enumeration: &EnumerationType,
+        index: usize,
+    ) -> Result<(Element, Option<GeneratorExpressionOperand>), Error> {
+        if !enumeration.payloads[index].is_unit() {
+            return Err(Error::TypeVariantExpectedUnit {
+                location,
+                variant: enumeration.variant_signature(index),
+            });
+        }
+
+        let mut expression = GeneratorExpression::new();
+        expression.push_operator(
+            location,
+            GeneratorExpressionOperator::enumeration_variant(enumeration, index),
+        );
+
+        let mut builder = GeneratorGroupExpressionBuilder::default();
+        builder.push_expression(Type::Enumeration(enumeration.to_owned()), expression);
+
+        let element = Element::Value(Value::Enumeration(EnumerationValue::new(
+            Some(location),
+            enumeration.to_owned(),
+        )));
+        Ok((
+            element,
+            Some(GeneratorExpressionOperand::Group(builder.finish())),
+        ))
+    }
 }
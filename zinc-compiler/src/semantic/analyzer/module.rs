@@ -11,6 +11,7 @@ use zinc_syntax::Module as SyntaxModule;
 use zinc_syntax::ModuleLocalStatement;
 
 use crate::semantic::analyzer::statement::module::Analyzer as ModStatementAnalyzer;
+use crate::semantic::analyzer::statement::r#impl::implementation::Implementation;
 use crate::semantic::analyzer::statement::r#impl::Analyzer as ImplStatementAnalyzer;
 use crate::semantic::analyzer::statement::r#use::Analyzer as UseStatementAnalyzer;
 use crate::semantic::error::Error;
@@ -34,10 +35,9 @@ impl Analyzer {
     /// `<module>.zn` in the module directory. For example, `mod foo;` will look for a file called
     /// `./foo.zn` and yield an error if it is absent.
     ///
-    /// Returns the module without the hoisted statements and the implementation scopes which
+    /// Returns the module without the hoisted statements and the implementations which
     /// must be defined forcibly.
     ///
-    #[allow(clippy::type_complexity)]
     pub fn declare(
         scope: Rc<RefCell<Scope>>,
         mut module: SyntaxModule,
@@ -45,102 +45,48 @@ impl Analyzer {
         scope_crate: Rc<RefCell<Scope>>,
         dependencies: HashMap<String, Rc<RefCell<Scope>>>,
         is_entry: bool,
-    ) -> Result<(SyntaxModule, Vec<Rc<RefCell<Scope>>>), Error> {
+    ) -> Result<(SyntaxModule, Vec<Implementation>), Error> {
         let mut instant_statements = Vec::with_capacity(module.statements.len());
-        let mut implementation_scopes = Vec::with_capacity(module.statements.len());
+        let mut implementations = Vec::with_capacity(module.statements.len());
 
         for hoisted_statement in module.statements.into_iter() {
-            match hoisted_statement {
-                ModuleLocalStatement::Const(statement) => {
-                    Scope::declare_constant(scope.clone(), statement)?;
-                }
-                ModuleLocalStatement::Type(statement) => {
-                    Scope::declare_type(scope.clone(), TypeStatementVariant::Type(statement))?;
-                }
-                ModuleLocalStatement::Struct(statement) => {
-                    Scope::declare_type(scope.clone(), TypeStatementVariant::Struct(statement))?;
-                }
-                ModuleLocalStatement::Enum(statement) => {
-                    Scope::declare_type(scope.clone(), TypeStatementVariant::Enum(statement))?;
-                }
-                ModuleLocalStatement::Fn(statement) => {
-                    if !is_entry
-                        && statement.identifier.name.as_str()
-                            == zinc_const::source::FUNCTION_MAIN_IDENTIFIER
-                    {
-                        return Err(Error::FunctionMainBeyondEntry {
-                            location: statement.location,
-                        });
-                    }
-
-                    if is_entry
-                        && statement.identifier.name.as_str()
-                            == zinc_const::source::FUNCTION_MAIN_IDENTIFIER
-                        && statement.is_constant
-                    {
-                        return Err(Error::EntryPointConstant {
-                            location: statement.location,
-                        });
-                    }
-
-                    Scope::declare_type(scope.clone(), TypeStatementVariant::Fn(statement))?;
-                }
-                ModuleLocalStatement::Mod(statement) => {
-                    let module = match modules.remove(statement.identifier.name.as_str()) {
-                        Some(module) => module,
-                        None => {
-                            return Err(Error::ModuleFileNotFound {
-                                location: statement.identifier.location,
-                                name: statement.identifier.name,
-                            });
-                        }
-                    };
-
-                    let identifier = ModStatementAnalyzer::analyze(statement)?;
-
-                    Scope::declare_module(
-                        scope.clone(),
-                        identifier,
-                        module,
-                        scope_crate.clone(),
-                        dependencies.clone(),
-                    )?;
-                }
-                ModuleLocalStatement::Contract(statement) => {
-                    if is_entry {
-                        Scope::declare_contract(scope.clone(), statement)?;
-                    } else {
-                        return Err(Error::ContractBeyondEntry {
-                            location: statement.location,
-                        });
-                    }
-                }
+            let result = match hoisted_statement {
                 ModuleLocalStatement::Impl(statement) => {
-                    let scope = ImplStatementAnalyzer::declare(scope.clone(), statement)?;
-                    implementation_scopes.push(scope);
+                    ImplStatementAnalyzer::declare(scope.clone(), statement, &implementations)
+                        .map(|implementation| implementations.extend(implementation))
                 }
                 ModuleLocalStatement::Use(statement) => {
-                    instant_statements.push(ModuleLocalStatement::Use(statement))
+                    instant_statements.push(ModuleLocalStatement::Use(statement));
+                    Ok(())
                 }
-                ModuleLocalStatement::Empty(_location) => {}
-            }
+                statement => Self::declare_statement(
+                    scope.clone(),
+                    statement,
+                    &mut modules,
+                    scope_crate.clone(),
+                    dependencies.clone(),
+                    is_entry,
+                ),
+            };
+
+            scope.borrow().recover(result)?;
         }
 
         module.statements = instant_statements;
 
-        Ok((module, implementation_scopes))
+        Ok((module, implementations))
     }
 
     ///
     /// 1. Defines the module aliases.
     /// 2. Defines the instant statements.
-    /// 3. Resolves the implementation scopes forcibly.
+    /// 3. Resolves the implementations forcibly, checking them against their traits.
     /// 4. Resolves the hoisted items forcibly.
     ///
     pub fn define(
         scope: Rc<RefCell<Scope>>,
         module: SyntaxModule,
-        implementation_scopes: Vec<Rc<RefCell<Scope>>>,
+        implementations: Vec<Implementation>,
         crate_item: Rc<RefCell<ScopeItem>>,
         super_item: Option<Rc<RefCell<ScopeItem>>>,
     ) -> Result<(), Error> {
@@ -151,16 +97,102 @@ impl Analyzer {
 
         for statement in module.statements.into_iter() {
             if let ModuleLocalStatement::Use(statement) = statement {
-                UseStatementAnalyzer::define(scope.clone(), statement)?;
+                let result = UseStatementAnalyzer::define(scope.clone(), statement);
+                scope.borrow().recover(result)?;
             }
         }
 
-        for implementation_scope in implementation_scopes.into_iter() {
-            implementation_scope.borrow().define()?;
+        for implementation in implementations.into_iter() {
+            let result = ImplStatementAnalyzer::define(implementation);
+            scope.borrow().recover(result)?;
         }
 
         scope.borrow().define()?;
 
         Ok(())
     }
+
+    ///
+    /// Declares a hoisted item statement in the module `scope`.
+    ///
+    /// The `impl` and `use` statements are not declared here, since they are collected to be
+    /// defined later during the module definition.
+    ///
+    fn declare_statement(
+        scope: Rc<RefCell<Scope>>,
+        statement: ModuleLocalStatement,
+        modules: &mut HashMap<String, Source>,
+        scope_crate: Rc<RefCell<Scope>>,
+        dependencies: HashMap<String, Rc<RefCell<Scope>>>,
+        is_entry: bool,
+    ) -> Result<(), Error> {
+        match statement {
+            ModuleLocalStatement::Const(statement) => {
+                Scope::declare_constant(scope, statement)?;
+            }
+            ModuleLocalStatement::Type(statement) => {
+                Scope::declare_type(scope, TypeStatementVariant::Type(statement))?;
+            }
+            ModuleLocalStatement::Struct(statement) => {
+                Scope::declare_type(scope, TypeStatementVariant::Struct(statement))?;
+            }
+            ModuleLocalStatement::Enum(statement) => {
+                Scope::declare_type(scope, TypeStatementVariant::Enum(statement))?;
+            }
+            ModuleLocalStatement::Fn(statement) => {
+                if !is_entry
+                    && statement.identifier.name.as_str()
+                        == zinc_const::source::FUNCTION_MAIN_IDENTIFIER
+                {
+                    return Err(Error::FunctionMainBeyondEntry {
+                        location: statement.location,
+                    });
+                }
+
+                if is_entry
+                    && statement.identifier.name.as_str()
+                        == zinc_const::source::FUNCTION_MAIN_IDENTIFIER
+                    && statement.is_constant
+                {
+                    return Err(Error::EntryPointConstant {
+                        location: statement.location,
+                    });
+                }
+
+                Scope::declare_type(scope, TypeStatementVariant::Fn(statement))?;
+            }
+            ModuleLocalStatement::Mod(statement) => {
+                let module = match modules.remove(statement.identifier.name.as_str()) {
+                    Some(module) => module,
+                    None => {
+                        return Err(Error::ModuleFileNotFound {
+                            location: statement.identifier.location,
+                            name: statement.identifier.name,
+                        });
+                    }
+                };
+
+                let identifier = ModStatementAnalyzer::analyze(statement)?;
+
+                Scope::declare_module(scope, identifier, module, scope_crate, dependencies)?;
+            }
+            ModuleLocalStatement::Contract(statement) => {
+                if is_entry {
+                    Scope::declare_contract(scope, statement)?;
+                } else {
+                    return Err(Error::ContractBeyondEntry {
+                        location: statement.location,
+                    });
+                }
+            }
+            ModuleLocalStatement::Trait(statement) => {
+                Scope::declare_trait(scope, statement)?;
+            }
+            ModuleLocalStatement::Impl(_)
+            | ModuleLocalStatement::Use(_)
+            | ModuleLocalStatement::Empty(_) => {}
+        }
+
+        Ok(())
+    }
 }
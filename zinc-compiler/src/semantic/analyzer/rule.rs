@@ -78,6 +78,7 @@ impl Rule {
             ExpressionOperator::Not => rule.constant_or_value(),
             ExpressionOperator::BitwiseNot => rule.constant_or_value(),
             ExpressionOperator::Negation => rule.constant_or_value(),
+            ExpressionOperator::Reference => Self::Place,
 
             ExpressionOperator::Index => rule.constant_or_place(),
             ExpressionOperator::Dot => rule.constant_or_place(),
@@ -145,6 +146,9 @@ impl Rule {
             ExpressionOperator::Negation => {
                 panic!(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS)
             }
+            ExpressionOperator::Reference => {
+                panic!("{}", zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS)
+            }
 
             ExpressionOperator::Index => rule.constant_or_value(),
             ExpressionOperator::Dot => Self::Field,
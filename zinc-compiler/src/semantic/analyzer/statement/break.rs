@@ -0,0 +1,35 @@
+//!
+//! The `break` statement semantic analyzer.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_syntax::BreakStatement;
+
+use crate::generator::statement::r#break::Statement as GeneratorBreakStatement;
+use crate::semantic::error::Error;
+use crate::semantic::scope::r#type::Type as ScopeType;
+use crate::semantic::scope::Scope;
+
+///
+/// The `break` statement semantic analyzer.
+///
+pub struct Analyzer {}
+
+impl Analyzer {
+    ///
+    /// Defines a loop early exit and returns its IR for the next compiler phase.
+    ///
+    pub fn define(
+        scope: Rc<RefCell<Scope>>,
+        statement: BreakStatement,
+    ) -> Result<GeneratorBreakStatement, Error> {
+        match Scope::define_early_exit(scope, |r#type| matches!(r#type, ScopeType::Loop)) {
+            Some(_) => Ok(GeneratorBreakStatement::new(statement.location)),
+            None => Err(Error::BreakStatementBeyondLoop {
+                location: statement.location,
+            }),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+//!
+//! The `continue` statement semantic analyzer.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_syntax::ContinueStatement;
+
+use crate::generator::statement::r#continue::Statement as GeneratorContinueStatement;
+use crate::semantic::error::Error;
+use crate::semantic::scope::r#type::Type as ScopeType;
+use crate::semantic::scope::Scope;
+
+///
+/// The `continue` statement semantic analyzer.
+///
+pub struct Analyzer {}
+
+impl Analyzer {
+    ///
+    /// Defines a loop early exit and returns its IR for the next compiler phase.
+    ///
+    pub fn define(
+        scope: Rc<RefCell<Scope>>,
+        statement: ContinueStatement,
+    ) -> Result<GeneratorContinueStatement, Error> {
+        match Scope::define_early_exit(scope, |r#type| matches!(r#type, ScopeType::Loop)) {
+            Some(_) => Ok(GeneratorContinueStatement::new(statement.location)),
+            None => Err(Error::ContinueStatementBeyondLoop {
+                location: statement.location,
+            }),
+        }
+    }
+}
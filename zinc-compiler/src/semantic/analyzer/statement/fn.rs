@@ -18,9 +18,12 @@ use crate::semantic::analyzer::rule::Rule as TranslationRule;
 use crate::semantic::binding::Binder;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error;
+use crate::semantic::generics::Template;
+use crate::semantic::scope::item::r#type::statement::Statement as TypeStatementVariant;
 use crate::semantic::scope::r#type::Type as ScopeType;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
+use crate::semantic::warning::lint::Lint;
 
 ///
 /// The `fn` statement semantic analyzer.
@@ -35,6 +38,10 @@ impl Analyzer {
         scope: Rc<RefCell<Scope>>,
         mut statement: FnStatement,
     ) -> Result<(Type, Option<GeneratorFunctionStatement>), Error> {
+        if !statement.generics.is_empty() {
+            return Self::generic(scope, statement).map(|r#type| (r#type, None));
+        }
+
         if let ScopeType::Contract = RefCell::borrow(&scope).r#type() {
             if statement.is_public && statement.is_constant {
                 return Err(Error::EntryPointConstant {
@@ -62,6 +69,46 @@ impl Analyzer {
         }
     }
 
+    ///
+    /// Analyzes a generic function statement.
+    ///
+    /// The function body is analyzed separately for each set of the generic arguments,
+    /// which are inferred at the call site.
+    ///
+    fn generic(scope: Rc<RefCell<Scope>>, mut statement: FnStatement) -> Result<Type, Error> {
+        let is_test = statement.attributes.iter().any(|attribute| {
+            matches!(
+                Attribute::try_from(attribute.to_owned()),
+                Ok(Attribute::Test)
+            )
+        });
+
+        if statement.is_constant
+            || is_test
+            || statement.identifier.name.as_str() == zinc_const::source::FUNCTION_MAIN_IDENTIFIER
+            || !RefCell::borrow(&scope).r#type().is_module()
+        {
+            return Err(Error::FunctionGenericsUnavailable {
+                location: statement.location,
+                function: statement.identifier.name,
+            });
+        }
+
+        let location = statement.location;
+        let identifier = statement.identifier.name.clone();
+        let generics = std::mem::take(&mut statement.generics);
+
+        let template = Template::new(
+            location,
+            identifier.clone(),
+            generics,
+            TypeStatementVariant::Fn(statement),
+            scope,
+        )?;
+
+        Ok(Type::generic_function(location, identifier, template))
+    }
+
     ///
     /// Analyzes a runtime function statement and returns its IR for the next compiler phase.
     ///
@@ -71,6 +118,29 @@ impl Analyzer {
         attributes: Vec<Attribute>,
     ) -> Result<(Type, GeneratorFunctionStatement), Error> {
         let scope_type = RefCell::borrow(&scope).r#type();
+
+        let expected_type = match statement.return_type {
+            Some(ref r#type) => Type::try_from_syntax(r#type.to_owned(), scope.clone())?,
+            None => Type::unit(None),
+        };
+
+        if !expected_type.is_instantiatable(false) {
+            return Err(Error::TypeInstantiationForbidden {
+                location: statement
+                    .return_type
+                    .map(|r#type| r#type.location)
+                    .unwrap_or(statement.location),
+                found: expected_type.to_string(),
+            });
+        }
+
+        let allowed_lints = Self::allowed_lints(attributes.as_slice());
+        Self::declare_unused_candidate(scope.clone(), &statement, allowed_lints.as_slice());
+
+        let function_scope_type = ScopeType::Function {
+            identifier: statement.identifier.name.clone(),
+            return_type: Some(expected_type.clone()),
+        };
         let mut scope_stack = if scope_type.is_implementation() {
             let alias_identifier =
                 Identifier::new(statement.location, Keyword::SelfUppercase.to_string());
@@ -81,32 +151,18 @@ impl Analyzer {
                     .parent()
                     .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
             );
-            scope_stack.push(Some(statement.identifier.name.clone()), ScopeType::Function);
+            scope_stack.push(Some(statement.identifier.name.clone()), function_scope_type);
             Scope::define_item(scope_stack.top(), alias_identifier, item)?;
             scope_stack
         } else {
             let mut scope_stack = ScopeStack::new(scope);
-            scope_stack.push(Some(statement.identifier.name.clone()), ScopeType::Function);
+            scope_stack.push(Some(statement.identifier.name.clone()), function_scope_type);
             scope_stack
         };
+        RefCell::borrow(&scope_stack.top()).allow_lints(allowed_lints.as_slice());
 
         let bindings = Binder::bind_arguments(statement.argument_bindings, scope_stack.top())?;
 
-        let expected_type = match statement.return_type {
-            Some(ref r#type) => Type::try_from_syntax(r#type.to_owned(), scope_stack.top())?,
-            None => Type::unit(None),
-        };
-
-        if !expected_type.is_instantiatable(false) {
-            return Err(Error::TypeInstantiationForbidden {
-                location: statement
-                    .return_type
-                    .map(|r#type| r#type.location)
-                    .unwrap_or(statement.location),
-                found: expected_type.to_string(),
-            });
-        }
-
         let return_expression_location = match statement
             .body
             .expression
@@ -122,12 +178,15 @@ impl Analyzer {
                 .unwrap_or(statement.location),
         };
 
+        let is_return_terminated = statement.body.is_return_terminated();
+
         let (result, intermediate) =
             BlockAnalyzer::analyze(scope_stack.top(), statement.body, TranslationRule::Value)?;
+        let has_early_return = RefCell::borrow(&scope_stack.top()).early_exits() > 0;
         scope_stack.pop();
 
         let result_type = Type::from_element(&result, scope_stack.top())?;
-        if expected_type != result_type {
+        if expected_type != result_type && !is_return_terminated {
             return Err(Error::FunctionReturnType {
                 location: return_expression_location,
                 function: statement.identifier.name.clone(),
@@ -173,6 +232,15 @@ impl Analyzer {
             _ => GeneratorFunctionRole::Ordinar,
         };
 
+        if !matches!(role, GeneratorFunctionRole::Ordinar) {
+            if let Some(binding) = bindings.iter().find(|binding| binding.is_reference) {
+                return Err(Error::FunctionReferenceArgumentForbidden {
+                    location: binding.identifier.location,
+                    function: statement.identifier.name,
+                });
+            }
+        }
+
         let is_mutable = bindings
             .first()
             .map(|binding| binding.is_mutable)
@@ -191,6 +259,7 @@ impl Analyzer {
             is_mutable,
             bindings,
             intermediate,
+            has_early_return,
             expected_type,
             type_id,
             role,
@@ -206,12 +275,28 @@ impl Analyzer {
     fn constant(
         scope: Rc<RefCell<Scope>>,
         statement: FnStatement,
-        _attributes: Vec<Attribute>,
+        attributes: Vec<Attribute>,
     ) -> Result<Type, Error> {
+        let allowed_lints = Self::allowed_lints(attributes.as_slice());
+        Self::declare_unused_candidate(scope.clone(), &statement, allowed_lints.as_slice());
+
         let mut scope_stack = ScopeStack::new(scope);
-        scope_stack.push(Some(statement.identifier.name.clone()), ScopeType::Function);
+        scope_stack.push(
+            Some(statement.identifier.name.clone()),
+            ScopeType::Function {
+                identifier: statement.identifier.name.clone(),
+                return_type: None,
+            },
+        );
+        RefCell::borrow(&scope_stack.top()).allow_lints(allowed_lints.as_slice());
 
         let bindings = Binder::bind_arguments(statement.argument_bindings, scope_stack.top())?;
+        if let Some(binding) = bindings.iter().find(|binding| binding.is_reference) {
+            return Err(Error::FunctionReferenceArgumentForbidden {
+                location: binding.identifier.location,
+                function: statement.identifier.name,
+            });
+        }
 
         let expected_type = match statement.return_type {
             Some(ref r#type) => Type::try_from_syntax(r#type.to_owned(), scope_stack.top())?,
@@ -320,9 +405,18 @@ impl Analyzer {
             });
         }
 
-        scope_stack.push(Some(statement.identifier.name.clone()), ScopeType::Function);
+        scope_stack.push(
+            Some(statement.identifier.name.clone()),
+            ScopeType::Function {
+                identifier: statement.identifier.name.clone(),
+                return_type: Some(Type::unit(None)),
+            },
+        );
+        RefCell::borrow(&scope_stack.top())
+            .allow_lints(Self::allowed_lints(attributes.as_slice()).as_slice());
         let (_result, intermediate) =
             BlockAnalyzer::analyze(scope_stack.top(), statement.body, TranslationRule::Value)?;
+        let has_early_return = RefCell::borrow(&scope_stack.top()).early_exits() > 0;
         scope_stack.pop();
 
         let (r#type, type_id) =
@@ -334,6 +428,7 @@ impl Analyzer {
             false,
             vec![],
             intermediate,
+            has_early_return,
             Type::Unit(None),
             type_id,
             GeneratorFunctionRole::UnitTest,
@@ -342,4 +437,44 @@ impl Analyzer {
 
         Ok((r#type, intermediate))
     }
+
+    ///
+    /// Collects the lints allowed with the `#[allow(...)]` function attributes.
+    ///
+    fn allowed_lints(attributes: &[Attribute]) -> Vec<Lint> {
+        attributes
+            .iter()
+            .flat_map(|attribute| attribute.allowed_lints().iter().copied())
+            .collect()
+    }
+
+    ///
+    /// Declares the module-level or contract function to be reported by the `dead_code` lint,
+    /// unless it is called during the analysis.
+    ///
+    /// The public functions are not reported, since they can be called by the dependent
+    /// projects or the contract users, and neither is the circuit entry point.
+    ///
+    fn declare_unused_candidate(
+        scope: Rc<RefCell<Scope>>,
+        statement: &FnStatement,
+        allowed_lints: &[Lint],
+    ) {
+        let scope = RefCell::borrow(&scope);
+        let scope_type = scope.r#type();
+
+        if !(scope_type.is_module() || scope_type == ScopeType::Contract)
+            || statement.is_public
+            || statement.identifier.name.as_str() == zinc_const::source::FUNCTION_MAIN_IDENTIFIER
+            || allowed_lints.iter().any(|lint| lint.covers(Lint::DeadCode))
+        {
+            return;
+        }
+
+        if let Some(warnings) = scope.warnings() {
+            warnings
+                .borrow_mut()
+                .declare_function(statement.location, statement.identifier.name.to_owned());
+        }
+    }
 }
@@ -8,19 +8,40 @@ mod tests;
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use num::BigInt;
 use num::Signed;
 use num::ToPrimitive;
+use num::Zero;
 
+use zinc_lexical::Location;
+use zinc_syntax::Binding as SyntaxBinding;
+use zinc_syntax::BindingPattern;
+use zinc_syntax::BindingPatternVariant;
+use zinc_syntax::ExpressionOperand;
+use zinc_syntax::ExpressionOperator;
+use zinc_syntax::ExpressionTree;
+use zinc_syntax::ExpressionTreeNode;
 use zinc_syntax::ForStatement;
+use zinc_syntax::Identifier;
+use zinc_syntax::LetStatement;
+use zinc_syntax::TupleExpression;
+use zinc_syntax::Type as SyntaxType;
+use zinc_syntax::TypeVariant as SyntaxTypeVariant;
 
+use crate::generator::expression::Expression as GeneratorExpression;
 use crate::generator::statement::r#for::Statement as GeneratorForLoopStatement;
+use crate::generator::statement::r#let::Statement as GeneratorDeclarationStatement;
 use crate::semantic::analyzer::expression::block::Analyzer as BlockAnalyzer;
 use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
 use crate::semantic::analyzer::rule::Rule as TranslationRule;
+use crate::semantic::analyzer::statement::r#let::Analyzer as LetStatementAnalyzer;
+use crate::semantic::binding::Binding;
+use crate::semantic::element::constant::integer::Integer as IntegerConstant;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
+use crate::semantic::scope::intrinsic::IntrinsicTypeId;
 use crate::semantic::scope::r#type::Type as ScopeType;
 use crate::semantic::scope::stack::Stack as ScopeStack;
 use crate::semantic::scope::Scope;
@@ -31,9 +52,21 @@ use crate::semantic::scope::Scope;
 pub struct Analyzer {}
 
 impl Analyzer {
+    /// The `enumerate` array adapter method name.
+    const ADAPTER_ENUMERATE: &'static str = "enumerate";
+    /// The `zip` array adapter function name.
+    const ADAPTER_ZIP: &'static str = "zip";
+
     ///
     /// Defines a for-loop and returns its IR for the next compiler phase.
     ///
+    /// The loops over arrays are desugared into the index loops, that is, the arrays are stored
+    /// to hidden variables before the loop, and the item pattern is bound to the array elements
+    /// at the beginning of each iteration.
+    ///
+    /// The loops over vectors iterate over the whole data array, and the iterations beyond
+    /// the vector length are cut off by an implicit `while` condition.
+    ///
     pub fn define(
         scope: Rc<RefCell<Scope>>,
         statement: ForStatement,
@@ -43,44 +76,138 @@ impl Analyzer {
 
         let mut scope_stack = ScopeStack::new(scope);
 
-        let (range_start, range_end, index_bitlength, is_index_signed, is_inclusive) =
-            match ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Constant)
-                .analyze(statement.bounds_expression)?
-            {
-                (Element::Constant(Constant::RangeInclusive(range)), _intermediate) => (
-                    range.start,
-                    range.end,
-                    range.bitlength,
-                    range.is_signed,
-                    true,
-                ),
-                (Element::Constant(Constant::Range(range)), _intermediate) => (
-                    range.start,
-                    range.end,
-                    range.bitlength,
-                    range.is_signed,
-                    false,
-                ),
-                (element, _intermediate) => {
-                    return Err(Error::ForStatementBoundsExpectedConstantRangeExpression {
-                        location: bounds_expression_location,
-                        found: element.to_string(),
-                    });
+        let index_identifier = Identifier::new(
+            statement.pattern.location,
+            format!("$index_{}_{}", location.line, location.column),
+        );
+        let mut arrays = Vec::new();
+
+        let (range_start, range_end, index_bitlength, is_index_signed, is_inclusive, item) =
+            match Self::bounds(
+                scope_stack.top(),
+                statement.bounds_expression,
+                &index_identifier,
+                &mut arrays,
+            )? {
+                Bounds::Range {
+                    start,
+                    end,
+                    bitlength,
+                    is_signed,
+                    is_inclusive,
+                } => (start, end, bitlength, is_signed, is_inclusive, None),
+                Bounds::Array { item, size } => {
+                    let end = BigInt::from(size);
+                    let bitlength = IntegerConstant::minimal_bitlength_bigints(
+                        &[&BigInt::zero(), &end],
+                        false,
+                        bounds_expression_location,
+                    )?;
+                    (BigInt::zero(), end, bitlength, false, false, Some(item))
                 }
             };
 
         scope_stack.push(None, ScopeType::Loop);
 
-        let index_location = statement.index_identifier.location;
-        let index_identifier = statement.index_identifier.name.to_owned();
+        let mut declarations = Vec::with_capacity(arrays.len());
+        let mut length_condition: Option<ExpressionTree> = None;
+        for array in arrays.into_iter() {
+            Scope::define_variable(
+                scope_stack.top(),
+                array.identifier.clone(),
+                false,
+                array.r#type.clone(),
+            )?;
+            let mut bindings = vec![Binding::new(array.identifier, false, false, array.r#type)];
+
+            if let Some(length) = array.length {
+                let length_type = Type::integer_unsigned(None, zinc_const::bitlength::INDEX);
+                Scope::define_variable(
+                    scope_stack.top(),
+                    length.clone(),
+                    false,
+                    length_type.clone(),
+                )?;
+
+                let condition = Self::length_condition(&index_identifier, length.clone());
+                length_condition = Some(match length_condition.take() {
+                    Some(previous) => ExpressionTree::new_with_leaves(
+                        location,
+                        ExpressionTreeNode::operator(ExpressionOperator::And),
+                        Some(previous),
+                        Some(condition),
+                    ),
+                    None => condition,
+                });
+
+                bindings.push(Binding::new(length, false, false, length_type));
+            }
+
+            declarations.push(GeneratorDeclarationStatement::new(
+                location,
+                bindings,
+                array.expression,
+            ));
+        }
+        let while_condition = match (length_condition, statement.while_condition) {
+            (Some(length_condition), Some(while_condition)) => {
+                Some(ExpressionTree::new_with_leaves(
+                    while_condition.location,
+                    ExpressionTreeNode::operator(ExpressionOperator::And),
+                    Some(length_condition),
+                    Some(while_condition),
+                ))
+            }
+            (length_condition, while_condition) => while_condition.or(length_condition),
+        };
+
+        let (index_identifier, item) = match (statement.pattern, item) {
+            (
+                BindingPattern {
+                    variant:
+                        BindingPatternVariant::Binding {
+                            identifier,
+                            is_mutable: false,
+                        },
+                    ..
+                },
+                None,
+            ) => (identifier, None),
+            (pattern, item) => {
+                let item = item.unwrap_or_else(|| {
+                    ExpressionTree::new(
+                        index_identifier.location,
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            index_identifier.clone(),
+                        )),
+                    )
+                });
+                (
+                    index_identifier,
+                    Some(LetStatement::new(
+                        pattern.location,
+                        SyntaxBinding::new(pattern.location, pattern, None),
+                        item,
+                    )),
+                )
+            }
+        };
+
+        let index_location = index_identifier.location;
+        let index_name = index_identifier.name.to_owned();
         Scope::define_variable(
             scope_stack.top(),
-            statement.index_identifier,
+            index_identifier,
             false,
             Type::scalar(Some(index_location), is_index_signed, index_bitlength),
         )?;
 
-        let while_condition = if let Some(expression) = statement.while_condition {
+        let item = match item {
+            Some(item) => LetStatementAnalyzer::define(scope_stack.top(), item)?,
+            None => None,
+        };
+
+        let while_condition = if let Some(expression) = while_condition {
             let location = expression.location;
             let (while_result, while_intermediate) =
                 ExpressionAnalyzer::new(scope_stack.top(), TranslationRule::Value)
@@ -103,6 +230,7 @@ impl Analyzer {
 
         let (_element, body) =
             BlockAnalyzer::analyze(scope_stack.top(), statement.block, TranslationRule::Value)?;
+        let has_early_exit = RefCell::borrow(&scope_stack.top()).early_exits() > 0;
 
         scope_stack.pop();
 
@@ -123,14 +251,325 @@ impl Analyzer {
 
         Ok(GeneratorForLoopStatement::new(
             location,
+            declarations,
             range_start,
             iterations_count,
             is_reversed,
-            index_identifier,
+            index_name,
             is_index_signed,
             index_bitlength,
+            item,
             while_condition,
+            has_early_exit,
             body,
         ))
     }
+
+    ///
+    /// Analyzes the loop bounds, which are either a constant range, or an array, or an array
+    /// adapter like `enumerate` or `zip`.
+    ///
+    fn bounds(
+        scope: Rc<RefCell<Scope>>,
+        expression: ExpressionTree,
+        index: &Identifier,
+        arrays: &mut Vec<IteratedArray>,
+    ) -> Result<Bounds, Error> {
+        if Self::is_adapter(scope.clone(), &expression) {
+            let (item, size) = Self::iterable(scope, expression, index, arrays)?;
+            return Ok(Bounds::Array { item, size });
+        }
+
+        let location = expression.location;
+        let (element, intermediate) =
+            ExpressionAnalyzer::new(scope.clone(), TranslationRule::Value).analyze(expression)?;
+        match element {
+            Element::Constant(Constant::RangeInclusive(range)) => Ok(Bounds::Range {
+                start: range.start,
+                end: range.end,
+                bitlength: range.bitlength,
+                is_signed: range.is_signed,
+                is_inclusive: true,
+            }),
+            Element::Constant(Constant::Range(range)) => Ok(Bounds::Range {
+                start: range.start,
+                end: range.end,
+                bitlength: range.bitlength,
+                is_signed: range.is_signed,
+                is_inclusive: false,
+            }),
+            element => {
+                let (item, size) =
+                    Self::array(scope, element, intermediate, location, index, arrays)?;
+                Ok(Bounds::Array { item, size })
+            }
+        }
+    }
+
+    ///
+    /// Analyzes an iterated array expression, which can be wrapped into the array adapters.
+    ///
+    /// Returns the item expression, which is the array element at the loop index, and the
+    /// array size.
+    ///
+    fn iterable(
+        scope: Rc<RefCell<Scope>>,
+        expression: ExpressionTree,
+        index: &Identifier,
+        arrays: &mut Vec<IteratedArray>,
+    ) -> Result<(ExpressionTree, usize), Error> {
+        let location = expression.location;
+
+        if Self::is_adapter(scope.clone(), &expression) {
+            let (callee, arguments) = match (expression.left, expression.right) {
+                (Some(callee), Some(arguments)) => (*callee, *arguments),
+                _ => panic!("{}", zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS),
+            };
+            let arguments = match *arguments.value {
+                ExpressionTreeNode::Operand(ExpressionOperand::List(list)) => list.elements,
+                _ => panic!("{}", zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS),
+            };
+
+            return match *callee.value {
+                ExpressionTreeNode::Operator(ExpressionOperator::Dot) => {
+                    let instance = *callee
+                        .left
+                        .expect(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS);
+                    let (item, size) = Self::iterable(scope, instance, index, arrays)?;
+
+                    let index = ExpressionTree::new(
+                        location,
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(index.clone())),
+                    );
+                    Ok((Self::tuple(location, vec![index, item]), size))
+                }
+                _ => {
+                    let mut items = Vec::with_capacity(arguments.len());
+                    let mut expected = None;
+                    for argument in arguments.into_iter() {
+                        let location = argument.location;
+                        let (item, size) = Self::iterable(scope.clone(), argument, index, arrays)?;
+                        match expected {
+                            Some(expected) if expected != size => {
+                                return Err(Error::ForStatementZipArraySizeMismatch {
+                                    location,
+                                    expected,
+                                    found: size,
+                                });
+                            }
+                            _ => expected = Some(size),
+                        }
+                        items.push(item);
+                    }
+
+                    Ok((
+                        Self::tuple(location, items),
+                        expected.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    ))
+                }
+            };
+        }
+
+        let (element, intermediate) =
+            ExpressionAnalyzer::new(scope.clone(), TranslationRule::Value).analyze(expression)?;
+        Self::array(scope, element, intermediate, location, index, arrays)
+    }
+
+    ///
+    /// Checks whether the analyzed iterated `element` is an array or vector, and stores it to
+    /// hidden variables.
+    ///
+    fn array(
+        scope: Rc<RefCell<Scope>>,
+        element: Element,
+        intermediate: GeneratorExpression,
+        location: Location,
+        index: &Identifier,
+        arrays: &mut Vec<IteratedArray>,
+    ) -> Result<(ExpressionTree, usize), Error> {
+        let r#type = match element {
+            Element::Constant(_) | Element::Value(_) => Some(Type::from_element(&element, scope)?),
+            _ => None,
+        };
+        let (r#type, size, has_length) = match r#type {
+            Some(Type::Array(array)) => {
+                let size = array.size;
+                (Type::Array(array), size, false)
+            }
+            Some(Type::Structure(structure))
+                if structure.type_id == IntrinsicTypeId::StdCollectionsVec as usize =>
+            {
+                match structure.fields.first() {
+                    Some((_name, Type::Array(array))) => {
+                        (Type::Array(array.to_owned()), array.size, true)
+                    }
+                    _ => panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+                }
+            }
+            _ => {
+                return Err(Error::ForStatementBoundsExpectedConstantRangeOrArray {
+                    location,
+                    found: element.to_string(),
+                })
+            }
+        };
+
+        let identifier =
+            Identifier::new(location, format!("{}_array_{}", index.name, arrays.len()));
+        let item = ExpressionTree::new_with_leaves(
+            location,
+            ExpressionTreeNode::operator(ExpressionOperator::Index),
+            Some(ExpressionTree::new(
+                location,
+                ExpressionTreeNode::operand(ExpressionOperand::Identifier(identifier.clone())),
+            )),
+            Some(ExpressionTree::new(
+                location,
+                ExpressionTreeNode::operand(ExpressionOperand::Identifier(index.clone())),
+            )),
+        );
+
+        let length = if has_length {
+            Some(Identifier::new(
+                location,
+                format!("{}_length_{}", index.name, arrays.len()),
+            ))
+        } else {
+            None
+        };
+
+        arrays.push(IteratedArray {
+            identifier,
+            r#type,
+            length,
+            expression: intermediate,
+        });
+
+        Ok((item, size))
+    }
+
+    ///
+    /// Checks whether the expression is an array adapter call, that is, `array.enumerate()`
+    /// or `zip(a, b)`, where `zip` is not an item declared by the user.
+    ///
+    fn is_adapter(scope: Rc<RefCell<Scope>>, expression: &ExpressionTree) -> bool {
+        let (callee, arguments) = match (
+            expression.value.as_ref(),
+            expression.left.as_ref(),
+            expression.right.as_ref(),
+        ) {
+            (
+                ExpressionTreeNode::Operator(ExpressionOperator::Call),
+                Some(callee),
+                Some(arguments),
+            ) => match arguments.value.as_ref() {
+                ExpressionTreeNode::Operand(ExpressionOperand::List(list)) => (callee, list),
+                _ => return false,
+            },
+            _ => return false,
+        };
+
+        match (
+            callee.value.as_ref(),
+            callee.right.as_ref().map(|right| right.value.as_ref()),
+        ) {
+            (
+                ExpressionTreeNode::Operator(ExpressionOperator::Dot),
+                Some(ExpressionTreeNode::Operand(ExpressionOperand::Identifier(identifier))),
+            ) => identifier.name == Self::ADAPTER_ENUMERATE && arguments.is_empty(),
+            (ExpressionTreeNode::Operand(ExpressionOperand::Identifier(identifier)), None) => {
+                identifier.name == Self::ADAPTER_ZIP
+                    && arguments.len() == 2
+                    && RefCell::borrow(&scope)
+                        .resolve_item(identifier, true)
+                        .is_err()
+            }
+            _ => false,
+        }
+    }
+
+    ///
+    /// Creates the vector iteration condition `index as u64 < length`.
+    ///
+    fn length_condition(index: &Identifier, length: Identifier) -> ExpressionTree {
+        let location = length.location;
+
+        let index = ExpressionTree::new_with_leaves(
+            location,
+            ExpressionTreeNode::operator(ExpressionOperator::Casting),
+            Some(ExpressionTree::new(
+                location,
+                ExpressionTreeNode::operand(ExpressionOperand::Identifier(index.to_owned())),
+            )),
+            Some(ExpressionTree::new(
+                location,
+                ExpressionTreeNode::operand(ExpressionOperand::Type(SyntaxType::new(
+                    location,
+                    SyntaxTypeVariant::integer_unsigned(zinc_const::bitlength::INDEX),
+                ))),
+            )),
+        );
+
+        ExpressionTree::new_with_leaves(
+            location,
+            ExpressionTreeNode::operator(ExpressionOperator::Lesser),
+            Some(index),
+            Some(ExpressionTree::new(
+                location,
+                ExpressionTreeNode::operand(ExpressionOperand::Identifier(length)),
+            )),
+        )
+    }
+
+    ///
+    /// Creates a tuple expression from the `elements`.
+    ///
+    fn tuple(location: Location, elements: Vec<ExpressionTree>) -> ExpressionTree {
+        ExpressionTree::new(
+            location,
+            ExpressionTreeNode::operand(ExpressionOperand::Tuple(TupleExpression::new(
+                location, elements,
+            ))),
+        )
+    }
+}
+
+///
+/// The analyzed loop bounds.
+///
+enum Bounds {
+    /// The constant range, e.g. `0..10`.
+    Range {
+        /// The range start.
+        start: BigInt,
+        /// The range end.
+        end: BigInt,
+        /// The range bound values bitlength.
+        bitlength: usize,
+        /// Whether the range bound values are signed.
+        is_signed: bool,
+        /// Whether the range is inclusive.
+        is_inclusive: bool,
+    },
+    /// The array or array adapter.
+    Array {
+        /// The item expression, which is evaluated at the beginning of each iteration.
+        item: ExpressionTree,
+        /// The array size, which is the number of iterations.
+        size: usize,
+    },
+}
+
+///
+/// The iterated array, which is stored to a hidden variable before the loop.
+///
+struct IteratedArray {
+    /// The hidden variable identifier.
+    identifier: Identifier,
+    /// The array type, which is the data array type for vectors.
+    r#type: Type,
+    /// The hidden vector length variable identifier, if the iterated value is a vector.
+    length: Option<Identifier>,
+    /// The array expression IR.
+    expression: GeneratorExpression,
 }
@@ -8,6 +8,7 @@ use crate::error::Error;
 use crate::semantic::element::constant::boolean::Boolean as BooleanConstant;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::r#type::Type;
+use crate::semantic::element::value::Value;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error as SemanticError;
 
@@ -116,7 +117,156 @@ fn main() {
 }
 
 #[test]
-fn error_bounds_expected_constant_range_expression() {
+fn ok_range_binding_mutable() {
+    let input = r#"
+fn main() {
+    for mut i in 0..10 {
+        i *= 2;
+        dbg!("{}", i);
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_range_wildcard() {
+    let input = r#"
+fn main() {
+    let mut sum = 0;
+    for _ in 0..10 {
+        sum += 1;
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_array() {
+    let input = r#"
+fn main() {
+    let array = [1, 2, 3, 4];
+    let mut sum = 0;
+    for item in array {
+        sum += item;
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_array_enumerate() {
+    let input = r#"
+fn main() {
+    let array = [1, 2, 3, 4];
+    let mut sum = 0;
+    for (index, item) in array.enumerate() while index < 3 {
+        sum += index * item;
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_array_zip() {
+    let input = r#"
+fn main() {
+    let a = [1, 2, 3, 4];
+    let b = [(true, 5), (false, 6), (true, 7), (false, 8)];
+    let mut sum = 0;
+    for (x, (is_added, y)) in zip(a, b) {
+        if is_added {
+            sum += x * y;
+        }
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_array_zip_enumerate_nested() {
+    let input = r#"
+fn main() {
+    let a = [[1, 2], [3, 4]];
+    let b = [5, 6];
+    let mut sum = 0;
+    for (i, (row, y)) in zip(a, b).enumerate() {
+        for (j, x) in row.enumerate() {
+            if i == j {
+                continue;
+            }
+            sum += x * y;
+        }
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_bounds_expected_array() {
+    let input = r#"
+fn main() {
+    let value = 42;
+    for item in value.enumerate() {}
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ForStatementBoundsExpectedConstantRangeOrArray {
+            location: Location::test(4, 17),
+            found: Element::Value(
+                Value::try_from_type(
+                    &Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+                    false,
+                    None,
+                )
+                .expect(zinc_const::panic::TEST_DATA_VALID),
+            )
+            .to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_zip_array_size_mismatch() {
+    let input = r#"
+fn main() {
+    let a = [1, 2, 3, 4];
+    let b = [5, 6, 7];
+    for (x, y) in zip(a, b) {}
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ForStatementZipArraySizeMismatch {
+            location: Location::test(5, 26),
+            expected: 4,
+            found: 3,
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_bounds_expected_constant_range_or_array() {
     let input = r#"
 fn main() {
     let mut sum = 0;
@@ -127,7 +277,7 @@ fn main() {
 "#;
 
     let expected = Err(Error::Semantic(
-        SemanticError::ForStatementBoundsExpectedConstantRangeExpression {
+        SemanticError::ForStatementBoundsExpectedConstantRangeOrArray {
             location: Location::test(4, 14),
             found: Element::Constant(Constant::Boolean(BooleanConstant::new(
                 Location::test(4, 14),
@@ -164,3 +314,79 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_break() {
+    let input = r#"
+fn main() {
+    let mut sum = 0;
+    for i in 0..10 {
+        if i == 5 {
+            break;
+        }
+        sum += i;
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_continue() {
+    let input = r#"
+fn main() {
+    let mut sum = 0;
+    for i in 0..10 {
+        if i % 2 == 0 {
+            continue;
+        }
+        sum += i;
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_break_beyond_loop() {
+    let input = r#"
+fn main() {
+    let mut sum = 0;
+    if sum == 0 {
+        break;
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::BreakStatementBeyondLoop {
+        location: Location::test(5, 9),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_continue_beyond_loop() {
+    let input = r#"
+fn main() {
+    let mut sum = 0;
+    if sum == 0 {
+        continue;
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ContinueStatementBeyondLoop {
+            location: Location::test(5, 9),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
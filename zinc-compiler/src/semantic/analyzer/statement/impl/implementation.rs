@@ -0,0 +1,49 @@
+//!
+//! The `impl` statement semantic analyzer implementation.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Location;
+use zinc_syntax::Identifier;
+
+use crate::semantic::scope::Scope;
+
+///
+/// The declared type implementation, which must be defined during the module definition.
+///
+#[derive(Debug, Clone)]
+pub struct Implementation {
+    /// The `impl` statement location.
+    pub location: Location,
+    /// The scope where the `impl` statement is declared, which is used to resolve the trait.
+    pub scope: Rc<RefCell<Scope>>,
+    /// The implemented type scope, where the implementation items are declared.
+    pub type_scope: Rc<RefCell<Scope>>,
+    /// The implemented trait identifier, if the statement is `impl {trait} for {type}`.
+    pub r#trait: Option<Identifier>,
+    /// The identifiers of the methods declared by the `impl` statement.
+    pub methods: Vec<Identifier>,
+}
+
+impl Implementation {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        location: Location,
+        scope: Rc<RefCell<Scope>>,
+        type_scope: Rc<RefCell<Scope>>,
+        r#trait: Option<Identifier>,
+        methods: Vec<Identifier>,
+    ) -> Self {
+        Self {
+            location,
+            scope,
+            type_scope,
+            r#trait,
+            methods,
+        }
+    }
+}
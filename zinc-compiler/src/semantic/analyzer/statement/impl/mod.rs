@@ -5,15 +5,23 @@
 #[cfg(test)]
 mod tests;
 
+pub mod implementation;
+
 use std::cell::RefCell;
 use std::rc::Rc;
 
 use zinc_lexical::Keyword;
+use zinc_syntax::Identifier;
 use zinc_syntax::ImplStatement;
 use zinc_syntax::ImplementationLocalStatement;
+use zinc_syntax::Type as SyntaxType;
 
+use crate::semantic::binding::Binding;
+use crate::semantic::element::r#type::function::Function;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error;
+use crate::semantic::generics::implementation::Implementation as GenericImplementation;
+use crate::semantic::generics::Template;
 use crate::semantic::scope::item::r#type::state::State as ScopeTypeItemState;
 use crate::semantic::scope::item::r#type::statement::Statement as TypeStatementVariant;
 use crate::semantic::scope::item::r#type::statement::Statement as ScopeTypeItemStatement;
@@ -21,6 +29,8 @@ use crate::semantic::scope::item::r#type::Type as ScopeTypeItem;
 use crate::semantic::scope::item::Item as ScopeItem;
 use crate::semantic::scope::Scope;
 
+use self::implementation::Implementation;
+
 ///
 /// The `impl` statement semantic analyzer.
 ///
@@ -32,15 +42,43 @@ impl Analyzer {
     ///
     /// Also declares the `Self` alias for the type being implemented.
     ///
+    /// The methods of all implementations of a type share the type namespace, so a trait method
+    /// conflicting with a method of an `implementations` item of the same type is reported.
+    ///
+    /// The generic structure implementations are passed to the structure template, so `None` is
+    /// returned, as there is nothing to define during the module definition.
+    ///
     pub fn declare(
         scope: Rc<RefCell<Scope>>,
         statement: ImplStatement,
-    ) -> Result<Rc<RefCell<Scope>>, Error> {
+        implementations: &[Implementation],
+    ) -> Result<Option<Implementation>, Error> {
+        let location = statement.location;
         let identifier_location = statement.identifier.location;
 
         let item = scope.borrow().resolve_item(&statement.identifier, true)?;
 
-        let scope = match *item.borrow() {
+        if let Some(template) = Self::template(&item)? {
+            return Self::declare_generic(scope, statement, template).map(|()| None);
+        }
+        if let Some(ref type_arguments) = statement.type_arguments {
+            return Err(Error::TypeUnexpectedGenerics {
+                location: type_arguments
+                    .first()
+                    .map(|r#type| r#type.location)
+                    .unwrap_or(identifier_location),
+                r#type: statement.identifier.name,
+            });
+        }
+        if let Some(parameter) = statement.generics.first() {
+            return Err(Error::TypeGenericParameterUnused {
+                location: parameter.location,
+                r#type: format!("impl {}", statement.identifier.name),
+                name: parameter.identifier.name.to_owned(),
+            });
+        }
+
+        let type_scope = match *item.borrow() {
             ScopeItem::Type(ScopeTypeItem { ref state, .. }) => match state.borrow().as_ref() {
                 Some(ScopeTypeItemState::Declared {
                     ref inner,
@@ -81,20 +119,358 @@ impl Analyzer {
             }
         };
 
-        Scope::insert_item(scope.clone(), Keyword::SelfUppercase.to_string(), item);
+        Scope::insert_item(type_scope.clone(), Keyword::SelfUppercase.to_string(), item);
 
+        let mut methods = Vec::with_capacity(statement.statements.len());
         for hoisted_statement in statement.statements.into_iter() {
             match hoisted_statement {
-                ImplementationLocalStatement::Const(statement) => {
-                    Scope::declare_constant(scope.clone(), statement)?;
+                ImplementationLocalStatement::Const(constant) => {
+                    if let Some(ref r#trait) = statement.r#trait {
+                        return Err(Error::ImplStatementTraitItemUnknown {
+                            location: constant.location,
+                            r#trait: r#trait.name.to_owned(),
+                            name: constant.identifier.name,
+                        });
+                    }
+
+                    Scope::declare_constant(type_scope.clone(), constant)?;
                 }
-                ImplementationLocalStatement::Fn(statement) => {
-                    Scope::declare_type(scope.clone(), TypeStatementVariant::Fn(statement))?;
+                ImplementationLocalStatement::Fn(method) => {
+                    Self::check_method_conflict(
+                        &type_scope,
+                        statement.r#trait.as_ref(),
+                        &method.identifier,
+                        implementations,
+                    )?;
+
+                    methods.push(method.identifier.clone());
+                    Scope::declare_type(type_scope.clone(), TypeStatementVariant::Fn(method))?;
                 }
                 ImplementationLocalStatement::Empty(_location) => {}
             }
         }
 
-        Ok(scope)
+        Ok(Some(Implementation::new(
+            location,
+            scope,
+            type_scope,
+            statement.r#trait,
+            methods,
+        )))
+    }
+
+    ///
+    /// Passes the generic structure implementation to the structure `template`.
+    ///
+    /// The implementation generic parameters must be used in the implemented type arguments,
+    /// e.g. `impl<T> Pair<T, u8>`, since they are inferred from the structure instances.
+    ///
+    fn declare_generic(
+        scope: Rc<RefCell<Scope>>,
+        statement: ImplStatement,
+        template: Rc<Template>,
+    ) -> Result<(), Error> {
+        if let Some(r#trait) = statement.r#trait {
+            return Err(Error::ImplStatementGenericTraitUnavailable {
+                location: statement.identifier.location,
+                r#trait: r#trait.name,
+                found: statement.identifier.name,
+            });
+        }
+
+        if let Some(parameter) = statement
+            .generics
+            .iter()
+            .find(|parameter| parameter.is_constant())
+        {
+            return Err(Error::TypeGenericConstantUnavailable {
+                location: parameter.location,
+                r#type: statement.identifier.name,
+                name: parameter.identifier.name.to_owned(),
+            });
+        }
+
+        let arguments = match statement.type_arguments {
+            Some(arguments) => arguments,
+            None => {
+                return Err(Error::TypeExpectedGenerics {
+                    location: statement.identifier.location,
+                    r#type: statement.identifier.name,
+                    expected: template.parameters.len(),
+                })
+            }
+        };
+        if arguments.len() != template.parameters.len() {
+            return Err(Error::TypeInvalidGenericsNumber {
+                location: statement.identifier.location,
+                r#type: statement.identifier.name,
+                expected: template.parameters.len(),
+                found: arguments.len(),
+            });
+        }
+
+        let identifier = format!("impl {}", statement.identifier.name);
+        let parameters =
+            Template::parameters(identifier.as_str(), statement.generics, scope.clone())?;
+        Template::check_unused(
+            identifier.as_str(),
+            parameters.as_slice(),
+            arguments.iter().collect::<Vec<&SyntaxType>>().as_slice(),
+        )?;
+
+        template.implement(GenericImplementation::new(
+            statement.location,
+            parameters,
+            arguments,
+            statement.statements,
+            scope,
+        ))
+    }
+
+    ///
+    /// Returns the template of the generic structure `item`, defining the structure if it has
+    /// been only declared.
+    ///
+    fn template(item: &Rc<RefCell<ScopeItem>>) -> Result<Option<Rc<Template>>, Error> {
+        let item = RefCell::borrow(item);
+        let r#type = match *item {
+            ScopeItem::Type(ref r#type) => r#type,
+            _ => return Ok(None),
+        };
+
+        let is_generic = match r#type.state.borrow().as_ref() {
+            Some(ScopeTypeItemState::Declared {
+                inner: ScopeTypeItemStatement::Struct(ref inner),
+                ..
+            }) => !inner.generics.is_empty(),
+            Some(ScopeTypeItemState::Defined {
+                inner: Type::Structure(ref inner),
+                ..
+            }) => inner.template.is_some(),
+            _ => false,
+        };
+        if !is_generic {
+            return Ok(None);
+        }
+
+        match r#type.define()? {
+            Type::Structure(structure) => Ok(structure.template),
+            _ => Ok(None),
+        }
+    }
+
+    ///
+    /// Defines the implementation items.
+    ///
+    /// If the implementation is of a trait, checks whether the implemented methods match the
+    /// trait method signatures.
+    ///
+    pub fn define(implementation: Implementation) -> Result<(), Error> {
+        RefCell::borrow(&implementation.type_scope).define()?;
+
+        let trait_identifier = match implementation.r#trait {
+            Some(identifier) => identifier,
+            None => return Ok(()),
+        };
+
+        let trait_item =
+            RefCell::borrow(&implementation.scope).resolve_item(&trait_identifier, true)?;
+        let r#trait = match *RefCell::borrow(&trait_item) {
+            ScopeItem::Trait(ref inner) => inner.to_owned(),
+            ref item => {
+                return Err(Error::ImplStatementExpectedTrait {
+                    location: trait_identifier.location,
+                    found: item.to_string(),
+                })
+            }
+        };
+        r#trait.define()?;
+
+        for method in implementation.methods.iter() {
+            if r#trait.method(method.name.as_str()).is_none() {
+                return Err(Error::ImplStatementTraitItemUnknown {
+                    location: method.location,
+                    r#trait: r#trait.identifier,
+                    name: method.name.to_owned(),
+                });
+            }
+        }
+
+        let self_item = RefCell::borrow(&implementation.type_scope).resolve_item(
+            &Identifier::new(implementation.location, Keyword::SelfUppercase.to_string()),
+            false,
+        )?;
+
+        for trait_method in r#trait.methods.iter() {
+            let method = match implementation
+                .methods
+                .iter()
+                .find(|method| method.name == trait_method.identifier.name)
+            {
+                Some(method) => method,
+                None => {
+                    return Err(Error::ImplStatementTraitMethodMissing {
+                        location: implementation.location,
+                        r#trait: r#trait.identifier.to_owned(),
+                        method: trait_method.identifier.name.to_owned(),
+                        reference: trait_method.location,
+                    })
+                }
+            };
+
+            let (expected_bindings, expected_return_type) =
+                r#trait.resolve_method(trait_method, self_item.clone())?;
+            let expected = (
+                trait_method.is_constant,
+                expected_bindings,
+                expected_return_type,
+            );
+
+            let item = RefCell::borrow(&implementation.type_scope).resolve_item(method, false)?;
+            let found_type = match *RefCell::borrow(&item) {
+                ScopeItem::Type(ref r#type) => r#type.define()?,
+                ref item => {
+                    return Err(Error::ImplStatementTraitItemUnknown {
+                        location: method.location,
+                        r#trait: r#trait.identifier.to_owned(),
+                        name: item.to_string(),
+                    })
+                }
+            };
+            let found = match found_type {
+                Type::Function(Function::Runtime(ref function)) => Some((
+                    false,
+                    function.bindings.to_owned(),
+                    *function.return_type.to_owned(),
+                )),
+                Type::Function(Function::Constant(ref function)) => Some((
+                    true,
+                    function.bindings.to_owned(),
+                    *function.return_type.to_owned(),
+                )),
+                _ => None,
+            };
+
+            let is_matching = match found {
+                Some(ref found) => Self::is_signature_matching(&expected, found),
+                None => false,
+            };
+            if !is_matching {
+                return Err(Error::ImplStatementTraitMethodSignatureMismatch {
+                    location: method.location,
+                    r#trait: r#trait.identifier.to_owned(),
+                    method: method.name.to_owned(),
+                    expected: Self::signature_to_string(method.name.as_str(), &expected),
+                    found: match found {
+                        Some(ref found) => Self::signature_to_string(method.name.as_str(), found),
+                        None => found_type.to_string(),
+                    },
+                    reference: trait_method.location,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Checks whether the method conflicts with a method of another implementation of the type.
+    ///
+    /// Conflicts between inherent methods are left to the scope redeclaration check.
+    ///
+    fn check_method_conflict(
+        type_scope: &Rc<RefCell<Scope>>,
+        r#trait: Option<&Identifier>,
+        method: &Identifier,
+        implementations: &[Implementation],
+    ) -> Result<(), Error> {
+        for implementation in implementations.iter() {
+            if !Rc::ptr_eq(&implementation.type_scope, type_scope) {
+                continue;
+            }
+
+            if r#trait.is_none() && implementation.r#trait.is_none() {
+                continue;
+            }
+
+            if let Some(other) = implementation
+                .methods
+                .iter()
+                .find(|other| other.name == method.name)
+            {
+                return Err(Error::ImplStatementTraitMethodConflict {
+                    location: method.location,
+                    method: method.name.to_owned(),
+                    r#trait: r#trait.map(|r#trait| r#trait.name.to_owned()),
+                    other: implementation
+                        .r#trait
+                        .as_ref()
+                        .map(|r#trait| r#trait.name.to_owned()),
+                    reference: other.location,
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Checks whether the implemented method signature matches the trait one.
+    ///
+    /// The argument names do not matter, except for the `self` instance argument, which must be
+    /// present in both signatures with the same mutability.
+    ///
+    fn is_signature_matching(
+        expected: &(bool, Vec<Binding>, Type),
+        found: &(bool, Vec<Binding>, Type),
+    ) -> bool {
+        let (expected_is_constant, expected_bindings, expected_return_type) = expected;
+        let (found_is_constant, found_bindings, found_return_type) = found;
+
+        if expected_is_constant != found_is_constant
+            || expected_bindings.len() != found_bindings.len()
+            || expected_return_type != found_return_type
+        {
+            return false;
+        }
+
+        expected_bindings
+            .iter()
+            .zip(found_bindings.iter())
+            .all(|(expected, found)| {
+                let expected_is_instance = expected.identifier.is_self_lowercase();
+                let found_is_instance = found.identifier.is_self_lowercase();
+
+                expected.r#type == found.r#type
+                    && expected_is_instance == found_is_instance
+                    && (!expected_is_instance || expected.is_mutable == found.is_mutable)
+            })
+    }
+
+    ///
+    /// Converts the method signature into a string to be shown in the error messages.
+    ///
+    fn signature_to_string(identifier: &str, signature: &(bool, Vec<Binding>, Type)) -> String {
+        let (is_constant, bindings, return_type) = signature;
+
+        format!(
+            "{}fn {}({}) -> {}",
+            if *is_constant { "const " } else { "" },
+            identifier,
+            bindings
+                .iter()
+                .map(|binding| if binding.identifier.is_self_lowercase() {
+                    format!(
+                        "{}{}",
+                        if binding.is_mutable { "mut " } else { "" },
+                        binding.identifier.name
+                    )
+                } else {
+                    binding.r#type.to_string()
+                })
+                .collect::<Vec<String>>()
+                .join(", "),
+            return_type,
+        )
     }
 }
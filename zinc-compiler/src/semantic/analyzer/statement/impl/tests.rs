@@ -74,3 +74,551 @@ fn main() {}
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_trait() {
+    let input = r#"
+trait Hashable {
+    fn hash(self) -> u8;
+
+    fn default() -> Self;
+}
+
+struct Data {
+    value: u8,
+}
+
+impl Hashable for Data {
+    fn hash(self) -> u8 {
+        self.value * 2
+    }
+
+    fn default() -> Self {
+        Self { value: 42 }
+    }
+}
+
+fn main() -> u8 {
+    Data::default().hash()
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_expected_trait() {
+    let input = r#"
+struct Hashable {
+    value: u8,
+}
+
+struct Data {
+    value: u8,
+}
+
+impl Hashable for Data {
+    fn hash(self) -> u8 {
+        self.value
+    }
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ImplStatementExpectedTrait {
+        location: Location::test(10, 6),
+        found: "type Hashable".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_trait_method_missing() {
+    let input = r#"
+trait Hashable {
+    fn hash(self) -> u8;
+}
+
+struct Data {
+    value: u8,
+}
+
+impl Hashable for Data {}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ImplStatementTraitMethodMissing {
+            location: Location::test(10, 1),
+            r#trait: "Hashable".to_owned(),
+            method: "hash".to_owned(),
+            reference: Location::test(3, 5),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_trait_item_unknown() {
+    let input = r#"
+trait Hashable {
+    fn hash(self) -> u8;
+}
+
+struct Data {
+    value: u8,
+}
+
+impl Hashable for Data {
+    fn hash(self) -> u8 {
+        self.value
+    }
+
+    fn serialize(self) -> u8 {
+        self.value
+    }
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ImplStatementTraitItemUnknown {
+            location: Location::test(15, 8),
+            r#trait: "Hashable".to_owned(),
+            name: "serialize".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_trait_method_signature_mismatch() {
+    let input = r#"
+trait Hashable {
+    fn hash(self) -> u8;
+}
+
+struct Data {
+    value: u8,
+}
+
+impl Hashable for Data {
+    fn hash(self) -> field {
+        self.value as field
+    }
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ImplStatementTraitMethodSignatureMismatch {
+            location: Location::test(11, 8),
+            r#trait: "Hashable".to_owned(),
+            method: "hash".to_owned(),
+            expected: "fn hash(self) -> u8".to_owned(),
+            found: "fn hash(self) -> field".to_owned(),
+            reference: Location::test(3, 5),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_trait_used_as_expression() {
+    let input = r#"
+trait Hashable {
+    fn hash(self) -> u8;
+}
+
+fn main() {
+    let value = Hashable;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::TraitUsedAsExpression {
+        location: Location::test(7, 17),
+        name: "Hashable".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_generic() {
+    let input = r#"
+struct Pair<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> Pair<A, B> {
+    const SIZE: u8 = 2;
+
+    fn new(first: A, second: B) -> Self {
+        Self { first: first, second: second }
+    }
+
+    fn swap(self) -> Pair<B, A> {
+        Pair { first: self.second, second: self.first }
+    }
+}
+
+fn main() -> bool {
+    let pair = Pair::<u8, bool>::new(42, true);
+    let other = Pair::<bool, u64>::new(false, 25 as u64).swap();
+    pair.swap().first && other.second && Pair::<u8, u8>::SIZE == 2
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_generic_concrete() {
+    let input = r#"
+struct Pair<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<T> Pair<T, T> {
+    fn same(self) -> T {
+        self.first
+    }
+}
+
+impl Pair<u8, bool> {
+    fn value(self) -> u8 {
+        if self.second { self.first } else { 0 }
+    }
+}
+
+fn main() -> u8 {
+    let pair = Pair { first: 42, second: true };
+    let same = Pair { first: 1, second: 2 };
+    pair.value() + same.same()
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_generic_method_not_implemented() {
+    let input = r#"
+struct Pair<A, B> {
+    first: A,
+    second: B,
+}
+
+impl Pair<u8, bool> {
+    fn value(self) -> u8 {
+        self.first
+    }
+}
+
+fn main() -> u8 {
+    let pair = Pair { first: 42, second: 25 };
+    pair.value()
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::StructureFieldDoesNotExist {
+        location: Location::test(15, 10),
+        r#type: "Pair<u8, u8>".to_owned(),
+        field_name: "value".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_generic_method_redeclared() {
+    let input = r#"
+struct Pair<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> Pair<A, B> {
+    fn value(self) -> A {
+        self.first
+    }
+}
+
+impl Pair<u8, bool> {
+    fn value(self) -> u8 {
+        self.first
+    }
+}
+
+fn main() -> u8 {
+    let pair = Pair { first: 42, second: true };
+    pair.value()
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ScopeItemRedeclared {
+        location: Location::test(14, 8),
+        name: "value".to_owned(),
+        reference: Some(Location::test(8, 5)),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_generic_path_without_arguments() {
+    let input = r#"
+struct Pair<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B> Pair<A, B> {
+    fn new(first: A, second: B) -> Self {
+        Self { first: first, second: second }
+    }
+}
+
+fn main() {
+    let pair = Pair::new(42, true);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::TypeExpectedGenerics {
+        location: Location::test(14, 16),
+        r#type: "Pair".to_owned(),
+        expected: 2,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_generic_type_without_arguments() {
+    let input = r#"
+struct Pair<A, B> {
+    first: A,
+    second: B,
+}
+
+impl Pair {
+    fn first(self) -> u8 {
+        0
+    }
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::TypeExpectedGenerics {
+        location: Location::test(7, 6),
+        r#type: "Pair".to_owned(),
+        expected: 2,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_generic_parameter_unused() {
+    let input = r#"
+struct Pair<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<A, B, C> Pair<A, B> {}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::TypeGenericParameterUnused {
+        location: Location::test(7, 12),
+        r#type: "impl Pair".to_owned(),
+        name: "C".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_generic_trait() {
+    let input = r#"
+trait Hashable {
+    fn hash(self) -> u8;
+}
+
+struct Pair<A, B> {
+    first: A,
+    second: B,
+}
+
+impl Hashable for Pair<u8, u8> {
+    fn hash(self) -> u8 {
+        self.first
+    }
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ImplStatementGenericTraitUnavailable {
+            location: Location::test(11, 19),
+            r#trait: "Hashable".to_owned(),
+            found: "Pair".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_trait_method_conflict() {
+    let input = r#"
+trait Hashable {
+    fn hash(self) -> u8;
+}
+
+trait Digestible {
+    fn hash(self) -> u8;
+}
+
+struct Data {
+    value: u8,
+}
+
+impl Hashable for Data {
+    fn hash(self) -> u8 {
+        self.value
+    }
+}
+
+impl Digestible for Data {
+    fn hash(self) -> u8 {
+        self.value
+    }
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ImplStatementTraitMethodConflict {
+            location: Location::test(21, 8),
+            method: "hash".to_owned(),
+            r#trait: Some("Digestible".to_owned()),
+            other: Some("Hashable".to_owned()),
+            reference: Location::test(15, 8),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_trait_method_conflict_inherent() {
+    let input = r#"
+trait Hashable {
+    fn hash(self) -> u8;
+}
+
+struct Data {
+    value: u8,
+}
+
+impl Data {
+    fn hash(self) -> u8 {
+        self.value
+    }
+}
+
+impl Hashable for Data {
+    fn hash(self) -> u8 {
+        self.value
+    }
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ImplStatementTraitMethodConflict {
+            location: Location::test(17, 8),
+            method: "hash".to_owned(),
+            r#trait: Some("Hashable".to_owned()),
+            other: None,
+            reference: Location::test(11, 8),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_trait_used_as_namespace() {
+    let input = r#"
+trait Hashable {
+    fn hash(self) -> u8;
+}
+
+struct Data {
+    value: u8,
+}
+
+impl Hashable for Data {
+    fn hash(self) -> u8 {
+        self.value
+    }
+}
+
+fn main() -> u8 {
+    Hashable::hash(Data { value: 42 })
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::TraitUsedAsNamespace {
+        location: Location::test(17, 5),
+        name: "Hashable".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
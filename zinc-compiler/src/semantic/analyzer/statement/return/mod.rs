@@ -0,0 +1,79 @@
+//!
+//! The `return` statement semantic analyzer.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_syntax::ReturnStatement;
+
+use crate::generator::statement::r#return::Statement as GeneratorReturnStatement;
+use crate::semantic::analyzer::expression::Analyzer as ExpressionAnalyzer;
+use crate::semantic::analyzer::rule::Rule as TranslationRule;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error;
+use crate::semantic::scope::r#type::Type as ScopeType;
+use crate::semantic::scope::Scope;
+
+///
+/// The `return` statement semantic analyzer.
+///
+pub struct Analyzer {}
+
+impl Analyzer {
+    ///
+    /// Defines an early return and returns its IR for the next compiler phase.
+    ///
+    pub fn define(
+        scope: Rc<RefCell<Scope>>,
+        statement: ReturnStatement,
+    ) -> Result<GeneratorReturnStatement, Error> {
+        let location = statement.location;
+
+        let (function, expected_type) = match Scope::define_early_exit(scope.clone(), |r#type| {
+            matches!(r#type, ScopeType::Function { .. })
+        }) {
+            Some(ScopeType::Function {
+                identifier,
+                return_type: Some(return_type),
+            }) => (identifier, return_type),
+            Some(ScopeType::Function {
+                identifier,
+                return_type: None,
+            }) => {
+                return Err(Error::ReturnStatementInConstantFunction {
+                    location,
+                    function: identifier,
+                })
+            }
+            _ => return Err(Error::ReturnStatementBeyondFunction { location }),
+        };
+
+        let (result_type, expression, result_location) = match statement.expression {
+            Some(expression) => {
+                let result_location = expression.location;
+                let (element, expression) =
+                    ExpressionAnalyzer::new(scope.clone(), TranslationRule::Value)
+                        .analyze(expression)?;
+                let result_type = Type::from_element(&element, scope)?;
+                (result_type, Some(expression), result_location)
+            }
+            None => (Type::unit(None), None, location),
+        };
+
+        if expected_type != result_type {
+            return Err(Error::FunctionReturnType {
+                location: result_location,
+                function,
+                expected: expected_type.to_string(),
+                found: result_type.to_string(),
+                reference: expected_type.location().unwrap_or(location),
+            });
+        }
+
+        Ok(GeneratorReturnStatement::new(location, expression))
+    }
+}
@@ -0,0 +1,214 @@
+//!
+//! The `return` statement tests.
+//!
+
+use zinc_lexical::Location;
+
+use crate::error::Error;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error as SemanticError;
+
+#[test]
+fn ok_value() {
+    let input = r#"
+fn another(value: u8) -> u8 {
+    if value > 10 {
+        return 10;
+    }
+
+    value
+}
+
+fn main() {
+    let value = another(42);
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_unit() {
+    let input = r#"
+fn another(value: u8) {
+    if value > 10 {
+        return;
+    }
+
+    dbg!("{}", value);
+}
+
+fn main() {
+    another(42);
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_terminating() {
+    let input = r#"
+fn another(value: u8) -> u8 {
+    let result = value * 2;
+    return result;
+}
+
+fn main() {
+    let value = another(42);
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_loop() {
+    let input = r#"
+fn another(array: [u8; 4]) -> u8 {
+    for i in 0..4 {
+        if array[i] == 42 {
+            return i;
+        }
+    }
+
+    0
+}
+
+fn main() {
+    let value = another([1, 2, 42, 4]);
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_diverging_main_branch() {
+    let input = r#"
+fn another(condition: bool, value: u8) -> u8 {
+    let result = if condition { return 7; } else { value };
+    result * 2
+}
+
+fn main() {
+    let value = another(true, 42);
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_diverging_else_branch() {
+    let input = r#"
+fn another(condition: bool, value: u8) -> u8 {
+    let result = if condition { value } else { return 7; };
+    result * 2
+}
+
+fn main() {
+    let value = another(true, 42);
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_diverging_match_branch() {
+    let input = r#"
+fn another(value: u8) -> bool {
+    let result = match value {
+        0 => { return false; },
+        1 => true,
+        _ => { return true; },
+    };
+    !result
+}
+
+fn main() {
+    let value = another(42);
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_diverging_loop_branch() {
+    let input = r#"
+fn another(array: [u8; 4]) -> u8 {
+    let mut sum = 0;
+    for i in 0..4 {
+        let value = if array[i] == 0 { continue; } else { array[i] };
+        sum += value;
+    }
+
+    sum
+}
+
+fn main() {
+    let value = another([1, 2, 0, 4]);
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_type_mismatch() {
+    let input = r#"
+fn another(value: u8) -> bool {
+    if value > 10 {
+        return value;
+    }
+
+    true
+}
+
+fn main() {
+    let value = another(42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionReturnType {
+        location: Location::test(4, 16),
+        function: "another".to_owned(),
+        expected: Type::boolean(None).to_string(),
+        found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+        reference: Location::test(2, 26),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_in_constant_function() {
+    let input = r#"
+const fn another(value: u8) -> u8 {
+    if value > 10 {
+        return 10;
+    }
+
+    value
+}
+
+fn main() {
+    const VALUE: u8 = another(42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ReturnStatementInConstantFunction {
+            location: Location::test(4, 9),
+            function: "another".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
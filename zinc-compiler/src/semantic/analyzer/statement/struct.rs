@@ -9,6 +9,8 @@ use zinc_syntax::StructStatement;
 
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error;
+use crate::semantic::generics::Template;
+use crate::semantic::scope::item::r#type::statement::Statement as TypeStatementVariant;
 use crate::semantic::scope::Scope;
 
 ///
@@ -21,6 +23,10 @@ impl Analyzer {
     /// Defines a compile-time only structure type.
     ///
     pub fn define(scope: Rc<RefCell<Scope>>, statement: StructStatement) -> Result<Type, Error> {
+        if !statement.generics.is_empty() {
+            return Self::generic(scope, statement);
+        }
+
         let mut fields: Vec<(String, Type)> = Vec::with_capacity(statement.fields.len());
         for field in statement.fields.into_iter() {
             if fields
@@ -57,4 +63,40 @@ impl Analyzer {
 
         Ok(r#type)
     }
+
+    ///
+    /// Defines a generic structure type, which is instantiated with the actual generic arguments
+    /// specified in the type or inferred from the structure literal.
+    ///
+    fn generic(scope: Rc<RefCell<Scope>>, mut statement: StructStatement) -> Result<Type, Error> {
+        if let Some(parameter) = statement
+            .generics
+            .iter()
+            .find(|parameter| parameter.is_constant())
+        {
+            return Err(Error::TypeGenericConstantUnavailable {
+                location: parameter.location,
+                r#type: statement.identifier.name,
+                name: parameter.identifier.name.to_owned(),
+            });
+        }
+
+        let location = statement.location;
+        let identifier = statement.identifier.name.clone();
+        let generics = std::mem::take(&mut statement.generics);
+
+        let template = Template::new(
+            location,
+            identifier.clone(),
+            generics,
+            TypeStatementVariant::Struct(statement),
+            RefCell::borrow(&scope)
+                .parent()
+                .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+        )?;
+
+        Ok(Type::generic_structure(
+            location, identifier, template, scope,
+        ))
+    }
 }
@@ -26,6 +26,7 @@ impl Analyzer {
     /// Defines an item imported by the compile-time only `use` statement.
     ///
     pub fn define(scope: Rc<RefCell<Scope>>, statement: UseStatement) -> Result<(), Error> {
+        let location = statement.location;
         let path_location = statement.path.location;
 
         let path = match ExpressionAnalyzer::new(scope.clone(), TranslationRule::Path)
@@ -45,7 +46,13 @@ impl Analyzer {
             Some(alias_identifier) => alias_identifier,
             None => path.last().to_owned(),
         };
-        Scope::define_item(scope, identifier, item.wrap())?;
+        let name = identifier.name.to_owned();
+        let item = item.wrap();
+        Scope::define_item(scope.clone(), identifier, item.clone())?;
+
+        if let Some(warnings) = RefCell::borrow(&scope).warnings() {
+            warnings.borrow_mut().declare_import(location, name, item);
+        }
 
         Ok(())
     }
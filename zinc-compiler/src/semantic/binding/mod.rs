@@ -12,6 +12,8 @@ use zinc_lexical::Keyword;
 use zinc_syntax::BindingPattern;
 use zinc_syntax::BindingPatternVariant;
 use zinc_syntax::Identifier;
+use zinc_syntax::Type as SyntaxType;
+use zinc_syntax::TypeVariant as SyntaxTypeVariant;
 
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error;
@@ -34,7 +36,9 @@ pub struct Binding {
     pub is_mutable: bool,
     /// Whether the binding is a wildcard.
     pub is_wildcard: bool,
-    /// The bound variable r#type.
+    /// Whether the binding is a `&mut` function argument.
+    pub is_reference: bool,
+    /// The bound variable r#type, which is the referenced type for the `&mut` arguments.
     pub r#type: Type,
 }
 
@@ -47,6 +51,20 @@ impl Binding {
             identifier,
             is_mutable,
             is_wildcard,
+            is_reference: false,
+            r#type,
+        }
+    }
+
+    ///
+    /// A shortcut constructor for the `&mut` function arguments.
+    ///
+    pub fn new_reference(identifier: Identifier, is_wildcard: bool, r#type: Type) -> Self {
+        Self {
+            identifier,
+            is_mutable: false,
+            is_wildcard,
+            is_reference: true,
             r#type,
         }
     }
@@ -147,6 +165,25 @@ impl Binder {
                         });
                     }
 
+                    let is_reference = binding
+                        .r#type
+                        .map(|r#type| {
+                            matches!(r#type.variant, SyntaxTypeVariant::MutableReference { .. })
+                        })
+                        .unwrap_or_default();
+                    if is_reference {
+                        if let Type::Contract(_) = r#type {
+                            return Err(Error::ReferenceContractStorage {
+                                location: binding.location,
+                            });
+                        }
+
+                        Scope::define_reference(scope.clone(), identifier.clone(), r#type.clone())?;
+
+                        result.push(Binding::new_reference(identifier, false, r#type));
+                        continue;
+                    }
+
                     Scope::define_variable(
                         scope.clone(),
                         identifier.clone(),
@@ -164,6 +201,7 @@ impl Binder {
                         location: identifier.location,
                         identifier: identifier.name.to_owned(),
                     })?;
+                    let (r#type, is_reference) = Self::strip_reference(r#type);
                     let r#type = Type::try_from_syntax(r#type, scope.clone())?;
 
                     if !r#type.is_instantiatable(false) {
@@ -173,6 +211,13 @@ impl Binder {
                         });
                     }
 
+                    if is_reference {
+                        Scope::define_reference(scope.clone(), identifier.clone(), r#type.clone())?;
+
+                        result.push(Binding::new_reference(identifier, false, r#type));
+                        continue;
+                    }
+
                     Scope::define_variable(
                         scope.clone(),
                         identifier.clone(),
@@ -192,6 +237,7 @@ impl Binder {
                         location: binding.location,
                         identifier: "_".to_owned(),
                     })?;
+                    let (r#type, is_reference) = Self::strip_reference(r#type);
                     let r#type = Type::try_from_syntax(r#type, scope.clone())?;
 
                     if !r#type.is_instantiatable(false) {
@@ -201,16 +247,26 @@ impl Binder {
                         });
                     }
 
-                    result.push(Binding::new(
-                        Identifier::new(binding.pattern.location, "_".to_owned()),
-                        false,
-                        true,
-                        r#type,
-                    ));
+                    let identifier = Identifier::new(binding.pattern.location, "_".to_owned());
+                    result.push(if is_reference {
+                        Binding::new_reference(identifier, true, r#type)
+                    } else {
+                        Binding::new(identifier, false, true, r#type)
+                    });
                 }
             }
         }
 
         Ok(result)
     }
+
+    ///
+    /// Unwraps the `&mut` argument type, returning the referenced type and the reference flag.
+    ///
+    fn strip_reference(r#type: SyntaxType) -> (SyntaxType, bool) {
+        match r#type.variant {
+            SyntaxTypeVariant::MutableReference { inner } => (*inner, true),
+            _ => (r#type, false),
+        }
+    }
 }
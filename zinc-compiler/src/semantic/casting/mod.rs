@@ -35,6 +35,8 @@ impl Caster {
     /// `b1` and `b2` are bitlengths
     /// `T` is any type
     ///
+    /// The enumerations with data variants cannot be casted to integers.
+    ///
     /// For more information on type semantics, see the official Zinc book.
     ///
     pub fn cast(from: &Type, to: &Type) -> Result<(), Error> {
@@ -45,9 +47,9 @@ impl Caster {
             (Type::IntegerSigned { .. }, Type::IntegerSigned { .. }) => Ok(()),
             (Type::IntegerSigned { .. }, Type::IntegerUnsigned { .. }) => Ok(()),
             (Type::IntegerSigned { .. }, Type::Field(_)) => Ok(()),
-            (Type::Enumeration(_), Type::IntegerSigned { .. }) => Ok(()),
-            (Type::Enumeration(_), Type::IntegerUnsigned { .. }) => Ok(()),
-            (Type::Enumeration(_), Type::Field(_)) => Ok(()),
+            (Type::Enumeration(inner), Type::IntegerSigned { .. }) if !inner.is_data() => Ok(()),
+            (Type::Enumeration(inner), Type::IntegerUnsigned { .. }) if !inner.is_data() => Ok(()),
+            (Type::Enumeration(inner), Type::Field(_)) if !inner.is_data() => Ok(()),
             (from, to) if from == to => Ok(()),
 
             (from @ Type::IntegerUnsigned { .. }, to) => Err(Error::CastingToInvalidType {
@@ -64,4 +66,32 @@ impl Caster {
             }),
         }
     }
+
+    ///
+    /// Checks whether the integer cast narrows the type, that is, some values of `from` cannot be
+    /// represented with `to`:
+    /// u<b1> -> u<b2>, where b2 < b1
+    /// u<b1> -> i<b2>, where b2 <= b1
+    /// i<b1> -> i<b2>, where b2 < b1
+    /// i<b1> -> u<b2>
+    ///
+    /// The enumerations are treated as unsigned integers of their bitlength.
+    ///
+    pub fn is_truncating(from: &Type, to: &Type) -> bool {
+        let (is_from_signed, from_bitlength) = match from {
+            Type::IntegerUnsigned { bitlength, .. } => (false, *bitlength),
+            Type::IntegerSigned { bitlength, .. } => (true, *bitlength),
+            Type::Enumeration(inner) if !inner.is_data() => (false, inner.bitlength),
+            _ => return false,
+        };
+
+        match to {
+            Type::IntegerUnsigned { bitlength, .. } => {
+                is_from_signed || *bitlength < from_bitlength
+            }
+            Type::IntegerSigned { bitlength, .. } if is_from_signed => *bitlength < from_bitlength,
+            Type::IntegerSigned { bitlength, .. } => *bitlength <= from_bitlength,
+            _ => false,
+        }
+    }
 }
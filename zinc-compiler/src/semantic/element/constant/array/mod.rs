@@ -7,10 +7,12 @@ mod tests;
 
 use std::fmt;
 
+use num::BigInt;
 use num::Signed;
 use num::ToPrimitive;
 
 use zinc_lexical::Location;
+use zinc_syntax::BytesLiteral;
 
 use crate::semantic::element::access::index::Index as IndexAccess;
 use crate::semantic::element::constant::integer::Integer as IntegerConstant;
@@ -62,6 +64,30 @@ impl Array {
         }
     }
 
+    ///
+    /// A shortcut constructor, which creates a `[u8; N]` array from `bytes`.
+    ///
+    pub fn new_bytes(location: Location, bytes: Vec<u8>) -> Self {
+        let values = bytes
+            .into_iter()
+            .map(|byte| {
+                Constant::Integer(IntegerConstant::new(
+                    location,
+                    BigInt::from(byte),
+                    false,
+                    zinc_const::bitlength::BYTE,
+                    false,
+                ))
+            })
+            .collect();
+
+        Self::new_with_values(
+            location,
+            Type::integer_unsigned(Some(location), zinc_const::bitlength::BYTE),
+            values,
+        )
+    }
+
     ///
     /// The array size.
     ///
@@ -286,6 +312,13 @@ impl Array {
     }
 }
 
+impl From<BytesLiteral> for Array {
+    fn from(literal: BytesLiteral) -> Self {
+        let location = literal.location;
+        Self::new_bytes(location, literal.into())
+    }
+}
+
 impl ITyped for Array {
     fn r#type(&self) -> Type {
         Type::array(
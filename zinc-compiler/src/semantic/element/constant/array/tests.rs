@@ -10,6 +10,20 @@ use crate::error::Error;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error as SemanticError;
 
+#[test]
+fn ok_bytes() {
+    let input = r#"
+const PREFIX: [u8; 4] = b"zinc";
+const SEPARATOR: [u8; 4] = hex"dead_beef";
+
+fn main() -> ([u8; 4], [u8; 4], u8) {
+    (PREFIX, SEPARATOR, b"zinc"[0] + hex"01"[0])
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
 #[test]
 fn error_pushing_invalid_type() {
     let input = r#"
@@ -48,6 +62,25 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_index_out_of_range_bytes() {
+    let input = r#"
+fn main() {
+    const VALUE: u8 = b"zinc"[4];
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ArrayIndexOutOfRange {
+        location: Location::test(3, 31),
+        index: BigInt::from(4).to_string(),
+        size: 4,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_slice_start_out_of_range() {
     let input = r#"
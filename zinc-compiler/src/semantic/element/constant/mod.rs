@@ -218,7 +218,23 @@ impl Constant {
                     found: constant_2.to_string(),
                 })
             }
-            (constant_1, _) => Err(Error::OperatorEqualsFirstOperandExpectedPrimitiveType {
+            (constant_1, constant_2) if constant_1.r#type().is_comparable() => {
+                if !constant_1.has_the_same_type_as(&constant_2) {
+                    return Err(Error::OperatorEqualsTypesMismatch {
+                        location: constant_1.location(),
+                        first: constant_1.r#type().to_string(),
+                        second: constant_2.r#type().to_string(),
+                    });
+                }
+
+                let result = constant_1.is_equal_to(&constant_2);
+
+                Ok((
+                    Self::Boolean(Boolean::new(constant_1.location(), result)),
+                    GeneratorExpressionOperator::equals_compound(&constant_1.r#type()),
+                ))
+            }
+            (constant_1, _) => Err(Error::OperatorEqualsFirstOperandExpectedComparable {
                 location: constant_1.location(),
                 found: constant_1.to_string(),
             }),
@@ -257,7 +273,23 @@ impl Constant {
                     found: constant_2.to_string(),
                 })
             }
-            (constant_1, _) => Err(Error::OperatorNotEqualsFirstOperandExpectedPrimitiveType {
+            (constant_1, constant_2) if constant_1.r#type().is_comparable() => {
+                if !constant_1.has_the_same_type_as(&constant_2) {
+                    return Err(Error::OperatorNotEqualsTypesMismatch {
+                        location: constant_1.location(),
+                        first: constant_1.r#type().to_string(),
+                        second: constant_2.r#type().to_string(),
+                    });
+                }
+
+                let result = !constant_1.is_equal_to(&constant_2);
+
+                Ok((
+                    Self::Boolean(Boolean::new(constant_1.location(), result)),
+                    GeneratorExpressionOperator::not_equals_compound(&constant_1.r#type()),
+                ))
+            }
+            (constant_1, _) => Err(Error::OperatorNotEqualsFirstOperandExpectedComparable {
                 location: constant_1.location(),
                 found: constant_1.to_string(),
             }),
@@ -743,6 +775,40 @@ impl Constant {
         }
     }
 
+    ///
+    /// Compares the constant with `other` of the same type element-wise, ignoring the locations.
+    ///
+    pub fn is_equal_to(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Unit(_), Self::Unit(_)) => true,
+            (Self::Boolean(inner_1), Self::Boolean(inner_2)) => inner_1.inner == inner_2.inner,
+            (Self::Integer(inner_1), Self::Integer(inner_2)) => inner_1.value == inner_2.value,
+            (Self::Array(inner_1), Self::Array(inner_2)) => {
+                inner_1.values.len() == inner_2.values.len()
+                    && inner_1
+                        .values
+                        .iter()
+                        .zip(inner_2.values.iter())
+                        .all(|(value_1, value_2)| value_1.is_equal_to(value_2))
+            }
+            (Self::Tuple(inner_1), Self::Tuple(inner_2)) => {
+                inner_1.values.len() == inner_2.values.len()
+                    && inner_1
+                        .values
+                        .iter()
+                        .zip(inner_2.values.iter())
+                        .all(|(value_1, value_2)| value_1.is_equal_to(value_2))
+            }
+            (Self::Structure(inner_1), Self::Structure(inner_2)) => {
+                inner_1.values.len() == inner_2.values.len()
+                    && inner_1.values.iter().zip(inner_2.values.iter()).all(
+                        |((_name_1, value_1), (_name_2, value_2))| value_1.is_equal_to(value_2),
+                    )
+            }
+            _ => false,
+        }
+    }
+
     ///
     /// Returns the constant location in the code.
     ///
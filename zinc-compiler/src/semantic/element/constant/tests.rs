@@ -260,7 +260,7 @@ fn main() {
 }
 
 #[test]
-fn error_operator_equals_1st_expected_primitive() {
+fn error_operator_equals_1st_expected_comparable() {
     let input = r#"
 fn main() {
     let value = "string" == 42;
@@ -268,7 +268,7 @@ fn main() {
 "#;
 
     let expected = Err(Error::Semantic(
-        SemanticError::OperatorEqualsFirstOperandExpectedPrimitiveType {
+        SemanticError::OperatorEqualsFirstOperandExpectedComparable {
             location: Location::test(3, 17),
             found: Constant::String(StringConstant::new(
                 Location::test(3, 17),
@@ -283,6 +283,114 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn ok_operator_equals_compound_tuple() {
+    let input = r#"
+fn main() {
+    const UNEQUAL: bool = (1, 2) == (1, 3);
+    const EQUAL: bool = (true, false) == (true, false);
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_operator_equals_compound_tuple_values() {
+    let (result, _operator) = tuple(Location::test(1, 1), &[1, 2])
+        .equals(tuple(Location::test(1, 20), &[1, 3]))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(
+        result,
+        Constant::Boolean(BooleanConstant::new(Location::test(1, 1), false))
+    );
+
+    let (result, _operator) = tuple(Location::test(1, 1), &[1, 2])
+        .equals(tuple(Location::test(1, 20), &[1, 2]))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(
+        result,
+        Constant::Boolean(BooleanConstant::new(Location::test(1, 1), true))
+    );
+}
+
+#[test]
+fn ok_operator_not_equals_compound_tuple_values() {
+    let (result, _operator) = tuple(Location::test(1, 1), &[1, 2])
+        .not_equals(tuple(Location::test(1, 20), &[1, 3]))
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    assert_eq!(
+        result,
+        Constant::Boolean(BooleanConstant::new(Location::test(1, 1), true))
+    );
+}
+
+#[test]
+fn error_operator_equals_types_mismatch_compound_tuple() {
+    let input = r#"
+fn main() {
+    let value = (1, 2) == (1, true);
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::OperatorEqualsTypesMismatch {
+            location: Location::test(3, 17),
+            first: Type::tuple(
+                Some(Location::test(3, 17)),
+                vec![
+                    Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+                    Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+                ],
+            )
+            .to_string(),
+            second: Type::tuple(
+                Some(Location::test(3, 27)),
+                vec![
+                    Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+                    Type::boolean(None),
+                ],
+            )
+            .to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_operator_equals_types_mismatch_compound() {
+    let input = r#"
+fn main() {
+    let value = [1, 2] == [1, 2, 3];
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::OperatorEqualsTypesMismatch {
+            location: Location::test(3, 17),
+            first: Type::array(
+                Some(Location::test(3, 17)),
+                Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+                2,
+            )
+            .to_string(),
+            second: Type::array(
+                Some(Location::test(3, 27)),
+                Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+                3,
+            )
+            .to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_operator_equals_2nd_expected_unit() {
     let input = r#"
@@ -358,7 +466,7 @@ fn main() {
 }
 
 #[test]
-fn error_operator_not_equals_1st_expected_primitive() {
+fn error_operator_not_equals_1st_expected_comparable() {
     let input = r#"
 fn main() {
     let value = "string" != 42;
@@ -366,7 +474,7 @@ fn main() {
 "#;
 
     let expected = Err(Error::Semantic(
-        SemanticError::OperatorNotEqualsFirstOperandExpectedPrimitiveType {
+        SemanticError::OperatorNotEqualsFirstOperandExpectedComparable {
             location: Location::test(3, 17),
             found: Constant::String(StringConstant::new(
                 Location::test(3, 17),
@@ -381,6 +489,37 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_operator_not_equals_types_mismatch_compound() {
+    let input = r#"
+fn main() {
+    let value = [1, 2] != [1, 2, 3];
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::OperatorNotEqualsTypesMismatch {
+            location: Location::test(3, 17),
+            first: Type::array(
+                Some(Location::test(3, 17)),
+                Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+                2,
+            )
+            .to_string(),
+            second: Type::array(
+                Some(Location::test(3, 27)),
+                Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
+                3,
+            )
+            .to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_operator_not_equals_2nd_expected_unit() {
     let input = r#"
@@ -1210,3 +1349,24 @@ fn main() {
 
     assert_eq!(result, expected);
 }
+
+///
+/// Creates a constant tuple of `u8` integers for testing purposes.
+///
+fn tuple(location: Location, values: &[u8]) -> Constant {
+    Constant::Tuple(TupleConstant::new_with_values(
+        location,
+        values
+            .iter()
+            .map(|value| {
+                Constant::Integer(IntegerConstant::new(
+                    location,
+                    BigInt::from(*value),
+                    false,
+                    zinc_const::bitlength::BYTE,
+                    true,
+                ))
+            })
+            .collect(),
+    ))
+}
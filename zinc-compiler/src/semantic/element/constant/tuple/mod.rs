@@ -120,7 +120,7 @@ impl ITyped for Tuple {
     }
 
     fn has_the_same_type_as(&self, other: &Self) -> bool {
-        self.values == other.values
+        self.r#type() == other.r#type()
     }
 }
 
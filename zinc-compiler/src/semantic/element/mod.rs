@@ -46,6 +46,7 @@ use self::path::Path;
 use self::place::Place;
 use self::r#type::Type;
 use self::tuple_index::TupleIndex;
+use self::value::enumeration::Enumeration as EnumerationValue;
 use self::value::Value;
 
 ///
@@ -69,6 +70,8 @@ pub enum Element {
     Path(Path),
     /// Memory descriptor (`lvalue`)
     Place(Place),
+    /// Mutable reference to a memory descriptor, which is passed to a `&mut` function argument
+    Reference(Place),
     /// Tuple field index
     TupleIndex(TupleIndex),
     /// Structure field identifier
@@ -1525,6 +1528,10 @@ impl Element {
                     match item {
                         Ok(item) => match *item.borrow() {
                             ScopeItem::Type(ref r#type) => {
+                                if let Some(warnings) = scope.borrow().warnings() {
+                                    warnings.borrow_mut().reference(&item);
+                                }
+
                                 let r#type = r#type.define()?;
                                 Ok((
                                     Element::Type(r#type),
@@ -1569,6 +1576,10 @@ impl Element {
                     match item {
                         Ok(item) => match *item.borrow() {
                             ScopeItem::Type(ref r#type) => {
+                                if let Some(warnings) = scope.borrow().warnings() {
+                                    warnings.borrow_mut().reference(&item);
+                                }
+
                                 let r#type = r#type.define()?;
                                 Ok((
                                     Element::Type(r#type),
@@ -1621,6 +1632,10 @@ impl Element {
                     match item {
                         Ok(item) => match *item.borrow() {
                             ScopeItem::Type(ref r#type) => {
+                                if let Some(warnings) = scope.borrow().warnings() {
+                                    warnings.borrow_mut().reference(&item);
+                                }
+
                                 let r#type = r#type.define()?;
                                 Ok((
                                     Element::Type(r#type),
@@ -1667,9 +1682,20 @@ impl Element {
     ///
     /// Executes the `::` path operator.
     ///
+    /// The first operand can also be a structure or enumeration type, e.g. `Pair::<u8, u8>`,
+    /// so the path starts from the type namespace.
+    ///
     pub fn path(self, other: Self) -> Result<Self, Error> {
         let mut path = match self {
             Self::Path(path) => path,
+            Self::Type(r#type @ Type::Structure(_)) | Self::Type(r#type @ Type::Enumeration(_)) => {
+                Path::new_based(
+                    r#type
+                        .location()
+                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    r#type,
+                )
+            }
             element => {
                 return Err(Error::OperatorPathFirstOperandExpectedPath {
                     location: element
@@ -1702,15 +1728,91 @@ impl Element {
     ///
     /// It is a special internal operator, which accepts the structure type and literal as operands.
     ///
-    pub fn structure(self, other: Self, scope: Rc<RefCell<Scope>>) -> Result<Self, Error> {
+    /// The first operand can also be a path to a structure-like enumeration variant, which
+    /// requires the variant constructor operator to be written to the IR.
+    ///
+    pub fn structure(
+        self,
+        other: Self,
+        scope: Rc<RefCell<Scope>>,
+    ) -> Result<(Self, Option<GeneratorExpressionOperator>), Error> {
+        let (enumeration, index) = match self {
+            Element::Path(ref path) => match *Scope::resolve_path(scope.clone(), path)?.borrow() {
+                ScopeItem::Variant(ref variant) if variant.data().is_some() => variant
+                    .data()
+                    .map(|(enumeration, index)| (enumeration.to_owned(), index))
+                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                ref item => {
+                    return Err(Error::OperatorStructureFirstOperandExpectedType {
+                        location: path.location,
+                        found: item.to_string(),
+                    })
+                }
+            },
+            element => {
+                return element
+                    .structure_type(other, scope)
+                    .map(|element| (element, None))
+            }
+        };
+
+        let location = self
+            .location()
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+        let r#type =
+            enumeration
+                .structure(index)
+                .ok_or_else(|| Error::TypeVariantExpectedStructure {
+                    location,
+                    variant: enumeration.variant_signature(index),
+                })?;
+
+        match other {
+            Element::Value(Value::Structure(mut structure)) => {
+                structure.validate(r#type)?;
+
+                let operator =
+                    GeneratorExpressionOperator::enumeration_variant(&enumeration, index);
+                let value = Value::Enumeration(EnumerationValue::new(Some(location), enumeration));
+                Ok((Self::Value(value), Some(operator)))
+            }
+            element => Err(Error::ExpressionNonConstantElement {
+                location: element
+                    .location()
+                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                found: enumeration.variant_signature(index),
+            }),
+        }
+    }
+
+    ///
+    /// Executes the structure initialization operator with a structure or contract type.
+    ///
+    fn structure_type(self, other: Self, scope: Rc<RefCell<Scope>>) -> Result<Self, Error> {
         match self {
             Element::Type(Type::Structure(r#type)) => match other {
                 Element::Value(Value::Structure(mut structure)) => {
+                    let r#type = r#type.infer(
+                        structure
+                            .fields
+                            .iter()
+                            .map(|(name, _location, r#type)| (name.to_owned(), r#type.to_owned()))
+                            .collect(),
+                    )?;
                     structure.validate(r#type)?;
 
                     Ok(Self::Value(Value::Structure(structure)))
                 }
                 Element::Constant(Constant::Structure(mut structure)) => {
+                    let r#type = r#type.infer(
+                        structure
+                            .values
+                            .iter()
+                            .map(|(identifier, constant)| {
+                                (identifier.name.to_owned(), constant.r#type())
+                            })
+                            .collect(),
+                    )?;
                     structure.validate(r#type)?;
 
                     Ok(Self::Constant(Constant::Structure(structure)))
@@ -1761,6 +1863,7 @@ impl Element {
             Self::ArgumentList(inner) => Some(inner.location),
             Self::Path(inner) => Some(inner.location),
             Self::Place(inner) => Some(inner.identifier.location),
+            Self::Reference(inner) => Some(inner.identifier.location),
             Self::TupleIndex(inner) => Some(inner.location),
             Self::Identifier(inner) => Some(inner.location),
             Self::Module(inner) => Some(inner.location),
@@ -1777,6 +1880,7 @@ impl fmt::Display for Element {
             Self::ArgumentList(inner) => write!(f, "argument list {}", inner),
             Self::Path(inner) => write!(f, "path {}", inner),
             Self::Place(inner) => write!(f, "place {}", inner),
+            Self::Reference(inner) => write!(f, "mutable reference {}", inner),
             Self::TupleIndex(inner) => write!(f, "tuple field {}", inner),
             Self::Identifier(inner) => write!(f, "structure field {}", inner.name),
             Self::Module(inner) => write!(f, "module {}", inner.name),
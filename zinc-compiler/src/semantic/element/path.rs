@@ -7,6 +7,8 @@ use std::fmt;
 use zinc_lexical::Location;
 use zinc_syntax::Identifier;
 
+use crate::semantic::element::r#type::Type;
+
 ///
 /// Paths are the `::` expressions which only exist at compile-time.
 /// They are usually coerced to place, value, constant or type expressions.
@@ -15,6 +17,8 @@ use zinc_syntax::Identifier;
 pub struct Path {
     /// The location of the path expression.
     pub location: Location,
+    /// The type the path starts from, e.g. `Pair::<u8, u8>` in `Pair::<u8, u8>::new`.
+    pub base: Option<Type>,
     /// The array of identifiers, which appear around the `::` operators.
     pub elements: Vec<Identifier>,
 }
@@ -26,6 +30,7 @@ impl Path {
     pub fn new(location: Location, initial: Identifier) -> Self {
         Self {
             location,
+            base: None,
             elements: vec![initial],
         }
     }
@@ -34,7 +39,24 @@ impl Path {
     /// A shortcut constructor.
     ///
     pub fn new_complex(location: Location, elements: Vec<Identifier>) -> Self {
-        Self { location, elements }
+        Self {
+            location,
+            base: None,
+            elements,
+        }
+    }
+
+    ///
+    /// A shortcut constructor, which starts the path from the `base` type namespace.
+    ///
+    /// The path is empty until the first element is pushed.
+    ///
+    pub fn new_based(location: Location, base: Type) -> Self {
+        Self {
+            location,
+            base: Some(base),
+            elements: vec![],
+        }
     }
 
     ///
@@ -56,6 +78,10 @@ impl Path {
 
 impl fmt::Display for Path {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(ref base) = self.base {
+            write!(f, "{}::", base)?;
+        }
+
         write!(
             f,
             "{}",
@@ -61,6 +61,25 @@ pub enum Element {
     },
 }
 
+impl Element {
+    ///
+    /// Returns the half-open bounds of the array elements accessed with a constant index or
+    /// slice, or `None` if the path element is not a constant array access.
+    ///
+    pub fn index_bounds(&self) -> Option<(BigInt, BigInt)> {
+        match self {
+            Self::IndexConstant { constant, .. } => {
+                Some((constant.value.to_owned(), constant.value.to_owned() + 1))
+            }
+            Self::IndexRange { start, end, .. } => Some((start.to_owned(), end.to_owned())),
+            Self::IndexRangeInclusive { start, end, .. } => {
+                Some((start.to_owned(), end.to_owned() + 1))
+            }
+            _ => None,
+        }
+    }
+}
+
 impl fmt::Display for Element {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
@@ -14,4 +14,7 @@ pub enum MemoryType {
         /// The field index in the contract storage.
         index: usize,
     },
+    /// Data allocated on the VM data stack of a calling function, which is accessed via
+    /// the address stored in a `&mut` function argument.
+    Reference,
 }
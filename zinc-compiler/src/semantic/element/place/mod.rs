@@ -22,6 +22,7 @@ use crate::semantic::element::access::dot::stack_field::StackField as StackField
 use crate::semantic::element::access::dot::Dot as DotAccessVariant;
 use crate::semantic::element::access::index::Index as IndexAccess;
 use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::structure::Structure as StructureType;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::tuple_index::TupleIndex;
 use crate::semantic::element::value::Value;
@@ -353,6 +354,44 @@ impl Place {
         }
     }
 
+    ///
+    /// Splits the `std::collections::Vec` place into its data array and length places.
+    ///
+    /// Cannot fail, since the vector fields are declared by the compiler.
+    ///
+    pub fn vec_fields(self) -> (Self, Self) {
+        let location = self.identifier.location;
+
+        let field = |place: Self, name: &str| match place
+            .structure_field(Identifier::new(location, name.to_owned()))
+            .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS)
+        {
+            (mut place, DotAccessVariant::StackField(access)) => {
+                place.push_element(PlaceElement::StackField { access });
+                place
+            }
+            _ => panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        };
+
+        (
+            field(self.clone(), StructureType::VEC_FIELD_DATA),
+            field(self, StructureType::VEC_FIELD_LENGTH),
+        )
+    }
+
+    ///
+    /// Returns the `std::collections::Vec` element type.
+    ///
+    pub fn vec_element_type(&self) -> Type {
+        match self.r#type {
+            Type::Structure(ref structure) => match structure.fields.first() {
+                Some((_name, Type::Array(array))) => array.r#type.deref().to_owned(),
+                _ => panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+            },
+            _ => panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        }
+    }
+
     ///
     /// Push a place path `element`, if it is already known and validated.
     ///
@@ -374,6 +413,54 @@ impl Place {
         }
         None
     }
+
+    ///
+    /// Whether the place shares some memory with the `other` one, e.g. if they are the same
+    /// variable, or one of them is a field or an element of the other.
+    ///
+    /// The array elements and slices with constant indexes are disjoint if their bounds do not
+    /// intersect. Any other array access is considered overlapping.
+    ///
+    pub fn overlaps(&self, other: &Self) -> bool {
+        if self.identifier.name != other.identifier.name {
+            return false;
+        }
+
+        for (element_1, element_2) in self.elements.iter().zip(other.elements.iter()) {
+            match (element_1, element_2) {
+                (
+                    PlaceElement::StackField { access: access_1 },
+                    PlaceElement::StackField { access: access_2 },
+                ) if access_1.name != access_2.name => return false,
+                (
+                    PlaceElement::ContractField { access: access_1 },
+                    PlaceElement::ContractField { access: access_2 },
+                ) if access_1.name != access_2.name => return false,
+                (PlaceElement::StackField { .. }, PlaceElement::StackField { .. })
+                | (PlaceElement::ContractField { .. }, PlaceElement::ContractField { .. }) => {}
+                (
+                    PlaceElement::IndexConstant {
+                        constant: constant_1,
+                        ..
+                    },
+                    PlaceElement::IndexConstant {
+                        constant: constant_2,
+                        ..
+                    },
+                ) if constant_1.value == constant_2.value => {}
+                (element_1, element_2) => {
+                    return match (element_1.index_bounds(), element_2.index_bounds()) {
+                        (Some((start_1, end_1)), Some((start_2, end_2))) => {
+                            start_1 < end_2 && start_2 < end_1
+                        }
+                        _ => true,
+                    };
+                }
+            }
+        }
+
+        true
+    }
 }
 
 impl fmt::Display for Place {
@@ -8,6 +8,7 @@ use zinc_lexical::Location;
 
 use crate::error::Error;
 use crate::semantic::element::constant::boolean::Boolean as BooleanConstant;
+use crate::semantic::element::constant::integer::Integer as IntegerConstant;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::Element;
@@ -466,3 +467,232 @@ contract Test {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_reference_variable() {
+    let input = r#"
+fn increment(value: &mut u8) {
+    value += 1;
+}
+
+fn main() {
+    let mut result = 42;
+    increment(&mut result);
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_reference_array_element() {
+    let input = r#"
+fn increment(value: &mut u8) {
+    value += 1;
+}
+
+fn main() {
+    let mut result = [1, 2, 3];
+    increment(&mut result[1]);
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_reference_array_slice() {
+    let input = r#"
+fn fill(values: &mut [u8; 2], value: u8) {
+    for i in 0..2 {
+        values[i] = value;
+    }
+}
+
+fn main() {
+    let mut result = [1, 2, 3, 4];
+    fill(&mut result[1..3], 42);
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_reference_structure_field() {
+    let input = r#"
+struct Data {
+    a: u8,
+    b: (u8, field),
+}
+
+fn double(value: &mut field) {
+    value += value;
+}
+
+fn main() {
+    let mut data = Data { a: 1, b: (2, 3 as field) };
+    double(&mut data.b.1);
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_reference_forwarded() {
+    let input = r#"
+fn increment(value: &mut u8) {
+    value += 1;
+}
+
+fn increment_element(values: &mut [u8; 4], index: u8) {
+    values[index] += 1;
+    increment(&mut values[0]);
+}
+
+fn main() {
+    let mut result = [1, 2, 3, 4];
+    increment_element(&mut result, 2);
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_reference_outside_function_argument_expression() {
+    let input = r#"
+fn main() {
+    let mut result = 42;
+    let reference = &mut result;
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ReferenceOutsideFunctionArgument {
+            location: Location::test(4, 21),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_reference_outside_function_argument_type() {
+    let input = r#"
+struct Data {
+    value: &mut u8,
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::ReferenceOutsideFunctionArgument {
+            location: Location::test(3, 12),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_reference_expected_place() {
+    let input = r#"
+fn increment(value: &mut u8) {
+    value += 1;
+}
+
+fn main() {
+    increment(&mut 42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ReferenceExpectedPlace {
+        location: Location::test(7, 20),
+        found: Element::Constant(Constant::Integer(IntegerConstant::new(
+            Location::test(7, 20),
+            BigInt::from(42),
+            false,
+            zinc_const::bitlength::BYTE,
+            true,
+        )))
+        .to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_reference_to_immutable_memory() {
+    let input = r#"
+fn increment(value: &mut u8) {
+    value += 1;
+}
+
+fn main() {
+    let result = 42;
+    increment(&mut result);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ReferenceToImmutableMemory {
+        location: Location::test(8, 20),
+        name: "result".to_owned(),
+        reference: Some(Location::test(7, 9)),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_reference_runtime_index() {
+    let input = r#"
+fn increment(value: &mut u8) {
+    value += 1;
+}
+
+fn main(index: u8) {
+    let mut result = [1, 2, 3];
+    increment(&mut result[index]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ReferenceRuntimeIndex {
+        location: Location::test(8, 20),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_reference_contract_storage() {
+    let input = r#"
+contract Test {
+    value: u8;
+
+    pub fn increment(&mut self) {
+        self.value += 1;
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ReferenceContractStorage {
+        location: Location::test(5, 22),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -5,21 +5,28 @@
 #[cfg(test)]
 mod tests;
 
+pub mod payload;
+
 use std::cell::RefCell;
 use std::convert::TryFrom;
 use std::fmt;
 use std::rc::Rc;
 
 use num::BigInt;
+use num::One;
+use num::Zero;
 
 use zinc_lexical::Location;
 use zinc_syntax::Variant;
 
 use crate::semantic::element::constant::integer::Integer as IntegerConstant;
 use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::structure::Structure as StructureType;
 use crate::semantic::error::Error;
 use crate::semantic::scope::Scope;
 
+use self::payload::Payload;
+
 ///
 /// Describes an enumeration type.
 ///
@@ -27,6 +34,9 @@ use crate::semantic::scope::Scope;
 /// and the implementation `scope`, which contains the enumeration variants and
 /// reference to its parent scope.
 ///
+/// If some variants carry data, the enumeration value consists of the variant tag followed by
+/// the payloads of all variants, where only the payload of the actual variant is meaningful.
+///
 #[derive(Debug, Clone)]
 pub struct Enumeration {
     /// The enumeration type location in the code.
@@ -41,6 +51,8 @@ pub struct Enumeration {
     pub names: Vec<String>,
     /// The ordered list of the variant values.
     pub values: Vec<BigInt>,
+    /// The ordered list of the variant payloads.
+    pub payloads: Vec<Payload>,
     /// The ordered list of the enumeration generic type arguments.
     pub generics: Vec<String>,
     /// The enumeration scope, where its methods and associated items are declared.
@@ -60,9 +72,21 @@ impl Enumeration {
         scope: Rc<RefCell<Scope>>,
     ) -> Result<Self, Error> {
         let mut variants_bigint = Vec::with_capacity(variants.len());
-        for variant in variants.iter() {
-            let value = IntegerConstant::try_from(&variant.literal)?;
-            variants_bigint.push((variant.identifier.to_owned(), value.value.to_owned()));
+        let mut payloads = Vec::with_capacity(variants.len());
+        let mut next_value = BigInt::zero();
+        for variant in variants.into_iter() {
+            let value = match variant.literal {
+                Some(ref literal) => IntegerConstant::try_from(literal)?.value,
+                None => next_value,
+            };
+            next_value = value.to_owned() + BigInt::one();
+
+            payloads.push(Payload::try_from_syntax(
+                variant.payload,
+                identifier.as_str(),
+                scope.clone(),
+            )?);
+            variants_bigint.push((variant.identifier, value));
         }
         let names: Vec<String> = variants_bigint
             .iter()
@@ -74,14 +98,14 @@ impl Enumeration {
             .collect();
         for (index, bigint) in bigints.iter().enumerate() {
             if bigints.iter().filter(|value| value == &bigint).count() > 1 {
-                let variant = variants
+                let (variant, _value) = variants_bigint
                     .get(index)
                     .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
 
                 return Err(Error::TypeDuplicateVariantValue {
-                    location: variant.identifier.location,
+                    location: variant.location,
                     r#type: identifier,
-                    variant_name: variant.identifier.name.to_owned(),
+                    variant_name: variant.name.to_owned(),
                     variant_value: bigint.to_owned(),
                 });
             }
@@ -100,6 +124,7 @@ impl Enumeration {
             bitlength: minimal_bitlength,
             names,
             values: bigints,
+            payloads,
             generics,
             scope: scope.clone(),
         };
@@ -117,6 +142,73 @@ impl Enumeration {
 
         Ok(enumeration)
     }
+
+    ///
+    /// Checks if some variants of the enumeration carry data.
+    ///
+    pub fn is_data(&self) -> bool {
+        self.payloads.iter().any(|payload| !payload.is_unit())
+    }
+
+    ///
+    /// The enumeration value size in the abstract data stack, that is, the variant tag and
+    /// the payloads of all variants.
+    ///
+    pub fn size(&self) -> usize {
+        1 + self.payloads.iter().map(Payload::size).sum::<usize>()
+    }
+
+    ///
+    /// Returns the index of the variant with `value`.
+    ///
+    pub fn index(&self, value: &BigInt) -> Option<usize> {
+        self.values
+            .iter()
+            .position(|variant_value| variant_value == value)
+    }
+
+    ///
+    /// Returns the offset of the variant payload within the enumeration value.
+    ///
+    pub fn offset(&self, index: usize) -> usize {
+        1 + self.payloads[..index]
+            .iter()
+            .map(Payload::size)
+            .sum::<usize>()
+    }
+
+    ///
+    /// Returns the variant full name, e.g. `Option::Some`.
+    ///
+    pub fn variant_name(&self, index: usize) -> String {
+        format!("{}::{}", self.identifier, self.names[index])
+    }
+
+    ///
+    /// Returns the variant signature, e.g. `Option::Some(u8)`.
+    ///
+    pub fn variant_signature(&self, index: usize) -> String {
+        format!("{}{}", self.variant_name(index), self.payloads[index])
+    }
+
+    ///
+    /// Returns the structure type describing the variant named fields, which is used to validate
+    /// the variant literals like `Shape::Rectangle { width: 4, height: 2 }`.
+    ///
+    pub fn structure(&self, index: usize) -> Option<StructureType> {
+        match self.payloads[index] {
+            Payload::Structure(ref fields) => Some(StructureType::new(
+                self.location,
+                self.variant_name(index),
+                self.type_id,
+                fields.to_owned(),
+                None,
+                None,
+                self.scope.clone(),
+            )),
+            _ => None,
+        }
+    }
 }
 
 impl PartialEq<Self> for Enumeration {
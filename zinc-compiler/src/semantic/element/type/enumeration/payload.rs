@@ -0,0 +1,137 @@
+//!
+//! The semantic analyzer enumeration variant payload.
+//!
+
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+use zinc_syntax::VariantPayload as SyntaxPayload;
+
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error;
+use crate::semantic::scope::Scope;
+
+///
+/// The enumeration variant payload, that is, the data carried by the variant.
+///
+#[derive(Debug, Clone)]
+pub enum Payload {
+    /// The variant without data.
+    Unit,
+    /// The variant with unnamed fields.
+    Tuple(Vec<Type>),
+    /// The variant with named fields.
+    Structure(Vec<(String, Type)>),
+}
+
+impl Payload {
+    ///
+    /// Resolves the syntax payload types in `scope`.
+    ///
+    /// `r#type` is the enumeration type name, which is used in error messages.
+    ///
+    pub fn try_from_syntax(
+        payload: SyntaxPayload,
+        r#type: &str,
+        scope: Rc<RefCell<Scope>>,
+    ) -> Result<Self, Error> {
+        let payload = match payload {
+            SyntaxPayload::Unit => Self::Unit,
+            SyntaxPayload::Tuple(types) => {
+                let mut result = Vec::with_capacity(types.len());
+                for r#type in types.into_iter() {
+                    let location = r#type.location;
+                    let r#type = Type::try_from_syntax(r#type, scope.clone())?;
+                    if !r#type.is_instantiatable(false) {
+                        return Err(Error::TypeInstantiationForbidden {
+                            location,
+                            found: r#type.to_string(),
+                        });
+                    }
+                    result.push(r#type);
+                }
+                Self::Tuple(result)
+            }
+            SyntaxPayload::Structure(fields) => {
+                let mut result: Vec<(String, Type)> = Vec::with_capacity(fields.len());
+                for field in fields.into_iter() {
+                    if result
+                        .iter()
+                        .any(|(name, _type)| name == &field.identifier.name)
+                    {
+                        return Err(Error::TypeDuplicateField {
+                            location: field.location,
+                            r#type: r#type.to_owned(),
+                            field_name: field.identifier.name,
+                        });
+                    }
+
+                    let location = field.r#type.location;
+                    let field_type = Type::try_from_syntax(field.r#type, scope.clone())?;
+                    if !field_type.is_instantiatable(false) {
+                        return Err(Error::TypeInstantiationForbidden {
+                            location,
+                            found: field_type.to_string(),
+                        });
+                    }
+                    result.push((field.identifier.name, field_type));
+                }
+                Self::Structure(result)
+            }
+        };
+
+        Ok(payload)
+    }
+
+    ///
+    /// Returns the ordered payload field types.
+    ///
+    pub fn types(&self) -> Vec<&Type> {
+        match self {
+            Self::Unit => vec![],
+            Self::Tuple(types) => types.iter().collect(),
+            Self::Structure(fields) => fields.iter().map(|(_name, r#type)| r#type).collect(),
+        }
+    }
+
+    ///
+    /// The payload size in the abstract data stack.
+    ///
+    pub fn size(&self) -> usize {
+        self.types().into_iter().map(|r#type| r#type.size()).sum()
+    }
+
+    ///
+    /// Checks if the payload has no fields.
+    ///
+    pub fn is_unit(&self) -> bool {
+        matches!(self, Self::Unit)
+    }
+}
+
+impl fmt::Display for Payload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Unit => Ok(()),
+            Self::Tuple(types) => write!(
+                f,
+                "({})",
+                types
+                    .iter()
+                    .map(|r#type| r#type.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            Self::Structure(fields) => write!(
+                f,
+                " {{ {} }}",
+                fields
+                    .iter()
+                    .map(|(name, r#type)| format!("{}: {}", name, r#type))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+        }
+    }
+}
@@ -34,3 +34,74 @@ fn main() -> u8 {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn error_variant_expected_unit() {
+    let input = r#"
+enum Option {
+    Some(u8),
+    None,
+}
+
+fn main() {
+    let value = Option::Some;
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::TypeVariantExpectedUnit {
+        location: Location::test(8, 17),
+        variant: "Option::Some(u8)".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_variant_expected_tuple() {
+    let input = r#"
+enum Shape {
+    Rect { width: u8, height: u8 },
+    Empty,
+}
+
+fn main() {
+    let value = Shape::Empty(42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::TypeVariantExpectedTuple {
+        location: Location::test(8, 17),
+        variant: "Shape::Empty".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_variant_expected_structure() {
+    let input = r#"
+enum Option {
+    Some(u8),
+    None,
+}
+
+fn main() {
+    let value = Option::Some { value: 42 };
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::TypeVariantExpectedStructure {
+            location: Location::test(8, 17),
+            variant: "Option::Some(u8)".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -181,7 +181,14 @@ impl Function {
     ) -> Result<Constant, Error> {
         let location = self.location;
 
-        let scope = Scope::new_child(self.identifier, ScopeType::Function, scope);
+        let scope = Scope::new_child(
+            self.identifier.clone(),
+            ScopeType::Function {
+                identifier: self.identifier,
+                return_type: None,
+            },
+            scope,
+        );
 
         for (name, constant) in arguments.into_iter() {
             Scope::insert_item(
@@ -0,0 +1,167 @@
+//!
+//! The semantic analyzer generic function element.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::fmt;
+use std::rc::Rc;
+
+use zinc_lexical::Location;
+
+use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::r#type::function::Function as FunctionType;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error;
+use crate::semantic::generics::argument::Argument;
+use crate::semantic::generics::Template;
+use crate::semantic::scope::item::r#type::statement::Statement as TypeStatementVariant;
+
+///
+/// The semantic analyzer generic function element.
+///
+/// The function is instantiated as a runtime one for each set of the generic arguments
+/// inferred from the actual argument types.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    /// The location where the function is declared.
+    pub location: Location,
+    /// The function identifier.
+    pub identifier: String,
+    /// The function template.
+    pub template: Rc<Template>,
+}
+
+impl Function {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(location: Location, identifier: String, template: Rc<Template>) -> Self {
+        Self {
+            location,
+            identifier,
+            template,
+        }
+    }
+
+    ///
+    /// Infers the generic arguments from the `argument_list` and returns the function instance.
+    ///
+    pub fn instantiate(
+        self,
+        location: Location,
+        argument_list: &ArgumentList,
+    ) -> Result<FunctionType, Error> {
+        let bindings = match self.template.statement {
+            TypeStatementVariant::Fn(ref statement) => &statement.argument_bindings,
+            _ => panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        };
+
+        if argument_list.arguments.len() != bindings.len() {
+            return Err(Error::FunctionArgumentCount {
+                location: self.location,
+                function: self.identifier,
+                expected: bindings.len(),
+                found: argument_list.arguments.len(),
+                reference: Some(argument_list.location),
+            });
+        }
+
+        let mut pairs = Vec::with_capacity(bindings.len());
+        for (index, (binding, element)) in bindings
+            .iter()
+            .zip(argument_list.arguments.iter())
+            .enumerate()
+        {
+            let r#type = match element {
+                Element::Value(value) => value.r#type(),
+                Element::Constant(constant) => constant.r#type(),
+                Element::Reference(place) => place.r#type.to_owned(),
+                element => {
+                    return Err(Error::FunctionArgumentNotEvaluable {
+                        location: element
+                            .location()
+                            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                        function: self.identifier,
+                        position: index + 1,
+                        found: element.to_string(),
+                    })
+                }
+            };
+
+            if let Some(ref pattern) = binding.r#type {
+                pairs.push((pattern, r#type));
+            }
+        }
+
+        let arguments = self.template.infer(location, pairs)?;
+        match self.template.instantiate(location, arguments)? {
+            Type::Function(function) => Ok(function),
+            _ => panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        }
+    }
+
+    ///
+    /// Checks the explicit generic `arguments` like `identity::<u8>` and returns the function
+    /// instance.
+    ///
+    pub fn instantiate_explicit(
+        self,
+        location: Location,
+        arguments: Vec<Argument>,
+    ) -> Result<FunctionType, Error> {
+        if arguments.len() != self.template.parameters.len() {
+            return Err(Error::TypeInvalidGenericsNumber {
+                location,
+                r#type: self.identifier,
+                expected: self.template.parameters.len(),
+                found: arguments.len(),
+            });
+        }
+
+        for (parameter, argument) in self.template.parameters.iter().zip(arguments.iter()) {
+            match (parameter.r#type.as_ref(), argument) {
+                (None, Argument::Constant(value)) => {
+                    return Err(Error::TypeGenericExpectedType {
+                        location,
+                        r#type: self.identifier,
+                        found: value.to_string(),
+                    })
+                }
+                (Some(_type), Argument::Type(r#type)) => {
+                    return Err(Error::TypeGenericExpectedConstant {
+                        location,
+                        r#type: self.identifier,
+                        found: r#type.to_string(),
+                    })
+                }
+                _ => {}
+            }
+        }
+
+        match self.template.instantiate(location, arguments)? {
+            Type::Function(function) => Ok(function),
+            _ => panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        }
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "fn {}<{}>",
+            self.identifier,
+            self.template
+                .parameters
+                .iter()
+                .map(|parameter| parameter.to_string())
+                .collect::<Vec<String>>()
+                .join(", "),
+        )
+    }
+}
@@ -0,0 +1,346 @@
+//!
+//! The generic function tests.
+//!
+
+use num::BigInt;
+
+use zinc_lexical::Location;
+
+use crate::error::Error;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error as SemanticError;
+
+#[test]
+fn ok_type_parameter() {
+    let input = r#"
+fn identity<T>(value: T) -> T {
+    value
+}
+
+fn main() -> (u8, bool) {
+    (identity(42), identity(true))
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_constant_parameter() {
+    let input = r#"
+fn sum<const N: u8>(values: [u8; N]) -> u8 {
+    let mut result = 0;
+    for i in 0..N {
+        result += values[i];
+    }
+    result
+}
+
+fn main() -> u8 {
+    sum([1, 2, 3]) + sum([1, 2, 3, 4, 5])
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_nested_parameter() {
+    let input = r#"
+fn first<T, const N: u8>(values: [(T, bool); N]) -> T {
+    values[0].0
+}
+
+fn main() -> field {
+    first([(1 as field, true), (2 as field, false)])
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_explicit_arguments() {
+    let input = r#"
+fn zero<T>() -> T {
+    0 as T
+}
+
+fn sum<const N: u8>(values: [u8; N]) -> u8 {
+    let mut result = 0;
+    for i in 0..N {
+        result += values[i];
+    }
+    result
+}
+
+fn main() -> (u8, field, u8) {
+    (zero::<u8>(), zero::<field>(), sum::<3>([1, 2, 3]))
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_parameter_not_inferred() {
+    let input = r#"
+fn default<T>(value: u8) -> T {
+    value
+}
+
+fn main() -> u8 {
+    default(42)
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::TypeGenericParameterNotInferred {
+            location: Location::test(7, 12),
+            r#type: "default".to_owned(),
+            name: "T".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_argument_type() {
+    let input = r#"
+fn both<T>(a: T, b: T) -> T {
+    a
+}
+
+fn main() -> u8 {
+    both(42, true)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(7, 14),
+        function: "both<u8>".to_owned(),
+        name: "b".to_owned(),
+        position: 2,
+        expected: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+        found: Type::boolean(None).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_argument_count() {
+    let input = r#"
+fn identity<T>(value: T) -> T {
+    value
+}
+
+fn main() -> u8 {
+    identity(1, 2)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
+        location: Location::test(7, 5),
+        function: "identity".to_owned(),
+        expected: 1,
+        found: 2,
+        reference: Some(Location::test(7, 13)),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_constant_parameter_overflow() {
+    let input = r#"
+fn length<const N: u8>(values: [bool; N]) -> u8 {
+    N
+}
+
+fn main() -> u8 {
+    length([false; 300])
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::OperatorCastingOverflow {
+        location: Location::test(7, 11),
+        value: BigInt::from(300),
+        r#type: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_unavailable_constant() {
+    let input = r#"
+const fn identity<T>(value: T) -> T {
+    value
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionGenericsUnavailable {
+            location: Location::test(2, 1),
+            function: "identity".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_unavailable_method() {
+    let input = r#"
+struct Data {
+    value: u8,
+}
+
+impl Data {
+    pub fn identity<T>(value: T) -> T {
+        value
+    }
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionGenericsUnavailable {
+            location: Location::test(7, 5),
+            function: "identity".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_unavailable_main() {
+    let input = r#"
+fn main<T>() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionGenericsUnavailable {
+            location: Location::test(2, 1),
+            function: "main".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_parameter_unused() {
+    let input = r#"
+fn default<T>(value: u8) -> u8 {
+    value
+}
+
+fn main() -> u8 {
+    default::<u8>(42)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::TypeGenericParameterUnused {
+        location: Location::test(2, 12),
+        r#type: "default".to_owned(),
+        name: "T".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_explicit_arguments_number() {
+    let input = r#"
+fn identity<T>(value: T) -> T {
+    value
+}
+
+fn main() -> u8 {
+    identity::<u8, bool>(42)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::TypeInvalidGenericsNumber {
+        location: Location::test(7, 5),
+        r#type: "identity".to_owned(),
+        expected: 1,
+        found: 2,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_explicit_argument_expected_type() {
+    let input = r#"
+fn identity<T>(value: T) -> T {
+    value
+}
+
+fn main() -> u8 {
+    identity::<4>(42)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::TypeGenericExpectedType {
+        location: Location::test(7, 5),
+        r#type: "identity".to_owned(),
+        found: "4".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_explicit_argument_type() {
+    let input = r#"
+fn identity<T>(value: T) -> T {
+    value
+}
+
+fn main() -> u8 {
+    identity::<bool>(42)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(7, 22),
+        function: "identity<bool>".to_owned(),
+        name: "value".to_owned(),
+        position: 1,
+        expected: Type::boolean(None).to_string(),
+        found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,123 @@
+//!
+//! The `std::convert::from_str_bytes` intrinsic function tests.
+//!
+
+use zinc_lexical::Location;
+
+use crate::error::Error;
+use crate::semantic::element::r#type::function::intrinsic::convert_from_str_bytes::Function as FromStrBytesFunction;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error as SemanticError;
+
+#[test]
+fn ok() {
+    let input = r#"
+const PREFIX: [u8; 4] = std::convert::from_str_bytes("zinc");
+
+fn main() -> [u8; 4] {
+    PREFIX
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_non_ascii() {
+    let input = r#"
+const PREFIX: [u8; 5] = std::convert::from_str_bytes("héé");
+
+fn main() -> [u8; 5] {
+    PREFIX
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_argument_count_lesser() {
+    let input = r#"
+fn main() {
+    let bytes = std::convert::from_str_bytes();
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
+        location: Location::test(3, 17),
+        function: FromStrBytesFunction::IDENTIFIER.to_owned(),
+        expected: FromStrBytesFunction::ARGUMENT_COUNT,
+        found: FromStrBytesFunction::ARGUMENT_COUNT - 1,
+        reference: None,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_argument_count_greater() {
+    let input = r#"
+fn main() {
+    let bytes = std::convert::from_str_bytes("zinc", "zinc");
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
+        location: Location::test(3, 17),
+        function: FromStrBytesFunction::IDENTIFIER.to_owned(),
+        expected: FromStrBytesFunction::ARGUMENT_COUNT,
+        found: FromStrBytesFunction::ARGUMENT_COUNT + 1,
+        reference: None,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_argument_1_string_expected_string() {
+    let input = r#"
+fn main() {
+    let bytes = std::convert::from_str_bytes(42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(3, 46),
+        function: FromStrBytesFunction::IDENTIFIER.to_owned(),
+        name: "string".to_owned(),
+        position: FromStrBytesFunction::ARGUMENT_INDEX_STRING + 1,
+        expected: Type::string(None).to_string(),
+        found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_argument_1_string_expected_constant() {
+    let input = r#"
+fn main(value: u8) {
+    let bytes = std::convert::from_str_bytes(value);
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionArgumentConstantness {
+            location: Location::test(3, 46),
+            function: FromStrBytesFunction::IDENTIFIER.to_owned(),
+            name: "string".to_owned(),
+            position: FromStrBytesFunction::ARGUMENT_INDEX_STRING + 1,
+            found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
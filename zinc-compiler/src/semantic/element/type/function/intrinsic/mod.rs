@@ -7,9 +7,11 @@ mod tests;
 
 pub mod contract_fetch;
 pub mod contract_transfer;
+pub mod convert_from_str_bytes;
 pub mod debug;
 pub mod require;
 pub mod stdlib;
+pub mod vec;
 
 use std::fmt;
 
@@ -20,6 +22,7 @@ use crate::semantic::element::r#type::contract::Contract as ContractType;
 
 use self::contract_fetch::Function as ContractFetchFunction;
 use self::contract_transfer::Function as ContractTransferFunction;
+use self::convert_from_str_bytes::Function as ConvertFromStrBytesFunction;
 use self::debug::Function as DebugFunction;
 use self::require::Function as RequireFunction;
 use self::stdlib::array_pad::Function as StdArrayPadFunction;
@@ -33,11 +36,15 @@ use self::stdlib::convert_from_bits_field::Function as StdConvertFromBitsFieldFu
 use self::stdlib::convert_from_bits_signed::Function as StdConvertFromBitsSignedFunction;
 use self::stdlib::convert_from_bits_unsigned::Function as StdConvertFromBitsUnsignedFunction;
 use self::stdlib::convert_to_bits::Function as StdConvertToBitsFunction;
+use self::stdlib::crypto_blake2s::Function as StdCryptoBlake2sFunction;
 use self::stdlib::crypto_pedersen::Function as StdConvertPedersenFunction;
+use self::stdlib::crypto_poseidon::Function as StdCryptoPoseidonFunction;
+use self::stdlib::crypto_rescue::Function as StdCryptoRescueFunction;
 use self::stdlib::crypto_schnorr_signature_verify::Function as StdCryptoSchnorrSignatureVerifyFunction;
 use self::stdlib::crypto_sha256::Function as StdCryptoSha256Function;
 use self::stdlib::ff_invert::Function as StdFfInvertFunction;
 use self::stdlib::Function as StandardLibraryFunction;
+use self::vec::Function as VecFunction;
 
 ///
 /// The semantic analyzer intrinsic function element.
@@ -52,8 +59,12 @@ pub enum Function {
     ContractFetch(ContractFetchFunction),
     /// The `<Contract>::transfer(...)` function. See the inner element description.
     ContractTransfer(ContractTransferFunction),
+    /// The `std::convert::from_str_bytes(...)` function. See the inner element description.
+    ConvertFromStrBytes(ConvertFromStrBytesFunction),
     /// The standard library function. See the inner element description.
     StandardLibrary(StandardLibraryFunction),
+    /// The `std::collections::Vec` function. See the inner element description.
+    Vec(VecFunction),
 }
 
 impl Function {
@@ -85,6 +96,20 @@ impl Function {
         Self::ContractFetch(ContractFetchFunction::new(contract_type))
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn convert_from_str_bytes() -> Self {
+        Self::ConvertFromStrBytes(ConvertFromStrBytesFunction::default())
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn vec(function: VecFunction) -> Self {
+        Self::Vec(function)
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -101,6 +126,15 @@ impl Function {
                     StdCryptoSchnorrSignatureVerifyFunction::default(),
                 ))
             }
+            LibraryFunctionIdentifier::CryptoBlake2s => Self::StandardLibrary(
+                StandardLibraryFunction::CryptoBlake2s(StdCryptoBlake2sFunction::default()),
+            ),
+            LibraryFunctionIdentifier::CryptoPoseidon => Self::StandardLibrary(
+                StandardLibraryFunction::CryptoPoseidon(StdCryptoPoseidonFunction::default()),
+            ),
+            LibraryFunctionIdentifier::CryptoRescue => Self::StandardLibrary(
+                StandardLibraryFunction::CryptoRescue(StdCryptoRescueFunction::default()),
+            ),
 
             LibraryFunctionIdentifier::ConvertToBits => Self::StandardLibrary(
                 StandardLibraryFunction::ConvertToBits(StdConvertToBitsFunction::default()),
@@ -178,7 +212,9 @@ impl Function {
             Self::Debug(_) => false,
             Self::ContractFetch(_) => false,
             Self::ContractTransfer(_) => true,
+            Self::ConvertFromStrBytes(_) => false,
             Self::StandardLibrary(inner) => inner.is_mutable(),
+            Self::Vec(inner) => inner.is_mutable(),
         }
     }
 
@@ -191,7 +227,9 @@ impl Function {
             Self::Debug(inner) => inner.identifier,
             Self::ContractFetch(inner) => inner.identifier,
             Self::ContractTransfer(inner) => inner.identifier,
+            Self::ConvertFromStrBytes(inner) => inner.identifier,
             Self::StandardLibrary(inner) => inner.identifier(),
+            Self::Vec(inner) => inner.identifier(),
         }
     }
 
@@ -204,7 +242,9 @@ impl Function {
             Self::Debug(inner) => inner.location = Some(location),
             Self::ContractFetch(inner) => inner.location = Some(location),
             Self::ContractTransfer(inner) => inner.location = Some(location),
+            Self::ConvertFromStrBytes(inner) => inner.location = Some(location),
             Self::StandardLibrary(inner) => inner.set_location(location),
+            Self::Vec(inner) => inner.set_location(location),
         }
     }
 
@@ -217,7 +257,9 @@ impl Function {
             Self::Debug(inner) => inner.location,
             Self::ContractFetch(inner) => inner.location,
             Self::ContractTransfer(inner) => inner.location,
+            Self::ConvertFromStrBytes(inner) => inner.location,
             Self::StandardLibrary(inner) => inner.location(),
+            Self::Vec(inner) => inner.location(),
         }
     }
 }
@@ -229,7 +271,9 @@ impl fmt::Display for Function {
             Self::Debug(inner) => write!(f, "{}", inner),
             Self::ContractFetch(inner) => write!(f, "{}", inner),
             Self::ContractTransfer(inner) => write!(f, "{}", inner),
+            Self::ConvertFromStrBytes(inner) => write!(f, "std::{}", inner),
             Self::StandardLibrary(inner) => write!(f, "std::{}", inner),
+            Self::Vec(inner) => write!(f, "std::{}", inner),
         }
     }
 }
@@ -16,7 +16,10 @@ pub mod convert_from_bits_field;
 pub mod convert_from_bits_signed;
 pub mod convert_from_bits_unsigned;
 pub mod convert_to_bits;
+pub mod crypto_blake2s;
 pub mod crypto_pedersen;
+pub mod crypto_poseidon;
+pub mod crypto_rescue;
 pub mod crypto_schnorr_signature_verify;
 pub mod crypto_sha256;
 pub mod ff_invert;
@@ -41,7 +44,10 @@ use self::convert_from_bits_field::Function as FromBitsFieldFunction;
 use self::convert_from_bits_signed::Function as FromBitsSignedFunction;
 use self::convert_from_bits_unsigned::Function as FromBitsUnsignedFunction;
 use self::convert_to_bits::Function as ToBitsFunction;
+use self::crypto_blake2s::Function as Blake2sFunction;
 use self::crypto_pedersen::Function as PedersenFunction;
+use self::crypto_poseidon::Function as PoseidonFunction;
+use self::crypto_rescue::Function as RescueFunction;
 use self::crypto_schnorr_signature_verify::Function as SchnorrSignatureVerifyFunction;
 use self::crypto_sha256::Function as Sha256Function;
 use self::ff_invert::Function as FfInvertFunction;
@@ -57,6 +63,12 @@ pub enum Function {
     CryptoPedersen(PedersenFunction),
     /// The `std::crypto::schnorr::Signature::verify` function variant.
     CryptoSchnorrSignatureVerify(SchnorrSignatureVerifyFunction),
+    /// The `std::crypto::blake2s` function variant.
+    CryptoBlake2s(Blake2sFunction),
+    /// The `std::crypto::poseidon` function variant.
+    CryptoPoseidon(PoseidonFunction),
+    /// The `std::crypto::rescue` function variant.
+    CryptoRescue(RescueFunction),
 
     /// The `std::convert::to_bits` function variant.
     ConvertToBits(ToBitsFunction),
@@ -96,6 +108,9 @@ impl Function {
             Self::CryptoSha256(inner) => inner.call(location, argument_list),
             Self::CryptoPedersen(inner) => inner.call(location, argument_list),
             Self::CryptoSchnorrSignatureVerify(inner) => inner.call(location, argument_list),
+            Self::CryptoBlake2s(inner) => inner.call(location, argument_list),
+            Self::CryptoPoseidon(inner) => inner.call(location, argument_list),
+            Self::CryptoRescue(inner) => inner.call(location, argument_list),
 
             Self::ConvertToBits(inner) => inner.call(location, argument_list),
             Self::ConvertFromBitsUnsigned(inner) => inner.call(location, argument_list),
@@ -123,6 +138,9 @@ impl Function {
             Self::CryptoSha256(inner) => inner.identifier,
             Self::CryptoPedersen(inner) => inner.identifier,
             Self::CryptoSchnorrSignatureVerify(inner) => inner.identifier,
+            Self::CryptoBlake2s(inner) => inner.identifier,
+            Self::CryptoPoseidon(inner) => inner.identifier,
+            Self::CryptoRescue(inner) => inner.identifier,
 
             Self::ConvertToBits(inner) => inner.identifier,
             Self::ConvertFromBitsUnsigned(inner) => inner.identifier,
@@ -150,6 +168,9 @@ impl Function {
             Self::CryptoSha256(inner) => inner.library_identifier,
             Self::CryptoPedersen(inner) => inner.library_identifier,
             Self::CryptoSchnorrSignatureVerify(inner) => inner.library_identifier,
+            Self::CryptoBlake2s(inner) => inner.library_identifier,
+            Self::CryptoPoseidon(inner) => inner.library_identifier,
+            Self::CryptoRescue(inner) => inner.library_identifier,
 
             Self::ConvertToBits(inner) => inner.library_identifier,
             Self::ConvertFromBitsUnsigned(inner) => inner.library_identifier,
@@ -177,6 +198,9 @@ impl Function {
             Self::CryptoSha256(_) => false,
             Self::CryptoPedersen(_) => false,
             Self::CryptoSchnorrSignatureVerify(_) => false,
+            Self::CryptoBlake2s(_) => false,
+            Self::CryptoPoseidon(_) => false,
+            Self::CryptoRescue(_) => false,
 
             Self::ConvertToBits(_) => false,
             Self::ConvertFromBitsUnsigned(_) => false,
@@ -204,6 +228,9 @@ impl Function {
             Self::CryptoSha256(inner) => inner.location = Some(location),
             Self::CryptoPedersen(inner) => inner.location = Some(location),
             Self::CryptoSchnorrSignatureVerify(inner) => inner.location = Some(location),
+            Self::CryptoBlake2s(inner) => inner.location = Some(location),
+            Self::CryptoPoseidon(inner) => inner.location = Some(location),
+            Self::CryptoRescue(inner) => inner.location = Some(location),
 
             Self::ConvertToBits(inner) => inner.location = Some(location),
             Self::ConvertFromBitsUnsigned(inner) => inner.location = Some(location),
@@ -231,6 +258,9 @@ impl Function {
             Self::CryptoSha256(inner) => inner.location,
             Self::CryptoPedersen(inner) => inner.location,
             Self::CryptoSchnorrSignatureVerify(inner) => inner.location,
+            Self::CryptoBlake2s(inner) => inner.location,
+            Self::CryptoPoseidon(inner) => inner.location,
+            Self::CryptoRescue(inner) => inner.location,
 
             Self::ConvertToBits(inner) => inner.location,
             Self::ConvertFromBitsUnsigned(inner) => inner.location,
@@ -257,6 +287,9 @@ impl fmt::Display for Function {
             Self::CryptoSha256(inner) => write!(f, "{}", inner),
             Self::CryptoPedersen(inner) => write!(f, "{}", inner),
             Self::CryptoSchnorrSignatureVerify(inner) => write!(f, "{}", inner),
+            Self::CryptoBlake2s(inner) => write!(f, "{}", inner),
+            Self::CryptoPoseidon(inner) => write!(f, "{}", inner),
+            Self::CryptoRescue(inner) => write!(f, "{}", inner),
 
             Self::ConvertToBits(inner) => write!(f, "{}", inner),
             Self::ConvertFromBitsUnsigned(inner) => write!(f, "{}", inner),
@@ -22,7 +22,10 @@ use crate::semantic::element::r#type::function::intrinsic::stdlib::convert_from_
 use crate::semantic::element::r#type::function::intrinsic::stdlib::convert_from_bits_signed::Function as ConvertFromBitsSignedFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::convert_from_bits_unsigned::Function as ConvertFromBitsUnsignedFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::convert_to_bits::Function as ConvertToBitsFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::crypto_blake2s::Function as CryptoBlake2sFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::crypto_pedersen::Function as CryptoPedersenFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::crypto_poseidon::Function as CryptoPoseidonFunction;
+use crate::semantic::element::r#type::function::intrinsic::stdlib::crypto_rescue::Function as CryptoRescueFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::crypto_schnorr_signature_verify::Function as CryptoSchnorrSignatureVerifyFunction;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::crypto_sha256::Function as CryptoSha256Function;
 use crate::semantic::element::r#type::function::intrinsic::stdlib::ff_invert::Function as FfInvertFunction;
@@ -311,6 +314,286 @@ fn main() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_crypto_blake2s_argument_count_lesser() {
+    let input = r#"
+fn main() {
+    std::crypto::blake2s();
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
+        location: Location::test(3, 5),
+        function: CryptoBlake2sFunction::IDENTIFIER.to_owned(),
+        expected: CryptoBlake2sFunction::ARGUMENT_COUNT,
+        found: CryptoBlake2sFunction::ARGUMENT_COUNT - 1,
+        reference: None,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_crypto_blake2s_argument_count_greater() {
+    let input = r#"
+fn main() {
+    std::crypto::blake2s([true; 8], 42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
+        location: Location::test(3, 5),
+        function: CryptoBlake2sFunction::IDENTIFIER.to_owned(),
+        expected: CryptoBlake2sFunction::ARGUMENT_COUNT,
+        found: CryptoBlake2sFunction::ARGUMENT_COUNT + 1,
+        reference: None,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_crypto_blake2s_argument_1_preimage_expected_bit_array() {
+    let input = r#"
+fn main() {
+    std::crypto::blake2s(42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(3, 26),
+        function: CryptoBlake2sFunction::IDENTIFIER.to_owned(),
+        name: "preimage".to_owned(),
+        position: CryptoBlake2sFunction::ARGUMENT_INDEX_PREIMAGE + 1,
+        expected: format!("[bool; N], N > 0, N % {} == 0", zinc_const::bitlength::BYTE),
+        found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_crypto_blake2s_argument_1_preimage_expected_bit_array_not_empty() {
+    let input = r#"
+fn main() {
+    std::crypto::blake2s([true; 0]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(3, 26),
+        function: CryptoBlake2sFunction::IDENTIFIER.to_owned(),
+        name: "preimage".to_owned(),
+        position: CryptoBlake2sFunction::ARGUMENT_INDEX_PREIMAGE + 1,
+        expected: format!("[bool; N], N > 0, N % {} == 0", zinc_const::bitlength::BYTE),
+        found: Type::array(Some(Location::test(3, 26)), Type::boolean(None), 0).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_crypto_blake2s_argument_1_preimage_expected_bit_array_size_multiple_8() {
+    let input = r#"
+fn main() {
+    std::crypto::blake2s([true; 4]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(3, 26),
+        function: CryptoBlake2sFunction::IDENTIFIER.to_owned(),
+        name: "preimage".to_owned(),
+        position: CryptoBlake2sFunction::ARGUMENT_INDEX_PREIMAGE + 1,
+        expected: format!("[bool; N], N > 0, N % {} == 0", zinc_const::bitlength::BYTE),
+        found: Type::array(Some(Location::test(3, 26)), Type::boolean(None), 4).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_crypto_poseidon_argument_count_lesser() {
+    let input = r#"
+fn main() {
+    std::crypto::poseidon();
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
+        location: Location::test(3, 5),
+        function: CryptoPoseidonFunction::IDENTIFIER.to_owned(),
+        expected: CryptoPoseidonFunction::ARGUMENT_COUNT,
+        found: CryptoPoseidonFunction::ARGUMENT_COUNT - 1,
+        reference: None,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_crypto_poseidon_argument_count_greater() {
+    let input = r#"
+fn main() {
+    std::crypto::poseidon([0 as field; 2], 42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
+        location: Location::test(3, 5),
+        function: CryptoPoseidonFunction::IDENTIFIER.to_owned(),
+        expected: CryptoPoseidonFunction::ARGUMENT_COUNT,
+        found: CryptoPoseidonFunction::ARGUMENT_COUNT + 1,
+        reference: None,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_crypto_poseidon_argument_1_preimage_expected_field_array() {
+    let input = r#"
+fn main() {
+    std::crypto::poseidon([true; 2]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(3, 27),
+        function: CryptoPoseidonFunction::IDENTIFIER.to_owned(),
+        name: "preimage".to_owned(),
+        position: CryptoPoseidonFunction::ARGUMENT_INDEX_PREIMAGE + 1,
+        expected: "[field; N], N > 0".to_owned(),
+        found: Type::array(Some(Location::test(3, 27)), Type::boolean(None), 2).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_crypto_poseidon_argument_1_preimage_expected_field_array_not_empty() {
+    let input = r#"
+fn main() {
+    std::crypto::poseidon([0 as field; 0]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(3, 27),
+        function: CryptoPoseidonFunction::IDENTIFIER.to_owned(),
+        name: "preimage".to_owned(),
+        position: CryptoPoseidonFunction::ARGUMENT_INDEX_PREIMAGE + 1,
+        expected: "[field; N], N > 0".to_owned(),
+        found: Type::array(Some(Location::test(3, 27)), Type::field(None), 0).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_crypto_rescue_argument_count_lesser() {
+    let input = r#"
+fn main() {
+    std::crypto::rescue();
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
+        location: Location::test(3, 5),
+        function: CryptoRescueFunction::IDENTIFIER.to_owned(),
+        expected: CryptoRescueFunction::ARGUMENT_COUNT,
+        found: CryptoRescueFunction::ARGUMENT_COUNT - 1,
+        reference: None,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_crypto_rescue_argument_count_greater() {
+    let input = r#"
+fn main() {
+    std::crypto::rescue([0 as field; 2], 42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
+        location: Location::test(3, 5),
+        function: CryptoRescueFunction::IDENTIFIER.to_owned(),
+        expected: CryptoRescueFunction::ARGUMENT_COUNT,
+        found: CryptoRescueFunction::ARGUMENT_COUNT + 1,
+        reference: None,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_crypto_rescue_argument_1_preimage_expected_field_array() {
+    let input = r#"
+fn main() {
+    std::crypto::rescue([true; 2]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(3, 25),
+        function: CryptoRescueFunction::IDENTIFIER.to_owned(),
+        name: "preimage".to_owned(),
+        position: CryptoRescueFunction::ARGUMENT_INDEX_PREIMAGE + 1,
+        expected: "[field; N], N > 0".to_owned(),
+        found: Type::array(Some(Location::test(3, 25)), Type::boolean(None), 2).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_crypto_rescue_argument_1_preimage_expected_field_array_not_empty() {
+    let input = r#"
+fn main() {
+    std::crypto::rescue([0 as field; 0]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(3, 25),
+        function: CryptoRescueFunction::IDENTIFIER.to_owned(),
+        name: "preimage".to_owned(),
+        position: CryptoRescueFunction::ARGUMENT_INDEX_PREIMAGE + 1,
+        expected: "[field; N], N > 0".to_owned(),
+        found: Type::array(Some(Location::test(3, 25)), Type::field(None), 0).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_crypto_schnorr_signature_verify_argument_count_lesser() {
     let input = r#"
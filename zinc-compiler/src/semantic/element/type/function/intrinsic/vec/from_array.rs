@@ -0,0 +1,170 @@
+//!
+//! The semantic analyzer `std::collections::Vec::from_array` intrinsic function element.
+//!
+
+use std::fmt;
+use std::ops::Deref;
+
+use zinc_lexical::Location;
+
+use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error;
+
+///
+/// The semantic analyzer `std::collections::Vec::from_array` intrinsic function element.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    /// The location where the function is called.
+    pub location: Option<Location>,
+    /// The function identifier.
+    pub identifier: &'static str,
+}
+
+impl Default for Function {
+    fn default() -> Self {
+        Self {
+            location: None,
+            identifier: Self::IDENTIFIER,
+        }
+    }
+}
+
+impl Function {
+    /// The function identifier.
+    pub const IDENTIFIER: &'static str = "from_array";
+
+    /// The position of the `array` argument in the function argument list.
+    pub const ARGUMENT_INDEX_ARRAY: usize = 0;
+
+    /// The position of the `length` argument in the function argument list.
+    pub const ARGUMENT_INDEX_LENGTH: usize = 1;
+
+    /// The expected number of the function arguments.
+    pub const ARGUMENT_COUNT: usize = 2;
+
+    ///
+    /// Calls the function with the `argument_list`, validating the call.
+    ///
+    /// Returns the vector element type, the vector capacity, and whether the length must be
+    /// checked at runtime, which is not required for constant lengths.
+    ///
+    pub fn call(
+        self,
+        location: Location,
+        argument_list: ArgumentList,
+    ) -> Result<(Type, usize, bool), Error> {
+        let mut actual_params = Vec::with_capacity(argument_list.arguments.len());
+        for (index, element) in argument_list.arguments.into_iter().enumerate() {
+            let location = element.location();
+
+            let (r#type, number) = match element {
+                Element::Value(value) => (value.r#type(), None),
+                Element::Constant(Constant::Integer(integer)) => {
+                    let number = integer.to_usize()?;
+                    (integer.r#type(), Some(number))
+                }
+                Element::Constant(constant) => (constant.r#type(), None),
+                element => {
+                    return Err(Error::FunctionArgumentNotEvaluable {
+                        location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                        function: self.identifier.to_owned(),
+                        position: index + 1,
+                        found: element.to_string(),
+                    })
+                }
+            };
+
+            actual_params.push((r#type, number, location));
+        }
+
+        let (element_type, capacity) = match actual_params.get(Self::ARGUMENT_INDEX_ARRAY) {
+            Some((Type::Array(array), _number, _location)) => {
+                (array.r#type.deref().to_owned(), array.size)
+            }
+            Some((r#type, _number, location)) => {
+                return Err(Error::FunctionArgumentType {
+                    location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: "array".to_owned(),
+                    position: Self::ARGUMENT_INDEX_ARRAY + 1,
+                    expected: "[T; N]".to_owned(),
+                    found: r#type.to_string(),
+                })
+            }
+            None => {
+                return Err(Error::FunctionArgumentCount {
+                    location,
+                    function: self.identifier.to_owned(),
+                    expected: Self::ARGUMENT_COUNT,
+                    found: actual_params.len(),
+                    reference: None,
+                })
+            }
+        };
+
+        let is_checked = match actual_params.get(Self::ARGUMENT_INDEX_LENGTH) {
+            Some((Type::IntegerUnsigned { bitlength, .. }, number, location))
+                if *bitlength <= zinc_const::bitlength::INDEX =>
+            {
+                match number {
+                    Some(length) if *length > capacity => {
+                        return Err(Error::FunctionStdlibVecLengthExceedsCapacity {
+                            location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                            length: *length,
+                            capacity,
+                        });
+                    }
+                    Some(_length) => false,
+                    None => true,
+                }
+            }
+            Some((r#type, _number, location)) => {
+                return Err(Error::FunctionArgumentType {
+                    location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: "length".to_owned(),
+                    position: Self::ARGUMENT_INDEX_LENGTH + 1,
+                    expected: Type::integer_unsigned(None, zinc_const::bitlength::INDEX)
+                        .to_string(),
+                    found: r#type.to_string(),
+                })
+            }
+            None => {
+                return Err(Error::FunctionArgumentCount {
+                    location,
+                    function: self.identifier.to_owned(),
+                    expected: Self::ARGUMENT_COUNT,
+                    found: actual_params.len(),
+                    reference: None,
+                })
+            }
+        };
+
+        if actual_params.len() > Self::ARGUMENT_COUNT {
+            return Err(Error::FunctionArgumentCount {
+                location,
+                function: self.identifier.to_owned(),
+                expected: Self::ARGUMENT_COUNT,
+                found: actual_params.len(),
+                reference: None,
+            });
+        }
+
+        Ok((element_type, capacity, is_checked))
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "collections::Vec::{}(array: [T; N], length: u64) -> Vec<T, N>",
+            self.identifier
+        )
+    }
+}
@@ -0,0 +1,102 @@
+//!
+//! The semantic analyzer `std::collections::Vec::len` intrinsic function element.
+//!
+
+use std::fmt;
+
+use zinc_lexical::Keyword;
+use zinc_lexical::Location;
+
+use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error;
+
+///
+/// The semantic analyzer `std::collections::Vec::len` intrinsic function element.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    /// The location where the function is called.
+    pub location: Option<Location>,
+    /// The function identifier.
+    pub identifier: &'static str,
+}
+
+impl Default for Function {
+    fn default() -> Self {
+        Self {
+            location: None,
+            identifier: Self::IDENTIFIER,
+        }
+    }
+}
+
+impl Function {
+    /// The function identifier.
+    pub const IDENTIFIER: &'static str = "len";
+
+    /// The position of the `self` argument in the function argument list.
+    pub const ARGUMENT_INDEX_SELF: usize = 0;
+
+    /// The expected number of the function arguments.
+    pub const ARGUMENT_COUNT: usize = 1;
+
+    ///
+    /// Calls the function with the `argument_list`, validating the call.
+    ///
+    /// Returns the vector instance, which is either a memory place or a value.
+    ///
+    pub fn call(self, location: Location, argument_list: ArgumentList) -> Result<Element, Error> {
+        let found = argument_list.arguments.len();
+        let mut arguments = argument_list.arguments.into_iter();
+
+        let instance = match arguments.next() {
+            Some(Element::Place(place)) if place.r#type.is_vec() => Element::Place(place),
+            Some(Element::Value(value)) if value.r#type().is_vec() => Element::Value(value),
+            Some(element) => {
+                return Err(Error::FunctionArgumentType {
+                    location: element
+                        .location()
+                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: Keyword::SelfLowercase.to_string(),
+                    position: Self::ARGUMENT_INDEX_SELF + 1,
+                    expected: "std::collections::Vec".to_owned(),
+                    found: element.to_string(),
+                })
+            }
+            None => {
+                return Err(Error::FunctionArgumentCount {
+                    location,
+                    function: self.identifier.to_owned(),
+                    expected: Self::ARGUMENT_COUNT,
+                    found,
+                    reference: None,
+                })
+            }
+        };
+
+        if found > Self::ARGUMENT_COUNT {
+            return Err(Error::FunctionArgumentCount {
+                location,
+                function: self.identifier.to_owned(),
+                expected: Self::ARGUMENT_COUNT,
+                found,
+                reference: None,
+            });
+        }
+
+        Ok(instance)
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "collections::Vec<T, N>::{}(self) -> u64",
+            self.identifier
+        )
+    }
+}
@@ -0,0 +1,108 @@
+//!
+//! The semantic analyzer `std::collections::Vec` intrinsic function element.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod from_array;
+pub mod len;
+pub mod new;
+pub mod pop;
+pub mod push;
+
+use std::fmt;
+
+use zinc_lexical::Location;
+
+use self::from_array::Function as FromArrayFunction;
+use self::len::Function as LenFunction;
+use self::new::Function as NewFunction;
+use self::pop::Function as PopFunction;
+use self::push::Function as PushFunction;
+
+///
+/// The semantic analyzer `std::collections::Vec` intrinsic function element.
+///
+/// Unlike the other standard library functions, the vector functions are not implemented in
+/// the VM, but translated into the ordinary data stack and contract storage instructions.
+///
+#[derive(Debug, Clone)]
+pub enum Function {
+    /// The `std::collections::Vec::new` function variant.
+    New(NewFunction),
+    /// The `std::collections::Vec::from_array` function variant.
+    FromArray(FromArrayFunction),
+    /// The `std::collections::Vec::len` function variant.
+    Len(LenFunction),
+    /// The `std::collections::Vec::push` function variant.
+    Push(PushFunction),
+    /// The `std::collections::Vec::pop` function variant.
+    Pop(PopFunction),
+}
+
+impl Function {
+    ///
+    /// Whether the function must be called from mutable context.
+    ///
+    pub fn is_mutable(&self) -> bool {
+        match self {
+            Self::New(_) => false,
+            Self::FromArray(_) => false,
+            Self::Len(_) => false,
+            Self::Push(_) => true,
+            Self::Pop(_) => true,
+        }
+    }
+
+    ///
+    /// Returns the function identifier, which is known at compile time.
+    ///
+    pub fn identifier(&self) -> &'static str {
+        match self {
+            Self::New(inner) => inner.identifier,
+            Self::FromArray(inner) => inner.identifier,
+            Self::Len(inner) => inner.identifier,
+            Self::Push(inner) => inner.identifier,
+            Self::Pop(inner) => inner.identifier,
+        }
+    }
+
+    ///
+    /// Sets the function call location in the code.
+    ///
+    pub fn set_location(&mut self, location: Location) {
+        match self {
+            Self::New(inner) => inner.location = Some(location),
+            Self::FromArray(inner) => inner.location = Some(location),
+            Self::Len(inner) => inner.location = Some(location),
+            Self::Push(inner) => inner.location = Some(location),
+            Self::Pop(inner) => inner.location = Some(location),
+        }
+    }
+
+    ///
+    /// Returns the location of the function call.
+    ///
+    pub fn location(&self) -> Option<Location> {
+        match self {
+            Self::New(inner) => inner.location,
+            Self::FromArray(inner) => inner.location,
+            Self::Len(inner) => inner.location,
+            Self::Push(inner) => inner.location,
+            Self::Pop(inner) => inner.location,
+        }
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::New(inner) => write!(f, "{}", inner),
+            Self::FromArray(inner) => write!(f, "{}", inner),
+            Self::Len(inner) => write!(f, "{}", inner),
+            Self::Push(inner) => write!(f, "{}", inner),
+            Self::Pop(inner) => write!(f, "{}", inner),
+        }
+    }
+}
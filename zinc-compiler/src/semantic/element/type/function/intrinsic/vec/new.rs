@@ -0,0 +1,85 @@
+//!
+//! The semantic analyzer `std::collections::Vec::new` intrinsic function element.
+//!
+
+use std::fmt;
+
+use zinc_lexical::Location;
+
+use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error;
+
+///
+/// The semantic analyzer `std::collections::Vec::new` intrinsic function element.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    /// The location where the function is called.
+    pub location: Option<Location>,
+    /// The function identifier.
+    pub identifier: &'static str,
+    /// The vector type specified as the path base, e.g. `Vec::<u8, 4>` in `Vec::<u8, 4>::new()`.
+    pub r#type: Option<Box<Type>>,
+}
+
+impl Default for Function {
+    fn default() -> Self {
+        Self {
+            location: None,
+            identifier: Self::IDENTIFIER,
+            r#type: None,
+        }
+    }
+}
+
+impl Function {
+    /// The function identifier.
+    pub const IDENTIFIER: &'static str = "new";
+
+    /// The expected number of the function arguments.
+    pub const ARGUMENT_COUNT: usize = 0;
+
+    /// The expected number of the vector generic arguments.
+    pub const GENERICS_COUNT: usize = 2;
+
+    ///
+    /// Calls the function with the `argument_list`, validating the call.
+    ///
+    /// The vector type must be specified as the path base, since there are no arguments to
+    /// infer the element type and capacity from.
+    ///
+    /// Returns the vector type.
+    ///
+    pub fn call(self, location: Location, argument_list: ArgumentList) -> Result<Type, Error> {
+        let found = argument_list.arguments.len();
+        if found > Self::ARGUMENT_COUNT {
+            return Err(Error::FunctionArgumentCount {
+                location,
+                function: self.identifier.to_owned(),
+                expected: Self::ARGUMENT_COUNT,
+                found,
+                reference: None,
+            });
+        }
+
+        match self.r#type {
+            Some(r#type) if r#type.is_vec() => Ok(*r#type),
+            _ => Err(Error::TypeExpectedGenerics {
+                location,
+                r#type: "Vec".to_owned(),
+                expected: Self::GENERICS_COUNT,
+            }),
+        }
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "collections::Vec::<T, N>::{}() -> Vec<T, N>",
+            self.identifier
+        )
+    }
+}
@@ -0,0 +1,152 @@
+//!
+//! The semantic analyzer `std::collections::Vec::push` intrinsic function element.
+//!
+
+use std::fmt;
+
+use zinc_lexical::Keyword;
+use zinc_lexical::Location;
+
+use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::place::Place;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::Element;
+use crate::semantic::error::Error;
+
+///
+/// The semantic analyzer `std::collections::Vec::push` intrinsic function element.
+///
+#[derive(Debug, Clone)]
+pub struct Function {
+    /// The location where the function is called.
+    pub location: Option<Location>,
+    /// The function identifier.
+    pub identifier: &'static str,
+}
+
+impl Default for Function {
+    fn default() -> Self {
+        Self {
+            location: None,
+            identifier: Self::IDENTIFIER,
+        }
+    }
+}
+
+impl Function {
+    /// The function identifier.
+    pub const IDENTIFIER: &'static str = "push";
+
+    /// The position of the `self` argument in the function argument list.
+    pub const ARGUMENT_INDEX_SELF: usize = 0;
+
+    /// The position of the `value` argument in the function argument list.
+    pub const ARGUMENT_INDEX_VALUE: usize = 1;
+
+    /// The expected number of the function arguments.
+    pub const ARGUMENT_COUNT: usize = 2;
+
+    ///
+    /// Calls the function with the `argument_list`, validating the call.
+    ///
+    /// Returns the vector memory place, which is modified in place.
+    ///
+    pub fn call(self, location: Location, argument_list: ArgumentList) -> Result<Place, Error> {
+        let found = argument_list.arguments.len();
+        let mut arguments = argument_list.arguments.into_iter();
+
+        let instance = match arguments.next() {
+            Some(Element::Place(place)) if place.r#type.is_vec() => place,
+            Some(Element::Value(value)) if value.r#type().is_vec() => {
+                return Err(Error::FunctionCallMutableFromImmutable {
+                    location,
+                    function: self.identifier.to_owned(),
+                })
+            }
+            Some(element) => {
+                return Err(Error::FunctionArgumentType {
+                    location: element
+                        .location()
+                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                    function: self.identifier.to_owned(),
+                    name: Keyword::SelfLowercase.to_string(),
+                    position: Self::ARGUMENT_INDEX_SELF + 1,
+                    expected: "std::collections::Vec".to_owned(),
+                    found: element.to_string(),
+                })
+            }
+            None => {
+                return Err(Error::FunctionArgumentCount {
+                    location,
+                    function: self.identifier.to_owned(),
+                    expected: Self::ARGUMENT_COUNT,
+                    found,
+                    reference: None,
+                })
+            }
+        };
+        let element_type = instance.vec_element_type();
+
+        match arguments.next() {
+            Some(Element::Value(value)) if value.r#type() == element_type => {}
+            Some(Element::Constant(constant)) if constant.r#type() == element_type => {}
+            Some(element) => {
+                let element_location = element
+                    .location()
+                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+                let found = match element {
+                    Element::Value(value) => value.r#type().to_string(),
+                    Element::Constant(constant) => constant.r#type().to_string(),
+                    element => {
+                        return Err(Error::FunctionArgumentNotEvaluable {
+                            location: element_location,
+                            function: self.identifier.to_owned(),
+                            position: Self::ARGUMENT_INDEX_VALUE + 1,
+                            found: element.to_string(),
+                        })
+                    }
+                };
+
+                return Err(Error::FunctionArgumentType {
+                    location: element_location,
+                    function: self.identifier.to_owned(),
+                    name: "value".to_owned(),
+                    position: Self::ARGUMENT_INDEX_VALUE + 1,
+                    expected: element_type.to_string(),
+                    found,
+                });
+            }
+            None => {
+                return Err(Error::FunctionArgumentCount {
+                    location,
+                    function: self.identifier.to_owned(),
+                    expected: Self::ARGUMENT_COUNT,
+                    found,
+                    reference: None,
+                })
+            }
+        }
+
+        if found > Self::ARGUMENT_COUNT {
+            return Err(Error::FunctionArgumentCount {
+                location,
+                function: self.identifier.to_owned(),
+                expected: Self::ARGUMENT_COUNT,
+                found,
+                reference: None,
+            });
+        }
+
+        Ok(instance)
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "collections::Vec<T, N>::{}(&mut self, value: T)",
+            self.identifier
+        )
+    }
+}
@@ -0,0 +1,412 @@
+//!
+//! The `std::collections::Vec` intrinsic function tests.
+//!
+
+use zinc_lexical::Location;
+
+use crate::error::Error;
+use crate::semantic::element::r#type::function::intrinsic::vec::from_array::Function as FromArrayFunction;
+use crate::semantic::element::r#type::function::intrinsic::vec::new::Function as NewFunction;
+use crate::semantic::element::r#type::function::intrinsic::vec::push::Function as PushFunction;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error as SemanticError;
+
+#[test]
+fn ok_circuit() {
+    let input = r#"
+use std::collections::Vec;
+
+fn main(input: [u8; 4], length: u64) -> (u64, u8) {
+    let mut vector = Vec::from_array(input, length);
+    vector.push(42);
+    let last = vector.pop();
+    vector[0] = last;
+
+    let mut sum = 0;
+    for item in vector {
+        sum += item;
+    }
+
+    (vector.len(), sum)
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_contract_storage() {
+    let input = r#"
+use std::collections::Vec;
+
+contract Test {
+    values: Vec<u64, 4>;
+
+    pub fn new() -> Self {
+        Self {
+            values: Vec::from_array([0 as u64; 4], 0),
+        }
+    }
+
+    pub fn push(mut self, value: u64) {
+        self.values.push(value);
+    }
+
+    pub fn last(self) -> u64 {
+        self.values[self.values.len() - 1]
+    }
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_new() {
+    let input = r#"
+use std::collections::Vec;
+
+fn main(value: u8) -> u64 {
+    let mut vector = Vec::<u8, 4>::new();
+    vector.push(value);
+    vector.len()
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_equals() {
+    let input = r#"
+use std::collections::Vec;
+
+fn main(input: [u8; 4], length: u64) -> (bool, bool) {
+    let mut vector = Vec::<u8, 4>::new();
+    vector.push(1);
+    let other = Vec::from_array(input, length);
+
+    (vector == other, vector != other)
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_new_expected_generics() {
+    let input = r#"
+use std::collections::Vec;
+
+fn main() {
+    let vector = Vec::new();
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::TypeExpectedGenerics {
+        location: Location::test(5, 18),
+        r#type: "Vec".to_owned(),
+        expected: NewFunction::GENERICS_COUNT,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_new_argument_count_greater() {
+    let input = r#"
+use std::collections::Vec;
+
+fn main() {
+    let vector = Vec::<u8, 4>::new(4);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
+        location: Location::test(5, 18),
+        function: NewFunction::IDENTIFIER.to_owned(),
+        expected: NewFunction::ARGUMENT_COUNT,
+        found: NewFunction::ARGUMENT_COUNT + 1,
+        reference: None,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_equals_nested() {
+    let input = r#"
+use std::collections::Vec;
+
+struct Data {
+    values: Vec<u8, 4>,
+}
+
+fn main() -> bool {
+    let data = Data { values: Vec::<u8, 4>::new() };
+    data == data
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::OperatorEqualsFirstOperandExpectedComparable {
+            location: Location::test(10, 5),
+            found: "structure Data".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_from_array_argument_count_lesser() {
+    let input = r#"
+use std::collections::Vec;
+
+fn main() {
+    let vector = Vec::from_array([1, 2, 3]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentCount {
+        location: Location::test(5, 18),
+        function: FromArrayFunction::IDENTIFIER.to_owned(),
+        expected: FromArrayFunction::ARGUMENT_COUNT,
+        found: FromArrayFunction::ARGUMENT_COUNT - 1,
+        reference: None,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_from_array_argument_1_array_expected_array() {
+    let input = r#"
+use std::collections::Vec;
+
+fn main() {
+    let vector = Vec::from_array(42, 0);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(5, 34),
+        function: FromArrayFunction::IDENTIFIER.to_owned(),
+        name: "array".to_owned(),
+        position: FromArrayFunction::ARGUMENT_INDEX_ARRAY + 1,
+        expected: "[T; N]".to_owned(),
+        found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_from_array_argument_2_length_expected_unsigned_integer() {
+    let input = r#"
+use std::collections::Vec;
+
+fn main() {
+    let vector = Vec::from_array([1, 2, 3], true);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(5, 45),
+        function: FromArrayFunction::IDENTIFIER.to_owned(),
+        name: "length".to_owned(),
+        position: FromArrayFunction::ARGUMENT_INDEX_LENGTH + 1,
+        expected: Type::integer_unsigned(None, zinc_const::bitlength::INDEX).to_string(),
+        found: Type::boolean(None).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_from_array_length_exceeds_capacity() {
+    let input = r#"
+use std::collections::Vec;
+
+fn main() {
+    let vector = Vec::from_array([1, 2, 3], 4);
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionStdlibVecLengthExceedsCapacity {
+            location: Location::test(5, 45),
+            length: 4,
+            capacity: 3,
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_push_argument_2_value_expected_element_type() {
+    let input = r#"
+use std::collections::Vec;
+
+fn main() {
+    let mut vector = Vec::from_array([1, 2, 3], 2);
+    vector.push(true);
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::FunctionArgumentType {
+        location: Location::test(6, 17),
+        function: PushFunction::IDENTIFIER.to_owned(),
+        name: "value".to_owned(),
+        position: PushFunction::ARGUMENT_INDEX_VALUE + 1,
+        expected: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+        found: Type::boolean(None).to_string(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_push_mutable_from_immutable() {
+    let input = r#"
+use std::collections::Vec;
+
+fn main() {
+    let vector = Vec::from_array([1, 2, 3], 2);
+    vector.push(42);
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionCallMutableFromImmutable {
+            location: Location::test(6, 16),
+            function: PushFunction::IDENTIFIER.to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_index_expected_place() {
+    let input = r#"
+use std::collections::Vec;
+
+fn main() -> u8 {
+    Vec::from_array([1, 2, 3], 2)[0]
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::OperatorIndexVecExpectedPlace {
+        location: Location::test(5, 34),
+        found: "value structure <runtime> 'Vec<u8, 3>' with fields { '$data' of type 'array [u8; 3]', '$length' of type 'u64' }".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_index_expected_integer() {
+    let input = r#"
+use std::collections::Vec;
+
+fn main() -> u8 {
+    let vector = Vec::from_array([1, 2, 3], 2);
+    vector[0..1]
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::OperatorIndexVecExpectedInteger {
+            location: Location::test(6, 12),
+            found: "constant range '0 .. 1' of type 'u8'".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_index_constant_out_of_range() {
+    let input = r#"
+use std::collections::Vec;
+
+fn main() -> u8 {
+    let vector = Vec::from_array([1, 2, 3], 2);
+    vector[3]
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ArrayIndexOutOfRange {
+        location: Location::test(6, 12),
+        index: "3".to_owned(),
+        size: 3,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_generic_expected_type() {
+    let input = r#"
+use std::collections::Vec;
+
+fn main(vector: Vec<4, 4>) {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::TypeGenericExpectedType {
+        location: Location::test(4, 17),
+        r#type: "Vec".to_owned(),
+        found: "4".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_generic_expected_constant() {
+    let input = r#"
+use std::collections::Vec;
+
+fn main(vector: Vec<u8, u8>) {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::TypeGenericExpectedConstant {
+            location: Location::test(4, 17),
+            r#type: "Vec".to_owned(),
+            found: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -3,11 +3,13 @@
 //!
 
 pub mod constant;
+pub mod generic;
 pub mod intrinsic;
 pub mod runtime;
 pub mod test;
 
 use std::fmt;
+use std::rc::Rc;
 
 use zinc_lexical::Location;
 use zinc_syntax::BlockExpression;
@@ -16,8 +18,11 @@ use zinc_types::LibraryFunctionIdentifier;
 use crate::semantic::binding::Binding;
 use crate::semantic::element::r#type::contract::Contract as ContractType;
 use crate::semantic::element::r#type::Type;
+use crate::semantic::generics::Template;
 
 use self::constant::Function as ConstantFunction;
+use self::generic::Function as GenericFunction;
+use self::intrinsic::vec::Function as VecFunction;
 use self::intrinsic::Function as IntrinsicFunction;
 use self::runtime::Function as RuntimeFunction;
 use self::test::Function as TestFunction;
@@ -42,6 +47,9 @@ pub enum Function {
     /// Unit test functions. They produce the intermediate representation and are run as separate
     /// entry points in the special test mode.
     Test(TestFunction),
+    /// Generic functions declared in modules. They are instantiated as runtime functions for each
+    /// set of the generic arguments inferred at the call site.
+    Generic(GenericFunction),
 }
 
 impl Function {
@@ -73,6 +81,20 @@ impl Function {
         Self::Intrinsic(IntrinsicFunction::library(identifier))
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn convert_from_str_bytes() -> Self {
+        Self::Intrinsic(IntrinsicFunction::convert_from_str_bytes())
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn vec(function: VecFunction) -> Self {
+        Self::Intrinsic(IntrinsicFunction::vec(function))
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -120,6 +142,13 @@ impl Function {
         Self::Test(TestFunction::new(location, identifier, type_id))
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn generic(location: Location, identifier: String, template: Rc<Template>) -> Self {
+        Self::Generic(GenericFunction::new(location, identifier, template))
+    }
+
     ///
     /// Returns the function identifier.
     ///
@@ -129,6 +158,7 @@ impl Function {
             Self::Runtime(inner) => inner.identifier.to_owned(),
             Self::Constant(inner) => inner.identifier.to_owned(),
             Self::Test(inner) => inner.identifier.to_owned(),
+            Self::Generic(inner) => inner.identifier.to_owned(),
         }
     }
 
@@ -141,6 +171,17 @@ impl Function {
             Self::Runtime(inner) => inner.is_mutable(),
             Self::Constant(inner) => inner.is_mutable(),
             Self::Test(_) => false,
+            Self::Generic(_) => false,
+        }
+    }
+
+    ///
+    /// Whether the function is a method, which takes its instance by mutable reference.
+    ///
+    pub fn is_reference_method(&self) -> bool {
+        match self {
+            Self::Runtime(inner) => inner.is_reference_method(),
+            _ => false,
         }
     }
 
@@ -153,6 +194,7 @@ impl Function {
             Self::Runtime(inner) => inner.location = value,
             Self::Constant(inner) => inner.location = value,
             Self::Test(inner) => inner.location = value,
+            Self::Generic(inner) => inner.location = value,
         }
     }
 
@@ -165,6 +207,7 @@ impl Function {
             Self::Runtime(inner) => Some(inner.location),
             Self::Constant(inner) => Some(inner.location),
             Self::Test(inner) => Some(inner.location),
+            Self::Generic(inner) => Some(inner.location),
         }
     }
 }
@@ -176,6 +219,7 @@ impl fmt::Display for Function {
             Self::Runtime(inner) => write!(f, "{}", inner),
             Self::Constant(inner) => write!(f, "{}", inner),
             Self::Test(inner) => write!(f, "{}", inner),
+            Self::Generic(inner) => write!(f, "{}", inner),
         }
     }
 }
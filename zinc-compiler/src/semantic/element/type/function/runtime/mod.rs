@@ -11,6 +11,7 @@ use zinc_lexical::Location;
 
 use crate::semantic::binding::Binding;
 use crate::semantic::element::argument_list::ArgumentList;
+use crate::semantic::element::place::Place;
 use crate::semantic::element::r#type::i_typed::ITyped;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::element::Element;
@@ -59,7 +60,13 @@ impl Function {
     pub fn input_size(&self) -> usize {
         self.bindings
             .iter()
-            .map(|binding| binding.r#type.size())
+            .map(|binding| {
+                if binding.is_reference {
+                    Type::field(None).size()
+                } else {
+                    binding.r#type.size()
+                }
+            })
             .sum()
     }
 
@@ -76,7 +83,17 @@ impl Function {
     pub fn is_mutable(&self) -> bool {
         self.bindings
             .first()
-            .map(|instance| instance.is_mutable)
+            .map(|instance| instance.is_mutable || instance.is_reference)
+            .unwrap_or_default()
+    }
+
+    ///
+    /// Whether the function is a method, which takes its instance by mutable reference.
+    ///
+    pub fn is_reference_method(&self) -> bool {
+        self.bindings
+            .first()
+            .map(|instance| instance.identifier.is_self_lowercase() && instance.is_reference)
             .unwrap_or_default()
     }
 
@@ -85,12 +102,30 @@ impl Function {
     ///
     pub fn call(self, argument_list: ArgumentList) -> Result<Type, Error> {
         let mut actual_params = Vec::with_capacity(argument_list.arguments.len());
+        let mut references: Vec<(Place, Option<Location>)> = Vec::new();
         for (index, element) in argument_list.arguments.into_iter().enumerate() {
             let location = element.location();
 
-            let r#type = match element {
-                Element::Value(value) => value.r#type(),
-                Element::Constant(constant) => constant.r#type(),
+            let (r#type, is_reference) = match element {
+                Element::Value(value) => (value.r#type(), false),
+                Element::Constant(constant) => (constant.r#type(), false),
+                Element::Reference(place) => {
+                    if let Some((_place, reference)) = references
+                        .iter()
+                        .find(|(borrowed, _location)| borrowed.overlaps(&place))
+                    {
+                        return Err(Error::FunctionArgumentReferenceAliased {
+                            location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                            function: self.identifier.to_owned(),
+                            position: index + 1,
+                            reference: *reference,
+                        });
+                    }
+
+                    let r#type = place.r#type.to_owned();
+                    references.push((place, location));
+                    (r#type, true)
+                }
                 element => {
                     return Err(Error::FunctionArgumentNotEvaluable {
                         location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
@@ -101,7 +136,7 @@ impl Function {
                 }
             };
 
-            actual_params.push((r#type, location));
+            actual_params.push((r#type, is_reference, location));
         }
 
         if actual_params.len() != self.bindings.len() {
@@ -117,8 +152,25 @@ impl Function {
         let bindings_length = self.bindings.len();
         for (index, binding) in self.bindings.into_iter().enumerate() {
             match actual_params.get(index) {
-                Some((actual_type, _location)) if actual_type == &binding.r#type => {}
-                Some((actual_type, location)) => {
+                Some((_actual_type, false, location)) if binding.is_reference => {
+                    return Err(Error::FunctionArgumentExpectedReference {
+                        location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                        function: self.identifier.to_owned(),
+                        name: binding.identifier.name,
+                        position: index + 1,
+                    });
+                }
+                Some((_actual_type, true, location)) if !binding.is_reference => {
+                    return Err(Error::FunctionArgumentUnexpectedReference {
+                        location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                        function: self.identifier.to_owned(),
+                        name: binding.identifier.name,
+                        position: index + 1,
+                    });
+                }
+                Some((actual_type, _is_reference, _location)) if actual_type == &binding.r#type => {
+                }
+                Some((actual_type, _is_reference, location)) => {
                     return Err(Error::FunctionArgumentType {
                         location: location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
                         function: self.identifier.to_owned(),
@@ -153,9 +205,10 @@ impl fmt::Display for Function {
             self.bindings
                 .iter()
                 .map(|binding| format!(
-                    "{}{}: {}",
+                    "{}{}: {}{}",
                     if binding.is_mutable { "mut " } else { "" },
                     binding.identifier.name,
+                    if binding.is_reference { "&mut " } else { "" },
                     binding.r#type
                 ))
                 .collect::<Vec<String>>()
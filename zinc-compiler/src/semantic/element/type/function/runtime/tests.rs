@@ -295,3 +295,263 @@ contract Data {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_calling_reference_method() {
+    let input = r#"
+struct Data {
+    value: u8,
+}
+
+impl Data {
+    pub fn increment(&mut self, by: u8) {
+        self.value += by;
+    }
+}
+
+fn main() {
+    let mut data = Data { value: 42 };
+    data.increment(1);
+    Data::increment(&mut data, 1);
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_calling_reference_method_from_immutable_structure() {
+    let input = r#"
+struct Data {
+    value: u8,
+}
+
+impl Data {
+    pub fn increment(&mut self) {
+        self.value += 1;
+    }
+}
+
+fn main() {
+    let data = Data { value: 42 };
+    data.increment();
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionCallMutableFromImmutable {
+            location: Location::test(14, 19),
+            function: "increment".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_argument_expected_reference() {
+    let input = r#"
+fn increment(value: &mut u8) {
+    value += 1;
+}
+
+fn main() {
+    let mut value = 42;
+    increment(value);
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionArgumentExpectedReference {
+            location: Location::test(8, 15),
+            function: "increment".to_owned(),
+            name: "value".to_owned(),
+            position: 1,
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_argument_unexpected_reference() {
+    let input = r#"
+fn increment(value: u8) -> u8 {
+    value + 1
+}
+
+fn main() {
+    let mut value = 42;
+    increment(&mut value);
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionArgumentUnexpectedReference {
+            location: Location::test(8, 20),
+            function: "increment".to_owned(),
+            name: "value".to_owned(),
+            position: 1,
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_argument_references_disjoint() {
+    let input = r#"
+struct Pair {
+    a: u8,
+    b: u8,
+}
+
+fn swap(x: &mut u8, y: &mut u8) {
+    let t = x;
+    x = y;
+    y = t;
+}
+
+fn main() {
+    let mut pair = Pair { a: 1, b: 2 };
+    swap(&mut pair.a, &mut pair.b);
+
+    let mut array = [1, 2, 3];
+    swap(&mut array[0], &mut array[2]);
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_argument_reference_aliased() {
+    let input = r#"
+fn increment(a: &mut u8, b: &mut u8) {
+    a += 1;
+    b += 1;
+}
+
+fn main() {
+    let mut value = 42;
+    increment(&mut value, &mut value);
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionArgumentReferenceAliased {
+            location: Location::test(9, 32),
+            function: "increment".to_owned(),
+            position: 2,
+            reference: Some(Location::test(9, 20)),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_argument_reference_aliased_parent() {
+    let input = r#"
+struct Pair {
+    a: u8,
+    b: u8,
+}
+
+fn reset(pair: &mut Pair, value: &mut u8) {
+    pair.a = 0;
+    value = 1;
+}
+
+fn main() {
+    let mut pair = Pair { a: 1, b: 2 };
+    reset(&mut pair, &mut pair.b);
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionArgumentReferenceAliased {
+            location: Location::test(14, 27),
+            function: "reset".to_owned(),
+            position: 2,
+            reference: Some(Location::test(14, 16)),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_argument_reference_aliased_slice() {
+    let input = r#"
+fn touch(slice: &mut [u8; 2], element: &mut u8) {
+    element = slice[0];
+}
+
+fn main() {
+    let mut array = [1, 2, 3];
+    touch(&mut array[1..3], &mut array[2]);
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionArgumentReferenceAliased {
+            location: Location::test(8, 34),
+            function: "touch".to_owned(),
+            position: 2,
+            reference: Some(Location::test(8, 16)),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_reference_argument_forbidden_entry() {
+    let input = r#"
+fn main(value: &mut u8) {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionReferenceArgumentForbidden {
+            location: Location::test(2, 9),
+            function: "main".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_reference_argument_forbidden_constant() {
+    let input = r#"
+const fn increment(value: &mut u8) {}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::FunctionReferenceArgumentForbidden {
+            location: Location::test(2, 20),
+            function: "increment".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
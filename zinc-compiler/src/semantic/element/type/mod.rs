@@ -32,6 +32,8 @@ use crate::semantic::binding::Binding;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::Element;
 use crate::semantic::error::Error;
+use crate::semantic::generics::argument::Argument;
+use crate::semantic::generics::Template;
 use crate::semantic::scope::intrinsic::IntrinsicTypeId;
 use crate::semantic::scope::item::r#type::index::INDEX as TYPE_INDEX;
 use crate::semantic::scope::item::Item as ScopeItem;
@@ -215,6 +217,40 @@ impl Type {
         ))
     }
 
+    ///
+    /// A helper type constructor, which allocates a unique sequence ID for the type.
+    ///
+    /// The generic structure has no fields, since it must be instantiated with the actual
+    /// generic arguments first.
+    ///
+    pub fn generic_structure(
+        location: Location,
+        identifier: String,
+        template: Template,
+        scope: Rc<RefCell<Scope>>,
+    ) -> Self {
+        let type_id = TYPE_INDEX.next(format!("structure {}", identifier));
+
+        let generics = template
+            .parameters
+            .iter()
+            .map(|parameter| parameter.identifier.to_owned())
+            .collect();
+
+        let mut structure = Structure::new(
+            Some(location),
+            identifier,
+            type_id,
+            vec![],
+            Some(generics),
+            None,
+            scope,
+        );
+        structure.template = Some(Rc::new(template));
+
+        Self::Structure(structure)
+    }
+
     ///
     /// A helper type constructor, which allocates a unique sequence ID for the type.
     ///
@@ -288,6 +324,13 @@ impl Type {
         )
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn generic_function(location: Location, identifier: String, template: Template) -> Self {
+        Self::Function(Function::generic(location, identifier, Rc::new(template)))
+    }
+
     ///
     /// A helper type constructor, which allocates a unique sequence ID for the type.
     ///
@@ -326,7 +369,7 @@ impl Type {
                 .iter()
                 .map(|(_name, r#type)| r#type.size())
                 .sum(),
-            Self::Enumeration(_inner) => 1,
+            Self::Enumeration(inner) => inner.size(),
             Self::Contract(_inner) => 1,
             Self::Function(_inner) => 0,
         }
@@ -336,13 +379,15 @@ impl Type {
     /// Checks if the type is scalar (a primitive non-unit type).
     ///
     pub fn is_scalar(&self) -> bool {
-        matches!(self,
-            Self::Boolean(_) |
-            Self::IntegerUnsigned { .. } |
-            Self::IntegerSigned { .. } |
-            Self::Field(_) |
-            Self::Enumeration { .. }
-        )
+        match self {
+            Self::Enumeration(inner) => !inner.is_data(),
+            _ => matches!(self,
+                Self::Boolean(_) |
+                Self::IntegerUnsigned { .. } |
+                Self::IntegerSigned { .. } |
+                Self::Field(_)
+            ),
+        }
     }
 
     ///
@@ -350,12 +395,14 @@ impl Type {
     /// enumeration values).
     ///
     pub fn is_scalar_unsigned(&self) -> bool {
-        matches!(self,
-            Self::Boolean(_) |
-            Self::IntegerUnsigned { .. } |
-            Self::Field(_) |
-            Self::Enumeration { .. }
-        )
+        match self {
+            Self::Enumeration(inner) => !inner.is_data(),
+            _ => matches!(self,
+                Self::Boolean(_) |
+                Self::IntegerUnsigned { .. } |
+                Self::Field(_)
+            ),
+        }
     }
 
     ///
@@ -369,11 +416,13 @@ impl Type {
     /// Checks if the type is an unsigned integer one (unsigned integers, fields and enumeration values).
     ///
     pub fn is_integer_unsigned(&self) -> bool {
-        matches!(self,
-            Self::IntegerUnsigned { .. } |
-            Self::Field(_) |
-            Self::Enumeration { .. }
-        )
+        match self {
+            Self::Enumeration(inner) => !inner.is_data(),
+            _ => matches!(self,
+                Self::IntegerUnsigned { .. } |
+                Self::Field(_)
+            ),
+        }
     }
 
     ///
@@ -464,6 +513,57 @@ impl Type {
         }
     }
 
+    ///
+    /// Checks if the values of the type can be compared with the `==` and `!=` operators.
+    ///
+    /// Compound values are compared element-wise, so all their elements must be comparable.
+    /// Strings, ranges, functions, contracts, and maps cannot be compared. The vectors are
+    /// compared up to their lengths, so they cannot be nested into other compared values.
+    ///
+    pub fn is_comparable(&self) -> bool {
+        match self {
+            Self::Structure(inner) if self.is_vec() => match inner.fields.first() {
+                Some((_name, Self::Array(array))) => array.r#type.is_comparable_element_wise(),
+                _ => false,
+            },
+            r#type => r#type.is_comparable_element_wise(),
+        }
+    }
+
+    ///
+    /// Checks if the values of the type can be compared element-wise, which is not the case for
+    /// the vectors.
+    ///
+    fn is_comparable_element_wise(&self) -> bool {
+        match self {
+            Self::Unit(_) => true,
+            Self::Boolean(_) => true,
+            Self::IntegerUnsigned { .. } => true,
+            Self::IntegerSigned { .. } => true,
+            Self::Field(_) => true,
+            Self::String(_) => false,
+            Self::Range(_) => false,
+            Self::RangeInclusive(_) => false,
+            Self::Array(inner) => inner.r#type.is_comparable_element_wise(),
+            Self::Tuple(inner) => inner.types.iter().all(Self::is_comparable_element_wise),
+            Self::Structure(inner) => {
+                !self.is_mtreemap()
+                    && !self.is_vec()
+                    && inner
+                        .fields
+                        .iter()
+                        .all(|(_name, r#type)| r#type.is_comparable_element_wise())
+            }
+            Self::Enumeration(inner) => inner
+                .payloads
+                .iter()
+                .flat_map(|payload| payload.types())
+                .all(Self::is_comparable_element_wise),
+            Self::Function(_) => false,
+            Self::Contract(_) => false,
+        }
+    }
+
     ///
     /// Checks if the type is an `std::collections::MTreeMap`, which is treated specially.
     ///
@@ -475,18 +575,39 @@ impl Type {
         }
     }
 
+    ///
+    /// Checks if the type is an `std::collections::Vec`, which is treated specially.
+    ///
+    pub fn is_vec(&self) -> bool {
+        if let Self::Structure(structure) = self {
+            structure.type_id == IntrinsicTypeId::StdCollectionsVec as usize
+        } else {
+            false
+        }
+    }
+
     ///
     /// Sets the generic arguments for the type.
     ///
+    /// The generic function is instantiated with the explicit `generics`, e.g. `identity::<u8>`.
+    ///
     /// Returns an error if the type does not expect any generic arguments.
     ///
     pub fn set_generics(
         &mut self,
         location: Location,
-        generics: Option<Vec<Type>>,
+        generics: Option<Vec<Argument>>,
     ) -> Result<(), Error> {
         match self {
             Self::Structure(inner) => inner.set_generics(location, generics),
+            Self::Function(Function::Generic(inner)) => match generics {
+                Some(generics) => {
+                    let function = inner.to_owned().instantiate_explicit(location, generics)?;
+                    *self = Self::Function(function);
+                    Ok(())
+                }
+                None => Ok(()),
+            },
             ref r#type if generics.is_some() => Err(Error::TypeUnexpectedGenerics {
                 location: self.location().unwrap_or(location),
                 r#type: r#type.to_string(),
@@ -548,8 +669,10 @@ impl Type {
                         let generics = if let Some(generics) = generics {
                             let mut semantic_generics = Vec::with_capacity(generics.len());
                             for generic in generics.into_iter() {
-                                semantic_generics
-                                    .push(Self::try_from_syntax(generic, scope.clone())?);
+                                semantic_generics.push(Self::generic_argument_from_syntax(
+                                    generic,
+                                    scope.clone(),
+                                )?);
                             }
                             Some(semantic_generics)
                         } else {
@@ -567,9 +690,46 @@ impl Type {
                     }
                 }
             }
+            SyntaxTypeVariant::MutableReference { .. } => {
+                return Err(Error::ReferenceOutsideFunctionArgument { location });
+            }
         })
     }
 
+    ///
+    /// Resolves the generic argument from the syntax type.
+    ///
+    /// The bare paths resolving to integer constants, including the integer literals, are
+    /// constant arguments like the vector capacity. Other syntax types are type arguments.
+    ///
+    fn generic_argument_from_syntax(
+        r#type: SyntaxType,
+        scope: Rc<RefCell<Scope>>,
+    ) -> Result<Argument, Error> {
+        if let SyntaxTypeVariant::Alias {
+            ref path,
+            generics: None,
+        } = r#type.variant
+        {
+            let element = match ExpressionAnalyzer::new(scope.clone(), TranslationRule::Type)
+                .analyze(path.to_owned())?
+            {
+                (Element::Path(_path), _intermediate) => {
+                    ExpressionAnalyzer::new(scope.clone(), TranslationRule::Constant)
+                        .analyze(path.to_owned())?
+                        .0
+                }
+                (element, _intermediate) => element,
+            };
+
+            if let Element::Constant(Constant::Integer(integer)) = element {
+                return Ok(Argument::Constant(integer.value));
+            }
+        }
+
+        Self::try_from_syntax(r#type, scope).map(Argument::Type)
+    }
+
     ///
     /// Gets the semantic element type where it is possible.
     ///
@@ -599,7 +759,7 @@ impl Type {
                 }
                 _ => panic!(zinc_const::panic::VALIDATED_DURING_SYNTAX_ANALYSIS),
             },
-            Element::Place(place) => {
+            Element::Place(place) | Element::Reference(place) => {
                 let mut r#type = place.r#type.to_owned();
                 r#type.set_location(place.identifier.location);
                 r#type
@@ -10,10 +10,16 @@ use std::collections::HashMap;
 use std::fmt;
 use std::rc::Rc;
 
+use num::ToPrimitive;
+
 use zinc_lexical::Location;
 
 use crate::semantic::element::r#type::Type;
 use crate::semantic::error::Error;
+use crate::semantic::generics::argument::Argument;
+use crate::semantic::generics::Template;
+use crate::semantic::scope::intrinsic::IntrinsicTypeId;
+use crate::semantic::scope::item::r#type::statement::Statement as TypeStatementVariant;
 use crate::semantic::scope::Scope;
 
 ///
@@ -25,6 +31,7 @@ use crate::semantic::scope::Scope;
 /// - data `fields`
 /// - the implementation `scope`, which contains the reference to its parent scope
 /// - the generic formal and actual arguments
+/// - the generic template, if the structure is declared with generic parameters
 ///
 #[derive(Debug, Clone)]
 pub struct Structure {
@@ -43,9 +50,17 @@ pub struct Structure {
     pub params: Option<HashMap<String, Type>>,
     /// The structure scope, where its methods and associated items are declared.
     pub scope: Rc<RefCell<Scope>>,
+    /// The generic structure template, which is instantiated with the actual generic arguments.
+    pub template: Option<Rc<Template>>,
 }
 
 impl Structure {
+    /// The `std::collections::Vec` data array field name.
+    pub const VEC_FIELD_DATA: &'static str = "$data";
+
+    /// The `std::collections::Vec` length field name.
+    pub const VEC_FIELD_LENGTH: &'static str = "$length";
+
     ///
     /// A shortcut constructor.
     ///
@@ -66,16 +81,22 @@ impl Structure {
             generics,
             params,
             scope,
+            template: None,
         }
     }
 
     ///
     /// Validates and sets the generic type arguments.
     ///
+    /// Only the intrinsic `std::collections::Vec` structure accepts a constant argument.
+    ///
+    /// The arguments may be omitted if the structure is already instantiated, e.g. if it is
+    /// referenced via the `Self` alias in a generic implementation.
+    ///
     pub fn set_generics(
         &mut self,
         location: Location,
-        generics: Option<Vec<Type>>,
+        generics: Option<Vec<Argument>>,
     ) -> Result<(), Error> {
         match (self.generics.as_ref(), generics) {
             (Some(formal), Some(actual)) => {
@@ -88,14 +109,42 @@ impl Structure {
                     });
                 }
 
-                let mut params = HashMap::with_capacity(actual.len());
-                for (name, r#type) in formal.iter().zip(actual.into_iter()) {
+                if self.type_id == IntrinsicTypeId::StdCollectionsVec as usize {
+                    return self.set_vec_generics(location, actual);
+                }
+
+                let mut types = Vec::with_capacity(actual.len());
+                for argument in actual.into_iter() {
+                    match argument {
+                        Argument::Type(r#type) => types.push(r#type),
+                        Argument::Constant(value) => {
+                            return Err(Error::TypeGenericExpectedType {
+                                location,
+                                r#type: self.identifier.to_owned(),
+                                found: value.to_string(),
+                            })
+                        }
+                    }
+                }
+
+                if let Some(template) = self.template.take() {
+                    let arguments = types.into_iter().map(Argument::Type).collect();
+                    *self = match template.instantiate(location, arguments)? {
+                        Type::Structure(structure) => structure,
+                        _ => panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+                    };
+                    return Ok(());
+                }
+
+                let mut params = HashMap::with_capacity(types.len());
+                for (name, r#type) in formal.iter().zip(types.into_iter()) {
                     params.insert(name.to_owned(), r#type);
                 }
                 self.params = Some(params);
 
                 Ok(())
             }
+            (Some(_names), None) if self.params.is_some() => Ok(()),
             (Some(names), None) => Err(Error::TypeExpectedGenerics {
                 location,
                 r#type: self.identifier.to_owned(),
@@ -108,10 +157,110 @@ impl Structure {
             (None, None) => Ok(()),
         }
     }
+
+    ///
+    /// Sets the element type `T` and the capacity `N` of the `std::collections::Vec<T, N>`.
+    ///
+    /// The vector is laid out as the `[T; N]` data array followed by the `u64` length. The field
+    /// names are not valid identifiers, so they cannot be accessed directly.
+    ///
+    fn set_vec_generics(&mut self, location: Location, actual: Vec<Argument>) -> Result<(), Error> {
+        let mut actual = actual.into_iter();
+
+        let element_type = match actual.next() {
+            Some(Argument::Type(r#type)) => r#type,
+            Some(Argument::Constant(value)) => {
+                return Err(Error::TypeGenericExpectedType {
+                    location,
+                    r#type: self.identifier.to_owned(),
+                    found: value.to_string(),
+                })
+            }
+            None => panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        };
+
+        let capacity = match actual.next() {
+            Some(Argument::Constant(value)) => {
+                value.to_usize().ok_or_else(|| Error::InvalidInteger {
+                    location,
+                    inner: zinc_math::Error::Overflow {
+                        value: value.to_owned(),
+                        is_signed: false,
+                        bitlength: zinc_const::bitlength::INDEX,
+                    },
+                })?
+            }
+            Some(Argument::Type(r#type)) => {
+                return Err(Error::TypeGenericExpectedConstant {
+                    location,
+                    r#type: self.identifier.to_owned(),
+                    found: r#type.to_string(),
+                })
+            }
+            None => panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        };
+
+        self.identifier = format!("Vec<{}, {}>", element_type, capacity);
+        self.fields = vec![
+            (
+                Self::VEC_FIELD_DATA.to_owned(),
+                Type::array(None, element_type.clone(), capacity),
+            ),
+            (
+                Self::VEC_FIELD_LENGTH.to_owned(),
+                Type::integer_unsigned(None, zinc_const::bitlength::INDEX),
+            ),
+        ];
+
+        let mut params = HashMap::with_capacity(1);
+        params.insert("T".to_owned(), element_type);
+        self.params = Some(params);
+
+        Ok(())
+    }
+}
+
+impl Structure {
+    ///
+    /// Infers the generic arguments from the structure literal `fields` and returns
+    /// the structure instance.
+    ///
+    /// Returns the structure as is, if it is not generic.
+    ///
+    pub fn infer(self, fields: Vec<(String, Type)>) -> Result<Self, Error> {
+        let template = match self.template {
+            Some(template) => template,
+            None => return Ok(self),
+        };
+        let location = self.location.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+
+        let mut pairs = Vec::with_capacity(fields.len());
+        if let TypeStatementVariant::Struct(ref statement) = template.statement {
+            for (name, r#type) in fields.into_iter() {
+                if let Some(field) = statement
+                    .fields
+                    .iter()
+                    .find(|field| field.identifier.name == name)
+                {
+                    pairs.push((&field.r#type, r#type));
+                }
+            }
+        }
+
+        let arguments = template.infer(location, pairs)?;
+        match template.instantiate(location, arguments)? {
+            Type::Structure(structure) => Ok(structure),
+            _ => panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        }
+    }
 }
 
 impl PartialEq<Self> for Structure {
     fn eq(&self, other: &Self) -> bool {
+        if self.type_id == IntrinsicTypeId::StdCollectionsVec as usize {
+            return self.type_id == other.type_id && self.fields == other.fields;
+        }
+
         self.type_id == other.type_id
     }
 }
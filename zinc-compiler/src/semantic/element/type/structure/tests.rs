@@ -100,3 +100,156 @@ contract Test {
 
     assert_eq!(result, expected);
 }
+
+#[test]
+fn ok_generic_explicit() {
+    let input = r#"
+struct Pair<A, B> {
+    first: A,
+    second: B,
+}
+
+fn main() -> bool {
+    let pair: Pair<u8, bool> = Pair { first: 42, second: true };
+    pair.second
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_generic_path() {
+    let input = r#"
+struct Pair<A, B> {
+    first: A,
+    second: B,
+}
+
+fn main() -> bool {
+    let pair = Pair::<u8, bool> { first: 42, second: true };
+    pair.second
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_generic_inferred() {
+    let input = r#"
+struct Pair<A, B> {
+    first: A,
+    second: B,
+}
+
+fn swap<A, B>(pair: Pair<A, B>) -> Pair<B, A> {
+    Pair {
+        first: pair.second,
+        second: pair.first,
+    }
+}
+
+fn main() -> bool {
+    let pair = swap(Pair { first: 42, second: true });
+    pair.first
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_generic_expected_generics() {
+    let input = r#"
+struct Pair<A, B> {
+    first: A,
+    second: B,
+}
+
+fn main() {
+    let pair: Pair = Pair { first: 42, second: true };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::TypeExpectedGenerics {
+        location: Location::test(8, 15),
+        r#type: "Pair".to_owned(),
+        expected: 2,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_generic_invalid_generics_number() {
+    let input = r#"
+struct Pair<A, B> {
+    first: A,
+    second: B,
+}
+
+fn main() {
+    let pair: Pair<u8> = Pair { first: 42, second: true };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::TypeInvalidGenericsNumber {
+        location: Location::test(8, 15),
+        r#type: "Pair".to_owned(),
+        expected: 2,
+        found: 1,
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_generic_parameter_unused() {
+    let input = r#"
+struct Tagged<T> {
+    value: u8,
+}
+
+fn main() {
+    let tagged = Tagged::<bool> { value: 42 };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::TypeGenericParameterUnused {
+        location: Location::test(2, 15),
+        r#type: "Tagged".to_owned(),
+        name: "T".to_owned(),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_generic_constant_unavailable() {
+    let input = r#"
+struct Buffer<const N: u8> {
+    data: [u8; N],
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::TypeGenericConstantUnavailable {
+            location: Location::test(2, 15),
+            r#type: "Buffer".to_owned(),
+            name: "N".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
@@ -0,0 +1,49 @@
+//!
+//! The semantic analyzer enumeration value element.
+//!
+
+use std::fmt;
+
+use zinc_lexical::Location;
+
+use crate::semantic::element::r#type::enumeration::Enumeration as EnumerationType;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::Type;
+
+///
+/// The value of an enumeration with data variants.
+///
+/// The C-like enumeration values are represented by integers.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Enumeration {
+    /// The value location in the code.
+    pub location: Option<Location>,
+    /// The enumeration type.
+    pub r#type: EnumerationType,
+}
+
+impl Enumeration {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(location: Option<Location>, r#type: EnumerationType) -> Self {
+        Self { location, r#type }
+    }
+}
+
+impl ITyped for Enumeration {
+    fn r#type(&self) -> Type {
+        Type::Enumeration(self.r#type.to_owned())
+    }
+
+    fn has_the_same_type_as(&self, other: &Self) -> bool {
+        self.r#type == other.r#type
+    }
+}
+
+impl fmt::Display for Enumeration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<runtime> of type '{}'", self.r#type())
+    }
+}
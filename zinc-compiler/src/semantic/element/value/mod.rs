@@ -8,6 +8,7 @@ mod tests;
 pub mod array;
 pub mod boolean;
 pub mod contract;
+pub mod enumeration;
 pub mod integer;
 pub mod structure;
 pub mod tuple;
@@ -44,6 +45,7 @@ use crate::semantic::error::Error;
 use self::array::Array;
 use self::boolean::Boolean;
 use self::contract::Contract;
+use self::enumeration::Enumeration;
 use self::integer::Integer;
 use self::structure::Structure;
 use self::tuple::Tuple;
@@ -69,6 +71,8 @@ pub enum Value {
     Tuple(Tuple),
     /// The structure type value.
     Structure(Structure),
+    /// The data enumeration type value.
+    Enumeration(Enumeration),
     /// The contract type value.
     Contract(Contract),
 }
@@ -181,7 +185,25 @@ impl Value {
                     .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
                 found: value_2.r#type().to_string(),
             }),
-            (value_1, _) => Err(Error::OperatorEqualsFirstOperandExpectedPrimitiveType {
+            (value_1, value_2) if value_1.r#type().is_comparable() => {
+                let location = value_1
+                    .location()
+                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+
+                if !value_1.has_the_same_type_as(&value_2) {
+                    return Err(Error::OperatorEqualsTypesMismatch {
+                        location,
+                        first: value_1.r#type().to_string(),
+                        second: value_2.r#type().to_string(),
+                    });
+                }
+
+                Ok((
+                    Self::Boolean(Boolean::new(Some(location))),
+                    GeneratorExpressionOperator::equals_compound(&value_1.r#type()),
+                ))
+            }
+            (value_1, _) => Err(Error::OperatorEqualsFirstOperandExpectedComparable {
                 location: value_1
                     .location()
                     .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
@@ -232,7 +254,25 @@ impl Value {
                     found: value_2.r#type().to_string(),
                 })
             }
-            (value_1, _) => Err(Error::OperatorNotEqualsFirstOperandExpectedPrimitiveType {
+            (value_1, value_2) if value_1.r#type().is_comparable() => {
+                let location = value_1
+                    .location()
+                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+
+                if !value_1.has_the_same_type_as(&value_2) {
+                    return Err(Error::OperatorNotEqualsTypesMismatch {
+                        location,
+                        first: value_1.r#type().to_string(),
+                        second: value_2.r#type().to_string(),
+                    });
+                }
+
+                Ok((
+                    Self::Boolean(Boolean::new(Some(location))),
+                    GeneratorExpressionOperator::not_equals_compound(&value_1.r#type()),
+                ))
+            }
+            (value_1, _) => Err(Error::OperatorNotEqualsFirstOperandExpectedComparable {
                 location: value_1
                     .location()
                     .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
@@ -847,6 +887,10 @@ impl Value {
                 location.or(inner.location),
                 inner.to_owned(),
             )),
+            Type::Enumeration(inner) if inner.is_data() => Self::Enumeration(Enumeration::new(
+                location.or(inner.location),
+                inner.to_owned(),
+            )),
             Type::Enumeration(inner) => {
                 let mut integer =
                     Integer::new(location.or(inner.location), false, inner.bitlength, false);
@@ -884,6 +928,7 @@ impl Value {
             Self::Array(inner) => inner.location,
             Self::Tuple(inner) => inner.location,
             Self::Structure(inner) => inner.location,
+            Self::Enumeration(inner) => inner.location,
             Self::Contract(inner) => inner.location,
         }
     }
@@ -898,6 +943,7 @@ impl ITyped for Value {
             Self::Array(inner) => inner.r#type(),
             Self::Tuple(inner) => inner.r#type(),
             Self::Structure(inner) => inner.r#type(),
+            Self::Enumeration(inner) => inner.r#type(),
             Self::Contract(inner) => inner.r#type(),
         }
     }
@@ -914,6 +960,9 @@ impl ITyped for Value {
             (Self::Structure(value_1), Self::Structure(value_2)) => {
                 value_1.has_the_same_type_as(value_2)
             }
+            (Self::Enumeration(value_1), Self::Enumeration(value_2)) => {
+                value_1.has_the_same_type_as(value_2)
+            }
             (Self::Contract(value_1), Self::Contract(value_2)) => {
                 value_1.has_the_same_type_as(value_2)
             }
@@ -931,6 +980,7 @@ impl fmt::Display for Value {
             Self::Array(inner) => write!(f, "array {}", inner),
             Self::Tuple(inner) => write!(f, "tuple {}", inner),
             Self::Structure(inner) => write!(f, "structure {}", inner),
+            Self::Enumeration(inner) => write!(f, "enumeration {}", inner),
             Self::Contract(inner) => write!(f, "contract {}", inner),
         }
     }
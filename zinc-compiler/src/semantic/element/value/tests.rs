@@ -144,7 +144,29 @@ fn main() {
 }
 
 #[test]
-fn error_operator_equals_1st_operand_expected_primitive() {
+fn error_operator_equals_1st_operand_expected_comparable() {
+    let input = r#"
+contract Test {
+    pub fn method(self) -> bool {
+        self == self
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::OperatorEqualsFirstOperandExpectedComparable {
+            location: Location::test(4, 9),
+            found: "contract Test".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_operator_equals_types_mismatch_compound() {
     let input = r#"
 fn main() {
     let array = [1, 2, 3];
@@ -154,14 +176,15 @@ fn main() {
 "#;
 
     let expected = Err(Error::Semantic(
-        SemanticError::OperatorEqualsFirstOperandExpectedPrimitiveType {
+        SemanticError::OperatorEqualsTypesMismatch {
             location: Location::test(5, 17),
-            found: Type::array(
+            first: Type::array(
                 Some(Location::test(5, 17)),
                 Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
                 3,
             )
             .to_string(),
+            second: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
         },
     ));
 
@@ -237,7 +260,29 @@ fn main() {
 }
 
 #[test]
-fn error_operator_not_equals_1st_operand_expected_primitive() {
+fn error_operator_not_equals_1st_operand_expected_comparable() {
+    let input = r#"
+contract Test {
+    pub fn method(self) -> bool {
+        self != self
+    }
+}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::OperatorNotEqualsFirstOperandExpectedComparable {
+            location: Location::test(4, 9),
+            found: "contract Test".to_owned(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_operator_not_equals_types_mismatch_compound() {
     let input = r#"
 fn main() {
     let array = [1, 2, 3];
@@ -247,14 +292,15 @@ fn main() {
 "#;
 
     let expected = Err(Error::Semantic(
-        SemanticError::OperatorNotEqualsFirstOperandExpectedPrimitiveType {
+        SemanticError::OperatorNotEqualsTypesMismatch {
             location: Location::test(5, 17),
-            found: Type::array(
+            first: Type::array(
                 Some(Location::test(5, 17)),
                 Type::integer_unsigned(None, zinc_const::bitlength::BYTE),
                 3,
             )
             .to_string(),
+            second: Type::integer_unsigned(None, zinc_const::bitlength::BYTE).to_string(),
         },
     ));
 
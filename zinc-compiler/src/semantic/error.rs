@@ -338,9 +338,9 @@ pub enum Error {
         /// The stringified invalid element found instead.
         found: String,
     },
-    /// The `==` operator expects a primitive type value as the first operand.
-    /// Primitive types are units, booleans, and integers.
-    OperatorEqualsFirstOperandExpectedPrimitiveType {
+    /// The `==` operator expects a comparable type value as the first operand.
+    /// Strings, ranges, functions, contracts, and maps cannot be compared.
+    OperatorEqualsFirstOperandExpectedComparable {
         /// The error location data.
         location: Location,
         /// The stringified invalid element found instead.
@@ -374,7 +374,7 @@ pub enum Error {
         /// The stringified invalid element found instead.
         found: String,
     },
-    /// The `==` operator expects two integer values of the same type.
+    /// The `==` operator expects two values of the same type.
     OperatorEqualsTypesMismatch {
         /// The error location data.
         location: Location,
@@ -391,9 +391,9 @@ pub enum Error {
         /// The stringified invalid element found instead.
         found: String,
     },
-    /// The `!=` operator expects a primitive type value as the first operand.
-    /// Primitive types are units, booleans, and integers.
-    OperatorNotEqualsFirstOperandExpectedPrimitiveType {
+    /// The `!=` operator expects a comparable type value as the first operand.
+    /// Strings, ranges, functions, contracts, and maps cannot be compared.
+    OperatorNotEqualsFirstOperandExpectedComparable {
         /// The error location data.
         location: Location,
         /// The stringified invalid element found instead.
@@ -427,7 +427,7 @@ pub enum Error {
         /// The stringified invalid element found instead.
         found: String,
     },
-    /// The `!=` operator expects two integer values of the same type.
+    /// The `!=` operator expects two values of the same type.
     OperatorNotEqualsTypesMismatch {
         /// The error location data.
         location: Location,
@@ -1147,6 +1147,21 @@ pub enum Error {
         /// The stringified invalid element found instead.
         found: String,
     },
+    /// The `[]` index operator can only be applied to a vector memory place, since the vector
+    /// length must be checked at runtime.
+    OperatorIndexVecExpectedPlace {
+        /// The error location data.
+        location: Location,
+        /// The stringified invalid element found instead.
+        found: String,
+    },
+    /// The `[]` index operator expects a vector index integer value as the second operand.
+    OperatorIndexVecExpectedInteger {
+        /// The memory descriptor location, usually a variable name.
+        location: Location,
+        /// The stringified invalid element found instead.
+        found: String,
+    },
     /// The index `[]` operator expects an evaluable element as the second operand.
     OperatorIndexSecondOperandExpectedEvaluable {
         /// The error location data.
@@ -1356,6 +1371,38 @@ pub enum Error {
         name: String,
     },
 
+    /// Mutable references are only allowed as function argument types and call arguments.
+    ReferenceOutsideFunctionArgument {
+        /// The reference type or expression location.
+        location: Location,
+    },
+    /// The `&mut` operator operand must be a memory place, e.g. a variable or its element.
+    ReferenceExpectedPlace {
+        /// The operand location.
+        location: Location,
+        /// The stringified element, found instead of a memory place.
+        found: String,
+    },
+    /// Tried to take a mutable reference to an immutable variable.
+    ReferenceToImmutableMemory {
+        /// The memory descriptor location, usually a variable name.
+        location: Location,
+        /// The name of the immutable variable.
+        name: String,
+        /// The location of the immutable variable. `None` for intrinsic items.
+        reference: Option<Location>,
+    },
+    /// Tried to take a mutable reference to an array element indexed with a runtime value.
+    ReferenceRuntimeIndex {
+        /// The memory descriptor location, usually a variable name.
+        location: Location,
+    },
+    /// Tried to take a mutable reference to the contract storage.
+    ReferenceContractStorage {
+        /// The memory descriptor location.
+        location: Location,
+    },
+
     /// The path expression must point to a type, but points to another kind of item.
     TypeAliasExpectedType {
         /// The path expression location.
@@ -1417,6 +1464,92 @@ pub enum Error {
         /// The number of found generics.
         found: usize,
     },
+    /// The generic parameter cannot be inferred from the actual arguments.
+    TypeGenericParameterNotInferred {
+        /// The generic item usage location.
+        location: Location,
+        /// The generic item name.
+        r#type: String,
+        /// The generic parameter name.
+        name: String,
+    },
+    /// The constant generic parameter type is not an integer one.
+    TypeGenericConstantExpectedInteger {
+        /// The generic parameter location.
+        location: Location,
+        /// The generic item name.
+        r#type: String,
+        /// The generic parameter name.
+        name: String,
+        /// The stringified invalid parameter type.
+        found: String,
+    },
+    /// Only functions can have constant generic parameters.
+    TypeGenericConstantUnavailable {
+        /// The generic parameter location.
+        location: Location,
+        /// The generic item name.
+        r#type: String,
+        /// The generic parameter name.
+        name: String,
+    },
+    /// The generic argument is expected to be a type, but a constant is found.
+    TypeGenericExpectedType {
+        /// The generic type location.
+        location: Location,
+        /// The generic item name.
+        r#type: String,
+        /// The stringified invalid argument.
+        found: String,
+    },
+    /// The generic argument is expected to be an integer constant, but a type is found.
+    TypeGenericExpectedConstant {
+        /// The generic type location.
+        location: Location,
+        /// The generic item name.
+        r#type: String,
+        /// The stringified invalid argument.
+        found: String,
+    },
+    /// The type parameter is not used in the generic item signature.
+    TypeGenericParameterUnused {
+        /// The generic parameter location.
+        location: Location,
+        /// The generic item name.
+        r#type: String,
+        /// The generic parameter name.
+        name: String,
+    },
+    /// The generic item is instantiated again while its instance is being analyzed.
+    TypeGenericRecursive {
+        /// The recursive instantiation location.
+        location: Location,
+        /// The generic item name.
+        r#type: String,
+        /// The generic item declaration location.
+        reference: Location,
+    },
+    /// The enumeration variant carries data, but is used as a unit one.
+    TypeVariantExpectedUnit {
+        /// The variant usage location.
+        location: Location,
+        /// The variant signature.
+        variant: String,
+    },
+    /// The enumeration variant is used as a tuple-like one, but is declared otherwise.
+    TypeVariantExpectedTuple {
+        /// The variant usage location.
+        location: Location,
+        /// The variant signature.
+        variant: String,
+    },
+    /// The enumeration variant is used as a structure-like one, but is declared otherwise.
+    TypeVariantExpectedStructure {
+        /// The variant usage location.
+        location: Location,
+        /// The variant signature.
+        variant: String,
+    },
 
     /// The actual arguments number does not match the formal arguments number.
     FunctionArgumentCount {
@@ -1506,6 +1639,53 @@ pub enum Error {
         /// The function identifier.
         function: String,
     },
+    /// The function takes the argument by mutable reference, but it is passed by value.
+    FunctionArgumentExpectedReference {
+        /// The error location data.
+        location: Location,
+        /// The function identifier.
+        function: String,
+        /// The name of the argument, which must be passed by reference.
+        name: String,
+        /// The position of the invalid argument.
+        position: usize,
+    },
+    /// The function takes the argument by value, but it is passed by mutable reference.
+    FunctionArgumentUnexpectedReference {
+        /// The error location data.
+        location: Location,
+        /// The function identifier.
+        function: String,
+        /// The name of the argument, which must be passed by value.
+        name: String,
+        /// The position of the invalid argument.
+        position: usize,
+    },
+    /// The same memory, or a part of it, is passed as two mutable reference arguments.
+    FunctionArgumentReferenceAliased {
+        /// The error location data.
+        location: Location,
+        /// The function identifier.
+        function: String,
+        /// The position of the argument, which borrows the already borrowed memory.
+        position: usize,
+        /// The location of the argument, which borrows the memory first.
+        reference: Option<Location>,
+    },
+    /// Only ordinary runtime functions and methods can take mutable reference arguments.
+    FunctionReferenceArgumentForbidden {
+        /// The argument location.
+        location: Location,
+        /// The function identifier.
+        function: String,
+    },
+    /// Only runtime functions declared in modules can have generic parameters.
+    FunctionGenericsUnavailable {
+        /// The function location.
+        location: Location,
+        /// The function identifier.
+        function: String,
+    },
     /// Tried to call a function with the `!` specifier, but the function does not require it.
     FunctionUnexpectedExclamationMark {
         /// The error location data.
@@ -1545,6 +1725,15 @@ pub enum Error {
         /// The stringified new length argument value.
         value: String,
     },
+    /// The constant vector length is greater than the vector capacity.
+    FunctionStdlibVecLengthExceedsCapacity {
+        /// The error location data.
+        location: Location,
+        /// The invalid vector length.
+        length: usize,
+        /// The vector capacity, that is, the initial array size.
+        capacity: usize,
+    },
 
     /// The unit test function cannot be called.
     UnitTestCallForbidden {
@@ -1678,12 +1867,6 @@ pub enum Error {
         /// The error location data.
         location: Location,
     },
-    /// A branch with an refutable pattern appears after the irrefutable one, that is, after the
-    /// branch, whose pattern always matches.
-    MatchBranchUnreachable {
-        /// The error location data.
-        location: Location,
-    },
     /// Only constants can act as the branch patterns.
     MatchBranchPatternPathExpectedConstant {
         /// The error location data.
@@ -1720,6 +1903,56 @@ pub enum Error {
         /// The first branch location, which helps user to find the error.
         reference: Location,
     },
+    /// A tuple or tuple-like variant pattern has an invalid number of elements.
+    MatchBranchPatternElementCount {
+        /// The error location data.
+        location: Location,
+        /// The stringified matched tuple type or variant signature.
+        r#type: String,
+        /// The number of expected elements.
+        expected: usize,
+        /// The number of found elements.
+        found: usize,
+    },
+    /// A structure pattern without the `..` rest pattern does not mention some field.
+    MatchBranchPatternFieldMissing {
+        /// The error location data.
+        location: Location,
+        /// The stringified matched structure type or variant signature.
+        r#type: String,
+        /// The missing field name.
+        field_name: String,
+    },
+    /// A structure pattern mentions some field more than once.
+    MatchBranchPatternFieldDuplicate {
+        /// The duplicate field location.
+        location: Location,
+        /// The duplicate field name.
+        field_name: String,
+        /// The first occurrence location, which helps user to find the error.
+        reference: Location,
+    },
+    /// A range pattern does not match any value, since its start is beyond its end.
+    MatchBranchPatternRangeEmpty {
+        /// The error location data.
+        location: Location,
+    },
+    /// A variable is bound by some alternatives of an or-pattern, but not by all of them.
+    MatchBranchPatternAlternativeBindingMissing {
+        /// The alternative location, which does not bind the variable.
+        location: Location,
+        /// The variable name.
+        name: String,
+        /// The binding location in another alternative, which helps user to find the error.
+        reference: Location,
+    },
+    /// The `match` branch guard is not of boolean type.
+    MatchBranchGuardExpectedBooleanCondition {
+        /// The error location data.
+        location: Location,
+        /// The invalid guard type, which is actually found.
+        found: String,
+    },
 
     /// The `while` condition is not of boolean type.
     ForStatementWhileExpectedBooleanCondition {
@@ -1728,13 +1961,22 @@ pub enum Error {
         /// The stringified invalid condition type.
         found: String,
     },
-    /// The loop bounds is not a constant range expression.
-    ForStatementBoundsExpectedConstantRangeExpression {
+    /// The loop bounds is neither a constant range expression nor an array.
+    ForStatementBoundsExpectedConstantRangeOrArray {
         /// The loop bounds expression location.
         location: Location,
         /// The stringified invalid bounds element.
         found: String,
     },
+    /// The arrays iterated with `zip` have different sizes.
+    ForStatementZipArraySizeMismatch {
+        /// The mismatching array expression location.
+        location: Location,
+        /// The size of the first array.
+        expected: usize,
+        /// The size of the mismatching array.
+        found: usize,
+    },
 
     /// Only structure or enumeration types can have an implementation, but another type was found.
     ImplStatementExpectedStructureOrEnumeration {
@@ -1743,6 +1985,108 @@ pub enum Error {
         /// The invalid type identifier.
         found: String,
     },
+    /// Only traits can be implemented for a type using the `impl {trait} for {type}` syntax.
+    ImplStatementExpectedTrait {
+        /// The invalid trait location in the code.
+        location: Location,
+        /// The stringified item found instead of the trait.
+        found: String,
+    },
+    /// The trait implementation lacks a method declared in the trait.
+    ImplStatementTraitMethodMissing {
+        /// The trait implementation location.
+        location: Location,
+        /// The implemented trait identifier.
+        r#trait: String,
+        /// The missing method identifier.
+        method: String,
+        /// The location of the method signature in the trait.
+        reference: Location,
+    },
+    /// The trait implementation contains an item, which is not declared in the trait.
+    ImplStatementTraitItemUnknown {
+        /// The unknown item location.
+        location: Location,
+        /// The implemented trait identifier.
+        r#trait: String,
+        /// The unknown item identifier.
+        name: String,
+    },
+    /// The trait implementation method signature does not match the one declared in the trait.
+    ImplStatementTraitMethodSignatureMismatch {
+        /// The implemented method location.
+        location: Location,
+        /// The implemented trait identifier.
+        r#trait: String,
+        /// The method identifier.
+        method: String,
+        /// The stringified method signature declared in the trait.
+        expected: String,
+        /// The stringified implemented method signature.
+        found: String,
+        /// The location of the method signature in the trait.
+        reference: Location,
+    },
+    /// A trait is used as an expression operand, whereas it can be only implemented for a type.
+    TraitUsedAsExpression {
+        /// The trait path location.
+        location: Location,
+        /// The trait identifier.
+        name: String,
+    },
+    /// A trait method has the same name as a method of another implementation of the type.
+    /// The methods of all implementations share the type namespace.
+    ImplStatementTraitMethodConflict {
+        /// The conflicting method identifier location.
+        location: Location,
+        /// The method identifier.
+        method: String,
+        /// The trait identifier, if the conflicting method belongs to a trait implementation.
+        r#trait: Option<String>,
+        /// The trait identifier, if the earlier method belongs to a trait implementation.
+        other: Option<String>,
+        /// The location of the earlier method identifier.
+        reference: Location,
+    },
+    /// A trait is used as a path namespace, e.g. `Hashable::hash(value)`.
+    TraitUsedAsNamespace {
+        /// The trait path element location.
+        location: Location,
+        /// The trait identifier.
+        name: String,
+    },
+    /// The generic structure cannot have a trait implementation.
+    ImplStatementGenericTraitUnavailable {
+        /// The implemented type identifier location.
+        location: Location,
+        /// The implemented trait identifier.
+        r#trait: String,
+        /// The implemented type identifier.
+        found: String,
+    },
+
+    /// The `return` statement is used beyond a function body, e.g. in a module constant.
+    ReturnStatementBeyondFunction {
+        /// The `return` statement location.
+        location: Location,
+    },
+    /// The `return` statement is used in a constant function, which is evaluated at compile time.
+    ReturnStatementInConstantFunction {
+        /// The `return` statement location.
+        location: Location,
+        /// The constant function identifier.
+        function: String,
+    },
+    /// The `break` statement is used beyond a loop body.
+    BreakStatementBeyondLoop {
+        /// The `break` statement location.
+        location: Location,
+    },
+    /// The `continue` statement is used beyond a loop body.
+    ContinueStatementBeyondLoop {
+        /// The `continue` statement location.
+        location: Location,
+    },
 
     /// The element after the `use` keyword must be a path to an item.
     UseStatementExpectedPath {
@@ -1802,6 +2146,13 @@ pub enum Error {
         /// The attribute name.
         name: String,
     },
+    /// The lint passed to the `#[allow(...)]` attribute is unknown.
+    AttributeUnknownLint {
+        /// The error location data.
+        location: Location,
+        /// The invalid lint name.
+        found: String,
+    },
 
     /// The type must be explicitly specified for this binding.
     BindingTypeRequired {
@@ -1869,7 +2220,7 @@ impl Error {
     ///
     /// Returns the semantic error code.
     ///
-    /// The last error code is `243` at `AttributeExpectedNested`.
+    /// The last error code is `272` at `FunctionReferenceArgumentForbidden`.
     ///
     /// Do not remove nor uncomment the commented out errors, as they
     /// help to see error codes from the previous Zinc versions.
@@ -1892,16 +2243,36 @@ impl Error {
             Self::MatchScrutineeInvalidType { .. } => 11,
             Self::MatchNotExhausted { .. } => 12,
             Self::MatchLessThanTwoBranches { .. } => 13,
-            Self::MatchBranchUnreachable { .. } => 14,
+            // Self::MatchBranchUnreachable { .. } => 14,
             Self::MatchBranchPatternPathExpectedConstant { .. } => 15,
             Self::MatchBranchPatternInvalidType { .. } => 16,
             Self::MatchBranchExpressionInvalidType { .. } => 17,
             Self::MatchBranchDuplicate { .. } => 18,
+            Self::MatchBranchPatternElementCount { .. } => 261,
+            Self::MatchBranchPatternFieldMissing { .. } => 262,
+            Self::MatchBranchPatternFieldDuplicate { .. } => 263,
+            Self::MatchBranchPatternRangeEmpty { .. } => 274,
+            Self::MatchBranchPatternAlternativeBindingMissing { .. } => 275,
+            Self::MatchBranchGuardExpectedBooleanCondition { .. } => 276,
 
             Self::ForStatementWhileExpectedBooleanCondition { .. } => 19,
-            Self::ForStatementBoundsExpectedConstantRangeExpression { .. } => 20,
+            Self::ForStatementBoundsExpectedConstantRangeOrArray { .. } => 20,
+            Self::ForStatementZipArraySizeMismatch { .. } => 273,
 
             Self::ImplStatementExpectedStructureOrEnumeration { .. } => 21,
+            Self::ImplStatementExpectedTrait { .. } => 248,
+            Self::ImplStatementTraitMethodMissing { .. } => 249,
+            Self::ImplStatementTraitItemUnknown { .. } => 250,
+            Self::ImplStatementTraitMethodSignatureMismatch { .. } => 251,
+            Self::TraitUsedAsExpression { .. } => 252,
+            Self::ImplStatementGenericTraitUnavailable { .. } => 257,
+            Self::ImplStatementTraitMethodConflict { .. } => 283,
+            Self::TraitUsedAsNamespace { .. } => 284,
+
+            Self::ReturnStatementBeyondFunction { .. } => 244,
+            Self::ReturnStatementInConstantFunction { .. } => 245,
+            Self::BreakStatementBeyondLoop { .. } => 246,
+            Self::ContinueStatementBeyondLoop { .. } => 247,
 
             Self::UseStatementExpectedPath { .. } => 22,
 
@@ -1911,6 +2282,7 @@ impl Error {
             Self::AttributeExpectedElement { .. } => 241,
             Self::AttributeExpectedIntegerLiteral { .. } => 242,
             Self::AttributeExpectedNested { .. } => 243,
+            Self::AttributeUnknownLint { .. } => 264,
 
             Self::BindingTypeRequired { .. } => 24,
             Self::BindingExpectedTuple { .. } => 25,
@@ -1927,6 +2299,12 @@ impl Error {
             Self::MutatingImmutableMemory { .. } => 34,
             Self::MutatingImmutableContractField { .. } => 35,
 
+            Self::ReferenceOutsideFunctionArgument { .. } => 265,
+            Self::ReferenceExpectedPlace { .. } => 266,
+            Self::ReferenceToImmutableMemory { .. } => 267,
+            Self::ReferenceRuntimeIndex { .. } => 268,
+            Self::ReferenceContractStorage { .. } => 269,
+
             Self::TypeAliasExpectedType { .. } => 36,
             Self::TypeInstantiationForbidden { .. } => 37,
             Self::TypeDuplicateField { .. } => 38,
@@ -1934,6 +2312,16 @@ impl Error {
             Self::TypeUnexpectedGenerics { .. } => 40,
             Self::TypeExpectedGenerics { .. } => 41,
             Self::TypeInvalidGenericsNumber { .. } => 42,
+            Self::TypeGenericParameterNotInferred { .. } => 253,
+            Self::TypeGenericConstantExpectedInteger { .. } => 254,
+            Self::TypeGenericConstantUnavailable { .. } => 255,
+            Self::TypeGenericExpectedType { .. } => 277,
+            Self::TypeGenericExpectedConstant { .. } => 278,
+            Self::TypeGenericParameterUnused { .. } => 285,
+            Self::TypeGenericRecursive { .. } => 286,
+            Self::TypeVariantExpectedUnit { .. } => 258,
+            Self::TypeVariantExpectedTuple { .. } => 259,
+            Self::TypeVariantExpectedStructure { .. } => 260,
 
             Self::FunctionArgumentCount { .. } => 43,
             Self::FunctionDebugArgumentCount { .. } => 44,
@@ -1943,11 +2331,17 @@ impl Error {
             Self::FunctionReturnType { .. } => 48,
             Self::FunctionNonCallable { .. } => 49,
             Self::FunctionCallMutableFromImmutable { .. } => 50,
+            Self::FunctionGenericsUnavailable { .. } => 256,
+            Self::FunctionArgumentExpectedReference { .. } => 270,
+            Self::FunctionArgumentUnexpectedReference { .. } => 271,
+            Self::FunctionReferenceArgumentForbidden { .. } => 272,
+            Self::FunctionArgumentReferenceAliased { .. } => 282,
             Self::FunctionUnexpectedExclamationMark { .. } => 51,
             Self::FunctionExpectedExclamationMark { .. } => 52,
             Self::FunctionStdlibArrayTruncatingToBiggerSize { .. } => 53,
             Self::FunctionStdlibArrayPaddingToLesserSize { .. } => 54,
             Self::FunctionStdlibArrayNewLengthInvalid { .. } => 55,
+            Self::FunctionStdlibVecLengthExceedsCapacity { .. } => 279,
 
             Self::InvalidInteger {
                 inner: zinc_math::Error::NumberParsing(_),
@@ -2009,14 +2403,14 @@ impl Error {
             Self::OperatorAndSecondOperandExpectedEvaluable { .. } => 100,
             Self::OperatorAndSecondOperandExpectedBoolean { .. } => 101,
             Self::OperatorEqualsFirstOperandExpectedEvaluable { .. } => 102,
-            Self::OperatorEqualsFirstOperandExpectedPrimitiveType { .. } => 103,
+            Self::OperatorEqualsFirstOperandExpectedComparable { .. } => 103,
             Self::OperatorEqualsSecondOperandExpectedEvaluable { .. } => 104,
             Self::OperatorEqualsSecondOperandExpectedUnit { .. } => 105,
             Self::OperatorEqualsSecondOperandExpectedBoolean { .. } => 106,
             Self::OperatorEqualsSecondOperandExpectedInteger { .. } => 107,
             Self::OperatorEqualsTypesMismatch { .. } => 108,
             Self::OperatorNotEqualsFirstOperandExpectedEvaluable { .. } => 109,
-            Self::OperatorNotEqualsFirstOperandExpectedPrimitiveType { .. } => 110,
+            Self::OperatorNotEqualsFirstOperandExpectedComparable { .. } => 110,
             Self::OperatorNotEqualsSecondOperandExpectedEvaluable { .. } => 111,
             Self::OperatorNotEqualsSecondOperandExpectedUnit { .. } => 112,
             Self::OperatorNotEqualsSecondOperandExpectedBoolean { .. } => 113,
@@ -2124,6 +2518,8 @@ impl Error {
             Self::OperatorNegationFieldOperandForbidden { .. } => 209,
             Self::OperatorIndexFirstOperandExpectedPlaceOrEvaluable { .. } => 210,
             Self::OperatorIndexFirstOperandExpectedArray { .. } => 211,
+            Self::OperatorIndexVecExpectedPlace { .. } => 280,
+            Self::OperatorIndexVecExpectedInteger { .. } => 281,
             Self::OperatorIndexSecondOperandExpectedEvaluable { .. } => 212,
             Self::OperatorIndexSecondOperandExpectedIntegerOrRange { .. } => 213,
             Self::OperatorDotFirstOperandExpectedPlaceOrEvaluable { .. } => 214,
@@ -0,0 +1,29 @@
+//!
+//! The semantic analyzer generic item actual argument.
+//!
+
+use std::fmt;
+
+use num::BigInt;
+
+use crate::semantic::element::r#type::Type;
+
+///
+/// The generic item actual argument, which is bound to a generic parameter.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Argument {
+    /// The type argument bound to a type parameter like `T`.
+    Type(Type),
+    /// The constant argument bound to a constant parameter like `const N: u8`.
+    Constant(BigInt),
+}
+
+impl fmt::Display for Argument {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Type(inner) => write!(f, "{}", inner),
+            Self::Constant(inner) => write!(f, "{}", inner),
+        }
+    }
+}
@@ -0,0 +1,54 @@
+//!
+//! The semantic analyzer generic structure implementation.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Location;
+use zinc_syntax::ImplementationLocalStatement;
+use zinc_syntax::Type as SyntaxType;
+
+use crate::semantic::generics::parameter::Parameter;
+use crate::semantic::scope::Scope;
+
+///
+/// The implementation of a generic structure, e.g. `impl<T> Pair<T>` or `impl Pair<u8>`.
+///
+/// The implementation items are declared in the scope of each structure instance, whose actual
+/// generic arguments match the implemented type `arguments`.
+///
+#[derive(Debug, Clone)]
+pub struct Implementation {
+    /// The `impl` statement location.
+    pub location: Location,
+    /// The implementation generic parameters, which are inferred from the instance arguments.
+    pub parameters: Vec<Parameter>,
+    /// The implemented type generic arguments, which may refer to the `parameters`.
+    pub arguments: Vec<SyntaxType>,
+    /// The implementation items, that is, constants and methods.
+    pub statements: Vec<ImplementationLocalStatement>,
+    /// The scope where the `impl` statement is declared.
+    pub scope: Rc<RefCell<Scope>>,
+}
+
+impl Implementation {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        location: Location,
+        parameters: Vec<Parameter>,
+        arguments: Vec<SyntaxType>,
+        statements: Vec<ImplementationLocalStatement>,
+        scope: Rc<RefCell<Scope>>,
+    ) -> Self {
+        Self {
+            location,
+            parameters,
+            arguments,
+            statements,
+            scope,
+        }
+    }
+}
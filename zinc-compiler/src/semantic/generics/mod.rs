@@ -0,0 +1,648 @@
+//!
+//! The semantic analyzer generic items.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod argument;
+pub mod implementation;
+pub mod parameter;
+
+use std::cell::Cell;
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+
+use num::BigInt;
+use num::Signed;
+
+use zinc_lexical::Keyword;
+use zinc_lexical::Location;
+use zinc_syntax::ExpressionOperand;
+use zinc_syntax::ExpressionTree;
+use zinc_syntax::ExpressionTreeNode;
+use zinc_syntax::GenericParameter;
+use zinc_syntax::ImplementationLocalStatement;
+use zinc_syntax::Type as SyntaxType;
+use zinc_syntax::TypeVariant as SyntaxTypeVariant;
+
+use crate::generator::statement::Statement as GeneratorStatement;
+use crate::semantic::element::constant::integer::Integer as IntegerConstant;
+use crate::semantic::element::constant::Constant;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error;
+use crate::semantic::scope::item::constant::Constant as ConstantItem;
+use crate::semantic::scope::item::r#type::state::State as TypeItemState;
+use crate::semantic::scope::item::r#type::statement::Statement as TypeStatementVariant;
+use crate::semantic::scope::item::r#type::Type as TypeItem;
+use crate::semantic::scope::item::Item as ScopeItem;
+use crate::semantic::scope::r#type::Type as ScopeType;
+use crate::semantic::scope::Scope;
+
+use self::argument::Argument;
+use self::implementation::Implementation;
+use self::parameter::Parameter;
+
+///
+/// The generic item template, which is a function or structure with generic parameters.
+///
+/// The template statement is analyzed separately for each set of the actual generic arguments,
+/// that is, the generic items are monomorphized. The instances are cached, so the same set
+/// of arguments always yields the same type.
+///
+/// The generic structure implementations are stored in the template as well, since their items
+/// are declared in the scope of each matching instance.
+///
+#[derive(Debug)]
+pub struct Template {
+    /// The location where the generic item is declared.
+    pub location: Location,
+    /// The generic item identifier.
+    pub identifier: String,
+    /// The generic formal parameters.
+    pub parameters: Vec<Parameter>,
+    /// The generic item statement without the generic parameters.
+    pub statement: TypeStatementVariant,
+    /// The scope where the generic item is declared.
+    pub scope: Rc<RefCell<Scope>>,
+    /// The instantiated items with their actual generic arguments.
+    instances: RefCell<Vec<(Vec<Argument>, Rc<RefCell<ScopeItem>>)>>,
+    /// The generic structure implementations.
+    implementations: RefCell<Vec<Implementation>>,
+    /// Whether an instance is being analyzed, which is used to detect the recursive items.
+    is_instantiating: Cell<bool>,
+}
+
+impl Template {
+    ///
+    /// Creates a template, resolving the constant parameter types in `scope`.
+    ///
+    pub fn new(
+        location: Location,
+        identifier: String,
+        parameters: Vec<GenericParameter>,
+        statement: TypeStatementVariant,
+        scope: Rc<RefCell<Scope>>,
+    ) -> Result<Self, Error> {
+        let parameters = Self::parameters(identifier.as_str(), parameters, scope.clone())?;
+
+        let signature: Vec<&SyntaxType> = match statement {
+            TypeStatementVariant::Fn(ref inner) => inner
+                .argument_bindings
+                .iter()
+                .filter_map(|binding| binding.r#type.as_ref())
+                .chain(inner.return_type.as_ref())
+                .collect(),
+            TypeStatementVariant::Struct(ref inner) => {
+                inner.fields.iter().map(|field| &field.r#type).collect()
+            }
+            _ => panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        };
+        Self::check_unused(
+            identifier.as_str(),
+            parameters.as_slice(),
+            signature.as_slice(),
+        )?;
+
+        Ok(Self {
+            location,
+            identifier,
+            parameters,
+            statement,
+            scope,
+            instances: RefCell::new(vec![]),
+            implementations: RefCell::new(vec![]),
+            is_instantiating: Cell::new(false),
+        })
+    }
+
+    ///
+    /// Checks the generic `parameters` of the `identifier` item for duplicates, resolving
+    /// the constant parameter types in `scope`.
+    ///
+    pub fn parameters(
+        identifier: &str,
+        parameters: Vec<GenericParameter>,
+        scope: Rc<RefCell<Scope>>,
+    ) -> Result<Vec<Parameter>, Error> {
+        let mut semantic_parameters: Vec<Parameter> = Vec::with_capacity(parameters.len());
+        for parameter in parameters.into_iter() {
+            if let Some(duplicate) = semantic_parameters
+                .iter()
+                .find(|duplicate| duplicate.identifier == parameter.identifier.name)
+            {
+                return Err(Error::ScopeItemRedeclared {
+                    location: parameter.identifier.location,
+                    name: parameter.identifier.name,
+                    reference: Some(duplicate.location),
+                });
+            }
+
+            let r#type = match parameter.r#type {
+                Some(r#type) => {
+                    let type_location = r#type.location;
+                    match Type::try_from_syntax(r#type, scope.clone())? {
+                        r#type @ Type::IntegerUnsigned { .. } => Some(r#type),
+                        r#type @ Type::IntegerSigned { .. } => Some(r#type),
+                        r#type @ Type::Field(_) => Some(r#type),
+                        r#type => {
+                            return Err(Error::TypeGenericConstantExpectedInteger {
+                                location: type_location,
+                                r#type: identifier.to_owned(),
+                                name: parameter.identifier.name,
+                                found: r#type.to_string(),
+                            })
+                        }
+                    }
+                }
+                None => None,
+            };
+
+            semantic_parameters.push(Parameter::new(
+                parameter.location,
+                parameter.identifier.name,
+                r#type,
+            ));
+        }
+
+        Ok(semantic_parameters)
+    }
+
+    ///
+    /// Checks whether each type parameter of the `identifier` item is used in its `signature`,
+    /// since otherwise it cannot be inferred.
+    ///
+    pub fn check_unused(
+        identifier: &str,
+        parameters: &[Parameter],
+        signature: &[&SyntaxType],
+    ) -> Result<(), Error> {
+        for parameter in parameters
+            .iter()
+            .filter(|parameter| parameter.r#type.is_none())
+        {
+            if !signature
+                .iter()
+                .any(|r#type| Self::is_mentioned(r#type, parameter.identifier.as_str()))
+            {
+                return Err(Error::TypeGenericParameterUnused {
+                    location: parameter.location,
+                    r#type: identifier.to_owned(),
+                    name: parameter.identifier.to_owned(),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Infers the generic arguments by matching the syntax types from the template statement
+    /// against the actual types.
+    ///
+    /// If a parameter is inferred differently several times, the first occurrence wins, and
+    /// the mismatch is reported later while validating the instance.
+    ///
+    pub fn infer(
+        &self,
+        location: Location,
+        pairs: Vec<(&SyntaxType, Type)>,
+    ) -> Result<Vec<Argument>, Error> {
+        let mut inferred = HashMap::with_capacity(self.parameters.len());
+        for (pattern, actual) in pairs.into_iter() {
+            Self::infer_type(self.parameters.as_slice(), pattern, &actual, &mut inferred);
+        }
+
+        self.parameters
+            .iter()
+            .map(|parameter| {
+                inferred
+                    .remove(parameter.identifier.as_str())
+                    .ok_or_else(|| Error::TypeGenericParameterNotInferred {
+                        location,
+                        r#type: self.identifier.to_owned(),
+                        name: parameter.identifier.to_owned(),
+                    })
+            })
+            .collect()
+    }
+
+    ///
+    /// Returns the template instance for the actual generic `arguments`.
+    ///
+    /// If the instance does not exist yet, the template statement is analyzed in a separate
+    /// scope, where the generic parameters are bound to the `arguments`. The template cannot be
+    /// instantiated again until the analysis is finished, since the item would be recursive.
+    ///
+    /// The new structure instance gets the items of its matching implementations.
+    ///
+    pub fn instantiate(&self, location: Location, arguments: Vec<Argument>) -> Result<Type, Error> {
+        if self.is_instantiating.get() {
+            return Err(Error::TypeGenericRecursive {
+                location,
+                r#type: self.identifier.to_owned(),
+                reference: self.location,
+            });
+        }
+
+        let instance = self
+            .instances
+            .borrow()
+            .iter()
+            .find(|(instance_arguments, _item)| instance_arguments == &arguments)
+            .map(|(_arguments, item)| item.to_owned());
+        if let Some(item) = instance {
+            return Self::define_instance(&item);
+        }
+
+        let identifier = format!(
+            "{}<{}>",
+            self.identifier,
+            arguments
+                .iter()
+                .map(|argument| argument.to_string())
+                .collect::<Vec<String>>()
+                .join(", ")
+        );
+
+        let scope = Scope::new_child(identifier.clone(), ScopeType::Generic, self.scope.clone());
+        Self::bind(
+            scope.clone(),
+            location,
+            self.parameters.as_slice(),
+            arguments.as_slice(),
+        )?;
+
+        let mut statement = self.statement.to_owned();
+        match statement {
+            TypeStatementVariant::Fn(ref mut inner) => inner.identifier.name = identifier,
+            TypeStatementVariant::Struct(ref mut inner) => inner.identifier.name = identifier,
+            _ => panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        }
+
+        let item = ScopeItem::Type(TypeItem::new_declared(
+            Some(self.location),
+            statement,
+            scope,
+        )?)
+        .wrap();
+        self.instances
+            .borrow_mut()
+            .push((arguments.clone(), item.clone()));
+
+        self.is_instantiating.set(true);
+        let result = Self::define_instance(&item);
+        self.is_instantiating.set(false);
+
+        let r#type = match result {
+            Ok(r#type) => r#type,
+            Err(error) => {
+                self.instances
+                    .borrow_mut()
+                    .retain(|(_arguments, instance)| !Rc::ptr_eq(instance, &item));
+                return Err(error);
+            }
+        };
+
+        match r#type {
+            Type::Structure(mut structure) => {
+                let mut params = HashMap::with_capacity(arguments.len());
+                for (parameter, argument) in self.parameters.iter().zip(arguments.iter()) {
+                    if let Argument::Type(r#type) = argument {
+                        params.insert(parameter.identifier.to_owned(), r#type.to_owned());
+                    }
+                }
+                structure.generics = Some(
+                    self.parameters
+                        .iter()
+                        .map(|parameter| parameter.identifier.to_owned())
+                        .collect(),
+                );
+                structure.params = Some(params);
+
+                let r#type = Type::Structure(structure);
+                if let ScopeItem::Type(ref instance) = *RefCell::borrow(&item) {
+                    instance.state.replace(Some(TypeItemState::Defined {
+                        inner: r#type.clone(),
+                        intermediate: None,
+                    }));
+                }
+
+                let implementations = self.implementations.borrow().clone();
+                for implementation in implementations.iter() {
+                    Self::apply(implementation, arguments.as_slice(), &item)?;
+                }
+
+                Ok(r#type)
+            }
+            r#type => Ok(r#type),
+        }
+    }
+
+    ///
+    /// Adds the generic structure `implementation`, declaring its items in the existing matching
+    /// instances and in the ones which are instantiated later.
+    ///
+    pub fn implement(&self, implementation: Implementation) -> Result<(), Error> {
+        let instances = self.instances.borrow().clone();
+        for (arguments, item) in instances.iter() {
+            Self::apply(&implementation, arguments.as_slice(), item)?;
+        }
+
+        self.implementations.borrow_mut().push(implementation);
+
+        Ok(())
+    }
+
+    ///
+    /// Extracts the intermediate representation from the template instances.
+    ///
+    pub fn get_intermediate(&self) -> Vec<GeneratorStatement> {
+        self.instances
+            .borrow()
+            .iter()
+            .flat_map(|(_arguments, item)| RefCell::borrow(item).get_intermediate())
+            .collect()
+    }
+
+    ///
+    /// Defines the template instance, which is always a type item.
+    ///
+    fn define_instance(item: &Rc<RefCell<ScopeItem>>) -> Result<Type, Error> {
+        match *RefCell::borrow(item) {
+            ScopeItem::Type(ref r#type) => r#type.define(),
+            _ => panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        }
+    }
+
+    ///
+    /// Binds the generic `parameters` to the actual `arguments` in the `scope`.
+    ///
+    fn bind(
+        scope: Rc<RefCell<Scope>>,
+        location: Location,
+        parameters: &[Parameter],
+        arguments: &[Argument],
+    ) -> Result<(), Error> {
+        for (parameter, argument) in parameters.iter().zip(arguments.iter()) {
+            let item = match (parameter.r#type.as_ref(), argument) {
+                (None, Argument::Type(r#type)) => ScopeItem::Type(TypeItem::new_defined(
+                    Some(parameter.location),
+                    r#type.to_owned(),
+                    true,
+                    None,
+                )),
+                (Some(r#type), Argument::Constant(value)) => {
+                    let (is_signed, bitlength) = match r#type {
+                        Type::IntegerUnsigned { bitlength, .. } => (false, *bitlength),
+                        Type::IntegerSigned { bitlength, .. } => (true, *bitlength),
+                        Type::Field(_) => (false, zinc_const::bitlength::FIELD),
+                        _ => panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+                    };
+
+                    let (constant, _intermediate) = IntegerConstant::new(
+                        location,
+                        value.to_owned(),
+                        value.is_negative(),
+                        zinc_const::bitlength::FIELD,
+                        false,
+                    )
+                    .cast(is_signed, bitlength)?;
+
+                    ScopeItem::Constant(ConstantItem::new_defined(
+                        parameter.location,
+                        Constant::Integer(constant),
+                    ))
+                }
+                _ => panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+            };
+
+            Scope::insert_item(scope.clone(), parameter.identifier.to_owned(), item.wrap());
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Declares the `implementation` items in the scope of the structure instance `item`,
+    /// if the instance actual generic `arguments` match the implemented type.
+    ///
+    /// The implementation parameters are inferred from the `arguments` and bound in a separate
+    /// scope, where the implementation items are defined later along with the `Self` alias.
+    ///
+    fn apply(
+        implementation: &Implementation,
+        arguments: &[Argument],
+        item: &Rc<RefCell<ScopeItem>>,
+    ) -> Result<(), Error> {
+        let type_scope = match Self::define_instance(item)? {
+            Type::Structure(structure) => structure.scope,
+            _ => panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        };
+
+        let mut inferred = HashMap::with_capacity(implementation.parameters.len());
+        for (pattern, argument) in implementation.arguments.iter().zip(arguments.iter()) {
+            if let Argument::Type(actual) = argument {
+                Self::infer_type(
+                    implementation.parameters.as_slice(),
+                    pattern,
+                    actual,
+                    &mut inferred,
+                );
+            }
+        }
+        let parameters: Option<Vec<Argument>> = implementation
+            .parameters
+            .iter()
+            .map(|parameter| inferred.remove(parameter.identifier.as_str()))
+            .collect();
+        let parameters = match parameters {
+            Some(parameters) => parameters,
+            None => return Ok(()),
+        };
+
+        let scope = Scope::new_child(
+            format!("impl {}", RefCell::borrow(&type_scope).name()),
+            ScopeType::Generic,
+            implementation.scope.clone(),
+        );
+        Self::bind(
+            scope.clone(),
+            implementation.location,
+            implementation.parameters.as_slice(),
+            parameters.as_slice(),
+        )?;
+
+        for (pattern, argument) in implementation.arguments.iter().zip(arguments.iter()) {
+            let is_matching = match argument {
+                Argument::Type(actual) => {
+                    &Type::try_from_syntax(pattern.to_owned(), scope.clone())? == actual
+                }
+                Argument::Constant(_) => false,
+            };
+            if !is_matching {
+                return Ok(());
+            }
+        }
+
+        Scope::insert_item(
+            scope.clone(),
+            Keyword::SelfUppercase.to_string(),
+            item.to_owned(),
+        );
+
+        for statement in implementation.statements.iter() {
+            match statement {
+                ImplementationLocalStatement::Const(constant) => {
+                    let item = ScopeItem::Constant(ConstantItem::new_declared(
+                        constant.identifier.location,
+                        constant.to_owned(),
+                        scope.clone(),
+                    ));
+                    Scope::define_item(
+                        type_scope.clone(),
+                        constant.identifier.to_owned(),
+                        item.wrap(),
+                    )?;
+                }
+                ImplementationLocalStatement::Fn(method) => {
+                    let item = ScopeItem::Type(TypeItem::new_declared(
+                        Some(method.location),
+                        TypeStatementVariant::Fn(method.to_owned()),
+                        scope.clone(),
+                    )?);
+                    Scope::define_item(
+                        type_scope.clone(),
+                        method.identifier.to_owned(),
+                        item.wrap(),
+                    )?;
+                }
+                ImplementationLocalStatement::Empty(_location) => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Matches the `pattern` syntax type against the `actual` semantic type, recording
+    /// the generic parameters found in the pattern.
+    ///
+    fn infer_type(
+        parameters: &[Parameter],
+        pattern: &SyntaxType,
+        actual: &Type,
+        inferred: &mut HashMap<String, Argument>,
+    ) {
+        match (&pattern.variant, actual) {
+            (
+                SyntaxTypeVariant::Alias {
+                    path,
+                    generics: None,
+                },
+                actual,
+            ) => {
+                if let Some(parameter) = Self::parameter(parameters, path) {
+                    if parameter.r#type.is_none() {
+                        inferred
+                            .entry(parameter.identifier.to_owned())
+                            .or_insert_with(|| Argument::Type(actual.to_owned()));
+                    }
+                }
+            }
+            (
+                SyntaxTypeVariant::Alias {
+                    generics: Some(generics),
+                    ..
+                },
+                Type::Structure(structure),
+            ) => {
+                if let (Some(names), Some(params)) =
+                    (structure.generics.as_ref(), structure.params.as_ref())
+                {
+                    for (pattern, name) in generics.iter().zip(names.iter()) {
+                        if let Some(actual) = params.get(name) {
+                            Self::infer_type(parameters, pattern, actual, inferred);
+                        }
+                    }
+                }
+            }
+            (SyntaxTypeVariant::Array { inner, size }, Type::Array(array)) => {
+                Self::infer_type(parameters, inner, &array.r#type, inferred);
+
+                if let Some(parameter) = Self::parameter(parameters, size) {
+                    if parameter.r#type.is_some() {
+                        inferred
+                            .entry(parameter.identifier.to_owned())
+                            .or_insert_with(|| Argument::Constant(BigInt::from(array.size)));
+                    }
+                }
+            }
+            (SyntaxTypeVariant::MutableReference { inner }, actual) => {
+                Self::infer_type(parameters, inner, actual, inferred);
+            }
+            (SyntaxTypeVariant::Tuple { inners }, Type::Tuple(tuple)) => {
+                for (pattern, actual) in inners.iter().zip(tuple.types.iter()) {
+                    Self::infer_type(parameters, pattern, actual, inferred);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    ///
+    /// Returns the generic parameter, if the `expression` consists of its identifier only.
+    ///
+    fn parameter<'a>(
+        parameters: &'a [Parameter],
+        expression: &ExpressionTree,
+    ) -> Option<&'a Parameter> {
+        let name = Self::identifier(expression)?;
+        parameters
+            .iter()
+            .find(|parameter| parameter.identifier == name)
+    }
+
+    ///
+    /// Checks whether the type parameter `name` is used in the `type` of the item signature.
+    ///
+    /// The array sizes are skipped, since they can only refer to the constant parameters.
+    ///
+    fn is_mentioned(r#type: &SyntaxType, name: &str) -> bool {
+        match r#type.variant {
+            SyntaxTypeVariant::Alias {
+                ref path,
+                ref generics,
+            } => {
+                Self::identifier(path) == Some(name)
+                    || generics
+                        .iter()
+                        .flatten()
+                        .any(|generic| Self::is_mentioned(generic, name))
+            }
+            SyntaxTypeVariant::Array { ref inner, .. } => Self::is_mentioned(inner, name),
+            SyntaxTypeVariant::Tuple { ref inners } => {
+                inners.iter().any(|inner| Self::is_mentioned(inner, name))
+            }
+            SyntaxTypeVariant::MutableReference { ref inner } => Self::is_mentioned(inner, name),
+            _ => false,
+        }
+    }
+
+    ///
+    /// Returns the identifier name, if the `expression` consists of an identifier only.
+    ///
+    fn identifier(expression: &ExpressionTree) -> Option<&str> {
+        if expression.left.is_some() || expression.right.is_some() {
+            return None;
+        }
+
+        match *expression.value {
+            ExpressionTreeNode::Operand(ExpressionOperand::Identifier(ref identifier)) => {
+                Some(identifier.name.as_str())
+            }
+            _ => None,
+        }
+    }
+}
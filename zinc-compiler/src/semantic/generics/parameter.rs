@@ -0,0 +1,44 @@
+//!
+//! The semantic analyzer generic item formal parameter.
+//!
+
+use std::fmt;
+
+use zinc_lexical::Location;
+
+use crate::semantic::element::r#type::Type;
+
+///
+/// The generic item formal parameter.
+///
+#[derive(Debug, Clone)]
+pub struct Parameter {
+    /// The location where the parameter is declared.
+    pub location: Location,
+    /// The parameter identifier.
+    pub identifier: String,
+    /// The constant parameter integer type, which is `None` for type parameters.
+    pub r#type: Option<Type>,
+}
+
+impl Parameter {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(location: Location, identifier: String, r#type: Option<Type>) -> Self {
+        Self {
+            location,
+            identifier,
+            r#type,
+        }
+    }
+}
+
+impl fmt::Display for Parameter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.r#type {
+            Some(ref r#type) => write!(f, "const {}: {}", self.identifier, r#type),
+            None => write!(f, "{}", self.identifier),
+        }
+    }
+}
@@ -0,0 +1,165 @@
+//!
+//! The generic items tests.
+//!
+
+use zinc_lexical::Location;
+
+use crate::error::Error;
+use crate::semantic::element::r#type::Type;
+use crate::semantic::error::Error as SemanticError;
+
+#[test]
+fn ok_instances_cached() {
+    let input = r#"
+struct Wrapper<T> {
+    value: T,
+}
+
+fn wrap<T>(value: T) -> Wrapper<T> {
+    Wrapper { value: value }
+}
+
+fn main() -> u8 {
+    let mut wrapper: Wrapper<u8> = wrap(1);
+    wrapper = wrap(2);
+    wrapper.value
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn ok_parameter_shadows_item() {
+    let input = r#"
+type T = bool;
+
+fn identity<T>(value: T) -> T {
+    value
+}
+
+fn main() -> u8 {
+    identity(42)
+}
+"#;
+
+    assert!(crate::semantic::tests::compile_entry(input).is_ok());
+}
+
+#[test]
+fn error_parameter_duplicate() {
+    let input = r#"
+fn identity<T, T>(value: T) -> T {
+    value
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::ScopeItemRedeclared {
+        location: Location::test(2, 16),
+        name: "T".to_owned(),
+        reference: Some(Location::test(2, 13)),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_constant_expected_integer() {
+    let input = r#"
+fn length<const N: bool>(values: [u8; N]) -> u8 {
+    0
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(
+        SemanticError::TypeGenericConstantExpectedInteger {
+            location: Location::test(2, 20),
+            r#type: "length".to_owned(),
+            name: "N".to_owned(),
+            found: Type::boolean(None).to_string(),
+        },
+    ));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_recursive() {
+    let input = r#"
+struct List<T> {
+    value: T,
+    next: List<T>,
+}
+
+fn main() {
+    let list = List { value: 42, next: 0 };
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::TypeGenericRecursive {
+        location: Location::test(4, 11),
+        r#type: "List".to_owned(),
+        reference: Location::test(2, 1),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_recursive_polymorphic() {
+    let input = r#"
+struct Wrapper<T> {
+    value: T,
+    inner: Wrapper<(T, T)>,
+}
+
+fn unwrap(wrapper: Wrapper<u8>) -> u8 {
+    wrapper.value
+}
+
+fn main() {}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::TypeGenericRecursive {
+        location: Location::test(4, 12),
+        r#type: "Wrapper".to_owned(),
+        reference: Location::test(2, 1),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_recursive_function() {
+    let input = r#"
+fn count<T>(value: T) -> u8 {
+    count(42)
+}
+
+fn main() -> u8 {
+    count(true)
+}
+"#;
+
+    let expected = Err(Error::Semantic(SemanticError::TypeGenericRecursive {
+        location: Location::test(3, 10),
+        r#type: "count".to_owned(),
+        reference: Location::test(2, 1),
+    }));
+
+    let result = crate::semantic::tests::compile_entry(input);
+
+    assert_eq!(result, expected);
+}
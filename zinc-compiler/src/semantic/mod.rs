@@ -3,11 +3,13 @@
 //!
 
 #[cfg(test)]
-mod tests;
+pub(crate) mod tests;
 
 pub mod analyzer;
 pub mod binding;
 pub mod casting;
 pub mod element;
 pub mod error;
+pub mod generics;
 pub mod scope;
+pub mod warning;
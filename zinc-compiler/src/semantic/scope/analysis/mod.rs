@@ -0,0 +1,71 @@
+//!
+//! The semantic analyzer scope analysis data.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod reference;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use crate::semantic::error::Error;
+use crate::semantic::scope::Scope;
+use crate::semantic::warning::Warning;
+
+use self::reference::Reference;
+
+///
+/// The analysis data collected for the developer tooling, e.g. the language server.
+///
+/// If the data is attached to the entry module scope, the analyzer does not stop at the first
+/// error, but saves it and goes on with the next module item.
+///
+#[derive(Debug, Default)]
+pub struct Analysis {
+    /// The entry module scope, which is set if the entry module has been declared.
+    pub scope: Option<Rc<RefCell<Scope>>>,
+    /// The errors collected throughout the analysis.
+    pub errors: Vec<Error>,
+    /// The warnings collected throughout the analysis.
+    pub warnings: Vec<Warning>,
+    /// The item references resolved throughout the analysis.
+    pub references: Vec<Reference>,
+}
+
+impl Analysis {
+    ///
+    /// Wraps the analysis data into `Rc<RefCell<_>>` to be shared between the module scopes.
+    ///
+    pub fn wrap(self) -> Rc<RefCell<Self>> {
+        Rc::new(RefCell::new(self))
+    }
+
+    ///
+    /// Saves an error, which has not stopped the analysis.
+    ///
+    /// The reference loop errors are only saved if they are the first ones, since an item, whose
+    /// definition has failed, cannot be referenced anymore and yields such an error every time.
+    ///
+    pub fn push_error(&mut self, error: Error) {
+        if let Error::ScopeReferenceLoop { .. } = error {
+            if !self.errors.is_empty() {
+                return;
+            }
+        }
+
+        self.errors.push(error);
+    }
+
+    ///
+    /// Finds the reference, whose identifier covers the `line` and `column` in the file with
+    /// the `file` index.
+    ///
+    pub fn reference_at(&self, file: usize, line: usize, column: usize) -> Option<&Reference> {
+        self.references
+            .iter()
+            .rev()
+            .find(|reference| reference.contains(file, line, column))
+    }
+}
@@ -0,0 +1,49 @@
+//!
+//! The semantic analyzer scope analysis reference.
+//!
+
+use zinc_lexical::Location;
+
+use crate::semantic::scope::item::Item;
+
+///
+/// The resolved item reference, which is used for navigation and hints.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reference {
+    /// The location of the referencing identifier.
+    pub location: Location,
+    /// The referencing identifier.
+    pub name: String,
+    /// The location where the referenced item is declared. `None` for intrinsic items.
+    pub declaration: Option<Location>,
+    /// The referenced item description, e.g. the variable type or function signature.
+    pub description: String,
+}
+
+impl Reference {
+    ///
+    /// Creates a reference to the defined `item`.
+    ///
+    pub fn new(location: Location, name: String, item: &Item) -> Self {
+        let description = item.description(name.as_str());
+
+        Self {
+            location,
+            declaration: item.location(),
+            name,
+            description,
+        }
+    }
+
+    ///
+    /// Checks whether the referencing identifier covers the `line` and `column` in the file with
+    /// the `file` index.
+    ///
+    pub fn contains(&self, file: usize, line: usize, column: usize) -> bool {
+        self.location.file == file
+            && self.location.line == line
+            && self.location.column <= column
+            && column < self.location.column + self.name.len()
+    }
+}
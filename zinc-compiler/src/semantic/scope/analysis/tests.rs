@@ -0,0 +1,166 @@
+//!
+//! The semantic analyzer scope analysis tests.
+//!
+
+use zinc_lexical::Location;
+
+use crate::semantic::error::Error;
+use crate::semantic::scope::Scope;
+
+#[test]
+fn ok_errors_multiple_items() {
+    let input = r#"
+fn first() -> u8 {
+    true
+}
+
+fn second() -> bool {
+    42
+}
+
+fn main() {}
+"#;
+
+    let analysis = crate::semantic::tests::check_entry(input);
+
+    assert_eq!(
+        analysis.errors,
+        vec![
+            Error::FunctionReturnType {
+                location: Location::test(3, 5),
+                function: "first".to_owned(),
+                expected: "u8".to_owned(),
+                found: "bool".to_owned(),
+                reference: Location::test(2, 15),
+            },
+            Error::FunctionReturnType {
+                location: Location::test(7, 5),
+                function: "second".to_owned(),
+                expected: "bool".to_owned(),
+                found: "u8".to_owned(),
+                reference: Location::test(6, 16),
+            },
+        ]
+    );
+    assert!(analysis.scope.is_some());
+}
+
+#[test]
+fn ok_errors_without_reference_loops() {
+    let input = r#"
+fn broken() -> u8 {
+    true
+}
+
+fn main() -> u8 {
+    broken()
+}
+"#;
+
+    let analysis = crate::semantic::tests::check_entry(input);
+
+    assert_eq!(
+        analysis.errors,
+        vec![Error::FunctionReturnType {
+            location: Location::test(3, 5),
+            function: "broken".to_owned(),
+            expected: "u8".to_owned(),
+            found: "bool".to_owned(),
+            reference: Location::test(2, 16),
+        }]
+    );
+}
+
+#[test]
+fn ok_reference_variable() {
+    let input = r#"
+fn main() -> u8 {
+    let value: u8 = 42;
+    value
+}
+"#;
+
+    let analysis = crate::semantic::tests::check_entry(input);
+    assert!(analysis.errors.is_empty());
+
+    let file = Location::test(0, 0).file;
+    let reference = analysis
+        .reference_at(file, 4, 7)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(reference.name, "value");
+    assert_eq!(reference.declaration, Some(Location::test(3, 9)));
+    assert_eq!(reference.description, "let value: u8");
+    assert!(analysis.reference_at(file, 4, 10).is_none());
+}
+
+#[test]
+fn ok_reference_function() {
+    let input = r#"
+fn sum(a: u8, b: u8) -> u8 {
+    a + b
+}
+
+fn main() -> u8 {
+    sum(1, 2)
+}
+"#;
+
+    let analysis = crate::semantic::tests::check_entry(input);
+    assert!(analysis.errors.is_empty());
+
+    let file = Location::test(0, 0).file;
+    let reference = analysis
+        .reference_at(file, 7, 5)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(reference.declaration, Some(Location::test(2, 1)));
+    assert_eq!(reference.description, "fn sum(a: u8, b: u8) -> u8");
+}
+
+#[test]
+fn ok_namespace_intrinsic_module() {
+    let input = r#"
+fn main() {}
+"#;
+
+    let analysis = crate::semantic::tests::check_entry(input);
+    let scope = analysis.scope.expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let namespace = Scope::resolve_namespace(scope, &["std", "crypto"])
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let names: Vec<String> = namespace
+        .borrow()
+        .items()
+        .into_iter()
+        .map(|(name, _item)| name)
+        .collect();
+
+    assert!(names.contains(&"sha256".to_owned()));
+    assert!(names.contains(&"poseidon".to_owned()));
+}
+
+#[test]
+fn ok_reference_structure() {
+    let input = r#"
+struct Data {
+    value: u8,
+}
+
+fn main() -> u8 {
+    let data = Data { value: 42 };
+    data.value
+}
+"#;
+
+    let analysis = crate::semantic::tests::check_entry(input);
+    assert!(analysis.errors.is_empty());
+
+    let file = Location::test(0, 0).file;
+    let reference = analysis
+        .reference_at(file, 7, 16)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    assert_eq!(reference.declaration, Some(Location::test(2, 1)));
+    assert_eq!(reference.description, "struct Data");
+}
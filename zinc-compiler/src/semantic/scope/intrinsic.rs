@@ -7,6 +7,12 @@ use std::rc::Rc;
 
 use zinc_types::LibraryFunctionIdentifier;
 
+use crate::semantic::element::r#type::function::intrinsic::vec::from_array::Function as VecFromArrayFunction;
+use crate::semantic::element::r#type::function::intrinsic::vec::len::Function as VecLenFunction;
+use crate::semantic::element::r#type::function::intrinsic::vec::new::Function as VecNewFunction;
+use crate::semantic::element::r#type::function::intrinsic::vec::pop::Function as VecPopFunction;
+use crate::semantic::element::r#type::function::intrinsic::vec::push::Function as VecPushFunction;
+use crate::semantic::element::r#type::function::intrinsic::vec::Function as VecFunction;
 use crate::semantic::element::r#type::function::Function as FunctionType;
 use crate::semantic::element::r#type::structure::Structure as StructureType;
 use crate::semantic::element::r#type::Type;
@@ -36,6 +42,8 @@ pub enum IntrinsicTypeId {
     ZkSyncTransaction = 2,
     /// The `std::collections::MTreeMap` structure type ID.
     StdCollectionsMTreeMap = 3,
+    /// The `std::collections::Vec` structure type ID.
+    StdCollectionsVec = 4,
 }
 
 impl IntrinsicScope {
@@ -148,6 +156,9 @@ impl IntrinsicScope {
 
         let sha256 = FunctionType::library(LibraryFunctionIdentifier::CryptoSha256);
         let pedersen = FunctionType::library(LibraryFunctionIdentifier::CryptoPedersen);
+        let blake2s = FunctionType::library(LibraryFunctionIdentifier::CryptoBlake2s);
+        let poseidon = FunctionType::library(LibraryFunctionIdentifier::CryptoPoseidon);
+        let rescue = FunctionType::library(LibraryFunctionIdentifier::CryptoRescue);
 
         let schnorr_scope = Scope::new_intrinsic("schnorr").wrap();
         let schnorr_signature_scope = Scope::new_intrinsic("Signature").wrap();
@@ -209,6 +220,21 @@ impl IntrinsicScope {
             pedersen.identifier(),
             ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(pedersen))).wrap(),
         );
+        Scope::insert_item(
+            scope.clone(),
+            blake2s.identifier(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(blake2s))).wrap(),
+        );
+        Scope::insert_item(
+            scope.clone(),
+            poseidon.identifier(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(poseidon))).wrap(),
+        );
+        Scope::insert_item(
+            scope.clone(),
+            rescue.identifier(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(rescue))).wrap(),
+        );
         Scope::insert_item(
             scope.clone(),
             ecc_scope.borrow().name(),
@@ -244,6 +270,7 @@ impl IntrinsicScope {
             FunctionType::library(LibraryFunctionIdentifier::ConvertFromBitsSigned);
         let from_bits_field =
             FunctionType::library(LibraryFunctionIdentifier::ConvertFromBitsField);
+        let from_str_bytes = FunctionType::convert_from_str_bytes();
 
         Scope::insert_item(
             scope.clone(),
@@ -271,6 +298,11 @@ impl IntrinsicScope {
             from_bits_field.identifier(),
             ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(from_bits_field))).wrap(),
         );
+        Scope::insert_item(
+            scope.clone(),
+            from_str_bytes.identifier(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(from_str_bytes))).wrap(),
+        );
 
         scope
     }
@@ -387,6 +419,41 @@ impl IntrinsicScope {
             .wrap(),
         );
 
+        let vec_scope = Scope::new_intrinsic("Vec").wrap();
+        let vec = StructureType::new(
+            None,
+            "Vec".to_owned(),
+            IntrinsicTypeId::StdCollectionsVec as usize,
+            vec![],
+            Some(vec!["T".to_owned(), "N".to_owned()]),
+            None,
+            vec_scope.clone(),
+        );
+        for function in vec![
+            VecFunction::New(VecNewFunction::default()),
+            VecFunction::FromArray(VecFromArrayFunction::default()),
+            VecFunction::Len(VecLenFunction::default()),
+            VecFunction::Push(VecPushFunction::default()),
+            VecFunction::Pop(VecPopFunction::default()),
+        ]
+        .into_iter()
+        {
+            Scope::insert_item(
+                vec_scope.clone(),
+                function.identifier().to_owned(),
+                ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Function(
+                    FunctionType::vec(function),
+                )))
+                .wrap(),
+            );
+        }
+
+        Scope::insert_item(
+            scope.clone(),
+            vec.identifier.clone(),
+            ScopeItem::Type(ScopeTypeItem::new_built_in(Type::Structure(vec))).wrap(),
+        );
+
         scope
     }
 
@@ -6,6 +6,7 @@ pub mod constant;
 pub mod field;
 pub mod index;
 pub mod module;
+pub mod r#trait;
 pub mod r#type;
 pub mod variable;
 pub mod variant;
@@ -15,12 +16,16 @@ use std::fmt;
 use std::rc::Rc;
 
 use crate::generator::statement::Statement as GeneratorStatement;
+use crate::semantic::element::r#type::function::Function as FunctionType;
+use crate::semantic::element::r#type::i_typed::ITyped;
+use crate::semantic::element::r#type::Type as TypeElement;
 use crate::semantic::error::Error;
 use zinc_lexical::Location;
 
 use self::constant::Constant;
 use self::field::Field;
 use self::module::Module;
+use self::r#trait::Trait;
 use self::r#type::Type;
 use self::variable::Variable;
 use self::variant::Variant;
@@ -68,6 +73,8 @@ pub enum Item {
     Variant(Variant),
     /// The type item. See the inner element description.
     Type(Type),
+    /// The trait item. See the inner element description.
+    Trait(Trait),
     /// The module item. See the inner element description.
     Module(Module),
 }
@@ -96,6 +103,9 @@ impl Item {
             Self::Type(inner) => {
                 inner.define()?;
             }
+            Self::Trait(inner) => {
+                inner.define()?;
+            }
             Self::Module(inner) => {
                 inner.define()?;
             }
@@ -114,6 +124,7 @@ impl Item {
             Self::Constant(inner) => Some(inner.location),
             Self::Variant(inner) => Some(inner.location),
             Self::Type(inner) => inner.location,
+            Self::Trait(inner) => Some(inner.location),
             Self::Module(inner) => inner.location,
         }
     }
@@ -128,10 +139,45 @@ impl Item {
             Self::Constant(inner) => inner.item_id,
             Self::Variant(inner) => inner.item_id,
             Self::Type(inner) => inner.item_id,
+            Self::Trait(inner) => inner.item_id,
             Self::Module(inner) => inner.item_id,
         }
     }
 
+    ///
+    /// Describes the item referenced by `name` for the tooling hints, e.g. the variable type or
+    /// function signature.
+    ///
+    pub fn description(&self, name: &str) -> String {
+        match self {
+            Self::Variable(inner) => format!(
+                "let {}{}: {}",
+                if inner.is_mutable { "mut " } else { "" },
+                name,
+                inner.r#type
+            ),
+            Self::Field(inner) => format!("{}: {}", name, inner.r#type),
+            Self::Constant(inner) => match inner.define() {
+                Ok(constant) => format!("const {}: {}", name, constant.r#type()),
+                Err(_) => format!("const {}", name),
+            },
+            Self::Variant(inner) => format!("{}: {}", name, inner.constant.r#type()),
+            Self::Type(inner) => match inner.define() {
+                Ok(TypeElement::Function(FunctionType::Intrinsic(inner))) => {
+                    format!("fn {}", inner)
+                }
+                Ok(TypeElement::Function(inner)) => inner.to_string(),
+                Ok(TypeElement::Structure(inner)) => format!("struct {}", inner),
+                Ok(TypeElement::Enumeration(inner)) => format!("enum {}", inner),
+                Ok(TypeElement::Contract(inner)) => format!("contract {}", inner),
+                Ok(r#type) => format!("type {} = {}", name, r#type),
+                Err(_) => format!("type {}", name),
+            },
+            Self::Trait(_) => format!("trait {}", name),
+            Self::Module(_) => format!("mod {}", name),
+        }
+    }
+
     ///
     /// Extracts the intermediate representation from the element.
     ///
@@ -142,6 +188,7 @@ impl Item {
             Self::Constant(_) => vec![],
             Self::Variant(_) => vec![],
             Self::Type(inner) => inner.get_intermediate(),
+            Self::Trait(_) => vec![],
             Self::Module(inner) => inner.get_intermediate(),
         }
     }
@@ -155,6 +202,7 @@ impl fmt::Display for Item {
             Self::Constant(inner) => write!(f, "constant {}", inner),
             Self::Variant(inner) => write!(f, "variant {}", inner),
             Self::Type(inner) => write!(f, "type {}", inner),
+            Self::Trait(inner) => write!(f, "trait {}", inner),
             Self::Module(inner) => write!(f, "module {}", inner),
         }
     }
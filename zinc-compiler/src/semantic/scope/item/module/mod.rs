@@ -15,9 +15,11 @@ use zinc_lexical::Location;
 use crate::generator::statement::Statement as GeneratorStatement;
 use crate::semantic::analyzer::module::Analyzer as ModuleAnalyzer;
 use crate::semantic::error::Error;
+use crate::semantic::scope::analysis::Analysis;
 use crate::semantic::scope::item::index::INDEX as ITEM_INDEX;
 use crate::semantic::scope::item::Item as ScopeItem;
 use crate::semantic::scope::Scope;
+use crate::semantic::warning::sink::Sink as WarningSink;
 use crate::source::Source;
 
 use self::state::State;
@@ -41,19 +43,27 @@ impl Module {
     ///
     /// Initializes an application entry module scope.
     ///
+    /// If `analysis` is set, the module tree is analyzed for the tooling, that is, the errors
+    /// and resolved references are saved there instead of stopping at the first error.
+    ///
     pub fn new_entry(
         module: Source,
         project: zinc_project::ManifestProject,
         dependencies: HashMap<String, Rc<RefCell<Scope>>>,
         is_dependency_entry: bool,
+        analysis: Option<Rc<RefCell<Analysis>>>,
     ) -> Result<Rc<RefCell<ScopeItem>>, Error> {
-        let scope = Scope::new_module(
+        let mut scope = Scope::new_module(
             module.name().to_owned(),
             dependencies.clone(),
             Some(project),
             is_dependency_entry,
-        )
-        .wrap();
+        );
+        scope.analysis = analysis;
+        if !is_dependency_entry {
+            scope.warnings = Some(WarningSink::default().wrap());
+        }
+        let scope = scope.wrap();
 
         let module = Self::new_declared(
             None,
@@ -105,7 +115,7 @@ impl Module {
             Source::Directory(directory) => (directory.entry.tree, directory.modules),
         };
 
-        let (module, implementation_scopes) = ModuleAnalyzer::declare(
+        let (module, implementations) = ModuleAnalyzer::declare(
             scope.clone(),
             module,
             modules,
@@ -121,7 +131,7 @@ impl Module {
             state: RefCell::new(Some(State::Declared {
                 scope,
                 module,
-                implementation_scopes,
+                implementations,
                 scope_crate,
                 scope_super,
             })),
@@ -178,7 +188,7 @@ impl Module {
             Some(State::Declared {
                 scope,
                 module,
-                implementation_scopes,
+                implementations,
                 scope_crate,
                 scope_super,
             }) => {
@@ -192,7 +202,7 @@ impl Module {
                 ModuleAnalyzer::define(
                     scope.clone(),
                     module,
-                    implementation_scopes,
+                    implementations,
                     crate_item,
                     super_item,
                 )?;
@@ -8,6 +8,7 @@ use std::rc::Rc;
 use zinc_syntax::Module as SyntaxModule;
 
 use crate::generator::statement::Statement as GeneratorStatement;
+use crate::semantic::analyzer::statement::r#impl::implementation::Implementation;
 use crate::semantic::scope::Scope;
 
 ///
@@ -21,8 +22,8 @@ pub enum State {
         scope: Rc<RefCell<Scope>>,
         /// The module syntax representation.
         module: SyntaxModule,
-        /// The scopes of the `impl` statement, which must be defined for their types during module definition.
-        implementation_scopes: Vec<Rc<RefCell<Scope>>>,
+        /// The `impl` statements, which must be defined for their types during module definition.
+        implementations: Vec<Implementation>,
         /// The reference to the application root scope.
         scope_crate: Rc<RefCell<Scope>>,
         /// The reference to the parent scope, if the current scope is not the root one.
@@ -0,0 +1,130 @@
+//!
+//! The semantic analyzer scope trait item.
+//!
+
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::fmt;
+use std::rc::Rc;
+
+use zinc_lexical::Keyword;
+use zinc_lexical::Location;
+use zinc_syntax::TraitLocalStatement;
+use zinc_syntax::TraitMethod;
+use zinc_syntax::TraitStatement;
+
+use crate::semantic::binding::Binder;
+use crate::semantic::binding::Binding;
+use crate::semantic::element::r#type::Type as TypeElement;
+use crate::semantic::error::Error;
+use crate::semantic::scope::item::index::INDEX as ITEM_INDEX;
+use crate::semantic::scope::item::Item;
+use crate::semantic::scope::r#type::Type as ScopeType;
+use crate::semantic::scope::Scope;
+
+///
+/// The trait item, declared using a `trait` statement.
+///
+/// Traits are resolved statically, so the item only stores the method signatures, which are
+/// resolved for each implementing type separately, with `Self` being an alias of the type.
+///
+#[derive(Debug, Clone)]
+pub struct Trait {
+    /// The location where the trait is declared.
+    pub location: Location,
+    /// The unique trait ID, allocated upon declaration.
+    pub item_id: usize,
+    /// The trait identifier.
+    pub identifier: String,
+    /// The trait method signatures.
+    pub methods: Vec<TraitMethod>,
+    /// The scope where the trait is declared, which is used to resolve the signature types.
+    pub scope: Rc<RefCell<Scope>>,
+}
+
+impl Trait {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(statement: TraitStatement, scope: Rc<RefCell<Scope>>) -> Self {
+        let item_id = ITEM_INDEX.next(format!("trait {}", statement.identifier.name));
+
+        let methods = statement
+            .statements
+            .into_iter()
+            .filter_map(|statement| match statement {
+                TraitLocalStatement::Fn(method) => Some(method),
+                TraitLocalStatement::Empty(_location) => None,
+            })
+            .collect();
+
+        Self {
+            location: statement.location,
+            item_id,
+            identifier: statement.identifier.name,
+            methods,
+            scope,
+        }
+    }
+
+    ///
+    /// Checks the trait method signatures for duplicates.
+    ///
+    pub fn define(&self) -> Result<(), Error> {
+        let mut methods = HashMap::with_capacity(self.methods.len());
+        for method in self.methods.iter() {
+            if let Some(reference) =
+                methods.insert(method.identifier.name.to_owned(), method.location)
+            {
+                return Err(Error::ScopeItemRedeclared {
+                    location: method.location,
+                    name: method.identifier.name.to_owned(),
+                    reference: Some(reference),
+                });
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Returns the method signature with the `name`, if it is declared in the trait.
+    ///
+    pub fn method(&self, name: &str) -> Option<&TraitMethod> {
+        self.methods
+            .iter()
+            .find(|method| method.identifier.name.as_str() == name)
+    }
+
+    ///
+    /// Resolves the `method` signature for the type, which is aliased by `Self` in the signature.
+    ///
+    /// Returns the argument bindings and the return type.
+    ///
+    pub fn resolve_method(
+        &self,
+        method: &TraitMethod,
+        self_item: Rc<RefCell<Item>>,
+    ) -> Result<(Vec<Binding>, TypeElement), Error> {
+        let scope = Scope::new_child(
+            self.identifier.to_owned(),
+            ScopeType::Trait,
+            self.scope.clone(),
+        );
+        Scope::insert_item(scope.clone(), Keyword::SelfUppercase.to_string(), self_item);
+
+        let bindings = Binder::bind_arguments(method.argument_bindings.to_owned(), scope.clone())?;
+        let return_type = match method.return_type {
+            Some(ref r#type) => TypeElement::try_from_syntax(r#type.to_owned(), scope)?,
+            None => TypeElement::unit(None),
+        };
+
+        Ok((bindings, return_type))
+    }
+}
+
+impl fmt::Display for Trait {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.identifier)
+    }
+}
@@ -52,6 +52,10 @@ impl Index {
             "structure std::collections::MTreeMap".to_owned(),
             IntrinsicTypeId::StdCollectionsMTreeMap as usize,
         );
+        index.next_with_id(
+            "structure std::collections::Vec".to_owned(),
+            IntrinsicTypeId::StdCollectionsVec as usize,
+        );
         index
     }
 
@@ -6,6 +6,7 @@ use std::cell::RefCell;
 use std::rc::Rc;
 
 use crate::generator::statement::Statement as GeneratorStatement;
+use crate::semantic::element::r#type::function::Function;
 use crate::semantic::element::r#type::Type as TypeElement;
 use crate::semantic::scope::item::r#type::statement::Statement as TypeStatementVariant;
 use crate::semantic::scope::Scope;
@@ -42,11 +43,20 @@ impl State {
                 inner,
                 intermediate,
             } => match inner {
+                TypeElement::Function(Function::Generic(ref inner)) => {
+                    inner.template.get_intermediate()
+                }
                 TypeElement::Function(_) => match intermediate.to_owned().take() {
                     Some(intermediate) => vec![intermediate],
                     None => vec![],
                 },
-                TypeElement::Structure(ref inner) => inner.scope.borrow().get_intermediate(),
+                TypeElement::Structure(ref inner) => {
+                    let mut intermediate = inner.scope.borrow().get_intermediate();
+                    if let Some(ref template) = inner.template {
+                        intermediate.extend(template.get_intermediate());
+                    }
+                    intermediate
+                }
                 TypeElement::Enumeration(ref inner) => inner.scope.borrow().get_intermediate(),
                 TypeElement::Contract(ref inner) => {
                     let mut intermediate = match intermediate.to_owned().take() {
@@ -2,10 +2,12 @@
 //! The semantic analyzer scope variable item.
 //!
 
+use std::cell::Cell;
 use std::fmt;
 
 use zinc_lexical::Location;
 
+use crate::semantic::element::place::memory_type::MemoryType;
 use crate::semantic::element::r#type::Type;
 use crate::semantic::scope::item::index::INDEX as ITEM_INDEX;
 
@@ -20,10 +22,16 @@ pub struct Variable {
     pub item_id: usize,
     /// Whether the variable is mutable.
     pub is_mutable: bool,
+    /// Whether the variable is a `&mut` function argument, which points to the caller data.
+    pub is_reference: bool,
     /// The variable name.
     pub identifier: String,
     /// The variable type.
     pub r#type: Type,
+    /// Whether the variable has been referenced, which is checked for the `unused_variables` lint.
+    pub is_used: Cell<bool>,
+    /// Whether the variable has been mutated, which is checked for the `unused_mut` lint.
+    pub is_mutated: Cell<bool>,
 }
 
 impl Variable {
@@ -42,8 +50,31 @@ impl Variable {
             location,
             item_id,
             is_mutable,
+            is_reference: false,
             identifier,
             r#type,
+            is_used: Cell::new(false),
+            is_mutated: Cell::new(false),
+        }
+    }
+
+    ///
+    /// A shortcut constructor for the `&mut` function arguments.
+    ///
+    pub fn new_reference(location: Option<Location>, identifier: String, r#type: Type) -> Self {
+        let mut variable = Self::new(location, false, identifier, r#type);
+        variable.is_reference = true;
+        variable
+    }
+
+    ///
+    /// Returns the memory type of the places pointing to the variable.
+    ///
+    pub fn memory_type(&self) -> MemoryType {
+        if self.is_reference {
+            MemoryType::Reference
+        } else {
+            MemoryType::Stack
         }
     }
 }
@@ -5,6 +5,7 @@
 use std::fmt;
 
 use crate::semantic::element::constant::Constant as ConstantElement;
+use crate::semantic::element::r#type::enumeration::Enumeration as EnumerationType;
 use crate::semantic::scope::item::index::INDEX as ITEM_INDEX;
 use zinc_lexical::Location;
 
@@ -34,6 +35,33 @@ impl Variant {
             constant,
         }
     }
+
+    ///
+    /// Returns the enumeration type and the variant index.
+    ///
+    pub fn enumeration(&self) -> Option<(&EnumerationType, usize)> {
+        match self.constant {
+            ConstantElement::Integer(ref integer) => {
+                integer.enumeration.as_ref().and_then(|enumeration| {
+                    enumeration
+                        .index(&integer.value)
+                        .map(|index| (enumeration, index))
+                })
+            }
+            _ => None,
+        }
+    }
+
+    ///
+    /// Returns the enumeration type and the variant index, if the variant belongs to
+    /// an enumeration with data variants.
+    ///
+    /// The variants of such enumerations are not constants and must be constructed at runtime.
+    ///
+    pub fn data(&self) -> Option<(&EnumerationType, usize)> {
+        self.enumeration()
+            .filter(|(enumeration, _index)| enumeration.is_data())
+    }
 }
 
 impl fmt::Display for Variant {
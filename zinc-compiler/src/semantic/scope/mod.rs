@@ -5,34 +5,46 @@
 #[cfg(test)]
 mod tests;
 
+pub mod analysis;
 pub mod intrinsic;
 pub mod item;
 pub mod stack;
 pub mod r#type;
 
+use std::cell::Cell;
 use std::cell::RefCell;
 use std::collections::HashMap;
 use std::rc::Rc;
 use std::str;
 
+use num::BigInt;
+
 use zinc_lexical::Keyword;
 use zinc_lexical::Location;
 use zinc_syntax::ConstStatement;
 use zinc_syntax::ContractStatement;
 use zinc_syntax::Identifier;
+use zinc_syntax::TraitStatement;
 
 use crate::generator::statement::Statement as GeneratorStatement;
 use crate::semantic::element::constant::Constant;
 use crate::semantic::element::path::Path;
 use crate::semantic::element::r#type::Type as SemanticType;
 use crate::semantic::error::Error;
+use crate::semantic::generics::argument::Argument as GenericArgument;
 use crate::semantic::scope::intrinsic::IntrinsicTypeId;
+use crate::semantic::warning::lint::Lint;
+use crate::semantic::warning::sink::Sink as WarningSink;
+use crate::semantic::warning::Warning;
 use crate::source::Source;
 
+use self::analysis::reference::Reference;
+use self::analysis::Analysis;
 use self::intrinsic::IntrinsicScope;
 use self::item::constant::Constant as ConstantItem;
 use self::item::field::Field as FieldItem;
 use self::item::module::Module as ModuleItem;
+use self::item::r#trait::Trait as TraitItem;
 use self::item::r#type::statement::Statement as TypeStatementVariant;
 use self::item::r#type::Type as TypeItem;
 use self::item::variable::Variable as VariableItem;
@@ -57,6 +69,14 @@ pub struct Scope {
     parent: Option<Rc<RefCell<Self>>>,
     /// The hashmap with items declared at the current scope level, with item names as keys.
     items: RefCell<HashMap<String, Rc<RefCell<Item>>>>,
+    /// The number of `return`, `break`, and `continue` statements exiting through the scope.
+    early_exits: Cell<usize>,
+    /// The analysis data, which is only attached to module scopes analyzed for the tooling.
+    analysis: Option<Rc<RefCell<Analysis>>>,
+    /// The warning sink, which is only attached to the entry module scope and its submodules.
+    warnings: Option<Rc<RefCell<WarningSink>>>,
+    /// The lints allowed at the current scope level, e.g. with the `#[allow(...)]` attribute.
+    allowed_lints: RefCell<Vec<Lint>>,
 }
 
 impl Scope {
@@ -75,6 +95,10 @@ impl Scope {
             r#type,
             parent,
             items: RefCell::new(HashMap::with_capacity(Self::ITEMS_INITIAL_CAPACITY)),
+            early_exits: Cell::new(0),
+            analysis: None,
+            warnings: None,
+            allowed_lints: RefCell::new(vec![]),
         }
     }
 
@@ -110,6 +134,10 @@ impl Scope {
             r#type,
             parent: Some(IntrinsicScope::initialize()),
             items: RefCell::new(items),
+            early_exits: Cell::new(0),
+            analysis: None,
+            warnings: None,
+            allowed_lints: RefCell::new(vec![]),
         }
     }
 
@@ -122,6 +150,10 @@ impl Scope {
             r#type: ScopeType::Intrinsic,
             parent: None,
             items: RefCell::new(HashMap::with_capacity(Self::ITEMS_INITIAL_CAPACITY)),
+            early_exits: Cell::new(0),
+            analysis: None,
+            warnings: None,
+            allowed_lints: RefCell::new(vec![]),
         }
     }
 
@@ -157,6 +189,45 @@ impl Scope {
         self.parent.to_owned()
     }
 
+    ///
+    /// Returns the number of `return`, `break`, and `continue` statements exiting through the scope.
+    ///
+    /// The generator uses it to allocate the early exit flags and guard the subsequent statements.
+    ///
+    pub fn early_exits(&self) -> usize {
+        self.early_exits.get()
+    }
+
+    ///
+    /// Registers an early exit statement in each scope it exits through, starting from `scope`
+    /// and up to the first one whose type satisfies `is_target`.
+    ///
+    /// Returns the target scope type, or `None` if a function or module boundary has been
+    /// reached first.
+    ///
+    pub fn define_early_exit<P>(scope: Rc<RefCell<Scope>>, is_target: P) -> Option<ScopeType>
+    where
+        P: Fn(&ScopeType) -> bool,
+    {
+        let mut current = Some(scope);
+        while let Some(scope) = current.take() {
+            let scope = RefCell::borrow(&scope);
+            scope.early_exits.set(scope.early_exits.get() + 1);
+
+            if is_target(&scope.r#type) {
+                return Some(scope.r#type());
+            }
+
+            match scope.r#type {
+                ScopeType::Function { .. } | ScopeType::Intrinsic => return None,
+                ref r#type if r#type.is_module() || r#type.is_implementation() => return None,
+                _ => current = scope.parent(),
+            }
+        }
+
+        None
+    }
+
     ///
     /// Wraps the scope into `Rc<RefCell<_>>` simplifying most of initializations.
     ///
@@ -191,6 +262,155 @@ impl Scope {
         }
     }
 
+    ///
+    /// Returns the analysis data from the nearest module scope, if the scope is being analyzed
+    /// for the tooling.
+    ///
+    pub fn analysis(&self) -> Option<Rc<RefCell<Analysis>>> {
+        match self.analysis {
+            Some(ref analysis) => Some(analysis.to_owned()),
+            None => match self.parent {
+                Some(ref parent) => parent.borrow().analysis(),
+                None => None,
+            },
+        }
+    }
+
+    ///
+    /// Saves the error to the analysis data and lets the analyzer go on, if the scope is
+    /// being analyzed for the tooling. Otherwise, returns the error as is.
+    ///
+    pub fn recover(&self, result: Result<(), Error>) -> Result<(), Error> {
+        match result {
+            Ok(()) => Ok(()),
+            Err(error) => match self.analysis() {
+                Some(analysis) => {
+                    analysis.borrow_mut().push_error(error);
+                    Ok(())
+                }
+                None => Err(error),
+            },
+        }
+    }
+
+    ///
+    /// Returns the warning sink from the nearest module scope, if the scope belongs to
+    /// the entry module or its submodules.
+    ///
+    pub fn warnings(&self) -> Option<Rc<RefCell<WarningSink>>> {
+        match self.warnings {
+            Some(ref warnings) => Some(warnings.to_owned()),
+            None => match self.parent {
+                Some(ref parent) => parent.borrow().warnings(),
+                None => None,
+            },
+        }
+    }
+
+    ///
+    /// Reports the unused items and returns the warnings collected throughout the entry module
+    /// analysis. Is called after the entry module has been defined.
+    ///
+    pub fn take_warnings(&self) -> Vec<Warning> {
+        match self.warnings() {
+            Some(warnings) => warnings.borrow_mut().finish(),
+            None => vec![],
+        }
+    }
+
+    ///
+    /// Allows the `lints` at the current scope level and in its children.
+    ///
+    pub fn allow_lints(&self, lints: &[Lint]) {
+        self.allowed_lints.borrow_mut().extend_from_slice(lints);
+    }
+
+    ///
+    /// Checks whether the `lint` is allowed at the current scope level or in any of its parents.
+    ///
+    pub fn is_lint_allowed(&self, lint: Lint) -> bool {
+        if self
+            .allowed_lints
+            .borrow()
+            .iter()
+            .any(|allowed| allowed.covers(lint))
+        {
+            return true;
+        }
+
+        match self.parent {
+            Some(ref parent) => parent.borrow().is_lint_allowed(lint),
+            None => false,
+        }
+    }
+
+    ///
+    /// Saves the warning to the sink, unless its lint is allowed or the scope does not belong to
+    /// the entry module.
+    ///
+    pub fn warn(&self, warning: Warning) {
+        if self.is_lint_allowed(warning.lint()) {
+            return;
+        }
+
+        if let Some(warnings) = self.warnings() {
+            warnings.borrow_mut().push(warning);
+        }
+    }
+
+    ///
+    /// Reports the variables declared at the current scope level, which have never been used or
+    /// mutated, if they were declared as mutable.
+    ///
+    /// Is called when the scope is popped from the stack, so all the references are known.
+    /// The `self` instance and the variables starting with an underscore are not reported as
+    /// unused.
+    ///
+    pub fn warn_unused_variables(&self) {
+        if self.warnings().is_none() {
+            return;
+        }
+
+        for (_name, item) in self.items.borrow().iter() {
+            if let Item::Variable(ref variable) = *RefCell::borrow(item) {
+                let location = match variable.location {
+                    Some(location) => location,
+                    None => continue,
+                };
+
+                if !variable.is_used.get() {
+                    if variable.identifier.starts_with('_')
+                        || variable.identifier == Keyword::SelfLowercase.to_string()
+                    {
+                        continue;
+                    }
+
+                    self.warn(Warning::UnusedVariable {
+                        location,
+                        name: variable.identifier.to_owned(),
+                    });
+                } else if variable.is_mutable && !variable.is_mutated.get() {
+                    self.warn(Warning::UnusedMut {
+                        location,
+                        name: variable.identifier.to_owned(),
+                    });
+                }
+            }
+        }
+    }
+
+    ///
+    /// Marks the variable with `identifier` as mutated, so it is not reported by
+    /// the `unused_mut` lint.
+    ///
+    pub fn mark_mutated(&self, identifier: &Identifier) {
+        if let Ok(item) = self.resolve_item(identifier, true) {
+            if let Item::Variable(ref variable) = *RefCell::borrow(&item) {
+                variable.is_mutated.set(true);
+            }
+        }
+    }
+
     ///
     /// Internally defines all the items in the order they have been declared.
     ///
@@ -204,12 +424,28 @@ impl Scope {
                 continue;
             }
 
-            RefCell::borrow(&item).define()?;
+            self.recover(RefCell::borrow(&item).define())?;
         }
 
         Ok(())
     }
 
+    ///
+    /// Returns the items declared at the current scope level sorted by name, except for
+    /// the `self`, `super`, and `crate` aliases.
+    ///
+    pub fn items(&self) -> Vec<(String, Rc<RefCell<Item>>)> {
+        let mut items: Vec<(String, Rc<RefCell<Item>>)> = self
+            .items
+            .borrow()
+            .iter()
+            .filter(|(name, _item)| !Keyword::is_alias(name.as_str()))
+            .map(|(name, item)| (name.to_owned(), item.to_owned()))
+            .collect();
+        items.sort_by(|(name_1, _), (name_2, _)| name_1.cmp(name_2));
+        items
+    }
+
     ///
     /// Inserts an item, does not check if the item has been already declared.
     ///
@@ -282,6 +518,41 @@ impl Scope {
         Ok(())
     }
 
+    ///
+    /// Defines a `&mut` function argument, which is a reference to the caller data.
+    ///
+    /// The `r#type` is the referenced data type.
+    ///
+    pub fn define_reference(
+        scope: Rc<RefCell<Scope>>,
+        identifier: Identifier,
+        r#type: SemanticType,
+    ) -> Result<(), Error> {
+        if let Ok(item) =
+            RefCell::borrow(&scope).resolve_item(&identifier, !identifier.is_self_lowercase())
+        {
+            return Err(Error::ScopeItemRedeclared {
+                location: identifier.location,
+                name: identifier.name.clone(),
+                reference: RefCell::borrow(&item).location(),
+            });
+        }
+
+        let name = identifier.name.clone();
+        let item = Item::Variable(VariableItem::new_reference(
+            Some(identifier.location),
+            identifier.name,
+            r#type,
+        ));
+
+        RefCell::borrow(&scope)
+            .items
+            .borrow_mut()
+            .insert(name, item.wrap());
+
+        Ok(())
+    }
+
     ///
     /// Defines a contract field.
     ///
@@ -491,6 +762,32 @@ impl Scope {
         Scope::declare_type(scope, TypeStatementVariant::Contract(statement))
     }
 
+    ///
+    /// Declares a trait, saving its method signatures to resolve them for each implementing type.
+    ///
+    pub fn declare_trait(
+        scope: Rc<RefCell<Scope>>,
+        statement: TraitStatement,
+    ) -> Result<(), Error> {
+        if let Ok(item) = RefCell::borrow(&scope).resolve_item(&statement.identifier, true) {
+            return Err(Error::ScopeItemRedeclared {
+                location: statement.location,
+                name: statement.identifier.name.clone(),
+                reference: RefCell::borrow(&item).location(),
+            });
+        }
+
+        let name = statement.identifier.name.clone();
+        let item = Item::Trait(TraitItem::new(statement, scope.clone()));
+
+        RefCell::borrow(&scope)
+            .items
+            .borrow_mut()
+            .insert(name, item.wrap());
+
+        Ok(())
+    }
+
     ///
     /// Declares a module, saving its representation to define itself later during the second
     /// pass or referencing for the first time.
@@ -511,8 +808,11 @@ impl Scope {
         }
 
         let name = identifier.name.clone();
-        let module_scope =
-            Self::new_module(identifier.name.clone(), dependencies.clone(), None, false).wrap();
+        let mut module_scope =
+            Self::new_module(identifier.name.clone(), dependencies.clone(), None, false);
+        module_scope.analysis = RefCell::borrow(&scope).analysis();
+        module_scope.warnings = RefCell::borrow(&scope).warnings();
+        let module_scope = module_scope.wrap();
         let module = ModuleItem::new_declared(
             Some(identifier.location),
             module_scope.clone(),
@@ -563,16 +863,53 @@ impl Scope {
         scope: Rc<RefCell<Scope>>,
         path: &Path,
     ) -> Result<Rc<RefCell<Item>>, Error> {
-        let mut current_scope = scope;
+        let analysis = RefCell::borrow(&scope).analysis();
+        let warnings = RefCell::borrow(&scope).warnings();
+
+        Self::resolve_path_inner(scope, path, analysis, warnings)
+    }
+
+    ///
+    /// Resolves an item at the specified path, saving the resolved references to `analysis`
+    /// and `warnings`.
+    ///
+    fn resolve_path_inner(
+        scope: Rc<RefCell<Scope>>,
+        path: &Path,
+        analysis: Option<Rc<RefCell<Analysis>>>,
+        warnings: Option<Rc<RefCell<WarningSink>>>,
+    ) -> Result<Rc<RefCell<Item>>, Error> {
+        let mut current_scope = match path.base {
+            Some(SemanticType::Structure(ref inner)) => inner.scope.to_owned(),
+            Some(SemanticType::Enumeration(ref inner)) => inner.scope.to_owned(),
+            Some(_) => panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+            None => scope,
+        };
 
         for (index, identifier) in path.elements.iter().enumerate() {
-            let is_element_first = index == 0;
+            let is_element_first = index == 0 && path.base.is_none();
             let is_element_last = index == path.elements.len() - 1;
 
             let item =
                 RefCell::borrow(&current_scope).resolve_item(identifier, is_element_first)?;
             RefCell::borrow(&item).define()?;
 
+            if let Some(ref analysis) = analysis {
+                let reference = Reference::new(
+                    identifier.location,
+                    identifier.name.to_owned(),
+                    &RefCell::borrow(&item),
+                );
+                analysis.borrow_mut().references.push(reference);
+            }
+
+            if let Some(ref warnings) = warnings {
+                warnings.borrow_mut().reference(&item);
+            }
+            if let Item::Variable(ref variable) = *RefCell::borrow(&item) {
+                variable.is_used.set(true);
+            }
+
             if is_element_last {
                 return Ok(item);
             }
@@ -583,7 +920,16 @@ impl Scope {
                     let r#type = r#type.define()?;
                     match r#type {
                         SemanticType::Enumeration(ref inner) => inner.scope.to_owned(),
-                        SemanticType::Structure(ref inner) => inner.scope.to_owned(),
+                        SemanticType::Structure(ref inner) => match inner.template {
+                            Some(ref template) => {
+                                return Err(Error::TypeExpectedGenerics {
+                                    location: identifier.location,
+                                    r#type: identifier.name.to_owned(),
+                                    expected: template.parameters.len(),
+                                });
+                            }
+                            None => inner.scope.to_owned(),
+                        },
                         SemanticType::Contract(ref inner) => inner.scope.to_owned(),
                         _ => {
                             return Err(Error::ScopeExpectedNamespace {
@@ -593,6 +939,12 @@ impl Scope {
                         }
                     }
                 }
+                Item::Trait(_) => {
+                    return Err(Error::TraitUsedAsNamespace {
+                        location: identifier.location,
+                        name: identifier.name.to_owned(),
+                    });
+                }
                 _ => {
                     return Err(Error::ScopeExpectedNamespace {
                         location: identifier.location,
@@ -608,6 +960,38 @@ impl Scope {
         })
     }
 
+    ///
+    /// Resolves the namespace scope, e.g. of a module or structure, at the path `names`.
+    ///
+    /// Is used by the tooling to look through the namespace items, so the references are not saved.
+    ///
+    pub fn resolve_namespace(
+        scope: Rc<RefCell<Scope>>,
+        names: &[&str],
+    ) -> Option<Rc<RefCell<Scope>>> {
+        let location = Location::default();
+        let path = Path::new_complex(
+            location,
+            names
+                .iter()
+                .map(|name| Identifier::new(location, (*name).to_owned()))
+                .collect(),
+        );
+
+        let item = Self::resolve_path_inner(scope, &path, None, None).ok()?;
+        let item = RefCell::borrow(&item);
+        match *item {
+            Item::Module(ref module) => module.define().ok(),
+            Item::Type(ref r#type) => match r#type.define().ok()? {
+                SemanticType::Enumeration(inner) => Some(inner.scope),
+                SemanticType::Structure(inner) => Some(inner.scope),
+                SemanticType::Contract(inner) => Some(inner.scope),
+                _ => None,
+            },
+            _ => None,
+        }
+    }
+
     ///
     /// Resolves the item with `identifier` within the current `scope`. Looks through the parent scopes
     /// if `recursive` is true.
@@ -637,7 +1021,7 @@ impl Scope {
     /// Cannot panic, since the type is declared by the developer in the intrinsic module.
     ///
     pub fn resolve_mtreemap(location: Location, scope: Rc<RefCell<Scope>>) -> SemanticType {
-        let item = Scope::resolve_path(
+        let item = Scope::resolve_path_inner(
             scope,
             &Path::new_complex(
                 location,
@@ -647,6 +1031,8 @@ impl Scope {
                     Identifier::new(location, "MTreeMap".to_owned()),
                 ],
             ),
+            None,
+            None,
         )
         .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
 
@@ -663,14 +1049,66 @@ impl Scope {
                         .set_generics(
                             location,
                             Some(vec![
-                                SemanticType::integer_unsigned(
+                                GenericArgument::Type(SemanticType::integer_unsigned(
                                     None,
                                     zinc_const::bitlength::ETH_ADDRESS,
-                                ),
-                                SemanticType::integer_unsigned(
+                                )),
+                                GenericArgument::Type(SemanticType::integer_unsigned(
                                     None,
                                     zinc_const::bitlength::INTEGER_MAX,
-                                ),
+                                )),
+                            ]),
+                        )
+                        .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+                    SemanticType::Structure(structure)
+                }
+                _type => panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+            },
+            _item => panic!("{}", zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+        }
+    }
+
+    ///
+    /// Resolves the `std::collections::Vec<T, N>` type with the `element_type` and `capacity`.
+    ///
+    /// Cannot panic, since the type is declared by the developer in the intrinsic module.
+    ///
+    pub fn resolve_vec(
+        location: Location,
+        scope: Rc<RefCell<Scope>>,
+        element_type: SemanticType,
+        capacity: usize,
+    ) -> SemanticType {
+        let item = Scope::resolve_path_inner(
+            scope,
+            &Path::new_complex(
+                location,
+                vec![
+                    Identifier::new(location, "std".to_owned()),
+                    Identifier::new(location, "collections".to_owned()),
+                    Identifier::new(location, "Vec".to_owned()),
+                ],
+            ),
+            None,
+            None,
+        )
+        .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
+
+        let item = RefCell::borrow(&item);
+        match &*item {
+            Item::Type(ref r#type) => match r#type
+                .define()
+                .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS)
+            {
+                SemanticType::Structure(mut structure)
+                    if structure.type_id == IntrinsicTypeId::StdCollectionsVec as usize =>
+                {
+                    structure
+                        .set_generics(
+                            location,
+                            Some(vec![
+                                GenericArgument::Type(element_type),
+                                GenericArgument::Constant(BigInt::from(capacity)),
                             ]),
                         )
                         .expect(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS);
@@ -58,9 +58,14 @@ impl Stack {
     ///
     /// Removes the deepest scope from the current hierarchy.
     ///
+    /// The scope variables are checked for being used, since they cannot be referenced anymore.
+    ///
     pub fn pop(&mut self) {
-        self.elements
+        let scope = self
+            .elements
             .pop()
             .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+
+        RefCell::borrow(&scope).warn_unused_variables();
     }
 }
@@ -2,6 +2,8 @@
 //! The semantic analyzer scope type.
 //!
 
+use crate::semantic::element::r#type::Type as SemanticType;
+
 ///
 /// The semantic analyzer scope type.
 ///
@@ -27,8 +29,17 @@ pub enum Type {
     Structure,
     /// The enumeration namespace, where its fields, methods, and associated items are declared.
     Enumeration,
+    /// The trait namespace, where its method signatures are resolved for an implementing type.
+    Trait,
+    /// The generic item instance namespace, where the generic parameters are bound to the actual arguments.
+    Generic,
     /// The function block.
-    Function,
+    Function {
+        /// The function identifier.
+        identifier: String,
+        /// The function return type, which is `None` for constant functions.
+        return_type: Option<SemanticType>,
+    },
     /// The conditional block.
     Conditional,
     /// The for-loop block.
@@ -14,7 +14,9 @@ use crate::error::Error;
 use crate::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
 use crate::semantic::analyzer::module::Analyzer as ModuleAnalyzer;
 use crate::semantic::error::Error as SemanticError;
+use crate::semantic::scope::analysis::Analysis;
 use crate::semantic::scope::Scope;
+use crate::semantic::warning::Warning;
 use crate::source::Source;
 
 pub(crate) fn compile_entry(code: &str) -> Result<(), Error> {
@@ -38,6 +40,36 @@ pub(crate) fn compile_entry_with_modules(
     Ok(())
 }
 
+pub(crate) fn compile_entry_warnings(code: &str) -> Result<Vec<Warning>, Error> {
+    let path = PathBuf::from("test.zn");
+    let source =
+        Source::test(code, path, HashMap::new()).expect(zinc_const::panic::TEST_DATA_VALID);
+    let project = zinc_project::ManifestProject::new(
+        "test".to_owned(),
+        zinc_project::ProjectType::Contract,
+        semver::Version::new(1, 0, 0),
+    );
+
+    let scope =
+        EntryAnalyzer::define(source, project, HashMap::new(), false).map_err(Error::Semantic)?;
+    let warnings = scope.borrow().take_warnings();
+
+    Ok(warnings)
+}
+
+pub(crate) fn check_entry(code: &str) -> Analysis {
+    let path = PathBuf::from("test.zn");
+    let source =
+        Source::test(code, path, HashMap::new()).expect(zinc_const::panic::TEST_DATA_VALID);
+    let project = zinc_project::ManifestProject::new(
+        "test".to_owned(),
+        zinc_project::ProjectType::Contract,
+        semver::Version::new(1, 0, 0),
+    );
+
+    EntryAnalyzer::check(source, project, HashMap::new())
+}
+
 pub(crate) fn compile_module(
     code: &str,
     file: usize,
@@ -57,7 +89,7 @@ pub(crate) fn compile_module_with_modules(
     scope_super: Rc<RefCell<Scope>>,
 ) -> Result<Rc<RefCell<Scope>>, Error> {
     let module = Parser::default().parse(code, file)?;
-    let (module, implementation_scopes) = ModuleAnalyzer::declare(
+    let (module, implementations) = ModuleAnalyzer::declare(
         scope.clone(),
         module,
         modules,
@@ -72,7 +104,7 @@ pub(crate) fn compile_module_with_modules(
     ModuleAnalyzer::define(
         scope.clone(),
         module,
-        implementation_scopes,
+        implementations,
         crate_item,
         Some(super_item),
     )?;
@@ -0,0 +1,74 @@
+//!
+//! The semantic analyzer lint.
+//!
+
+use std::fmt;
+use std::str::FromStr;
+
+///
+/// The lint, which is a named class of warnings.
+///
+/// Lints are allowed with the `#[allow(...)]` function attribute, and allowed or denied
+/// for the whole project with the `znc -A <lint>` and `znc -D <lint>` options.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Lint {
+    /// The group of all the lints.
+    Warnings,
+    /// Variables, which are never read.
+    UnusedVariables,
+    /// Items imported with `use`, which are never referenced.
+    UnusedImports,
+    /// Functions, which are never called.
+    DeadCode,
+    /// Variables declared with `mut`, which are never mutated.
+    UnusedMut,
+    /// The `match` branches, which follow the exhaustive ones.
+    UnreachablePatterns,
+    /// The `require` calls with a condition which is always true.
+    NoopRequire,
+    /// The integer casts to types, which cannot hold all the values of the original type.
+    TruncatingCasts,
+}
+
+impl Lint {
+    ///
+    /// Checks whether the lint is `other` or the group it belongs to.
+    ///
+    pub fn covers(self, other: Self) -> bool {
+        self == Self::Warnings || self == other
+    }
+}
+
+impl FromStr for Lint {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "warnings" => Ok(Self::Warnings),
+            "unused_variables" => Ok(Self::UnusedVariables),
+            "unused_imports" => Ok(Self::UnusedImports),
+            "dead_code" => Ok(Self::DeadCode),
+            "unused_mut" => Ok(Self::UnusedMut),
+            "unreachable_patterns" => Ok(Self::UnreachablePatterns),
+            "noop_require" => Ok(Self::NoopRequire),
+            "truncating_casts" => Ok(Self::TruncatingCasts),
+            another => Err(format!("lint `{}` is unknown", another)),
+        }
+    }
+}
+
+impl fmt::Display for Lint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Warnings => write!(f, "warnings"),
+            Self::UnusedVariables => write!(f, "unused_variables"),
+            Self::UnusedImports => write!(f, "unused_imports"),
+            Self::DeadCode => write!(f, "dead_code"),
+            Self::UnusedMut => write!(f, "unused_mut"),
+            Self::UnreachablePatterns => write!(f, "unreachable_patterns"),
+            Self::NoopRequire => write!(f, "noop_require"),
+            Self::TruncatingCasts => write!(f, "truncating_casts"),
+        }
+    }
+}
@@ -0,0 +1,172 @@
+//!
+//! The semantic analyzer warning.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod lint;
+pub mod sink;
+
+use zinc_lexical::Location;
+
+use crate::diagnostic::label::Label;
+use crate::diagnostic::level::Level;
+use crate::diagnostic::span::Span;
+use crate::diagnostic::Diagnostic;
+
+use self::lint::Lint;
+
+///
+/// The semantic analyzer warning.
+///
+/// Unlike errors, warnings do not stop the analysis, but point to the code which is very likely
+/// to be a mistake.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Warning {
+    /// The variable is never read.
+    UnusedVariable {
+        /// The variable declaration location.
+        location: Location,
+        /// The variable name.
+        name: String,
+    },
+    /// The item imported with `use` is never referenced.
+    UnusedImport {
+        /// The `use` statement location.
+        location: Location,
+        /// The imported item name.
+        name: String,
+    },
+    /// The function is never called.
+    UnusedFunction {
+        /// The function declaration location.
+        location: Location,
+        /// The function name.
+        name: String,
+    },
+    /// The variable is declared with `mut`, but is never mutated.
+    UnusedMut {
+        /// The variable declaration location.
+        location: Location,
+        /// The variable name.
+        name: String,
+    },
+    /// The `match` branch follows the branches which have already matched all the values,
+    /// e.g. the one with a wildcard or irrefutable binding.
+    UnreachableMatchBranch {
+        /// The branch pattern location.
+        location: Location,
+    },
+    /// The `require` condition is the `true` constant, so the call does not check anything.
+    NoopRequire {
+        /// The `require` call location.
+        location: Location,
+    },
+    /// The integer is casted to a type, which cannot hold all the values of the original type.
+    TruncatingCast {
+        /// The casting operator location.
+        location: Location,
+        /// The stringified original type.
+        from: String,
+        /// The stringified target type.
+        to: String,
+    },
+}
+
+impl Warning {
+    ///
+    /// Returns the lint the warning belongs to.
+    ///
+    pub fn lint(&self) -> Lint {
+        match self {
+            Self::UnusedVariable { .. } => Lint::UnusedVariables,
+            Self::UnusedImport { .. } => Lint::UnusedImports,
+            Self::UnusedFunction { .. } => Lint::DeadCode,
+            Self::UnusedMut { .. } => Lint::UnusedMut,
+            Self::UnreachableMatchBranch { .. } => Lint::UnreachablePatterns,
+            Self::NoopRequire { .. } => Lint::NoopRequire,
+            Self::TruncatingCast { .. } => Lint::TruncatingCasts,
+        }
+    }
+
+    ///
+    /// Returns the semantic warning code.
+    ///
+    pub fn code(&self) -> usize {
+        match self {
+            Self::UnusedVariable { .. } => 1,
+            Self::UnusedImport { .. } => 2,
+            Self::UnusedFunction { .. } => 3,
+            Self::UnusedMut { .. } => 4,
+            Self::UnreachableMatchBranch { .. } => 5,
+            Self::NoopRequire { .. } => 6,
+            Self::TruncatingCast { .. } => 7,
+        }
+    }
+
+    ///
+    /// Returns the location the warning points to.
+    ///
+    pub fn location(&self) -> Location {
+        match self {
+            Self::UnusedVariable { location, .. } => *location,
+            Self::UnusedImport { location, .. } => *location,
+            Self::UnusedFunction { location, .. } => *location,
+            Self::UnusedMut { location, .. } => *location,
+            Self::UnreachableMatchBranch { location } => *location,
+            Self::NoopRequire { location } => *location,
+            Self::TruncatingCast { location, .. } => *location,
+        }
+    }
+
+    ///
+    /// Converts the warning into a diagnostic with the source code label.
+    ///
+    /// The `level` is `error` if the warning lint has been denied.
+    ///
+    pub fn diagnostic(&self, level: Level) -> Diagnostic {
+        let (message, help) = match self {
+            Self::UnusedVariable { name, .. } => (
+                format!("unused variable `{}`", name),
+                format!(
+                    "if this is intentional, prefix it with an underscore: `_{}`",
+                    name
+                ),
+            ),
+            Self::UnusedImport { name, .. } => (
+                format!("unused import `{}`", name),
+                "remove the `use` statement".to_owned(),
+            ),
+            Self::UnusedFunction { name, .. } => (
+                format!("function `{}` is never used", name),
+                "remove the function or add `#[allow(dead_code)]` to it".to_owned(),
+            ),
+            Self::UnusedMut { name, .. } => (
+                format!("variable `{}` does not need to be mutable", name),
+                "remove the `mut` keyword".to_owned(),
+            ),
+            Self::UnreachableMatchBranch { .. } => (
+                "match expression branch is unreachable".to_owned(),
+                "consider removing the branch or moving it above the branch with a wildcard or irrefutable binding".to_owned(),
+            ),
+            Self::NoopRequire { .. } => (
+                "`require` condition is always true".to_owned(),
+                "the call does not check anything, so either fix the condition or remove it"
+                    .to_owned(),
+            ),
+            Self::TruncatingCast { from, to, .. } => (
+                format!("casting `{}` to `{}` may overflow", from, to),
+                format!(
+                    "check that the value fits into `{}` before casting, or cast to a wider type",
+                    to
+                ),
+            ),
+        };
+
+        Diagnostic::new(level, Some(self.code()), message)
+            .with_label(Label::primary(Span::token(self.location()), None))
+            .with_help(Some(help.as_str()))
+    }
+}
@@ -0,0 +1,118 @@
+//!
+//! The semantic analyzer warning sink.
+//!
+
+use std::cell::RefCell;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+use zinc_lexical::Location;
+
+use crate::semantic::scope::item::Item;
+use crate::semantic::warning::Warning;
+
+///
+/// The warnings collected throughout the analysis of the entry module and its submodules.
+///
+/// The dependencies are not attached to any sink, since they are not edited by the developer.
+///
+#[derive(Debug, Default)]
+pub struct Sink {
+    /// The warnings collected throughout the analysis.
+    warnings: Vec<Warning>,
+    /// The functions, which must be called at least once, with their declaration locations.
+    functions: Vec<(Location, String)>,
+    /// The imported items, which must be referenced at least once, with the `use` statement
+    /// locations and the flags set upon the first reference.
+    imports: Vec<(Location, String, Rc<RefCell<Item>>, bool)>,
+    /// The declaration locations of the referenced items.
+    references: HashSet<(usize, usize, usize)>,
+}
+
+impl Sink {
+    ///
+    /// Wraps the sink into `Rc<RefCell<_>>` to be shared between the module scopes.
+    ///
+    pub fn wrap(self) -> Rc<RefCell<Self>> {
+        Rc::new(RefCell::new(self))
+    }
+
+    ///
+    /// Saves a warning, ignoring the duplicates.
+    ///
+    /// The duplicates appear if a piece of code is analyzed several times, e.g. a generic
+    /// function instantiated with different types.
+    ///
+    pub fn push(&mut self, warning: Warning) {
+        let key = (warning.code(), Self::key(warning.location()));
+        if self
+            .warnings
+            .iter()
+            .any(|existing| (existing.code(), Self::key(existing.location())) == key)
+        {
+            return;
+        }
+
+        self.warnings.push(warning);
+    }
+
+    ///
+    /// Declares a function, which is reported unless it is referenced during the analysis.
+    ///
+    pub fn declare_function(&mut self, location: Location, name: String) {
+        self.functions.push((location, name));
+    }
+
+    ///
+    /// Declares an imported item, which is reported unless it is referenced during the analysis.
+    ///
+    pub fn declare_import(&mut self, location: Location, name: String, item: Rc<RefCell<Item>>) {
+        self.imports.push((location, name, item, false));
+    }
+
+    ///
+    /// Registers a reference to the `item`.
+    ///
+    pub fn reference(&mut self, item: &Rc<RefCell<Item>>) {
+        for (_location, _name, import, is_used) in self.imports.iter_mut() {
+            if Rc::ptr_eq(import, item) {
+                *is_used = true;
+            }
+        }
+
+        if let Some(location) = RefCell::borrow(item).location() {
+            self.references.insert(Self::key(location));
+        }
+    }
+
+    ///
+    /// Reports the unused functions and imports, and returns all the warnings sorted by location.
+    ///
+    pub fn finish(&mut self) -> Vec<Warning> {
+        let functions = std::mem::take(&mut self.functions);
+        for (location, name) in functions.into_iter() {
+            if !self.references.contains(&Self::key(location)) {
+                self.push(Warning::UnusedFunction { location, name });
+            }
+        }
+
+        let imports = std::mem::take(&mut self.imports);
+        for (location, name, _item, is_used) in imports.into_iter() {
+            if !is_used {
+                self.push(Warning::UnusedImport { location, name });
+            }
+        }
+
+        let mut warnings = std::mem::take(&mut self.warnings);
+        warnings.sort_by_key(|warning| Self::key(warning.location()));
+        warnings
+    }
+
+    ///
+    /// Returns the location key, which also takes the file into account, unlike the location
+    /// equality operator.
+    ///
+    fn key(location: Location) -> (usize, usize, usize) {
+        (location.file, location.line, location.column)
+    }
+}
@@ -0,0 +1,292 @@
+//!
+//! The semantic analyzer warning tests.
+//!
+
+use zinc_lexical::Location;
+
+use crate::diagnostic::level::Level;
+
+use crate::semantic::warning::lint::Lint;
+use crate::semantic::warning::Warning;
+
+#[test]
+fn ok_no_warnings() {
+    let input = r#"
+use std::convert::to_bits;
+
+fn double(value: u8) -> u8 {
+    value * 2
+}
+
+fn main(input: u8) -> u8 {
+    let mut result = double(input);
+    result += 1;
+    let _bits = to_bits(result);
+    result
+}
+"#;
+
+    let expected = Ok(vec![]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_unused_variable() {
+    let input = r#"
+fn main() -> u8 {
+    let value = 42;
+    let _ignored = 64;
+    25
+}
+"#;
+
+    let expected = Ok(vec![Warning::UnusedVariable {
+        location: Location::test(3, 9),
+        name: "value".to_owned(),
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_unused_variable_argument() {
+    let input = r#"
+fn main(value: u8) -> u8 {
+    25
+}
+"#;
+
+    let expected = Ok(vec![Warning::UnusedVariable {
+        location: Location::test(2, 9),
+        name: "value".to_owned(),
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_unused_import() {
+    let input = r#"
+use std::convert::to_bits;
+
+fn main() {}
+"#;
+
+    let expected = Ok(vec![Warning::UnusedImport {
+        location: Location::test(2, 1),
+        name: "to_bits".to_owned(),
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_unused_function() {
+    let input = r#"
+fn unused() -> u8 {
+    42
+}
+
+pub fn exported() -> u8 {
+    42
+}
+
+fn main() {}
+"#;
+
+    let expected = Ok(vec![Warning::UnusedFunction {
+        location: Location::test(2, 1),
+        name: "unused".to_owned(),
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_unused_function_contract() {
+    let input = r#"
+contract Test {
+    pub value: u8;
+
+    pub fn new(value: u8) -> Self {
+        Self { value: Self::validated(value) }
+    }
+
+    pub fn get(self) -> u8 {
+        self.doubled()
+    }
+
+    fn validated(value: u8) -> u8 {
+        require(value < 128);
+        value
+    }
+
+    fn doubled(self) -> u8 {
+        self.value * 2
+    }
+
+    fn unused(self) -> u8 {
+        self.value
+    }
+}
+"#;
+
+    let expected = Ok(vec![Warning::UnusedFunction {
+        location: Location::test(22, 5),
+        name: "unused".to_owned(),
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_unused_mut() {
+    let input = r#"
+fn main() -> u8 {
+    let mut value = 42;
+    value
+}
+"#;
+
+    let expected = Ok(vec![Warning::UnusedMut {
+        location: Location::test(3, 13),
+        name: "value".to_owned(),
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_noop_require() {
+    let input = r#"
+fn main(value: u8) {
+    require(true);
+    require(value > 0);
+}
+"#;
+
+    let expected = Ok(vec![Warning::NoopRequire {
+        location: Location::test(3, 5),
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_truncating_cast() {
+    let input = r#"
+fn main(value: u64) -> u8 {
+    let extended = value as u128;
+    let _signed = value as i128;
+    extended as u8
+}
+"#;
+
+    let expected = Ok(vec![Warning::TruncatingCast {
+        location: Location::test(5, 14),
+        from: "u128".to_owned(),
+        to: "u8".to_owned(),
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn warning_truncating_cast_signed_to_unsigned() {
+    let input = r#"
+fn main(value: i8) -> u8 {
+    value as u8
+}
+"#;
+
+    let expected = Ok(vec![Warning::TruncatingCast {
+        location: Location::test(3, 11),
+        from: "i8".to_owned(),
+        to: "u8".to_owned(),
+    }]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_truncating_cast_diagnostic() {
+    let input = r#"
+fn main(value: u64) -> u8 {
+    value as u8
+}
+"#;
+
+    let warnings = crate::semantic::tests::compile_entry_warnings(input)
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+    let diagnostic = warnings[0].diagnostic(Level::Warning);
+
+    assert_eq!(diagnostic.message, "casting `u64` to `u8` may overflow");
+    assert_eq!(
+        diagnostic.help.as_deref(),
+        Some("check that the value fits into `u8` before casting, or cast to a wider type")
+    );
+}
+
+#[test]
+fn ok_allow_function() {
+    let input = r#"
+#[allow(truncating_casts, noop_require)]
+fn main(value: u64) -> u8 {
+    require(true);
+    value as u8
+}
+"#;
+
+    let expected = Ok(vec![]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_allow_warnings() {
+    let input = r#"
+#[allow(warnings)]
+fn unused() {
+    let mut value = 42;
+}
+
+fn main() {}
+"#;
+
+    let expected = Ok(vec![]);
+
+    let result = crate::semantic::tests::compile_entry_warnings(input);
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_lint_covers() {
+    assert!(Lint::Warnings.covers(Lint::DeadCode));
+    assert!(Lint::UnusedMut.covers(Lint::UnusedMut));
+    assert!(!Lint::UnusedMut.covers(Lint::UnusedVariables));
+    assert!(!Lint::DeadCode.covers(Lint::Warnings));
+}
@@ -16,6 +16,7 @@ use crate::generator::zinc_vm::State as ZincVMState;
 use crate::generator::IBytecodeWritable;
 use crate::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
 use crate::semantic::scope::Scope;
+use crate::semantic::warning::Warning;
 use crate::source::error::Error;
 use crate::source::file::File;
 use crate::source::Source;
@@ -173,7 +174,7 @@ impl Directory {
         Ok(
             EntryAnalyzer::define(Source::Directory(self), project, dependencies, true)
                 .map_err(CompilerError::Semantic)
-                .map_err(|error| error.format())
+                .map_err(CompilerError::diagnostic)
                 .map_err(Error::Compiling)?,
         )
     }
@@ -182,11 +183,13 @@ impl Directory {
     /// Gets all the intermediate representation scattered around the application scope tree and
     /// writes it to the bytecode.
     ///
+    /// Also returns the warnings collected throughout the analysis.
+    ///
     pub fn compile(
         self,
         manifest: zinc_project::Manifest,
         dependencies: HashMap<String, Rc<RefCell<Scope>>>,
-    ) -> anyhow::Result<Rc<RefCell<ZincVMState>>> {
+    ) -> anyhow::Result<(Rc<RefCell<ZincVMState>>, Vec<Warning>)> {
         let scope = EntryAnalyzer::define(
             Source::Directory(self),
             manifest.project.clone(),
@@ -194,13 +197,15 @@ impl Directory {
             false,
         )
         .map_err(CompilerError::Semantic)
-        .map_err(|error| error.format())
+        .map_err(CompilerError::diagnostic)
         .map_err(Error::Compiling)?;
 
+        let warnings = scope.borrow().take_warnings();
+
         let state = ZincVMState::new(manifest).wrap();
         Module::new(scope.borrow().get_intermediate()).write_to_zinc_vm(state.clone());
 
-        Ok(state)
+        Ok((state, warnings))
     }
 
     ///
@@ -4,6 +4,8 @@
 
 use thiserror::Error;
 
+use crate::diagnostic::Diagnostic;
+
 ///
 /// The source code module error.
 ///
@@ -51,7 +53,7 @@ pub enum Error {
         zinc_const::file_name::LIBRARY_ENTRY
     )]
     ProjectEntryNotFound,
-    /// The source code compiler analysis error, formatted as string.
+    /// The source code compiler analysis error, rendered as a diagnostic.
     #[error("{0}")]
-    Compiling(String),
+    Compiling(Diagnostic),
 }
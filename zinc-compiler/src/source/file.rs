@@ -21,6 +21,7 @@ use crate::generator::zinc_vm::State as ZincVMState;
 use crate::generator::IBytecodeWritable;
 use crate::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
 use crate::semantic::scope::Scope;
+use crate::semantic::warning::Warning;
 use crate::source::error::Error;
 use crate::source::Source;
 
@@ -60,7 +61,7 @@ impl File {
                 next_file_id,
             )
             .map_err(CompilerError::from)
-            .map_err(|error| error.format())
+            .map_err(CompilerError::diagnostic)
             .map_err(Error::Compiling)?;
 
         Ok(Self {
@@ -115,7 +116,7 @@ impl File {
                 next_file_id,
             )
             .map_err(CompilerError::from)
-            .map_err(|error| error.format())
+            .map_err(CompilerError::diagnostic)
             .map_err(Error::Compiling)?;
 
         Ok(Self {
@@ -138,7 +139,7 @@ impl File {
         Ok(
             EntryAnalyzer::define(Source::File(self), project, dependencies, true)
                 .map_err(CompilerError::Semantic)
-                .map_err(|error| error.format())
+                .map_err(CompilerError::diagnostic)
                 .map_err(Error::Compiling)?,
         )
     }
@@ -147,11 +148,13 @@ impl File {
     /// Gets all the intermediate representation scattered around the application scope tree and
     /// writes it to the bytecode.
     ///
+    /// Also returns the warnings collected throughout the analysis.
+    ///
     pub fn compile(
         self,
         manifest: zinc_project::Manifest,
         dependencies: HashMap<String, Rc<RefCell<Scope>>>,
-    ) -> anyhow::Result<Rc<RefCell<ZincVMState>>> {
+    ) -> anyhow::Result<(Rc<RefCell<ZincVMState>>, Vec<Warning>)> {
         let scope = EntryAnalyzer::define(
             Source::File(self),
             manifest.project.clone(),
@@ -159,13 +162,15 @@ impl File {
             false,
         )
         .map_err(CompilerError::Semantic)
-        .map_err(|error| error.format())
+        .map_err(CompilerError::diagnostic)
         .map_err(Error::Compiling)?;
 
+        let warnings = scope.borrow().take_warnings();
+
         let state = ZincVMState::new(manifest).wrap();
         Module::new(scope.borrow().get_intermediate()).write_to_zinc_vm(state.clone());
 
-        Ok(state)
+        Ok((state, warnings))
     }
 
     ///
@@ -199,7 +204,7 @@ impl File {
         let tree = Parser::default()
             .parse(code, next_file_id)
             .map_err(CompilerError::from)
-            .map_err(|error| error.format())
+            .map_err(CompilerError::diagnostic)
             .map_err(Error::Compiling)?;
 
         FILE_INDEX.next(&path, code.to_owned());
@@ -15,7 +15,10 @@ use std::rc::Rc;
 use anyhow::Context;
 
 use crate::generator::zinc_vm::State as ZincVMState;
+use crate::semantic::analyzer::entry::Analyzer as EntryAnalyzer;
+use crate::semantic::scope::analysis::Analysis;
 use crate::semantic::scope::Scope;
+use crate::semantic::warning::Warning;
 use crate::source::error::Error;
 
 use self::directory::Directory;
@@ -97,15 +100,30 @@ impl Source {
         }
     }
 
+    ///
+    /// Runs the semantic analyzer for the tooling, which goes on after errors.
+    ///
+    /// Returns the analysis data with the errors, resolved references, and the entry module scope.
+    ///
+    pub fn check(
+        self,
+        project: zinc_project::ManifestProject,
+        dependencies: HashMap<String, Rc<RefCell<Scope>>>,
+    ) -> Analysis {
+        EntryAnalyzer::check(self, project, dependencies)
+    }
+
     ///
     /// Gets all the intermediate representation scattered around the application scope tree and
     /// writes it to the bytecode.
     ///
+    /// Also returns the warnings collected throughout the analysis.
+    ///
     pub fn compile(
         self,
         manifest: zinc_project::Manifest,
         dependencies: HashMap<String, Rc<RefCell<Scope>>>,
-    ) -> anyhow::Result<Rc<RefCell<ZincVMState>>> {
+    ) -> anyhow::Result<(Rc<RefCell<ZincVMState>>, Vec<Warning>)> {
         match self {
             Self::File(inner) => inner.compile(manifest, dependencies),
             Self::Directory(inner) => inner.compile(manifest, dependencies),
@@ -6,6 +6,10 @@ use std::path::PathBuf;
 
 use structopt::StructOpt;
 
+use zinc_compiler::Lint;
+use zinc_compiler::MessageFormat;
+use zinc_compiler::OptimizationLevel;
+
 ///
 /// The Zinc compiler arguments.
 ///
@@ -36,9 +40,24 @@ pub struct Arguments {
     #[structopt(long = "test-only")]
     pub test_only: bool,
 
-    /// Enables the dead function code elimination optimization.
-    #[structopt(long = "opt-dfe")]
-    pub optimize_dead_function_elimination: bool,
+    /// The bytecode optimization level, from `0` to `2`. Writes the build to the release target
+    /// directory, if the optimizations are enabled.
+    #[structopt(short = "O", long = "opt-level", default_value = "0")]
+    pub optimization_level: OptimizationLevel,
+
+    /// The diagnostic message format, either `human` or `json`.
+    #[structopt(long = "message-format", default_value = "human")]
+    pub message_format: MessageFormat,
+
+    /// The lints, whose warnings are not reported, e.g. `unused_variables` or `warnings` for
+    /// all of them.
+    #[structopt(short = "A", long = "allow", number_of_values = 1)]
+    pub allowed_lints: Vec<Lint>,
+
+    /// The lints, whose warnings are reported as errors and fail the build, e.g. `dead_code`
+    /// or `warnings` for all of them.
+    #[structopt(short = "D", long = "deny", number_of_values = 1)]
+    pub denied_lints: Vec<Lint>,
 }
 
 impl Arguments {
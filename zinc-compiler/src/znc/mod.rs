@@ -13,6 +13,10 @@ use std::thread;
 use anyhow::Context;
 
 use zinc_compiler::Bundler;
+use zinc_compiler::Diagnostic;
+use zinc_compiler::DiagnosticLevel;
+use zinc_compiler::MessageFormat;
+use zinc_compiler::OptimizationLevel;
 
 use self::arguments::Arguments;
 
@@ -20,10 +24,20 @@ use self::arguments::Arguments;
 /// The application entry point.
 ///
 fn main() {
-    process::exit(match main_inner() {
+    let args = Arguments::new();
+    let message_format = args.message_format;
+
+    process::exit(match main_inner(args) {
         Ok(()) => zinc_const::exit_code::SUCCESS,
         Err(error) => {
-            log::error!("{:?}", error);
+            match message_format {
+                MessageFormat::Human => log::error!("{:?}", error),
+                MessageFormat::Json => println!(
+                    "{}",
+                    serde_json::to_string(&Diagnostic::from(&error))
+                        .expect(zinc_const::panic::DATA_CONVERSION)
+                ),
+            }
             zinc_const::exit_code::FAILURE
         }
     })
@@ -32,12 +46,13 @@ fn main() {
 ///
 /// The auxiliary `main` function to facilitate the `?` error conversion operator.
 ///
-fn main_inner() -> anyhow::Result<()> {
-    let args = Arguments::new();
-
+fn main_inner(args: Arguments) -> anyhow::Result<()> {
     zinc_logger::initialize(zinc_const::app_name::COMPILER, args.verbosity, args.quiet);
 
-    let optimize_dead_function_elimination = args.optimize_dead_function_elimination;
+    let optimization_level = args.optimization_level;
+    let message_format = args.message_format;
+    let allowed_lints = args.allowed_lints;
+    let denied_lints = args.denied_lints;
 
     let mut manifest_path = args.manifest_path;
     if !manifest_path.is_dir()
@@ -56,7 +71,7 @@ fn main_inner() -> anyhow::Result<()> {
         .with_context(|| data_directory_path.to_string_lossy().to_string())?;
 
     let mut target_directory_path = manifest_path.clone();
-    target_directory_path.push(if args.optimize_dead_function_elimination {
+    target_directory_path.push(if optimization_level != OptimizationLevel::None {
         zinc_const::directory::TARGET_RELEASE
     } else {
         zinc_const::directory::TARGET_DEBUG
@@ -69,19 +84,35 @@ fn main_inner() -> anyhow::Result<()> {
     fs::create_dir_all(&dependencies_directory_path)
         .with_context(|| dependencies_directory_path.to_string_lossy().to_string())?;
 
-    let build = thread::Builder::new()
+    let (build, warnings) = thread::Builder::new()
         .stack_size(zinc_const::limit::COMPILER_STACK_SIZE)
         .spawn(move || {
-            Bundler::new(
+            let mut bundler = Bundler::new(
                 manifest_path,
                 dependencies_directory_path,
-                optimize_dead_function_elimination,
+                optimization_level,
             )
-            .bundle()
+            .with_lints(allowed_lints, denied_lints);
+            let build = bundler.bundle();
+            (build, bundler.take_warnings())
         })
         .expect(zinc_const::panic::SYNCHRONIZATION)
         .join()
-        .expect(zinc_const::panic::SYNCHRONIZATION)?;
+        .expect(zinc_const::panic::SYNCHRONIZATION);
+
+    for warning in warnings.into_iter() {
+        match message_format {
+            MessageFormat::Human => match warning.level {
+                DiagnosticLevel::Error => log::error!("{}", warning),
+                DiagnosticLevel::Warning => log::warn!("{}", warning),
+            },
+            MessageFormat::Json => println!(
+                "{}",
+                serde_json::to_string(&warning).expect(zinc_const::panic::DATA_CONVERSION)
+            ),
+        }
+    }
+    let build = build?;
 
     let mut input_template_path = data_directory_path;
     input_template_path.push(format!(
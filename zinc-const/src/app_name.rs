@@ -11,6 +11,9 @@ pub static COMPILER: &str = "znc";
 /// The virtual machine default application name.
 pub static VIRTUAL_MACHINE: &str = "zvm";
 
+/// The language server default application name.
+pub static LANGUAGE_SERVER: &str = "zinc-lsp";
+
 /// The integration tester default application name.
 pub static TESTER: &str = "zinc-tester";
 
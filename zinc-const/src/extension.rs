@@ -5,6 +5,9 @@
 /// The manifest file extension.
 pub static MANIFEST: &str = "toml";
 
+/// The dependency lockfile extension.
+pub static LOCKFILE: &str = "lock";
+
 /// The source code file extension.
 pub static SOURCE: &str = "zn";
 
@@ -13,3 +16,9 @@ pub static BINARY: &str = "znb";
 
 /// The JSON data file extension.
 pub static JSON: &str = "json";
+
+/// The Solidity source code file extension.
+pub static SOLIDITY: &str = "sol";
+
+/// The folded call stacks file extension.
+pub static FOLDED: &str = "folded";
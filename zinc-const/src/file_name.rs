@@ -5,6 +5,9 @@
 /// The project manifest file name.
 pub static MANIFEST: &str = "Zargo";
 
+/// The project dependency lockfile name.
+pub static LOCKFILE: &str = "Zargo";
+
 /// The circuit or contract entry file name.
 pub static APPLICATION_ENTRY: &str = "main";
 
@@ -29,6 +32,12 @@ pub static PROVING_KEY: &str = "proving_key";
 /// The verifying key file default name.
 pub static VERIFYING_KEY: &str = "verifying_key";
 
+/// The Solidity verifier contract file default name.
+pub static VERIFIER: &str = "verifier";
+
+/// The constraint profile call stacks file default name.
+pub static PROFILE: &str = "profile";
+
 /// The private key file default name (testnet only!).
 pub static PRIVATE_KEY: &str = "private_key";
 
@@ -60,6 +60,25 @@ pub enum Error {
         /// The invalid character.
         found: char,
     },
+    /// A non-ASCII character is found in a byte string literal.
+    ExpectedAsciiCharacter {
+        /// The location of the invalid character.
+        location: Location,
+        /// The invalid character.
+        found: char,
+    },
+    /// An unknown escape sequence is found in a byte string literal.
+    UnknownCharacterEscape {
+        /// The location of the invalid character.
+        location: Location,
+        /// The invalid character after the `\`.
+        found: char,
+    },
+    /// A hexadecimal bytes literal contains an odd number of digits.
+    UnpairedHexadecimalDigit {
+        /// The location of the unpaired digit.
+        location: Location,
+    },
     /// An unexpected character forbidden in the current state.
     InvalidCharacter {
         /// The location of the invalid character.
@@ -133,6 +152,27 @@ impl Error {
         }
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn expected_ascii_character(location: Location, found: char) -> Self {
+        Self::ExpectedAsciiCharacter { location, found }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn unknown_character_escape(location: Location, found: char) -> Self {
+        Self::UnknownCharacterEscape { location, found }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn unpaired_hexadecimal_digit(location: Location) -> Self {
+        Self::UnpairedHexadecimalDigit { location }
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -11,9 +11,11 @@ pub(crate) mod token;
 
 pub use self::error::Error;
 pub use self::stream::TokenStream;
+pub use self::token::lexeme::comment::Comment;
 pub use self::token::lexeme::identifier::Identifier;
 pub use self::token::lexeme::keyword::Keyword;
 pub use self::token::lexeme::literal::boolean::Boolean as BooleanLiteral;
+pub use self::token::lexeme::literal::bytes::Bytes as BytesLiteral;
 pub use self::token::lexeme::literal::integer::Integer as IntegerLiteral;
 pub use self::token::lexeme::literal::string::String as StringLiteral;
 pub use self::token::lexeme::literal::Literal;
@@ -0,0 +1,43 @@
+//!
+//! The lexical bytes literal parser error.
+//!
+
+///
+/// The lexical bytes literal parser error.
+///
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    /// The lexeme is not a bytes literal, which means that another parser must be run.
+    NotABytes,
+    /// The literal has not been terminated before the end of the line.
+    UnterminatedDoubleQuote {
+        /// The position where the unterminated literal ends.
+        offset: usize,
+    },
+    /// A non-ASCII character is found in a byte string literal.
+    ExpectedAsciiCharacter {
+        /// The invalid character.
+        found: char,
+        /// The position of the invalid character.
+        offset: usize,
+    },
+    /// An unknown escape sequence is found in a byte string literal.
+    UnknownCharacterEscape {
+        /// The invalid character after the `\`.
+        found: char,
+        /// The position of the invalid character.
+        offset: usize,
+    },
+    /// A non-hexadecimal character is found in a hexadecimal bytes literal.
+    ExpectedOneOfHexadecimal {
+        /// The invalid character.
+        found: char,
+        /// The position of the invalid character.
+        offset: usize,
+    },
+    /// A hexadecimal bytes literal contains an odd number of digits.
+    UnpairedHexadecimalDigit {
+        /// The position of the unpaired digit.
+        offset: usize,
+    },
+}
@@ -0,0 +1,193 @@
+//!
+//! The lexical bytes literal parser.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod error;
+pub mod output;
+
+use crate::token::lexeme::literal::bytes::Bytes;
+use crate::token::lexeme::literal::integer::Integer;
+
+use self::error::Error;
+use self::output::Output;
+
+///
+/// The parser state.
+///
+pub enum State {
+    /// The prefix and `"` have been parsed so far, and a byte string character is expected.
+    Character,
+    /// The `\` has been found, so an escape sequence character is expected.
+    EscapedCharacter,
+    /// The `\x` has been found, so the high digit of the escaped byte is expected.
+    EscapedHexadecimalHighDigit,
+    /// The high digit of the escaped byte has been parsed so far.
+    EscapedHexadecimalLowDigit {
+        /// The high digit value.
+        high: u8,
+    },
+    /// The prefix and `"`, or a complete byte have been parsed so far.
+    HexadecimalHighDigit,
+    /// The high digit of a byte has been parsed so far.
+    HexadecimalLowDigit {
+        /// The high digit value.
+        high: u8,
+        /// The position of the high digit.
+        offset: usize,
+    },
+}
+
+///
+/// Parses a bytes literal.
+///
+/// The byte strings support the `\n`, `\r`, `\t`, `\0`, `\\`, `\"` and `\xNN` escapes.
+///
+/// Examples:
+/// 'b"zinc"'
+/// 'hex"deadbeef"'
+///
+pub fn parse(input: &str) -> Result<Output, Error> {
+    let (mut state, mut size) = if input.starts_with(Bytes::PREFIX_HEXADECIMAL) {
+        (
+            State::HexadecimalHighDigit,
+            Bytes::PREFIX_HEXADECIMAL.chars().count(),
+        )
+    } else if input.starts_with(Bytes::PREFIX_STRING) {
+        (State::Character, Bytes::PREFIX_STRING.chars().count())
+    } else {
+        return Err(Error::NotABytes);
+    };
+    match input.chars().nth(size) {
+        Some('\"') => size += 1,
+        _ => return Err(Error::NotABytes),
+    }
+
+    let mut bytes = Vec::with_capacity(32);
+
+    loop {
+        let character = input.chars().nth(size);
+        match state {
+            State::Character => match character {
+                Some('\"') => {
+                    size += 1;
+                    return Ok(Output::new(size, Bytes::new_string(bytes)));
+                }
+                Some('\\') => {
+                    size += 1;
+                    state = State::EscapedCharacter;
+                }
+                Some('\n') | None => return Err(Error::UnterminatedDoubleQuote { offset: size }),
+                Some(character) if character.is_ascii() => {
+                    bytes.push(character as u8);
+                    size += 1;
+                }
+                Some(character) => {
+                    return Err(Error::ExpectedAsciiCharacter {
+                        found: character,
+                        offset: size,
+                    })
+                }
+            },
+            State::EscapedCharacter => match character {
+                Some('\n') | None => return Err(Error::UnterminatedDoubleQuote { offset: size }),
+                Some('x') => {
+                    size += 1;
+                    state = State::EscapedHexadecimalHighDigit;
+                }
+                Some(character) => {
+                    let byte = match character {
+                        'n' => b'\n',
+                        'r' => b'\r',
+                        't' => b'\t',
+                        '0' => b'\0',
+                        '\\' => b'\\',
+                        '\"' => b'\"',
+                        character => {
+                            return Err(Error::UnknownCharacterEscape {
+                                found: character,
+                                offset: size,
+                            })
+                        }
+                    };
+                    bytes.push(byte);
+                    size += 1;
+                    state = State::Character;
+                }
+            },
+            State::EscapedHexadecimalHighDigit => match character {
+                Some('\n') | None => return Err(Error::UnterminatedDoubleQuote { offset: size }),
+                Some(character) => match character.to_digit(16) {
+                    Some(digit) => {
+                        size += 1;
+                        state = State::EscapedHexadecimalLowDigit { high: digit as u8 };
+                    }
+                    None => {
+                        return Err(Error::ExpectedOneOfHexadecimal {
+                            found: character,
+                            offset: size,
+                        })
+                    }
+                },
+            },
+            State::EscapedHexadecimalLowDigit { high } => match character {
+                Some('\n') | None => return Err(Error::UnterminatedDoubleQuote { offset: size }),
+                Some(character) => match character.to_digit(16) {
+                    Some(digit) => {
+                        bytes.push((high << 4) | digit as u8);
+                        size += 1;
+                        state = State::Character;
+                    }
+                    None => {
+                        return Err(Error::ExpectedOneOfHexadecimal {
+                            found: character,
+                            offset: size,
+                        })
+                    }
+                },
+            },
+            State::HexadecimalHighDigit => match character {
+                Some('\"') => {
+                    size += 1;
+                    return Ok(Output::new(size, Bytes::new_hexadecimal(bytes)));
+                }
+                Some(Integer::CHARACTER_DELIMITER) => size += 1,
+                Some('\n') | None => return Err(Error::UnterminatedDoubleQuote { offset: size }),
+                Some(character) => match character.to_digit(16) {
+                    Some(digit) => {
+                        state = State::HexadecimalLowDigit {
+                            high: digit as u8,
+                            offset: size,
+                        };
+                        size += 1;
+                    }
+                    None => {
+                        return Err(Error::ExpectedOneOfHexadecimal {
+                            found: character,
+                            offset: size,
+                        })
+                    }
+                },
+            },
+            State::HexadecimalLowDigit { high, offset } => match character {
+                Some('\"') => return Err(Error::UnpairedHexadecimalDigit { offset }),
+                Some('\n') | None => return Err(Error::UnterminatedDoubleQuote { offset: size }),
+                Some(character) => match character.to_digit(16) {
+                    Some(digit) => {
+                        bytes.push((high << 4) | digit as u8);
+                        size += 1;
+                        state = State::HexadecimalHighDigit;
+                    }
+                    None => {
+                        return Err(Error::ExpectedOneOfHexadecimal {
+                            found: character,
+                            offset: size,
+                        })
+                    }
+                },
+            },
+        }
+    }
+}
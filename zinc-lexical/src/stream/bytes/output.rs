@@ -0,0 +1,25 @@
+//!
+//! The lexical bytes literal parser output.
+//!
+
+use crate::token::lexeme::literal::bytes::Bytes;
+
+///
+/// The lexical bytes literal parser output.
+///
+#[derive(Debug, PartialEq)]
+pub struct Output {
+    /// The number of characters in the literal, including the prefix and quotes.
+    pub size: usize,
+    /// The bytes literal data.
+    pub bytes: Bytes,
+}
+
+impl Output {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(size: usize, bytes: Bytes) -> Self {
+        Self { size, bytes }
+    }
+}
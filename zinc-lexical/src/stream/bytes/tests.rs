@@ -0,0 +1,119 @@
+//!
+//! The lexical bytes literal parser tests.
+//!
+
+use super::parse;
+use super::Error;
+use super::Output;
+use crate::token::lexeme::literal::bytes::Bytes;
+
+#[test]
+fn ok_string() {
+    let input = r#"b"zinc\"""#;
+    let expected = Ok(Output::new(
+        input.len(),
+        Bytes::new_string(b"zinc\"".to_vec()),
+    ));
+    let result = parse(input);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_string_escapes() {
+    let input = r#"b"\n\r\t\0\\\"\x7f\xFF""#;
+    let expected = Ok(Output::new(
+        input.len(),
+        Bytes::new_string(vec![b'\n', b'\r', b'\t', 0x00, b'\\', b'"', 0x7f, 0xff]),
+    ));
+    let result = parse(input);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_string_empty() {
+    let input = r#"b"""#;
+    let expected = Ok(Output::new(input.len(), Bytes::new_string(vec![])));
+    let result = parse(input);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_hexadecimal() {
+    let input = r#"hex"dead_BEEF""#;
+    let expected = Ok(Output::new(
+        input.len(),
+        Bytes::new_hexadecimal(vec![0xde, 0xad, 0xbe, 0xef]),
+    ));
+    let result = parse(input);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_not_a_bytes() {
+    let input = r#"hexadecimal"#;
+    let expected = Err(Error::NotABytes);
+    let result = parse(input);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_unterminated_double_quote() {
+    let input = r#"b"zinc"#;
+    let expected = Err(Error::UnterminatedDoubleQuote {
+        offset: input.len(),
+    });
+    let result = parse(input);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_expected_ascii_character() {
+    let input = r#"b"zinc™""#;
+    let expected = Err(Error::ExpectedAsciiCharacter {
+        found: '™',
+        offset: 6,
+    });
+    let result = parse(input);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_expected_one_of_hexadecimal() {
+    let input = r#"hex"deadbeeg""#;
+    let expected = Err(Error::ExpectedOneOfHexadecimal {
+        found: 'g',
+        offset: 11,
+    });
+    let result = parse(input);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_unpaired_hexadecimal_digit() {
+    let input = r#"hex"deadbee""#;
+    let expected = Err(Error::UnpairedHexadecimalDigit { offset: 10 });
+    let result = parse(input);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_unknown_character_escape() {
+    let input = r#"b"zinc\q""#;
+    let expected = Err(Error::UnknownCharacterEscape {
+        found: 'q',
+        offset: 7,
+    });
+    let result = parse(input);
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_escaped_byte_expected_one_of_hexadecimal() {
+    let input = r#"b"\x4g""#;
+    let expected = Err(Error::ExpectedOneOfHexadecimal {
+        found: 'g',
+        offset: 5,
+    });
+    let result = parse(input);
+    assert_eq!(result, expected);
+}
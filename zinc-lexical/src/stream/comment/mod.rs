@@ -79,7 +79,7 @@ pub fn parse(input: &str) -> Result<Output, Error> {
                     size += 1;
                     column += 1;
                     lines += 1;
-                    let comment = Comment::new_line(input[2..size - 1].to_owned());
+                    let comment = Comment::new_line(input.chars().skip(2).take(size - 3).collect());
                     return Ok(Output::new(size, lines, column, comment));
                 }
                 Some(_) => {
@@ -87,7 +87,7 @@ pub fn parse(input: &str) -> Result<Output, Error> {
                     column += 1;
                 }
                 None => {
-                    let comment = Comment::new_line(input[2..size].to_owned());
+                    let comment = Comment::new_line(input.chars().skip(2).take(size - 2).collect());
                     return Ok(Output::new(size, lines, column, comment));
                 }
             },
@@ -112,7 +112,8 @@ pub fn parse(input: &str) -> Result<Output, Error> {
                 Some('/') => {
                     size += 1;
                     column += 1;
-                    let comment = Comment::new_block(input[2..size - 2].to_owned());
+                    let comment =
+                        Comment::new_block(input.chars().skip(2).take(size - 4).collect());
                     return Ok(Output::new(size, lines, column, comment));
                 }
                 Some('\n') => {
@@ -2,6 +2,7 @@
 //! The lexical token stream.
 //!
 
+pub mod bytes;
 pub mod comment;
 pub mod integer;
 pub mod string;
@@ -21,6 +22,7 @@ use crate::token::lexeme::Lexeme;
 use crate::token::location::Location;
 use crate::token::Token;
 
+use self::bytes::error::Error as BytesParserError;
 use self::comment::error::Error as CommentParserError;
 use self::integer::error::Error as IntegerParserError;
 use self::string::error::Error as StringParserError;
@@ -34,11 +36,15 @@ pub struct TokenStream<'a> {
     input: &'a str,
     /// The number of characters processed so far
     offset: usize,
+    /// The number of bytes processed so far, which is the start of the unprocessed input slice
+    position: usize,
     /// The current position in the file
     location: Location,
     /// The queue buffer where the characters acquired with the look-ahead method are stored.
     /// If the queue is not empty, the next character will be taken therefrom.
     look_ahead: VecDeque<Token>,
+    /// Whether the comments are yielded as tokens instead of being skipped.
+    is_preserving_comments: bool,
 }
 
 impl<'a> TokenStream<'a> {
@@ -53,8 +59,21 @@ impl<'a> TokenStream<'a> {
         Self {
             input,
             offset: 0,
+            position: 0,
             location: Location::new(file),
             look_ahead: VecDeque::with_capacity(Self::LOOK_AHEAD_INITIAL_CAPACITY),
+            is_preserving_comments: false,
+        }
+    }
+
+    ///
+    /// Initializes a stream, which yields the comments as `Lexeme::Comment` tokens.
+    /// Used by tools which must not lose the comments, e.g. the source code formatter.
+    ///
+    pub fn new_preserving_comments(input: &'a str, file: usize) -> Self {
+        Self {
+            is_preserving_comments: true,
+            ..Self::new(input, file)
         }
     }
 
@@ -107,25 +126,52 @@ impl<'a> TokenStream<'a> {
         Self {
             input,
             offset: 0,
+            position: 0,
             location: Location::new(0),
             look_ahead: VecDeque::with_capacity(Self::LOOK_AHEAD_INITIAL_CAPACITY),
+            is_preserving_comments: false,
         }
     }
 
+    ///
+    /// The number of characters processed so far, that is, the end of the last token taken
+    /// from the input. The look-ahead tokens are also counted, so the value is only meaningful
+    /// if the stream is read with the `next` method alone.
+    ///
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    ///
+    /// Skips `size` characters of the input.
+    ///
+    /// The subparsers count characters, whereas the input is sliced by bytes, so the byte
+    /// length of the skipped characters is computed to support the non-ASCII input.
+    ///
+    fn skip(&mut self, size: usize) {
+        self.position += self.input[self.position..]
+            .chars()
+            .take(size)
+            .map(char::len_utf8)
+            .sum::<usize>();
+        self.offset += size;
+    }
+
     ///
     /// The function checks if a character:
     /// 1. Is a whitespace -> skip
     /// 2. Starts a comment -> start the comment subparser
     /// 3. Starts a string literal -> start the string subparser
     /// 4. Starts a number -> start the number subparser
-    /// 5. Starts a word -> start the word subparser
-    /// 6. Starts a symbol -> start the operand subparser
-    /// 7. Is unknown -> yield an 'invalid character' error
+    /// 5. Starts a bytes literal -> start the bytes subparser
+    /// 6. Starts a word -> start the word subparser
+    /// 7. Starts a symbol -> start the operand subparser
+    /// 8. Is unknown -> yield an 'invalid character' error
     ///
     /// If the end of input has been reached, an 'EOF' token is returned for consequent calls.
     ///
     fn advance(&mut self) -> Result<Token, Error> {
-        while let Some(character) = self.input.chars().nth(self.offset) {
+        while let Some(character) = self.input[self.position..].chars().next() {
             if character.is_ascii_whitespace() {
                 if character == '\n' {
                     self.location.line += 1;
@@ -133,19 +179,26 @@ impl<'a> TokenStream<'a> {
                 } else if character != '\r' {
                     self.location.column += 1;
                 }
-                self.offset += 1;
+                self.skip(1);
                 continue;
             }
 
             if character == '/' {
-                match self::comment::parse(&self.input[self.offset..]) {
+                match self::comment::parse(&self.input[self.position..]) {
                     Ok(output) => {
+                        let location = self.location;
                         self.location.line += output.lines;
                         self.location.column = match output.comment {
                             Comment::Line { .. } => 1,
+                            Comment::Block { .. } if output.lines == 0 => {
+                                self.location.column + output.column - 1
+                            }
                             Comment::Block { .. } => output.column,
                         };
-                        self.offset += output.size;
+                        self.skip(output.size);
+                        if self.is_preserving_comments {
+                            return Ok(Token::new(Lexeme::Comment(output.comment), location));
+                        }
                         continue;
                     }
                     Err(CommentParserError::NotAComment) => {}
@@ -159,11 +212,11 @@ impl<'a> TokenStream<'a> {
             }
 
             if character == '\"' {
-                match self::string::parse(&self.input[self.offset..]) {
+                match self::string::parse(&self.input[self.position..]) {
                     Ok(output) => {
                         let location = self.location;
                         self.location.column += output.size;
-                        self.offset += output.size;
+                        self.skip(output.size);
                         return Ok(Token::new(
                             Lexeme::Literal(Literal::String(StringLiteral::new(output.string))),
                             location,
@@ -180,11 +233,11 @@ impl<'a> TokenStream<'a> {
             }
 
             if character.is_ascii_digit() {
-                match self::integer::parse(&self.input[self.offset..]) {
+                match self::integer::parse(&self.input[self.position..]) {
                     Ok(output) => {
                         let location = self.location;
                         self.location.column += output.size;
-                        self.offset += output.size;
+                        self.skip(output.size);
                         return Ok(Token::new(
                             Lexeme::Literal(Literal::Integer(output.integer)),
                             location,
@@ -228,18 +281,60 @@ impl<'a> TokenStream<'a> {
             }
 
             if Identifier::can_start_with(character) {
-                let output = self::word::parse(&self.input[self.offset..]);
+                match self::bytes::parse(&self.input[self.position..]) {
+                    Ok(output) => {
+                        let location = self.location;
+                        self.location.column += output.size;
+                        self.skip(output.size);
+                        return Ok(Token::new(
+                            Lexeme::Literal(Literal::Bytes(output.bytes)),
+                            location,
+                        ));
+                    }
+                    Err(BytesParserError::NotABytes) => {}
+                    Err(BytesParserError::UnterminatedDoubleQuote { offset }) => {
+                        return Err(Error::unterminated_double_quote_string(
+                            self.location,
+                            self.location.shifted_right(offset),
+                        ));
+                    }
+                    Err(BytesParserError::ExpectedAsciiCharacter { found, offset }) => {
+                        return Err(Error::expected_ascii_character(
+                            self.location.shifted_right(offset),
+                            found,
+                        ))
+                    }
+                    Err(BytesParserError::UnknownCharacterEscape { found, offset }) => {
+                        return Err(Error::unknown_character_escape(
+                            self.location.shifted_right(offset),
+                            found,
+                        ))
+                    }
+                    Err(BytesParserError::ExpectedOneOfHexadecimal { found, offset }) => {
+                        return Err(Error::expected_one_of_hexadecimal(
+                            self.location.shifted_right(offset),
+                            found,
+                        ))
+                    }
+                    Err(BytesParserError::UnpairedHexadecimalDigit { offset }) => {
+                        return Err(Error::unpaired_hexadecimal_digit(
+                            self.location.shifted_right(offset),
+                        ))
+                    }
+                }
+
+                let output = self::word::parse(&self.input[self.position..]);
                 let location = self.location;
                 self.location.column += output.size;
-                self.offset += output.size;
+                self.skip(output.size);
                 return Ok(Token::new(output.word, location));
             }
 
-            return match self::symbol::parse(&self.input[self.offset..]) {
+            return match self::symbol::parse(&self.input[self.position..]) {
                 Ok(output) => {
                     let location = self.location;
                     self.location.column += output.size;
-                    self.offset += output.size;
+                    self.skip(output.size);
                     Ok(Token::new(Lexeme::Symbol(output.symbol), location))
                 }
                 Err(SymbolParserError::InvalidCharacter { found, offset }) => Err(
@@ -4,9 +4,12 @@
 
 use crate::error::Error;
 use crate::stream::TokenStream;
+use crate::token::lexeme::comment::Comment;
 use crate::token::lexeme::identifier::Identifier;
 use crate::token::lexeme::keyword::Keyword;
+use crate::token::lexeme::literal::bytes::Bytes;
 use crate::token::lexeme::literal::integer::Integer;
+use crate::token::lexeme::literal::string::String as StringLiteral;
 use crate::token::lexeme::literal::Literal;
 use crate::token::lexeme::symbol::Symbol;
 use crate::token::lexeme::Lexeme;
@@ -82,6 +85,166 @@ let mut c: u8 = 2 + 2;
     assert_eq!(result, expected);
 }
 
+#[test]
+fn ok_preserving_comments() {
+    let input = r#"
+/* block */
+let a = 42; // line
+"#;
+
+    let expected = vec![
+        Token {
+            lexeme: Lexeme::Comment(Comment::new_block(" block ".to_owned())),
+            location: Location::test(2, 1),
+        },
+        Token {
+            lexeme: Lexeme::Keyword(Keyword::Let),
+            location: Location::test(3, 1),
+        },
+        Token {
+            lexeme: Lexeme::Identifier(Identifier::new("a".to_owned())),
+            location: Location::test(3, 5),
+        },
+        Token {
+            lexeme: Lexeme::Symbol(Symbol::Equals),
+            location: Location::test(3, 7),
+        },
+        Token {
+            lexeme: Lexeme::Literal(Literal::Integer(Integer::new_decimal("42".to_owned()))),
+            location: Location::test(3, 9),
+        },
+        Token {
+            lexeme: Lexeme::Symbol(Symbol::Semicolon),
+            location: Location::test(3, 11),
+        },
+        Token {
+            lexeme: Lexeme::Comment(Comment::new_line(" line".to_owned())),
+            location: Location::test(3, 13),
+        },
+    ];
+
+    let mut result = Vec::with_capacity(expected.len());
+    let mut stream = TokenStream::new_preserving_comments(input, Location::test(1, 1).file);
+    loop {
+        match stream.next().expect(zinc_const::panic::TEST_DATA_VALID) {
+            Token {
+                lexeme: Lexeme::Eof,
+                ..
+            } => break,
+            token => result.push(token),
+        }
+    }
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_block_comment_inline() {
+    let input = r#"foo(a, /* arg */ b)"#;
+
+    let expected = vec![
+        Token {
+            lexeme: Lexeme::Comment(Comment::new_block(" arg ".to_owned())),
+            location: Location::test(1, 8),
+        },
+        Token {
+            lexeme: Lexeme::Identifier(Identifier::new("b".to_owned())),
+            location: Location::test(1, 18),
+        },
+        Token {
+            lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+            location: Location::test(1, 19),
+        },
+    ];
+
+    let mut result = Vec::with_capacity(expected.len());
+    let mut stream = TokenStream::new_preserving_comments(input, Location::test(1, 1).file);
+    loop {
+        match stream.next().expect(zinc_const::panic::TEST_DATA_VALID) {
+            Token {
+                lexeme: Lexeme::Eof,
+                ..
+            } => break,
+            token => result.push(token),
+        }
+    }
+
+    assert_eq!(result.split_off(4), expected);
+}
+
+#[test]
+fn ok_bytes() {
+    let input = r#"b"zinc" hex"cafe" hex"#;
+
+    let expected = vec![
+        Token {
+            lexeme: Lexeme::Literal(Literal::Bytes(Bytes::new_string(b"zinc".to_vec()))),
+            location: Location::test(1, 1),
+        },
+        Token {
+            lexeme: Lexeme::Literal(Literal::Bytes(Bytes::new_hexadecimal(vec![0xca, 0xfe]))),
+            location: Location::test(1, 9),
+        },
+        Token {
+            lexeme: Lexeme::Identifier(Identifier::new("hex".to_owned())),
+            location: Location::test(1, 19),
+        },
+    ];
+
+    let mut result = Vec::with_capacity(expected.len());
+    let mut stream = TokenStream::test(input);
+    loop {
+        match stream.next().expect(zinc_const::panic::TEST_DATA_VALID) {
+            Token {
+                lexeme: Lexeme::Eof,
+                ..
+            } => break,
+            token => result.push(token),
+        }
+    }
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn ok_non_ascii() {
+    let input = r#"/* ° */ "héé" // ™
+b"zinc""#;
+
+    let expected = vec![
+        Token {
+            lexeme: Lexeme::Comment(Comment::new_block(" ° ".to_owned())),
+            location: Location::test(1, 1),
+        },
+        Token {
+            lexeme: Lexeme::Literal(Literal::String(StringLiteral::new("héé".to_owned()))),
+            location: Location::test(1, 9),
+        },
+        Token {
+            lexeme: Lexeme::Comment(Comment::new_line(" ™".to_owned())),
+            location: Location::test(1, 15),
+        },
+        Token {
+            lexeme: Lexeme::Literal(Literal::Bytes(Bytes::new_string(b"zinc".to_vec()))),
+            location: Location::test(2, 1),
+        },
+    ];
+
+    let mut result = Vec::with_capacity(expected.len());
+    let mut stream = TokenStream::new_preserving_comments(input, Location::test(1, 1).file);
+    loop {
+        match stream.next().expect(zinc_const::panic::TEST_DATA_VALID) {
+            Token {
+                lexeme: Lexeme::Eof,
+                ..
+            } => break,
+            token => result.push(token),
+        }
+    }
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_unterminated_block_comment() {
     let input = "/*block comment";
@@ -160,6 +323,42 @@ fn error_expected_one_of_hexadecimal() {
     assert_eq!(result, expected);
 }
 
+#[test]
+fn error_expected_ascii_character() {
+    let input = "b\"zinc™\"";
+
+    let expected: Result<Token, Error> =
+        Err(Error::expected_ascii_character(Location::test(1, 7), '™'));
+
+    let result = TokenStream::test(input).next();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_unknown_character_escape() {
+    let input = "b\"zinc\\q\"";
+
+    let expected: Result<Token, Error> =
+        Err(Error::unknown_character_escape(Location::test(1, 8), 'q'));
+
+    let result = TokenStream::test(input).next();
+
+    assert_eq!(result, expected);
+}
+
+#[test]
+fn error_unpaired_hexadecimal_digit() {
+    let input = "hex\"abc\"";
+
+    let expected: Result<Token, Error> =
+        Err(Error::unpaired_hexadecimal_digit(Location::test(1, 7)));
+
+    let result = TokenStream::test(input).next();
+
+    assert_eq!(result, expected);
+}
+
 #[test]
 fn error_invalid_character() {
     let input = "@";
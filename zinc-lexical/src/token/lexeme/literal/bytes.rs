@@ -0,0 +1,88 @@
+//!
+//! The lexical token bytes literal lexeme.
+//!
+
+use std::fmt;
+
+///
+/// The lexical bytes literal.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Bytes {
+    /// A byte string literal, like `b"zinc"`.
+    String {
+        /// The inner literal bytes.
+        inner: Vec<u8>,
+    },
+    /// A hexadecimal bytes literal, like `hex"deadbeef"`.
+    Hexadecimal {
+        /// The inner literal bytes.
+        inner: Vec<u8>,
+    },
+}
+
+impl Bytes {
+    /// The byte string literal prefix.
+    pub const PREFIX_STRING: &'static str = "b";
+    /// The hexadecimal bytes literal prefix.
+    pub const PREFIX_HEXADECIMAL: &'static str = "hex";
+
+    ///
+    /// Creates a byte string value.
+    ///
+    pub fn new_string(inner: Vec<u8>) -> Self {
+        Self::String { inner }
+    }
+
+    ///
+    /// Creates a hexadecimal bytes value.
+    ///
+    pub fn new_hexadecimal(inner: Vec<u8>) -> Self {
+        Self::Hexadecimal { inner }
+    }
+}
+
+impl From<Bytes> for Vec<u8> {
+    fn from(literal: Bytes) -> Self {
+        match literal {
+            Bytes::String { inner } => inner,
+            Bytes::Hexadecimal { inner } => inner,
+        }
+    }
+}
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::String { inner } => write!(
+                f,
+                "{}\"{}\"",
+                Self::PREFIX_STRING,
+                inner
+                    .iter()
+                    .map(|byte| match *byte {
+                        b'\n' => "\\n".to_owned(),
+                        b'\r' => "\\r".to_owned(),
+                        b'\t' => "\\t".to_owned(),
+                        b'\0' => "\\0".to_owned(),
+                        b'\"' | b'\\' => format!("\\{}", *byte as char),
+                        byte if byte.is_ascii_graphic() || byte == b' ' =>
+                            (byte as char).to_string(),
+                        byte => format!("\\x{:02x}", byte),
+                    })
+                    .collect::<Vec<String>>()
+                    .join("")
+            ),
+            Self::Hexadecimal { inner } => write!(
+                f,
+                "{}\"{}\"",
+                Self::PREFIX_HEXADECIMAL,
+                inner
+                    .iter()
+                    .map(|byte| format!("{:02x}", byte))
+                    .collect::<Vec<String>>()
+                    .join("")
+            ),
+        }
+    }
+}
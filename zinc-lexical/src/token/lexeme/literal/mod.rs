@@ -3,12 +3,14 @@
 //!
 
 pub mod boolean;
+pub mod bytes;
 pub mod integer;
 pub mod string;
 
 use std::fmt;
 
 use self::boolean::Boolean;
+use self::bytes::Bytes;
 use self::integer::Integer;
 use self::string::String;
 
@@ -23,6 +25,8 @@ pub enum Literal {
     Integer(Integer),
     /// A string literal, like `"message"`.
     String(String),
+    /// A bytes literal, like `b"zinc"`, or `hex"deadbeef"`.
+    Bytes(Bytes),
 }
 
 impl fmt::Display for Literal {
@@ -31,6 +35,7 @@ impl fmt::Display for Literal {
             Self::Boolean(inner) => write!(f, "{}", inner),
             Self::Integer(inner) => write!(f, "{}", inner),
             Self::String(inner) => write!(f, "{}", inner),
+            Self::Bytes(inner) => write!(f, "{}", inner),
         }
     }
 }
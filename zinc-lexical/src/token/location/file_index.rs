@@ -3,7 +3,10 @@
 //!
 
 use std::collections::HashMap;
+use std::ops::Range;
 use std::path::PathBuf;
+use std::sync::atomic::AtomicUsize;
+use std::sync::atomic::Ordering;
 use std::sync::RwLock;
 
 use lazy_static::lazy_static;
@@ -15,6 +18,8 @@ use lazy_static::lazy_static;
 pub struct FileIndex {
     /// The inner file data storage with the file unique ID as the key.
     pub inner: RwLock<HashMap<usize, Data>>,
+    /// The last allocated file sequence ID, which is not decreased when the files are removed.
+    sequence: AtomicUsize,
 }
 
 impl Default for FileIndex {
@@ -51,6 +56,7 @@ impl FileIndex {
     pub fn new() -> Self {
         Self {
             inner: RwLock::new(HashMap::with_capacity(Self::INITIAL_CAPACITY)),
+            sequence: AtomicUsize::new(0),
         }
     }
 
@@ -58,7 +64,7 @@ impl FileIndex {
     /// Allocates the next file sequence ID.
     ///
     pub fn next(&self, path: &PathBuf, code: String) -> usize {
-        let sequence_id = self.peek();
+        let sequence_id = self.sequence.fetch_add(1, Ordering::SeqCst) + 1;
 
         log::debug!("File ID {:06} for {:?}", sequence_id, path);
 
@@ -80,10 +86,7 @@ impl FileIndex {
     /// Returns the current file sequence ID.
     ///
     pub fn current(&self) -> usize {
-        self.inner
-            .read()
-            .expect(zinc_const::panic::SYNCHRONIZATION)
-            .len()
+        self.sequence.load(Ordering::SeqCst)
     }
 
     ///
@@ -105,4 +108,19 @@ impl FileIndex {
             .path
             .to_owned()
     }
+
+    ///
+    /// Removes the files with the `indexes`, e.g. the ones of an outdated project analysis.
+    ///
+    /// The sequence IDs are never reused, so the removed indexes are not allocated again.
+    ///
+    pub fn remove(&self, indexes: Range<usize>) {
+        let mut inner = self
+            .inner
+            .write()
+            .expect(zinc_const::panic::SYNCHRONIZATION);
+        for index in indexes {
+            inner.remove(&index);
+        }
+    }
 }
@@ -2,6 +2,9 @@
 //! The lexical token location.
 //!
 
+#[cfg(test)]
+mod tests;
+
 pub mod file_index;
 
 use std::fmt;
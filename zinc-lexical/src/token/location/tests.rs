@@ -0,0 +1,27 @@
+//!
+//! The lexical token location tests.
+//!
+
+use std::path::PathBuf;
+
+use super::file_index::FileIndex;
+
+#[test]
+fn ok_file_index_remove() {
+    let index = FileIndex::new();
+
+    let first = index.next(&PathBuf::from("first.zn"), "first".to_owned());
+    let second = index.next(&PathBuf::from("second.zn"), "second".to_owned());
+    index.remove(first..second);
+
+    let third = index.next(&PathBuf::from("third.zn"), "third".to_owned());
+
+    assert_eq!(third, second + 1);
+    assert_eq!(index.current(), third);
+    assert_eq!(index.get_path(second), PathBuf::from("second.zn"));
+    assert!(!index
+        .inner
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .contains_key(&first));
+}
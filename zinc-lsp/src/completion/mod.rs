@@ -0,0 +1,99 @@
+//!
+//! The language server path completion.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use lsp_types::CompletionItem;
+use lsp_types::CompletionItemKind;
+
+use zinc_compiler::Scope;
+use zinc_compiler::ScopeItem;
+
+///
+/// Extracts the namespace path preceding the cursor, e.g. `["std", "crypto"]` for the line
+/// `let hash = std::crypto::sh`.
+///
+/// Returns `None` if the cursor is not located after a path separator `::`.
+///
+pub fn namespace(line: &str) -> Option<Vec<&str>> {
+    let line = line.trim_end_matches(is_identifier_character);
+    let mut line = line.strip_suffix("::")?;
+
+    let mut names = Vec::new();
+    loop {
+        let name_start = line
+            .rfind(|character: char| !is_identifier_character(character))
+            .map(|index| index + 1)
+            .unwrap_or_default();
+        let name = &line[name_start..];
+        if name.is_empty() || name.starts_with(|character: char| character.is_ascii_digit()) {
+            return None;
+        }
+        names.push(name);
+
+        match line[..name_start].strip_suffix("::") {
+            Some(rest) => line = rest,
+            None => break,
+        }
+    }
+    names.reverse();
+
+    Some(names)
+}
+
+///
+/// Lists the items of the namespace at the path `names`, resolved from the `scope`.
+///
+pub fn items(scope: Rc<RefCell<Scope>>, names: &[&str]) -> Vec<CompletionItem> {
+    let namespace = match Scope::resolve_namespace(scope, names) {
+        Some(namespace) => namespace,
+        None => return vec![],
+    };
+
+    let items = RefCell::borrow(&namespace).items();
+    items
+        .into_iter()
+        .map(|(name, item)| {
+            let item = RefCell::borrow(&item);
+            let description = item.description(name.as_str());
+
+            CompletionItem {
+                kind: Some(kind(&item, description.as_str())),
+                detail: Some(description),
+                label: name,
+                ..Default::default()
+            }
+        })
+        .collect()
+}
+
+///
+/// Chooses the completion item kind, which is usually displayed as an icon.
+///
+fn kind(item: &ScopeItem, description: &str) -> CompletionItemKind {
+    match item {
+        ScopeItem::Variable(_) => CompletionItemKind::VARIABLE,
+        ScopeItem::Field(_) => CompletionItemKind::FIELD,
+        ScopeItem::Constant(_) => CompletionItemKind::CONSTANT,
+        ScopeItem::Variant(_) => CompletionItemKind::ENUM_MEMBER,
+        ScopeItem::Trait(_) => CompletionItemKind::INTERFACE,
+        ScopeItem::Module(_) => CompletionItemKind::MODULE,
+        ScopeItem::Type(_) if description.starts_with("fn ") => CompletionItemKind::FUNCTION,
+        ScopeItem::Type(_) if description.starts_with("const fn ") => CompletionItemKind::FUNCTION,
+        ScopeItem::Type(_) if description.starts_with("enum ") => CompletionItemKind::ENUM,
+        ScopeItem::Type(_) if description.starts_with("contract ") => CompletionItemKind::CLASS,
+        ScopeItem::Type(_) => CompletionItemKind::STRUCT,
+    }
+}
+
+///
+/// Checks whether the character can be a part of an identifier.
+///
+fn is_identifier_character(character: char) -> bool {
+    character.is_ascii_alphanumeric() || character == '_'
+}
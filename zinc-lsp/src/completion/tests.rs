@@ -0,0 +1,34 @@
+//!
+//! The language server path completion tests.
+//!
+
+#[test]
+fn ok_namespace_single() {
+    assert_eq!(super::namespace("    std::"), Some(vec!["std"]));
+}
+
+#[test]
+fn ok_namespace_nested_with_partial_name() {
+    assert_eq!(
+        super::namespace("    let hash = std::crypto::sh"),
+        Some(vec!["std", "crypto"])
+    );
+}
+
+#[test]
+fn ok_namespace_in_call_arguments() {
+    assert_eq!(
+        super::namespace("    call(value, crate::module::"),
+        Some(vec!["crate", "module"])
+    );
+}
+
+#[test]
+fn error_namespace_without_separator() {
+    assert_eq!(super::namespace("    let value = std"), None);
+}
+
+#[test]
+fn error_namespace_empty_name() {
+    assert_eq!(super::namespace("    ::"), None);
+}
@@ -0,0 +1,84 @@
+//!
+//! The language server diagnostic conversion.
+//!
+
+use std::path::Path;
+use std::path::PathBuf;
+
+use lsp_types::DiagnosticRelatedInformation;
+use lsp_types::DiagnosticSeverity;
+use lsp_types::Location;
+use lsp_types::NumberOrString;
+use lsp_types::Range;
+use lsp_types::Url;
+
+use zinc_compiler::Diagnostic;
+use zinc_compiler::DiagnosticLabel;
+use zinc_compiler::DiagnosticLevel;
+
+use crate::position;
+
+///
+/// Converts the compiler diagnostic to the protocol one.
+///
+/// Returns the path of the file with the primary label. If the diagnostic has no labels,
+/// e.g. if the manifest is invalid, it is attributed to the `fallback` file.
+///
+pub fn convert(diagnostic: Diagnostic, fallback: &Path) -> (PathBuf, lsp_types::Diagnostic) {
+    let mut labels = diagnostic.labels.into_iter();
+
+    let (path, range) = match labels.next() {
+        Some(label) => (PathBuf::from(label.file.as_str()), self::range(&label)),
+        None => (fallback.to_owned(), Range::default()),
+    };
+
+    let related_information: Vec<DiagnosticRelatedInformation> = labels
+        .filter_map(|label| {
+            let uri = Url::from_file_path(label.file.as_str()).ok()?;
+
+            Some(DiagnosticRelatedInformation {
+                location: Location::new(uri, self::range(&label)),
+                message: label.message.unwrap_or_default(),
+            })
+        })
+        .collect();
+
+    let message = match diagnostic.help {
+        Some(help) => format!("{}\nhelp: {}", diagnostic.message, help),
+        None => diagnostic.message,
+    };
+
+    let diagnostic = lsp_types::Diagnostic {
+        range,
+        severity: Some(match diagnostic.level {
+            DiagnosticLevel::Error => DiagnosticSeverity::ERROR,
+            DiagnosticLevel::Warning => DiagnosticSeverity::WARNING,
+        }),
+        code: diagnostic
+            .code
+            .map(|code| NumberOrString::String(format!("{:04}", code))),
+        source: Some(zinc_const::app_name::COMPILER.to_owned()),
+        message,
+        related_information: if related_information.is_empty() {
+            None
+        } else {
+            Some(related_information)
+        },
+        ..Default::default()
+    };
+
+    (path, diagnostic)
+}
+
+///
+/// Converts the label span to the protocol range, using the spanned source code lines.
+///
+fn range(label: &DiagnosticLabel) -> Range {
+    let text_start = label.lines.first().map(String::as_str).unwrap_or_default();
+    let text_end = label.lines.last().map(String::as_str).unwrap_or_default();
+
+    Range::new(
+        position::from_line_column(text_start, label.line_start, label.column_start),
+        position::from_line_column(text_end, label.line_end, label.column_end),
+    )
+}
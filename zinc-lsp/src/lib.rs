@@ -0,0 +1,11 @@
+//!
+//! The Zinc language server library.
+//!
+
+pub(crate) mod completion;
+pub(crate) mod diagnostic;
+pub(crate) mod position;
+pub(crate) mod project;
+pub(crate) mod server;
+
+pub use self::server::Server;
@@ -0,0 +1,105 @@
+//!
+//! The language server position conversions.
+//!
+//! The compiler columns are counted in characters, whereas the protocol ones are counted in
+//! UTF-16 code units, so the conversions require the text of the line.
+//!
+
+#[cfg(test)]
+mod tests;
+
+use lsp_types::Position;
+use lsp_types::Range;
+
+use zinc_lexical::Location;
+use zinc_lexical::FILE_INDEX;
+
+///
+/// Converts the compiler location, whose lines and columns start from 1, to the protocol position,
+/// whose lines and characters start from 0.
+///
+/// The `text` is the source code of the location line.
+///
+pub fn from_location(text: &str, location: Location) -> Position {
+    from_line_column(text, location.line, location.column)
+}
+
+///
+/// Converts the compiler line and column, which start from 1, to the protocol position.
+///
+/// The `text` is the source code of the `line`. The columns beyond the line end are counted as
+/// single code units.
+///
+pub fn from_line_column(text: &str, line: usize, column: usize) -> Position {
+    let mut characters = text.chars();
+    let character: usize = (1..column)
+        .map(|_| characters.next().map(char::len_utf16).unwrap_or(1))
+        .sum();
+
+    Position::new(line.saturating_sub(1) as u32, character as u32)
+}
+
+///
+/// Converts the protocol position to the compiler line and column, which start from 1.
+///
+/// The `text` is the source code of the position line.
+///
+pub fn to_line_column(text: &str, position: Position) -> (usize, usize) {
+    let mut code_units = 0;
+    let mut column = 1;
+    for character in text.chars() {
+        if code_units >= position.character as usize {
+            break;
+        }
+        code_units += character.len_utf16();
+        column += 1;
+    }
+    column += (position.character as usize).saturating_sub(code_units);
+
+    (position.line as usize + 1, column)
+}
+
+///
+/// Returns the single-line range of `length` characters starting at `location`.
+///
+/// The `text` is the source code of the location line.
+///
+pub fn range(text: &str, location: Location, length: usize) -> Range {
+    let start = from_location(text, location);
+    let end = from_line_column(text, location.line, location.column + length);
+
+    Range::new(start, end)
+}
+
+///
+/// Returns the byte offset of the protocol `character` in the line `text`.
+///
+/// If the character is located beyond the line end, the line length is returned.
+///
+pub fn byte_offset(text: &str, character: u32) -> usize {
+    let mut code_units = 0;
+    for (offset, character_value) in text.char_indices() {
+        if code_units >= character as usize {
+            return offset;
+        }
+        code_units += character_value.len_utf16();
+    }
+
+    text.len()
+}
+
+///
+/// Returns the source code of the `line` of the indexed `file`.
+///
+/// Returns an empty string if the file or line does not exist.
+///
+pub fn line(file: usize, line: usize) -> String {
+    FILE_INDEX
+        .inner
+        .read()
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .get(&file)
+        .and_then(|data| data.code.lines().nth(line.saturating_sub(1)))
+        .map(|text| text.to_owned())
+        .unwrap_or_default()
+}
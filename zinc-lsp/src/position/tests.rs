@@ -0,0 +1,60 @@
+//!
+//! The language server position conversion tests.
+//!
+
+use lsp_types::Position;
+use lsp_types::Range;
+
+use zinc_lexical::Location;
+
+#[test]
+fn ok_from_line_column_ascii() {
+    assert_eq!(
+        super::from_line_column("let value = 42;", 3, 5),
+        Position::new(2, 4)
+    );
+}
+
+#[test]
+fn ok_from_line_column_non_ascii() {
+    // `é` is one UTF-16 code unit, and `😀` is a surrogate pair
+    assert_eq!(
+        super::from_line_column("dbg!(\"é😀\", value);", 1, 12),
+        Position::new(0, 12)
+    );
+}
+
+#[test]
+fn ok_from_line_column_beyond_line_end() {
+    assert_eq!(super::from_line_column("é", 1, 4), Position::new(0, 3));
+}
+
+#[test]
+fn ok_to_line_column_non_ascii() {
+    assert_eq!(
+        super::to_line_column("dbg!(\"é😀\", value);", Position::new(0, 12)),
+        (1, 12)
+    );
+}
+
+#[test]
+fn ok_range_non_ascii() {
+    assert_eq!(
+        super::range("dbg!(\"é😀\", value);", Location::test(1, 12), 5),
+        Range::new(Position::new(0, 12), Position::new(0, 17))
+    );
+}
+
+#[test]
+fn ok_byte_offset_non_ascii() {
+    let text = "let s = \"😀\"; std::";
+
+    let offset = super::byte_offset(text, 18);
+
+    assert_eq!(&text[..offset], "let s = \"😀\"; std:");
+}
+
+#[test]
+fn ok_byte_offset_beyond_line_end() {
+    assert_eq!(super::byte_offset("é", 5), 2);
+}
@@ -0,0 +1,142 @@
+//!
+//! The language server project.
+//!
+
+use std::collections::HashMap;
+use std::ops::Range;
+use std::path::Path;
+use std::path::PathBuf;
+
+use lsp_types::Position;
+
+use zinc_compiler::Analysis;
+use zinc_compiler::AnalysisReference;
+use zinc_compiler::Bundler;
+use zinc_compiler::Diagnostic;
+use zinc_compiler::DiagnosticLevel;
+use zinc_compiler::OptimizationLevel;
+use zinc_lexical::FILE_INDEX;
+
+use crate::diagnostic;
+use crate::position;
+
+///
+/// The Zinc project, which is analyzed as a whole when any of its files is opened or saved.
+///
+pub struct Project {
+    /// The analysis data, which is absent if the project could not be parsed.
+    pub analysis: Option<Analysis>,
+    /// The protocol diagnostics grouped by file paths.
+    pub diagnostics: HashMap<PathBuf, Vec<lsp_types::Diagnostic>>,
+    /// The file indexes allocated during the analysis, with file paths as keys.
+    files: HashMap<PathBuf, usize>,
+    /// The range of the file indexes allocated during the analysis.
+    file_indexes: Range<usize>,
+}
+
+impl Project {
+    ///
+    /// Finds the project directory, that is, the nearest parent directory with the manifest.
+    ///
+    pub fn find(path: &Path) -> Option<PathBuf> {
+        path.ancestors()
+            .find(|directory| Self::manifest_path(directory).exists())
+            .map(|directory| directory.to_owned())
+    }
+
+    ///
+    /// Analyzes the project at `path` with its dependencies.
+    ///
+    /// The dependencies are expected to be already downloaded by `zargo`.
+    ///
+    pub fn analyze(path: PathBuf) -> Self {
+        log::debug!("Analyzing the project at {:?}", path);
+
+        let first_file_index = FILE_INDEX.peek();
+
+        let mut dependencies_directory_path = path.clone();
+        dependencies_directory_path.push(zinc_const::directory::TARGET_DEPS);
+
+        let result = Bundler::new(
+            path.clone(),
+            dependencies_directory_path,
+            OptimizationLevel::None,
+        )
+        .check();
+        let (analysis, diagnostics) = match result {
+            Ok(mut analysis) => {
+                let mut diagnostics: Vec<Diagnostic> = analysis
+                    .errors
+                    .drain(..)
+                    .map(|error| zinc_compiler::Error::Semantic(error).diagnostic())
+                    .collect();
+                diagnostics.extend(
+                    analysis
+                        .warnings
+                        .drain(..)
+                        .map(|warning| warning.diagnostic(DiagnosticLevel::Warning)),
+                );
+                (Some(analysis), diagnostics)
+            }
+            Err(error) => (None, vec![Diagnostic::from(&error)]),
+        };
+
+        let manifest_path = Self::manifest_path(path.as_path());
+        let mut diagnostics_by_path: HashMap<PathBuf, Vec<lsp_types::Diagnostic>> = HashMap::new();
+        for diagnostic in diagnostics.into_iter() {
+            let (path, diagnostic) = diagnostic::convert(diagnostic, manifest_path.as_path());
+            diagnostics_by_path
+                .entry(path)
+                .or_default()
+                .push(diagnostic);
+        }
+
+        let file_indexes = first_file_index..FILE_INDEX.peek();
+        let files = file_indexes
+            .clone()
+            .map(|index| (FILE_INDEX.get_path(index), index))
+            .collect();
+
+        Self {
+            analysis,
+            diagnostics: diagnostics_by_path,
+            files,
+            file_indexes,
+        }
+    }
+
+    ///
+    /// Removes the project files from the global file index.
+    ///
+    /// Must be called when the project is replaced with a newer analysis, since the files are
+    /// indexed anew on each analysis.
+    ///
+    pub fn release(self) {
+        FILE_INDEX.remove(self.file_indexes);
+    }
+
+    ///
+    /// Finds the reference to an item at the `position` in the file at `path`.
+    ///
+    pub fn reference_at(&self, path: &Path, position: Position) -> Option<&AnalysisReference> {
+        let analysis = self.analysis.as_ref()?;
+        let file = *self.files.get(path)?;
+        let text = position::line(file, position.line as usize + 1);
+        let (line, column) = position::to_line_column(text.as_str(), position);
+
+        analysis.reference_at(file, line, column)
+    }
+
+    ///
+    /// Returns the manifest path in the project `directory`.
+    ///
+    fn manifest_path(directory: &Path) -> PathBuf {
+        let mut path = directory.to_owned();
+        path.push(format!(
+            "{}.{}",
+            zinc_const::file_name::MANIFEST,
+            zinc_const::extension::MANIFEST
+        ));
+        path
+    }
+}
@@ -0,0 +1,315 @@
+//!
+//! The language server.
+//!
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use lsp_server::Connection;
+use lsp_server::ErrorCode;
+use lsp_server::Message;
+use lsp_server::Notification;
+use lsp_server::Request;
+use lsp_server::Response;
+use lsp_types::notification::DidChangeTextDocument;
+use lsp_types::notification::DidCloseTextDocument;
+use lsp_types::notification::DidOpenTextDocument;
+use lsp_types::notification::DidSaveTextDocument;
+use lsp_types::notification::Notification as INotification;
+use lsp_types::notification::PublishDiagnostics;
+use lsp_types::request::Completion;
+use lsp_types::request::GotoDefinition;
+use lsp_types::request::HoverRequest;
+use lsp_types::request::Request as IRequest;
+use lsp_types::CompletionOptions;
+use lsp_types::CompletionParams;
+use lsp_types::CompletionResponse;
+use lsp_types::GotoDefinitionParams;
+use lsp_types::GotoDefinitionResponse;
+use lsp_types::Hover;
+use lsp_types::HoverContents;
+use lsp_types::HoverParams;
+use lsp_types::HoverProviderCapability;
+use lsp_types::Location;
+use lsp_types::MarkupContent;
+use lsp_types::MarkupKind;
+use lsp_types::OneOf;
+use lsp_types::PublishDiagnosticsParams;
+use lsp_types::ServerCapabilities;
+use lsp_types::TextDocumentSyncCapability;
+use lsp_types::TextDocumentSyncKind;
+use lsp_types::TextDocumentSyncOptions;
+use lsp_types::TextDocumentSyncSaveOptions;
+use lsp_types::Url;
+
+use zinc_lexical::FILE_INDEX;
+
+use crate::completion;
+use crate::position;
+use crate::project::Project;
+
+///
+/// The language server, which communicates with the editor via the `connection`.
+///
+/// The projects are analyzed from the file system when their files are opened or saved.
+/// The unsaved document contents are only used for the completion.
+///
+pub struct Server {
+    /// The connection to the editor.
+    connection: Connection,
+    /// The open document contents, with their URIs as keys.
+    documents: HashMap<Url, String>,
+    /// The analyzed projects, with their directories as keys.
+    projects: HashMap<PathBuf, Project>,
+}
+
+impl Server {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(connection: Connection) -> Self {
+        Self {
+            connection,
+            documents: HashMap::new(),
+            projects: HashMap::new(),
+        }
+    }
+
+    ///
+    /// The features provided by the server.
+    ///
+    pub fn capabilities() -> ServerCapabilities {
+        ServerCapabilities {
+            text_document_sync: Some(TextDocumentSyncCapability::Options(
+                TextDocumentSyncOptions {
+                    open_close: Some(true),
+                    change: Some(TextDocumentSyncKind::FULL),
+                    save: Some(TextDocumentSyncSaveOptions::Supported(true)),
+                    ..Default::default()
+                },
+            )),
+            hover_provider: Some(HoverProviderCapability::Simple(true)),
+            definition_provider: Some(OneOf::Left(true)),
+            completion_provider: Some(CompletionOptions {
+                trigger_characters: Some(vec![":".to_owned()]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    ///
+    /// Handles the editor messages until the shutdown request.
+    ///
+    pub fn run(mut self) -> anyhow::Result<()> {
+        let receiver = self.connection.receiver.clone();
+
+        for message in receiver.iter() {
+            match message {
+                Message::Request(request) => {
+                    if self.connection.handle_shutdown(&request)? {
+                        return Ok(());
+                    }
+
+                    let response = self.handle_request(request);
+                    self.connection.sender.send(Message::Response(response))?;
+                }
+                Message::Notification(notification) => {
+                    self.handle_notification(notification)?;
+                }
+                Message::Response(_response) => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Dispatches the editor request to its handler.
+    ///
+    fn handle_request(&mut self, request: Request) -> Response {
+        let id = request.id;
+
+        let result = match request.method.as_str() {
+            GotoDefinition::METHOD => serde_json::from_value(request.params)
+                .map(|params| serde_json::to_value(self.definition(params))),
+            HoverRequest::METHOD => serde_json::from_value(request.params)
+                .map(|params| serde_json::to_value(self.hover(params))),
+            Completion::METHOD => serde_json::from_value(request.params)
+                .map(|params| serde_json::to_value(self.completion(params))),
+            method => {
+                return Response::new_err(
+                    id,
+                    ErrorCode::MethodNotFound as i32,
+                    format!("Method `{}` is not supported", method),
+                );
+            }
+        };
+
+        match result {
+            Ok(Ok(result)) => Response::new_ok(id, result),
+            Ok(Err(error)) | Err(error) => {
+                Response::new_err(id, ErrorCode::InvalidParams as i32, error.to_string())
+            }
+        }
+    }
+
+    ///
+    /// Dispatches the editor notification to its handler.
+    ///
+    fn handle_notification(&mut self, notification: Notification) -> anyhow::Result<()> {
+        match notification.method.as_str() {
+            DidOpenTextDocument::METHOD => {
+                let params: <DidOpenTextDocument as INotification>::Params =
+                    serde_json::from_value(notification.params)?;
+                let uri = params.text_document.uri;
+
+                self.documents
+                    .insert(uri.clone(), params.text_document.text);
+                self.analyze(&uri)?;
+            }
+            DidChangeTextDocument::METHOD => {
+                let params: <DidChangeTextDocument as INotification>::Params =
+                    serde_json::from_value(notification.params)?;
+
+                if let Some(change) = params.content_changes.into_iter().last() {
+                    self.documents.insert(params.text_document.uri, change.text);
+                }
+            }
+            DidSaveTextDocument::METHOD => {
+                let params: <DidSaveTextDocument as INotification>::Params =
+                    serde_json::from_value(notification.params)?;
+
+                self.analyze(&params.text_document.uri)?;
+            }
+            DidCloseTextDocument::METHOD => {
+                let params: <DidCloseTextDocument as INotification>::Params =
+                    serde_json::from_value(notification.params)?;
+
+                self.documents.remove(&params.text_document.uri);
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Analyzes the project the document at `uri` belongs to and publishes the diagnostics.
+    ///
+    /// The files, which had diagnostics after the previous analysis, are cleared, and the
+    /// previous analysis files are removed from the file index.
+    ///
+    fn analyze(&mut self, uri: &Url) -> anyhow::Result<()> {
+        let path = match uri.to_file_path() {
+            Ok(path) => path,
+            Err(()) => return Ok(()),
+        };
+        let project_path = match Project::find(path.as_path()) {
+            Some(project_path) => project_path,
+            None => {
+                log::warn!("The file {:?} does not belong to a Zinc project", path);
+                return Ok(());
+            }
+        };
+
+        let project = Project::analyze(project_path.clone());
+
+        let mut paths: Vec<PathBuf> = project.diagnostics.keys().cloned().collect();
+        if let Some(previous) = self.projects.get(&project_path) {
+            paths.extend(
+                previous
+                    .diagnostics
+                    .keys()
+                    .filter(|path| !project.diagnostics.contains_key(*path))
+                    .cloned(),
+            );
+        }
+
+        for path in paths.into_iter() {
+            let uri = match Url::from_file_path(path.as_path()) {
+                Ok(uri) => uri,
+                Err(()) => continue,
+            };
+            let diagnostics = project.diagnostics.get(&path).cloned().unwrap_or_default();
+
+            self.connection
+                .sender
+                .send(Message::Notification(Notification::new(
+                    PublishDiagnostics::METHOD.to_owned(),
+                    PublishDiagnosticsParams::new(uri, diagnostics, None),
+                )))?;
+        }
+
+        if let Some(previous) = self.projects.insert(project_path, project) {
+            previous.release();
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Finds the declaration of the item referenced at the cursor.
+    ///
+    fn definition(&self, params: GotoDefinitionParams) -> Option<GotoDefinitionResponse> {
+        let document = params.text_document_position_params;
+        let path = document.text_document.uri.to_file_path().ok()?;
+        let project = self.projects.get(&Project::find(path.as_path())?)?;
+
+        let declaration = project
+            .reference_at(path.as_path(), document.position)?
+            .declaration?;
+        let uri = Url::from_file_path(FILE_INDEX.get_path(declaration.file)).ok()?;
+
+        let text = position::line(declaration.file, declaration.line);
+
+        Some(GotoDefinitionResponse::Scalar(Location::new(
+            uri,
+            position::range(text.as_str(), declaration, 0),
+        )))
+    }
+
+    ///
+    /// Describes the item referenced at the cursor, e.g. shows the variable type.
+    ///
+    fn hover(&self, params: HoverParams) -> Option<Hover> {
+        let document = params.text_document_position_params;
+        let path = document.text_document.uri.to_file_path().ok()?;
+        let project = self.projects.get(&Project::find(path.as_path())?)?;
+
+        let reference = project.reference_at(path.as_path(), document.position)?;
+        let text = position::line(reference.location.file, reference.location.line);
+
+        Some(Hover {
+            contents: HoverContents::Markup(MarkupContent {
+                kind: MarkupKind::Markdown,
+                value: format!("```zinc\n{}\n```", reference.description),
+            }),
+            range: Some(position::range(
+                text.as_str(),
+                reference.location,
+                reference.name.chars().count(),
+            )),
+        })
+    }
+
+    ///
+    /// Lists the items of the namespace preceding the cursor, e.g. `std::crypto::`.
+    ///
+    fn completion(&self, params: CompletionParams) -> Option<CompletionResponse> {
+        let document = params.text_document_position;
+        let path = document.text_document.uri.to_file_path().ok()?;
+        let project = self.projects.get(&Project::find(path.as_path())?)?;
+        let scope = project.analysis.as_ref()?.scope.to_owned()?;
+
+        let text = self.documents.get(&document.text_document.uri)?;
+        let line = text.lines().nth(document.position.line as usize)?;
+        let line = &line[..position::byte_offset(line, document.position.character)];
+        let names = completion::namespace(line)?;
+
+        Some(CompletionResponse::Array(completion::items(
+            scope,
+            names.as_slice(),
+        )))
+    }
+}
@@ -0,0 +1,32 @@
+//!
+//! The Zinc language server arguments.
+//!
+
+use structopt::StructOpt;
+
+///
+/// The Zinc language server arguments.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = zinc_const::app_name::LANGUAGE_SERVER,
+    about = "The Zinc language server"
+)]
+pub struct Arguments {
+    /// Prints more logs, if passed several times.
+    #[structopt(short = "v", long = "verbose", parse(from_occurrences))]
+    pub verbosity: usize,
+
+    /// Suppresses output, if set.
+    #[structopt(short = "q", long = "quiet")]
+    pub quiet: bool,
+}
+
+impl Arguments {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new() -> Self {
+        Self::from_args()
+    }
+}
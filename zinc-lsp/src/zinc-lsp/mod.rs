@@ -0,0 +1,58 @@
+//!
+//! The Zinc language server binary.
+//!
+
+pub(crate) mod arguments;
+
+use std::process;
+use std::thread;
+
+use lsp_server::Connection;
+
+use zinc_lsp::Server;
+
+use self::arguments::Arguments;
+
+///
+/// The application entry point.
+///
+fn main() {
+    let args = Arguments::new();
+
+    process::exit(match main_inner(args) {
+        Ok(()) => zinc_const::exit_code::SUCCESS,
+        Err(error) => {
+            log::error!("{:?}", error);
+            zinc_const::exit_code::FAILURE
+        }
+    })
+}
+
+///
+/// The auxiliary `main` function to facilitate the `?` error conversion operator.
+///
+/// The standard output is occupied by the protocol, so the logs are written to the standard error.
+///
+fn main_inner(args: Arguments) -> anyhow::Result<()> {
+    zinc_logger::initialize(
+        zinc_const::app_name::LANGUAGE_SERVER,
+        args.verbosity,
+        args.quiet,
+    );
+
+    let (connection, io_threads) = Connection::stdio();
+    connection.initialize(serde_json::to_value(Server::capabilities())?)?;
+    log::info!("Initialized");
+
+    thread::Builder::new()
+        .stack_size(zinc_const::limit::COMPILER_STACK_SIZE)
+        .spawn(move || Server::new(connection).run())
+        .expect(zinc_const::panic::SYNCHRONIZATION)
+        .join()
+        .expect(zinc_const::panic::SYNCHRONIZATION)?;
+
+    io_threads.join()?;
+    log::info!("Shut down");
+
+    Ok(())
+}
@@ -3,14 +3,19 @@
 //!
 
 pub(crate) mod error;
+pub(crate) mod lockfile;
 pub(crate) mod manifest;
 pub(crate) mod project;
 pub(crate) mod source;
 
 pub use self::error::Error;
+pub use self::lockfile::package::Package as LockfilePackage;
+pub use self::lockfile::Lockfile;
+pub use self::manifest::dependency::Dependency as ManifestDependency;
 pub use self::manifest::Manifest;
 pub use self::manifest::Project as ManifestProject;
 pub use self::project::r#type::Type as ProjectType;
+pub use self::project::storage_hasher::StorageHasher;
 pub use self::project::Project;
 pub use self::source::directory::Directory;
 pub use self::source::file::File;
@@ -0,0 +1,155 @@
+//!
+//! The Zinc project dependency lockfile.
+//!
+
+pub mod package;
+
+use std::convert::TryFrom;
+use std::fs::File;
+use std::io::Read;
+use std::io::Write;
+use std::path::PathBuf;
+
+use anyhow::Context;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::manifest::dependency::Dependency;
+
+use self::package::Package;
+
+///
+/// The Zinc project dependency lockfile representation.
+///
+/// Records the resolved versions and source code checksums of the whole dependency tree, so
+/// the same versions are used by the subsequent builds and the changed sources are detected.
+///
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Lockfile {
+    /// The resolved dependency packages, ordered by their names and versions.
+    #[serde(default, rename = "package")]
+    pub packages: Vec<Package>,
+}
+
+impl Lockfile {
+    /// The comment written at the beginning of the file.
+    const HEADER: &'static str =
+        "# This file is generated by Zargo. It is not intended for manual editing.\n\n";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(mut packages: Vec<Package>) -> Self {
+        packages.sort_by(|package_1, package_2| {
+            package_1
+                .name
+                .cmp(&package_2.name)
+                .then_with(|| package_1.version.cmp(&package_2.version))
+        });
+        packages.dedup();
+
+        Self { packages }
+    }
+
+    ///
+    /// Returns the highest locked Zandbox package named `name`, which satisfies the `dependency`
+    /// requirement.
+    ///
+    pub fn get(&self, name: &str, dependency: &Dependency) -> Option<&Package> {
+        self.packages
+            .iter()
+            .filter(|package| {
+                package.name == name
+                    && package.path.is_none()
+                    && dependency.matches(&package.version)
+            })
+            .max_by(|package_1, package_2| package_1.version.cmp(&package_2.version))
+    }
+
+    ///
+    /// Returns the locked package with the exact `name` and `version`.
+    ///
+    pub fn find(&self, name: &str, version: &semver::Version) -> Option<&Package> {
+        self.packages
+            .iter()
+            .find(|package| package.name == name && &package.version == version)
+    }
+
+    ///
+    /// Checks if the lockfile exists in the project at the given `path`.
+    ///
+    pub fn exists_at(path: &PathBuf) -> bool {
+        let mut path = path.to_owned();
+        if path.is_dir() {
+            path.push(PathBuf::from(Self::file_name()));
+        }
+        path.exists()
+    }
+
+    ///
+    /// Reads the lockfile from the project at the given `path`, if it exists.
+    ///
+    pub fn try_from_project(path: &PathBuf) -> anyhow::Result<Option<Self>> {
+        if Self::exists_at(path) {
+            Self::try_from(path).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    ///
+    /// Writes the lockfile to a file in the project at the given `path`.
+    ///
+    pub fn write_to(&self, path: &PathBuf) -> anyhow::Result<()> {
+        let mut path = path.to_owned();
+        if path.is_dir() || !path.ends_with(Self::file_name()) {
+            path.push(PathBuf::from(Self::file_name()));
+        }
+
+        let mut file = File::create(&path).with_context(|| path.to_string_lossy().to_string())?;
+        file.write_all(Self::HEADER.as_bytes())
+            .with_context(|| path.to_string_lossy().to_string())?;
+        file.write_all(
+            toml::to_string_pretty(self)
+                .expect(zinc_const::panic::DATA_CONVERSION)
+                .as_bytes(),
+        )
+        .with_context(|| path.to_string_lossy().to_string())?;
+
+        Ok(())
+    }
+
+    ///
+    /// Creates a string with the default file name.
+    ///
+    fn file_name() -> String {
+        format!(
+            "{}.{}",
+            zinc_const::file_name::LOCKFILE,
+            zinc_const::extension::LOCKFILE
+        )
+    }
+}
+
+impl TryFrom<&PathBuf> for Lockfile {
+    type Error = anyhow::Error;
+
+    fn try_from(path: &PathBuf) -> Result<Self, Self::Error> {
+        let mut path = path.to_owned();
+        if path.is_dir() {
+            path.push(PathBuf::from(Self::file_name()));
+        }
+
+        let mut file = File::open(&path).with_context(|| path.to_string_lossy().to_string())?;
+        let size = file
+            .metadata()
+            .with_context(|| path.to_string_lossy().to_string())?
+            .len() as usize;
+
+        let mut buffer = String::with_capacity(size);
+        file.read_to_string(&mut buffer)
+            .with_context(|| path.to_string_lossy().to_string())?;
+
+        toml::from_str(buffer.as_str()).with_context(|| path.to_string_lossy().to_string())
+    }
+}
@@ -0,0 +1,56 @@
+//!
+//! The Zinc project lockfile package.
+//!
+
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The resolved dependency package.
+///
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct Package {
+    /// The project name.
+    pub name: String,
+    /// The resolved project version.
+    pub version: semver::Version,
+    /// The local project path relative to the main project root. Only for path dependencies.
+    pub path: Option<PathBuf>,
+    /// The SHA-256 hash of the project source code.
+    pub checksum: String,
+    /// The package dependencies in the `<name> <version>` format.
+    #[serde(default)]
+    pub dependencies: Vec<String>,
+}
+
+impl Package {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        name: String,
+        version: semver::Version,
+        path: Option<PathBuf>,
+        checksum: String,
+        mut dependencies: Vec<String>,
+    ) -> Self {
+        dependencies.sort();
+
+        Self {
+            name,
+            version,
+            path,
+            checksum,
+            dependencies,
+        }
+    }
+
+    ///
+    /// Returns the package identifier used in the dependency lists.
+    ///
+    pub fn id(&self) -> String {
+        format!("{} {}", self.name, self.version)
+    }
+}
@@ -0,0 +1,59 @@
+//!
+//! The Zinc project manifest dependency.
+//!
+
+use std::path::PathBuf;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The `dependencies` section entry representation.
+///
+/// The short form is a version requirement string, e.g. `'^1.2'`, and the detailed form is a
+/// table, e.g. `{ path = '../lib' }`, which allows to use a local project as a dependency.
+///
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum Dependency {
+    /// The version requirement of a project uploaded to the Zandbox server.
+    Version(semver::VersionReq),
+    /// The detailed dependency description.
+    Detailed {
+        /// The version requirement. Any version is accepted, if not specified.
+        version: Option<semver::VersionReq>,
+        /// The local project path relative to the dependent project root.
+        path: Option<PathBuf>,
+    },
+}
+
+impl Dependency {
+    ///
+    /// Returns the version requirement, if specified.
+    ///
+    pub fn version(&self) -> Option<&semver::VersionReq> {
+        match self {
+            Self::Version(version) => Some(version),
+            Self::Detailed { version, .. } => version.as_ref(),
+        }
+    }
+
+    ///
+    /// Returns the local project path, if specified.
+    ///
+    pub fn path(&self) -> Option<&PathBuf> {
+        match self {
+            Self::Version(_) => None,
+            Self::Detailed { path, .. } => path.as_ref(),
+        }
+    }
+
+    ///
+    /// Checks if the `version` satisfies the dependency version requirement.
+    ///
+    pub fn matches(&self, version: &semver::Version) -> bool {
+        self.version()
+            .map(|requirement| requirement.matches(version))
+            .unwrap_or(true)
+    }
+}
@@ -2,6 +2,8 @@
 //! The Zinc project manifest file.
 //!
 
+pub mod dependency;
+
 use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::fs::File;
@@ -14,6 +16,9 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use crate::project::r#type::Type as ProjectType;
+use crate::project::storage_hasher::StorageHasher;
+
+use self::dependency::Dependency;
 
 ///
 /// The Zinc project manifest file representation.
@@ -23,7 +28,7 @@ pub struct Manifest {
     /// The `project` section.
     pub project: Project,
     /// The `dependencies` section.
-    pub dependencies: Option<HashMap<String, semver::Version>>,
+    pub dependencies: Option<HashMap<String, Dependency>>,
 }
 
 ///
@@ -37,6 +42,8 @@ pub struct Project {
     pub r#type: ProjectType,
     /// The project version in the string format.
     pub version: semver::Version,
+    /// The contract storage Merkle tree hasher. SHA-256 is used if not specified.
+    pub storage_hasher: Option<StorageHasher>,
 }
 
 impl Project {
@@ -48,6 +55,7 @@ impl Project {
             name,
             r#type,
             version,
+            storage_hasher: None,
         }
     }
 }
@@ -62,6 +70,7 @@ impl Manifest {
                 name: project_name.to_owned(),
                 r#type: project_type,
                 version: semver::Version::new(0, 1, 0),
+                storage_hasher: None,
             },
             dependencies: Some(HashMap::new()),
         }
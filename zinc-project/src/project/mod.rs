@@ -2,6 +2,7 @@
 //! The Zinc project representation.
 //!
 
+pub mod storage_hasher;
 pub mod r#type;
 
 use serde::Deserialize;
@@ -0,0 +1,44 @@
+//!
+//! The Zinc project contract storage hasher.
+//!
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+///
+/// The hash function used to build the contract storage Merkle tree.
+///
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub enum StorageHasher {
+    /// The SHA-256 hash function. Cheap to compute natively, but expensive in the circuit.
+    #[serde(rename = "sha256")]
+    #[default]
+    Sha256,
+    /// The SNARK-friendly Rescue hash function over the BN256 scalar field.
+    #[serde(rename = "rescue")]
+    Rescue,
+}
+
+impl FromStr for StorageHasher {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "sha256" => Ok(Self::Sha256),
+            "rescue" => Ok(Self::Rescue),
+            another => Err(another.to_owned()),
+        }
+    }
+}
+
+impl fmt::Display for StorageHasher {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Sha256 => write!(f, "sha256"),
+            Self::Rescue => write!(f, "rescue"),
+        }
+    }
+}
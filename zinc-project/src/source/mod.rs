@@ -11,6 +11,8 @@ use std::path::PathBuf;
 use anyhow::Context;
 use serde::Deserialize;
 use serde::Serialize;
+use sha2::Digest;
+use sha2::Sha256;
 
 use crate::error::Error;
 
@@ -61,6 +63,37 @@ impl Source {
         }
     }
 
+    ///
+    /// Computes the SHA-256 hash of the source code, which does not depend on the file system
+    /// order of the directory entries.
+    ///
+    pub fn checksum(&self) -> String {
+        let mut hasher = Sha256::new();
+        self.hash_into(&mut hasher);
+        hex::encode(hasher.finalize())
+    }
+
+    ///
+    /// Writes the file paths and contents into the `hasher` in the file path order.
+    ///
+    fn hash_into(&self, hasher: &mut Sha256) {
+        match self {
+            Self::File(inner) => {
+                hasher.update(inner.path.as_bytes());
+                hasher.update([0]);
+                hasher.update(inner.code.as_bytes());
+                hasher.update([0]);
+            }
+            Self::Directory(inner) => {
+                let mut modules: Vec<&Source> = inner.modules.values().collect();
+                modules.sort_by(|module_1, module_2| module_1.name().cmp(module_2.name()));
+                for module in modules.into_iter() {
+                    module.hash_into(hasher);
+                }
+            }
+        }
+    }
+
     ///
     /// Gets the file or directory name.
     ///
@@ -0,0 +1,55 @@
+//!
+//! The source code formatter comment.
+//!
+
+use zinc_lexical::Comment as LexicalComment;
+use zinc_lexical::Location;
+
+///
+/// The comment, which is put back into the formatted code next to the code it was written at.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comment {
+    /// The location of the comment start.
+    pub location: Location,
+    /// The comment text including the `//` or `/* */` delimiters.
+    pub text: String,
+    /// Whether the comment follows some code on the same line.
+    pub is_trailing: bool,
+    /// Whether the comment is separated from the preceding code with an empty line.
+    pub is_blank_line_before: bool,
+    /// Whether the comment is a block one followed by some code on the same line.
+    pub is_inline: bool,
+}
+
+impl Comment {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        location: Location,
+        comment: LexicalComment,
+        is_trailing: bool,
+        is_blank_line_before: bool,
+    ) -> Self {
+        let text = match comment {
+            LexicalComment::Line { inner } => format!("//{}", inner.trim_end()),
+            LexicalComment::Block { inner } => format!("/*{}*/", inner.replace('\r', "")),
+        };
+
+        Self {
+            location,
+            text,
+            is_trailing,
+            is_blank_line_before,
+            is_inline: false,
+        }
+    }
+
+    ///
+    /// Whether the comment starts before the `location`.
+    ///
+    pub fn is_before(&self, location: Location) -> bool {
+        (self.location.line, self.location.column) < (location.line, location.column)
+    }
+}
@@ -0,0 +1,107 @@
+//!
+//! The source code formatter error.
+//!
+
+use std::fmt;
+
+use zinc_lexical::Location;
+
+use crate::error::Error as SyntaxError;
+use crate::error::ParsingError;
+
+///
+/// The source code formatter error.
+///
+#[derive(Debug, PartialEq)]
+pub enum Error {
+    /// The source code cannot be parsed, so there is no syntax tree to format.
+    Parsing(ParsingError),
+    /// The formatted code would not preserve a token of the original code, e.g. an attribute,
+    /// which is not kept in the syntax tree. The file is left as it is.
+    TokenLost {
+        /// The token location in the original code.
+        location: Location,
+        /// The token text.
+        token: String,
+    },
+    /// The formatted code cannot be parsed. The file is left as it is.
+    OutputInvalid(ParsingError),
+}
+
+impl Error {
+    ///
+    /// Returns the location of the syntax error.
+    ///
+    fn parsing_error_location(error: &ParsingError) -> Location {
+        match error {
+            ParsingError::Lexical(zinc_lexical::Error::UnterminatedBlockComment {
+                start, ..
+            }) => *start,
+            ParsingError::Lexical(zinc_lexical::Error::UnterminatedDoubleQuoteString {
+                start,
+                ..
+            }) => *start,
+            ParsingError::Lexical(zinc_lexical::Error::ExpectedOneOfBinary {
+                location, ..
+            })
+            | ParsingError::Lexical(zinc_lexical::Error::ExpectedOneOfOctal { location, .. })
+            | ParsingError::Lexical(zinc_lexical::Error::ExpectedOneOfDecimal {
+                location, ..
+            })
+            | ParsingError::Lexical(zinc_lexical::Error::ExpectedOneOfHexadecimal {
+                location,
+                ..
+            })
+            | ParsingError::Lexical(zinc_lexical::Error::ExpectedAsciiCharacter {
+                location, ..
+            })
+            | ParsingError::Lexical(zinc_lexical::Error::UnknownCharacterEscape {
+                location, ..
+            })
+            | ParsingError::Lexical(zinc_lexical::Error::UnpairedHexadecimalDigit { location })
+            | ParsingError::Lexical(zinc_lexical::Error::InvalidCharacter { location, .. })
+            | ParsingError::Lexical(zinc_lexical::Error::UnexpectedEnd { location })
+            | ParsingError::Syntax(SyntaxError::ExpectedOneOf { location, .. })
+            | ParsingError::Syntax(SyntaxError::ExpectedOneOfOrOperator { location, .. })
+            | ParsingError::Syntax(SyntaxError::ExpectedIdentifier { location, .. })
+            | ParsingError::Syntax(SyntaxError::ExpectedMutOrIdentifier { location, .. })
+            | ParsingError::Syntax(SyntaxError::ExpectedFieldIdentifier { location, .. })
+            | ParsingError::Syntax(SyntaxError::ExpectedType { location, .. })
+            | ParsingError::Syntax(SyntaxError::ExpectedExpressionOrOperand { location, .. })
+            | ParsingError::Syntax(SyntaxError::ExpectedTypeOrValue { location, .. })
+            | ParsingError::Syntax(SyntaxError::ExpectedValue { location, .. })
+            | ParsingError::Syntax(SyntaxError::ExpectedLiteral { location, .. })
+            | ParsingError::Syntax(SyntaxError::ExpectedIntegerLiteral { location, .. })
+            | ParsingError::Syntax(SyntaxError::ExpectedBindingPattern { location, .. })
+            | ParsingError::Syntax(SyntaxError::ExpectedMatchPattern { location, .. }) => *location,
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Parsing(error) => {
+                let location = Self::parsing_error_location(error);
+                write!(
+                    f,
+                    "syntax error at {}:{}, the file cannot be formatted",
+                    location.line, location.column
+                )
+            }
+            Self::TokenLost { location, token } => write!(
+                f,
+                "`{}` at {}:{} would be lost, the file cannot be formatted",
+                token, location.line, location.column
+            ),
+            Self::OutputInvalid(error) => {
+                let location = Self::parsing_error_location(error);
+                write!(
+                    f,
+                    "the formatted code is invalid at {}:{}, the file is left unchanged",
+                    location.line, location.column
+                )
+            }
+        }
+    }
+}
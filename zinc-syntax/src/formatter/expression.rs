@@ -0,0 +1,783 @@
+//!
+//! The source code formatter expressions.
+//!
+
+use zinc_lexical::Location;
+
+use crate::formatter::Formatter;
+use crate::tree::attribute::element::variant::Variant as AttributeElementVariant;
+use crate::tree::attribute::element::Element as AttributeElement;
+use crate::tree::binding::Binding;
+use crate::tree::expression::array::variant::Variant as ArrayExpressionVariant;
+use crate::tree::expression::conditional::Expression as ConditionalExpression;
+use crate::tree::expression::r#match::Expression as MatchExpression;
+use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+use crate::tree::expression::tree::node::operator::Operator as ExpressionOperator;
+use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
+use crate::tree::expression::tree::Tree as ExpressionTree;
+use crate::tree::generic_parameter::GenericParameter;
+use crate::tree::identifier::Identifier;
+use crate::tree::literal::integer::Literal as IntegerLiteral;
+use crate::tree::literal::Literal;
+use crate::tree::pattern_binding::variant::Variant as BindingPatternVariant;
+use crate::tree::pattern_binding::Pattern as BindingPattern;
+use crate::tree::pattern_match::variant::Variant as MatchPatternVariant;
+use crate::tree::pattern_match::Pattern as MatchPattern;
+use crate::tree::r#type::variant::Variant as TypeVariant;
+use crate::tree::r#type::Type;
+
+impl Formatter {
+    ///
+    /// Formats an expression.
+    ///
+    pub(crate) fn expression(&mut self, tree: &ExpressionTree) {
+        match *tree.value {
+            ExpressionTreeNode::Operand(ref operand) => {
+                self.touch(tree.location);
+                self.operand(operand);
+            }
+            ExpressionTreeNode::Operator(operator) => self.operator(tree, operator),
+        }
+    }
+
+    ///
+    /// Returns the location of the leftmost token of the expression.
+    ///
+    pub(crate) fn expression_start(tree: &ExpressionTree) -> Location {
+        match (tree.value.as_ref(), tree.left.as_ref()) {
+            (ExpressionTreeNode::Operator(operator), Some(left)) if !Self::is_prefix(*operator) => {
+                Self::expression_start(left)
+            }
+            _ => tree.location,
+        }
+    }
+
+    ///
+    /// Checks whether the operator is a prefix unary one.
+    ///
+    pub(crate) fn is_prefix(operator: ExpressionOperator) -> bool {
+        matches!(
+            operator,
+            ExpressionOperator::Not
+                | ExpressionOperator::BitwiseNot
+                | ExpressionOperator::Negation
+                | ExpressionOperator::Reference
+        )
+    }
+
+    ///
+    /// Formats an identifier.
+    ///
+    pub(crate) fn identifier(&mut self, identifier: &Identifier) {
+        self.touch(identifier.location);
+        self.write(identifier.name.as_str());
+    }
+
+    ///
+    /// Formats an integer literal in its original spelling.
+    ///
+    pub(crate) fn integer_literal(&mut self, literal: &IntegerLiteral) {
+        self.touch(literal.location);
+        let text = match self.source.literal(literal.location) {
+            Some(text) => text.to_owned(),
+            None => literal.inner.to_string(),
+        };
+        self.write(text.as_str());
+    }
+
+    ///
+    /// Formats a literal in its original spelling.
+    ///
+    pub(crate) fn literal(&mut self, literal: &Literal) {
+        match literal {
+            Literal::Boolean(inner) => {
+                self.touch(inner.location);
+                self.write(inner.inner.to_string().as_str());
+            }
+            Literal::Integer(inner) => self.integer_literal(inner),
+            Literal::String(inner) => {
+                self.touch(inner.location);
+                let text = match self.source.literal(inner.location) {
+                    Some(text) => text.to_owned(),
+                    None => format!("\"{}\"", inner.inner),
+                };
+                self.write(text.as_str());
+            }
+            Literal::Bytes(inner) => {
+                self.touch(inner.location);
+                let text = match self.source.literal(inner.location) {
+                    Some(text) => text.to_owned(),
+                    None => inner.inner.to_string(),
+                };
+                self.write(text.as_str());
+            }
+        }
+    }
+
+    ///
+    /// Formats a type.
+    ///
+    pub(crate) fn r#type(&mut self, r#type: &Type) {
+        self.touch(r#type.location);
+        match r#type.variant {
+            TypeVariant::Unit => self.write("()"),
+            TypeVariant::Boolean => self.write("bool"),
+            TypeVariant::IntegerUnsigned { bitlength } => {
+                self.write(format!("u{}", bitlength).as_str())
+            }
+            TypeVariant::IntegerSigned { bitlength } => {
+                self.write(format!("i{}", bitlength).as_str())
+            }
+            TypeVariant::Field => self.write("field"),
+            TypeVariant::Array {
+                ref inner,
+                ref size,
+            } => {
+                self.write("[");
+                self.r#type(inner);
+                self.write("; ");
+                self.expression(size);
+                self.write("]");
+            }
+            TypeVariant::Tuple { ref inners } => {
+                self.write("(");
+                for (index, inner) in inners.iter().enumerate() {
+                    if index > 0 {
+                        self.write(", ");
+                    }
+                    self.r#type(inner);
+                }
+                if inners.len() == 1 {
+                    self.write(",");
+                }
+                self.write(")");
+            }
+            TypeVariant::Alias {
+                ref path,
+                ref generics,
+            } => {
+                self.expression(path);
+                if let Some(generics) = generics {
+                    self.generics(generics.as_slice());
+                }
+            }
+            TypeVariant::MutableReference { ref inner } => {
+                self.write("&mut ");
+                self.r#type(inner);
+            }
+        }
+    }
+
+    ///
+    /// Formats a generic argument list `<u8, 4>`.
+    ///
+    pub(crate) fn generics(&mut self, generics: &[Type]) {
+        self.write("<");
+        for (index, generic) in generics.iter().enumerate() {
+            if index > 0 {
+                self.write(", ");
+            }
+            self.r#type(generic);
+        }
+        self.write(">");
+    }
+
+    ///
+    /// Formats the generic parameters, if there are any.
+    ///
+    pub(crate) fn generic_parameters(&mut self, parameters: &[GenericParameter]) {
+        if parameters.is_empty() {
+            return;
+        }
+
+        self.write("<");
+        for (index, parameter) in parameters.iter().enumerate() {
+            if index > 0 {
+                self.write(", ");
+            }
+            self.touch(parameter.location);
+            if let Some(ref r#type) = parameter.r#type {
+                self.write("const ");
+                self.identifier(&parameter.identifier);
+                self.write(": ");
+                self.r#type(r#type);
+            } else {
+                self.identifier(&parameter.identifier);
+            }
+        }
+        self.write(">");
+    }
+
+    ///
+    /// Formats the function argument bindings in parentheses.
+    ///
+    pub(crate) fn bindings(&mut self, bindings: &[Binding]) {
+        self.write("(");
+        for (index, binding) in bindings.iter().enumerate() {
+            if index > 0 {
+                self.write(", ");
+            }
+            self.binding(binding);
+        }
+        self.write(")");
+    }
+
+    ///
+    /// Formats a binding with its optional type.
+    ///
+    pub(crate) fn binding(&mut self, binding: &Binding) {
+        if let (
+            BindingPatternVariant::Binding { ref identifier, .. },
+            Some(Type {
+                variant: TypeVariant::MutableReference { .. },
+                ..
+            }),
+        ) = (&binding.pattern.variant, &binding.r#type)
+        {
+            if identifier.is_self_lowercase() {
+                self.touch(binding.location);
+                self.write("&mut ");
+                self.identifier(identifier);
+                return;
+            }
+        }
+
+        self.binding_pattern(&binding.pattern);
+        if let Some(ref r#type) = binding.r#type {
+            self.write(": ");
+            self.r#type(r#type);
+        }
+    }
+
+    ///
+    /// Formats the attribute elements separated with commas.
+    ///
+    pub(crate) fn attribute_elements(&mut self, elements: &[AttributeElement]) {
+        for (index, element) in elements.iter().enumerate() {
+            if index > 0 {
+                self.write(", ");
+            }
+            self.expression(&element.path);
+            match element.variant {
+                Some(AttributeElementVariant::Value(ref literal)) => {
+                    self.write(" = ");
+                    self.literal(literal);
+                }
+                Some(AttributeElementVariant::Nested(ref elements)) => {
+                    self.write("(");
+                    self.attribute_elements(elements.as_slice());
+                    self.write(")");
+                }
+                None => {}
+            }
+        }
+    }
+
+    ///
+    /// Formats an operator expression, putting its operands into parentheses if the operator
+    /// precedence requires that.
+    ///
+    fn operator(&mut self, tree: &ExpressionTree, operator: ExpressionOperator) {
+        let symbol = match operator {
+            ExpressionOperator::Not => Some("!"),
+            ExpressionOperator::BitwiseNot => Some("~"),
+            ExpressionOperator::Negation => Some("-"),
+            ExpressionOperator::Reference => Some("&mut "),
+            _ => None,
+        };
+        if let Some(symbol) = symbol {
+            self.touch(tree.location);
+            self.write(symbol);
+            if let Some(ref operand) = tree.left {
+                self.operand_expression(operand, operator, false);
+            }
+            return;
+        }
+
+        if let Some(ref left) = tree.left {
+            self.operand_expression(left, operator, false);
+        }
+        self.touch(tree.location);
+        let separator = match operator {
+            ExpressionOperator::Index => {
+                self.write("[");
+                if let Some(ref right) = tree.right {
+                    self.expression(right);
+                }
+                self.write("]");
+                return;
+            }
+            ExpressionOperator::CallIntrinsic => "!",
+            ExpressionOperator::Call => "",
+            ExpressionOperator::Dot => ".",
+            ExpressionOperator::Path => "::",
+            ExpressionOperator::Structure => " ",
+            ExpressionOperator::Casting => " as ",
+            ExpressionOperator::Range => "..",
+            ExpressionOperator::RangeInclusive => "..=",
+            operator => Self::binary_symbol(operator),
+        };
+        self.write(separator);
+        if let Some(ref right) = tree.right {
+            self.operand_expression(right, operator, true);
+        }
+    }
+
+    ///
+    /// Formats an operand of the `parent` operator, which is put into parentheses if its
+    /// precedence is lower than the parent one.
+    ///
+    fn operand_expression(
+        &mut self,
+        tree: &ExpressionTree,
+        parent: ExpressionOperator,
+        is_right: bool,
+    ) {
+        let is_parenthesized = match *tree.value {
+            ExpressionTreeNode::Operand(_) => false,
+            ExpressionTreeNode::Operator(ExpressionOperator::Dot)
+                if parent == ExpressionOperator::Dot
+                    && Self::is_tuple_index(tree.right.as_deref()) =>
+            {
+                // `tuple.0.1` would be read as `tuple` and the `0.1` decimal literal
+                true
+            }
+            ExpressionTreeNode::Operator(operator) => {
+                let precedence = Self::precedence(operator);
+                let parent_precedence = Self::precedence(parent);
+                precedence < parent_precedence
+                    || (precedence == parent_precedence
+                        && (is_right || !Self::is_left_associative(parent)))
+            }
+        };
+
+        if is_parenthesized {
+            self.write("(");
+            self.expression(tree);
+            self.write(")");
+        } else {
+            self.expression(tree);
+        }
+    }
+
+    ///
+    /// Formats an operand.
+    ///
+    fn operand(&mut self, operand: &ExpressionOperand) {
+        match operand {
+            ExpressionOperand::LiteralUnit(_) => self.write("()"),
+            ExpressionOperand::LiteralBoolean(inner) => {
+                self.literal(&Literal::Boolean(inner.to_owned()))
+            }
+            ExpressionOperand::LiteralInteger(inner) => self.integer_literal(inner),
+            ExpressionOperand::LiteralString(inner) => {
+                self.literal(&Literal::String(inner.to_owned()))
+            }
+            ExpressionOperand::LiteralBytes(inner) => {
+                self.literal(&Literal::Bytes(inner.to_owned()))
+            }
+            ExpressionOperand::TupleIndex(inner) => self.integer_literal(&inner.literal),
+            ExpressionOperand::Identifier(inner) => self.identifier(inner),
+            ExpressionOperand::Type(inner) => self.r#type(inner),
+            ExpressionOperand::GenericPath(inner) => {
+                self.touch(inner.location);
+                if let TypeVariant::Alias {
+                    ref path,
+                    generics: Some(ref generics),
+                } = inner.variant
+                {
+                    self.expression(path);
+                    self.write("::");
+                    self.generics(generics.as_slice());
+                }
+            }
+            ExpressionOperand::Array(inner) => match inner.variant {
+                ArrayExpressionVariant::List { ref elements } => {
+                    self.expression_list(inner.location, elements.as_slice(), ("[", "]"), false)
+                }
+                ArrayExpressionVariant::Repeated {
+                    ref expression,
+                    ref size_expression,
+                } => {
+                    self.write("[");
+                    self.expression(expression);
+                    self.write("; ");
+                    self.expression(size_expression);
+                    self.write("]");
+                }
+            },
+            ExpressionOperand::Tuple(inner) => {
+                self.expression_list(inner.location, inner.elements.as_slice(), ("(", ")"), true)
+            }
+            ExpressionOperand::Structure(inner) => {
+                if inner.fields.is_empty() {
+                    self.write("{}");
+                    return;
+                }
+
+                self.write("{ ");
+                for (index, (identifier, expression)) in inner.fields.iter().enumerate() {
+                    if index > 0 {
+                        self.write(", ");
+                    }
+                    self.identifier(identifier);
+                    self.write(": ");
+                    self.expression(expression);
+                }
+                self.write(" }");
+            }
+            ExpressionOperand::List(inner) => {
+                self.expression_list(inner.location, inner.elements.as_slice(), ("(", ")"), false)
+            }
+            ExpressionOperand::Block(inner) => self.block(inner),
+            ExpressionOperand::Conditional(inner) => self.conditional(inner),
+            ExpressionOperand::Match(inner) => self.r#match(inner),
+        }
+    }
+
+    ///
+    /// Formats the expressions separated with commas in the `brackets`, where the opening one
+    /// is located at `location`.
+    ///
+    /// If there are line comments between the brackets, each expression is written on a
+    /// separate line, so the comments stay next to the expressions they were written at.
+    /// The `is_tuple` flag tells whether a single expression must be followed by a comma.
+    ///
+    fn expression_list(
+        &mut self,
+        location: Location,
+        expressions: &[ExpressionTree],
+        brackets: (&str, &str),
+        is_tuple: bool,
+    ) {
+        let (opening, closing) = brackets;
+        let closing_location = self.source.closing_parenthesis(location);
+        let has_comments = match closing_location {
+            Some(closing_location) => self
+                .source
+                .comments
+                .iter()
+                .filter(|comment| !comment.is_before(location))
+                .take_while(|comment| comment.is_before(closing_location))
+                .any(|comment| !comment.is_inline),
+            None => false,
+        };
+
+        self.write(opening);
+        if has_comments && !expressions.is_empty() {
+            self.newline();
+            self.indent += 1;
+            self.is_block_start = true;
+            for expression in expressions.iter() {
+                self.begin_line(Self::expression_start(expression));
+                self.expression(expression);
+                self.write(",");
+            }
+            self.newline();
+            if let Some(closing_location) = closing_location {
+                self.comments_before(closing_location);
+                self.newline();
+                self.touch(closing_location);
+            }
+            self.indent -= 1;
+            self.is_block_start = false;
+        } else {
+            for (index, expression) in expressions.iter().enumerate() {
+                if index > 0 {
+                    self.write(", ");
+                }
+                self.expression(expression);
+            }
+            if is_tuple && expressions.len() == 1 {
+                self.write(",");
+            }
+        }
+        self.write(closing);
+    }
+
+    ///
+    /// Formats a conditional expression.
+    ///
+    /// The `else if` chains are written as such, whereas an `else` block containing a single
+    /// conditional expression is left as it is.
+    ///
+    fn conditional(&mut self, conditional: &ConditionalExpression) {
+        self.touch(conditional.location);
+        self.write("if ");
+        if let Some(ref pattern) = conditional.pattern {
+            self.write("let ");
+            self.match_pattern(pattern);
+            self.write(" = ");
+        }
+        self.expression(&conditional.condition);
+        self.write(" ");
+        self.block(&conditional.main_block);
+
+        if let Some(ref block) = conditional.else_block {
+            self.write(" else ");
+            if let (true, Some(expression)) = (block.statements.is_empty(), &block.expression) {
+                if let ExpressionTreeNode::Operand(ExpressionOperand::Conditional(ref inner)) =
+                    *expression.value
+                {
+                    if inner.location == block.location {
+                        self.conditional(inner);
+                        return;
+                    }
+                }
+            }
+            self.block(block);
+        }
+    }
+
+    ///
+    /// Formats a match expression with each branch on a separate line.
+    ///
+    fn r#match(&mut self, expression: &MatchExpression) {
+        self.touch(expression.location);
+        self.write("match ");
+        self.expression(&expression.scrutinee);
+        self.write(" ");
+
+        let closing = self.closing_bracket();
+        self.open_block();
+        for (pattern, guard, branch) in expression.branches.iter() {
+            self.begin_line(pattern.location);
+            self.match_pattern(pattern);
+            if let Some(guard) = guard {
+                self.write(" if ");
+                self.expression(guard);
+            }
+            self.write(" => ");
+            self.expression(branch);
+            self.write(",");
+        }
+        self.close_block(closing);
+    }
+
+    ///
+    /// Formats a binding pattern.
+    ///
+    pub(crate) fn binding_pattern(&mut self, pattern: &BindingPattern) {
+        self.touch(pattern.location);
+        match pattern.variant {
+            BindingPatternVariant::Binding {
+                ref identifier,
+                is_mutable,
+            } => {
+                if is_mutable {
+                    self.write("mut ");
+                }
+                self.identifier(identifier);
+            }
+            BindingPatternVariant::BindingList { ref bindings } => {
+                self.write("(");
+                for (index, binding) in bindings.iter().enumerate() {
+                    if index > 0 {
+                        self.write(", ");
+                    }
+                    self.binding_pattern(binding);
+                }
+                self.write(")");
+            }
+            BindingPatternVariant::Wildcard => self.write("_"),
+        }
+    }
+
+    ///
+    /// Formats a match pattern.
+    ///
+    fn match_pattern(&mut self, pattern: &MatchPattern) {
+        self.touch(pattern.location);
+        match pattern.variant {
+            MatchPatternVariant::BooleanLiteral(ref literal) => {
+                self.literal(&Literal::Boolean(literal.to_owned()))
+            }
+            MatchPatternVariant::IntegerLiteral(ref literal) => self.integer_literal(literal),
+            MatchPatternVariant::Range {
+                ref start,
+                ref end,
+                is_inclusive,
+            } => {
+                self.integer_literal(start);
+                self.write(if is_inclusive { "..=" } else { ".." });
+                self.integer_literal(end);
+            }
+            MatchPatternVariant::Binding(ref identifier) => self.identifier(identifier),
+            MatchPatternVariant::Path(ref path) => self.expression(path),
+            MatchPatternVariant::Wildcard => self.write("_"),
+            MatchPatternVariant::Tuple(ref elements) => {
+                self.write("(");
+                self.match_patterns(elements.as_slice());
+                if elements.len() == 1 {
+                    self.write(",");
+                }
+                self.write(")");
+            }
+            MatchPatternVariant::TupleVariant {
+                ref path,
+                ref elements,
+            } => {
+                self.expression(path);
+                self.write("(");
+                self.match_patterns(elements.as_slice());
+                self.write(")");
+            }
+            MatchPatternVariant::Structure {
+                ref path,
+                ref fields,
+                is_rest_ignored,
+            } => {
+                self.expression(path);
+                if fields.is_empty() && !is_rest_ignored {
+                    self.write(" {}");
+                    return;
+                }
+
+                self.write(" { ");
+                for (index, (identifier, pattern)) in fields.iter().enumerate() {
+                    if index > 0 {
+                        self.write(", ");
+                    }
+                    self.identifier(identifier);
+                    if pattern.location != identifier.location {
+                        self.write(": ");
+                        self.match_pattern(pattern);
+                    }
+                }
+                if is_rest_ignored {
+                    if !fields.is_empty() {
+                        self.write(", ");
+                    }
+                    self.write("..");
+                }
+                self.write(" }");
+            }
+            MatchPatternVariant::Alternatives(ref alternatives) => {
+                for (index, alternative) in alternatives.iter().enumerate() {
+                    if index > 0 {
+                        self.write(" | ");
+                    }
+                    self.match_pattern(alternative);
+                }
+            }
+        }
+    }
+
+    ///
+    /// Formats the match patterns separated with commas.
+    ///
+    fn match_patterns(&mut self, patterns: &[MatchPattern]) {
+        for (index, pattern) in patterns.iter().enumerate() {
+            if index > 0 {
+                self.write(", ");
+            }
+            self.match_pattern(pattern);
+        }
+    }
+
+    ///
+    /// Checks whether the expression is a tuple field index.
+    ///
+    fn is_tuple_index(tree: Option<&ExpressionTree>) -> bool {
+        matches!(
+            tree.map(|tree| tree.value.as_ref()),
+            Some(ExpressionTreeNode::Operand(ExpressionOperand::TupleIndex(
+                _
+            )))
+        )
+    }
+
+    ///
+    /// The operator precedence, where the greater value binds tighter.
+    ///
+    fn precedence(operator: ExpressionOperator) -> usize {
+        match operator {
+            ExpressionOperator::Assignment
+            | ExpressionOperator::AssignmentBitwiseOr
+            | ExpressionOperator::AssignmentBitwiseXor
+            | ExpressionOperator::AssignmentBitwiseAnd
+            | ExpressionOperator::AssignmentBitwiseShiftLeft
+            | ExpressionOperator::AssignmentBitwiseShiftRight
+            | ExpressionOperator::AssignmentAddition
+            | ExpressionOperator::AssignmentSubtraction
+            | ExpressionOperator::AssignmentMultiplication
+            | ExpressionOperator::AssignmentDivision
+            | ExpressionOperator::AssignmentRemainder => 1,
+            ExpressionOperator::Range | ExpressionOperator::RangeInclusive => 2,
+            ExpressionOperator::Or => 3,
+            ExpressionOperator::Xor => 4,
+            ExpressionOperator::And => 5,
+            ExpressionOperator::Equals
+            | ExpressionOperator::NotEquals
+            | ExpressionOperator::GreaterEquals
+            | ExpressionOperator::LesserEquals
+            | ExpressionOperator::Greater
+            | ExpressionOperator::Lesser => 6,
+            ExpressionOperator::BitwiseOr => 7,
+            ExpressionOperator::BitwiseXor => 8,
+            ExpressionOperator::BitwiseAnd => 9,
+            ExpressionOperator::BitwiseShiftLeft | ExpressionOperator::BitwiseShiftRight => 10,
+            ExpressionOperator::Addition | ExpressionOperator::Subtraction => 11,
+            ExpressionOperator::Multiplication
+            | ExpressionOperator::Division
+            | ExpressionOperator::Remainder => 12,
+            ExpressionOperator::Casting => 13,
+            ExpressionOperator::Not
+            | ExpressionOperator::BitwiseNot
+            | ExpressionOperator::Negation
+            | ExpressionOperator::Reference => 14,
+            ExpressionOperator::Index
+            | ExpressionOperator::Dot
+            | ExpressionOperator::CallIntrinsic
+            | ExpressionOperator::Call => 15,
+            ExpressionOperator::Structure => 16,
+            ExpressionOperator::Path => 17,
+        }
+    }
+
+    ///
+    /// Checks whether the operator can be chained without parentheses, grouping from the left.
+    ///
+    fn is_left_associative(operator: ExpressionOperator) -> bool {
+        !matches!(Self::precedence(operator), 1 | 2 | 6)
+    }
+
+    ///
+    /// The binary operator symbol surrounded with spaces.
+    ///
+    fn binary_symbol(operator: ExpressionOperator) -> &'static str {
+        match operator {
+            ExpressionOperator::Assignment => " = ",
+            ExpressionOperator::AssignmentBitwiseOr => " |= ",
+            ExpressionOperator::AssignmentBitwiseXor => " ^= ",
+            ExpressionOperator::AssignmentBitwiseAnd => " &= ",
+            ExpressionOperator::AssignmentBitwiseShiftLeft => " <<= ",
+            ExpressionOperator::AssignmentBitwiseShiftRight => " >>= ",
+            ExpressionOperator::AssignmentAddition => " += ",
+            ExpressionOperator::AssignmentSubtraction => " -= ",
+            ExpressionOperator::AssignmentMultiplication => " *= ",
+            ExpressionOperator::AssignmentDivision => " /= ",
+            ExpressionOperator::AssignmentRemainder => " %= ",
+            ExpressionOperator::Or => " || ",
+            ExpressionOperator::Xor => " ^^ ",
+            ExpressionOperator::And => " && ",
+            ExpressionOperator::Equals => " == ",
+            ExpressionOperator::NotEquals => " != ",
+            ExpressionOperator::GreaterEquals => " >= ",
+            ExpressionOperator::LesserEquals => " <= ",
+            ExpressionOperator::Greater => " > ",
+            ExpressionOperator::Lesser => " < ",
+            ExpressionOperator::BitwiseOr => " | ",
+            ExpressionOperator::BitwiseXor => " ^ ",
+            ExpressionOperator::BitwiseAnd => " & ",
+            ExpressionOperator::BitwiseShiftLeft => " << ",
+            ExpressionOperator::BitwiseShiftRight => " >> ",
+            ExpressionOperator::Addition => " + ",
+            ExpressionOperator::Subtraction => " - ",
+            ExpressionOperator::Multiplication => " * ",
+            ExpressionOperator::Division => " / ",
+            ExpressionOperator::Remainder => " % ",
+            _ => " ",
+        }
+    }
+}
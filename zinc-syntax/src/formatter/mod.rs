@@ -0,0 +1,650 @@
+//!
+//! The source code formatter.
+//!
+
+pub mod comment;
+pub mod error;
+pub mod expression;
+pub mod source;
+pub mod statement;
+
+use zinc_lexical::Location;
+
+use crate::parser::Parser;
+
+use self::error::Error;
+use self::source::Source;
+
+///
+/// The source code formatter.
+///
+/// Pretty-prints the syntax tree in the canonical style. The comments and empty lines, which
+/// are dropped by the syntax analyzer, are collected from the token stream and put back before
+/// the code they precede or at the end of the line they trail.
+///
+pub struct Formatter {
+    /// The source code data missing in the syntax tree.
+    source: Source,
+    /// The formatted code.
+    output: String,
+    /// The line being written.
+    line: String,
+    /// The current indentation level.
+    indent: usize,
+    /// The furthest original code position written so far.
+    position: (usize, usize),
+    /// Whether nothing has been written since a block was opened.
+    is_block_start: bool,
+}
+
+impl Formatter {
+    /// The indentation unit.
+    const INDENT: &'static str = "    ";
+
+    ///
+    /// Formats the source code `input` of the file with index `file`.
+    ///
+    /// The keywords, identifiers, and literals of the formatted code are checked to match the
+    /// original ones, so the code is never changed beyond its layout and punctuation.
+    ///
+    pub fn format(input: &str, file: usize) -> Result<String, Error> {
+        let source = Source::new(input, file).map_err(Error::Parsing)?;
+        let module = Parser::default()
+            .parse(input, file)
+            .map_err(Error::Parsing)?;
+
+        let expected = source.words.clone();
+        let mut formatter = Self::new(source);
+        formatter.module(&module);
+        let output = formatter.finish();
+
+        let found = Source::new(output.as_str(), file)
+            .map_err(Error::OutputInvalid)?
+            .words;
+        for (index, (location, token)) in expected.iter().enumerate() {
+            match found.get(index) {
+                Some((_, found)) if found == token => continue,
+                _ => {
+                    return Err(Error::TokenLost {
+                        location: *location,
+                        token: token.to_owned(),
+                    })
+                }
+            }
+        }
+        if let Some((location, token)) = found.get(expected.len()) {
+            return Err(Error::TokenLost {
+                location: *location,
+                token: token.to_owned(),
+            });
+        }
+
+        Parser::default()
+            .parse(output.as_str(), file)
+            .map_err(Error::OutputInvalid)?;
+
+        Ok(output)
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    fn new(source: Source) -> Self {
+        Self {
+            source,
+            output: String::with_capacity(4096),
+            line: String::with_capacity(128),
+            indent: 0,
+            position: (0, 0),
+            is_block_start: true,
+        }
+    }
+
+    ///
+    /// Writes the remaining comments and returns the formatted code.
+    ///
+    fn finish(mut self) -> String {
+        self.newline();
+        while let Some(comment) = self.source.comments.pop_front() {
+            if comment.is_blank_line_before {
+                self.blank_line();
+            }
+            self.write(comment.text.as_str());
+            self.newline();
+        }
+
+        self.output
+    }
+
+    ///
+    /// Appends `text` to the current line.
+    ///
+    fn write(&mut self, text: &str) {
+        match text.strip_prefix(' ') {
+            Some(text) if self.line.ends_with(' ') => self.line.push_str(text),
+            _ => self.line.push_str(text),
+        }
+        self.is_block_start = false;
+    }
+
+    ///
+    /// Writes a block comment followed by some code on the same line.
+    ///
+    fn inline_comment(&mut self, text: &str) {
+        if !self.line.is_empty() && !self.line.ends_with(&[' ', '(', '['][..]) {
+            self.write(" ");
+        }
+        self.write(text);
+        self.write(" ");
+    }
+
+    ///
+    /// Marks the original code at `location` as written, writing the inline comments
+    /// preceding it.
+    ///
+    fn touch(&mut self, location: Location) {
+        while let Some(comment) = self.source.comments.front() {
+            if !comment.is_inline || !comment.is_before(location) {
+                break;
+            }
+
+            if let Some(comment) = self.source.comments.pop_front() {
+                self.inline_comment(comment.text.as_str());
+            }
+        }
+
+        let position = (location.line, location.column);
+        if position > self.position {
+            self.position = position;
+        }
+    }
+
+    ///
+    /// Finishes the current line, appending the comments trailing the code written so far.
+    ///
+    fn newline(&mut self) {
+        while let Some(comment) = self.source.comments.front() {
+            let is_written_over = comment.is_before(self.position());
+            let is_trailing = comment.is_trailing
+                && !comment.is_inline
+                && comment.location.line <= self.position.0
+                && !self
+                    .source
+                    .is_code_between(self.position(), comment.location);
+            if !is_written_over && !is_trailing {
+                break;
+            }
+
+            if let Some(comment) = self.source.comments.pop_front() {
+                if !self.line.is_empty() {
+                    self.line.push(' ');
+                }
+                self.line.push_str(comment.text.as_str());
+            }
+        }
+
+        if self.line.is_empty() {
+            return;
+        }
+
+        for _ in 0..self.indent {
+            self.output.push_str(Self::INDENT);
+        }
+        self.output.push_str(self.line.trim_end());
+        self.output.push('\n');
+        self.line.clear();
+    }
+
+    ///
+    /// Writes an empty line, unless it is the beginning of a block or there is one already.
+    ///
+    fn blank_line(&mut self) {
+        if self.is_block_start || self.output.is_empty() || self.output.ends_with("\n\n") {
+            return;
+        }
+
+        self.output.push('\n');
+    }
+
+    ///
+    /// Writes the comments located before `location` on separate lines.
+    ///
+    fn comments_before(&mut self, location: Location) {
+        while let Some(comment) = self.source.comments.front() {
+            if !comment.is_before(location) {
+                break;
+            }
+
+            if let Some(comment) = self.source.comments.pop_front() {
+                if comment.is_blank_line_before {
+                    self.blank_line();
+                }
+                if comment.is_inline {
+                    self.inline_comment(comment.text.as_str());
+                } else {
+                    self.write(comment.text.as_str());
+                    self.newline();
+                }
+            }
+        }
+    }
+
+    ///
+    /// Prepares a new line for an item starting at `location`, writing the comments and the
+    /// empty line preceding it.
+    ///
+    fn begin_line(&mut self, location: Location) {
+        self.newline();
+        self.comments_before(location);
+        if self.source.is_blank_line_before(location) {
+            self.blank_line();
+        }
+    }
+
+    ///
+    /// Opens a block with the curly bracket, which is written at the end of the current line.
+    ///
+    fn open_block(&mut self) {
+        self.write("{");
+        self.newline();
+        self.indent += 1;
+        self.is_block_start = true;
+    }
+
+    ///
+    /// Closes the block, whose closing curly bracket is located at `closing` in the original
+    /// code, writing the comments at the end of the block.
+    ///
+    fn close_block(&mut self, closing: Option<Location>) {
+        self.newline();
+        if let Some(closing) = closing {
+            self.comments_before(closing);
+            self.newline();
+        }
+        self.indent -= 1;
+        self.is_block_start = false;
+        self.write("}");
+        if let Some(closing) = closing {
+            self.touch(closing);
+        }
+    }
+
+    ///
+    /// Checks whether there are comments before the `closing` curly bracket, which is used to
+    /// decide whether an empty block can be written as `{}`.
+    ///
+    fn has_comments_before(&self, closing: Option<Location>) -> bool {
+        match (self.source.comments.front(), closing) {
+            (Some(comment), Some(closing)) => comment.is_before(closing),
+            _ => false,
+        }
+    }
+
+    ///
+    /// Returns the original location of the bracket closing the block, which is opened after
+    /// the code written so far.
+    ///
+    fn closing_bracket(&self) -> Option<Location> {
+        self.source
+            .closing_bracket(self.position().shifted_right(1))
+    }
+
+    ///
+    /// Returns the furthest original code position written so far.
+    ///
+    fn position(&self) -> Location {
+        Location {
+            line: self.position.0,
+            column: self.position.1,
+            file: 0,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinc_lexical::Location;
+
+    use super::Error;
+    use super::Formatter;
+
+    #[test]
+    fn ok_layout() {
+        let input = r#"fn main(a:u8,b :u8)->u8{let c=a+b;c}"#;
+
+        let expected = Ok(r#"fn main(a: u8, b: u8) -> u8 {
+    let c = a + b;
+    c
+}
+"#
+        .to_owned());
+
+        let result = Formatter::format(input, 0);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_comments_preserved() {
+        let input = r#"//! The module comment.
+
+/// The constant.
+const A: u8 = 0x2A; // the answer
+
+
+fn main() {
+    /* nothing */
+}
+"#;
+
+        let expected = Ok(r#"//! The module comment.
+
+/// The constant.
+const A: u8 = 0x2A; // the answer
+
+fn main() {
+    /* nothing */
+}
+"#
+        .to_owned());
+
+        let result = Formatter::format(input, 0);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_parentheses() {
+        let input = r#"fn main() -> u8 { (1 + 2) * 3 - ((4 - 5) - (6 - 7)) }"#;
+
+        let expected = Ok(r#"fn main() -> u8 {
+    (1 + 2) * 3 - (4 - 5 - (6 - 7))
+}
+"#
+        .to_owned());
+
+        let result = Formatter::format(input, 0);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_conditional_else_if() {
+        let input =
+            r#"fn main(a: u8) -> u8 { if a == 1 { 10 } else if a == 2 { 20 } else { 30 } }"#;
+
+        let expected = Ok(r#"fn main(a: u8) -> u8 {
+    if a == 1 {
+        10
+    } else if a == 2 {
+        20
+    } else {
+        30
+    }
+}
+"#
+        .to_owned());
+
+        let result = Formatter::format(input, 0);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_mutable_references() {
+        let input = r#"impl Data { fn set(&mut self,a:&mut [u8;2]) { a[0]=1; } }
+fn main() { let mut a = [0; 2]; Data::set(&mut  a[0..2]); }"#;
+
+        let expected = Ok(r#"impl Data {
+    fn set(&mut self, a: &mut [u8; 2]) {
+        a[0] = 1;
+    }
+}
+fn main() {
+    let mut a = [0; 2];
+    Data::set(&mut a[0..2]);
+}
+"#
+        .to_owned());
+
+        let result = Formatter::format(input, 0);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_for_patterns() {
+        let input = r#"fn main(a:[u8;2],b:[u8;2]) { for ( i,x ) in a.enumerate() {} for (x,y) in zip(a,b) {} }"#;
+
+        let expected = Ok(r#"fn main(a: [u8; 2], b: [u8; 2]) {
+    for (i, x) in a.enumerate() {}
+    for (x, y) in zip(a, b) {}
+}
+"#
+        .to_owned());
+
+        let result = Formatter::format(input, 0);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_match_alternatives_and_guards() {
+        let input = r#"fn main(a:u8)->u8 { if let 0..=9|42=a { 1 } else { match a { 10..20 =>2, x if x>100=>3, _=>4, } } }"#;
+
+        let expected = Ok(r#"fn main(a: u8) -> u8 {
+    if let 0..=9 | 42 = a {
+        1
+    } else {
+        match a {
+            10..20 => 2,
+            x if x > 100 => 3,
+            _ => 4,
+        }
+    }
+}
+"#
+        .to_owned());
+
+        let result = Formatter::format(input, 0);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_bytes_literals() {
+        let input = r##"const A:[u8;6]=b"zinc\"1";const B:[u8;4]=hex"DEAD_beef";"##;
+
+        let expected = Ok(r##"const A: [u8; 6] = b"zinc\"1";
+const B: [u8; 4] = hex"DEAD_beef";
+"##
+        .to_owned());
+
+        let result = Formatter::format(input, 0);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_generic_paths() {
+        let input =
+            r#"fn main() -> u8 { let pair = Pair::<u8> { a: 1, b: 2 }; convert::<u8, 4>(pair.a) }"#;
+
+        let expected = Ok(r#"fn main() -> u8 {
+    let pair = Pair::<u8> { a: 1, b: 2 };
+    convert::<u8, 4>(pair.a)
+}
+"#
+        .to_owned());
+
+        let result = Formatter::format(input, 0);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_generic_implementations() {
+        let input = r#"impl<T>Pair<T,u8> { fn first(self)->T { self.first } } impl Pair<bool> {}"#;
+
+        let expected = Ok(r#"impl<T> Pair<T, u8> {
+    fn first(self) -> T {
+        self.first
+    }
+}
+impl Pair<bool> {}
+"#
+        .to_owned());
+
+        let result = Formatter::format(input, 0);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_comment_inline_field() {
+        let input = r#"struct Data { /* inline */ a: u8, b: u8 }"#;
+
+        let expected = r#"struct Data {
+    /* inline */ a: u8,
+    b: u8,
+}
+"#;
+
+        let result = Formatter::format(input, 0);
+        assert_eq!(result, Ok(expected.to_owned()));
+
+        let result = Formatter::format(expected, 0);
+        assert_eq!(result, Ok(expected.to_owned()));
+    }
+
+    #[test]
+    fn ok_comment_trailing_conditional() {
+        let input = r#"fn main() {
+    let y = if true { 1 } else { 2 }; // trailing
+}
+"#;
+
+        let expected = r#"fn main() {
+    let y = if true {
+        1
+    } else {
+        2
+    }; // trailing
+}
+"#;
+
+        let result = Formatter::format(input, 0);
+        assert_eq!(result, Ok(expected.to_owned()));
+
+        let result = Formatter::format(expected, 0);
+        assert_eq!(result, Ok(expected.to_owned()));
+    }
+
+    #[test]
+    fn ok_comment_array_elements() {
+        let input = r#"fn main() {
+    let a = [
+        1, // one
+        2, // two
+    ];
+}
+"#;
+
+        let expected = r#"fn main() {
+    let a = [
+        1, // one
+        2, // two
+    ];
+}
+"#;
+
+        let result = Formatter::format(input, 0);
+        assert_eq!(result, Ok(expected.to_owned()));
+
+        let result = Formatter::format(expected, 0);
+        assert_eq!(result, Ok(expected.to_owned()));
+    }
+
+    #[test]
+    fn ok_comment_inline_argument() {
+        let input = r#"fn main() {
+    foo(a,/* arg */b);
+}
+"#;
+
+        let expected = r#"fn main() {
+    foo(a, /* arg */ b);
+}
+"#;
+
+        let result = Formatter::format(input, 0);
+        assert_eq!(result, Ok(expected.to_owned()));
+
+        let result = Formatter::format(expected, 0);
+        assert_eq!(result, Ok(expected.to_owned()));
+    }
+
+    #[test]
+    fn ok_match_statement_terminated() {
+        let input = r#"fn main(a: u8) {
+    match a { _ => {}, };
+    let b = 1;
+}
+"#;
+
+        let expected = r#"fn main(a: u8) {
+    match a {
+        _ => {},
+    };
+    let b = 1;
+}
+"#;
+
+        let result = Formatter::format(input, 0);
+        assert_eq!(result, Ok(expected.to_owned()));
+
+        let result = Formatter::format(expected, 0);
+        assert_eq!(result, Ok(expected.to_owned()));
+    }
+
+    #[test]
+    fn ok_idempotent() {
+        let input = r#"
+contract Test {
+    pub value: u64;
+
+    pub fn get(self) -> u64 { self.value }
+}
+"#;
+
+        let formatted = Formatter::format(input, 0).expect("Formatting failed");
+        let result = Formatter::format(formatted.as_str(), 0);
+
+        assert_eq!(result, Ok(formatted));
+    }
+
+    #[test]
+    fn error_token_lost() {
+        let input = r#"#[test]
+struct Data { a: u8 }
+"#;
+
+        let expected = Err(Error::TokenLost {
+            location: Location::test(1, 3),
+            token: "test".to_owned(),
+        });
+
+        let result = Formatter::format(input, 0);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_parsing() {
+        let input = r#"fn main( {}"#;
+
+        let result = Formatter::format(input, 0);
+
+        assert!(matches!(result, Err(Error::Parsing(_))));
+    }
+}
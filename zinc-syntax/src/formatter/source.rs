@@ -0,0 +1,252 @@
+//!
+//! The source code formatter token data.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+use zinc_lexical::Keyword;
+use zinc_lexical::Lexeme;
+use zinc_lexical::Location;
+use zinc_lexical::Symbol;
+use zinc_lexical::Token;
+use zinc_lexical::TokenStream;
+
+use crate::error::ParsingError;
+use crate::formatter::comment::Comment;
+
+///
+/// The source code data, which is dropped by the syntax analyzer, but must be preserved by the
+/// formatter, like comments, empty lines and the original spelling of literals.
+///
+#[derive(Debug, Default)]
+pub struct Source {
+    /// The comments in the order of appearance.
+    pub comments: VecDeque<Comment>,
+    /// The keywords, identifiers, and literals in the order of appearance.
+    pub words: Vec<(Location, String)>,
+    /// The literal source code text by its location.
+    literals: HashMap<(usize, usize), String>,
+    /// The locations of tokens, which are separated from the preceding code with an empty line.
+    blank_lines: HashSet<(usize, usize)>,
+    /// The `pub` keyword locations by the location of the token following them.
+    public_keywords: HashMap<(usize, usize), Location>,
+    /// The closing curly bracket locations by the location of their opening counterparts.
+    brackets: BTreeMap<(usize, usize), Location>,
+    /// The closing parenthesis and square bracket locations by the location of their opening
+    /// counterparts.
+    parentheses: HashMap<(usize, usize), Location>,
+    /// The closing curly bracket locations, which are followed by a semicolon.
+    terminated_brackets: HashSet<(usize, usize)>,
+    /// The locations of keywords, identifiers, literals, and closing curly brackets, which a
+    /// trailing comment must not be moved over.
+    code: BTreeSet<(usize, usize)>,
+}
+
+impl Source {
+    ///
+    /// Reads the tokens of the `input` with the comments preserved.
+    ///
+    pub fn new(input: &str, file: usize) -> Result<Self, ParsingError> {
+        let characters: Vec<char> = input.chars().collect();
+        let mut stream = TokenStream::new_preserving_comments(input, file);
+
+        let mut source = Self::default();
+        let mut opening_brackets = Vec::new();
+        let mut opening_parentheses = Vec::new();
+        let mut previous: Option<Token> = None;
+        let mut is_previous_trailing = false;
+        let mut start = 0;
+
+        loop {
+            let token = stream.next()?;
+            let end = stream.offset().min(characters.len());
+
+            let text: String = characters[start.min(end)..end].iter().collect();
+            let whitespace_lines = text
+                .chars()
+                .take_while(|character| character.is_ascii_whitespace())
+                .filter(|character| *character == '\n')
+                .count();
+            let text = text.trim().to_owned();
+            start = end;
+
+            let is_after_line_comment = matches!(
+                previous,
+                Some(Token {
+                    lexeme: Lexeme::Comment(zinc_lexical::Comment::Line { .. }),
+                    ..
+                })
+            );
+            let is_blank_line_before = previous.is_some()
+                && (whitespace_lines >= 2 || (is_after_line_comment && whitespace_lines >= 1));
+
+            let key = (token.location.line, token.location.column);
+            match token.lexeme {
+                Lexeme::Eof => break,
+                Lexeme::Comment(ref comment) => {
+                    let is_trailing = whitespace_lines == 0
+                        && !is_after_line_comment
+                        && match previous {
+                            Some(Token {
+                                lexeme: Lexeme::Comment(_),
+                                ..
+                            }) => is_previous_trailing,
+                            Some(_) => true,
+                            None => false,
+                        };
+                    is_previous_trailing = is_trailing;
+
+                    source.comments.push_back(Comment::new(
+                        token.location,
+                        comment.to_owned(),
+                        is_trailing,
+                        is_blank_line_before,
+                    ));
+                }
+                ref lexeme => {
+                    if is_blank_line_before {
+                        source.blank_lines.insert(key);
+                    }
+                    if let (
+                        0,
+                        Some(Token {
+                            lexeme: Lexeme::Comment(zinc_lexical::Comment::Block { .. }),
+                            ..
+                        }),
+                    ) = (whitespace_lines, previous.as_ref())
+                    {
+                        if let Some(comment) = source.comments.back_mut() {
+                            comment.is_inline = true;
+                        }
+                    }
+
+                    match lexeme {
+                        Lexeme::Keyword(_) | Lexeme::Identifier(_) => {
+                            source.words.push((token.location, text));
+                            source.code.insert(key);
+                        }
+                        Lexeme::Literal(_) => {
+                            source.words.push((token.location, text.clone()));
+                            source.literals.insert(key, text);
+                            source.code.insert(key);
+                        }
+                        Lexeme::Symbol(Symbol::BracketCurlyLeft) => {
+                            opening_brackets.push(key);
+                        }
+                        Lexeme::Symbol(Symbol::BracketCurlyRight) => {
+                            if let Some(opening) = opening_brackets.pop() {
+                                source.brackets.insert(opening, token.location);
+                            }
+                            source.code.insert(key);
+                        }
+                        Lexeme::Symbol(Symbol::ParenthesisLeft)
+                        | Lexeme::Symbol(Symbol::BracketSquareLeft) => {
+                            opening_parentheses.push(key);
+                        }
+                        Lexeme::Symbol(Symbol::ParenthesisRight)
+                        | Lexeme::Symbol(Symbol::BracketSquareRight) => {
+                            if let Some(opening) = opening_parentheses.pop() {
+                                source.parentheses.insert(opening, token.location);
+                            }
+                        }
+                        Lexeme::Symbol(Symbol::Semicolon) => {
+                            if let Some(Token {
+                                lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                                location,
+                            }) = previous
+                            {
+                                source
+                                    .terminated_brackets
+                                    .insert((location.line, location.column));
+                            }
+                        }
+                        _ => {}
+                    }
+
+                    if let Some(Token {
+                        lexeme: Lexeme::Keyword(Keyword::Pub),
+                        location,
+                    }) = previous
+                    {
+                        source.public_keywords.insert(key, location);
+                    }
+                }
+            }
+
+            previous = Some(token);
+        }
+
+        Ok(source)
+    }
+
+    ///
+    /// Returns the original spelling of the literal at `location`.
+    ///
+    pub fn literal(&self, location: Location) -> Option<&str> {
+        self.literals
+            .get(&(location.line, location.column))
+            .map(String::as_str)
+    }
+
+    ///
+    /// Checks whether the token at `location` is separated from the preceding code with an
+    /// empty line.
+    ///
+    pub fn is_blank_line_before(&self, location: Location) -> bool {
+        self.blank_lines.contains(&(location.line, location.column))
+    }
+
+    ///
+    /// Returns the location of the `pub` keyword, if the token at `location` is preceded by one.
+    ///
+    pub fn public_keyword(&self, location: Location) -> Option<Location> {
+        self.public_keywords
+            .get(&(location.line, location.column))
+            .copied()
+    }
+
+    ///
+    /// Returns the location of the parenthesis or square bracket closing the one at `location`.
+    ///
+    pub fn closing_parenthesis(&self, location: Location) -> Option<Location> {
+        self.parentheses
+            .get(&(location.line, location.column))
+            .copied()
+    }
+
+    ///
+    /// Checks whether the closing curly bracket at `location` is followed by a semicolon.
+    ///
+    pub fn is_terminated(&self, location: Location) -> bool {
+        self.terminated_brackets
+            .contains(&(location.line, location.column))
+    }
+
+    ///
+    /// Checks whether there is some code strictly between the `start` and `end` locations.
+    ///
+    pub fn is_code_between(&self, start: Location, end: Location) -> bool {
+        let start = (start.line, start.column);
+        let end = (end.line, end.column);
+        start < end
+            && self
+                .code
+                .range(start..end)
+                .any(|location| *location != start)
+    }
+
+    ///
+    /// Returns the location of the curly bracket closing the first block, which is opened at
+    /// or after `location`.
+    ///
+    pub fn closing_bracket(&self, location: Location) -> Option<Location> {
+        self.brackets
+            .range((location.line, location.column)..)
+            .next()
+            .map(|(_, closing)| *closing)
+    }
+}
@@ -0,0 +1,561 @@
+//!
+//! The source code formatter statements.
+//!
+
+use zinc_lexical::Location;
+
+use crate::formatter::Formatter;
+use crate::tree::attribute::Attribute;
+use crate::tree::expression::block::Expression as BlockExpression;
+use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
+use crate::tree::expression::tree::Tree as ExpressionTree;
+use crate::tree::field::Field;
+use crate::tree::module::Module;
+use crate::tree::statement::contract::Statement as ContractStatement;
+use crate::tree::statement::field::Statement as FieldStatement;
+use crate::tree::statement::local_contract::Statement as ContractLocalStatement;
+use crate::tree::statement::local_fn::Statement as FunctionLocalStatement;
+use crate::tree::statement::local_impl::Statement as ImplementationLocalStatement;
+use crate::tree::statement::local_mod::Statement as ModuleLocalStatement;
+use crate::tree::statement::local_trait::Statement as TraitLocalStatement;
+use crate::tree::statement::module::Statement as ModStatement;
+use crate::tree::statement::r#const::Statement as ConstStatement;
+use crate::tree::statement::r#enum::Statement as EnumStatement;
+use crate::tree::statement::r#fn::Statement as FnStatement;
+use crate::tree::statement::r#for::Statement as ForStatement;
+use crate::tree::statement::r#impl::Statement as ImplStatement;
+use crate::tree::statement::r#let::Statement as LetStatement;
+use crate::tree::statement::r#return::Statement as ReturnStatement;
+use crate::tree::statement::r#struct::Statement as StructStatement;
+use crate::tree::statement::r#trait::method::Method as TraitMethod;
+use crate::tree::statement::r#trait::Statement as TraitStatement;
+use crate::tree::statement::r#type::Statement as TypeStatement;
+use crate::tree::statement::r#use::Statement as UseStatement;
+use crate::tree::variant::payload::Payload as VariantPayload;
+use crate::tree::variant::Variant;
+
+impl Formatter {
+    ///
+    /// Formats the module statements.
+    ///
+    pub(crate) fn module(&mut self, module: &Module) {
+        for statement in module.statements.iter() {
+            self.module_local_statement(statement);
+        }
+    }
+
+    ///
+    /// Formats a module-level statement.
+    ///
+    fn module_local_statement(&mut self, statement: &ModuleLocalStatement) {
+        match statement {
+            ModuleLocalStatement::Const(inner) => self.const_statement(inner),
+            ModuleLocalStatement::Type(inner) => self.type_statement(inner),
+            ModuleLocalStatement::Struct(inner) => self.struct_statement(inner),
+            ModuleLocalStatement::Enum(inner) => self.enum_statement(inner),
+            ModuleLocalStatement::Fn(inner) => self.fn_statement(inner),
+            ModuleLocalStatement::Mod(inner) => self.mod_statement(inner),
+            ModuleLocalStatement::Use(inner) => self.use_statement(inner),
+            ModuleLocalStatement::Trait(inner) => self.trait_statement(inner),
+            ModuleLocalStatement::Impl(inner) => self.impl_statement(inner),
+            ModuleLocalStatement::Contract(inner) => self.contract_statement(inner),
+            ModuleLocalStatement::Empty(_) => {}
+        }
+    }
+
+    ///
+    /// Formats a contract-level statement.
+    ///
+    fn contract_local_statement(&mut self, statement: &ContractLocalStatement) {
+        match statement {
+            ContractLocalStatement::Field(inner) => self.field_statement(inner),
+            ContractLocalStatement::Const(inner) => self.const_statement(inner),
+            ContractLocalStatement::Fn(inner) => self.fn_statement(inner),
+            ContractLocalStatement::Empty(_) => {}
+        }
+    }
+
+    ///
+    /// Formats an implementation-level statement.
+    ///
+    fn implementation_local_statement(&mut self, statement: &ImplementationLocalStatement) {
+        match statement {
+            ImplementationLocalStatement::Const(inner) => self.const_statement(inner),
+            ImplementationLocalStatement::Fn(inner) => self.fn_statement(inner),
+            ImplementationLocalStatement::Empty(_) => {}
+        }
+    }
+
+    ///
+    /// Formats a function-level statement.
+    ///
+    /// The `is_terminated` flag tells whether an expression statement must be followed by a
+    /// semicolon even if it is a block, conditional, or match expression.
+    ///
+    fn function_local_statement(
+        &mut self,
+        statement: &FunctionLocalStatement,
+        is_terminated: bool,
+    ) {
+        match statement {
+            FunctionLocalStatement::Let(inner) => self.let_statement(inner),
+            FunctionLocalStatement::Const(inner) => self.const_statement(inner),
+            FunctionLocalStatement::For(inner) => self.for_statement(inner),
+            FunctionLocalStatement::Return(inner) => self.return_statement(inner),
+            FunctionLocalStatement::Break(inner) => {
+                self.begin_statement(inner.location);
+                self.write("break;");
+            }
+            FunctionLocalStatement::Continue(inner) => {
+                self.begin_statement(inner.location);
+                self.write("continue;");
+            }
+            FunctionLocalStatement::Expression(inner) => {
+                self.begin_line(Self::expression_start(inner));
+                self.expression(inner);
+                if is_terminated
+                    || !inner.can_be_unterminated()
+                    || self.source.is_terminated(self.position())
+                {
+                    self.write(";");
+                }
+            }
+            FunctionLocalStatement::Empty(_) => {}
+        }
+    }
+
+    ///
+    /// Formats the block statements and the result expression.
+    ///
+    pub(crate) fn block(&mut self, block: &BlockExpression) {
+        let closing = self.source.closing_bracket(block.location);
+        self.touch(block.location);
+
+        let statements: Vec<&FunctionLocalStatement> = block
+            .statements
+            .iter()
+            .filter(|statement| !matches!(statement, FunctionLocalStatement::Empty(_)))
+            .collect();
+
+        if statements.is_empty() && block.expression.is_none() && !self.has_comments_before(closing)
+        {
+            self.write("{}");
+            if let Some(closing) = closing {
+                self.touch(closing);
+            }
+            return;
+        }
+
+        self.open_block();
+        for (index, statement) in statements.iter().enumerate() {
+            let is_terminated = match statements.get(index + 1) {
+                Some(FunctionLocalStatement::Expression(next)) => !Self::is_statement_start(next),
+                Some(_) => false,
+                None => block.expression.is_none(),
+            };
+            self.function_local_statement(statement, is_terminated);
+        }
+        if let Some(ref expression) = block.expression {
+            self.begin_line(Self::expression_start(expression));
+            self.expression(expression);
+        }
+        self.close_block(closing);
+    }
+
+    ///
+    /// Formats a `const` statement.
+    ///
+    fn const_statement(&mut self, statement: &ConstStatement) {
+        self.begin_statement(statement.location);
+        self.write("const ");
+        self.identifier(&statement.identifier);
+        self.write(": ");
+        self.r#type(&statement.r#type);
+        self.write(" = ");
+        self.expression(&statement.expression);
+        self.write(";");
+    }
+
+    ///
+    /// Formats a `type` statement.
+    ///
+    fn type_statement(&mut self, statement: &TypeStatement) {
+        self.begin_statement(statement.location);
+        self.write("type ");
+        self.identifier(&statement.identifier);
+        self.write(" = ");
+        self.r#type(&statement.r#type);
+        self.write(";");
+    }
+
+    ///
+    /// Formats a `struct` statement.
+    ///
+    fn struct_statement(&mut self, statement: &StructStatement) {
+        self.begin_statement(statement.location);
+        self.write("struct ");
+        self.identifier(&statement.identifier);
+        self.generic_parameters(statement.generics.as_slice());
+        self.write(" ");
+
+        let closing = self.closing_bracket();
+        if statement.fields.is_empty() && !self.has_comments_before(closing) {
+            self.write("{}");
+            return;
+        }
+
+        self.open_block();
+        for field in statement.fields.iter() {
+            self.begin_line(field.location);
+            self.field(field);
+            self.write(",");
+        }
+        self.close_block(closing);
+    }
+
+    ///
+    /// Formats an `enum` statement.
+    ///
+    fn enum_statement(&mut self, statement: &EnumStatement) {
+        self.begin_statement(statement.location);
+        self.write("enum ");
+        self.identifier(&statement.identifier);
+        self.write(" ");
+
+        let closing = self.closing_bracket();
+        if statement.variants.is_empty() && !self.has_comments_before(closing) {
+            self.write("{}");
+            return;
+        }
+
+        self.open_block();
+        for variant in statement.variants.iter() {
+            self.begin_line(variant.location);
+            self.variant(variant);
+            self.write(",");
+        }
+        self.close_block(closing);
+    }
+
+    ///
+    /// Formats a `fn` statement.
+    ///
+    fn fn_statement(&mut self, statement: &FnStatement) {
+        let start = statement
+            .attributes
+            .first()
+            .map(|attribute| attribute.location)
+            .unwrap_or(statement.location);
+        self.begin_line(start);
+        for attribute in statement.attributes.iter() {
+            self.attribute(attribute);
+            self.newline();
+        }
+
+        if statement.is_public || self.source.public_keyword(statement.location).is_some() {
+            self.write("pub ");
+        }
+        if statement.is_constant {
+            self.write("const ");
+        }
+        self.touch(statement.location);
+        self.write("fn ");
+        self.identifier(&statement.identifier);
+        self.generic_parameters(statement.generics.as_slice());
+        self.bindings(statement.argument_bindings.as_slice());
+        if let Some(ref r#type) = statement.return_type {
+            self.write(" -> ");
+            self.r#type(r#type);
+        }
+        self.write(" ");
+        self.block(&statement.body);
+    }
+
+    ///
+    /// Formats a `mod` statement.
+    ///
+    fn mod_statement(&mut self, statement: &ModStatement) {
+        self.begin_statement(statement.location);
+        self.write("mod ");
+        self.identifier(&statement.identifier);
+        self.write(";");
+    }
+
+    ///
+    /// Formats a `use` statement.
+    ///
+    fn use_statement(&mut self, statement: &UseStatement) {
+        self.begin_statement(statement.location);
+        self.write("use ");
+        self.expression(&statement.path);
+        if let Some(ref alias) = statement.alias_identifier {
+            self.write(" as ");
+            self.identifier(alias);
+        }
+        self.write(";");
+    }
+
+    ///
+    /// Formats a `trait` statement.
+    ///
+    fn trait_statement(&mut self, statement: &TraitStatement) {
+        self.begin_statement(statement.location);
+        self.write("trait ");
+        self.identifier(&statement.identifier);
+        self.write(" ");
+
+        let closing = self.closing_bracket();
+        let methods: Vec<&TraitMethod> = statement
+            .statements
+            .iter()
+            .filter_map(|statement| match statement {
+                TraitLocalStatement::Fn(method) => Some(method),
+                TraitLocalStatement::Empty(_) => None,
+            })
+            .collect();
+        if methods.is_empty() && !self.has_comments_before(closing) {
+            self.write("{}");
+            return;
+        }
+
+        self.open_block();
+        for method in methods.into_iter() {
+            self.begin_statement(method.location);
+            if method.is_constant {
+                self.write("const ");
+            }
+            self.write("fn ");
+            self.identifier(&method.identifier);
+            self.bindings(method.argument_bindings.as_slice());
+            if let Some(ref r#type) = method.return_type {
+                self.write(" -> ");
+                self.r#type(r#type);
+            }
+            self.write(";");
+        }
+        self.close_block(closing);
+    }
+
+    ///
+    /// Formats an `impl` statement.
+    ///
+    fn impl_statement(&mut self, statement: &ImplStatement) {
+        self.begin_statement(statement.location);
+        self.write("impl");
+        self.generic_parameters(statement.generics.as_slice());
+        self.write(" ");
+        if let Some(ref r#trait) = statement.r#trait {
+            self.identifier(r#trait);
+            self.write(" for ");
+        }
+        self.identifier(&statement.identifier);
+        if let Some(ref arguments) = statement.type_arguments {
+            self.generics(arguments.as_slice());
+        }
+        self.write(" ");
+
+        let closing = self.closing_bracket();
+        if Self::is_empty(statement.statements.as_slice(), |statement| {
+            matches!(statement, ImplementationLocalStatement::Empty(_))
+        }) && !self.has_comments_before(closing)
+        {
+            self.write("{}");
+            return;
+        }
+
+        self.open_block();
+        for statement in statement.statements.iter() {
+            self.implementation_local_statement(statement);
+        }
+        self.close_block(closing);
+    }
+
+    ///
+    /// Formats a `contract` statement.
+    ///
+    fn contract_statement(&mut self, statement: &ContractStatement) {
+        self.begin_statement(statement.location);
+        self.write("contract ");
+        self.identifier(&statement.identifier);
+        self.write(" ");
+
+        let closing = self.closing_bracket();
+        if Self::is_empty(statement.statements.as_slice(), |statement| {
+            matches!(statement, ContractLocalStatement::Empty(_))
+        }) && !self.has_comments_before(closing)
+        {
+            self.write("{}");
+            return;
+        }
+
+        self.open_block();
+        for statement in statement.statements.iter() {
+            self.contract_local_statement(statement);
+        }
+        self.close_block(closing);
+    }
+
+    ///
+    /// Formats a contract storage field statement.
+    ///
+    fn field_statement(&mut self, statement: &FieldStatement) {
+        let is_public = self.begin_statement(statement.location);
+        if statement.is_public && !is_public {
+            self.write("pub ");
+        }
+        self.identifier(&statement.identifier);
+        self.write(": ");
+        self.r#type(&statement.r#type);
+        self.write(";");
+    }
+
+    ///
+    /// Formats a `let` statement.
+    ///
+    fn let_statement(&mut self, statement: &LetStatement) {
+        self.begin_statement(statement.location);
+        self.write("let ");
+        self.binding(&statement.binding);
+        self.write(" = ");
+        self.expression(&statement.expression);
+        self.write(";");
+    }
+
+    ///
+    /// Formats a `for` statement.
+    ///
+    fn for_statement(&mut self, statement: &ForStatement) {
+        self.begin_statement(statement.location);
+        self.write("for ");
+        self.binding_pattern(&statement.pattern);
+        self.write(" in ");
+        self.expression(&statement.bounds_expression);
+        if let Some(ref condition) = statement.while_condition {
+            self.write(" while ");
+            self.expression(condition);
+        }
+        self.write(" ");
+        self.block(&statement.block);
+    }
+
+    ///
+    /// Formats a `return` statement.
+    ///
+    fn return_statement(&mut self, statement: &ReturnStatement) {
+        self.begin_statement(statement.location);
+        self.write("return");
+        if let Some(ref expression) = statement.expression {
+            self.write(" ");
+            self.expression(expression);
+        }
+        self.write(";");
+    }
+
+    ///
+    /// Formats a structure field or an enumeration variant field.
+    ///
+    pub(crate) fn field(&mut self, field: &Field) {
+        self.identifier(&field.identifier);
+        self.write(": ");
+        self.r#type(&field.r#type);
+    }
+
+    ///
+    /// Formats an enumeration variant.
+    ///
+    fn variant(&mut self, variant: &Variant) {
+        self.identifier(&variant.identifier);
+        match variant.payload {
+            VariantPayload::Unit => {}
+            VariantPayload::Tuple(ref types) => {
+                self.write("(");
+                for (index, r#type) in types.iter().enumerate() {
+                    if index > 0 {
+                        self.write(", ");
+                    }
+                    self.r#type(r#type);
+                }
+                self.write(")");
+            }
+            VariantPayload::Structure(ref fields) => {
+                self.write(" { ");
+                for (index, field) in fields.iter().enumerate() {
+                    if index > 0 {
+                        self.write(", ");
+                    }
+                    self.field(field);
+                }
+                self.write(" }");
+            }
+        }
+        if let Some(ref literal) = variant.literal {
+            self.write(" = ");
+            self.integer_literal(literal);
+        }
+    }
+
+    ///
+    /// Formats an attribute on a separate line.
+    ///
+    fn attribute(&mut self, attribute: &Attribute) {
+        self.touch(attribute.location);
+        self.write(if attribute.is_inner { "#![" } else { "#[" });
+        self.attribute_elements(attribute.elements.as_slice());
+        self.write("]");
+    }
+
+    ///
+    /// Prepares a new line for a statement and writes the `pub` keyword, if the statement is
+    /// preceded by one.
+    ///
+    /// Returns whether the `pub` keyword has been written.
+    ///
+    fn begin_statement(&mut self, location: Location) -> bool {
+        let public_keyword = self.source.public_keyword(location);
+        self.begin_line(public_keyword.unwrap_or(location));
+        self.touch(location);
+        if public_keyword.is_some() {
+            self.write("pub ");
+        }
+        public_keyword.is_some()
+    }
+
+    ///
+    /// Checks whether the statement list contains only empty statements.
+    ///
+    fn is_empty<S, F>(statements: &[S], is_empty: F) -> bool
+    where
+        F: Fn(&S) -> bool,
+    {
+        statements.iter().all(is_empty)
+    }
+
+    ///
+    /// Checks whether the expression cannot be parsed as a continuation of the preceding
+    /// block, conditional, or match expression, so the latter does not need a semicolon.
+    ///
+    fn is_statement_start(expression: &ExpressionTree) -> bool {
+        let mut tree = expression;
+        loop {
+            match *tree.value {
+                ExpressionTreeNode::Operand(ExpressionOperand::Identifier(_))
+                | ExpressionTreeNode::Operand(ExpressionOperand::LiteralBoolean(_))
+                | ExpressionTreeNode::Operand(ExpressionOperand::LiteralInteger(_))
+                | ExpressionTreeNode::Operand(ExpressionOperand::LiteralString(_))
+                | ExpressionTreeNode::Operand(ExpressionOperand::LiteralBytes(_))
+                | ExpressionTreeNode::Operand(ExpressionOperand::Block(_))
+                | ExpressionTreeNode::Operand(ExpressionOperand::Conditional(_))
+                | ExpressionTreeNode::Operand(ExpressionOperand::Match(_)) => return true,
+                ExpressionTreeNode::Operand(_) => return false,
+                ExpressionTreeNode::Operator(operator) if Self::is_prefix(operator) => {
+                    return false
+                }
+                ExpressionTreeNode::Operator(_) => match tree.left {
+                    Some(ref left) => tree = left,
+                    None => return false,
+                },
+            }
+        }
+    }
+}
@@ -3,11 +3,14 @@
 //!
 
 pub(crate) mod error;
+pub(crate) mod formatter;
 pub(crate) mod parser;
 pub(crate) mod tree;
 
 pub use self::error::Error;
 pub use self::error::ParsingError;
+pub use self::formatter::error::Error as FormatterError;
+pub use self::formatter::Formatter;
 pub use self::parser::Parser;
 pub use self::tree::attribute::element::variant::Variant as AttributeElementVariant;
 pub use self::tree::attribute::element::Element as AttributeElement;
@@ -25,8 +28,10 @@ pub use self::tree::expression::tree::node::operator::Operator as ExpressionOper
 pub use self::tree::expression::tree::node::Node as ExpressionTreeNode;
 pub use self::tree::expression::tree::Tree as ExpressionTree;
 pub use self::tree::expression::tuple::Expression as TupleExpression;
+pub use self::tree::generic_parameter::GenericParameter;
 pub use self::tree::identifier::Identifier;
 pub use self::tree::literal::boolean::Literal as BooleanLiteral;
+pub use self::tree::literal::bytes::Literal as BytesLiteral;
 pub use self::tree::literal::integer::Literal as IntegerLiteral;
 pub use self::tree::literal::string::Literal as StringLiteral;
 pub use self::tree::literal::Literal;
@@ -43,15 +48,22 @@ pub use self::tree::statement::local_contract::Statement as ContractLocalStateme
 pub use self::tree::statement::local_fn::Statement as FunctionLocalStatement;
 pub use self::tree::statement::local_impl::Statement as ImplementationLocalStatement;
 pub use self::tree::statement::local_mod::Statement as ModuleLocalStatement;
+pub use self::tree::statement::local_trait::Statement as TraitLocalStatement;
 pub use self::tree::statement::module::Statement as ModStatement;
+pub use self::tree::statement::r#break::Statement as BreakStatement;
 pub use self::tree::statement::r#const::Statement as ConstStatement;
+pub use self::tree::statement::r#continue::Statement as ContinueStatement;
 pub use self::tree::statement::r#enum::Statement as EnumStatement;
 pub use self::tree::statement::r#fn::Statement as FnStatement;
 pub use self::tree::statement::r#for::Statement as ForStatement;
 pub use self::tree::statement::r#impl::Statement as ImplStatement;
 pub use self::tree::statement::r#let::Statement as LetStatement;
+pub use self::tree::statement::r#return::Statement as ReturnStatement;
 pub use self::tree::statement::r#struct::Statement as StructStatement;
+pub use self::tree::statement::r#trait::method::Method as TraitMethod;
+pub use self::tree::statement::r#trait::Statement as TraitStatement;
 pub use self::tree::statement::r#type::Statement as TypeStatement;
 pub use self::tree::statement::r#use::Statement as UseStatement;
 pub use self::tree::tuple_index::TupleIndex;
+pub use self::tree::variant::payload::Payload as VariantPayload;
 pub use self::tree::variant::Variant;
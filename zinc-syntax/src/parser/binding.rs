@@ -17,6 +17,14 @@ use crate::parser::pattern_binding::Parser as BindingPatternParser;
 use crate::parser::r#type::Parser as TypeParser;
 use crate::tree::binding::builder::Builder as BindingBuilder;
 use crate::tree::binding::Binding;
+use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
+use crate::tree::expression::tree::Tree as ExpressionTree;
+use crate::tree::identifier::Identifier;
+use crate::tree::pattern_binding::variant::Variant as BindingPatternVariant;
+use crate::tree::pattern_binding::Pattern as BindingPattern;
+use crate::tree::r#type::variant::Variant as TypeVariant;
+use crate::tree::r#type::Type;
 
 ///
 /// The parser state.
@@ -25,6 +33,10 @@ use crate::tree::binding::Binding;
 pub enum State {
     /// The initial state.
     Binding,
+    /// The `&` has been parsed so far.
+    ReferenceMut,
+    /// The `& mut` has been parsed so far.
+    ReferenceSelf,
     /// The `{binding}` has been parsed so far.
     ColorOrEnd,
     /// The `{binding} :` has been parsed so far.
@@ -57,6 +69,7 @@ impl Parser {
     /// 'a'
     /// 'mut a: u8'
     /// '_: bool'
+    /// '&mut self'
     ///
     pub fn parse(
         mut self,
@@ -108,6 +121,14 @@ impl Parser {
 
                             self.state = State::ColorOrEnd;
                         }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Ampersand),
+                            location,
+                        } => {
+                            self.builder.set_location(location);
+
+                            self.state = State::ReferenceMut;
+                        }
                         Token { location, lexeme } => {
                             return Err(ParsingError::Syntax(
                                 SyntaxError::expected_binding_pattern(location, lexeme),
@@ -115,6 +136,68 @@ impl Parser {
                         }
                     }
                 }
+                State::ReferenceMut => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Mut),
+                            ..
+                        } => {
+                            self.state = State::ReferenceSelf;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["mut"],
+                                lexeme,
+                                None,
+                            )))
+                        }
+                    }
+                }
+                State::ReferenceSelf => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::SelfLowercase),
+                            location,
+                        } => {
+                            self.builder.set_pattern(BindingPattern::new(
+                                location,
+                                BindingPatternVariant::new_binding(
+                                    Identifier::new(location, Keyword::SelfLowercase.to_string()),
+                                    false,
+                                ),
+                            ));
+                            self.builder.set_type(Type::new(
+                                location,
+                                TypeVariant::mutable_reference(Type::new(
+                                    location,
+                                    TypeVariant::alias(
+                                        ExpressionTree::new(
+                                            location,
+                                            ExpressionTreeNode::operand(
+                                                ExpressionOperand::Identifier(Identifier::new(
+                                                    location,
+                                                    Keyword::SelfUppercase.to_string(),
+                                                )),
+                                            ),
+                                        ),
+                                        None,
+                                    ),
+                                )),
+                            ));
+
+                            return Ok((self.builder.finish(), None));
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["self"],
+                                lexeme,
+                                None,
+                            )))
+                        }
+                    }
+                }
                 State::ColorOrEnd => {
                     match crate::parser::take_or_next(self.next.take(), stream.clone())? {
                         Token {
@@ -140,13 +223,20 @@ impl Parser {
 
 #[cfg(test)]
 mod tests {
+    use zinc_lexical::Identifier as LexicalIdentifier;
+    use zinc_lexical::Keyword;
     use zinc_lexical::Lexeme;
     use zinc_lexical::Location;
     use zinc_lexical::Token;
     use zinc_lexical::TokenStream;
 
     use super::Parser;
+    use crate::error::Error as SyntaxError;
+    use crate::error::ParsingError;
     use crate::tree::binding::Binding;
+    use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+    use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
+    use crate::tree::expression::tree::Tree as ExpressionTree;
     use crate::tree::identifier::Identifier;
     use crate::tree::pattern_binding::variant::Variant as BindingPatternVariant;
     use crate::tree::pattern_binding::Pattern as BindingPattern;
@@ -203,4 +293,91 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn ok_with_mutable_reference_type() {
+        let input = r#"value: &mut u8"#;
+
+        let expected = Ok((
+            Binding::new(
+                Location::test(1, 1),
+                BindingPattern::new(
+                    Location::test(1, 1),
+                    BindingPatternVariant::new_binding(
+                        Identifier::new(Location::test(1, 1), "value".to_owned()),
+                        false,
+                    ),
+                ),
+                Some(Type::new(
+                    Location::test(1, 8),
+                    TypeVariant::mutable_reference(Type::new(
+                        Location::test(1, 13),
+                        TypeVariant::integer_unsigned(zinc_const::bitlength::BYTE),
+                    )),
+                )),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_mutable_reference_self() {
+        let input = r#"&mut self"#;
+
+        let expected = Ok((
+            Binding::new(
+                Location::test(1, 1),
+                BindingPattern::new(
+                    Location::test(1, 6),
+                    BindingPatternVariant::new_binding(
+                        Identifier::new(Location::test(1, 6), Keyword::SelfLowercase.to_string()),
+                        false,
+                    ),
+                ),
+                Some(Type::new(
+                    Location::test(1, 6),
+                    TypeVariant::mutable_reference(Type::new(
+                        Location::test(1, 6),
+                        TypeVariant::alias(
+                            ExpressionTree::new(
+                                Location::test(1, 6),
+                                ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                    Identifier::new(
+                                        Location::test(1, 6),
+                                        Keyword::SelfUppercase.to_string(),
+                                    ),
+                                )),
+                            ),
+                            None,
+                        ),
+                    )),
+                )),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_self() {
+        let input = r#"&mut value"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 6),
+            vec!["self"],
+            Lexeme::Identifier(LexicalIdentifier::new("value".to_owned())),
+            None,
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
 }
@@ -91,6 +91,12 @@ impl Parser {
                         Token {
                             lexeme: Lexeme::Keyword(Keyword::SelfLowercase),
                             ..
+                        }
+                        | token
+                        @
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Ampersand),
+                            ..
                         } => {
                             let (binding, next) =
                                 BindingParser::default().parse(stream.clone(), Some(token))?;
@@ -230,6 +230,7 @@ impl Parser {
 
 #[cfg(test)]
 mod tests {
+    use zinc_lexical::IntegerLiteral as LexicalIntegerLiteral;
     use zinc_lexical::Lexeme;
     use zinc_lexical::Location;
     use zinc_lexical::Symbol;
@@ -244,6 +245,9 @@ mod tests {
     use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
     use crate::tree::expression::tree::Tree as ExpressionTree;
     use crate::tree::identifier::Identifier;
+    use crate::tree::literal::integer::Literal as IntegerLiteral;
+    use crate::tree::r#type::variant::Variant as TypeVariant;
+    use crate::tree::r#type::Type;
 
     #[test]
     fn ok() {
@@ -288,6 +292,67 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_generic_path() {
+        let input = r#"mega::ultra::<u8, 4>;"#;
+
+        let expected = Ok((
+            ExpressionTree::new(
+                Location::test(1, 5),
+                ExpressionTreeNode::operand(ExpressionOperand::GenericPath(Type::new(
+                    Location::test(1, 5),
+                    TypeVariant::alias(
+                        ExpressionTree::new_with_leaves(
+                            Location::test(1, 5),
+                            ExpressionTreeNode::operator(ExpressionOperator::Path),
+                            Some(ExpressionTree::new(
+                                Location::test(1, 1),
+                                ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                    Identifier::new(Location::test(1, 1), "mega".to_owned()),
+                                )),
+                            )),
+                            Some(ExpressionTree::new(
+                                Location::test(1, 7),
+                                ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                    Identifier::new(Location::test(1, 7), "ultra".to_owned()),
+                                )),
+                            )),
+                        ),
+                        Some(vec![
+                            Type::new(
+                                Location::test(1, 15),
+                                TypeVariant::integer_unsigned(zinc_const::bitlength::BYTE),
+                            ),
+                            Type::new(
+                                Location::test(1, 19),
+                                TypeVariant::alias(
+                                    ExpressionTree::new(
+                                        Location::test(1, 19),
+                                        ExpressionTreeNode::operand(
+                                            ExpressionOperand::LiteralInteger(IntegerLiteral::new(
+                                                Location::test(1, 19),
+                                                LexicalIntegerLiteral::new_decimal("4".to_owned()),
+                                            )),
+                                        ),
+                                    ),
+                                    None,
+                                ),
+                            ),
+                        ]),
+                    ),
+                ))),
+            ),
+            Some(Token::new(
+                Lexeme::Symbol(Symbol::Semicolon),
+                Location::test(1, 21),
+            )),
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_bracket_square_right() {
         let input = r#"array[42)"#;
@@ -5,11 +5,13 @@
 use std::cell::RefCell;
 use std::rc::Rc;
 
+use zinc_lexical::Keyword;
 use zinc_lexical::Lexeme;
 use zinc_lexical::Symbol;
 use zinc_lexical::Token;
 use zinc_lexical::TokenStream;
 
+use crate::error::Error as SyntaxError;
 use crate::error::ParsingError;
 use crate::parser::expression::access::Parser as AccessOperandParser;
 use crate::tree::expression::tree::builder::Builder as ExpressionTreeBuilder;
@@ -30,12 +32,13 @@ pub struct Parser {
 impl Parser {
     ///
     /// Parses a casting expression operand, which is
-    /// a lower precedence unary logical NOT, bitwise NOT or negation, or binary access
-    /// operator expression.
+    /// a lower precedence unary logical NOT, bitwise NOT, negation or mutable reference,
+    /// or binary access operator expression.
     ///
     /// '-42'
     /// '~0b101010'
     /// '!true'
+    /// '&mut array[1]'
     /// 'foo(bar, 42, true)[42][0..5].4.value
     ///
     pub fn parse(
@@ -75,6 +78,29 @@ impl Parser {
                     .eat_operator(ExpressionOperator::Negation, location);
                 Ok((self.builder.finish(), next))
             }
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::Ampersand),
+                location,
+            } => match crate::parser::take_or_next(None, stream.clone())? {
+                Token {
+                    lexeme: Lexeme::Keyword(Keyword::Mut),
+                    ..
+                } => {
+                    let (expression, next) = AccessOperandParser::default().parse(stream, None)?;
+                    self.builder.eat(expression);
+                    self.builder
+                        .eat_operator(ExpressionOperator::Reference, location);
+                    Ok((self.builder.finish(), next))
+                }
+                Token { lexeme, location } => {
+                    Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                        location,
+                        vec!["mut"],
+                        lexeme,
+                        Some(crate::parser::r#type::HINT_ONLY_MUTABLE_REFERENCES),
+                    )))
+                }
+            },
             token => {
                 let (expression, next) =
                     AccessOperandParser::default().parse(stream, Some(token))?;
@@ -240,4 +266,41 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn ok_reference() {
+        let input = r#"&mut array[1]"#;
+
+        let expected = Ok((
+            ExpressionTree::new_with_leaves(
+                Location::test(1, 1),
+                ExpressionTreeNode::operator(ExpressionOperator::Reference),
+                Some(ExpressionTree::new_with_leaves(
+                    Location::test(1, 11),
+                    ExpressionTreeNode::operator(ExpressionOperator::Index),
+                    Some(ExpressionTree::new(
+                        Location::test(1, 6),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::test(1, 6), "array".to_owned()),
+                        )),
+                    )),
+                    Some(ExpressionTree::new(
+                        Location::test(1, 12),
+                        ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                            IntegerLiteral::new(
+                                Location::test(1, 12),
+                                LexicalIntegerLiteral::new_decimal("1".to_owned()),
+                            ),
+                        )),
+                    )),
+                )),
+                None,
+            ),
+            Some(Token::new(Lexeme::Eof, Location::test(1, 14))),
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
 }
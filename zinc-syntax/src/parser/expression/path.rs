@@ -13,10 +13,13 @@ use zinc_lexical::TokenStream;
 use crate::error::ParsingError;
 use crate::parser::expression::structure::Parser as StructureExpressionParser;
 use crate::parser::expression::terminal::Parser as TerminalOperandParser;
+use crate::parser::r#type::generics::Parser as GenericsParser;
 use crate::tree::expression::tree::builder::Builder as ExpressionTreeBuilder;
 use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
 use crate::tree::expression::tree::node::operator::Operator as ExpressionOperator;
 use crate::tree::expression::tree::Tree as ExpressionTree;
+use crate::tree::r#type::variant::Variant as TypeVariant;
+use crate::tree::r#type::Type;
 
 ///
 /// The parser state.
@@ -51,11 +54,13 @@ pub struct Parser {
 impl Parser {
     ///
     /// Parses a path expression, which consists of several items.
-    /// Can be terminated with a structure literal.
+    /// Can be terminated with explicit generic arguments and a structure literal.
     ///
     /// 'value'
     /// 'path::to::Type'
     /// 'path::to::Structure { a: 42, b: 25 }'
+    /// 'path::to::function::<u8, 4>'
+    /// 'path::to::Structure::<u8> { a: 42, b: 25 }'
     ///
     pub fn parse(
         mut self,
@@ -79,13 +84,34 @@ impl Parser {
                             lexeme: Lexeme::Symbol(Symbol::DoubleColon),
                             location,
                         } => {
+                            let look_ahead = stream.borrow_mut().look_ahead(1)?.to_owned();
+                            if let Token {
+                                lexeme: Lexeme::Symbol(Symbol::Lesser),
+                                ..
+                            } = look_ahead
+                            {
+                                let path = std::mem::take(&mut self.builder).finish();
+                                let location = path.location;
+
+                                let (generics, next) =
+                                    GenericsParser::default().parse(stream.clone(), None)?;
+                                self.next = next;
+
+                                self.builder.eat_operand(
+                                    ExpressionOperand::GenericPath(Type::new(
+                                        location,
+                                        TypeVariant::alias(path, Some(generics)),
+                                    )),
+                                    location,
+                                );
+                                continue;
+                            }
+
                             self.builder
                                 .eat_operator(ExpressionOperator::Path, location);
                             self.state = State::Terminal;
                         }
-                        token
-                        @
-                        Token {
+                        token @ Token {
                             lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
                             ..
                         } => {
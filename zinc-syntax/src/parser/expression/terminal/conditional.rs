@@ -15,6 +15,7 @@ use crate::error::Error as SyntaxError;
 use crate::error::ParsingError;
 use crate::parser::expression::terminal::block::Parser as BlockExpressionParser;
 use crate::parser::expression::Parser as ExpressionParser;
+use crate::parser::pattern_match::Parser as MatchPatternParser;
 use crate::tree::expression::block::Expression as BlockExpression;
 use crate::tree::expression::conditional::builder::Builder as ConditionalExpressionBuilder;
 use crate::tree::expression::conditional::Expression as ConditionalExpression;
@@ -30,6 +31,12 @@ pub enum State {
     /// The initial state.
     KeywordIf,
     /// The `if` has been parsed so far.
+    KeywordLetOrCondition,
+    /// The `if let` has been parsed so far.
+    Pattern,
+    /// The `if let {pattern}` has been parsed so far.
+    Equals,
+    /// The `if` or `if let {pattern} =` has been parsed so far.
     Condition,
     /// The `if {expression}` has been parsed so far.
     MainBlock,
@@ -70,6 +77,12 @@ impl Parser {
     /// }
     /// '
     ///
+    /// '
+    /// if let Option::Some(value) = option {
+    ///     value
+    /// }
+    /// '
+    ///
     pub fn parse(
         mut self,
         stream: Rc<RefCell<TokenStream>>,
@@ -86,7 +99,7 @@ impl Parser {
                             location,
                         } => {
                             self.builder.set_location(location);
-                            self.state = State::Condition;
+                            self.state = State::KeywordLetOrCondition;
                         }
                         Token { lexeme, location } => {
                             return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
@@ -98,6 +111,41 @@ impl Parser {
                         }
                     }
                 }
+                State::KeywordLetOrCondition => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Let),
+                            ..
+                        } => self.state = State::Pattern,
+                        token => {
+                            self.next = Some(token);
+                            self.state = State::Condition;
+                        }
+                    }
+                }
+                State::Pattern => {
+                    let (pattern, next) =
+                        MatchPatternParser::default().parse(stream.clone(), self.next.take())?;
+                    self.next = next;
+                    self.builder.set_pattern(pattern);
+                    self.state = State::Equals;
+                }
+                State::Equals => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Equals),
+                            ..
+                        } => self.state = State::Condition,
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["="],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
                 State::Condition => {
                     let (expression, next) =
                         ExpressionParser::default().parse(stream.clone(), self.next.take())?;
@@ -184,6 +232,8 @@ mod tests {
     use crate::tree::expression::tree::Tree as ExpressionTree;
     use crate::tree::literal::boolean::Literal as BooleanLiteral;
     use crate::tree::literal::integer::Literal as IntegerLiteral;
+    use crate::tree::pattern_match::variant::Variant as MatchPatternVariant;
+    use crate::tree::pattern_match::Pattern as MatchPattern;
 
     #[test]
     fn ok_nested() {
@@ -192,6 +242,7 @@ mod tests {
         let expected = Ok((
             ConditionalExpression::new(
                 Location::test(1, 1),
+                None,
                 ExpressionTree::new(
                     Location::test(1, 4),
                     ExpressionTreeNode::operand(ExpressionOperand::LiteralBoolean(
@@ -219,6 +270,7 @@ mod tests {
                         ExpressionTreeNode::operand(ExpressionOperand::Conditional(
                             ConditionalExpression::new(
                                 Location::test(1, 20),
+                                None,
                                 ExpressionTree::new(
                                     Location::test(1, 23),
                                     ExpressionTreeNode::operand(ExpressionOperand::LiteralBoolean(
@@ -267,6 +319,93 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_let() {
+        let input = r#"if let 1 | 2 = 42 { true } else { false }"#;
+
+        let expected = Ok((
+            ConditionalExpression::new(
+                Location::test(1, 1),
+                Some(MatchPattern::new(
+                    Location::test(1, 8),
+                    MatchPatternVariant::Alternatives(vec![
+                        MatchPattern::new(
+                            Location::test(1, 8),
+                            MatchPatternVariant::IntegerLiteral(IntegerLiteral::new(
+                                Location::test(1, 8),
+                                LexicalIntegerLiteral::new_decimal("1".to_owned()),
+                            )),
+                        ),
+                        MatchPattern::new(
+                            Location::test(1, 12),
+                            MatchPatternVariant::IntegerLiteral(IntegerLiteral::new(
+                                Location::test(1, 12),
+                                LexicalIntegerLiteral::new_decimal("2".to_owned()),
+                            )),
+                        ),
+                    ]),
+                )),
+                ExpressionTree::new(
+                    Location::test(1, 16),
+                    ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                        IntegerLiteral::new(
+                            Location::test(1, 16),
+                            LexicalIntegerLiteral::new_decimal("42".to_owned()),
+                        ),
+                    )),
+                ),
+                BlockExpression::new(
+                    Location::test(1, 19),
+                    vec![],
+                    Some(ExpressionTree::new(
+                        Location::test(1, 21),
+                        ExpressionTreeNode::operand(ExpressionOperand::LiteralBoolean(
+                            BooleanLiteral::new(
+                                Location::test(1, 21),
+                                LexicalBooleanLiteral::r#true(),
+                            ),
+                        )),
+                    )),
+                ),
+                Some(BlockExpression::new(
+                    Location::test(1, 33),
+                    vec![],
+                    Some(ExpressionTree::new(
+                        Location::test(1, 35),
+                        ExpressionTreeNode::operand(ExpressionOperand::LiteralBoolean(
+                            BooleanLiteral::new(
+                                Location::test(1, 35),
+                                LexicalBooleanLiteral::r#false(),
+                            ),
+                        )),
+                    )),
+                )),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_equals() {
+        let input = r#"if let value == 42 { value }"#;
+
+        let expected: Result<_, ParsingError> =
+            Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                Location::test(1, 14),
+                vec!["="],
+                Lexeme::Symbol(Symbol::DoubleEquals),
+                None,
+            )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_bracket_square_right() {
         let input = r#"if true { 42 } else ("#;
@@ -32,6 +32,10 @@ pub enum State {
     /// The `match {expression} {` has been parsed so far.
     BracketCurlyRightOrBranchPattern,
     /// The `match {expression} { {pattern}` has been parsed so far.
+    KeywordIfOrSelect,
+    /// The `match {expression} { {pattern} if` has been parsed so far.
+    BranchGuard,
+    /// The `match {expression} { {pattern} if {expression}` has been parsed so far.
     Select,
     /// The `match {expression} { {pattern} =>` has been parsed so far.
     BranchExpression,
@@ -65,7 +69,9 @@ impl Parser {
     /// '
     /// match value {
     ///     1 => value * 5,
-    ///     2 => value * 10,
+    ///     2 | 3 => value * 10,
+    ///     4..=9 => value * 20,
+    ///     another if another > 100 => 0,
     ///     another => another - 1,
     /// }
     /// '
@@ -134,10 +140,37 @@ impl Parser {
                                 MatchPatternParser::default().parse(stream.clone(), Some(token))?;
                             self.next = next;
                             self.builder.push_branch_pattern(pattern);
-                            self.state = State::Select;
+                            self.state = State::KeywordIfOrSelect;
                         }
                     }
                 }
+                State::KeywordIfOrSelect => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::If),
+                            ..
+                        } => self.state = State::BranchGuard,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::EqualsGreater),
+                            ..
+                        } => self.state = State::BranchExpression,
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["if", "=>"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::BranchGuard => {
+                    let (expression, next) =
+                        ExpressionParser::default().parse(stream.clone(), self.next.take())?;
+                    self.next = next;
+                    self.builder.set_branch_guard(expression);
+                    self.state = State::Select;
+                }
                 State::Select => {
                     match crate::parser::take_or_next(self.next.take(), stream.clone())? {
                         Token {
@@ -262,6 +295,7 @@ mod tests {
                             LexicalBooleanLiteral::r#false(),
                         )),
                     ),
+                    None,
                     ExpressionTree::new(
                         Location::test(3, 18),
                         ExpressionTreeNode::operand(ExpressionOperand::LiteralBoolean(
@@ -309,6 +343,7 @@ mod tests {
                                 LexicalIntegerLiteral::new_decimal("1".to_owned()),
                             )),
                         ),
+                        None,
                         ExpressionTree::new(
                             Location::test(3, 14),
                             ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
@@ -327,6 +362,7 @@ mod tests {
                                 LexicalIntegerLiteral::new_decimal("2".to_owned()),
                             )),
                         ),
+                        None,
                         ExpressionTree::new(
                             Location::test(4, 14),
                             ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
@@ -342,6 +378,7 @@ mod tests {
                             Location::test(5, 9),
                             MatchPatternVariant::new_wildcard(),
                         ),
+                        None,
                         ExpressionTree::new(
                             Location::test(5, 14),
                             ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
@@ -379,6 +416,76 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_guard() {
+        let input = r#"
+    match test {
+        value if value => true,
+        _ => false,
+    }
+"#;
+
+        let expected = Ok((
+            MatchExpression::new(
+                Location::test(2, 5),
+                ExpressionTree::new(
+                    Location::test(2, 11),
+                    ExpressionTreeNode::operand(ExpressionOperand::Identifier(Identifier::new(
+                        Location::test(2, 11),
+                        "test".to_owned(),
+                    ))),
+                ),
+                vec![
+                    (
+                        MatchPattern::new(
+                            Location::test(3, 9),
+                            MatchPatternVariant::new_binding(Identifier::new(
+                                Location::test(3, 9),
+                                "value".to_owned(),
+                            )),
+                        ),
+                        Some(ExpressionTree::new(
+                            Location::test(3, 18),
+                            ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                Identifier::new(Location::test(3, 18), "value".to_owned()),
+                            )),
+                        )),
+                        ExpressionTree::new(
+                            Location::test(3, 27),
+                            ExpressionTreeNode::operand(ExpressionOperand::LiteralBoolean(
+                                BooleanLiteral::new(
+                                    Location::test(3, 27),
+                                    LexicalBooleanLiteral::r#true(),
+                                ),
+                            )),
+                        ),
+                    ),
+                    (
+                        MatchPattern::new(
+                            Location::test(4, 9),
+                            MatchPatternVariant::new_wildcard(),
+                        ),
+                        None,
+                        ExpressionTree::new(
+                            Location::test(4, 14),
+                            ExpressionTreeNode::operand(ExpressionOperand::LiteralBoolean(
+                                BooleanLiteral::new(
+                                    Location::test(4, 14),
+                                    LexicalBooleanLiteral::r#false(),
+                                ),
+                            )),
+                        ),
+                    ),
+                ],
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_select() {
         let input = r#"match 42 * 2 { value ->"#;
@@ -386,7 +493,7 @@ mod tests {
         let expected: Result<_, ParsingError> =
             Err(ParsingError::Syntax(SyntaxError::expected_one_of(
                 Location::test(1, 22),
-                vec!["=>"],
+                vec!["if", "=>"],
                 Lexeme::Symbol(Symbol::MinusGreater),
                 None,
             )));
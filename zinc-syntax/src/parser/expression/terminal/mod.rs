@@ -26,6 +26,7 @@ use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
 use crate::tree::expression::tree::Tree as ExpressionTree;
 use crate::tree::identifier::builder::Builder as IdentifierBuilder;
 use crate::tree::literal::boolean::Literal as BooleanLiteral;
+use crate::tree::literal::bytes::Literal as BytesLiteral;
 use crate::tree::literal::integer::Literal as IntegerLiteral;
 use crate::tree::literal::string::Literal as StringLiteral;
 
@@ -189,6 +190,14 @@ impl Parser {
                     location,
                     None,
                 )),
+                Token {
+                    lexeme: Lexeme::Literal(LexicalLiteral::Bytes(bytes)),
+                    location,
+                } => Ok((
+                    ExpressionOperand::LiteralBytes(BytesLiteral::new(location, bytes)),
+                    location,
+                    None,
+                )),
                 Token { lexeme, location } => Err(ParsingError::Syntax(
                     SyntaxError::expected_expression_or_operand(location, lexeme),
                 )),
@@ -204,6 +213,7 @@ impl Parser {
 #[cfg(test)]
 mod tests {
     use zinc_lexical::BooleanLiteral as LexicalBooleanLiteral;
+    use zinc_lexical::BytesLiteral as LexicalBytesLiteral;
     use zinc_lexical::IntegerLiteral as LexicalIntegerLiteral;
     use zinc_lexical::Lexeme;
     use zinc_lexical::Location;
@@ -219,6 +229,7 @@ mod tests {
     use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
     use crate::tree::expression::tree::Tree as ExpressionTree;
     use crate::tree::literal::boolean::Literal as BooleanLiteral;
+    use crate::tree::literal::bytes::Literal as BytesLiteral;
     use crate::tree::literal::integer::Literal as IntegerLiteral;
     use crate::tree::literal::string::Literal as StringLiteral;
 
@@ -283,6 +294,26 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_literal_bytes() {
+        let input = r#"hex"deadbeef""#;
+
+        let expected = Ok((
+            ExpressionTree::new(
+                Location::test(1, 1),
+                ExpressionTreeNode::Operand(ExpressionOperand::LiteralBytes(BytesLiteral::new(
+                    Location::test(1, 1),
+                    LexicalBytesLiteral::new_hexadecimal(vec![0xde, 0xad, 0xbe, 0xef]),
+                ))),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn ok_parenthesized() {
         let input = r#"(2 + 2)"#;
@@ -0,0 +1,192 @@
+//!
+//! The generic parameter parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Keyword;
+use zinc_lexical::Lexeme;
+use zinc_lexical::Symbol;
+use zinc_lexical::Token;
+use zinc_lexical::TokenStream;
+
+use crate::error::Error as SyntaxError;
+use crate::error::ParsingError;
+use crate::parser::r#type::Parser as TypeParser;
+use crate::tree::generic_parameter::builder::Builder as GenericParameterBuilder;
+use crate::tree::generic_parameter::GenericParameter;
+use crate::tree::identifier::Identifier;
+
+/// The missing identifier error hint.
+pub static HINT_EXPECTED_IDENTIFIER: &str =
+    "generic parameter must have an identifier, e.g. `T` or `const N: u8`";
+/// The missing constant type error hint.
+pub static HINT_EXPECTED_TYPE: &str =
+    "constant generic parameter must have a type, e.g. `const N: u8`";
+
+///
+/// The generic parameter parser.
+///
+#[derive(Default)]
+pub struct Parser {
+    /// The builder of the parsed value.
+    builder: GenericParameterBuilder,
+    /// The token returned from a subparser.
+    next: Option<Token>,
+}
+
+impl Parser {
+    ///
+    /// Parses a generic parameter.
+    ///
+    /// 'T'
+    /// 'const N: u8'
+    ///
+    pub fn parse(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        initial: Option<Token>,
+    ) -> Result<(GenericParameter, Option<Token>), ParsingError> {
+        self.next = initial;
+
+        let is_constant = match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Const),
+                location,
+            } => {
+                self.builder.set_location(location);
+                true
+            }
+            token => {
+                self.builder.set_location(token.location);
+                self.next = Some(token);
+                false
+            }
+        };
+
+        match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+            Token {
+                lexeme: Lexeme::Identifier(identifier),
+                location,
+            } => {
+                let identifier = Identifier::new(location, identifier.inner);
+                self.builder.set_identifier(identifier);
+            }
+            Token { lexeme, location } => {
+                return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+                    location,
+                    lexeme,
+                    Some(HINT_EXPECTED_IDENTIFIER),
+                )));
+            }
+        }
+
+        if !is_constant {
+            return Ok((self.builder.finish(), None));
+        }
+
+        match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::Colon),
+                ..
+            } => {}
+            Token { lexeme, location } => {
+                return Err(ParsingError::Syntax(SyntaxError::expected_type(
+                    location,
+                    lexeme,
+                    Some(HINT_EXPECTED_TYPE),
+                )));
+            }
+        }
+
+        let (r#type, next) = TypeParser::default().parse(stream, None)?;
+        self.builder.set_type(r#type);
+        Ok((self.builder.finish(), next))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinc_lexical::Lexeme;
+    use zinc_lexical::Location;
+    use zinc_lexical::Symbol;
+    use zinc_lexical::TokenStream;
+
+    use super::Parser;
+    use crate::error::Error as SyntaxError;
+    use crate::error::ParsingError;
+    use crate::tree::generic_parameter::GenericParameter;
+    use crate::tree::identifier::Identifier;
+    use crate::tree::r#type::variant::Variant as TypeVariant;
+    use crate::tree::r#type::Type;
+
+    #[test]
+    fn ok_type() {
+        let input = r#"T"#;
+
+        let expected = Ok((
+            GenericParameter::new(
+                Location::test(1, 1),
+                Identifier::new(Location::test(1, 1), "T".to_owned()),
+                None,
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_constant() {
+        let input = r#"const N: u8"#;
+
+        let expected = Ok((
+            GenericParameter::new(
+                Location::test(1, 1),
+                Identifier::new(Location::test(1, 7), "N".to_owned()),
+                Some(Type::new(
+                    Location::test(1, 10),
+                    TypeVariant::integer_unsigned(zinc_const::bitlength::BYTE),
+                )),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_identifier() {
+        let input = r#"const :"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+            Location::test(1, 7),
+            Lexeme::Symbol(Symbol::Colon),
+            Some(super::HINT_EXPECTED_IDENTIFIER),
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_type() {
+        let input = r#"const N"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_type(
+            Location::test(1, 8),
+            Lexeme::Eof,
+            Some(super::HINT_EXPECTED_TYPE),
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+}
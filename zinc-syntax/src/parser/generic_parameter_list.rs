@@ -0,0 +1,228 @@
+//!
+//! The generic parameter list parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Lexeme;
+use zinc_lexical::Symbol;
+use zinc_lexical::Token;
+use zinc_lexical::TokenStream;
+
+use crate::error::Error as SyntaxError;
+use crate::error::ParsingError;
+use crate::parser::generic_parameter::Parser as GenericParameterParser;
+use crate::tree::generic_parameter::GenericParameter;
+
+///
+/// The parser state.
+///
+#[derive(Debug, Default, Clone, Copy)]
+pub enum State {
+    /// The initial state.
+    #[default]
+    Lesser,
+    /// The `<` has been parsed so far.
+    ParameterOrGreater,
+    /// The `< {parameter}` has been parsed so far.
+    CommaOrGreater,
+}
+
+///
+/// The generic parameter list parser.
+///
+#[derive(Default)]
+pub struct Parser {
+    /// The parser state.
+    state: State,
+    /// The token returned from a subparser.
+    next: Option<Token>,
+    /// The parsed parameters.
+    parameters: Vec<GenericParameter>,
+}
+
+impl Parser {
+    ///
+    /// Parses a generic parameter list.
+    ///
+    /// '<T, U, const N: u8>'
+    ///
+    pub fn parse(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        initial: Option<Token>,
+    ) -> Result<(Vec<GenericParameter>, Option<Token>), ParsingError> {
+        self.next = initial;
+
+        loop {
+            match self.state {
+                State::Lesser => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Lesser),
+                            ..
+                        } => {
+                            self.state = State::ParameterOrGreater;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["<"],
+                                lexeme,
+                                None,
+                            )))
+                        }
+                    }
+                }
+                State::ParameterOrGreater => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Greater),
+                            ..
+                        } => {
+                            return Ok((self.parameters, None));
+                        }
+                        token => {
+                            let (parameter, next) = GenericParameterParser::default()
+                                .parse(stream.clone(), Some(token))?;
+                            self.parameters.push(parameter);
+                            self.next = next;
+                            self.state = State::CommaOrGreater;
+                        }
+                    }
+                }
+                State::CommaOrGreater => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => self.state = State::ParameterOrGreater,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Greater),
+                            ..
+                        } => return Ok((self.parameters, None)),
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![",", ">"],
+                                lexeme,
+                                None,
+                            )))
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinc_lexical::Lexeme;
+    use zinc_lexical::Location;
+    use zinc_lexical::Symbol;
+    use zinc_lexical::TokenStream;
+
+    use super::Parser;
+    use crate::error::Error as SyntaxError;
+    use crate::error::ParsingError;
+    use crate::tree::generic_parameter::GenericParameter;
+    use crate::tree::identifier::Identifier;
+    use crate::tree::r#type::variant::Variant as TypeVariant;
+    use crate::tree::r#type::Type;
+
+    #[test]
+    fn ok_empty() {
+        let input = r#"<>"#;
+
+        let expected = Ok((vec![], None));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_multiple() {
+        let input = r#"<A, B, const N: u8>"#;
+
+        let expected = Ok((
+            vec![
+                GenericParameter::new(
+                    Location::test(1, 2),
+                    Identifier::new(Location::test(1, 2), "A".to_owned()),
+                    None,
+                ),
+                GenericParameter::new(
+                    Location::test(1, 5),
+                    Identifier::new(Location::test(1, 5), "B".to_owned()),
+                    None,
+                ),
+                GenericParameter::new(
+                    Location::test(1, 8),
+                    Identifier::new(Location::test(1, 14), "N".to_owned()),
+                    Some(Type::new(
+                        Location::test(1, 17),
+                        TypeVariant::integer_unsigned(zinc_const::bitlength::BYTE),
+                    )),
+                ),
+            ],
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_single_with_comma() {
+        let input = r#"<T,>"#;
+
+        let expected = Ok((
+            vec![GenericParameter::new(
+                Location::test(1, 2),
+                Identifier::new(Location::test(1, 2), "T".to_owned()),
+                None,
+            )],
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_comma_or_greater() {
+        let input = r#"<T U>"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 4),
+            vec![",", ">"],
+            Lexeme::Identifier(zinc_lexical::Identifier::new("U".to_owned())),
+            None,
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_lesser() {
+        let input = r#"(T)"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 1),
+            vec!["<"],
+            Lexeme::Symbol(Symbol::ParenthesisLeft),
+            None,
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+}
@@ -8,6 +8,8 @@ pub mod binding_list;
 pub mod expression;
 pub mod field;
 pub mod field_list;
+pub mod generic_parameter;
+pub mod generic_parameter_list;
 pub mod identifier_path;
 pub mod pattern_binding;
 pub mod pattern_match;
@@ -20,6 +20,7 @@ use crate::tree::identifier::Identifier;
 use crate::tree::literal::boolean::Literal as BooleanLiteral;
 use crate::tree::literal::integer::Literal as IntegerLiteral;
 use crate::tree::pattern_match::builder::Builder as MatchPatternBuilder;
+use crate::tree::pattern_match::variant::Variant as MatchPatternVariant;
 use crate::tree::pattern_match::Pattern as MatchPattern;
 
 ///
@@ -29,10 +30,28 @@ use crate::tree::pattern_match::Pattern as MatchPattern;
 pub enum State {
     /// The initial state.
     Start,
+    /// The integer literal has been parsed so far.
+    RangeOperatorOrEnd,
+    /// The integer literal and a `..` or `..=` range operator have been parsed so far.
+    RangeEnd,
     /// The first path operand has been parsed so far.
     PathOperatorOrEnd,
     /// The first path operand and a `::` path operator have been parsed so far.
     PathOperand,
+    /// The `(` or `{path}(` or the element pattern with a comma have been parsed so far.
+    ElementOrParenthesisRight,
+    /// The element pattern has been parsed so far.
+    CommaOrParenthesisRight,
+    /// The `{path} {` or the field pattern with a comma have been parsed so far.
+    FieldOrBracketCurlyRight,
+    /// The field identifier has been parsed so far.
+    ColonOrCommaOrBracketCurlyRight,
+    /// The field identifier and `:` have been parsed so far.
+    FieldPattern,
+    /// The field pattern has been parsed so far.
+    CommaOrBracketCurlyRight,
+    /// The `..` has been parsed so far.
+    BracketCurlyRight,
 }
 
 impl Default for State {
@@ -50,6 +69,10 @@ pub struct Parser {
     state: State,
     /// The builder of the parsed value.
     builder: MatchPatternBuilder,
+    /// The field identifier, whose pattern is being parsed.
+    field: Option<Identifier>,
+    /// Whether the range pattern end is included with `..=`.
+    is_range_inclusive: bool,
     /// The token returned from a subparser.
     next: Option<Token>,
 }
@@ -63,8 +86,60 @@ impl Parser {
     /// 'variable'
     /// 'Path::To::Item'
     /// '_'
+    /// '(value, 42, _)'
+    /// 'Path::To::Variant(value, _)'
+    /// 'Path::To::Structure { a, b: 42, .. }'
+    /// '0..=9'
+    /// '1 | 2 | Path::To::Item'
     ///
     pub fn parse(
+        self,
+        stream: Rc<RefCell<TokenStream>>,
+        initial: Option<Token>,
+    ) -> Result<(MatchPattern, Option<Token>), ParsingError> {
+        let (pattern, mut next) = self.parse_alternative(stream.clone(), initial)?;
+        let location = pattern.location;
+        let mut alternatives = vec![pattern];
+
+        loop {
+            let is_alternative = match next.as_ref() {
+                Some(token) => Self::is_vertical_bar(token),
+                None => Self::is_vertical_bar(stream.borrow_mut().look_ahead(1)?),
+            };
+            if !is_alternative {
+                break;
+            }
+            if next.take().is_none() {
+                stream.borrow_mut().next()?;
+            }
+
+            let (pattern, next_token) = Self::default().parse_alternative(stream.clone(), None)?;
+            next = next_token;
+            alternatives.push(pattern);
+        }
+
+        if alternatives.len() == 1 {
+            return Ok((
+                alternatives
+                    .pop()
+                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS),
+                next,
+            ));
+        }
+
+        Ok((
+            MatchPattern::new(
+                location,
+                MatchPatternVariant::new_alternatives(alternatives),
+            ),
+            next,
+        ))
+    }
+
+    ///
+    /// Parses a single match pattern alternative, that is, a pattern without `|`.
+    ///
+    fn parse_alternative(
         mut self,
         stream: Rc<RefCell<TokenStream>>,
         initial: Option<Token>,
@@ -91,7 +166,7 @@ impl Parser {
                             self.builder.set_location(location);
                             self.builder
                                 .set_integer_literal(IntegerLiteral::new(location, integer));
-                            return Ok((self.builder.finish(), None));
+                            self.state = State::RangeOperatorOrEnd;
                         }
                         Token {
                             lexeme: Lexeme::Identifier(identifier),
@@ -131,6 +206,14 @@ impl Parser {
                             self.builder.set_wildcard();
                             return Ok((self.builder.finish(), None));
                         }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                            location,
+                        } => {
+                            self.builder.set_location(location);
+                            self.builder.set_elements();
+                            self.state = State::ElementOrParenthesisRight;
+                        }
                         Token { lexeme, location } => {
                             return Err(ParsingError::Syntax(SyntaxError::expected_match_pattern(
                                 location, lexeme,
@@ -138,6 +221,38 @@ impl Parser {
                         }
                     }
                 }
+                State::RangeOperatorOrEnd => {
+                    self.is_range_inclusive = match stream.borrow_mut().look_ahead(1)? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::DoubleDot),
+                            ..
+                        } => false,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::DoubleDotEquals),
+                            ..
+                        } => true,
+                        _ => return Ok((self.builder.finish(), None)),
+                    };
+                    stream.borrow_mut().next()?;
+                    self.state = State::RangeEnd;
+                }
+                State::RangeEnd => {
+                    return match crate::parser::take_or_next(self.next.take(), stream)? {
+                        Token {
+                            lexeme: Lexeme::Literal(LexicalLiteral::Integer(integer)),
+                            location,
+                        } => {
+                            self.builder.set_range_end(
+                                IntegerLiteral::new(location, integer),
+                                self.is_range_inclusive,
+                            );
+                            Ok((self.builder.finish(), None))
+                        }
+                        Token { lexeme, location } => Err(ParsingError::Syntax(
+                            SyntaxError::expected_integer_literal(location, lexeme),
+                        )),
+                    };
+                }
                 State::PathOperatorOrEnd => {
                     match crate::parser::take_or_next(self.next.take(), stream.clone())? {
                         Token {
@@ -148,6 +263,20 @@ impl Parser {
                                 .push_path_operator(ExpressionOperator::Path, location);
                             self.state = State::PathOperand;
                         }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                            ..
+                        } => {
+                            self.builder.set_elements();
+                            self.state = State::ElementOrParenthesisRight;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                            ..
+                        } => {
+                            self.builder.set_fields();
+                            self.state = State::FieldOrBracketCurlyRight;
+                        }
                         token => return Ok((self.builder.finish(), Some(token))),
                     }
                 }
@@ -158,14 +287,156 @@ impl Parser {
                     self.builder.push_path_element(expression);
                     self.state = State::PathOperatorOrEnd;
                 }
+                State::ElementOrParenthesisRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        token => {
+                            let (element, next) =
+                                Self::default().parse(stream.clone(), Some(token))?;
+                            self.next = next;
+                            self.builder.push_element(element);
+                            self.state = State::CommaOrParenthesisRight;
+                        }
+                    }
+                }
+                State::CommaOrParenthesisRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => {
+                            self.builder.set_comma();
+                            self.state = State::ElementOrParenthesisRight;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![",", ")"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::FieldOrBracketCurlyRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::DoubleDot),
+                            ..
+                        } => {
+                            self.builder.set_rest_ignored();
+                            self.state = State::BracketCurlyRight;
+                        }
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            self.field = Some(Identifier::new(location, identifier.inner));
+                            self.state = State::ColonOrCommaOrBracketCurlyRight;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+                                location, lexeme, None,
+                            )));
+                        }
+                    }
+                }
+                State::ColonOrCommaOrBracketCurlyRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Colon),
+                            ..
+                        } => self.state = State::FieldPattern,
+                        token => {
+                            let identifier = self
+                                .field
+                                .take()
+                                .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+                            let pattern = MatchPattern::new(
+                                identifier.location,
+                                MatchPatternVariant::Binding(identifier.clone()),
+                            );
+                            self.builder.push_field(identifier, pattern);
+                            self.next = Some(token);
+                            self.state = State::CommaOrBracketCurlyRight;
+                        }
+                    }
+                }
+                State::FieldPattern => {
+                    let (pattern, next) =
+                        Self::default().parse(stream.clone(), self.next.take())?;
+                    self.next = next;
+                    let identifier = self
+                        .field
+                        .take()
+                        .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+                    self.builder.push_field(identifier, pattern);
+                    self.state = State::CommaOrBracketCurlyRight;
+                }
+                State::CommaOrBracketCurlyRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => self.state = State::FieldOrBracketCurlyRight,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![",", "}"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::BracketCurlyRight => {
+                    return match crate::parser::take_or_next(self.next.take(), stream)? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            ..
+                        } => Ok((self.builder.finish(), None)),
+                        Token { lexeme, location } => Err(ParsingError::Syntax(
+                            SyntaxError::expected_one_of(location, vec!["}"], lexeme, None),
+                        )),
+                    };
+                }
             }
         }
     }
+
+    ///
+    /// Checks whether the token is the `|` alternative separator.
+    ///
+    fn is_vertical_bar(token: &Token) -> bool {
+        matches!(
+            token,
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::VerticalBar),
+                ..
+            }
+        )
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use zinc_lexical::BooleanLiteral as LexicalBooleanLiteral;
+    use zinc_lexical::Identifier as LexicalIdentifier;
     use zinc_lexical::IntegerLiteral as LexicalIntegerLiteral;
     use zinc_lexical::Keyword;
     use zinc_lexical::Lexeme;
@@ -174,6 +445,8 @@ mod tests {
     use zinc_lexical::TokenStream;
 
     use super::Parser;
+    use crate::error::Error as SyntaxError;
+    use crate::error::ParsingError;
     use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
     use crate::tree::expression::tree::node::operator::Operator as ExpressionOperator;
     use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
@@ -334,4 +607,346 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn ok_tuple() {
+        let input = r#"(value, 42, _)"#;
+
+        let expected = Ok((
+            MatchPattern::new(
+                Location::test(1, 1),
+                MatchPatternVariant::Tuple(vec![
+                    MatchPattern::new(
+                        Location::test(1, 2),
+                        MatchPatternVariant::Binding(Identifier::new(
+                            Location::test(1, 2),
+                            "value".to_owned(),
+                        )),
+                    ),
+                    MatchPattern::new(
+                        Location::test(1, 9),
+                        MatchPatternVariant::IntegerLiteral(IntegerLiteral::new(
+                            Location::test(1, 9),
+                            LexicalIntegerLiteral::new_decimal("42".to_owned()),
+                        )),
+                    ),
+                    MatchPattern::new(Location::test(1, 13), MatchPatternVariant::Wildcard),
+                ]),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_tuple_single() {
+        let input = r#"(value,)"#;
+
+        let expected = Ok((
+            MatchPattern::new(
+                Location::test(1, 1),
+                MatchPatternVariant::Tuple(vec![MatchPattern::new(
+                    Location::test(1, 2),
+                    MatchPatternVariant::Binding(Identifier::new(
+                        Location::test(1, 2),
+                        "value".to_owned(),
+                    )),
+                )]),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_parenthesized() {
+        let input = r#"(_)"#;
+
+        let expected = Ok((
+            MatchPattern::new(Location::test(1, 2), MatchPatternVariant::Wildcard),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_tuple_variant() {
+        let input = r#"Option::Some(value)"#;
+
+        let expected = Ok((
+            MatchPattern::new(
+                Location::test(1, 1),
+                MatchPatternVariant::TupleVariant {
+                    path: ExpressionTree::new_with_leaves(
+                        Location::test(1, 7),
+                        ExpressionTreeNode::operator(ExpressionOperator::Path),
+                        Some(ExpressionTree::new(
+                            Location::test(1, 1),
+                            ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                Identifier::new(Location::test(1, 1), "Option".to_owned()),
+                            )),
+                        )),
+                        Some(ExpressionTree::new(
+                            Location::test(1, 9),
+                            ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                Identifier::new(Location::test(1, 9), "Some".to_owned()),
+                            )),
+                        )),
+                    ),
+                    elements: vec![MatchPattern::new(
+                        Location::test(1, 14),
+                        MatchPatternVariant::Binding(Identifier::new(
+                            Location::test(1, 14),
+                            "value".to_owned(),
+                        )),
+                    )],
+                },
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_structure() {
+        let input = r#"Point { x, y: 0, .. }"#;
+
+        let expected = Ok((
+            MatchPattern::new(
+                Location::test(1, 1),
+                MatchPatternVariant::Structure {
+                    path: ExpressionTree::new(
+                        Location::test(1, 1),
+                        ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                            Identifier::new(Location::test(1, 1), "Point".to_owned()),
+                        )),
+                    ),
+                    fields: vec![
+                        (
+                            Identifier::new(Location::test(1, 9), "x".to_owned()),
+                            MatchPattern::new(
+                                Location::test(1, 9),
+                                MatchPatternVariant::Binding(Identifier::new(
+                                    Location::test(1, 9),
+                                    "x".to_owned(),
+                                )),
+                            ),
+                        ),
+                        (
+                            Identifier::new(Location::test(1, 12), "y".to_owned()),
+                            MatchPattern::new(
+                                Location::test(1, 15),
+                                MatchPatternVariant::IntegerLiteral(IntegerLiteral::new(
+                                    Location::test(1, 15),
+                                    LexicalIntegerLiteral::new_decimal("0".to_owned()),
+                                )),
+                            ),
+                        ),
+                    ],
+                    is_rest_ignored: true,
+                },
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_range() {
+        let input = r#"0..10"#;
+
+        let expected = Ok((
+            MatchPattern::new(
+                Location::test(1, 1),
+                MatchPatternVariant::Range {
+                    start: IntegerLiteral::new(
+                        Location::test(1, 1),
+                        LexicalIntegerLiteral::new_decimal("0".to_owned()),
+                    ),
+                    end: IntegerLiteral::new(
+                        Location::test(1, 4),
+                        LexicalIntegerLiteral::new_decimal("10".to_owned()),
+                    ),
+                    is_inclusive: false,
+                },
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_range_inclusive() {
+        let input = r#"0..=9"#;
+
+        let expected = Ok((
+            MatchPattern::new(
+                Location::test(1, 1),
+                MatchPatternVariant::Range {
+                    start: IntegerLiteral::new(
+                        Location::test(1, 1),
+                        LexicalIntegerLiteral::new_decimal("0".to_owned()),
+                    ),
+                    end: IntegerLiteral::new(
+                        Location::test(1, 5),
+                        LexicalIntegerLiteral::new_decimal("9".to_owned()),
+                    ),
+                    is_inclusive: true,
+                },
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_alternatives() {
+        let input = r#"1 | 2..=5 | _"#;
+
+        let expected = Ok((
+            MatchPattern::new(
+                Location::test(1, 1),
+                MatchPatternVariant::Alternatives(vec![
+                    MatchPattern::new(
+                        Location::test(1, 1),
+                        MatchPatternVariant::IntegerLiteral(IntegerLiteral::new(
+                            Location::test(1, 1),
+                            LexicalIntegerLiteral::new_decimal("1".to_owned()),
+                        )),
+                    ),
+                    MatchPattern::new(
+                        Location::test(1, 5),
+                        MatchPatternVariant::Range {
+                            start: IntegerLiteral::new(
+                                Location::test(1, 5),
+                                LexicalIntegerLiteral::new_decimal("2".to_owned()),
+                            ),
+                            end: IntegerLiteral::new(
+                                Location::test(1, 9),
+                                LexicalIntegerLiteral::new_decimal("5".to_owned()),
+                            ),
+                            is_inclusive: true,
+                        },
+                    ),
+                    MatchPattern::new(Location::test(1, 13), MatchPatternVariant::Wildcard),
+                ]),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_alternatives_nested() {
+        let input = r#"(true, a | b)"#;
+
+        let expected = Ok((
+            MatchPattern::new(
+                Location::test(1, 1),
+                MatchPatternVariant::Tuple(vec![
+                    MatchPattern::new(
+                        Location::test(1, 2),
+                        MatchPatternVariant::BooleanLiteral(BooleanLiteral::new(
+                            Location::test(1, 2),
+                            LexicalBooleanLiteral::r#true(),
+                        )),
+                    ),
+                    MatchPattern::new(
+                        Location::test(1, 8),
+                        MatchPatternVariant::Alternatives(vec![
+                            MatchPattern::new(
+                                Location::test(1, 8),
+                                MatchPatternVariant::Binding(Identifier::new(
+                                    Location::test(1, 8),
+                                    "a".to_owned(),
+                                )),
+                            ),
+                            MatchPattern::new(
+                                Location::test(1, 12),
+                                MatchPatternVariant::Binding(Identifier::new(
+                                    Location::test(1, 12),
+                                    "b".to_owned(),
+                                )),
+                            ),
+                        ]),
+                    ),
+                ]),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_comma_or_parenthesis_right() {
+        let input = r#"(a b)"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 4),
+            vec![",", ")"],
+            Lexeme::Identifier(LexicalIdentifier::new("b".to_owned())),
+            None,
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_bracket_curly_right() {
+        let input = r#"Point { x, .. y }"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 15),
+            vec!["}"],
+            Lexeme::Identifier(LexicalIdentifier::new("y".to_owned())),
+            None,
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_integer_literal() {
+        let input = r#"0..=x"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_integer_literal(
+            Location::test(1, 5),
+            Lexeme::Identifier(LexicalIdentifier::new("x".to_owned())),
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
 }
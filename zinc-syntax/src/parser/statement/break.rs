@@ -0,0 +1,107 @@
+//!
+//! The `break` statement parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Keyword;
+use zinc_lexical::Lexeme;
+use zinc_lexical::Symbol;
+use zinc_lexical::Token;
+use zinc_lexical::TokenStream;
+
+use crate::error::Error as SyntaxError;
+use crate::error::ParsingError;
+use crate::tree::statement::r#break::Statement as BreakStatement;
+
+///
+/// The `break` statement parser.
+///
+#[derive(Default)]
+pub struct Parser {
+    /// The token returned from a subparser.
+    next: Option<Token>,
+}
+
+impl Parser {
+    ///
+    /// Parses a 'break' statement.
+    ///
+    /// 'break;'
+    ///
+    pub fn parse(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        initial: Option<Token>,
+    ) -> Result<(BreakStatement, Option<Token>), ParsingError> {
+        self.next = initial;
+
+        let location = match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Break),
+                location,
+            } => location,
+            Token { lexeme, location } => {
+                return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                    location,
+                    vec!["break"],
+                    lexeme,
+                    None,
+                )));
+            }
+        };
+
+        match crate::parser::take_or_next(self.next.take(), stream)? {
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::Semicolon),
+                ..
+            } => Ok((BreakStatement::new(location), None)),
+            Token { lexeme, location } => Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                location,
+                vec![";"],
+                lexeme,
+                None,
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinc_lexical::Lexeme;
+    use zinc_lexical::Location;
+    use zinc_lexical::TokenStream;
+
+    use super::Parser;
+    use crate::error::Error as SyntaxError;
+    use crate::error::ParsingError;
+    use crate::tree::statement::r#break::Statement as BreakStatement;
+
+    #[test]
+    fn ok() {
+        let input = r#"break;"#;
+
+        let expected = Ok((BreakStatement::new(Location::test(1, 1)), None));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_semicolon() {
+        let input = r#"break"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 6),
+            vec![";"],
+            Lexeme::Eof,
+            None,
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+}
@@ -0,0 +1,107 @@
+//!
+//! The `continue` statement parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Keyword;
+use zinc_lexical::Lexeme;
+use zinc_lexical::Symbol;
+use zinc_lexical::Token;
+use zinc_lexical::TokenStream;
+
+use crate::error::Error as SyntaxError;
+use crate::error::ParsingError;
+use crate::tree::statement::r#continue::Statement as ContinueStatement;
+
+///
+/// The `continue` statement parser.
+///
+#[derive(Default)]
+pub struct Parser {
+    /// The token returned from a subparser.
+    next: Option<Token>,
+}
+
+impl Parser {
+    ///
+    /// Parses a 'continue' statement.
+    ///
+    /// 'continue;'
+    ///
+    pub fn parse(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        initial: Option<Token>,
+    ) -> Result<(ContinueStatement, Option<Token>), ParsingError> {
+        self.next = initial;
+
+        let location = match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Continue),
+                location,
+            } => location,
+            Token { lexeme, location } => {
+                return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                    location,
+                    vec!["continue"],
+                    lexeme,
+                    None,
+                )));
+            }
+        };
+
+        match crate::parser::take_or_next(self.next.take(), stream)? {
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::Semicolon),
+                ..
+            } => Ok((ContinueStatement::new(location), None)),
+            Token { lexeme, location } => Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                location,
+                vec![";"],
+                lexeme,
+                None,
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinc_lexical::Lexeme;
+    use zinc_lexical::Location;
+    use zinc_lexical::TokenStream;
+
+    use super::Parser;
+    use crate::error::Error as SyntaxError;
+    use crate::error::ParsingError;
+    use crate::tree::statement::r#continue::Statement as ContinueStatement;
+
+    #[test]
+    fn ok() {
+        let input = r#"continue;"#;
+
+        let expected = Ok((ContinueStatement::new(Location::test(1, 1)), None));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_semicolon() {
+        let input = r#"continue"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 9),
+            vec![";"],
+            Lexeme::Eof,
+            None,
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+}
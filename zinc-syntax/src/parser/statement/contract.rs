@@ -404,6 +404,7 @@ mod tests {
                     false,
                     false,
                     Identifier::new(Location::test(3, 12), "f".to_owned()),
+                    vec![],
                     vec![Binding::new(
                         Location::test(3, 14),
                         BindingPattern::new(
@@ -450,6 +451,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(3, 12), "f1".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(3, 15),
                             BindingPattern::new(
@@ -470,6 +472,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(5, 12), "f2".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(5, 15),
                             BindingPattern::new(
@@ -490,6 +493,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(7, 12), "f3".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(7, 15),
                             BindingPattern::new(
@@ -557,6 +561,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(7, 12), "f1".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(7, 15),
                             BindingPattern::new(
@@ -672,6 +677,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(11, 12), "f1".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(11, 15),
                             BindingPattern::new(
@@ -692,6 +698,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(13, 12), "f2".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(13, 15),
                             BindingPattern::new(
@@ -712,6 +719,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(15, 12), "f3".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(15, 15),
                             BindingPattern::new(
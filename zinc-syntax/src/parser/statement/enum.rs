@@ -164,6 +164,7 @@ mod tests {
     use crate::tree::identifier::Identifier;
     use crate::tree::literal::integer::Literal as IntegerLiteral;
     use crate::tree::statement::r#enum::Statement as EnumStatement;
+    use crate::tree::variant::payload::Payload;
     use crate::tree::variant::Variant;
 
     #[test]
@@ -224,10 +225,11 @@ mod tests {
                 vec![Variant::new(
                     Location::test(3, 9),
                     Identifier::new(Location::test(3, 9), "A".to_owned()),
-                    IntegerLiteral::new(
+                    Some(IntegerLiteral::new(
                         Location::test(3, 13),
                         LexicalIntegerLiteral::new_decimal("1".to_owned()),
-                    ),
+                    )),
+                    Payload::Unit,
                 )],
             ),
             None,
@@ -256,26 +258,29 @@ mod tests {
                     Variant::new(
                         Location::test(3, 9),
                         Identifier::new(Location::test(3, 9), "A".to_owned()),
-                        IntegerLiteral::new(
+                        Some(IntegerLiteral::new(
                             Location::test(3, 13),
                             LexicalIntegerLiteral::new_decimal("1".to_owned()),
-                        ),
+                        )),
+                        Payload::Unit,
                     ),
                     Variant::new(
                         Location::test(4, 9),
                         Identifier::new(Location::test(4, 9), "B".to_owned()),
-                        IntegerLiteral::new(
+                        Some(IntegerLiteral::new(
                             Location::test(4, 13),
                             LexicalIntegerLiteral::new_decimal("2".to_owned()),
-                        ),
+                        )),
+                        Payload::Unit,
                     ),
                     Variant::new(
                         Location::test(5, 9),
                         Identifier::new(Location::test(5, 9), "C".to_owned()),
-                        IntegerLiteral::new(
+                        Some(IntegerLiteral::new(
                             Location::test(5, 13),
                             LexicalIntegerLiteral::new_decimal("3".to_owned()),
-                        ),
+                        )),
+                        Payload::Unit,
                     ),
                 ],
             ),
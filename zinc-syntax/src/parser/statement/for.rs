@@ -15,14 +15,10 @@ use crate::error::Error as SyntaxError;
 use crate::error::ParsingError;
 use crate::parser::expression::terminal::block::Parser as BlockExpressionParser;
 use crate::parser::expression::Parser as ExpressionParser;
-use crate::tree::identifier::Identifier;
+use crate::parser::pattern_binding::Parser as BindingPatternParser;
 use crate::tree::statement::r#for::builder::Builder as ForStatementBuilder;
 use crate::tree::statement::r#for::Statement as ForStatement;
 
-/// The missing index identifier error hint.
-pub static HINT_EXPECTED_INDEX_IDENTIFIER: &str =
-    "for-loops must have the index identifier, e.g. `for i in 0..10 { ... }`";
-
 ///
 /// The parser state.
 ///
@@ -31,16 +27,16 @@ pub enum State {
     /// The initial state.
     KeywordFor,
     /// The `for` has been parsed so far.
-    IndexIdentifier,
-    /// The `for {identifier}` has been parsed so far.
+    BindingPattern,
+    /// The `for {pattern}` has been parsed so far.
     KeywordIn,
-    /// The `for {identifier} in` has been parsed so far.
+    /// The `for {pattern} in` has been parsed so far.
     BoundsExpression,
-    /// The `for {identifier} in {expression}` has been parsed so far.
+    /// The `for {pattern} in {expression}` has been parsed so far.
     BlockExpressionOrKeywordWhile,
-    /// The `for {identifier} in {expression} while` has been parsed so far.
+    /// The `for {pattern} in {expression} while` has been parsed so far.
     WhileConditionExpression,
-    /// The `for {identifier} in {expression}` with optional `while {expression}` has been parsed so far.
+    /// The `for {pattern} in {expression}` with optional `while {expression}` has been parsed so far.
     BlockExpression,
 }
 
@@ -73,6 +69,12 @@ impl Parser {
     /// }
     /// '
     ///
+    /// '
+    /// for (index, item) in array.enumerate() {
+    ///     x += item;
+    /// }
+    /// '
+    ///
     pub fn parse(
         mut self,
         stream: Rc<RefCell<TokenStream>>,
@@ -89,7 +91,7 @@ impl Parser {
                             location,
                         } => {
                             self.builder.set_location(location);
-                            self.state = State::IndexIdentifier;
+                            self.state = State::BindingPattern;
                         }
                         Token { lexeme, location } => {
                             return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
@@ -101,24 +103,12 @@ impl Parser {
                         }
                     }
                 }
-                State::IndexIdentifier => {
-                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
-                        Token {
-                            lexeme: Lexeme::Identifier(identifier),
-                            location,
-                        } => {
-                            let identifier = Identifier::new(location, identifier.inner);
-                            self.builder.set_index_identifier(identifier);
-                            self.state = State::KeywordIn;
-                        }
-                        Token { lexeme, location } => {
-                            return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
-                                location,
-                                lexeme,
-                                Some(HINT_EXPECTED_INDEX_IDENTIFIER),
-                            )));
-                        }
-                    }
+                State::BindingPattern => {
+                    let (pattern, next) =
+                        BindingPatternParser::default().parse(stream.clone(), self.next.take())?;
+                    self.next = next;
+                    self.builder.set_pattern(pattern);
+                    self.state = State::KeywordIn;
                 }
                 State::KeywordIn => {
                     match crate::parser::take_or_next(self.next.take(), stream.clone())? {
@@ -210,6 +200,8 @@ mod tests {
     use crate::tree::expression::tree::Tree as ExpressionTree;
     use crate::tree::identifier::Identifier;
     use crate::tree::literal::integer::Literal as IntegerLiteral;
+    use crate::tree::pattern_binding::variant::Variant as BindingPatternVariant;
+    use crate::tree::pattern_binding::Pattern as BindingPattern;
     use crate::tree::statement::r#for::Statement as ForStatement;
 
     #[test]
@@ -219,7 +211,13 @@ mod tests {
         let expected = Ok((
             ForStatement::new(
                 Location::test(1, 1),
-                Identifier::new(Location::test(1, 5), "i".to_owned()),
+                BindingPattern::new(
+                    Location::test(1, 5),
+                    BindingPatternVariant::new_binding(
+                        Identifier::new(Location::test(1, 5), "i".to_owned()),
+                        false,
+                    ),
+                ),
                 ExpressionTree::new_with_leaves(
                     Location::test(1, 11),
                     ExpressionTreeNode::operator(ExpressionOperator::Range),
@@ -260,7 +258,13 @@ mod tests {
         let expected = Ok((
             ForStatement::new(
                 Location::test(1, 1),
-                Identifier::new(Location::test(1, 5), "i".to_owned()),
+                BindingPattern::new(
+                    Location::test(1, 5),
+                    BindingPatternVariant::new_binding(
+                        Identifier::new(Location::test(1, 5), "i".to_owned()),
+                        false,
+                    ),
+                ),
                 ExpressionTree::new_with_leaves(
                     Location::test(1, 11),
                     ExpressionTreeNode::operator(ExpressionOperator::RangeInclusive),
@@ -320,13 +324,56 @@ mod tests {
     }
 
     #[test]
-    fn error_expected_identifier() {
+    fn ok_binding_list() {
+        let input = r#"for (i, item) in array {}"#;
+
+        let expected = Ok((
+            ForStatement::new(
+                Location::test(1, 1),
+                BindingPattern::new(
+                    Location::test(1, 5),
+                    BindingPatternVariant::new_binding_list(vec![
+                        BindingPattern::new(
+                            Location::test(1, 6),
+                            BindingPatternVariant::new_binding(
+                                Identifier::new(Location::test(1, 6), "i".to_owned()),
+                                false,
+                            ),
+                        ),
+                        BindingPattern::new(
+                            Location::test(1, 9),
+                            BindingPatternVariant::new_binding(
+                                Identifier::new(Location::test(1, 9), "item".to_owned()),
+                                false,
+                            ),
+                        ),
+                    ]),
+                ),
+                ExpressionTree::new(
+                    Location::test(1, 18),
+                    ExpressionTreeNode::operand(ExpressionOperand::Identifier(Identifier::new(
+                        Location::test(1, 18),
+                        "array".to_owned(),
+                    ))),
+                ),
+                None,
+                BlockExpression::new(Location::test(1, 24), vec![], None),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_binding_pattern() {
         let input = r#"for { 2 + 2 }"#;
 
-        let expected = Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_binding_pattern(
             Location::test(1, 5),
             Lexeme::Symbol(Symbol::BracketCurlyLeft),
-            Some(super::HINT_EXPECTED_INDEX_IDENTIFIER),
         )));
 
         let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
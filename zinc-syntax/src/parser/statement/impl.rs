@@ -13,6 +13,8 @@ use zinc_lexical::TokenStream;
 
 use crate::error::Error as SyntaxError;
 use crate::error::ParsingError;
+use crate::parser::generic_parameter_list::Parser as GenericParameterListParser;
+use crate::parser::r#type::generics::Parser as GenericsParser;
 use crate::parser::statement::local_impl::Parser as ImplementationLocalStatementParser;
 use crate::tree::identifier::Identifier;
 use crate::tree::statement::r#impl::builder::Builder as ImplStatementBuilder;
@@ -21,6 +23,9 @@ use crate::tree::statement::r#impl::Statement as ImplStatement;
 /// The missing identifier error hint.
 pub static HINT_EXPECTED_IDENTIFIER: &str =
     "type implementation must have an identifier, e.g. `impl Data { ... }`";
+/// The missing type identifier after the trait identifier error hint.
+pub static HINT_EXPECTED_TYPE_IDENTIFIER: &str =
+    "trait implementation must have a type identifier, e.g. `impl Hashable for Data { ... }`";
 
 ///
 /// The parser state.
@@ -30,10 +35,19 @@ pub enum State {
     /// The initial state.
     KeywordImpl,
     /// The `impl` has been parsed so far.
+    GenericsOrIdentifier,
+    /// The `impl` with optional `<{generics}>` has been parsed so far.
     Identifier,
     /// The `impl {identifier}` has been parsed so far.
+    KeywordForOrGenericsOrBracketCurlyLeft,
+    /// The `impl {trait} for` has been parsed so far.
+    TypeIdentifier,
+    /// The `impl {trait} for {identifier}` has been parsed so far.
+    GenericsOrBracketCurlyLeft,
+    /// The `impl {identifier}` or `impl {trait} for {identifier}` with optional `<{arguments}>`
+    /// has been parsed so far.
     BracketCurlyLeft,
-    /// The `impl {identifier} {` has been parsed so far.
+    /// The `impl {identifier} {` or `impl {trait} for {identifier} {` has been parsed so far.
     StatementOrBracketCurlyRight,
 }
 
@@ -68,6 +82,22 @@ impl Parser {
     /// }
     /// '
     ///
+    /// '
+    /// impl Hashable for Data {
+    ///     fn hash(self) -> field {
+    ///         self.value
+    ///     }
+    /// }
+    /// '
+    ///
+    /// '
+    /// impl<T> Pair<T> {
+    ///     fn first(self) -> T {
+    ///         self.first
+    ///     }
+    /// }
+    /// '
+    ///
     pub fn parse(
         mut self,
         stream: Rc<RefCell<TokenStream>>,
@@ -84,7 +114,7 @@ impl Parser {
                             location,
                         } => {
                             self.builder.set_location(location);
-                            self.state = State::Identifier;
+                            self.state = State::GenericsOrIdentifier;
                         }
                         Token { lexeme, location } => {
                             return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
@@ -96,6 +126,23 @@ impl Parser {
                         }
                     }
                 }
+                State::GenericsOrIdentifier => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        token
+                        @
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Lesser),
+                            ..
+                        } => {
+                            let (generics, next) = GenericParameterListParser::default()
+                                .parse(stream.clone(), Some(token))?;
+                            self.builder.set_generics(generics);
+                            self.next = next;
+                        }
+                        token => self.next = Some(token),
+                    }
+                    self.state = State::Identifier;
+                }
                 State::Identifier => {
                     match crate::parser::take_or_next(self.next.take(), stream.clone())? {
                         Token {
@@ -104,7 +151,7 @@ impl Parser {
                         } => {
                             let identifier = Identifier::new(location, identifier.inner);
                             self.builder.set_identifier(identifier);
-                            self.state = State::BracketCurlyLeft;
+                            self.state = State::KeywordForOrGenericsOrBracketCurlyLeft;
                         }
                         Token { lexeme, location } => {
                             return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
@@ -115,6 +162,57 @@ impl Parser {
                         }
                     }
                 }
+                State::KeywordForOrGenericsOrBracketCurlyLeft => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::For),
+                            ..
+                        } => {
+                            self.builder.set_trait();
+                            self.state = State::TypeIdentifier;
+                        }
+                        token => {
+                            self.next = Some(token);
+                            self.state = State::GenericsOrBracketCurlyLeft;
+                        }
+                    }
+                }
+                State::TypeIdentifier => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            let identifier = Identifier::new(location, identifier.inner);
+                            self.builder.set_identifier(identifier);
+                            self.state = State::GenericsOrBracketCurlyLeft;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+                                location,
+                                lexeme,
+                                Some(HINT_EXPECTED_TYPE_IDENTIFIER),
+                            )));
+                        }
+                    }
+                }
+                State::GenericsOrBracketCurlyLeft => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        token
+                        @
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Lesser),
+                            ..
+                        } => {
+                            let (arguments, next) =
+                                GenericsParser::default().parse(stream.clone(), Some(token))?;
+                            self.builder.set_type_arguments(arguments);
+                            self.next = next;
+                        }
+                        token => self.next = Some(token),
+                    }
+                    self.state = State::BracketCurlyLeft;
+                }
                 State::BracketCurlyLeft => {
                     match crate::parser::take_or_next(self.next.take(), stream.clone())? {
                         Token {
@@ -161,6 +259,7 @@ mod tests {
     use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
     use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
     use crate::tree::expression::tree::Tree as ExpressionTree;
+    use crate::tree::generic_parameter::GenericParameter;
     use crate::tree::identifier::Identifier;
     use crate::tree::literal::integer::Literal as IntegerLiteral;
     use crate::tree::pattern_binding::variant::Variant as BindingPatternVariant;
@@ -181,7 +280,54 @@ mod tests {
         let expected = Ok((
             ImplStatement::new(
                 Location::test(2, 5),
+                vec![],
                 Identifier::new(Location::test(2, 10), "Test".to_owned()),
+                None,
+                None,
+                vec![],
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_generic() {
+        let input = r#"
+    impl<T> Pair<T, u8> {}
+"#;
+
+        let expected = Ok((
+            ImplStatement::new(
+                Location::test(2, 5),
+                vec![GenericParameter::new(
+                    Location::test(2, 10),
+                    Identifier::new(Location::test(2, 10), "T".to_owned()),
+                    None,
+                )],
+                Identifier::new(Location::test(2, 13), "Pair".to_owned()),
+                Some(vec![
+                    Type::new(
+                        Location::test(2, 18),
+                        TypeVariant::alias(
+                            ExpressionTree::new(
+                                Location::test(2, 18),
+                                ExpressionTreeNode::operand(ExpressionOperand::Identifier(
+                                    Identifier::new(Location::test(2, 18), "T".to_owned()),
+                                )),
+                            ),
+                            None,
+                        ),
+                    ),
+                    Type::new(
+                        Location::test(2, 21),
+                        TypeVariant::integer_unsigned(zinc_const::bitlength::BYTE),
+                    ),
+                ]),
+                None,
                 vec![],
             ),
             None,
@@ -203,7 +349,10 @@ mod tests {
         let expected = Ok((
             ImplStatement::new(
                 Location::test(2, 5),
+                vec![],
                 Identifier::new(Location::test(2, 10), "Test".to_owned()),
+                None,
+                None,
                 vec![ImplementationLocalStatement::Const(ConstStatement::new(
                     Location::test(3, 9),
                     Identifier::new(Location::test(3, 15), "VALUE".to_owned()),
@@ -242,7 +391,10 @@ mod tests {
         let expected = Ok((
             ImplStatement::new(
                 Location::test(2, 5),
+                vec![],
                 Identifier::new(Location::test(2, 10), "Test".to_owned()),
+                None,
+                None,
                 vec![
                     ImplementationLocalStatement::Const(ConstStatement::new(
                         Location::test(3, 9),
@@ -307,12 +459,16 @@ mod tests {
         let expected = Ok((
             ImplStatement::new(
                 Location::test(2, 5),
+                vec![],
                 Identifier::new(Location::test(2, 10), "Test".to_owned()),
+                None,
+                None,
                 vec![ImplementationLocalStatement::Fn(FnStatement::new(
                     Location::test(3, 9),
                     false,
                     false,
                     Identifier::new(Location::test(3, 12), "f".to_owned()),
+                    vec![],
                     vec![Binding::new(
                         Location::test(3, 14),
                         BindingPattern::new(
@@ -352,13 +508,17 @@ mod tests {
         let expected = Ok((
             ImplStatement::new(
                 Location::test(2, 5),
+                vec![],
                 Identifier::new(Location::test(2, 10), "Test".to_owned()),
+                None,
+                None,
                 vec![
                     ImplementationLocalStatement::Fn(FnStatement::new(
                         Location::test(3, 9),
                         false,
                         false,
                         Identifier::new(Location::test(3, 12), "f1".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(3, 15),
                             BindingPattern::new(
@@ -379,6 +539,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(5, 12), "f2".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(5, 15),
                             BindingPattern::new(
@@ -399,6 +560,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(7, 12), "f3".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(7, 15),
                             BindingPattern::new(
@@ -437,7 +599,10 @@ mod tests {
         let expected = Ok((
             ImplStatement::new(
                 Location::test(2, 5),
+                vec![],
                 Identifier::new(Location::test(2, 10), "Test".to_owned()),
+                None,
+                None,
                 vec![
                     ImplementationLocalStatement::Const(ConstStatement::new(
                         Location::test(3, 9),
@@ -458,6 +623,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(5, 12), "f".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(5, 14),
                             BindingPattern::new(
@@ -504,7 +670,10 @@ mod tests {
         let expected = Ok((
             ImplStatement::new(
                 Location::test(2, 5),
+                vec![],
                 Identifier::new(Location::test(2, 10), "Test".to_owned()),
+                None,
+                None,
                 vec![
                     ImplementationLocalStatement::Const(ConstStatement::new(
                         Location::test(3, 9),
@@ -553,6 +722,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(9, 12), "f1".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(9, 15),
                             BindingPattern::new(
@@ -573,6 +743,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(11, 12), "f2".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(11, 15),
                             BindingPattern::new(
@@ -593,6 +764,7 @@ mod tests {
                         false,
                         false,
                         Identifier::new(Location::test(13, 12), "f3".to_owned()),
+                        vec![],
                         vec![Binding::new(
                             Location::test(13, 15),
                             BindingPattern::new(
@@ -632,4 +804,67 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn ok_trait_single_function() {
+        let input = r#"
+    impl Hashable for Test {
+        fn f(a: field) -> field {}
+    }
+"#;
+
+        let expected = Ok((
+            ImplStatement::new(
+                Location::test(2, 5),
+                vec![],
+                Identifier::new(Location::test(2, 23), "Test".to_owned()),
+                None,
+                Some(Identifier::new(
+                    Location::test(2, 10),
+                    "Hashable".to_owned(),
+                )),
+                vec![ImplementationLocalStatement::Fn(FnStatement::new(
+                    Location::test(3, 9),
+                    false,
+                    false,
+                    Identifier::new(Location::test(3, 12), "f".to_owned()),
+                    vec![],
+                    vec![Binding::new(
+                        Location::test(3, 14),
+                        BindingPattern::new(
+                            Location::test(3, 14),
+                            BindingPatternVariant::new_binding(
+                                Identifier::new(Location::test(3, 14), "a".to_owned()),
+                                false,
+                            ),
+                        ),
+                        Some(Type::new(Location::test(3, 17), TypeVariant::field())),
+                    )],
+                    Some(Type::new(Location::test(3, 27), TypeVariant::field())),
+                    BlockExpression::new(Location::test(3, 33), vec![], None),
+                    vec![],
+                ))],
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_type_identifier() {
+        let input = r#"impl Hashable for { const VALUE: u64 = 42; }"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+            Location::test(1, 19),
+            Lexeme::Symbol(Symbol::BracketCurlyLeft),
+            Some(super::HINT_EXPECTED_TYPE_IDENTIFIER),
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
 }
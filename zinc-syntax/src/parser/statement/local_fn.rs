@@ -13,9 +13,12 @@ use zinc_lexical::TokenStream;
 
 use crate::error::ParsingError;
 use crate::parser::expression::Parser as ExpressionParser;
+use crate::parser::statement::r#break::Parser as BreakStatementParser;
 use crate::parser::statement::r#const::Parser as ConstStatementParser;
+use crate::parser::statement::r#continue::Parser as ContinueStatementParser;
 use crate::parser::statement::r#for::Parser as ForStatementParser;
 use crate::parser::statement::r#let::Parser as LetStatementParser;
+use crate::parser::statement::r#return::Parser as ReturnStatementParser;
 use crate::tree::statement::local_fn::Statement as FunctionLocalStatement;
 
 ///
@@ -72,6 +75,39 @@ impl Parser {
                 self.next = next;
                 FunctionLocalStatement::For(statement)
             }
+            token
+            @
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Return),
+                ..
+            } => {
+                let (statement, next) =
+                    ReturnStatementParser::default().parse(stream.clone(), Some(token))?;
+                self.next = next;
+                FunctionLocalStatement::Return(statement)
+            }
+            token
+            @
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Break),
+                ..
+            } => {
+                let (statement, next) =
+                    BreakStatementParser::default().parse(stream.clone(), Some(token))?;
+                self.next = next;
+                FunctionLocalStatement::Break(statement)
+            }
+            token
+            @
+            Token {
+                lexeme: Lexeme::Keyword(Keyword::Continue),
+                ..
+            } => {
+                let (statement, next) =
+                    ContinueStatementParser::default().parse(stream.clone(), Some(token))?;
+                self.next = next;
+                FunctionLocalStatement::Continue(statement)
+            }
             Token {
                 lexeme: Lexeme::Symbol(Symbol::Semicolon),
                 location,
@@ -119,7 +155,9 @@ mod tests {
     use crate::tree::r#type::variant::Variant as TypeVariant;
     use crate::tree::r#type::Type;
     use crate::tree::statement::local_fn::Statement as FunctionLocalStatement;
+    use crate::tree::statement::r#break::Statement as BreakStatement;
     use crate::tree::statement::r#let::Statement as LetStatement;
+    use crate::tree::statement::r#return::Statement as ReturnStatement;
 
     #[test]
     fn ok_semicolon_terminated() {
@@ -190,4 +228,45 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn ok_return() {
+        let input = r#"return 42;"#;
+
+        let expected = Ok((
+            FunctionLocalStatement::Return(ReturnStatement::new(
+                Location::test(1, 1),
+                Some(ExpressionTree::new(
+                    Location::test(1, 8),
+                    ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                        IntegerLiteral::new(
+                            Location::test(1, 8),
+                            LexicalIntegerLiteral::new_decimal("42".to_owned()),
+                        ),
+                    )),
+                )),
+            )),
+            None,
+            false,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_break() {
+        let input = r#"break;"#;
+
+        let expected = Ok((
+            FunctionLocalStatement::Break(BreakStatement::new(Location::test(1, 1))),
+            None,
+            false,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
 }
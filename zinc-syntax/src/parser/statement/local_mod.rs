@@ -21,6 +21,7 @@ use crate::parser::statement::r#enum::Parser as EnumStatementParser;
 use crate::parser::statement::r#fn::Parser as FnStatementParser;
 use crate::parser::statement::r#impl::Parser as ImplStatementParser;
 use crate::parser::statement::r#struct::Parser as StructStatementParser;
+use crate::parser::statement::r#trait::Parser as TraitStatementParser;
 use crate::parser::statement::r#type::Parser as TypeStatementParser;
 use crate::parser::statement::r#use::Parser as UseStatementParser;
 use crate::tree::attribute::Attribute;
@@ -28,7 +29,7 @@ use crate::tree::statement::local_mod::Statement as ModuleLocalStatement;
 
 /// The invalid statement error hint.
 pub static HINT_ONLY_SOME_STATEMENTS: &str =
-    "only constants, types, functions, and type implementations may be declared at the module root";
+    "only constants, types, functions, traits, and type implementations may be declared at the module root";
 
 ///
 /// The parser state.
@@ -210,6 +211,16 @@ impl Parser {
                             .map(|(statement, next)| (ModuleLocalStatement::Use(statement), next)),
                         token
                         @
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Trait),
+                            ..
+                        } => TraitStatementParser::default()
+                            .parse(stream.clone(), Some(token))
+                            .map(|(statement, next)| {
+                                (ModuleLocalStatement::Trait(statement), next)
+                            }),
+                        token
+                        @
                         Token {
                             lexeme: Lexeme::Keyword(Keyword::Impl),
                             ..
@@ -234,7 +245,8 @@ impl Parser {
                             Err(ParsingError::Syntax(SyntaxError::expected_one_of(
                                 location,
                                 vec![
-                                    "type", "struct", "enum", "fn", "mod", "use", "impl", "const",
+                                    "type", "struct", "enum", "fn", "mod", "use", "trait", "impl",
+                                    "const",
                                 ],
                                 lexeme,
                                 Some(HINT_ONLY_SOME_STATEMENTS),
@@ -278,6 +290,7 @@ mod tests {
                 true,
                 false,
                 Identifier::new(Location::test(1, 8), "f".to_owned()),
+                vec![],
                 vec![Binding::new(
                     Location::test(1, 10),
                     BindingPattern::new(
@@ -311,6 +324,7 @@ mod tests {
                 false,
                 true,
                 Identifier::new(Location::test(1, 10), "f".to_owned()),
+                vec![],
                 vec![Binding::new(
                     Location::test(1, 12),
                     BindingPattern::new(
@@ -344,6 +358,7 @@ mod tests {
                 true,
                 true,
                 Identifier::new(Location::test(1, 14), "f".to_owned()),
+                vec![],
                 vec![Binding::new(
                     Location::test(1, 16),
                     BindingPattern::new(
@@ -381,6 +396,7 @@ fn test() {}
                 false,
                 Identifier::new(Location::test(3, 4), "test".to_owned()),
                 vec![],
+                vec![],
                 None,
                 BlockExpression::new(Location::test(3, 11), vec![], None),
                 vec![Attribute::new(
@@ -422,6 +438,7 @@ fn test() {}
                 false,
                 Identifier::new(Location::test(5, 4), "test".to_owned()),
                 vec![],
+                vec![],
                 None,
                 BlockExpression::new(Location::test(5, 11), vec![], None),
                 vec![
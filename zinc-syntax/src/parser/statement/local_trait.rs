@@ -0,0 +1,344 @@
+//!
+//! The trait-local statement parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Keyword;
+use zinc_lexical::Lexeme;
+use zinc_lexical::Symbol;
+use zinc_lexical::Token;
+use zinc_lexical::TokenStream;
+
+use crate::error::Error as SyntaxError;
+use crate::error::ParsingError;
+use crate::parser::binding_list::Parser as BindingListParser;
+use crate::parser::r#type::Parser as TypeParser;
+use crate::tree::identifier::Identifier;
+use crate::tree::statement::local_trait::Statement as TraitLocalStatement;
+use crate::tree::statement::r#trait::method::builder::Builder as TraitMethodBuilder;
+
+/// The invalid statement error hint.
+pub static HINT_ONLY_SOME_STATEMENTS: &str =
+    "only method signatures may be declared within a trait, e.g. `fn hash(self) -> field;`";
+/// The missing identifier error hint.
+pub static HINT_EXPECTED_IDENTIFIER: &str =
+    "method must have an identifier, e.g. `fn hash(self) -> field;`";
+/// The missing argument list error hint.
+pub static HINT_EXPECTED_ARGUMENT_LIST: &str =
+    "method must have the argument list, e.g. `fn hash(self) -> field;`";
+/// The method body error hint.
+pub static HINT_EXPECTED_SEMICOLON: &str =
+    "trait methods must be terminated with a semicolon and cannot have a body";
+
+///
+/// The parser state.
+///
+#[derive(Debug, Default, Clone, Copy)]
+pub enum State {
+    /// The initial state.
+    #[default]
+    KeywordConstOrNext,
+    /// The optional `const` has been parsed so far.
+    KeywordFnOrSemicolon,
+    /// The `fn` has been parsed so far.
+    Identifier,
+    /// The `fn {identifier}` has been parsed so far.
+    ParenthesisLeft,
+    /// The `fn {identifier} (` has been parsed so far.
+    ArgumentBindingList,
+    /// The `fn {identifier} ( {arguments}` has been parsed so far.
+    ParenthesisRight,
+    /// The `fn {identifier} ( {arguments} )` has been parsed so far.
+    ArrowOrSemicolon,
+    /// The `fn {identifier} ( {arguments} ) ->` has been parsed so far.
+    ReturnType,
+    /// The `fn {identifier} ( {arguments} )` with optional `-> {type}` has been parsed so far.
+    Semicolon,
+}
+
+///
+/// The trait-local statement parser.
+///
+#[derive(Default)]
+pub struct Parser {
+    /// The parser state.
+    state: State,
+    /// The builder of the parsed method signature.
+    builder: TraitMethodBuilder,
+    /// The `const` keyword token, which is stored to get its location as the statement location.
+    keyword_constant: Option<Token>,
+    /// The token returned from a subparser.
+    next: Option<Token>,
+}
+
+impl Parser {
+    ///
+    /// Parses a statement allowed in traits.
+    ///
+    /// '
+    /// fn hash(self) -> field;
+    /// '
+    ///
+    pub fn parse(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        initial: Option<Token>,
+    ) -> Result<(TraitLocalStatement, Option<Token>), ParsingError> {
+        self.next = initial;
+
+        loop {
+            match self.state {
+                State::KeywordConstOrNext => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        token @ Token {
+                            lexeme: Lexeme::Keyword(Keyword::Const),
+                            ..
+                        } => {
+                            self.builder.set_constant();
+                            self.keyword_constant = Some(token);
+                        }
+                        token => self.next = Some(token),
+                    }
+
+                    self.state = State::KeywordFnOrSemicolon;
+                }
+                State::KeywordFnOrSemicolon => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Fn),
+                            location,
+                        } => {
+                            self.builder.set_location(
+                                self.keyword_constant
+                                    .take()
+                                    .map(|token| token.location)
+                                    .unwrap_or(location),
+                            );
+                            self.state = State::Identifier;
+                        }
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Semicolon),
+                            location,
+                        } => return Ok((TraitLocalStatement::Empty(location), None)),
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["fn"],
+                                lexeme,
+                                Some(HINT_ONLY_SOME_STATEMENTS),
+                            )));
+                        }
+                    }
+                }
+                State::Identifier => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            let identifier = Identifier::new(location, identifier.inner);
+                            self.builder.set_identifier(identifier);
+                            self.state = State::ParenthesisLeft;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+                                location,
+                                lexeme,
+                                Some(HINT_EXPECTED_IDENTIFIER),
+                            )));
+                        }
+                    }
+                }
+                State::ParenthesisLeft => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                            ..
+                        } => self.state = State::ArgumentBindingList,
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["("],
+                                lexeme,
+                                Some(HINT_EXPECTED_ARGUMENT_LIST),
+                            )));
+                        }
+                    }
+                }
+                State::ArgumentBindingList => {
+                    let (argument_bindings, next) =
+                        BindingListParser::default().parse(stream.clone(), self.next.take())?;
+                    self.builder.set_argument_bindings(argument_bindings);
+                    self.next = next;
+                    self.state = State::ParenthesisRight;
+                }
+                State::ParenthesisRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                            ..
+                        } => self.state = State::ArrowOrSemicolon,
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![")"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::ArrowOrSemicolon => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::MinusGreater),
+                            ..
+                        } => self.state = State::ReturnType,
+                        token => {
+                            self.next = Some(token);
+                            self.state = State::Semicolon;
+                        }
+                    }
+                }
+                State::ReturnType => {
+                    let (r#type, next) =
+                        TypeParser::default().parse(stream.clone(), self.next.take())?;
+                    self.next = next;
+                    self.builder.set_return_type(r#type);
+                    self.state = State::Semicolon;
+                }
+                State::Semicolon => {
+                    return match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Semicolon),
+                            ..
+                        } => Ok((TraitLocalStatement::Fn(self.builder.finish()), None)),
+                        Token { lexeme, location } => {
+                            Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![";"],
+                                lexeme,
+                                Some(HINT_EXPECTED_SEMICOLON),
+                            )))
+                        }
+                    };
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinc_lexical::Lexeme;
+    use zinc_lexical::Location;
+    use zinc_lexical::Symbol;
+    use zinc_lexical::TokenStream;
+
+    use super::Parser;
+    use crate::error::Error as SyntaxError;
+    use crate::error::ParsingError;
+    use crate::tree::binding::Binding;
+    use crate::tree::identifier::Identifier;
+    use crate::tree::pattern_binding::variant::Variant as BindingPatternVariant;
+    use crate::tree::pattern_binding::Pattern as BindingPattern;
+    use crate::tree::r#type::variant::Variant as TypeVariant;
+    use crate::tree::r#type::Type;
+    use crate::tree::statement::local_trait::Statement as TraitLocalStatement;
+    use crate::tree::statement::r#trait::method::Method as TraitMethod;
+
+    #[test]
+    fn ok_fn() {
+        let input = r#"fn f(a: field) -> field;"#;
+
+        let expected = Ok((
+            TraitLocalStatement::Fn(TraitMethod::new(
+                Location::test(1, 1),
+                false,
+                Identifier::new(Location::test(1, 4), "f".to_owned()),
+                vec![Binding::new(
+                    Location::test(1, 6),
+                    BindingPattern::new(
+                        Location::test(1, 6),
+                        BindingPatternVariant::new_binding(
+                            Identifier::new(Location::test(1, 6), "a".to_owned()),
+                            false,
+                        ),
+                    ),
+                    Some(Type::new(Location::test(1, 9), TypeVariant::field())),
+                )],
+                Some(Type::new(Location::test(1, 19), TypeVariant::field())),
+            )),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_fn_constant() {
+        let input = r#"const fn f();"#;
+
+        let expected = Ok((
+            TraitLocalStatement::Fn(TraitMethod::new(
+                Location::test(1, 1),
+                true,
+                Identifier::new(Location::test(1, 10), "f".to_owned()),
+                vec![],
+                None,
+            )),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_empty() {
+        let input = r#";"#;
+
+        let expected = Ok((TraitLocalStatement::Empty(Location::test(1, 1)), None));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_semicolon() {
+        let input = r#"fn f(a: field) -> field {}"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 25),
+            vec![";"],
+            Lexeme::Symbol(Symbol::BracketCurlyLeft),
+            Some(super::HINT_EXPECTED_SEMICOLON),
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_only_some_statements() {
+        let input = r#"const VALUE: u8 = 42;"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 7),
+            vec!["fn"],
+            Lexeme::Identifier(zinc_lexical::Identifier::new("VALUE".to_owned())),
+            Some(super::HINT_ONLY_SOME_STATEMENTS),
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+}
@@ -2,7 +2,9 @@
 //! The statement parser.
 //!
 
+pub mod r#break;
 pub mod r#const;
+pub mod r#continue;
 pub mod contract;
 pub mod r#enum;
 pub mod field;
@@ -14,7 +16,10 @@ pub mod local_contract;
 pub mod local_fn;
 pub mod local_impl;
 pub mod local_mod;
+pub mod local_trait;
 pub mod module;
+pub mod r#return;
 pub mod r#struct;
+pub mod r#trait;
 pub mod r#type;
 pub mod r#use;
@@ -0,0 +1,277 @@
+//!
+//! The `trait` statement parser.
+//!
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use zinc_lexical::Keyword;
+use zinc_lexical::Lexeme;
+use zinc_lexical::Symbol;
+use zinc_lexical::Token;
+use zinc_lexical::TokenStream;
+
+use crate::error::Error as SyntaxError;
+use crate::error::ParsingError;
+use crate::parser::statement::local_trait::Parser as TraitLocalStatementParser;
+use crate::tree::identifier::Identifier;
+use crate::tree::statement::r#trait::builder::Builder as TraitStatementBuilder;
+use crate::tree::statement::r#trait::Statement as TraitStatement;
+
+/// The missing identifier error hint.
+pub static HINT_EXPECTED_IDENTIFIER: &str =
+    "trait must have an identifier, e.g. `trait Hashable { ... }`";
+
+///
+/// The parser state.
+///
+#[derive(Debug, Default, Clone, Copy)]
+pub enum State {
+    /// The initial state.
+    #[default]
+    KeywordTrait,
+    /// The `trait` has been parsed so far.
+    Identifier,
+    /// The `trait {identifier}` has been parsed so far.
+    BracketCurlyLeft,
+    /// The `trait {identifier} {` has been parsed so far.
+    StatementOrBracketCurlyRight,
+}
+
+///
+/// The `trait` statement parser.
+///
+#[derive(Default)]
+pub struct Parser {
+    /// The parser state.
+    state: State,
+    /// The builder of the parsed value.
+    builder: TraitStatementBuilder,
+    /// The token returned from a subparser.
+    next: Option<Token>,
+}
+
+impl Parser {
+    ///
+    /// Parses a 'trait' statement.
+    ///
+    /// '
+    /// trait Hashable {
+    ///     fn hash(self) -> field;
+    /// }
+    /// '
+    ///
+    pub fn parse(
+        mut self,
+        stream: Rc<RefCell<TokenStream>>,
+        initial: Option<Token>,
+    ) -> Result<(TraitStatement, Option<Token>), ParsingError> {
+        self.next = initial;
+
+        loop {
+            match self.state {
+                State::KeywordTrait => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Keyword(Keyword::Trait),
+                            location,
+                        } => {
+                            self.builder.set_location(location);
+                            self.state = State::Identifier;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["trait"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::Identifier => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            let identifier = Identifier::new(location, identifier.inner);
+                            self.builder.set_identifier(identifier);
+                            self.state = State::BracketCurlyLeft;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+                                location,
+                                lexeme,
+                                Some(HINT_EXPECTED_IDENTIFIER),
+                            )));
+                        }
+                    }
+                }
+                State::BracketCurlyLeft => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                            ..
+                        } => {
+                            self.state = State::StatementOrBracketCurlyRight;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec!["{"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::StatementOrBracketCurlyRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            ..
+                        } => return Ok((self.builder.finish(), None)),
+                        token => {
+                            let (statement, next) = TraitLocalStatementParser::default()
+                                .parse(stream.clone(), Some(token))?;
+                            self.next = next;
+                            self.builder.push_statement(statement);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use zinc_lexical::Lexeme;
+    use zinc_lexical::Location;
+    use zinc_lexical::Symbol;
+    use zinc_lexical::TokenStream;
+
+    use super::Parser;
+    use crate::error::Error as SyntaxError;
+    use crate::error::ParsingError;
+    use crate::tree::binding::Binding;
+    use crate::tree::identifier::Identifier;
+    use crate::tree::pattern_binding::variant::Variant as BindingPatternVariant;
+    use crate::tree::pattern_binding::Pattern as BindingPattern;
+    use crate::tree::r#type::variant::Variant as TypeVariant;
+    use crate::tree::r#type::Type;
+    use crate::tree::statement::local_trait::Statement as TraitLocalStatement;
+    use crate::tree::statement::r#trait::method::Method as TraitMethod;
+    use crate::tree::statement::r#trait::Statement as TraitStatement;
+
+    #[test]
+    fn ok_empty() {
+        let input = r#"
+    trait Test {}
+"#;
+
+        let expected = Ok((
+            TraitStatement::new(
+                Location::test(2, 5),
+                Identifier::new(Location::test(2, 11), "Test".to_owned()),
+                vec![],
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_multiple_methods() {
+        let input = r#"
+    trait Test {
+        fn f1(self) -> field;
+
+        fn f2(a: field);
+    }
+"#;
+
+        let expected = Ok((
+            TraitStatement::new(
+                Location::test(2, 5),
+                Identifier::new(Location::test(2, 11), "Test".to_owned()),
+                vec![
+                    TraitLocalStatement::Fn(TraitMethod::new(
+                        Location::test(3, 9),
+                        false,
+                        Identifier::new(Location::test(3, 12), "f1".to_owned()),
+                        vec![Binding::new(
+                            Location::test(3, 15),
+                            BindingPattern::new(
+                                Location::test(3, 15),
+                                BindingPatternVariant::new_binding(
+                                    Identifier::new(Location::test(3, 15), "self".to_owned()),
+                                    false,
+                                ),
+                            ),
+                            None,
+                        )],
+                        Some(Type::new(Location::test(3, 24), TypeVariant::field())),
+                    )),
+                    TraitLocalStatement::Fn(TraitMethod::new(
+                        Location::test(5, 9),
+                        false,
+                        Identifier::new(Location::test(5, 12), "f2".to_owned()),
+                        vec![Binding::new(
+                            Location::test(5, 15),
+                            BindingPattern::new(
+                                Location::test(5, 15),
+                                BindingPatternVariant::new_binding(
+                                    Identifier::new(Location::test(5, 15), "a".to_owned()),
+                                    false,
+                                ),
+                            ),
+                            Some(Type::new(Location::test(5, 18), TypeVariant::field())),
+                        )],
+                        None,
+                    )),
+                ],
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_identifier() {
+        let input = r#"trait { fn f(); }"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+            Location::test(1, 7),
+            Lexeme::Symbol(Symbol::BracketCurlyLeft),
+            Some(super::HINT_EXPECTED_IDENTIFIER),
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_bracket_curly_left() {
+        let input = r#"trait Test;"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 11),
+            vec!["{"],
+            Lexeme::Symbol(Symbol::Semicolon),
+            None,
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+}
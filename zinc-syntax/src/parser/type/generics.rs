@@ -6,6 +6,7 @@ use std::cell::RefCell;
 use std::rc::Rc;
 
 use zinc_lexical::Lexeme;
+use zinc_lexical::Literal as LexicalLiteral;
 use zinc_lexical::Symbol;
 use zinc_lexical::Token;
 use zinc_lexical::TokenStream;
@@ -13,6 +14,11 @@ use zinc_lexical::TokenStream;
 use crate::error::Error as SyntaxError;
 use crate::error::ParsingError;
 use crate::parser::r#type::Parser as TypeParser;
+use crate::tree::expression::tree::node::operand::Operand as ExpressionOperand;
+use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
+use crate::tree::expression::tree::Tree as ExpressionTree;
+use crate::tree::literal::integer::Literal as IntegerLiteral;
+use crate::tree::r#type::variant::Variant as TypeVariant;
 use crate::tree::r#type::Type;
 
 ///
@@ -51,7 +57,11 @@ impl Parser {
     ///
     /// Parses a generic type arguments list.
     ///
+    /// The constant arguments like `4` in `Vec<u8, 4>` are integer literals, which are
+    /// represented as aliases resolved during the semantic analysis.
+    ///
     /// '<u8, field, bool>'
+    /// '<u8, 4>'
     ///
     pub fn parse(
         mut self,
@@ -88,6 +98,20 @@ impl Parser {
                         } => {
                             return Ok((self.types, self.next.take()));
                         }
+                        Token {
+                            lexeme: Lexeme::Literal(LexicalLiteral::Integer(integer)),
+                            location,
+                        } => {
+                            let path = ExpressionTree::new(
+                                location,
+                                ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                                    IntegerLiteral::new(location, integer),
+                                )),
+                            );
+                            self.types
+                                .push(Type::new(location, TypeVariant::alias(path, None)));
+                            self.state = State::CommaOrGreater;
+                        }
                         token => {
                             let (r#type, next) =
                                 TypeParser::default().parse(stream.clone(), Some(token))?;
@@ -278,6 +302,40 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_constant() {
+        let input = r#"<u8, 4>"#;
+
+        let expected = Ok((
+            vec![
+                Type::new(
+                    Location::test(1, 2),
+                    TypeVariant::integer_unsigned(zinc_const::bitlength::BYTE),
+                ),
+                Type::new(
+                    Location::test(1, 6),
+                    TypeVariant::alias(
+                        ExpressionTree::new(
+                            Location::test(1, 6),
+                            ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                                IntegerLiteral::new(
+                                    Location::test(1, 6),
+                                    LexicalIntegerLiteral::new_decimal("4".to_owned()),
+                                ),
+                            )),
+                        ),
+                        None,
+                    ),
+                ),
+            ],
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_comma_or_greater() {
         let input = r#"<field;>"#;
@@ -25,6 +25,10 @@ use self::array::Parser as ArrayParser;
 use self::generics::Parser as GenericsParser;
 use self::tuple::Parser as TupleParser;
 
+/// The missing `mut` error hint.
+pub static HINT_ONLY_MUTABLE_REFERENCES: &str =
+    "only mutable references are supported, e.g. `&mut [u8; 16]`";
+
 ///
 /// The type parser.
 ///
@@ -44,6 +48,7 @@ impl Parser {
     /// '[u8; 16]'
     /// '(u8, field, bool)'
     /// 'Path::To::Type`
+    /// '&mut [u8; 16]'
     ///
     pub fn parse(
         mut self,
@@ -134,6 +139,28 @@ impl Parser {
                 lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
                 ..
             } => TupleParser::default().parse(stream.clone(), Some(token)),
+            Token {
+                lexeme: Lexeme::Symbol(Symbol::Ampersand),
+                location,
+            } => match crate::parser::take_or_next(None, stream.clone())? {
+                Token {
+                    lexeme: Lexeme::Keyword(Keyword::Mut),
+                    ..
+                } => {
+                    let (inner, next) = Self::default().parse(stream, None)?;
+                    self.builder.set_location(location);
+                    self.builder.set_reference_type(inner);
+                    Ok((self.builder.finish(), next))
+                }
+                Token { lexeme, location } => {
+                    Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                        location,
+                        vec!["mut"],
+                        lexeme,
+                        Some(HINT_ONLY_MUTABLE_REFERENCES),
+                    )))
+                }
+            },
             Token { lexeme, location } => Err(ParsingError::Syntax(SyntaxError::expected_type(
                 location, lexeme, None,
             ))),
@@ -160,6 +187,7 @@ mod tests {
     use crate::tree::expression::tree::node::Node as ExpressionTreeNode;
     use crate::tree::expression::tree::Tree as ExpressionTree;
     use crate::tree::identifier::Identifier;
+    use crate::tree::literal::integer::Literal as IntegerLiteral;
     use crate::tree::r#type::variant::Variant as TypeVariant;
     use crate::tree::r#type::Type;
 
@@ -378,6 +406,58 @@ mod tests {
         assert_eq!(result, expected);
     }
 
+    #[test]
+    fn ok_mutable_reference() {
+        let input = r#"&mut [u8; 4]"#;
+
+        let expected = Ok((
+            Type::new(
+                Location::test(1, 1),
+                TypeVariant::mutable_reference(Type::new(
+                    Location::test(1, 6),
+                    TypeVariant::array(
+                        Type::new(
+                            Location::test(1, 7),
+                            TypeVariant::integer_unsigned(zinc_const::bitlength::BYTE),
+                        ),
+                        ExpressionTree::new(
+                            Location::test(1, 11),
+                            ExpressionTreeNode::operand(ExpressionOperand::LiteralInteger(
+                                IntegerLiteral::new(
+                                    Location::test(1, 11),
+                                    LexicalIntegerLiteral::new_decimal("4".to_owned()),
+                                ),
+                            )),
+                        ),
+                    ),
+                )),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn error_expected_mut() {
+        let input = r#"&u8"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 2),
+            vec!["mut"],
+            Lexeme::Keyword(Keyword::IntegerUnsigned {
+                bitlength: zinc_const::bitlength::BYTE,
+            }),
+            Some(super::HINT_ONLY_MUTABLE_REFERENCES),
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
     #[test]
     fn error_expected_type_keyword() {
         let input = r#"while"#;
@@ -13,24 +13,52 @@ use zinc_lexical::TokenStream;
 
 use crate::error::Error as SyntaxError;
 use crate::error::ParsingError;
+use crate::parser::field_list::Parser as FieldListParser;
+use crate::parser::r#type::Parser as TypeParser;
 use crate::tree::identifier::Identifier;
 use crate::tree::literal::integer::Literal as IntegerLiteral;
+use crate::tree::r#type::Type;
 use crate::tree::variant::builder::Builder as VariantBuilder;
+use crate::tree::variant::payload::Payload;
 use crate::tree::variant::Variant;
 
 /// The missing identifier error hint.
 pub static HINT_EXPECTED_IDENTIFIER: &str =
     "enumeration variant must have an identifier, e.g. `Value = 42`";
-/// The missing value error hint.
-pub static HINT_EXPECTED_VALUE: &str = "enumeration variant must be initialized, e.g. `Value = 42`";
+
+///
+/// The parser state.
+///
+#[derive(Debug, Default, Clone, Copy)]
+pub enum State {
+    /// The initial state.
+    #[default]
+    Identifier,
+    /// The `{identifier}` has been parsed so far.
+    ValueOrPayloadOrEnd,
+    /// The `{identifier} =` has been parsed so far.
+    Value,
+    /// The `{identifier} (` or `{identifier} ( {type},` has been parsed so far.
+    TypeOrParenthesisRight,
+    /// The `{identifier} ( {type}` has been parsed so far.
+    CommaOrParenthesisRight,
+    /// The `{identifier} {` has been parsed so far.
+    FieldList,
+    /// The `{identifier} { {fields}` has been parsed so far.
+    BracketCurlyRight,
+}
 
 ///
 /// The variant parser.
 ///
 #[derive(Default)]
 pub struct Parser {
+    /// The parser state.
+    state: State,
     /// The builder of the parsed value.
     builder: VariantBuilder,
+    /// The tuple payload types parsed so far.
+    types: Vec<Type>,
     /// The token returned from a subparser.
     next: Option<Token>,
 }
@@ -40,6 +68,9 @@ impl Parser {
     /// Parses an enum variant.
     ///
     /// 'A = 1'
+    /// 'A'
+    /// 'A(u8, bool)'
+    /// 'A { a: u8, b: bool }'
     ///
     pub fn parse(
         mut self,
@@ -48,51 +79,123 @@ impl Parser {
     ) -> Result<(Variant, Option<Token>), ParsingError> {
         self.next = initial;
 
-        match crate::parser::take_or_next(self.next.take(), stream.clone())? {
-            Token {
-                lexeme: Lexeme::Identifier(identifier),
-                location,
-            } => {
-                let identifier = Identifier::new(location, identifier.inner);
-                self.builder.set_location(location);
-                self.builder.set_identifier(identifier);
-            }
-            Token { lexeme, location } => {
-                return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
-                    location,
-                    lexeme,
-                    Some(HINT_EXPECTED_IDENTIFIER),
-                )));
-            }
-        }
-
-        match crate::parser::take_or_next(self.next.take(), stream.clone())? {
-            Token {
-                lexeme: Lexeme::Symbol(Symbol::Equals),
-                ..
-            } => {}
-            Token { lexeme, location } => {
-                return Err(ParsingError::Syntax(SyntaxError::expected_value(
-                    location,
-                    lexeme,
-                    Some(HINT_EXPECTED_VALUE),
-                )));
+        loop {
+            match self.state {
+                State::Identifier => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Identifier(identifier),
+                            location,
+                        } => {
+                            let identifier = Identifier::new(location, identifier.inner);
+                            self.builder.set_location(location);
+                            self.builder.set_identifier(identifier);
+                            self.state = State::ValueOrPayloadOrEnd;
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_identifier(
+                                location,
+                                lexeme,
+                                Some(HINT_EXPECTED_IDENTIFIER),
+                            )));
+                        }
+                    }
+                }
+                State::ValueOrPayloadOrEnd => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Equals),
+                            ..
+                        } => self.state = State::Value,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisLeft),
+                            ..
+                        } => self.state = State::TypeOrParenthesisRight,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyLeft),
+                            ..
+                        } => self.state = State::FieldList,
+                        token => return Ok((self.builder.finish(), Some(token))),
+                    }
+                }
+                State::Value => {
+                    return match crate::parser::take_or_next(self.next.take(), stream)? {
+                        Token {
+                            lexeme: Lexeme::Literal(LexicalLiteral::Integer(literal)),
+                            location,
+                        } => {
+                            self.builder
+                                .set_literal(IntegerLiteral::new(location, literal));
+                            Ok((self.builder.finish(), None))
+                        }
+                        Token { lexeme, location } => Err(ParsingError::Syntax(
+                            SyntaxError::expected_integer_literal(location, lexeme),
+                        )),
+                    };
+                }
+                State::TypeOrParenthesisRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                            ..
+                        } => {
+                            self.builder
+                                .set_payload(Payload::Tuple(std::mem::take(&mut self.types)));
+                            return Ok((self.builder.finish(), None));
+                        }
+                        token => {
+                            let (r#type, next) =
+                                TypeParser::default().parse(stream.clone(), Some(token))?;
+                            self.next = next;
+                            self.types.push(r#type);
+                            self.state = State::CommaOrParenthesisRight;
+                        }
+                    }
+                }
+                State::CommaOrParenthesisRight => {
+                    match crate::parser::take_or_next(self.next.take(), stream.clone())? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::Comma),
+                            ..
+                        } => self.state = State::TypeOrParenthesisRight,
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::ParenthesisRight),
+                            ..
+                        } => {
+                            self.builder
+                                .set_payload(Payload::Tuple(std::mem::take(&mut self.types)));
+                            return Ok((self.builder.finish(), None));
+                        }
+                        Token { lexeme, location } => {
+                            return Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+                                location,
+                                vec![",", ")"],
+                                lexeme,
+                                None,
+                            )));
+                        }
+                    }
+                }
+                State::FieldList => {
+                    let (fields, next) =
+                        FieldListParser::default().parse(stream.clone(), self.next.take())?;
+                    self.builder.set_payload(Payload::Structure(fields));
+                    self.next = next;
+                    self.state = State::BracketCurlyRight;
+                }
+                State::BracketCurlyRight => {
+                    return match crate::parser::take_or_next(self.next.take(), stream)? {
+                        Token {
+                            lexeme: Lexeme::Symbol(Symbol::BracketCurlyRight),
+                            ..
+                        } => Ok((self.builder.finish(), None)),
+                        Token { lexeme, location } => Err(ParsingError::Syntax(
+                            SyntaxError::expected_one_of(location, vec!["}"], lexeme, None),
+                        )),
+                    };
+                }
             }
         }
-
-        match crate::parser::take_or_next(self.next.take(), stream)? {
-            Token {
-                lexeme: Lexeme::Literal(LexicalLiteral::Integer(literal)),
-                location,
-            } => {
-                self.builder
-                    .set_literal(IntegerLiteral::new(location, literal));
-                Ok((self.builder.finish(), self.next.take()))
-            }
-            Token { lexeme, location } => Err(ParsingError::Syntax(
-                SyntaxError::expected_integer_literal(location, lexeme),
-            )),
-        }
     }
 }
 
@@ -100,15 +203,21 @@ impl Parser {
 mod tests {
     use zinc_lexical::Identifier as LexicalIdentifier;
     use zinc_lexical::IntegerLiteral as LexicalIntegerLiteral;
+    use zinc_lexical::Keyword;
     use zinc_lexical::Lexeme;
     use zinc_lexical::Location;
+    use zinc_lexical::Token;
     use zinc_lexical::TokenStream;
 
     use super::Parser;
     use crate::error::Error as SyntaxError;
     use crate::error::ParsingError;
+    use crate::tree::field::Field;
     use crate::tree::identifier::Identifier;
     use crate::tree::literal::integer::Literal as IntegerLiteral;
+    use crate::tree::r#type::variant::Variant as TypeVariant;
+    use crate::tree::r#type::Type;
+    use crate::tree::variant::payload::Payload;
     use crate::tree::variant::Variant;
 
     #[test]
@@ -119,10 +228,11 @@ mod tests {
             Variant::new(
                 Location::test(1, 1),
                 Identifier::new(Location::test(1, 1), "A".to_owned()),
-                IntegerLiteral::new(
+                Some(IntegerLiteral::new(
                     Location::test(1, 5),
                     LexicalIntegerLiteral::new_decimal("1".to_owned()),
-                ),
+                )),
+                Payload::Unit,
             ),
             None,
         ));
@@ -133,14 +243,70 @@ mod tests {
     }
 
     #[test]
-    fn error_expected_value() {
+    fn ok_unit() {
         let input = r#"A"#;
 
-        let expected = Err(ParsingError::Syntax(SyntaxError::expected_value(
-            Location::test(1, 2),
-            Lexeme::Eof,
-            Some(super::HINT_EXPECTED_VALUE),
-        )));
+        let expected = Ok((
+            Variant::new(
+                Location::test(1, 1),
+                Identifier::new(Location::test(1, 1), "A".to_owned()),
+                None,
+                Payload::Unit,
+            ),
+            Some(Token::new(Lexeme::Eof, Location::test(1, 2))),
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_tuple() {
+        let input = r#"A(u8, bool)"#;
+
+        let expected = Ok((
+            Variant::new(
+                Location::test(1, 1),
+                Identifier::new(Location::test(1, 1), "A".to_owned()),
+                None,
+                Payload::Tuple(vec![
+                    Type::new(Location::test(1, 3), TypeVariant::integer_unsigned(8)),
+                    Type::new(Location::test(1, 7), TypeVariant::boolean()),
+                ]),
+            ),
+            None,
+        ));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    fn ok_structure() {
+        let input = r#"A { a: u8, b: bool }"#;
+
+        let expected = Ok((
+            Variant::new(
+                Location::test(1, 1),
+                Identifier::new(Location::test(1, 1), "A".to_owned()),
+                None,
+                Payload::Structure(vec![
+                    Field::new(
+                        Location::test(1, 5),
+                        Identifier::new(Location::test(1, 5), "a".to_owned()),
+                        Type::new(Location::test(1, 8), TypeVariant::integer_unsigned(8)),
+                    ),
+                    Field::new(
+                        Location::test(1, 12),
+                        Identifier::new(Location::test(1, 12), "b".to_owned()),
+                        Type::new(Location::test(1, 15), TypeVariant::boolean()),
+                    ),
+                ]),
+            ),
+            None,
+        ));
 
         let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
 
@@ -160,4 +326,20 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn error_expected_comma_or_parenthesis_right() {
+        let input = r#"A(u8 bool)"#;
+
+        let expected = Err(ParsingError::Syntax(SyntaxError::expected_one_of(
+            Location::test(1, 6),
+            vec![",", ")"],
+            Lexeme::Keyword(Keyword::Bool),
+            None,
+        )));
+
+        let result = Parser::default().parse(TokenStream::test(input).wrap(), None);
+
+        assert_eq!(result, expected);
+    }
 }
@@ -37,4 +37,29 @@ impl Expression {
             expression: expression.map(Box::new),
         }
     }
+
+    ///
+    /// Checks if the block has no result expression and ends with a `return` statement,
+    /// so its result is always provided by some `return` statement.
+    ///
+    pub fn is_return_terminated(&self) -> bool {
+        self.expression.is_none()
+            && matches!(
+                self.statements.last(),
+                Some(FunctionLocalStatement::Return(_))
+            )
+    }
+    ///
+    /// Checks if the block has no result expression and ends with a `return`, `break`,
+    /// or `continue` statement, so the control flow never reaches the end of the block.
+    ///
+    pub fn is_diverging(&self) -> bool {
+        self.expression.is_none()
+            && matches!(
+                self.statements.last(),
+                Some(FunctionLocalStatement::Return(_))
+                    | Some(FunctionLocalStatement::Break(_))
+                    | Some(FunctionLocalStatement::Continue(_))
+            )
+    }
 }
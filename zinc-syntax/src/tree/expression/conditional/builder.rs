@@ -7,6 +7,7 @@ use zinc_lexical::Location;
 use crate::tree::expression::block::Expression as BlockExpression;
 use crate::tree::expression::conditional::Expression as ConditionalExpression;
 use crate::tree::expression::tree::Tree as ExpressionTree;
+use crate::tree::pattern_match::Pattern as MatchPattern;
 
 ///
 /// The conditional expression builder.
@@ -15,7 +16,9 @@ use crate::tree::expression::tree::Tree as ExpressionTree;
 pub struct Builder {
     /// The location of the syntax construction.
     location: Option<Location>,
-    /// The condition expression.
+    /// The `if let` pattern, which is matched against the condition expression.
+    pattern: Option<MatchPattern>,
+    /// The condition expression, or the matched expression if the pattern is set.
     condition: Option<ExpressionTree>,
     /// The main conditional block expression.
     main_block: Option<BlockExpression>,
@@ -31,6 +34,13 @@ impl Builder {
         self.location = Some(value);
     }
 
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_pattern(&mut self, value: MatchPattern) {
+        self.pattern = Some(value);
+    }
+
     ///
     /// Sets the corresponding builder value.
     ///
@@ -67,6 +77,7 @@ impl Builder {
                     "location"
                 )
             }),
+            self.pattern.take(),
             self.condition.take().unwrap_or_else(|| {
                 panic!(
                     "{}{}",
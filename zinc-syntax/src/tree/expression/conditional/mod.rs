@@ -8,6 +8,7 @@ use zinc_lexical::Location;
 
 use crate::tree::expression::block::Expression as BlockExpression;
 use crate::tree::expression::tree::Tree as ExpressionTree;
+use crate::tree::pattern_match::Pattern as MatchPattern;
 
 ///
 /// The conditional expression.
@@ -16,7 +17,9 @@ use crate::tree::expression::tree::Tree as ExpressionTree;
 pub struct Expression {
     /// The location of the syntax construction.
     pub location: Location,
-    /// The condition expression.
+    /// The `if let` pattern, which is matched against the condition expression.
+    pub pattern: Option<MatchPattern>,
+    /// The condition expression, or the matched expression if the pattern is set.
     pub condition: Box<ExpressionTree>,
     /// The main conditional block expression.
     pub main_block: BlockExpression,
@@ -30,12 +33,14 @@ impl Expression {
     ///
     pub fn new(
         location: Location,
+        pattern: Option<MatchPattern>,
         condition: ExpressionTree,
         main_block: BlockExpression,
         else_block: Option<BlockExpression>,
     ) -> Self {
         Self {
             location,
+            pattern,
             condition: Box::new(condition),
             main_block,
             else_block,
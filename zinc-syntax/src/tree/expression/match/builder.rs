@@ -17,8 +17,8 @@ pub struct Builder {
     location: Option<Location>,
     /// The match scrutinee expression, which is the matched expression.
     scrutinee: Option<ExpressionTree>,
-    /// The match pattern-expression pairs.
-    branches: Vec<(MatchPattern, Option<ExpressionTree>)>,
+    /// The match branches, which consist of the pattern, optional guard condition and expression.
+    branches: Vec<(MatchPattern, Option<ExpressionTree>, Option<ExpressionTree>)>,
 }
 
 impl Builder {
@@ -40,13 +40,13 @@ impl Builder {
     /// Pushes the corresponding builder value.
     ///
     pub fn push_branch_pattern(&mut self, value: MatchPattern) {
-        self.branches.push((value, None));
+        self.branches.push((value, None, None));
     }
 
     ///
     /// Sets the corresponding builder value.
     ///
-    pub fn set_branch_expression(&mut self, value: ExpressionTree) {
+    pub fn set_branch_guard(&mut self, value: ExpressionTree) {
         self.branches
             .last_mut()
             .unwrap_or_else(|| {
@@ -59,6 +59,22 @@ impl Builder {
             .1 = Some(value);
     }
 
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_branch_expression(&mut self, value: ExpressionTree) {
+        self.branches
+            .last_mut()
+            .unwrap_or_else(|| {
+                panic!(
+                    "{}{}",
+                    zinc_const::panic::BUILDER_REQUIRES_VALUE,
+                    "branch pattern"
+                )
+            })
+            .2 = Some(value);
+    }
+
     ///
     /// Finalizes the builder and returns the built value.
     ///
@@ -83,9 +99,10 @@ impl Builder {
             }),
             self.branches
                 .into_iter()
-                .map(|(pattern, expression)| {
+                .map(|(pattern, guard, expression)| {
                     (
                         pattern,
+                        guard,
                         expression.unwrap_or_else(|| {
                             panic!(
                                 "{}{}",
@@ -95,7 +112,7 @@ impl Builder {
                         }),
                     )
                 })
-                .collect::<Vec<(MatchPattern, ExpressionTree)>>(),
+                .collect::<Vec<(MatchPattern, Option<ExpressionTree>, ExpressionTree)>>(),
         )
     }
 }
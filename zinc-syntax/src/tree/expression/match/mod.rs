@@ -18,8 +18,8 @@ pub struct Expression {
     pub location: Location,
     /// The match scrutinee expression, which is the matched expression.
     pub scrutinee: ExpressionTree,
-    /// The match pattern-expression pairs.
-    pub branches: Vec<(MatchPattern, ExpressionTree)>,
+    /// The match branches, which consist of the pattern, optional guard condition and expression.
+    pub branches: Vec<(MatchPattern, Option<ExpressionTree>, ExpressionTree)>,
 }
 
 impl Expression {
@@ -29,7 +29,7 @@ impl Expression {
     pub fn new(
         location: Location,
         scrutinee: ExpressionTree,
-        branches: Vec<(MatchPattern, ExpressionTree)>,
+        branches: Vec<(MatchPattern, Option<ExpressionTree>, ExpressionTree)>,
     ) -> Self {
         Self {
             location,
@@ -9,6 +9,7 @@ use std::fmt;
 
 use zinc_lexical::Location;
 
+use self::node::operand::Operand;
 use self::node::Node;
 
 ///
@@ -70,6 +71,17 @@ impl Tree {
             _ => false,
         }
     }
+
+    ///
+    /// Checks if the tree is a single block, which never reaches its end because of
+    /// some `return`, `break`, or `continue` statement.
+    ///
+    pub fn is_diverging(&self) -> bool {
+        match *self.value {
+            Node::Operand(Operand::Block(ref block)) => block.is_diverging(),
+            _ => false,
+        }
+    }
 }
 
 impl fmt::Display for Tree {
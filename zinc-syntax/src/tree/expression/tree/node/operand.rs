@@ -15,6 +15,7 @@ use crate::tree::expression::structure::Expression as StructureExpression;
 use crate::tree::expression::tuple::Expression as TupleExpression;
 use crate::tree::identifier::Identifier;
 use crate::tree::literal::boolean::Literal as BooleanLiteral;
+use crate::tree::literal::bytes::Literal as BytesLiteral;
 use crate::tree::literal::integer::Literal as IntegerLiteral;
 use crate::tree::literal::string::Literal as StringLiteral;
 use crate::tree::r#type::Type;
@@ -33,12 +34,16 @@ pub enum Operand {
     LiteralInteger(IntegerLiteral),
     /// "Zinc is the best language for ZKP".
     LiteralString(StringLiteral),
+    /// `b"zinc"`, `hex"deadbeef"`, etc.
+    LiteralBytes(BytesLiteral),
     /// A tuple field identifier.
     TupleIndex(TupleIndex),
     /// An item identifier.
     Identifier(Identifier),
     /// A syntax type, e.g. a keyword, array, tuple, etc.
     Type(Type),
+    /// A path with explicit generic arguments `identity::<u8>`, stored as a type alias.
+    GenericPath(Type),
     /// An array literal expression.
     Array(ArrayExpression),
     /// A tuple literal expression.
@@ -91,6 +91,9 @@ pub enum Operator {
     /// The unary `-` operator.
     Negation,
 
+    /// The unary `&mut` operator.
+    Reference,
+
     /// The `[]` operator.
     Index,
     /// The `.` operator.
@@ -0,0 +1,43 @@
+//!
+//! The generic parameter.
+//!
+
+pub mod builder;
+
+use zinc_lexical::Location;
+
+use crate::tree::identifier::Identifier;
+use crate::tree::r#type::Type;
+
+///
+/// The generic parameter, which is either a type `T` or a constant `const N: u8`.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct GenericParameter {
+    /// The location of the syntax construction.
+    pub location: Location,
+    /// The generic parameter identifier.
+    pub identifier: Identifier,
+    /// The constant parameter type, which is `None` for type parameters.
+    pub r#type: Option<Type>,
+}
+
+impl GenericParameter {
+    ///
+    /// Creates a generic parameter.
+    ///
+    pub fn new(location: Location, identifier: Identifier, r#type: Option<Type>) -> Self {
+        Self {
+            location,
+            identifier,
+            r#type,
+        }
+    }
+
+    ///
+    /// Checks whether the parameter is a constant one.
+    ///
+    pub fn is_constant(&self) -> bool {
+        self.r#type.is_some()
+    }
+}
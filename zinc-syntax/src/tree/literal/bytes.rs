@@ -0,0 +1,32 @@
+//!
+//! The bytes literal.
+//!
+
+use zinc_lexical::BytesLiteral as LexicalBytesLiteral;
+use zinc_lexical::Location;
+
+///
+/// The bytes literal.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Literal {
+    /// The location of the syntax construction.
+    pub location: Location,
+    /// The inner lexical literal.
+    pub inner: LexicalBytesLiteral,
+}
+
+impl Literal {
+    ///
+    /// Creates a new literal value.
+    ///
+    pub fn new(location: Location, inner: LexicalBytesLiteral) -> Self {
+        Self { location, inner }
+    }
+}
+
+impl From<Literal> for Vec<u8> {
+    fn from(literal: Literal) -> Self {
+        literal.inner.into()
+    }
+}
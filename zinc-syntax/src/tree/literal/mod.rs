@@ -3,10 +3,12 @@
 //!
 
 pub mod boolean;
+pub mod bytes;
 pub mod integer;
 pub mod string;
 
 use self::boolean::Literal as BooleanLiteral;
+use self::bytes::Literal as BytesLiteral;
 use self::integer::Literal as IntegerLiteral;
 use self::string::Literal as StringLiteral;
 
@@ -21,4 +23,6 @@ pub enum Literal {
     Integer(IntegerLiteral),
     /// The string literal.
     String(StringLiteral),
+    /// The bytes literal.
+    Bytes(BytesLiteral),
 }
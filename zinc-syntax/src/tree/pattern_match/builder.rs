@@ -25,12 +25,23 @@ pub struct Builder {
     boolean_literal: Option<BooleanLiteral>,
     /// The integer literal variant, which means that the pattern is an integer constant.
     integer_literal: Option<IntegerLiteral>,
+    /// The range end literal and whether it is inclusive, which means that the pattern is
+    /// an integer range starting with the integer literal.
+    range_end: Option<(IntegerLiteral, bool)>,
     /// The binding variant, which means that the pattern is a variable binding.
     binding: Option<Identifier>,
     /// The path builder variant, which means that the pattern is a path expression.
     path_builder: ExpressionTreeBuilder,
     /// If the pattern variant is a wildcard.
     is_wildcard: bool,
+    /// The tuple or enumeration variant unnamed field patterns.
+    elements: Option<Vec<MatchPattern>>,
+    /// If the tuple elements are followed by a comma, which distinguishes `(a,)` from `(a)`.
+    has_comma: bool,
+    /// The structure or enumeration variant named field patterns.
+    fields: Option<Vec<(Identifier, MatchPattern)>>,
+    /// If the omitted structure fields are ignored with `..`.
+    is_rest_ignored: bool,
 }
 
 impl Builder {
@@ -55,6 +66,13 @@ impl Builder {
         self.integer_literal = Some(value);
     }
 
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_range_end(&mut self, value: IntegerLiteral, is_inclusive: bool) {
+        self.range_end = Some((value, is_inclusive));
+    }
+
     ///
     /// Sets the corresponding builder value.
     ///
@@ -85,6 +103,50 @@ impl Builder {
         self.is_wildcard = true;
     }
 
+    ///
+    /// Sets the tuple or enumeration variant pattern without elements.
+    ///
+    pub fn set_elements(&mut self) {
+        self.elements = Some(vec![]);
+    }
+
+    ///
+    /// Pushes the corresponding builder value.
+    ///
+    pub fn push_element(&mut self, value: MatchPattern) {
+        self.elements.get_or_insert_with(Vec::new).push(value);
+    }
+
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_comma(&mut self) {
+        self.has_comma = true;
+    }
+
+    ///
+    /// Sets the structure pattern without fields.
+    ///
+    pub fn set_fields(&mut self) {
+        self.fields = Some(vec![]);
+    }
+
+    ///
+    /// Pushes the corresponding builder value.
+    ///
+    pub fn push_field(&mut self, identifier: Identifier, pattern: MatchPattern) {
+        self.fields
+            .get_or_insert_with(Vec::new)
+            .push((identifier, pattern));
+    }
+
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_rest_ignored(&mut self) {
+        self.is_rest_ignored = true;
+    }
+
     ///
     /// Finalizes the builder and returns the built value.
     ///
@@ -100,12 +162,40 @@ impl Builder {
             )
         });
 
+        if self.elements.is_some() || self.fields.is_some() {
+            self.move_binding_to_path();
+        }
+
         let variant = if self.is_wildcard {
             MatchPatternVariant::Wildcard
         } else if let Some(boolean_literal) = self.boolean_literal.take() {
             MatchPatternVariant::BooleanLiteral(boolean_literal)
         } else if let Some(integer_literal) = self.integer_literal.take() {
-            MatchPatternVariant::IntegerLiteral(integer_literal)
+            match self.range_end.take() {
+                Some((end, is_inclusive)) => {
+                    MatchPatternVariant::new_range(integer_literal, end, is_inclusive)
+                }
+                None => MatchPatternVariant::IntegerLiteral(integer_literal),
+            }
+        } else if let Some(mut elements) = self.elements.take() {
+            if !self.path_builder.is_empty() {
+                MatchPatternVariant::TupleVariant {
+                    path: self.path_builder.finish(),
+                    elements,
+                }
+            } else if elements.len() == 1 && !self.has_comma {
+                return elements
+                    .pop()
+                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+            } else {
+                MatchPatternVariant::Tuple(elements)
+            }
+        } else if let Some(fields) = self.fields.take() {
+            MatchPatternVariant::Structure {
+                path: self.path_builder.finish(),
+                fields,
+                is_rest_ignored: self.is_rest_ignored,
+            }
         } else if let Some(identifier) = self.binding.take() {
             MatchPatternVariant::Binding(identifier)
         } else if !self.path_builder.is_empty() {
@@ -114,7 +204,7 @@ impl Builder {
             panic!(
                 "{}{}",
                 zinc_const::panic::BUILDER_REQUIRES_VALUE,
-                "boolean | integer | binding | path | wildcard"
+                "boolean | integer | range | binding | path | wildcard | tuple | structure"
             );
         };
 
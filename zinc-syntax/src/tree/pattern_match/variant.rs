@@ -6,6 +6,7 @@ use crate::tree::expression::tree::Tree as ExpressionTree;
 use crate::tree::identifier::Identifier;
 use crate::tree::literal::boolean::Literal as BooleanLiteral;
 use crate::tree::literal::integer::Literal as IntegerLiteral;
+use crate::tree::pattern_match::Pattern;
 
 ///
 /// The match pattern variant.
@@ -16,12 +17,42 @@ pub enum Variant {
     BooleanLiteral(BooleanLiteral),
     /// An integer refutable literal pattern.
     IntegerLiteral(IntegerLiteral),
+    /// An integer refutable range pattern, e.g. `0..10` or `0..=9`.
+    Range {
+        /// The range start literal.
+        start: IntegerLiteral,
+        /// The range end literal.
+        end: IntegerLiteral,
+        /// Whether the range end is included with `..=`.
+        is_inclusive: bool,
+    },
     /// A variable irrefutable binding pattern.
     Binding(Identifier),
     /// An expression path refutable pattern, usually points to a constant or enumeration variant.
     Path(ExpressionTree),
     /// A wildcard irrefutable pattern.
     Wildcard,
+    /// A tuple pattern, e.g. `(value, 42, _)`.
+    Tuple(Vec<Pattern>),
+    /// An enumeration variant refutable pattern with unnamed fields, e.g. `Option::Some(value)`.
+    TupleVariant {
+        /// The enumeration variant path.
+        path: ExpressionTree,
+        /// The variant field patterns.
+        elements: Vec<Pattern>,
+    },
+    /// A structure or enumeration variant pattern with named fields, e.g. `Point { x, y: 0, .. }`.
+    Structure {
+        /// The structure type or enumeration variant path.
+        path: ExpressionTree,
+        /// The field patterns. A field without an explicit pattern is bound to a variable
+        /// with the same name.
+        fields: Vec<(Identifier, Pattern)>,
+        /// Whether the omitted fields are ignored with `..`.
+        is_rest_ignored: bool,
+    },
+    /// A pattern with alternatives, which matches if any of them matches, e.g. `1 | 2 | 3`.
+    Alternatives(Vec<Pattern>),
 }
 
 impl Variant {
@@ -39,6 +70,17 @@ impl Variant {
         Self::IntegerLiteral(literal)
     }
 
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_range(start: IntegerLiteral, end: IntegerLiteral, is_inclusive: bool) -> Self {
+        Self::Range {
+            start,
+            end,
+            is_inclusive,
+        }
+    }
+
     ///
     /// A shortcut constructor.
     ///
@@ -59,4 +101,40 @@ impl Variant {
     pub fn new_wildcard() -> Self {
         Self::Wildcard
     }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_tuple(elements: Vec<Pattern>) -> Self {
+        Self::Tuple(elements)
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_tuple_variant(path: ExpressionTree, elements: Vec<Pattern>) -> Self {
+        Self::TupleVariant { path, elements }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_structure(
+        path: ExpressionTree,
+        fields: Vec<(Identifier, Pattern)>,
+        is_rest_ignored: bool,
+    ) -> Self {
+        Self::Structure {
+            path,
+            fields,
+            is_rest_ignored,
+        }
+    }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new_alternatives(alternatives: Vec<Pattern>) -> Self {
+        Self::Alternatives(alternatives)
+    }
 }
@@ -0,0 +1,23 @@
+//!
+//! The `break` statement.
+//!
+
+use zinc_lexical::Location;
+
+///
+/// The `break` statement.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statement {
+    /// The location of the syntax construction.
+    pub location: Location,
+}
+
+impl Statement {
+    ///
+    /// Creates a `break` statement.
+    ///
+    pub fn new(location: Location) -> Self {
+        Self { location }
+    }
+}
@@ -9,6 +9,7 @@ use zinc_lexical::Location;
 use crate::tree::attribute::Attribute;
 use crate::tree::binding::Binding;
 use crate::tree::expression::block::Expression as BlockExpression;
+use crate::tree::generic_parameter::GenericParameter;
 use crate::tree::identifier::Identifier;
 use crate::tree::r#type::Type;
 
@@ -25,6 +26,8 @@ pub struct Statement {
     pub is_constant: bool,
     /// The function identifier.
     pub identifier: Identifier,
+    /// The function generic parameters, which are empty if the function is not generic.
+    pub generics: Vec<GenericParameter>,
     /// The function argument bindings list.
     pub argument_bindings: Vec<Binding>,
     /// The optional function return type, which is `()` if not specified.
@@ -45,6 +48,7 @@ impl Statement {
         is_public: bool,
         is_constant: bool,
         identifier: Identifier,
+        generics: Vec<GenericParameter>,
         argument_bindings: Vec<Binding>,
         return_type: Option<Type>,
         body: BlockExpression,
@@ -55,6 +59,7 @@ impl Statement {
             is_public,
             is_constant,
             identifier,
+            generics,
             argument_bindings,
             return_type,
             body,
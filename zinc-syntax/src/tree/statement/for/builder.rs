@@ -6,7 +6,7 @@ use zinc_lexical::Location;
 
 use crate::tree::expression::block::Expression as BlockExpression;
 use crate::tree::expression::tree::Tree as ExpressionTree;
-use crate::tree::identifier::Identifier;
+use crate::tree::pattern_binding::Pattern as BindingPattern;
 use crate::tree::statement::r#for::Statement as ForStatement;
 
 ///
@@ -16,9 +16,9 @@ use crate::tree::statement::r#for::Statement as ForStatement;
 pub struct Builder {
     /// The location of the syntax construction.
     location: Option<Location>,
-    /// The loop index variable identifier.
-    index_identifier: Option<Identifier>,
-    /// The loop index bounds range expression.
+    /// The loop item binding pattern.
+    pattern: Option<BindingPattern>,
+    /// The loop index bounds range or iterated array expression.
     bounds_expression: Option<ExpressionTree>,
     /// The optional loop `while` condition expression.
     while_condition: Option<ExpressionTree>,
@@ -37,8 +37,8 @@ impl Builder {
     ///
     /// Sets the corresponding builder value.
     ///
-    pub fn set_index_identifier(&mut self, value: Identifier) {
-        self.index_identifier = Some(value);
+    pub fn set_pattern(&mut self, value: BindingPattern) {
+        self.pattern = Some(value);
     }
 
     ///
@@ -77,12 +77,8 @@ impl Builder {
                     "location"
                 )
             }),
-            self.index_identifier.take().unwrap_or_else(|| {
-                panic!(
-                    "{}{}",
-                    zinc_const::panic::BUILDER_REQUIRES_VALUE,
-                    "index identifier"
-                )
+            self.pattern.take().unwrap_or_else(|| {
+                panic!("{}{}", zinc_const::panic::BUILDER_REQUIRES_VALUE, "pattern")
             }),
             self.bounds_expression.take().unwrap_or_else(|| {
                 panic!(
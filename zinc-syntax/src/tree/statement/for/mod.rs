@@ -8,7 +8,7 @@ use zinc_lexical::Location;
 
 use crate::tree::expression::block::Expression as BlockExpression;
 use crate::tree::expression::tree::Tree as ExpressionTree;
-use crate::tree::identifier::Identifier;
+use crate::tree::pattern_binding::Pattern as BindingPattern;
 
 ///
 /// The `for` statement.
@@ -17,9 +17,9 @@ use crate::tree::identifier::Identifier;
 pub struct Statement {
     /// The location of the syntax construction.
     pub location: Location,
-    /// The loop index variable identifier.
-    pub index_identifier: Identifier,
-    /// The loop index bounds range expression.
+    /// The loop item binding pattern, which is the index variable for range loops.
+    pub pattern: BindingPattern,
+    /// The loop index bounds range or iterated array expression.
     pub bounds_expression: ExpressionTree,
     /// The optional loop `while` condition expression.
     pub while_condition: Option<ExpressionTree>,
@@ -33,14 +33,14 @@ impl Statement {
     ///
     pub fn new(
         location: Location,
-        index_identifier: Identifier,
+        pattern: BindingPattern,
         bounds_expression: ExpressionTree,
         while_condition: Option<ExpressionTree>,
         block: BlockExpression,
     ) -> Self {
         Self {
             location,
-            index_identifier,
+            pattern,
             bounds_expression,
             while_condition,
             block,
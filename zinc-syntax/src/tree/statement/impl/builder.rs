@@ -4,7 +4,9 @@
 
 use zinc_lexical::Location;
 
+use crate::tree::generic_parameter::GenericParameter;
 use crate::tree::identifier::Identifier;
+use crate::tree::r#type::Type;
 use crate::tree::statement::local_impl::Statement as ImplementationLocalStatement;
 use crate::tree::statement::r#impl::Statement as ImplStatement;
 
@@ -15,8 +17,14 @@ use crate::tree::statement::r#impl::Statement as ImplStatement;
 pub struct Builder {
     /// The location of the syntax construction.
     location: Option<Location>,
+    /// The generic parameters, if the statement is `impl<{parameters}> ...`.
+    generics: Vec<GenericParameter>,
     /// The identifier of the implemented type.
     identifier: Option<Identifier>,
+    /// The generic arguments of the implemented type, e.g. `<A, u8>` in `impl<A> Pair<A, u8>`.
+    type_arguments: Option<Vec<Type>>,
+    /// The identifier of the implemented trait, if the statement is `impl {trait} for {type}`.
+    r#trait: Option<Identifier>,
     /// The implementation statements.
     statements: Vec<ImplementationLocalStatement>,
 }
@@ -29,6 +37,13 @@ impl Builder {
         self.location = Some(value);
     }
 
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_generics(&mut self, value: Vec<GenericParameter>) {
+        self.generics = value;
+    }
+
     ///
     /// Sets the corresponding builder value.
     ///
@@ -36,6 +51,22 @@ impl Builder {
         self.identifier = Some(value);
     }
 
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_type_arguments(&mut self, value: Vec<Type>) {
+        self.type_arguments = Some(value);
+    }
+
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    /// The identifier parsed before the `for` keyword is moved to the trait identifier.
+    ///
+    pub fn set_trait(&mut self) {
+        self.r#trait = self.identifier.take();
+    }
+
     ///
     /// Pushes the corresponding builder value.
     ///
@@ -58,6 +89,7 @@ impl Builder {
                     "location"
                 )
             }),
+            self.generics,
             self.identifier.take().unwrap_or_else(|| {
                 panic!(
                     "{}{}",
@@ -65,6 +97,8 @@ impl Builder {
                     "identifier"
                 )
             }),
+            self.type_arguments.take(),
+            self.r#trait.take(),
             self.statements,
         )
     }
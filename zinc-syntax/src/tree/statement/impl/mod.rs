@@ -6,7 +6,9 @@ pub mod builder;
 
 use zinc_lexical::Location;
 
+use crate::tree::generic_parameter::GenericParameter;
 use crate::tree::identifier::Identifier;
+use crate::tree::r#type::Type;
 use crate::tree::statement::local_impl::Statement as ImplementationLocalStatement;
 
 ///
@@ -16,8 +18,14 @@ use crate::tree::statement::local_impl::Statement as ImplementationLocalStatemen
 pub struct Statement {
     /// The location of the syntax construction.
     pub location: Location,
+    /// The generic parameters, if the statement is `impl<{parameters}> ...`.
+    pub generics: Vec<GenericParameter>,
     /// The identifier of the implemented type.
     pub identifier: Identifier,
+    /// The generic arguments of the implemented type, e.g. `<A, u8>` in `impl<A> Pair<A, u8>`.
+    pub type_arguments: Option<Vec<Type>>,
+    /// The identifier of the implemented trait, if the statement is `impl {trait} for {type}`.
+    pub r#trait: Option<Identifier>,
     /// The implementation statements.
     pub statements: Vec<ImplementationLocalStatement>,
 }
@@ -28,12 +36,18 @@ impl Statement {
     ///
     pub fn new(
         location: Location,
+        generics: Vec<GenericParameter>,
         identifier: Identifier,
+        type_arguments: Option<Vec<Type>>,
+        r#trait: Option<Identifier>,
         statements: Vec<ImplementationLocalStatement>,
     ) -> Self {
         Self {
             location,
+            generics,
             identifier,
+            type_arguments,
+            r#trait,
             statements,
         }
     }
@@ -11,6 +11,7 @@ use crate::tree::statement::r#enum::Statement as EnumStatement;
 use crate::tree::statement::r#fn::Statement as FnStatement;
 use crate::tree::statement::r#impl::Statement as ImplStatement;
 use crate::tree::statement::r#struct::Statement as StructStatement;
+use crate::tree::statement::r#trait::Statement as TraitStatement;
 use crate::tree::statement::r#type::Statement as TypeStatement;
 use crate::tree::statement::r#use::Statement as UseStatement;
 
@@ -33,6 +34,8 @@ pub enum Statement {
     Mod(ModStatement),
     /// The `use` statement.
     Use(UseStatement),
+    /// The `trait` statement.
+    Trait(TraitStatement),
     /// The `impl` statement.
     Impl(ImplStatement),
     /// The `contract` statement.
@@ -54,6 +57,7 @@ impl Statement {
             Self::Fn(inner) => inner.location,
             Self::Mod(inner) => inner.location,
             Self::Use(inner) => inner.location,
+            Self::Trait(inner) => inner.location,
             Self::Impl(inner) => inner.location,
             Self::Contract(inner) => inner.location,
             Self::Empty(location) => *location,
@@ -0,0 +1,30 @@
+//!
+//! The trait-local statement.
+//!
+
+use zinc_lexical::Location;
+
+use crate::tree::statement::r#trait::method::Method as TraitMethod;
+
+///
+/// The trait-level statement.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Statement {
+    /// The method signature.
+    Fn(TraitMethod),
+    /// The empty `;` statement.
+    Empty(Location),
+}
+
+impl Statement {
+    ///
+    /// The statement location.
+    ///
+    pub fn location(&self) -> Location {
+        match self {
+            Self::Fn(inner) => inner.location,
+            Self::Empty(location) => *location,
+        }
+    }
+}
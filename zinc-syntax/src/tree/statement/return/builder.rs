@@ -0,0 +1,54 @@
+//!
+//! The `return` statement builder.
+//!
+
+use zinc_lexical::Location;
+
+use crate::tree::expression::tree::Tree as ExpressionTree;
+use crate::tree::statement::r#return::Statement as ReturnStatement;
+
+///
+/// The `return` statement builder.
+///
+#[derive(Default)]
+pub struct Builder {
+    /// The location of the syntax construction.
+    location: Option<Location>,
+    /// The optional returned expression.
+    expression: Option<ExpressionTree>,
+}
+
+impl Builder {
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_location(&mut self, value: Location) {
+        self.location = Some(value);
+    }
+
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_expression(&mut self, value: ExpressionTree) {
+        self.expression = Some(value);
+    }
+
+    ///
+    /// Finalizes the builder and returns the built value.
+    ///
+    /// # Panics
+    /// If some of the required items has not been set.
+    ///
+    pub fn finish(mut self) -> ReturnStatement {
+        ReturnStatement::new(
+            self.location.take().unwrap_or_else(|| {
+                panic!(
+                    "{}{}",
+                    zinc_const::panic::BUILDER_REQUIRES_VALUE,
+                    "location"
+                )
+            }),
+            self.expression.take(),
+        )
+    }
+}
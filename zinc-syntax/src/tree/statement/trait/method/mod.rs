@@ -0,0 +1,49 @@
+//!
+//! The `trait` method signature.
+//!
+
+pub mod builder;
+
+use zinc_lexical::Location;
+
+use crate::tree::binding::Binding;
+use crate::tree::identifier::Identifier;
+use crate::tree::r#type::Type;
+
+///
+/// The `trait` method signature, which is a function statement without the body.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct Method {
+    /// The location of the syntax construction.
+    pub location: Location,
+    /// If the method is constant.
+    pub is_constant: bool,
+    /// The method identifier.
+    pub identifier: Identifier,
+    /// The method argument bindings list.
+    pub argument_bindings: Vec<Binding>,
+    /// The optional method return type, which is `()` if not specified.
+    pub return_type: Option<Type>,
+}
+
+impl Method {
+    ///
+    /// Creates a `trait` method signature.
+    ///
+    pub fn new(
+        location: Location,
+        is_constant: bool,
+        identifier: Identifier,
+        argument_bindings: Vec<Binding>,
+        return_type: Option<Type>,
+    ) -> Self {
+        Self {
+            location,
+            is_constant,
+            identifier,
+            argument_bindings,
+            return_type,
+        }
+    }
+}
@@ -30,6 +30,8 @@ pub struct Builder {
     path_expression: Option<ExpressionTree>,
     /// The optional generic type arguments.
     generics: Option<Vec<Type>>,
+    /// The referenced type, which means that the type is a mutable reference.
+    reference_type: Option<Type>,
 }
 
 /// The invalid type keyword panic, which is prevented by the type parser.
@@ -98,6 +100,13 @@ impl Builder {
         self.generics = Some(value);
     }
 
+    ///
+    /// Sets the corresponding builder value.
+    ///
+    pub fn set_reference_type(&mut self, value: Type) {
+        self.reference_type = Some(value);
+    }
+
     ///
     /// Finalizes the builder and returns the built value.
     ///
@@ -113,7 +122,9 @@ impl Builder {
             )
         });
 
-        let variant = if let Some(path) = self.path_expression.take() {
+        let variant = if let Some(inner) = self.reference_type.take() {
+            TypeVariant::mutable_reference(inner)
+        } else if let Some(path) = self.path_expression.take() {
             TypeVariant::alias(path, self.generics.take())
         } else if let Some(keyword) = self.keyword.take() {
             match keyword {
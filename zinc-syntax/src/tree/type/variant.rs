@@ -46,9 +46,14 @@ pub enum Variant {
     Alias {
         /// The path expression, which points to an aliased type.
         path: ExpressionTree,
-        /// The optional generic type arguments.
+        /// The optional generic arguments, where the constant ones are integer literal aliases.
         generics: Option<Vec<Type>>,
     },
+    /// `&mut {type}` in the source code.
+    MutableReference {
+        /// The referenced type.
+        inner: Box<Type>,
+    },
 }
 
 impl Variant {
@@ -121,4 +126,13 @@ impl Variant {
     pub fn alias(path: ExpressionTree, generics: Option<Vec<Type>>) -> Self {
         Self::Alias { path, generics }
     }
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn mutable_reference(inner: Type) -> Self {
+        Self::MutableReference {
+            inner: Box::new(inner),
+        }
+    }
 }
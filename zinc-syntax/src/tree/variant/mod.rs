@@ -3,12 +3,15 @@
 //!
 
 pub mod builder;
+pub mod payload;
 
 use zinc_lexical::Location;
 
 use crate::tree::identifier::Identifier;
 use crate::tree::literal::integer::Literal as IntegerLiteral;
 
+use self::payload::Payload;
+
 ///
 /// The enumeration variant.
 ///
@@ -18,19 +21,27 @@ pub struct Variant {
     pub location: Location,
     /// The enumeration variant identifier.
     pub identifier: Identifier,
-    /// The enumeration variant integer value.
-    pub literal: IntegerLiteral,
+    /// The enumeration variant integer value. If not set, the previous variant value plus one is used.
+    pub literal: Option<IntegerLiteral>,
+    /// The enumeration variant payload.
+    pub payload: Payload,
 }
 
 impl Variant {
     ///
     /// Creates an enumeration variant.
     ///
-    pub fn new(location: Location, identifier: Identifier, literal: IntegerLiteral) -> Self {
+    pub fn new(
+        location: Location,
+        identifier: Identifier,
+        literal: Option<IntegerLiteral>,
+        payload: Payload,
+    ) -> Self {
         Self {
             location,
             identifier,
             literal,
+            payload,
         }
     }
 }
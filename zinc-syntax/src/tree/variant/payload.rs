@@ -0,0 +1,19 @@
+//!
+//! The enumeration variant payload.
+//!
+
+use crate::tree::field::Field;
+use crate::tree::r#type::Type;
+
+///
+/// The enumeration variant payload, that is, the data carried by the variant.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Payload {
+    /// The variant without data, e.g. `None`.
+    Unit,
+    /// The variant with unnamed fields, e.g. `Some(u8)`.
+    Tuple(Vec<Type>),
+    /// The variant with named fields, e.g. `Point { x: u8, y: u8 }`.
+    Structure(Vec<Field>),
+}
@@ -11,6 +11,7 @@ use anyhow::Context;
 use zinc_compiler::EntryAnalyzer;
 use zinc_compiler::Error as CompilerError;
 use zinc_compiler::IBytecodeWritable;
+use zinc_compiler::OptimizationLevel;
 use zinc_compiler::Source;
 use zinc_compiler::ZincVMState;
 
@@ -29,7 +30,7 @@ pub struct Instance {
 
 impl Instance {
     ///
-    /// Creates a test instance.
+    /// Creates a test instance, optimizing its bytecode with `optimization_level`.
     ///
     pub fn new(
         name: String,
@@ -37,6 +38,7 @@ impl Instance {
         path: PathBuf,
         method: Option<String>,
         input: serde_json::Value,
+        optimization_level: OptimizationLevel,
     ) -> anyhow::Result<Self> {
         let project_type = if method.is_some() {
             zinc_project::ProjectType::Contract
@@ -66,7 +68,7 @@ impl Instance {
                 zinc_compiler::Module::new(scope.borrow().get_intermediate())
                     .write_to_zinc_vm(state.clone());
 
-                Ok(ZincVMState::unwrap_rc(state).into_application(true))
+                Ok(ZincVMState::unwrap_rc(state).into_application(optimization_level))
             })
             .expect(zinc_const::panic::SYNCHRONIZATION)
             .join()
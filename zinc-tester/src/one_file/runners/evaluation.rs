@@ -11,6 +11,8 @@ use colored::Colorize;
 use num::BigInt;
 use num::Zero;
 
+use zinc_compiler::OptimizationLevel;
+
 use zinc_vm::Bn256;
 use zinc_vm::CircuitFacade;
 use zinc_vm::ContractFacade;
@@ -81,6 +83,7 @@ impl IRunnable for Runner {
                 path.to_owned(),
                 case.method.clone(),
                 case.input,
+                OptimizationLevel::Full,
             ) {
                 Ok(application) => application,
                 Err(error) => {
@@ -3,6 +3,8 @@
 //!
 
 pub mod evaluation;
+pub mod optimization_check;
+pub mod proof_check;
 
 use std::path::PathBuf;
 use std::sync::Arc;
@@ -0,0 +1,258 @@
+//!
+//! The optimization-check test runner.
+//!
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use colored::Colorize;
+use num::BigInt;
+use num::Zero;
+
+use zinc_compiler::OptimizationLevel;
+
+use zinc_vm::Bn256;
+use zinc_vm::CircuitFacade;
+use zinc_vm::ContractFacade;
+use zinc_vm::ContractInput;
+
+use crate::error::Error;
+use crate::one_file::file::File;
+use crate::one_file::instance::Instance;
+use crate::one_file::metadata::Metadata;
+use crate::one_file::runners::IRunnable;
+use crate::summary::Summary;
+
+///
+/// The optimization-check runner.
+///
+/// Compiles each case without and with the optimizations, and checks that the optimized build
+/// returns the same result and does not produce more constraints.
+///
+#[derive(Clone)]
+pub struct Runner {
+    /// If zero, does not print the successful tests.
+    pub verbosity: usize,
+    /// If set, runs only the tests whose full names contain the string.
+    pub filter: Option<String>,
+}
+
+impl Runner {
+    ///
+    /// Creates a runner instance.
+    ///
+    pub fn new(verbosity: usize, filter: Option<String>) -> Self {
+        Self { verbosity, filter }
+    }
+
+    ///
+    /// Runs the instance and counts its constraints.
+    ///
+    /// Returns the output, or `None` if the instance has panicked, and the number of constraints,
+    /// which is only counted if the instance has not panicked.
+    ///
+    fn check(
+        instance: Instance,
+        method: Option<String>,
+    ) -> anyhow::Result<(Option<serde_json::Value>, usize)> {
+        match instance.application {
+            zinc_types::Application::Circuit(circuit) => {
+                let output = match CircuitFacade::new(circuit.clone())
+                    .run::<Bn256>(instance.input.clone())
+                {
+                    Ok(output) => output.result.into_json(),
+                    Err(_error) => return Ok((None, 0)),
+                };
+
+                let profiler = CircuitFacade::new(circuit).profile::<Bn256>(instance.input)?;
+
+                Ok((Some(output), profiler.total()))
+            }
+            zinc_types::Application::Contract(contract) => {
+                let method_name = method
+                    .unwrap_or_else(|| zinc_const::source::FUNCTION_MAIN_IDENTIFIER.to_owned());
+
+                let mut input = instance.input;
+                if method_name != zinc_const::contract::CONSTRUCTOR_IDENTIFIER {
+                    input.insert_contract_instance(BigInt::zero());
+                }
+
+                let contract_input = |input: zinc_types::Value| {
+                    let storage: Vec<zinc_types::ContractFieldValue> = contract
+                        .storage
+                        .clone()
+                        .into_iter()
+                        .map(zinc_types::ContractFieldValue::new_from_type)
+                        .collect();
+
+                    let mut storages = HashMap::with_capacity(1);
+                    storages.insert(
+                        zksync_types::Address::default(),
+                        zinc_types::Value::Contract(storage),
+                    );
+
+                    ContractInput::new(
+                        input,
+                        storages,
+                        method_name.clone(),
+                        zinc_types::TransactionMsg::default(),
+                    )
+                };
+
+                let output = match ContractFacade::new(contract.clone())
+                    .run::<Bn256>(contract_input(input.clone()))
+                {
+                    Ok(output) => output.result.into_json(),
+                    Err(_error) => return Ok((None, 0)),
+                };
+
+                let profiler = ContractFacade::new(contract.clone())
+                    .profile::<Bn256>(contract_input(input))?;
+
+                Ok((Some(output), profiler.total()))
+            }
+            zinc_types::Application::Library(_library) => {
+                anyhow::bail!(Error::CannotRunLibrary);
+            }
+        }
+    }
+}
+
+impl IRunnable for Runner {
+    fn run(
+        self,
+        path: PathBuf,
+        file: File,
+        metadata: Metadata,
+        summary: Arc<Mutex<Summary>>,
+    ) -> anyhow::Result<()> {
+        let path = match path.strip_prefix(crate::ONE_FILE_TESTS_DIRECTORY) {
+            Ok(path) => path,
+            Err(_error) => &path,
+        };
+
+        for case in metadata.cases.into_iter() {
+            let case_name = format!("{}::{}", path.to_string_lossy(), case.case);
+            if let Some(filter) = self.filter.as_ref() {
+                if !case_name.contains(filter) {
+                    continue;
+                }
+            }
+
+            if metadata.ignore || case.ignore {
+                summary
+                    .lock()
+                    .expect(zinc_const::panic::SYNCHRONIZATION)
+                    .ignored += 1;
+                println!("[INTEGRATION] {} {}", "IGNORE".yellow(), case_name);
+                continue;
+            }
+
+            let mut instances = Vec::with_capacity(2);
+            for optimization_level in [OptimizationLevel::None, OptimizationLevel::Full].iter() {
+                match Instance::new(
+                    case_name.clone(),
+                    file.code.as_str(),
+                    path.to_owned(),
+                    case.method.clone(),
+                    case.input.clone(),
+                    *optimization_level,
+                ) {
+                    Ok(instance) => instances.push(instance),
+                    Err(error) => {
+                        summary
+                            .lock()
+                            .expect(zinc_const::panic::SYNCHRONIZATION)
+                            .invalid += 1;
+                        println!(
+                            "[INTEGRATION] {} {}: {:?}",
+                            "INVALID".red(),
+                            case_name,
+                            error
+                        );
+                        break;
+                    }
+                }
+            }
+            if instances.len() != 2 {
+                continue;
+            }
+            let optimized = instances
+                .pop()
+                .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+            let unoptimized = instances
+                .pop()
+                .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+
+            let result = Self::check(unoptimized, case.method.clone()).and_then(|unoptimized| {
+                Self::check(optimized, case.method.clone())
+                    .map(|optimized| (unoptimized, optimized))
+            });
+
+            match result {
+                Ok(((unoptimized_output, unoptimized_constraints), (optimized_output, _)))
+                    if unoptimized_output != optimized_output =>
+                {
+                    summary
+                        .lock()
+                        .expect(zinc_const::panic::SYNCHRONIZATION)
+                        .failed += 1;
+                    println!(
+                        "[INTEGRATION] {} {} (expected {:?} with {} constraints, but got {:?})",
+                        "FAILED".bright_red(),
+                        case_name,
+                        unoptimized_output,
+                        unoptimized_constraints,
+                        optimized_output,
+                    );
+                }
+                Ok(((_, unoptimized_constraints), (_, optimized_constraints)))
+                    if optimized_constraints > unoptimized_constraints =>
+                {
+                    summary
+                        .lock()
+                        .expect(zinc_const::panic::SYNCHRONIZATION)
+                        .failed += 1;
+                    println!(
+                        "[INTEGRATION] {} {} (expected at most {} constraints, but got {})",
+                        "FAILED".bright_red(),
+                        case_name,
+                        unoptimized_constraints,
+                        optimized_constraints,
+                    );
+                }
+                Ok(((_, unoptimized_constraints), (_, optimized_constraints))) => {
+                    summary
+                        .lock()
+                        .expect(zinc_const::panic::SYNCHRONIZATION)
+                        .passed += 1;
+                    if self.verbosity > 0 {
+                        println!(
+                            "[INTEGRATION] {} {} ({} -> {} constraints)",
+                            "PASSED".green(),
+                            case_name,
+                            unoptimized_constraints,
+                            optimized_constraints,
+                        );
+                    }
+                }
+                Err(error) => {
+                    summary
+                        .lock()
+                        .expect(zinc_const::panic::SYNCHRONIZATION)
+                        .failed += 1;
+                    println!(
+                        "[INTEGRATION] {} {}: {}",
+                        "FAILED".bright_red(),
+                        case_name,
+                        error
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
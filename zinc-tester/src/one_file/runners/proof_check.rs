@@ -0,0 +1,242 @@
+//!
+//! The proof-check test runner.
+//!
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::Mutex;
+
+use colored::Colorize;
+use num::BigInt;
+use num::Zero;
+
+use zinc_compiler::OptimizationLevel;
+
+use zinc_vm::Bn256;
+use zinc_vm::CircuitFacade;
+use zinc_vm::ContractFacade;
+use zinc_vm::ContractInput;
+use zinc_vm::Facade;
+
+use crate::error::Error;
+use crate::one_file::file::File;
+use crate::one_file::instance::Instance;
+use crate::one_file::metadata::Metadata;
+use crate::one_file::runners::IRunnable;
+use crate::summary::Summary;
+
+///
+/// The proof-check runner.
+///
+/// Makes the trusted setup, generates the proof, checks the result, and verifies the proof.
+/// The cases which are expected to panic are skipped, since no proof can be generated for them.
+///
+#[derive(Clone)]
+pub struct Runner {
+    /// If zero, does not print the successful tests.
+    pub verbosity: usize,
+    /// If set, runs only the tests whose full names contain the string.
+    pub filter: Option<String>,
+}
+
+impl Runner {
+    ///
+    /// Creates a runner instance.
+    ///
+    pub fn new(verbosity: usize, filter: Option<String>) -> Self {
+        Self { verbosity, filter }
+    }
+
+    ///
+    /// Makes the trusted setup, proves and verifies the circuit.
+    ///
+    /// Returns the circuit output and the verification result.
+    ///
+    fn check_circuit(
+        circuit: zinc_types::Circuit,
+        input: zinc_types::Value,
+    ) -> anyhow::Result<(serde_json::Value, bool)> {
+        let parameters = CircuitFacade::new(circuit.clone()).setup::<Bn256>()?;
+        let verifying_key = parameters.vk.clone();
+        let (output, proof) = CircuitFacade::new(circuit).prove::<Bn256>(parameters, input)?;
+
+        let output_json = output.clone().into_json();
+        let is_verified = Facade::verify(verifying_key, proof, output)?;
+
+        Ok((output_json, is_verified))
+    }
+
+    ///
+    /// Makes the trusted setup, proves and verifies the contract method.
+    ///
+    /// Returns the contract method output and the verification result.
+    ///
+    fn check_contract(
+        contract: zinc_types::Contract,
+        method_name: String,
+        input: ContractInput,
+    ) -> anyhow::Result<(serde_json::Value, bool)> {
+        let parameters =
+            ContractFacade::new(contract.clone()).setup::<Bn256>(method_name.clone())?;
+        let verifying_key = parameters.vk.clone();
+        let (output, proof) =
+            ContractFacade::new(contract.clone()).prove::<Bn256>(parameters, input)?;
+
+        let output_json = output.clone().into_json();
+        let public_input =
+            ContractFacade::new(contract).public_input(method_name.as_str(), output)?;
+        let is_verified = Facade::verify(verifying_key, proof, public_input)?;
+
+        Ok((output_json, is_verified))
+    }
+}
+
+impl IRunnable for Runner {
+    fn run(
+        self,
+        path: PathBuf,
+        file: File,
+        metadata: Metadata,
+        summary: Arc<Mutex<Summary>>,
+    ) -> anyhow::Result<()> {
+        let path = match path.strip_prefix(crate::ONE_FILE_TESTS_DIRECTORY) {
+            Ok(path) => path,
+            Err(_error) => &path,
+        };
+
+        for case in metadata.cases.into_iter() {
+            let case_name = format!("{}::{}", path.to_string_lossy(), case.case);
+            if let Some(filter) = self.filter.as_ref() {
+                if !case_name.contains(filter) {
+                    continue;
+                }
+            }
+
+            if case.should_panic {
+                continue;
+            }
+
+            if metadata.ignore || case.ignore {
+                summary
+                    .lock()
+                    .expect(zinc_const::panic::SYNCHRONIZATION)
+                    .ignored += 1;
+                println!("[INTEGRATION] {} {}", "IGNORE".yellow(), case_name);
+                continue;
+            }
+
+            let mut instance = match Instance::new(
+                case_name.clone(),
+                file.code.as_str(),
+                path.to_owned(),
+                case.method.clone(),
+                case.input,
+                OptimizationLevel::Full,
+            ) {
+                Ok(application) => application,
+                Err(error) => {
+                    summary
+                        .lock()
+                        .expect(zinc_const::panic::SYNCHRONIZATION)
+                        .invalid += 1;
+                    println!(
+                        "[INTEGRATION] {} {}: {:?}",
+                        "INVALID".red(),
+                        case_name,
+                        error
+                    );
+                    continue;
+                }
+            };
+
+            let result = match instance.application {
+                zinc_types::Application::Circuit(circuit) => {
+                    Self::check_circuit(circuit, instance.input)
+                }
+                zinc_types::Application::Contract(contract) => {
+                    let storage: Vec<zinc_types::ContractFieldValue> = contract
+                        .storage
+                        .clone()
+                        .into_iter()
+                        .map(zinc_types::ContractFieldValue::new_from_type)
+                        .collect();
+
+                    let method_name = case
+                        .method
+                        .unwrap_or_else(|| zinc_const::source::FUNCTION_MAIN_IDENTIFIER.to_owned());
+                    if method_name != zinc_const::contract::CONSTRUCTOR_IDENTIFIER {
+                        instance.input.insert_contract_instance(BigInt::zero());
+                    }
+
+                    let mut storages = HashMap::with_capacity(1);
+                    storages.insert(
+                        zksync_types::Address::default(),
+                        zinc_types::Value::Contract(storage),
+                    );
+                    let input = ContractInput::new(
+                        instance.input,
+                        storages,
+                        method_name.clone(),
+                        zinc_types::TransactionMsg::default(),
+                    );
+
+                    Self::check_contract(contract, method_name, input)
+                }
+                zinc_types::Application::Library(_library) => {
+                    anyhow::bail!(Error::CannotRunLibrary);
+                }
+            };
+
+            match result {
+                Ok((result_json, true)) if case.output == result_json => {
+                    summary
+                        .lock()
+                        .expect(zinc_const::panic::SYNCHRONIZATION)
+                        .passed += 1;
+                    if self.verbosity > 0 {
+                        println!("[INTEGRATION] {} {}", "PASSED".green(), case_name);
+                    }
+                }
+                Ok((result_json, true)) => {
+                    summary
+                        .lock()
+                        .expect(zinc_const::panic::SYNCHRONIZATION)
+                        .failed += 1;
+                    println!(
+                        "[INTEGRATION] {} {} (expected {}, but got {})",
+                        "FAILED".bright_red(),
+                        case_name,
+                        case.output,
+                        result_json
+                    );
+                }
+                Ok((_result_json, false)) => {
+                    summary
+                        .lock()
+                        .expect(zinc_const::panic::SYNCHRONIZATION)
+                        .failed += 1;
+                    println!(
+                        "[INTEGRATION] {} {} (verification failed)",
+                        "FAILED".bright_red(),
+                        case_name
+                    );
+                }
+                Err(error) => {
+                    summary
+                        .lock()
+                        .expect(zinc_const::panic::SYNCHRONIZATION)
+                        .failed += 1;
+                    println!(
+                        "[INTEGRATION] {} {}: {}",
+                        "FAILED".bright_red(),
+                        case_name,
+                        error
+                    );
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -24,6 +24,14 @@ pub struct Arguments {
     /// Runs only tests whose name contains the specified string.
     #[structopt(short = "f", long = "filter")]
     pub filter: Option<String>,
+
+    /// Makes the trusted setup, proves and verifies the one-file tests instead of just running them.
+    #[structopt(long = "proof-check")]
+    pub proof_check: bool,
+
+    /// Checks that the optimized one-file tests return the same results with no more constraints.
+    #[structopt(long = "optimization-check")]
+    pub optimization_check: bool,
 }
 
 impl Arguments {
@@ -25,11 +25,25 @@ fn main() -> anyhow::Result<()> {
     let summary = zinc_tester::Summary::default().wrap();
 
     println!("[INTEGRATION] Running one-file tests");
-    zinc_tester::OneFileTestsDirectory::new(&PathBuf::from(zinc_tester::ONE_FILE_TESTS_DIRECTORY))?
-        .run(
+    let one_file_tests_directory = zinc_tester::OneFileTestsDirectory::new(&PathBuf::from(
+        zinc_tester::ONE_FILE_TESTS_DIRECTORY,
+    ))?;
+    if args.proof_check {
+        one_file_tests_directory.run(
+            zinc_tester::ProofCheckRunner::new(args.verbosity, args.filter),
+            summary.clone(),
+        );
+    } else if args.optimization_check {
+        one_file_tests_directory.run(
+            zinc_tester::OptimizationCheckRunner::new(args.verbosity, args.filter),
+            summary.clone(),
+        );
+    } else {
+        one_file_tests_directory.run(
             zinc_tester::EvaluationRunner::new(args.verbosity, args.filter),
             summary.clone(),
         );
+    }
 
     println!("[INTEGRATION] Running project tests");
     zinc_tester::OrdinarTestsDirectory::new(&PathBuf::from(
@@ -24,6 +24,8 @@ pub struct Contract {
     pub name: String,
     /// The contract storage structure.
     pub storage: Vec<ContractFieldType>,
+    /// The contract storage Merkle tree hasher.
+    pub storage_hasher: zinc_project::StorageHasher,
     /// The contract methods.
     pub methods: HashMap<String, Method>,
     /// The contract unit tests.
@@ -39,6 +41,7 @@ impl Contract {
     pub fn new(
         name: String,
         storage: Vec<ContractFieldType>,
+        storage_hasher: zinc_project::StorageHasher,
         methods: HashMap<String, Method>,
         unit_tests: HashMap<String, UnitTest>,
         instructions: Vec<Instruction>,
@@ -46,6 +49,7 @@ impl Contract {
         Self {
             name,
             storage,
+            storage_hasher,
             methods,
             unit_tests,
             instructions,
@@ -0,0 +1,172 @@
+//!
+//! The bytecode application disassembly.
+//!
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::application::Application;
+use crate::instructions::Instruction;
+
+///
+/// The bytecode application disassembly.
+///
+/// Lists the instructions of each function along with their addresses, resolving the call
+/// targets to function names. The debug location markers are not listed themselves, but are
+/// turned into the source code file and line annotations.
+///
+pub struct Disassembly<'a> {
+    /// The application being disassembled.
+    application: &'a Application,
+    /// The function names by their addresses.
+    functions: HashMap<usize, String>,
+    /// The entry point descriptions by their addresses.
+    entries: BTreeMap<usize, Vec<String>>,
+}
+
+impl<'a> Disassembly<'a> {
+    ///
+    /// Collects the function addresses and entry points of the `application`.
+    ///
+    pub fn new(application: &'a Application) -> Self {
+        let instructions = application.instructions();
+
+        let mut functions = HashMap::new();
+        for (index, instruction) in instructions.iter().enumerate() {
+            if let Instruction::FunctionMarker(marker) = instruction {
+                let address = match index.checked_sub(1).map(|index| &instructions[index]) {
+                    Some(Instruction::FileMarker(_)) => index - 1,
+                    _ => index,
+                };
+                functions.insert(address, marker.function.to_owned());
+            }
+        }
+
+        let mut entries: BTreeMap<usize, Vec<String>> = BTreeMap::new();
+        let unit_tests = match application {
+            Application::Circuit(circuit) => {
+                entries
+                    .entry(circuit.address)
+                    .or_default()
+                    .push(format!("circuit `{}` entry", circuit.name));
+                &circuit.unit_tests
+            }
+            Application::Contract(contract) => {
+                for (name, method) in contract.methods.iter() {
+                    entries.entry(method.address).or_default().push(format!(
+                        "{} method `{}`",
+                        if method.is_mutable {
+                            "mutable"
+                        } else {
+                            "immutable"
+                        },
+                        name
+                    ));
+                }
+                &contract.unit_tests
+            }
+            Application::Library(library) => &library.unit_tests,
+        };
+        for (name, unit_test) in unit_tests.iter() {
+            let mut description = format!("unit test `{}`", name);
+            if unit_test.should_panic {
+                description.push_str(", should panic");
+            }
+            if unit_test.is_ignored {
+                description.push_str(", ignored");
+            }
+            entries
+                .entry(unit_test.address)
+                .or_default()
+                .push(description);
+        }
+        for descriptions in entries.values_mut() {
+            descriptions.sort();
+        }
+
+        Self {
+            application,
+            functions,
+            entries,
+        }
+    }
+
+    ///
+    /// Returns the function name at `address`, or the address itself if it is not known.
+    ///
+    fn function_name(&self, address: usize) -> String {
+        match self.functions.get(&address) {
+            Some(name) => format!("<{}>", name),
+            None => format!("{:04}", address),
+        }
+    }
+}
+
+impl<'a> fmt::Display for Disassembly<'a> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (kind, name) = match self.application {
+            Application::Circuit(circuit) => ("circuit", circuit.name.as_str()),
+            Application::Contract(contract) => ("contract", contract.name.as_str()),
+            Application::Library(library) => ("library", library.name.as_str()),
+        };
+        let instructions = self.application.instructions();
+        writeln!(
+            f,
+            "; {} `{}`, {} instructions",
+            kind,
+            name,
+            instructions.len()
+        )?;
+        for (address, descriptions) in self.entries.iter() {
+            for description in descriptions.iter() {
+                writeln!(
+                    f,
+                    "; {:04} {} {}",
+                    address,
+                    self.function_name(*address),
+                    description
+                )?;
+            }
+        }
+
+        let mut file: Option<&str> = None;
+        let mut line: Option<usize> = None;
+        for (address, instruction) in instructions.iter().enumerate() {
+            if let Some(function) = self.functions.get(&address) {
+                writeln!(f)?;
+                write!(f, "{:04} <{}>:", address, function)?;
+                if let Some(descriptions) = self.entries.get(&address) {
+                    write!(f, " ; {}", descriptions.join("; "))?;
+                }
+                writeln!(f)?;
+            }
+
+            match instruction {
+                Instruction::FileMarker(marker) => {
+                    if file != Some(marker.file.as_str()) {
+                        line = None;
+                    }
+                    file = Some(marker.file.as_str());
+                }
+                Instruction::LineMarker(marker) => {
+                    if line != Some(marker.line) {
+                        writeln!(f, "    ; {}:{}", file.unwrap_or("<unknown>"), marker.line)?;
+                    }
+                    line = Some(marker.line);
+                }
+                Instruction::FunctionMarker(_) | Instruction::ColumnMarker(_) => {}
+                Instruction::Call(call) => writeln!(
+                    f,
+                    "    {:04}  {}  ; -> {}",
+                    address,
+                    instruction,
+                    self.function_name(call.address)
+                )?,
+                instruction => writeln!(f, "    {:04}  {}", address, instruction)?,
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -4,6 +4,7 @@
 
 pub mod circuit;
 pub mod contract;
+pub mod disassembly;
 pub mod library;
 pub mod unit_test;
 
@@ -66,6 +67,7 @@ impl Application {
     pub fn new_contract(
         name: String,
         storage: Vec<ContractFieldType>,
+        storage_hasher: zinc_project::StorageHasher,
         methods: HashMap<String, ContractMethod>,
         unit_tests: HashMap<String, UnitTest>,
         instructions: Vec<Instruction>,
@@ -73,6 +75,7 @@ impl Application {
         Self::Contract(Contract::new(
             name,
             storage,
+            storage_hasher,
             methods,
             unit_tests,
             instructions,
@@ -44,6 +44,13 @@ pub enum Type {
     /// The contract type.
     Contract(Vec<ContractField>),
 
+    /// The `std::collections::Vec` type.
+    Vec {
+        /// The vector element type.
+        r#type: Box<Type>,
+        /// The vector capacity.
+        capacity: usize,
+    },
     /// The `std::collections::MTreeMap` type.
     Map {
         /// The map key type.
@@ -100,6 +107,18 @@ impl Type {
                 .flatten()
                 .collect(),
 
+            Self::Vec { r#type, capacity } => {
+                let mut types: Vec<ScalarType> =
+                    vec![Self::into_flat_scalar_types(*r#type); capacity]
+                        .into_iter()
+                        .flatten()
+                        .collect();
+                types.push(ScalarType::Integer(IntegerType::new(
+                    false,
+                    zinc_const::bitlength::INDEX,
+                )));
+                types
+            }
             Self::Map { .. } => vec![],
         }
     }
@@ -131,6 +150,7 @@ impl Type {
             Self::Structure(fields) => fields.iter().map(|(_, r#type)| r#type.size()).sum(),
             Self::Contract(_) => Self::eth_address().size(),
 
+            Self::Vec { r#type, capacity } => r#type.size() * *capacity + 1,
             Self::Map { .. } => 0,
         }
     }
@@ -196,6 +216,9 @@ impl fmt::Display for Type {
                     .join(", ")
             ),
 
+            Self::Vec { r#type, capacity } => {
+                write!(f, "std::collections::Vec<{}, {}>", r#type, capacity)
+            }
             Self::Map {
                 key_type,
                 value_type,
@@ -10,6 +10,7 @@ use std::collections::HashSet;
 use anyhow::Context;
 use num::BigInt;
 use num::Signed;
+use num::ToPrimitive;
 use num::Zero;
 use serde::Deserialize;
 use serde::Serialize;
@@ -50,6 +51,13 @@ pub enum Value {
     /// Represented with JSON object.
     Contract(Vec<ContractField>),
 
+    /// The `std::collections::Vec` value, represented with JSON array of its actual elements.
+    Vec {
+        /// The vector elements, which are padded with default values up to the vector capacity.
+        values: Vec<Value>,
+        /// The vector length.
+        length: usize,
+    },
     /// The `std::collections::MTreeMap` value.
     Map(Vec<(Value, Value)>),
 }
@@ -101,6 +109,10 @@ impl Value {
                     .collect(),
             ),
 
+            Type::Vec { r#type, capacity } => Self::Vec {
+                values: vec![Self::new(*r#type); capacity],
+                length: 0,
+            },
             Type::Map { .. } => Self::Map(vec![]),
         }
     }
@@ -122,6 +134,7 @@ impl Value {
             Type::Structure(fields) => Self::structure_from_json(value, fields),
             Type::Contract(fields) => Self::contract_from_json(value, fields),
 
+            Type::Vec { r#type, capacity } => Self::vec_from_json(value, *r#type, capacity),
             Type::Map {
                 key_type,
                 value_type,
@@ -210,6 +223,20 @@ impl Value {
                 Self::Contract(result)
             }
 
+            Type::Vec { r#type, capacity } => {
+                let mut offset = 0;
+                let mut values = Vec::with_capacity(capacity);
+                for _ in 0..capacity {
+                    let slice = &flat_values[offset..];
+                    offset += r#type.size();
+                    values.push(Self::from_flat_values(*r#type.clone(), slice));
+                }
+                let length = flat_values
+                    .get(offset)
+                    .and_then(|length| length.to_usize())
+                    .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+                Self::Vec { values, length }
+            }
             Type::Map { .. } => Self::Map(vec![]),
         }
     }
@@ -241,6 +268,14 @@ impl Value {
                 .flatten()
                 .collect(),
 
+            Self::Vec { values, length } => {
+                let mut flat_values: Vec<BigInt> = values
+                    .into_iter()
+                    .flat_map(Self::into_flat_values)
+                    .collect();
+                flat_values.push(BigInt::from(length));
+                flat_values
+            }
             Self::Map(_entries) => vec![],
         }
     }
@@ -293,6 +328,13 @@ impl Value {
                 serde_json::Value::Object(object)
             }
 
+            Self::Vec { values, length } => serde_json::Value::Array(
+                values
+                    .into_iter()
+                    .take(length)
+                    .map(Self::into_json)
+                    .collect(),
+            ),
             Self::Map(entries) => {
                 let mut array = Vec::with_capacity(entries.len());
                 for (key, value) in entries.into_iter() {
@@ -591,6 +633,42 @@ impl Value {
         Ok(Self::Contract(field_values))
     }
 
+    ///
+    /// Creates an `std::collections::Vec` value from the JSON `value`.
+    ///
+    /// The array may be shorter than the vector `capacity`, so the rest is padded with
+    /// default values of `r#type`.
+    ///
+    fn vec_from_json(
+        value: serde_json::Value,
+        r#type: Type,
+        capacity: usize,
+    ) -> anyhow::Result<Self> {
+        let array = value
+            .as_array()
+            .cloned()
+            .ok_or_else(|| Error::type_error("JSON array".to_owned(), value))?;
+
+        if array.len() > capacity {
+            anyhow::bail!(Error::CapacityExceeded {
+                capacity,
+                found: array.len(),
+            });
+        }
+
+        let length = array.len();
+        let mut values = Vec::with_capacity(capacity);
+        for (index, value) in array.into_iter().enumerate() {
+            let typed_value = Self::try_from_typed_json(value, r#type.clone())
+                .with_context(|| format!("[{}]", index))?;
+
+            values.push(typed_value);
+        }
+        values.resize(capacity, Self::new(r#type));
+
+        Ok(Self::Vec { values, length })
+    }
+
     ///
     /// Creates an `std::collections::MTreeMap` value from the JSON `value`.
     ///
@@ -47,6 +47,15 @@ pub enum Error {
         found: usize,
     },
 
+    /// The vector input has more elements than the vector capacity.
+    #[error("expected at most {capacity} values, but found {found}")]
+    CapacityExceeded {
+        /// The vector capacity.
+        capacity: usize,
+        /// The actual number of values.
+        found: usize,
+    },
+
     /// The map input is malformed.
     #[error("expected an array with `key` and `value` fields, found `{0}`")]
     InvalidMapFormat(String),
@@ -16,6 +16,12 @@ pub enum LibraryFunctionIdentifier {
     CryptoPedersen,
     /// The `std::crypto::schnorr::Signature::verify` function identifier.
     CryptoSchnorrSignatureVerify,
+    /// The `std::crypto::blake2s` function identifier.
+    CryptoBlake2s,
+    /// The `std::crypto::poseidon` function identifier.
+    CryptoPoseidon,
+    /// The `std::crypto::rescue` function identifier.
+    CryptoRescue,
 
     /// The `std::convert::to_bits` function identifier.
     ConvertToBits,
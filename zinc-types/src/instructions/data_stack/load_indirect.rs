@@ -0,0 +1,50 @@
+//!
+//! The `load from stack by reference` instruction.
+//!
+
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::instructions::Instruction;
+
+///
+/// The `load from stack by reference` instruction.
+///
+/// Takes `reference` from evaluation stack, loads several values from the data stack
+/// at the absolute `reference` address onto the evaluation stack.
+///
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LoadIndirect {
+    /// The size of the data chunk which must be loaded.
+    pub size: usize,
+}
+
+impl LoadIndirect {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(size: usize) -> Self {
+        Self { size }
+    }
+
+    ///
+    /// If the instruction is for the debug mode only.
+    ///
+    pub fn is_debug(&self) -> bool {
+        false
+    }
+}
+
+impl From<LoadIndirect> for Instruction {
+    fn from(inner: LoadIndirect) -> Self {
+        Self::LoadIndirect(inner)
+    }
+}
+
+impl fmt::Display for LoadIndirect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "load_indirect {}", self.size)
+    }
+}
@@ -0,0 +1,59 @@
+//!
+//! The `load from stack by reference and index` instruction.
+//!
+
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::instructions::Instruction;
+
+///
+/// The `load from stack by reference and index` instruction.
+///
+/// Takes `reference` and `index` from evaluation stack, loads several values from the data stack
+/// at the absolute `reference + index` address onto the evaluation stack.
+///
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LoadIndirectByIndex {
+    /// The size of the data chunk which must be loaded.
+    pub value_size: usize,
+    /// The total size of the data chunk where the linear scan must be applied.
+    pub total_size: usize,
+}
+
+impl LoadIndirectByIndex {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(value_size: usize, total_size: usize) -> Self {
+        Self {
+            value_size,
+            total_size,
+        }
+    }
+
+    ///
+    /// If the instruction is for the debug mode only.
+    ///
+    pub fn is_debug(&self) -> bool {
+        false
+    }
+}
+
+impl From<LoadIndirectByIndex> for Instruction {
+    fn from(inner: LoadIndirectByIndex) -> Self {
+        Self::LoadIndirectByIndex(inner)
+    }
+}
+
+impl fmt::Display for LoadIndirectByIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "load_indirect_by_index {} {}",
+            self.value_size, self.total_size
+        )
+    }
+}
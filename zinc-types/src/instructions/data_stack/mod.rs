@@ -4,5 +4,10 @@
 
 pub mod load;
 pub mod load_by_index;
+pub mod load_indirect;
+pub mod load_indirect_by_index;
+pub mod reference;
 pub mod store;
 pub mod store_by_index;
+pub mod store_indirect;
+pub mod store_indirect_by_index;
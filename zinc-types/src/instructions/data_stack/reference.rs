@@ -0,0 +1,52 @@
+//!
+//! The `take stack reference` instruction.
+//!
+
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::instructions::Instruction;
+
+///
+/// The `take stack reference` instruction.
+///
+/// Pushes the absolute data stack address of the current frame `address` onto the evaluation
+/// stack, so the data can be loaded and stored by the called functions.
+///
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Reference {
+    /// The start address of the referenced data in the current frame.
+    pub address: usize,
+    /// The size of the referenced data, which may be read and written via the reference.
+    pub size: usize,
+}
+
+impl Reference {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(address: usize, size: usize) -> Self {
+        Self { address, size }
+    }
+
+    ///
+    /// If the instruction is for the debug mode only.
+    ///
+    pub fn is_debug(&self) -> bool {
+        false
+    }
+}
+
+impl From<Reference> for Instruction {
+    fn from(inner: Reference) -> Self {
+        Self::Reference(inner)
+    }
+}
+
+impl fmt::Display for Reference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "reference {} {}", self.address, self.size)
+    }
+}
@@ -0,0 +1,50 @@
+//!
+//! The `store to stack by reference` instruction.
+//!
+
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::instructions::Instruction;
+
+///
+/// The `store to stack by reference` instruction.
+///
+/// Takes `reference` and several values from evaluation stack,
+/// stores the values in the data stack at the absolute `reference` address.
+///
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StoreIndirect {
+    /// The size of data that must be stored from the bottom to top.
+    pub size: usize,
+}
+
+impl StoreIndirect {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(size: usize) -> Self {
+        Self { size }
+    }
+
+    ///
+    /// If the instruction is for the debug mode only.
+    ///
+    pub fn is_debug(&self) -> bool {
+        false
+    }
+}
+
+impl From<StoreIndirect> for Instruction {
+    fn from(inner: StoreIndirect) -> Self {
+        Self::StoreIndirect(inner)
+    }
+}
+
+impl fmt::Display for StoreIndirect {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "store_indirect {}", self.size)
+    }
+}
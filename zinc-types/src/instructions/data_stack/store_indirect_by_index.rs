@@ -0,0 +1,59 @@
+//!
+//! The `store to stack by reference and index` instruction.
+//!
+
+use std::fmt;
+
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::instructions::Instruction;
+
+///
+/// The `store to stack by reference and index` instruction.
+///
+/// Takes `reference`, `index` and several values from evaluation stack,
+/// stores the values in the data stack at the absolute `reference + index` address.
+///
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StoreIndirectByIndex {
+    /// The size of the data chunk which must be stored.
+    pub value_size: usize,
+    /// The total size of the data chunk where the linear scan must be applied.
+    pub total_size: usize,
+}
+
+impl StoreIndirectByIndex {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(value_size: usize, total_size: usize) -> Self {
+        Self {
+            value_size,
+            total_size,
+        }
+    }
+
+    ///
+    /// If the instruction is for the debug mode only.
+    ///
+    pub fn is_debug(&self) -> bool {
+        false
+    }
+}
+
+impl From<StoreIndirectByIndex> for Instruction {
+    fn from(inner: StoreIndirectByIndex) -> Self {
+        Self::StoreIndirectByIndex(inner)
+    }
+}
+
+impl fmt::Display for StoreIndirectByIndex {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "store_indirect_by_index {} {}",
+            self.value_size, self.total_size
+        )
+    }
+}
@@ -25,8 +25,13 @@ use self::contract::load::StorageLoad;
 use self::contract::store::StorageStore;
 use self::data_stack::load::Load;
 use self::data_stack::load_by_index::LoadByIndex;
+use self::data_stack::load_indirect::LoadIndirect;
+use self::data_stack::load_indirect_by_index::LoadIndirectByIndex;
+use self::data_stack::reference::Reference;
 use self::data_stack::store::Store;
 use self::data_stack::store_by_index::StoreByIndex;
+use self::data_stack::store_indirect::StoreIndirect;
+use self::data_stack::store_indirect_by_index::StoreIndirectByIndex;
 use self::dbg::Dbg;
 use self::evaluation_stack::copy::Copy;
 use self::evaluation_stack::push::Push;
@@ -91,6 +96,16 @@ pub enum Instruction {
     Store(Store),
     /// A data stack instruction.
     StoreByIndex(StoreByIndex),
+    /// A data stack instruction.
+    Reference(Reference),
+    /// A data stack instruction.
+    LoadIndirect(LoadIndirect),
+    /// A data stack instruction.
+    LoadIndirectByIndex(LoadIndirectByIndex),
+    /// A data stack instruction.
+    StoreIndirect(StoreIndirect),
+    /// A data stack instruction.
+    StoreIndirectByIndex(StoreIndirectByIndex),
 
     /// A contract storage instruction.
     StorageInit(StorageInit),
@@ -200,6 +215,11 @@ impl Instruction {
             Self::LoadByIndex(inner) => inner.is_debug(),
             Self::Store(inner) => inner.is_debug(),
             Self::StoreByIndex(inner) => inner.is_debug(),
+            Self::Reference(inner) => inner.is_debug(),
+            Self::LoadIndirect(inner) => inner.is_debug(),
+            Self::LoadIndirectByIndex(inner) => inner.is_debug(),
+            Self::StoreIndirect(inner) => inner.is_debug(),
+            Self::StoreIndirectByIndex(inner) => inner.is_debug(),
 
             Self::StorageInit(inner) => inner.is_debug(),
             Self::StorageFetch(inner) => inner.is_debug(),
@@ -267,6 +287,11 @@ impl fmt::Display for Instruction {
             Self::LoadByIndex(inner) => write!(f, "{}", inner),
             Self::Store(inner) => write!(f, "{}", inner),
             Self::StoreByIndex(inner) => write!(f, "{}", inner),
+            Self::Reference(inner) => write!(f, "{}", inner),
+            Self::LoadIndirect(inner) => write!(f, "{}", inner),
+            Self::LoadIndirectByIndex(inner) => write!(f, "{}", inner),
+            Self::StoreIndirect(inner) => write!(f, "{}", inner),
+            Self::StoreIndirectByIndex(inner) => write!(f, "{}", inner),
 
             Self::StorageInit(inner) => write!(f, "{}", inner),
             Self::StorageFetch(inner) => write!(f, "{}", inner),
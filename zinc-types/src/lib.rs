@@ -15,6 +15,7 @@ pub(crate) mod utils;
 pub use self::application::circuit::Circuit;
 pub use self::application::contract::method::Method as ContractMethod;
 pub use self::application::contract::Contract;
+pub use self::application::disassembly::Disassembly;
 pub use self::application::library::Library;
 pub use self::application::unit_test::UnitTest;
 pub use self::application::Application;
@@ -36,8 +37,13 @@ pub use self::instructions::contract::load::StorageLoad;
 pub use self::instructions::contract::store::StorageStore;
 pub use self::instructions::data_stack::load::Load;
 pub use self::instructions::data_stack::load_by_index::LoadByIndex;
+pub use self::instructions::data_stack::load_indirect::LoadIndirect;
+pub use self::instructions::data_stack::load_indirect_by_index::LoadIndirectByIndex;
+pub use self::instructions::data_stack::reference::Reference;
 pub use self::instructions::data_stack::store::Store;
 pub use self::instructions::data_stack::store_by_index::StoreByIndex;
+pub use self::instructions::data_stack::store_indirect::StoreIndirect;
+pub use self::instructions::data_stack::store_indirect_by_index::StoreIndirectByIndex;
 pub use self::instructions::dbg::Dbg;
 pub use self::instructions::evaluation_stack::copy::Copy;
 pub use self::instructions::evaluation_stack::push::Push;
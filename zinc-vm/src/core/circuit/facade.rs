@@ -2,16 +2,27 @@
 //! The virtual machine circuit facade.
 //!
 
+use std::marker::PhantomData;
+
 use colored::Colorize;
 use num::BigInt;
 
+use franklin_crypto::bellman::groth16;
+use franklin_crypto::bellman::groth16::Parameters;
+use franklin_crypto::bellman::groth16::Proof;
 use franklin_crypto::bellman::pairing::bn256::Bn256;
 
 use zinc_const::UnitTestExitCode;
 
 use crate::constraint_systems::main::Main as MainCS;
 use crate::core::circuit::output::Output as CircuitOutput;
+use crate::core::circuit::synthesizer::Synthesizer as CircuitSynthesizer;
 use crate::core::circuit::State as CircuitState;
+use crate::core::debugger::Debugger;
+use crate::core::profiler::Profiler;
+use crate::core::unit_test::options::Options as UnitTestOptions;
+use crate::core::unit_test::summary::Summary as UnitTestSummary;
+use crate::core::unit_test::Runner as UnitTestRunner;
 use crate::core::virtual_machine::IVirtualMachine;
 use crate::error::Error;
 use crate::IEngine;
@@ -26,18 +37,37 @@ impl Facade {
     }
 
     pub fn run<E: IEngine>(self, input: zinc_types::Value) -> Result<CircuitOutput, Error> {
+        self.run_with_debugger::<E>(input, None)
+    }
+
+    pub fn debug<E: IEngine>(
+        self,
+        input: zinc_types::Value,
+        debugger: Debugger,
+    ) -> Result<CircuitOutput, Error> {
+        self.run_with_debugger::<E>(input, Some(debugger))
+    }
+
+    fn run_with_debugger<E: IEngine>(
+        self,
+        input: zinc_types::Value,
+        debugger: Option<Debugger>,
+    ) -> Result<CircuitOutput, Error> {
         let cs = MainCS::<Bn256>::new();
 
         let inputs_flat = input.into_flat_values();
         let output_type = self.inner.output.clone();
 
         let mut state = CircuitState::new(cs);
+        if let Some(debugger) = debugger {
+            state.set_debugger(debugger);
+        }
 
         let mut num_constraints = 0;
         let result = state.run(
             self.inner,
             Some(&inputs_flat),
-            |cs| {
+            |cs, _instruction| {
                 let num = cs.num_constraints() - num_constraints;
                 num_constraints += num;
                 log::trace!("Constraints: {}", num);
@@ -62,42 +92,142 @@ impl Facade {
         Ok(CircuitOutput::new(output_value))
     }
 
-    pub fn test<E: IEngine>(self) -> Result<UnitTestExitCode, Error> {
-        let mut exit_code = UnitTestExitCode::Passed;
-
-        for (name, unit_test) in self.inner.unit_tests.clone().into_iter() {
-            if unit_test.is_ignored {
-                log::info!("test {} ... {}", name, "ignore".yellow());
-                return Ok(UnitTestExitCode::Ignored);
-            }
+    pub fn profile<E: IEngine>(self, input: zinc_types::Value) -> Result<Profiler, Error> {
+        let cs = MainCS::<Bn256>::new();
 
-            let cs = MainCS::<Bn256>::new();
+        let inputs_flat = input.into_flat_values();
 
-            let mut state = CircuitState::new(cs);
+        let mut state = CircuitState::new(cs);
 
-            match state.test(self.inner.clone(), unit_test.address) {
-                Err(_) if unit_test.should_panic => {
-                    log::info!("test {} ... {} (failed)", name, "ok".green());
-                }
-                Ok(_) if unit_test.should_panic => {
-                    log::error!(
-                        "test {} ... {} (should have failed)",
-                        name,
-                        "error".bright_red()
-                    );
-                    exit_code = UnitTestExitCode::Failed;
+        let mut profiler = Profiler::new();
+        state.run(
+            self.inner,
+            Some(&inputs_flat),
+            |cs, instruction| profiler.record(instruction, cs.num_constraints()),
+            |cs| {
+                if !cs.is_satisfied() {
+                    return Err(Error::UnsatisfiedConstraint);
                 }
 
-                Ok(_) => {
-                    log::info!("test {} ... {}", name, "ok".green());
-                }
-                Err(error) => {
-                    log::error!("test {} ... {} ({})", name, "error".bright_red(), error);
-                    exit_code = UnitTestExitCode::Failed;
-                }
-            };
+                Ok(())
+            },
+        )?;
+
+        Ok(profiler)
+    }
+
+    pub fn setup<E: IEngine>(self) -> Result<Parameters<E>, Error> {
+        let rng = &mut rand::thread_rng();
+        let mut result = None;
+
+        let synthesizable = CircuitSynthesizer {
+            inputs: None,
+            output: &mut result,
+            bytecode: self.inner,
+
+            _pd: PhantomData,
+        };
+
+        let parameters = groth16::generate_random_parameters::<E, _, _>(synthesizable, rng)?;
+
+        match result.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS) {
+            Ok(_) => Ok(parameters),
+            Err(error) => Err(error),
         }
+    }
+
+    pub fn prove<E: IEngine>(
+        self,
+        parameters: Parameters<E>,
+        input: zinc_types::Value,
+    ) -> Result<(zinc_types::Value, Proof<E>), Error> {
+        let rng = &mut rand::thread_rng();
+        let mut result = None;
+
+        let inputs_flat = input.into_flat_values();
+        let output_type = self.inner.output.clone();
+
+        let synthesizable = CircuitSynthesizer {
+            inputs: Some(inputs_flat),
+            output: &mut result,
+            bytecode: self.inner,
+
+            _pd: PhantomData,
+        };
+
+        let proof = groth16::create_random_proof(synthesizable, &parameters, rng)?;
+
+        match result.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS) {
+            Ok(result) => {
+                let output_flat: Vec<BigInt> =
+                    result.into_iter().filter_map(|value| value).collect();
+                let output_value = zinc_types::Value::from_flat_values(output_type, &output_flat);
+
+                Ok((output_value, proof))
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    pub fn test<E: IEngine>(self, options: UnitTestOptions) -> Result<UnitTestSummary, Error> {
+        let circuit = self.inner;
+
+        UnitTestRunner::new(options).run(
+            circuit.name.clone(),
+            circuit.unit_tests.clone(),
+            |unit_test| {
+                let cs = MainCS::<Bn256>::new();
 
-        Ok(exit_code)
+                let mut state = CircuitState::new(cs);
+                state.test(circuit.clone(), unit_test.address)?;
+
+                Ok(state.constraint_system().num_constraints())
+            },
+        )
+    }
+
+    pub fn debug_test<E: IEngine>(
+        self,
+        name: &str,
+        debugger: Debugger,
+    ) -> Result<UnitTestExitCode, Error> {
+        let unit_test =
+            self.inner
+                .unit_tests
+                .get(name)
+                .cloned()
+                .ok_or_else(|| Error::UnitTestNotFound {
+                    found: name.to_owned(),
+                })?;
+
+        let cs = MainCS::<Bn256>::new();
+
+        let mut state = CircuitState::new(cs);
+        state.set_debugger(debugger);
+
+        Ok(match state.test(self.inner, unit_test.address) {
+            Err(Error::DebuggerTerminated) => return Err(Error::DebuggerTerminated),
+            Err(_) if unit_test.should_panic => {
+                log::info!("test {} ... {} (failed)", name, "ok".green());
+                UnitTestExitCode::Passed
+            }
+            Ok(_) if unit_test.should_panic => {
+                log::error!(
+                    "test {} ... {} (should have failed)",
+                    name,
+                    "error".bright_red()
+                );
+                UnitTestExitCode::Failed
+            }
+
+            Ok(_) => {
+                log::info!("test {} ... {}", name, "ok".green());
+                UnitTestExitCode::Passed
+            }
+            Err(error) => {
+                log::error!("test {} ... {} ({})", name, "error".bright_red(), error);
+                UnitTestExitCode::Failed
+            }
+        })
     }
 }
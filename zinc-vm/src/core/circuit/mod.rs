@@ -15,6 +15,9 @@ use franklin_crypto::bellman::ConstraintSystem;
 use crate::core::contract::storage::leaf::LeafVariant;
 use crate::core::contract::storage::setup::Storage as SetupStorage;
 use crate::core::counter::NamespaceCounter;
+use crate::core::debugger::Debugger;
+use crate::core::debugger::IDebuggable;
+use crate::core::debugger::StorageFields;
 use crate::core::execution_state::block::branch::Branch;
 use crate::core::execution_state::block::r#loop::Loop;
 use crate::core::execution_state::block::Block;
@@ -41,6 +44,7 @@ where
     outputs: Vec<Scalar<E>>,
 
     pub(crate) location: Location,
+    debugger: Option<Debugger>,
 }
 
 impl<E, CS> State<E, CS>
@@ -55,6 +59,7 @@ where
             outputs: vec![],
 
             location: Location::new(),
+            debugger: None,
         }
     }
 
@@ -66,7 +71,7 @@ where
         mut check_cs: F,
     ) -> Result<Vec<Option<BigInt>>, Error>
     where
-        CB: FnMut(&CS),
+        CB: FnMut(&CS, &zinc_types::Instruction),
         F: FnMut(&CS) -> Result<(), Error>,
     {
         self.counter.cs.enforce(
@@ -81,13 +86,12 @@ where
         let input_size = circuit.input.size();
         self.init_root_frame(circuit.input, input_values)?;
 
-        if let Err(error) = zinc_types::Call::new(circuit.address, input_size)
-            .execute(self)
-            .and(check_cs(&self.counter.cs))
-        {
+        let entry = zinc_types::Call::new(circuit.address, input_size);
+        if let Err(error) = entry.clone().execute(self).and(check_cs(&self.counter.cs)) {
             log::error!("{}\nat {}", error, self.location.to_string().blue());
             return Err(error);
         }
+        instruction_callback(&self.counter.cs, &zinc_types::Instruction::Call(entry));
 
         let mut step = 0;
         while self.execution_state.instruction_counter < circuit.instructions.len() {
@@ -96,8 +100,8 @@ where
                 step, self.execution_state.instruction_counter
             );
             self.counter.cs.push_namespace(|| namespace);
-            let instruction =
-                circuit.instructions[self.execution_state.instruction_counter].clone();
+            let instruction_address = self.execution_state.instruction_counter;
+            let instruction = circuit.instructions[instruction_address].clone();
 
             log::trace!(
                 "{}:{} > {}",
@@ -106,6 +110,8 @@ where
                 instruction,
             );
 
+            self.debug(instruction_address, &instruction)?;
+
             self.execution_state.instruction_counter += 1;
             if let Err(error) = instruction.execute(self).and(check_cs(&self.counter.cs)) {
                 log::error!("{}\nat {}", error, self.location.to_string().blue());
@@ -113,7 +119,7 @@ where
             }
 
             log::trace!("{}", self.execution_state);
-            instruction_callback(&self.counter.cs);
+            instruction_callback(&self.counter.cs, &circuit.instructions[instruction_address]);
             self.counter.cs.pop_namespace();
             step += 1;
         }
@@ -155,6 +161,8 @@ where
                 instruction,
             );
 
+            self.debug(self.execution_state.instruction_counter, &instruction)?;
+
             self.execution_state.instruction_counter += 1;
             if let Err(error) = instruction.execute(self) {
                 log::error!("{}\nat {}", error, self.location.to_string().blue());
@@ -217,6 +225,30 @@ where
             .ok_or_else(|| MalformedBytecode::StackUnderflow.into())
     }
 
+    ///
+    /// Attaches the interactive `debugger`, which is called before each instruction.
+    ///
+    pub fn set_debugger(&mut self, debugger: Debugger) {
+        self.debugger = Some(debugger);
+    }
+
+    ///
+    /// Passes the `instruction` at `address` to the debugger, if it is attached.
+    ///
+    fn debug(
+        &mut self,
+        address: usize,
+        instruction: &zinc_types::Instruction,
+    ) -> Result<(), Error> {
+        if let Some(mut debugger) = self.debugger.take() {
+            let result = debugger.before_instruction(address, instruction, &*self);
+            self.debugger = Some(debugger);
+            result?;
+        }
+
+        Ok(())
+    }
+
     fn top_frame(&mut self) -> Result<&mut Frame<E>, Error> {
         self.execution_state
             .frames_stack
@@ -259,6 +291,18 @@ where
             .set(frame_start + address, cell)
     }
 
+    fn reference(&mut self, address: usize) -> Result<usize, Error> {
+        Ok(self.top_frame()?.stack_frame_start + address)
+    }
+
+    fn load_indirect(&mut self, reference: usize) -> Result<Cell<E>, Error> {
+        self.execution_state.data_stack.get(reference)
+    }
+
+    fn store_indirect(&mut self, reference: usize, cell: Cell<E>) -> Result<(), Error> {
+        self.execution_state.data_stack.set(reference, cell)
+    }
+
     fn storage_init(
         &mut self,
         _project: zinc_project::ManifestProject,
@@ -498,3 +542,21 @@ where
         self.location = location;
     }
 }
+
+impl<E, CS> IDebuggable for State<E, CS>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    fn evaluation_stack(&self) -> Vec<String> {
+        self.execution_state.evaluation_stack_cells()
+    }
+
+    fn data_stack_frame(&self) -> Vec<(usize, Option<String>)> {
+        self.execution_state.data_stack_frame_cells()
+    }
+
+    fn storages(&self) -> Result<Vec<(BigInt, StorageFields)>, Error> {
+        Ok(vec![])
+    }
+}
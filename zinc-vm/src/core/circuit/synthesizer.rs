@@ -30,7 +30,8 @@ where
 {
     fn synthesize<CS: ConstraintSystem<E>>(self, cs: &mut CS) -> Result<(), SynthesisError> {
         let mut circuit = State::new(DedupCS::new(LoggingCS::new(cs)));
-        *self.output = Some(circuit.run(self.bytecode, self.inputs.as_deref(), |_| {}, |_| Ok(())));
+        *self.output =
+            Some(circuit.run(self.bytecode, self.inputs.as_deref(), |_, _| {}, |_| Ok(())));
 
         Ok(())
     }
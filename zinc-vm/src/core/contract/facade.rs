@@ -3,10 +3,15 @@
 //!
 
 use std::collections::HashMap;
+use std::marker::PhantomData;
 
 use colored::Colorize;
 use num::BigInt;
+use num::Zero;
 
+use franklin_crypto::bellman::groth16;
+use franklin_crypto::bellman::groth16::Parameters;
+use franklin_crypto::bellman::groth16::Proof;
 use franklin_crypto::bellman::pairing::bn256::Bn256;
 use franklin_crypto::bellman::ConstraintSystem;
 
@@ -19,10 +24,19 @@ use crate::core::contract::output::Output as ContractOutput;
 use crate::core::contract::storage::database::Storage as DatabaseStorage;
 use crate::core::contract::storage::keeper::DummyKeeper;
 use crate::core::contract::storage::keeper::IKeeper;
+use crate::core::contract::storage::setup::Storage as SetupStorage;
+use crate::core::contract::synthesizer::Synthesizer as ContractSynthesizer;
 use crate::core::contract::State as ContractState;
+use crate::core::debugger::Debugger;
+use crate::core::profiler::Profiler;
+use crate::core::unit_test::options::Options as UnitTestOptions;
+use crate::core::unit_test::summary::Summary as UnitTestSummary;
+use crate::core::unit_test::Runner as UnitTestRunner;
 use crate::core::virtual_machine::IVirtualMachine;
 use crate::error::Error;
+use crate::gadgets::contract::merkle_tree::hasher::rescue::Hasher as RescueHasher;
 use crate::gadgets::contract::merkle_tree::hasher::sha256::Hasher as Sha256Hasher;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
 use crate::gadgets::contract::merkle_tree::IMerkleTree;
 use crate::gadgets::contract::storage::StorageGadget;
 use crate::IEngine;
@@ -51,45 +65,123 @@ impl Facade {
     }
 
     pub fn run<E: IEngine>(self, input: ContractInput) -> Result<ContractOutput, Error> {
+        match self.inner.storage_hasher {
+            zinc_project::StorageHasher::Sha256 => {
+                self.run_with_hasher::<Sha256Hasher>(input, None)
+            }
+            zinc_project::StorageHasher::Rescue => {
+                self.run_with_hasher::<RescueHasher>(input, None)
+            }
+        }
+    }
+
+    pub fn debug<E: IEngine>(
+        self,
+        input: ContractInput,
+        debugger: Debugger,
+    ) -> Result<ContractOutput, Error> {
+        match self.inner.storage_hasher {
+            zinc_project::StorageHasher::Sha256 => {
+                self.run_with_hasher::<Sha256Hasher>(input, Some(debugger))
+            }
+            zinc_project::StorageHasher::Rescue => {
+                self.run_with_hasher::<RescueHasher>(input, Some(debugger))
+            }
+        }
+    }
+
+    pub fn profile<E: IEngine>(self, input: ContractInput) -> Result<Profiler, Error> {
+        match self.inner.storage_hasher {
+            zinc_project::StorageHasher::Sha256 => self.profile_with_hasher::<Sha256Hasher>(input),
+            zinc_project::StorageHasher::Rescue => self.profile_with_hasher::<RescueHasher>(input),
+        }
+    }
+
+    pub fn setup<E: IEngine>(self, method_name: String) -> Result<Parameters<E>, Error> {
+        match self.inner.storage_hasher {
+            zinc_project::StorageHasher::Sha256 => {
+                self.setup_with_hasher::<E, Sha256Hasher>(method_name)
+            }
+            zinc_project::StorageHasher::Rescue => {
+                self.setup_with_hasher::<E, RescueHasher>(method_name)
+            }
+        }
+    }
+
+    pub fn prove<E: IEngine>(
+        self,
+        parameters: Parameters<E>,
+        input: ContractInput,
+    ) -> Result<(zinc_types::Value, Proof<E>), Error> {
+        match self.inner.storage_hasher {
+            zinc_project::StorageHasher::Sha256 => {
+                self.prove_with_hasher::<E, Sha256Hasher>(parameters, input)
+            }
+            zinc_project::StorageHasher::Rescue => {
+                self.prove_with_hasher::<E, RescueHasher>(parameters, input)
+            }
+        }
+    }
+
+    pub fn test<E: IEngine>(self, options: UnitTestOptions) -> Result<UnitTestSummary, Error> {
+        match self.inner.storage_hasher {
+            zinc_project::StorageHasher::Sha256 => self.test_with_hasher::<Sha256Hasher>(options),
+            zinc_project::StorageHasher::Rescue => self.test_with_hasher::<RescueHasher>(options),
+        }
+    }
+
+    pub fn debug_test<E: IEngine>(
+        self,
+        name: &str,
+        debugger: Debugger,
+    ) -> Result<UnitTestExitCode, Error> {
+        match self.inner.storage_hasher {
+            zinc_project::StorageHasher::Sha256 => {
+                self.debug_test_with_hasher::<Sha256Hasher>(name, debugger)
+            }
+            zinc_project::StorageHasher::Rescue => {
+                self.debug_test_with_hasher::<RescueHasher>(name, debugger)
+            }
+        }
+    }
+
+    fn run_with_hasher<H>(
+        self,
+        input: ContractInput,
+        debugger: Option<Debugger>,
+    ) -> Result<ContractOutput, Error>
+    where
+        H: IMerkleTreeHasher<Bn256>,
+    {
         let mut cs = ConstantCS {};
 
-        let method = self
-            .inner
-            .methods
-            .get(input.method_name.as_str())
-            .cloned()
-            .ok_or(Error::MethodNotFound {
-                found: input.method_name.clone(),
-            })?;
+        let method = self.method(input.method_name.as_str())?;
         let arguments_flat = input.arguments.into_flat_values();
-        let output_type = if method.is_mutable {
-            method.output.into_mutable_method_output()
-        } else if method.name.as_str() == zinc_const::contract::CONSTRUCTOR_IDENTIFIER {
-            zinc_types::Type::eth_address()
-        } else {
-            method.output
-        };
+        let output_type = self.output_type(input.method_name.as_str())?;
 
         let mut storages = HashMap::with_capacity(1);
         if method.name.as_str() != zinc_const::contract::CONSTRUCTOR_IDENTIFIER {
             for (address, storage) in input.storages.into_iter() {
                 let address = BigInt::from_bytes_be(num::bigint::Sign::Plus, address.as_bytes());
                 let storage =
-                    DatabaseStorage::<Bn256>::from_build(self.inner.storage.clone(), storage)?;
+                    DatabaseStorage::<Bn256, H>::from_build(self.inner.storage.clone(), storage)?;
                 let storage_gadget =
-                    StorageGadget::<_, _, Sha256Hasher>::new(cs.namespace(|| "storage"), storage)?;
+                    StorageGadget::<_, _, H>::new(cs.namespace(|| "storage"), storage)?;
                 storages.insert(address, storage_gadget);
             }
         }
 
         let mut state = ContractState::new(cs, storages, self.keeper, input.transaction);
+        if let Some(debugger) = debugger {
+            state.set_debugger(debugger);
+        }
 
         let mut num_constraints = 0;
         let result = state.run(
             self.inner,
             method.input,
             Some(&arguments_flat),
-            |cs| {
+            |cs, _instruction| {
                 let num = cs.num_constraints() - num_constraints;
                 num_constraints += num;
                 log::trace!("Constraints: {}", num);
@@ -129,47 +221,270 @@ impl Facade {
         ))
     }
 
-    pub fn test<E: IEngine>(self) -> Result<UnitTestExitCode, Error> {
-        let mut exit_code = UnitTestExitCode::Passed;
+    fn profile_with_hasher<H>(self, input: ContractInput) -> Result<Profiler, Error>
+    where
+        H: IMerkleTreeHasher<Bn256>,
+    {
+        let mut cs = MainCS::<Bn256>::new();
 
-        for (name, unit_test) in self.inner.unit_tests.clone().into_iter() {
-            if unit_test.is_ignored {
-                log::info!("test {} ... {}", name, "ignore".yellow());
-                return Ok(UnitTestExitCode::Ignored);
-            }
+        let method = self.method(input.method_name.as_str())?;
+        let arguments_flat = input.arguments.into_flat_values();
 
-            let cs = MainCS::<Bn256>::new();
+        let mut storages = HashMap::with_capacity(1);
+        if method.name.as_str() != zinc_const::contract::CONSTRUCTOR_IDENTIFIER {
+            for (address, storage) in input.storages.into_iter() {
+                let address = BigInt::from_bytes_be(num::bigint::Sign::Plus, address.as_bytes());
+                let storage =
+                    DatabaseStorage::<Bn256, H>::from_build(self.inner.storage.clone(), storage)?;
+                let storage_gadget =
+                    StorageGadget::<_, _, H>::new(cs.namespace(|| "storage"), storage)?;
+                storages.insert(address, storage_gadget);
+            }
+        }
 
-            let mut state = ContractState::<_, _, DatabaseStorage<_>, Sha256Hasher>::new(
-                cs,
-                HashMap::with_capacity(1),
-                Box::new(DummyKeeper::default()),
-                unit_test.zksync_msg.unwrap_or_default(),
-            );
+        let mut state = ContractState::new(cs, storages, self.keeper, input.transaction);
 
-            match state.test(self.inner.clone(), unit_test.address) {
-                Err(_) if unit_test.should_panic => {
-                    log::info!("test {} ... {} (failed)", name, "ok".green());
-                }
-                Ok(_) if unit_test.should_panic => {
-                    log::error!(
-                        "test {} ... {} (should have failed)",
-                        name,
-                        "error".bright_red()
-                    );
-                    exit_code = UnitTestExitCode::Failed;
+        let mut profiler = Profiler::new();
+        state.run(
+            self.inner,
+            method.input,
+            Some(&arguments_flat),
+            |cs, instruction| profiler.record(instruction, cs.num_constraints()),
+            |cs| {
+                if !cs.is_satisfied() {
+                    return Err(Error::UnsatisfiedConstraint);
                 }
 
-                Ok(_) => {
-                    log::info!("test {} ... {}", name, "ok".green());
-                }
-                Err(error) => {
-                    log::error!("test {} ... {} ({})", name, "error".bright_red(), error);
-                    exit_code = UnitTestExitCode::Failed;
-                }
-            };
+                Ok(())
+            },
+            method.address,
+        )?;
+
+        Ok(profiler)
+    }
+
+    fn setup_with_hasher<E, H>(self, method_name: String) -> Result<Parameters<E>, Error>
+    where
+        E: IEngine,
+        H: IMerkleTreeHasher<E>,
+    {
+        let rng = &mut rand::thread_rng();
+        let mut result = None;
+
+        let method = self.method(method_name.as_str())?;
+
+        let arguments = zinc_types::Value::new(method.input.clone());
+
+        let mut storages = HashMap::with_capacity(1);
+        if method.name.as_str() != zinc_const::contract::CONSTRUCTOR_IDENTIFIER {
+            let storage = SetupStorage::<E, H>::from_build(
+                self.inner.storage.clone(),
+                zinc_types::Value::Contract(vec![]),
+            )?;
+            storages.insert(BigInt::zero(), storage);
+        }
+
+        let synthesizable = ContractSynthesizer::<E, _, H> {
+            inputs: Some(arguments.into_flat_values()),
+            output: &mut result,
+            bytecode: self.inner,
+            method,
+            storages,
+            keeper: self.keeper,
+            transaction: zinc_types::TransactionMsg::default(),
+
+            _pd: PhantomData,
+        };
+
+        let parameters = groth16::generate_random_parameters::<E, _, _>(synthesizable, rng)?;
+
+        match result.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS) {
+            Ok(_) => Ok(parameters),
+            Err(error) => Err(error),
         }
+    }
+
+    fn prove_with_hasher<E, H>(
+        self,
+        parameters: Parameters<E>,
+        input: ContractInput,
+    ) -> Result<(zinc_types::Value, Proof<E>), Error>
+    where
+        E: IEngine,
+        H: IMerkleTreeHasher<E>,
+    {
+        let rng = &mut rand::thread_rng();
+        let mut result = None;
+
+        let method = self.method(input.method_name.as_str())?;
+        let arguments_flat = input.arguments.into_flat_values();
+        let output_type = self.output_type(input.method_name.as_str())?;
+
+        let mut storages = HashMap::with_capacity(1);
+        if method.name.as_str() != zinc_const::contract::CONSTRUCTOR_IDENTIFIER {
+            for (address, storage) in input.storages.into_iter() {
+                let address = BigInt::from_bytes_be(num::bigint::Sign::Plus, address.as_bytes());
+                let storage =
+                    DatabaseStorage::<E, H>::from_build(self.inner.storage.clone(), storage)?;
+                storages.insert(address, storage);
+            }
+        }
+
+        let synthesizable = ContractSynthesizer::<E, _, H> {
+            inputs: Some(arguments_flat),
+            output: &mut result,
+            bytecode: self.inner,
+            method,
+            storages,
+            keeper: self.keeper,
+            transaction: input.transaction,
+
+            _pd: PhantomData,
+        };
+
+        let proof = groth16::create_random_proof(synthesizable, &parameters, rng)?;
+
+        match result.expect(zinc_const::panic::VALUE_ALWAYS_EXISTS) {
+            Ok(result) => {
+                let output_flat: Vec<BigInt> =
+                    result.into_iter().filter_map(|value| value).collect();
+                let output_value = zinc_types::Value::from_flat_values(output_type, &output_flat);
+
+                Ok((output_value, proof))
+            }
+            Err(error) => Err(error),
+        }
+    }
+
+    ///
+    /// Returns the type of the value written to the output file by the method `method_name`.
+    ///
+    pub fn output_type(&self, method_name: &str) -> Result<zinc_types::Type, Error> {
+        let method = self.method(method_name)?;
+
+        Ok(if method.is_mutable {
+            method.output.into_mutable_method_output()
+        } else if method.name.as_str() == zinc_const::contract::CONSTRUCTOR_IDENTIFIER {
+            zinc_types::Type::eth_address()
+        } else {
+            method.output
+        })
+    }
+
+    ///
+    /// Converts the method `method_name` output into the public input of its proof.
+    ///
+    /// The storage root hash is always a part of the public input, but it is only written to
+    /// the output file by mutable methods.
+    ///
+    pub fn public_input(
+        &self,
+        method_name: &str,
+        output: zinc_types::Value,
+    ) -> Result<zinc_types::Value, Error> {
+        let method = self.method(method_name)?;
+
+        Ok(if method.is_mutable {
+            output
+        } else {
+            zinc_types::Value::Structure(vec![
+                ("result".to_owned(), output),
+                (
+                    "root_hash".to_owned(),
+                    zinc_types::Value::Scalar(zinc_types::ScalarValue::Field(BigInt::zero())),
+                ),
+            ])
+        })
+    }
+
+    fn test_with_hasher<H>(self, options: UnitTestOptions) -> Result<UnitTestSummary, Error>
+    where
+        H: IMerkleTreeHasher<Bn256>,
+    {
+        let contract = self.inner;
+
+        UnitTestRunner::new(options).run(
+            contract.name.clone(),
+            contract.unit_tests.clone(),
+            |unit_test| {
+                let cs = MainCS::<Bn256>::new();
+
+                let mut state = ContractState::<_, _, DatabaseStorage<_, H>, H>::new(
+                    cs,
+                    HashMap::with_capacity(1),
+                    Box::new(DummyKeeper::default()),
+                    unit_test.zksync_msg.clone().unwrap_or_default(),
+                );
+                state.test(contract.clone(), unit_test.address)?;
 
-        Ok(exit_code)
+                Ok(state.constraint_system().num_constraints())
+            },
+        )
+    }
+
+    fn debug_test_with_hasher<H>(
+        self,
+        name: &str,
+        debugger: Debugger,
+    ) -> Result<UnitTestExitCode, Error>
+    where
+        H: IMerkleTreeHasher<Bn256>,
+    {
+        let unit_test =
+            self.inner
+                .unit_tests
+                .get(name)
+                .cloned()
+                .ok_or_else(|| Error::UnitTestNotFound {
+                    found: name.to_owned(),
+                })?;
+
+        let cs = MainCS::<Bn256>::new();
+
+        let mut state = ContractState::<_, _, DatabaseStorage<_, H>, H>::new(
+            cs,
+            HashMap::with_capacity(1),
+            Box::new(DummyKeeper::default()),
+            unit_test.zksync_msg.unwrap_or_default(),
+        );
+        state.set_debugger(debugger);
+
+        Ok(match state.test(self.inner, unit_test.address) {
+            Err(Error::DebuggerTerminated) => return Err(Error::DebuggerTerminated),
+            Err(_) if unit_test.should_panic => {
+                log::info!("test {} ... {} (failed)", name, "ok".green());
+                UnitTestExitCode::Passed
+            }
+            Ok(_) if unit_test.should_panic => {
+                log::error!(
+                    "test {} ... {} (should have failed)",
+                    name,
+                    "error".bright_red()
+                );
+                UnitTestExitCode::Failed
+            }
+
+            Ok(_) => {
+                log::info!("test {} ... {}", name, "ok".green());
+                UnitTestExitCode::Passed
+            }
+            Err(error) => {
+                log::error!("test {} ... {} ({})", name, "error".bright_red(), error);
+                UnitTestExitCode::Failed
+            }
+        })
+    }
+
+    ///
+    /// Returns the contract method metadata by its name.
+    ///
+    fn method(&self, name: &str) -> Result<zinc_types::ContractMethod, Error> {
+        self.inner
+            .methods
+            .get(name)
+            .cloned()
+            .ok_or_else(|| Error::MethodNotFound {
+                found: name.to_owned(),
+            })
     }
 }
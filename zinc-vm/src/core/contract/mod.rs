@@ -22,6 +22,9 @@ use crate::core::contract::output::initializer::Initializer;
 use crate::core::contract::storage::keeper::IKeeper;
 use crate::core::contract::storage::leaf::LeafVariant;
 use crate::core::counter::NamespaceCounter;
+use crate::core::debugger::Debugger;
+use crate::core::debugger::IDebuggable;
+use crate::core::debugger::StorageFields;
 use crate::core::execution_state::block::branch::Branch;
 use crate::core::execution_state::block::r#loop::Loop;
 use crate::core::execution_state::block::Block;
@@ -57,6 +60,7 @@ where
     transaction: zinc_types::TransactionMsg,
 
     pub(crate) location: Location,
+    debugger: Option<Debugger>,
 }
 
 impl<E, CS, S, H> State<E, CS, S, H>
@@ -82,6 +86,7 @@ where
             transaction,
 
             location: Location::new(),
+            debugger: None,
         }
     }
 
@@ -95,7 +100,7 @@ where
         address: usize,
     ) -> Result<Vec<Option<BigInt>>, Error>
     where
-        CB: FnMut(&CS),
+        CB: FnMut(&CS, &zinc_types::Instruction),
         F: FnMut(&CS) -> Result<(), Error>,
     {
         self.counter.cs.enforce(
@@ -110,13 +115,12 @@ where
         let input_size = input_type.size();
         self.init_root_frame(input_type, input_values)?;
 
-        if let Err(error) = zinc_types::Call::new(address, input_size)
-            .execute(self)
-            .and(check_cs(&self.counter.cs))
-        {
+        let entry = zinc_types::Call::new(address, input_size);
+        if let Err(error) = entry.clone().execute(self).and(check_cs(&self.counter.cs)) {
             log::error!("{}\nat {}", error, self.location.to_string().blue());
             return Err(error);
         }
+        instruction_callback(&self.counter.cs, &zinc_types::Instruction::Call(entry));
 
         let mut step = 0;
         let execution_time = std::time::Instant::now();
@@ -126,8 +130,8 @@ where
                 step, self.execution_state.instruction_counter
             );
             self.counter.cs.push_namespace(|| namespace);
-            let instruction =
-                contract.instructions[self.execution_state.instruction_counter].clone();
+            let instruction_address = self.execution_state.instruction_counter;
+            let instruction = contract.instructions[instruction_address].clone();
 
             log::trace!(
                 "{}:{} > {}",
@@ -136,6 +140,8 @@ where
                 instruction,
             );
 
+            self.debug(instruction_address, &instruction)?;
+
             self.execution_state.instruction_counter += 1;
             if let Err(error) = instruction.execute(self).and(check_cs(&self.counter.cs)) {
                 log::error!("{}\nat {}", error, self.location.to_string().blue());
@@ -143,7 +149,10 @@ where
             }
 
             log::trace!("{}", self.execution_state);
-            instruction_callback(&self.counter.cs);
+            instruction_callback(
+                &self.counter.cs,
+                &contract.instructions[instruction_address],
+            );
             self.counter.cs.pop_namespace();
             step += 1;
         }
@@ -190,6 +199,8 @@ where
                 instruction,
             );
 
+            self.debug(self.execution_state.instruction_counter, &instruction)?;
+
             self.execution_state.instruction_counter += 1;
             if let Err(error) = instruction.execute(self) {
                 log::error!("{}\nat {}", error, self.location.to_string().blue());
@@ -262,6 +273,30 @@ where
             .ok_or_else(|| MalformedBytecode::StackUnderflow.into())
     }
 
+    ///
+    /// Attaches the interactive `debugger`, which is called before each instruction.
+    ///
+    pub fn set_debugger(&mut self, debugger: Debugger) {
+        self.debugger = Some(debugger);
+    }
+
+    ///
+    /// Passes the `instruction` at `address` to the debugger, if it is attached.
+    ///
+    fn debug(
+        &mut self,
+        address: usize,
+        instruction: &zinc_types::Instruction,
+    ) -> Result<(), Error> {
+        if let Some(mut debugger) = self.debugger.take() {
+            let result = debugger.before_instruction(address, instruction, &*self);
+            self.debugger = Some(debugger);
+            result?;
+        }
+
+        Ok(())
+    }
+
     fn top_frame(&mut self) -> Result<&mut Frame<E>, Error> {
         self.execution_state
             .frames_stack
@@ -306,6 +341,18 @@ where
             .set(frame_start + address, cell)
     }
 
+    fn reference(&mut self, address: usize) -> Result<usize, Error> {
+        Ok(self.top_frame()?.stack_frame_start + address)
+    }
+
+    fn load_indirect(&mut self, reference: usize) -> Result<Cell<E>, Error> {
+        self.execution_state.data_stack.get(reference)
+    }
+
+    fn store_indirect(&mut self, reference: usize, cell: Cell<E>) -> Result<(), Error> {
+        self.execution_state.data_stack.set(reference, cell)
+    }
+
     fn storage_init(
         &mut self,
         project: zinc_project::ManifestProject,
@@ -688,3 +735,29 @@ where
         self.location = location;
     }
 }
+
+impl<E, CS, S, H> IDebuggable for State<E, CS, S, H>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+    S: IMerkleTree<E>,
+    H: IMerkleTreeHasher<E>,
+{
+    fn evaluation_stack(&self) -> Vec<String> {
+        self.execution_state.evaluation_stack_cells()
+    }
+
+    fn data_stack_frame(&self) -> Vec<(usize, Option<String>)> {
+        self.execution_state.data_stack_frame_cells()
+    }
+
+    fn storages(&self) -> Result<Vec<(BigInt, StorageFields)>, Error> {
+        let mut storages = Vec::with_capacity(self.storages.len());
+        for (address, storage) in self.storages.iter() {
+            storages.push((address.to_owned(), storage.fields()?));
+        }
+        storages.sort_by(|a, b| a.0.cmp(&b.0));
+
+        Ok(storages)
+    }
+}
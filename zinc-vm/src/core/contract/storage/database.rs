@@ -1,3 +1,5 @@
+use std::marker::PhantomData;
+
 use num::bigint::ToBigInt;
 use num::BigInt;
 use num::ToPrimitive;
@@ -10,18 +12,26 @@ use crate::core::contract::storage::leaf::LeafInput;
 use crate::core::contract::storage::leaf::LeafOutput;
 use crate::core::contract::storage::leaf::LeafVariant;
 use crate::error::Error;
+use crate::gadgets::contract::merkle_tree::hasher::sha256::Hasher as Sha256Hasher;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
 use crate::gadgets::contract::merkle_tree::IMerkleTree;
 use crate::gadgets::scalar::Scalar;
 use crate::IEngine;
 
-pub struct Storage<E: IEngine> {
+pub struct Storage<E: IEngine, H: IMerkleTreeHasher<E> = Sha256Hasher> {
     field_types: Vec<zinc_types::ContractFieldType>,
     hash_tree: Vec<Vec<u8>>,
     leaf_values: Vec<LeafVariant<E>>,
     depth: usize,
+
+    _pd: PhantomData<H>,
 }
 
-impl<E: IEngine> IMerkleTree<E> for Storage<E> {
+impl<E, H> IMerkleTree<E> for Storage<E, H>
+where
+    E: IEngine,
+    H: IMerkleTreeHasher<E>,
+{
     fn from_evaluation_stack(
         field_types: Vec<zinc_types::ContractFieldType>,
         mut values: Vec<Scalar<E>>,
@@ -65,6 +75,7 @@ impl<E: IEngine> IMerkleTree<E> for Storage<E> {
             hash_tree: vec![vec![]; hash_tree_size],
             leaf_values,
             depth,
+            _pd: PhantomData,
         })
     }
 
@@ -125,13 +136,14 @@ impl<E: IEngine> IMerkleTree<E> for Storage<E> {
             hash_tree: vec![vec![]; hash_tree_size],
             leaf_values,
             depth,
+            _pd: PhantomData,
         })
     }
 
     fn load(&self, index: BigInt) -> Result<Leaf<E>, Error> {
         let index = index.to_usize().ok_or(Error::ExpectedUsize(index))?;
 
-        Ok(Leaf::new(
+        Ok(Leaf::new::<H>(
             self.leaf_values[index].to_owned(),
             None,
             self.depth,
@@ -1,6 +1,6 @@
 use num::BigInt;
 
-use crate::core::contract::storage::sha256;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
 use crate::gadgets::scalar::Scalar;
 use crate::IEngine;
 
@@ -96,31 +96,24 @@ pub enum LeafOutput {
 }
 
 impl<E: IEngine> Leaf<E> {
-    pub fn new(
+    pub fn new<H: IMerkleTreeHasher<E>>(
         leaf_values: LeafVariant<E>,
         authentication_path: Option<Vec<Vec<bool>>>,
         depth: usize,
     ) -> Self {
+        let hasher = H::default();
+
         Self {
             leaf_values: leaf_values.clone(),
             leaf_value_hash: {
-                let mut hash = Vec::with_capacity(
-                    zinc_const::bitlength::SHA256_HASH * zinc_const::bitlength::BYTE,
-                );
                 let values = match leaf_values {
                     LeafVariant::Array(array) => array,
                     LeafVariant::Map { .. } => vec![],
                 };
-                for i in sha256::leaf_value_hash::<E>(values).into_iter() {
-                    for j in (0..zinc_const::bitlength::BYTE).rev() {
-                        let bit = ((i >> j) & 1u8) == 1u8;
-                        hash.push(bit);
-                    }
-                }
-                hash
+                hasher.leaf_value_hash_native(values)
             },
             authentication_path: authentication_path
-                .unwrap_or_else(|| vec![vec![false; zinc_const::bitlength::SHA256_HASH]; depth]),
+                .unwrap_or_else(|| vec![vec![false; hasher.hash_width()]; depth]),
         }
     }
 }
@@ -5,5 +5,6 @@
 pub mod database;
 pub mod keeper;
 pub mod leaf;
+pub mod rescue;
 pub mod setup;
 pub mod sha256;
@@ -0,0 +1,27 @@
+use franklin_crypto::bellman::pairing::ff::Field;
+use franklin_crypto::bellman::pairing::ff::PrimeField;
+use franklin_crypto::rescue::rescue_hash;
+
+use crate::gadgets::scalar::Scalar;
+use crate::IEngine;
+
+pub fn rescue<E: IEngine>(preimage: &[E::Fr]) -> E::Fr {
+    rescue_hash::<E>(E::rescue_params(), preimage)[0]
+}
+
+pub fn leaf_value_hash<E: IEngine>(leaf_value: Vec<Scalar<E>>) -> Vec<bool> {
+    let mut preimage: Vec<E::Fr> = leaf_value
+        .into_iter()
+        .map(|field| field.get_value().unwrap_or_else(E::Fr::zero))
+        .collect();
+    if preimage.is_empty() {
+        preimage.push(E::Fr::zero());
+    }
+
+    let digest = rescue::<E>(preimage.as_slice()).into_repr();
+
+    let limbs = digest.as_ref();
+    (0..zinc_const::bitlength::FIELD)
+        .map(|index| ((limbs[index / 64] >> (index % 64)) & 1u64) == 1u64)
+        .collect()
+}
@@ -1,3 +1,5 @@
+use std::marker::PhantomData;
+
 use num::bigint::ToBigInt;
 use num::BigInt;
 use num::ToPrimitive;
@@ -8,17 +10,25 @@ use crate::core::contract::storage::leaf::Leaf;
 use crate::core::contract::storage::leaf::LeafOutput;
 use crate::core::contract::storage::leaf::LeafVariant;
 use crate::error::Error;
+use crate::gadgets::contract::merkle_tree::hasher::sha256::Hasher as Sha256Hasher;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
 use crate::gadgets::contract::merkle_tree::IMerkleTree;
 use crate::gadgets::scalar::Scalar;
 use crate::IEngine;
 
-pub struct Storage<E: IEngine> {
+pub struct Storage<E: IEngine, H: IMerkleTreeHasher<E> = Sha256Hasher> {
     field_types: Vec<zinc_types::ContractFieldType>,
     leaf_values: Vec<Vec<Scalar<E>>>,
     depth: usize,
+
+    _pd: PhantomData<H>,
 }
 
-impl<E: IEngine> IMerkleTree<E> for Storage<E> {
+impl<E, H> IMerkleTree<E> for Storage<E, H>
+where
+    E: IEngine,
+    H: IMerkleTreeHasher<E>,
+{
     fn from_evaluation_stack(
         field_types: Vec<zinc_types::ContractFieldType>,
         _values: Vec<Scalar<E>>,
@@ -30,6 +40,7 @@ impl<E: IEngine> IMerkleTree<E> for Storage<E> {
             field_types: field_types.clone(),
             leaf_values: vec![vec![]; leaf_values_count],
             depth,
+            _pd: PhantomData,
         };
 
         for (index, field) in field_types.into_iter().enumerate() {
@@ -56,6 +67,7 @@ impl<E: IEngine> IMerkleTree<E> for Storage<E> {
             field_types: field_types.clone(),
             leaf_values: vec![vec![]; leaf_values_count],
             depth,
+            _pd: PhantomData,
         };
 
         for (index, field) in field_types.into_iter().enumerate() {
@@ -74,7 +86,7 @@ impl<E: IEngine> IMerkleTree<E> for Storage<E> {
     fn load(&self, index: BigInt) -> Result<Leaf<E>, Error> {
         let index = index.to_usize().ok_or(Error::ExpectedUsize(index))?;
 
-        Ok(Leaf::new(
+        Ok(Leaf::new::<H>(
             LeafVariant::Array(self.leaf_values[index].to_owned()),
             None,
             self.depth,
@@ -16,32 +16,42 @@ use crate::constraint_systems::logging::Logging as LoggingCS;
 use crate::core::contract::storage::keeper::IKeeper;
 use crate::core::contract::State;
 use crate::error::Error;
-use crate::gadgets::contract::merkle_tree::hasher::sha256::Hasher as Sha256Hasher;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
 use crate::gadgets::contract::merkle_tree::IMerkleTree;
 use crate::gadgets::contract::storage::StorageGadget;
 use crate::IEngine;
 
-pub struct Synthesizer<'a, E: IEngine, S: IMerkleTree<E>> {
+pub struct Synthesizer<'a, E: IEngine, S: IMerkleTree<E>, H: IMerkleTreeHasher<E>> {
     pub inputs: Option<Vec<BigInt>>,
     pub output: &'a mut Option<Result<Vec<Option<BigInt>>, Error>>,
     pub bytecode: zinc_types::Contract,
     pub method: zinc_types::ContractMethod,
-    pub storages: HashMap<BigInt, StorageGadget<E, S, Sha256Hasher>>,
+    pub storages: HashMap<BigInt, S>,
     pub keeper: Box<dyn IKeeper>,
     pub transaction: zinc_types::TransactionMsg,
 
-    pub _pd: PhantomData<E>,
+    pub _pd: PhantomData<(E, H)>,
 }
 
-impl<E, S> bellman::Circuit<E> for Synthesizer<'_, E, S>
+impl<E, S, H> bellman::Circuit<E> for Synthesizer<'_, E, S, H>
 where
     E: IEngine,
     S: IMerkleTree<E>,
+    H: IMerkleTreeHasher<E>,
 {
     fn synthesize<CS: ConstraintSystem<E>>(self, cs: &mut CS) -> Result<(), SynthesisError> {
+        let mut storages = HashMap::with_capacity(self.storages.len());
+        for (address, storage) in self.storages.into_iter() {
+            let storage_gadget = StorageGadget::<_, _, H>::new(
+                cs.namespace(|| format!("storage {}", address)),
+                storage,
+            )?;
+            storages.insert(address, storage_gadget);
+        }
+
         let mut contract = State::new(
             DedupCS::new(LoggingCS::new(cs)),
-            self.storages,
+            storages,
             self.keeper,
             self.transaction,
         );
@@ -50,7 +60,7 @@ where
             self.bytecode,
             self.method.input,
             self.inputs.as_deref(),
-            |_| {},
+            |_, _| {},
             |_| Ok(()),
             self.method.address,
         ));
@@ -0,0 +1,670 @@
+//!
+//! The virtual machine interactive debugger.
+//!
+
+use std::fmt;
+use std::io;
+use std::io::BufRead;
+use std::io::Write;
+use std::str::FromStr;
+
+use num::BigInt;
+
+use zinc_types::Instruction;
+
+use crate::error::Error;
+
+///
+/// The contract storage fields with their declared types and current values.
+///
+pub type StorageFields = Vec<(zinc_types::ContractFieldType, zinc_types::Value)>;
+
+///
+/// The virtual machine state, which can be inspected by the debugger.
+///
+pub trait IDebuggable {
+    ///
+    /// Returns the evaluation stack cells, starting from the top one.
+    ///
+    fn evaluation_stack(&self) -> Vec<String>;
+
+    ///
+    /// Returns the current function data stack frame cells along with their frame addresses.
+    ///
+    fn data_stack_frame(&self) -> Vec<(usize, Option<String>)>;
+
+    ///
+    /// Returns the storage fields of the contract instances along with their ETH addresses.
+    ///
+    fn storages(&self) -> Result<Vec<(BigInt, StorageFields)>, Error>;
+}
+
+///
+/// The debugger breakpoint.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Breakpoint {
+    /// The source code line breakpoint, e.g. `src/main.zn:42`.
+    Line {
+        /// The source code file path or its suffix.
+        file: String,
+        /// The source code line number.
+        line: usize,
+    },
+    /// The function breakpoint, e.g. `main` or `Wallet::deposit`.
+    Function(String),
+}
+
+impl Breakpoint {
+    ///
+    /// Checks whether the breakpoint is set on the source code `file` and `line`.
+    ///
+    fn is_line(&self, file: &str, line: usize) -> bool {
+        match self {
+            Self::Line {
+                file: expected,
+                line: expected_line,
+            } => {
+                *expected_line == line
+                    && (file == expected || file.ends_with(format!("/{}", expected).as_str()))
+            }
+            Self::Function(_) => false,
+        }
+    }
+
+    ///
+    /// Checks whether the breakpoint is set on the `function`.
+    ///
+    fn is_function(&self, function: &str) -> bool {
+        match self {
+            Self::Line { .. } => false,
+            Self::Function(expected) => {
+                function == expected || function.ends_with(format!("::{}", expected).as_str())
+            }
+        }
+    }
+}
+
+impl FromStr for Breakpoint {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        if value.is_empty() || value.contains(char::is_whitespace) {
+            return Err(format!("invalid breakpoint `{}`", value));
+        }
+
+        if let Some(index) = value.rfind(':') {
+            if let Ok(line) = value[index + 1..].parse::<usize>() {
+                if index == 0 {
+                    return Err(format!("invalid breakpoint `{}`", value));
+                }
+
+                return Ok(Self::Line {
+                    file: value[..index].to_owned(),
+                    line,
+                });
+            }
+        }
+
+        Ok(Self::Function(value.to_owned()))
+    }
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Line { file, line } => write!(f, "{}:{}", file, line),
+            Self::Function(function) => write!(f, "{}", function),
+        }
+    }
+}
+
+///
+/// The call stack frame of the debugged application.
+///
+#[derive(Debug, Default, Clone)]
+struct Frame {
+    /// The function name set by the function marker.
+    function: Option<String>,
+    /// The source code file set by the file marker.
+    file: Option<String>,
+    /// The source code line set by the line marker.
+    line: Option<usize>,
+    /// Whether a line marker has been executed since the last ordinary instruction.
+    is_line_entered: bool,
+    /// Whether a function marker has been executed since the last ordinary instruction.
+    is_function_entered: bool,
+}
+
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}:{}",
+            self.file.as_deref().unwrap_or("<unknown file>"),
+            self.line
+                .map(|line| line.to_string())
+                .unwrap_or_else(|| "<unknown line>".to_owned()),
+        )?;
+        if let Some(function) = self.function.as_deref() {
+            write!(f, " (in {})", function)?;
+        }
+
+        Ok(())
+    }
+}
+
+///
+/// The debugger execution mode, which defines where the execution is stopped next time.
+///
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Mode {
+    /// Stops only at breakpoints.
+    Continue,
+    /// Stops at the next instruction.
+    StepInstruction,
+    /// Stops at the next source code line, entering the called functions.
+    StepIn,
+    /// Stops at the next source code line of the function at `depth` or its callers.
+    StepOver {
+        /// The call stack depth where the step has been started.
+        depth: usize,
+    },
+    /// Stops after returning from the function at `depth`.
+    StepOut {
+        /// The call stack depth where the step has been started.
+        depth: usize,
+    },
+    /// Never stops again, since the input has been closed.
+    Detached,
+    /// The session has been terminated by the user.
+    Terminated,
+}
+
+///
+/// The virtual machine interactive debugger.
+///
+/// Is called before each instruction is executed. The source code locations and the call stack
+/// are tracked with the debug marker instructions and the `call` and `return` instructions, since
+/// the location markers are only written by the compiler when the location changes in the
+/// bytecode order, not in the execution order.
+///
+pub struct Debugger {
+    /// The debugger commands input.
+    input: Box<dyn BufRead>,
+    /// The debugger messages output.
+    output: Box<dyn Write>,
+    /// The breakpoints set by the user.
+    breakpoints: Vec<Breakpoint>,
+    /// The current execution mode.
+    mode: Mode,
+    /// The call stack, whose root frame belongs to the entry function.
+    stack: Vec<Frame>,
+    /// The last command, which is repeated if an empty line is entered.
+    last_command: Option<String>,
+}
+
+impl Debugger {
+    /// The command prompt.
+    const PROMPT: &'static str = "(zdb) ";
+
+    /// The commands reference printed by the `help` command.
+    const HELP: &'static str = r#"Commands:
+    break <file:line|function>, b   set a breakpoint
+    delete [number], d              delete a breakpoint or all of them
+    breakpoints, i                  list the breakpoints
+    continue, c                     continue until a breakpoint is hit
+    step, s                         step to the next source line, entering calls
+    next, n                         step to the next source line, skipping calls
+    finish, f                       step out of the current function
+    stepi, si                       step to the next instruction
+    where, bt                       print the location and the call stack
+    stack                           print the evaluation stack
+    frame                           print the current data stack frame
+    storage                         print the contract storage fields
+    help, h                         print this reference
+    quit, q                         terminate the session
+An empty line repeats the last command."#;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    /// If there are no `breakpoints`, the execution is stopped at the first source code line.
+    ///
+    pub fn new(
+        breakpoints: Vec<Breakpoint>,
+        input: Box<dyn BufRead>,
+        output: Box<dyn Write>,
+    ) -> Self {
+        let mode = if breakpoints.is_empty() {
+            Mode::StepIn
+        } else {
+            Mode::Continue
+        };
+
+        Self {
+            input,
+            output,
+            breakpoints,
+            mode,
+            stack: vec![Frame::default()],
+            last_command: None,
+        }
+    }
+
+    ///
+    /// Called before the `instruction` at `address` is executed. If the execution must be
+    /// stopped, reads and executes the user commands until the execution is resumed.
+    ///
+    /// Returns an error if the session has been terminated by the user.
+    ///
+    pub fn before_instruction<VM>(
+        &mut self,
+        address: usize,
+        instruction: &Instruction,
+        vm: &VM,
+    ) -> Result<(), Error>
+    where
+        VM: IDebuggable,
+    {
+        let depth = self.stack.len();
+        let frame = self
+            .stack
+            .last_mut()
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+        match instruction {
+            Instruction::FileMarker(marker) => {
+                frame.file = Some(marker.file.to_owned());
+                frame.line = None;
+                return Ok(());
+            }
+            Instruction::FunctionMarker(marker) => {
+                frame.function = Some(marker.function.to_owned());
+                frame.is_function_entered = true;
+                return Ok(());
+            }
+            Instruction::LineMarker(marker) => {
+                frame.line = Some(marker.line);
+                frame.is_line_entered = true;
+                return Ok(());
+            }
+            Instruction::ColumnMarker(_) => return Ok(()),
+            _ => {}
+        }
+
+        let is_line_entered = std::mem::take(&mut frame.is_line_entered);
+        let is_function_entered = std::mem::take(&mut frame.is_function_entered);
+
+        if self.mode != Mode::Detached {
+            let breakpoint = self.breakpoints.iter().position(|breakpoint| {
+                let is_line = match (frame.file.as_deref(), frame.line) {
+                    (Some(file), Some(line)) => is_line_entered && breakpoint.is_line(file, line),
+                    _ => false,
+                };
+                let is_function = match frame.function.as_deref() {
+                    Some(function) => is_function_entered && breakpoint.is_function(function),
+                    None => false,
+                };
+                is_line || is_function
+            });
+
+            let is_stopped = breakpoint.is_some()
+                || match self.mode {
+                    Mode::StepInstruction => true,
+                    Mode::StepIn => is_line_entered,
+                    Mode::StepOver { depth: start } => is_line_entered && depth <= start,
+                    Mode::StepOut { depth: start } => depth < start,
+                    Mode::Continue | Mode::Detached | Mode::Terminated => false,
+                };
+
+            if is_stopped {
+                self.stop(address, instruction, breakpoint, vm)
+                    .map_err(Error::DebuggerIo)?;
+                if let Mode::Terminated = self.mode {
+                    return Err(Error::DebuggerTerminated);
+                }
+            }
+        }
+
+        match instruction {
+            Instruction::Call(_) => {
+                let file = self.stack.last().and_then(|frame| frame.file.clone());
+                self.stack.push(Frame {
+                    file,
+                    ..Frame::default()
+                });
+            }
+            Instruction::Return(_) if self.stack.len() > 1 => {
+                self.stack.pop();
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    ///
+    /// Prints the stop location and executes the user commands until the execution is resumed.
+    ///
+    fn stop<VM>(
+        &mut self,
+        address: usize,
+        instruction: &Instruction,
+        breakpoint: Option<usize>,
+        vm: &VM,
+    ) -> io::Result<()>
+    where
+        VM: IDebuggable,
+    {
+        if let Some(index) = breakpoint {
+            write!(self.output, "Breakpoint {}, ", index + 1)?;
+        }
+        self.print_location(address, instruction)?;
+
+        loop {
+            write!(self.output, "{}", Self::PROMPT)?;
+            self.output.flush()?;
+
+            let mut line = String::new();
+            if self.input.read_line(&mut line)? == 0 {
+                writeln!(self.output)?;
+                self.mode = Mode::Detached;
+                return Ok(());
+            }
+
+            let line = match line.trim() {
+                "" => match self.last_command.clone() {
+                    Some(command) => command,
+                    None => continue,
+                },
+                line => line.to_owned(),
+            };
+            self.last_command = Some(line.clone());
+
+            let mut words = line.split_whitespace();
+            let command = words.next().unwrap_or_default();
+            let argument = words.next();
+
+            let depth = self.stack.len();
+            match command {
+                "c" | "continue" => self.mode = Mode::Continue,
+                "s" | "step" => self.mode = Mode::StepIn,
+                "n" | "next" => self.mode = Mode::StepOver { depth },
+                "f" | "finish" => self.mode = Mode::StepOut { depth },
+                "si" | "stepi" => self.mode = Mode::StepInstruction,
+                "q" | "quit" => self.mode = Mode::Terminated,
+                "b" | "break" => {
+                    match argument.map(Breakpoint::from_str) {
+                        Some(Ok(breakpoint)) => {
+                            writeln!(
+                                self.output,
+                                "Breakpoint {} at {}",
+                                self.breakpoints.len() + 1,
+                                breakpoint
+                            )?;
+                            self.breakpoints.push(breakpoint);
+                        }
+                        Some(Err(error)) => writeln!(self.output, "{}", error)?,
+                        None => writeln!(self.output, "Usage: break <file:line|function>")?,
+                    }
+                    continue;
+                }
+                "d" | "delete" => {
+                    match argument.map(str::parse::<usize>) {
+                        Some(Ok(number)) if number >= 1 && number <= self.breakpoints.len() => {
+                            let breakpoint = self.breakpoints.remove(number - 1);
+                            writeln!(self.output, "Deleted breakpoint at {}", breakpoint)?;
+                        }
+                        Some(_) => writeln!(self.output, "No such breakpoint")?,
+                        None => {
+                            self.breakpoints.clear();
+                            writeln!(self.output, "Deleted all breakpoints")?;
+                        }
+                    }
+                    continue;
+                }
+                "i" | "breakpoints" => {
+                    if self.breakpoints.is_empty() {
+                        writeln!(self.output, "No breakpoints")?;
+                    }
+                    for (index, breakpoint) in self.breakpoints.iter().enumerate() {
+                        writeln!(self.output, "{:>4} {}", index + 1, breakpoint)?;
+                    }
+                    continue;
+                }
+                "bt" | "where" => {
+                    self.print_location(address, instruction)?;
+                    for (index, frame) in self.stack.iter().rev().enumerate() {
+                        writeln!(self.output, "#{:<3} {}", index, frame)?;
+                    }
+                    continue;
+                }
+                "stack" => {
+                    let cells = vm.evaluation_stack();
+                    if cells.is_empty() {
+                        writeln!(self.output, "The evaluation stack is empty")?;
+                    }
+                    for (index, cell) in cells.into_iter().enumerate() {
+                        writeln!(self.output, "{:>4}  {}", index, cell)?;
+                    }
+                    continue;
+                }
+                "frame" => {
+                    let cells = vm.data_stack_frame();
+                    if cells.is_empty() {
+                        writeln!(self.output, "The data stack frame is empty")?;
+                    }
+                    for (address, cell) in cells.into_iter() {
+                        writeln!(
+                            self.output,
+                            "{:>4}  {}",
+                            address,
+                            cell.as_deref().unwrap_or("<uninitialized>")
+                        )?;
+                    }
+                    continue;
+                }
+                "storage" => {
+                    self.print_storages(vm)?;
+                    continue;
+                }
+                "h" | "help" => {
+                    writeln!(self.output, "{}", Self::HELP)?;
+                    continue;
+                }
+                command => {
+                    writeln!(
+                        self.output,
+                        "Unknown command `{}`. Type `help` for the list of commands.",
+                        command
+                    )?;
+                    continue;
+                }
+            }
+
+            return Ok(());
+        }
+    }
+
+    ///
+    /// Prints the current source code location and the `instruction` at `address`.
+    ///
+    fn print_location(&mut self, address: usize, instruction: &Instruction) -> io::Result<()> {
+        let frame = self
+            .stack
+            .last()
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+        writeln!(self.output, "{}", frame)?;
+        writeln!(self.output, "    {:04}  {}", address, instruction)
+    }
+
+    ///
+    /// Prints the typed storage fields of each contract instance.
+    ///
+    fn print_storages<VM>(&mut self, vm: &VM) -> io::Result<()>
+    where
+        VM: IDebuggable,
+    {
+        let storages = match vm.storages() {
+            Ok(storages) => storages,
+            Err(error) => return writeln!(self.output, "{}", error),
+        };
+        if storages.is_empty() {
+            writeln!(self.output, "No contract storages")?;
+        }
+
+        for (address, fields) in storages.into_iter() {
+            writeln!(self.output, "Contract 0x{:0>40}:", address.to_str_radix(16))?;
+            for (field, value) in fields.into_iter() {
+                writeln!(
+                    self.output,
+                    "    {}: {} = {}",
+                    field.name,
+                    field.r#type,
+                    serde_json::to_string(&value.into_json()).unwrap_or_default()
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::io;
+    use std::io::Write;
+    use std::rc::Rc;
+
+    use num::BigInt;
+
+    use crate::error::Error;
+
+    use super::Breakpoint;
+    use super::Debugger;
+    use super::IDebuggable;
+    use super::StorageFields;
+
+    #[derive(Clone, Default)]
+    struct Output(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for Output {
+        fn write(&mut self, buffer: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().write(buffer)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    struct VM;
+
+    impl IDebuggable for VM {
+        fn evaluation_stack(&self) -> Vec<String> {
+            vec!["42 as u8 (det)".to_owned()]
+        }
+
+        fn data_stack_frame(&self) -> Vec<(usize, Option<String>)> {
+            vec![(0, Some("1 as u8 (witness)".to_owned())), (1, None)]
+        }
+
+        fn storages(&self) -> Result<Vec<(BigInt, StorageFields)>, Error> {
+            Ok(vec![])
+        }
+    }
+
+    fn session(breakpoints: Vec<Breakpoint>, commands: &str) -> Result<String, Error> {
+        let output = Output::default();
+        let mut debugger = Debugger::new(
+            breakpoints,
+            Box::new(io::Cursor::new(commands.as_bytes().to_vec())),
+            Box::new(output.clone()),
+        );
+
+        let instructions: Vec<zinc_types::Instruction> = vec![
+            zinc_types::FileMarker::new("src/main.zn".to_owned()).into(),
+            zinc_types::FunctionMarker::new("main".to_owned()).into(),
+            zinc_types::LineMarker::new(2).into(),
+            zinc_types::Call::new(7, 0).into(),
+            zinc_types::LineMarker::new(3).into(),
+            zinc_types::Add.into(),
+            zinc_types::Return::new(1).into(),
+            zinc_types::FileMarker::new("src/main.zn".to_owned()).into(),
+            zinc_types::FunctionMarker::new("double".to_owned()).into(),
+            zinc_types::LineMarker::new(7).into(),
+            zinc_types::Mul.into(),
+            zinc_types::Return::new(1).into(),
+        ];
+        for address in [0, 1, 2, 3, 7, 8, 9, 10, 11, 4, 5, 6].iter().copied() {
+            debugger.before_instruction(address, &instructions[address], &VM)?;
+        }
+
+        let output = output.0.borrow().clone();
+        Ok(String::from_utf8(output).expect(zinc_const::panic::DATA_CONVERSION))
+    }
+
+    #[test]
+    fn ok_breakpoint_parsing() {
+        assert_eq!(
+            "src/main.zn:42".parse::<Breakpoint>(),
+            Ok(Breakpoint::Line {
+                file: "src/main.zn".to_owned(),
+                line: 42
+            })
+        );
+        assert_eq!(
+            "Wallet::deposit".parse::<Breakpoint>(),
+            Ok(Breakpoint::Function("Wallet::deposit".to_owned()))
+        );
+        assert!(":42".parse::<Breakpoint>().is_err());
+    }
+
+    #[test]
+    fn ok_step_in() {
+        let output = session(vec![], "s\ns\nstack\nframe\nc\n").expect("Session failed");
+
+        assert_eq!(
+            output,
+            r#"src/main.zn:2 (in main)
+    0003  call 7 0
+(zdb) src/main.zn:7 (in double)
+    0010  mul
+(zdb) src/main.zn:3 (in main)
+    0005  add
+(zdb)    0  42 as u8 (det)
+(zdb)    0  1 as u8 (witness)
+   1  <uninitialized>
+(zdb) "#
+        );
+    }
+
+    #[test]
+    fn ok_step_over() {
+        let output = session(vec![], "n\nbt\n").expect("Session failed");
+
+        assert!(output.contains("(zdb) src/main.zn:3 (in main)\n    0005  add\n"));
+        assert!(output.contains("#0   src/main.zn:3 (in main)\n"));
+    }
+
+    #[test]
+    fn ok_breakpoints() {
+        let output = session(
+            vec![Breakpoint::Function("double".to_owned())],
+            "b main.zn:3\nc\nc\n",
+        )
+        .expect("Session failed");
+
+        assert!(output.starts_with("Breakpoint 1, src/main.zn:7 (in double)\n"));
+        assert!(output.contains("Breakpoint 2 at main.zn:3\n"));
+        assert!(output.contains("Breakpoint 2, src/main.zn:3 (in main)\n"));
+    }
+
+    #[test]
+    fn error_terminated() {
+        let result = session(vec![], "q\n");
+
+        assert!(matches!(result, Err(Error::DebuggerTerminated)));
+    }
+}
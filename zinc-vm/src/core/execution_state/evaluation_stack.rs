@@ -92,6 +92,13 @@ impl<E: IEngine> EvaluationStack<E> {
         Ok(())
     }
 
+    ///
+    /// Returns the cells of all the branch frames, starting from the top one.
+    ///
+    pub fn iter(&self) -> impl Iterator<Item = &Cell<E>> {
+        self.stack.iter().rev().flat_map(|frame| frame.iter().rev())
+    }
+
     pub fn revert(&mut self) -> Result<(), Error> {
         self.stack.pop().ok_or(MalformedBytecode::StackUnderflow)?;
         Ok(())
@@ -14,6 +14,7 @@ use crate::core::contract::output::initializer::Initializer;
 use crate::gadgets::scalar::Scalar;
 use crate::IEngine;
 
+use self::cell::Cell;
 use self::data_stack::DataStack;
 use self::evaluation_stack::EvaluationStack;
 use self::function_frame::Frame;
@@ -46,6 +47,39 @@ impl<E: IEngine> ExecutionState<E> {
             initializers: Vec::with_capacity(Self::INITIALIZERS_INITIAL_CAPACITY),
         }
     }
+
+    ///
+    /// Returns the evaluation stack cells in the human-readable form, starting from the top one.
+    ///
+    pub fn evaluation_stack_cells(&self) -> Vec<String> {
+        self.evaluation_stack
+            .iter()
+            .map(|Cell::Value(value)| value.to_string())
+            .collect()
+    }
+
+    ///
+    /// Returns the current function data stack frame cells in the human-readable form along
+    /// with their frame addresses.
+    ///
+    pub fn data_stack_frame_cells(&self) -> Vec<(usize, Option<String>)> {
+        let frame = match self.frames_stack.last() {
+            Some(frame) => frame,
+            None => return vec![],
+        };
+
+        (frame.stack_frame_start..frame.stack_frame_end)
+            .map(|address| {
+                let cell = self
+                    .data_stack
+                    .memory
+                    .get(address)
+                    .and_then(Option::as_ref)
+                    .map(|Cell::Value(value)| value.to_string());
+                (address - frame.stack_frame_start, cell)
+            })
+            .collect()
+    }
 }
 
 impl<E: IEngine> fmt::Display for ExecutionState<E> {
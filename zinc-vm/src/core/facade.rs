@@ -18,14 +18,7 @@ impl Facade {
         proof: Proof<E>,
         public_input: zinc_types::Value,
     ) -> Result<bool, VerificationError> {
-        let public_input_flat = public_input
-            .into_flat_values()
-            .into_iter()
-            .map(|value| {
-                gadgets::scalar::fr_bigint::bigint_to_fr::<E>(&value)
-                    .ok_or(VerificationError::ValueOverflow(value))
-            })
-            .collect::<Result<Vec<E::Fr>, VerificationError>>()?;
+        let public_input_flat = Self::public_input_flat::<E>(public_input)?;
 
         let prepared_verifying_key = groth16::prepare_verifying_key(&verifying_key);
         let success = groth16::verify_proof(
@@ -37,4 +30,20 @@ impl Facade {
 
         Ok(success)
     }
+
+    ///
+    /// Converts the public input value into the field elements, which the proof is verified with.
+    ///
+    pub(crate) fn public_input_flat<E: IEngine>(
+        public_input: zinc_types::Value,
+    ) -> Result<Vec<E::Fr>, VerificationError> {
+        public_input
+            .into_flat_values()
+            .into_iter()
+            .map(|value| {
+                gadgets::scalar::fr_bigint::bigint_to_fr::<E>(&value)
+                    .ok_or(VerificationError::ValueOverflow(value))
+            })
+            .collect()
+    }
 }
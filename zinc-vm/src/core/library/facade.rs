@@ -9,7 +9,12 @@ use franklin_crypto::bellman::pairing::bn256::Bn256;
 use zinc_const::UnitTestExitCode;
 
 use crate::constraint_systems::main::Main as MainCS;
+use crate::core::debugger::Debugger;
 use crate::core::library::State as LibraryState;
+use crate::core::unit_test::options::Options as UnitTestOptions;
+use crate::core::unit_test::summary::Summary as UnitTestSummary;
+use crate::core::unit_test::Runner as UnitTestRunner;
+use crate::core::virtual_machine::IVirtualMachine;
 use crate::error::Error;
 use crate::IEngine;
 
@@ -22,42 +27,65 @@ impl Facade {
         Self { inner }
     }
 
-    pub fn test<E: IEngine>(self) -> Result<UnitTestExitCode, Error> {
-        let mut exit_code = UnitTestExitCode::Passed;
+    pub fn test<E: IEngine>(self, options: UnitTestOptions) -> Result<UnitTestSummary, Error> {
+        let library = self.inner;
 
-        for (name, unit_test) in self.inner.unit_tests.clone().into_iter() {
-            if unit_test.is_ignored {
-                log::info!("test {} ... {}", name, "ignore".yellow());
-                return Ok(UnitTestExitCode::Ignored);
+        UnitTestRunner::new(options).run(
+            library.name.clone(),
+            library.unit_tests.clone(),
+            |unit_test| {
+                let cs = MainCS::<Bn256>::new();
+
+                let mut state = LibraryState::new(cs);
+                state.test(library.clone(), unit_test.address)?;
+
+                Ok(state.constraint_system().num_constraints())
+            },
+        )
+    }
+
+    pub fn debug_test<E: IEngine>(
+        self,
+        name: &str,
+        debugger: Debugger,
+    ) -> Result<UnitTestExitCode, Error> {
+        let unit_test =
+            self.inner
+                .unit_tests
+                .get(name)
+                .cloned()
+                .ok_or_else(|| Error::UnitTestNotFound {
+                    found: name.to_owned(),
+                })?;
+
+        let cs = MainCS::<Bn256>::new();
+
+        let mut state = LibraryState::new(cs);
+        state.set_debugger(debugger);
+
+        Ok(match state.test(self.inner, unit_test.address) {
+            Err(Error::DebuggerTerminated) => return Err(Error::DebuggerTerminated),
+            Err(_) if unit_test.should_panic => {
+                log::info!("test {} ... {} (failed)", name, "ok".green());
+                UnitTestExitCode::Passed
+            }
+            Ok(_) if unit_test.should_panic => {
+                log::error!(
+                    "test {} ... {} (should have failed)",
+                    name,
+                    "error".bright_red()
+                );
+                UnitTestExitCode::Failed
             }
 
-            let cs = MainCS::<Bn256>::new();
-
-            let mut state = LibraryState::new(cs);
-
-            match state.test(self.inner.clone(), unit_test.address) {
-                Err(_) if unit_test.should_panic => {
-                    log::info!("test {} ... {} (failed)", name, "ok".green());
-                }
-                Ok(_) if unit_test.should_panic => {
-                    log::error!(
-                        "test {} ... {} (should have failed)",
-                        name,
-                        "error".bright_red()
-                    );
-                    exit_code = UnitTestExitCode::Failed;
-                }
-
-                Ok(_) => {
-                    log::info!("test {} ... {}", name, "ok".green());
-                }
-                Err(error) => {
-                    log::error!("test {} ... {} ({})", name, "error".bright_red(), error);
-                    exit_code = UnitTestExitCode::Failed;
-                }
-            };
-        }
-
-        Ok(exit_code)
+            Ok(_) => {
+                log::info!("test {} ... {}", name, "ok".green());
+                UnitTestExitCode::Passed
+            }
+            Err(error) => {
+                log::error!("test {} ... {} ({})", name, "error".bright_red(), error);
+                UnitTestExitCode::Failed
+            }
+        })
     }
 }
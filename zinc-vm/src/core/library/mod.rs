@@ -12,6 +12,9 @@ use franklin_crypto::bellman::ConstraintSystem;
 use crate::core::contract::storage::leaf::LeafVariant;
 use crate::core::contract::storage::setup::Storage as SetupStorage;
 use crate::core::counter::NamespaceCounter;
+use crate::core::debugger::Debugger;
+use crate::core::debugger::IDebuggable;
+use crate::core::debugger::StorageFields;
 use crate::core::execution_state::block::branch::Branch;
 use crate::core::execution_state::block::r#loop::Loop;
 use crate::core::execution_state::block::Block;
@@ -37,6 +40,7 @@ where
     execution_state: ExecutionState<E>,
 
     pub(crate) location: Location,
+    debugger: Option<Debugger>,
 }
 
 impl<E, CS> State<E, CS>
@@ -50,6 +54,7 @@ where
             execution_state: ExecutionState::new(),
 
             location: Location::new(),
+            debugger: None,
         }
     }
 
@@ -87,6 +92,8 @@ where
                 instruction,
             );
 
+            self.debug(self.execution_state.instruction_counter, &instruction)?;
+
             self.execution_state.instruction_counter += 1;
             if let Err(error) = instruction.execute(self) {
                 log::error!("{}\nat {}", error, self.location.to_string().blue());
@@ -137,6 +144,30 @@ where
             .ok_or_else(|| MalformedBytecode::StackUnderflow.into())
     }
 
+    ///
+    /// Attaches the interactive `debugger`, which is called before each instruction.
+    ///
+    pub fn set_debugger(&mut self, debugger: Debugger) {
+        self.debugger = Some(debugger);
+    }
+
+    ///
+    /// Passes the `instruction` at `address` to the debugger, if it is attached.
+    ///
+    fn debug(
+        &mut self,
+        address: usize,
+        instruction: &zinc_types::Instruction,
+    ) -> Result<(), Error> {
+        if let Some(mut debugger) = self.debugger.take() {
+            let result = debugger.before_instruction(address, instruction, &*self);
+            self.debugger = Some(debugger);
+            result?;
+        }
+
+        Ok(())
+    }
+
     fn top_frame(&mut self) -> Result<&mut Frame<E>, Error> {
         self.execution_state
             .frames_stack
@@ -179,6 +210,18 @@ where
             .set(frame_start + address, cell)
     }
 
+    fn reference(&mut self, address: usize) -> Result<usize, Error> {
+        Ok(self.top_frame()?.stack_frame_start + address)
+    }
+
+    fn load_indirect(&mut self, reference: usize) -> Result<Cell<E>, Error> {
+        self.execution_state.data_stack.get(reference)
+    }
+
+    fn store_indirect(&mut self, reference: usize, cell: Cell<E>) -> Result<(), Error> {
+        self.execution_state.data_stack.set(reference, cell)
+    }
+
     fn storage_init(
         &mut self,
         _project: zinc_project::ManifestProject,
@@ -414,3 +457,21 @@ where
         self.location = location;
     }
 }
+
+impl<E, CS> IDebuggable for State<E, CS>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    fn evaluation_stack(&self) -> Vec<String> {
+        self.execution_state.evaluation_stack_cells()
+    }
+
+    fn data_stack_frame(&self) -> Vec<(usize, Option<String>)> {
+        self.execution_state.data_stack_frame_cells()
+    }
+
+    fn storages(&self) -> Result<Vec<(BigInt, StorageFields)>, Error> {
+        Ok(vec![])
+    }
+}
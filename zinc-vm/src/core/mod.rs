@@ -5,8 +5,12 @@
 pub mod circuit;
 pub mod contract;
 pub mod counter;
+pub mod debugger;
 pub mod execution_state;
 pub mod facade;
 pub mod library;
 pub mod location;
+pub mod profiler;
+pub mod unit_test;
+pub mod verifier;
 pub mod virtual_machine;
@@ -0,0 +1,307 @@
+//!
+//! The virtual machine constraint profiler.
+//!
+
+use std::collections::HashMap;
+use std::fmt;
+
+use zinc_types::Instruction;
+
+///
+/// The call stack frame of the profiled application.
+///
+#[derive(Debug, Clone)]
+struct Frame {
+    /// The function name set by the function marker.
+    function: String,
+    /// The source code file set by the file marker.
+    file: Option<String>,
+    /// The source code line set by the line marker.
+    line: Option<usize>,
+}
+
+impl Frame {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(function: String, file: Option<String>) -> Self {
+        Self {
+            function,
+            file,
+            line: None,
+        }
+    }
+}
+
+///
+/// The virtual machine constraint profiler.
+///
+/// Attributes the constraints synthesized by each instruction to the source code function and
+/// line, which are tracked with the debug marker instructions. The call stack is tracked with
+/// the `call` and `return` instructions, since the location markers are only written by the
+/// compiler when the location changes in the bytecode order, not in the execution order.
+///
+#[derive(Debug)]
+pub struct Profiler {
+    /// The call stack, whose root frame holds the constraints synthesized before the entry.
+    stack: Vec<Frame>,
+    /// The total number of constraints recorded so far.
+    total: usize,
+    /// The numbers of constraints synthesized at each source code line.
+    lines: HashMap<(String, usize), usize>,
+    /// The numbers of constraints synthesized within each function, excluding its callees.
+    functions: HashMap<String, usize>,
+    /// The numbers of constraints synthesized at each call stack.
+    stacks: HashMap<Vec<String>, usize>,
+}
+
+impl Default for Profiler {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Profiler {
+    /// The pseudo-function holding the input and storage allocation constraints.
+    const ROOT_FUNCTION: &'static str = "<setup>";
+
+    /// The pseudo-function name used until the function marker is executed.
+    const UNKNOWN_FUNCTION: &'static str = "<unknown>";
+
+    /// The file name used until the file marker is executed.
+    const UNKNOWN_FILE: &'static str = "<unknown>";
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new() -> Self {
+        Self {
+            stack: vec![Frame::new(Self::ROOT_FUNCTION.to_owned(), None)],
+            total: 0,
+            lines: HashMap::new(),
+            functions: HashMap::new(),
+            stacks: HashMap::new(),
+        }
+    }
+
+    ///
+    /// Records the `instruction` which has just been executed, where `constraints` is the
+    /// total number of constraints in the constraint system after its execution.
+    ///
+    pub fn record(&mut self, instruction: &Instruction, constraints: usize) {
+        let frame = self
+            .stack
+            .last_mut()
+            .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+        match instruction {
+            Instruction::FileMarker(marker) => {
+                frame.file = Some(marker.file.to_owned());
+                frame.line = None;
+            }
+            Instruction::FunctionMarker(marker) => frame.function = marker.function.to_owned(),
+            Instruction::LineMarker(marker) => frame.line = Some(marker.line),
+            _ => {}
+        }
+
+        let count = constraints.saturating_sub(self.total);
+        self.total = constraints;
+        if count > 0 {
+            let frame = self
+                .stack
+                .last()
+                .expect(zinc_const::panic::VALUE_ALWAYS_EXISTS);
+
+            let file = frame
+                .file
+                .clone()
+                .unwrap_or_else(|| Self::UNKNOWN_FILE.to_owned());
+            *self
+                .lines
+                .entry((file, frame.line.unwrap_or_default()))
+                .or_default() += count;
+            *self.functions.entry(frame.function.to_owned()).or_default() += count;
+
+            let stack = match self.stack.len() {
+                1 => vec![Self::ROOT_FUNCTION.to_owned()],
+                _ => self.stack[1..]
+                    .iter()
+                    .map(|frame| frame.function.to_owned())
+                    .collect(),
+            };
+            *self.stacks.entry(stack).or_default() += count;
+        }
+
+        match instruction {
+            Instruction::Call(_) => {
+                let file = self.stack.last().and_then(|frame| frame.file.clone());
+                self.stack
+                    .push(Frame::new(Self::UNKNOWN_FUNCTION.to_owned(), file));
+            }
+            Instruction::Return(_) if self.stack.len() > 1 => {
+                self.stack.pop();
+            }
+            _ => {}
+        }
+    }
+
+    ///
+    /// Returns the total number of constraints recorded.
+    ///
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    ///
+    /// Returns the functions with their own and inclusive numbers of constraints, sorted by
+    /// the inclusive number in descending order.
+    ///
+    pub fn functions(&self) -> Vec<(String, usize, usize)> {
+        let mut inclusive: HashMap<&str, usize> = HashMap::with_capacity(self.functions.len());
+        for (stack, count) in self.stacks.iter() {
+            let mut seen = Vec::with_capacity(stack.len());
+            for function in stack.iter() {
+                if !seen.contains(&function) {
+                    *inclusive.entry(function.as_str()).or_default() += count;
+                    seen.push(function);
+                }
+            }
+        }
+
+        let mut functions: Vec<(String, usize, usize)> = self
+            .functions
+            .iter()
+            .map(|(name, count)| {
+                let inclusive = inclusive.get(name.as_str()).copied().unwrap_or(*count);
+                (name.to_owned(), *count, inclusive)
+            })
+            .collect();
+        functions.sort_by(|a, b| b.2.cmp(&a.2).then(b.1.cmp(&a.1)).then(a.0.cmp(&b.0)));
+        functions
+    }
+
+    ///
+    /// Returns the source code lines with their numbers of constraints, sorted by the number
+    /// in descending order.
+    ///
+    pub fn lines(&self) -> Vec<(String, usize, usize)> {
+        let mut lines: Vec<(String, usize, usize)> = self
+            .lines
+            .iter()
+            .map(|((file, line), count)| (file.to_owned(), *line, *count))
+            .collect();
+        lines.sort_by(|a, b| b.2.cmp(&a.2).then(a.0.cmp(&b.0)).then(a.1.cmp(&b.1)));
+        lines
+    }
+
+    ///
+    /// Returns the call stacks in the folded format, which is accepted by the flamegraph tools,
+    /// that is, the semicolon-separated function names followed by the number of constraints.
+    ///
+    pub fn folded_stacks(&self) -> String {
+        let mut stacks: Vec<String> = self
+            .stacks
+            .iter()
+            .map(|(stack, count)| format!("{} {}", stack.join(";"), count))
+            .collect();
+        stacks.sort();
+
+        let mut result = stacks.join("\n");
+        result.push('\n');
+        result
+    }
+}
+
+impl fmt::Display for Profiler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let percent = |count: usize| match self.total {
+            0 => 0.0,
+            total => (count as f64) * 100.0 / (total as f64),
+        };
+
+        writeln!(f, "Total constraints: {}", self.total)?;
+
+        writeln!(f)?;
+        writeln!(
+            f,
+            "{:>12} {:>7} {:>12} {:>7}  Function",
+            "Inclusive", "%", "Own", "%"
+        )?;
+        for (name, count, inclusive) in self.functions().into_iter() {
+            writeln!(
+                f,
+                "{:>12} {:>6.2}% {:>12} {:>6.2}%  {}",
+                inclusive,
+                percent(inclusive),
+                count,
+                percent(count),
+                name
+            )?;
+        }
+
+        writeln!(f)?;
+        writeln!(f, "{:>12} {:>7}  Line", "Own", "%")?;
+        for (file, line, count) in self.lines().into_iter() {
+            writeln!(
+                f,
+                "{:>12} {:>6.2}%  {}:{}",
+                count,
+                percent(count),
+                file,
+                line
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Profiler;
+
+    #[test]
+    fn ok_attribution() {
+        let mut profiler = Profiler::new();
+
+        profiler.record(&zinc_types::Call::new(0, 0).into(), 3);
+        profiler.record(&zinc_types::FileMarker::new("main.zn".to_owned()).into(), 3);
+        profiler.record(
+            &zinc_types::FunctionMarker::new("main".to_owned()).into(),
+            3,
+        );
+        profiler.record(&zinc_types::LineMarker::new(2).into(), 3);
+        profiler.record(&zinc_types::Call::new(8, 0).into(), 3);
+        profiler.record(&zinc_types::FileMarker::new("main.zn".to_owned()).into(), 3);
+        profiler.record(
+            &zinc_types::FunctionMarker::new("double".to_owned()).into(),
+            3,
+        );
+        profiler.record(&zinc_types::LineMarker::new(7).into(), 3);
+        profiler.record(&zinc_types::Mul.into(), 10);
+        profiler.record(&zinc_types::Return::new(1).into(), 10);
+        profiler.record(&zinc_types::Add.into(), 15);
+        profiler.record(&zinc_types::Return::new(1).into(), 15);
+
+        assert_eq!(profiler.total(), 15);
+        assert_eq!(
+            profiler.functions(),
+            vec![
+                ("main".to_owned(), 5, 12),
+                ("double".to_owned(), 7, 7),
+                ("<setup>".to_owned(), 3, 3),
+            ]
+        );
+        assert_eq!(
+            profiler.lines(),
+            vec![
+                ("main.zn".to_owned(), 7, 7),
+                ("main.zn".to_owned(), 2, 5),
+                ("<unknown>".to_owned(), 0, 3),
+            ]
+        );
+        assert_eq!(
+            profiler.folded_stacks(),
+            "<setup> 3\nmain 5\nmain;double 7\n".to_owned()
+        );
+    }
+}
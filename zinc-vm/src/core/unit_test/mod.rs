@@ -0,0 +1,135 @@
+//!
+//! The virtual machine unit test runner.
+//!
+
+#[cfg(test)]
+mod tests;
+
+pub mod options;
+pub mod report;
+pub mod summary;
+
+use std::collections::HashMap;
+use std::time::Instant;
+
+use colored::Colorize;
+use rayon::prelude::*;
+
+use crate::error::Error;
+
+use self::options::Options;
+use self::summary::Outcome;
+use self::summary::Summary;
+use self::summary::Test;
+
+///
+/// The virtual machine unit test runner.
+///
+/// Selects the tests with the filters and runs them in parallel, since each test is executed
+/// with its own constraint system and state.
+///
+pub struct Runner {
+    /// The runner options.
+    options: Options,
+}
+
+impl Runner {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(options: Options) -> Self {
+        Self { options }
+    }
+
+    ///
+    /// Runs the selected `unit_tests` of the application `name`.
+    ///
+    /// The `test` callback executes a single unit test and returns the number of constraints
+    /// synthesized by it.
+    ///
+    pub fn run<F>(
+        &self,
+        name: String,
+        unit_tests: HashMap<String, zinc_types::UnitTest>,
+        test: F,
+    ) -> Result<Summary, Error>
+    where
+        F: Fn(&zinc_types::UnitTest) -> Result<usize, Error> + Sync,
+    {
+        let started_at = Instant::now();
+
+        let total = unit_tests.len();
+        let mut selected: Vec<(String, zinc_types::UnitTest)> = unit_tests
+            .into_iter()
+            .filter(|(name, _unit_test)| self.options.is_selected(name.as_str()))
+            .collect();
+        selected.sort_by(|(name_1, _), (name_2, _)| name_1.cmp(name_2));
+        let filtered_out = total - selected.len();
+
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(self.options.threads.unwrap_or_default())
+            .build()?;
+        let tests: Vec<Test> = pool.install(|| {
+            selected
+                .into_par_iter()
+                .map(|(name, unit_test)| self.run_one(name, unit_test, &test))
+                .collect()
+        });
+
+        Ok(Summary::new(
+            name,
+            tests,
+            filtered_out,
+            started_at.elapsed(),
+        ))
+    }
+
+    ///
+    /// Runs a single unit test, measuring its execution time.
+    ///
+    fn run_one<F>(&self, name: String, unit_test: zinc_types::UnitTest, test: &F) -> Test
+    where
+        F: Fn(&zinc_types::UnitTest) -> Result<usize, Error>,
+    {
+        if unit_test.is_ignored && !self.options.include_ignored {
+            log::info!("test {} ... {}", name, "ignore".yellow());
+            return Test::new(name, Outcome::Ignored, Default::default(), None);
+        }
+
+        let started_at = Instant::now();
+        let result = test(&unit_test);
+        let duration = started_at.elapsed();
+
+        let (outcome, constraints) = match result {
+            Err(_) if unit_test.should_panic => {
+                log::info!("test {} ... {} (failed)", name, "ok".green());
+                (Outcome::Passed, None)
+            }
+            Ok(_) if unit_test.should_panic => {
+                log::error!(
+                    "test {} ... {} (should have failed)",
+                    name,
+                    "error".bright_red()
+                );
+                (Outcome::Failed("should have failed".to_owned()), None)
+            }
+
+            Ok(constraints) => {
+                log::info!(
+                    "test {} ... {} ({} constraints, {:.2}s)",
+                    name,
+                    "ok".green(),
+                    constraints,
+                    duration.as_secs_f64(),
+                );
+                (Outcome::Passed, Some(constraints))
+            }
+            Err(error) => {
+                log::error!("test {} ... {} ({})", name, "error".bright_red(), error);
+                (Outcome::Failed(error.to_string()), None)
+            }
+        };
+
+        Test::new(name, outcome, duration, constraints)
+    }
+}
@@ -0,0 +1,54 @@
+//!
+//! The virtual machine unit test options.
+//!
+
+///
+/// The virtual machine unit test options.
+///
+#[derive(Debug, Default, Clone)]
+pub struct Options {
+    /// The test name filters. If empty, all the tests are selected.
+    pub filters: Vec<String>,
+    /// If set, the test names must be equal to a filter instead of containing it.
+    pub is_exact: bool,
+    /// If set, the tests marked with the `ignore` attribute are run as well.
+    pub include_ignored: bool,
+    /// The number of threads to run the tests in. If unset, the number of CPUs is used.
+    pub threads: Option<usize>,
+}
+
+impl Options {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        filters: Vec<String>,
+        is_exact: bool,
+        include_ignored: bool,
+        threads: Option<usize>,
+    ) -> Self {
+        Self {
+            filters,
+            is_exact,
+            include_ignored,
+            threads,
+        }
+    }
+
+    ///
+    /// Checks if the test with `name` is selected by the filters.
+    ///
+    pub fn is_selected(&self, name: &str) -> bool {
+        if self.filters.is_empty() {
+            return true;
+        }
+
+        self.filters.iter().any(|filter| {
+            if self.is_exact {
+                name == filter.as_str()
+            } else {
+                name.contains(filter.as_str())
+            }
+        })
+    }
+}
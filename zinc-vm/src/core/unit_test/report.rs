@@ -0,0 +1,125 @@
+//!
+//! The virtual machine unit test reports.
+//!
+
+use std::fmt::Write;
+
+use crate::core::unit_test::summary::Outcome;
+use crate::core::unit_test::summary::Summary;
+
+impl Summary {
+    ///
+    /// Returns the machine-readable JSON report.
+    ///
+    pub fn to_json(&self) -> serde_json::Value {
+        let tests: Vec<serde_json::Value> = self
+            .tests
+            .iter()
+            .map(|test| {
+                let (outcome, message) = match test.outcome {
+                    Outcome::Passed => ("passed", None),
+                    Outcome::Failed(ref message) => ("failed", Some(message.to_owned())),
+                    Outcome::Ignored => ("ignored", None),
+                };
+
+                serde_json::json!({
+                    "name": test.name,
+                    "outcome": outcome,
+                    "message": message,
+                    "duration": test.duration.as_secs_f64(),
+                    "constraints": test.constraints,
+                })
+            })
+            .collect();
+
+        serde_json::json!({
+            "name": self.name,
+            "passed": self.passed(),
+            "failed": self.failed(),
+            "ignored": self.ignored(),
+            "filtered_out": self.filtered_out,
+            "duration": self.duration.as_secs_f64(),
+            "tests": tests,
+        })
+    }
+
+    ///
+    /// Returns the JUnit XML report, which is understood by most of the CI systems.
+    ///
+    /// The number of constraints is written to the standard output section of each test case,
+    /// since the format has no dedicated attribute for it.
+    ///
+    pub fn to_junit(&self) -> String {
+        let mut xml = String::with_capacity(256 + self.tests.len() * 128);
+
+        let _ = writeln!(xml, r#"<?xml version="1.0" encoding="UTF-8"?>"#);
+        let _ = writeln!(
+            xml,
+            r#"<testsuites name="{}" tests="{}" failures="{}" skipped="{}" time="{:.3}">"#,
+            Self::escape(self.name.as_str()),
+            self.tests.len(),
+            self.failed(),
+            self.ignored(),
+            self.duration.as_secs_f64(),
+        );
+        let _ = writeln!(
+            xml,
+            r#"  <testsuite name="{}" tests="{}" failures="{}" skipped="{}" time="{:.3}">"#,
+            Self::escape(self.name.as_str()),
+            self.tests.len(),
+            self.failed(),
+            self.ignored(),
+            self.duration.as_secs_f64(),
+        );
+
+        for test in self.tests.iter() {
+            let _ = write!(
+                xml,
+                r#"    <testcase name="{}" classname="{}" time="{:.3}">"#,
+                Self::escape(test.name.as_str()),
+                Self::escape(self.name.as_str()),
+                test.duration.as_secs_f64(),
+            );
+            match test.outcome {
+                Outcome::Passed => {}
+                Outcome::Failed(ref message) => {
+                    let _ = write!(
+                        xml,
+                        r#"<failure message="{}"/>"#,
+                        Self::escape(message.as_str())
+                    );
+                }
+                Outcome::Ignored => {
+                    let _ = write!(xml, "<skipped/>");
+                }
+            }
+            if let Some(constraints) = test.constraints {
+                let _ = write!(xml, "<system-out>constraints: {}</system-out>", constraints);
+            }
+            let _ = writeln!(xml, "</testcase>");
+        }
+
+        let _ = writeln!(xml, "  </testsuite>");
+        let _ = writeln!(xml, "</testsuites>");
+
+        xml
+    }
+
+    ///
+    /// Escapes the XML special characters.
+    ///
+    fn escape(value: &str) -> String {
+        let mut escaped = String::with_capacity(value.len());
+        for character in value.chars() {
+            match character {
+                '&' => escaped.push_str("&amp;"),
+                '<' => escaped.push_str("&lt;"),
+                '>' => escaped.push_str("&gt;"),
+                '"' => escaped.push_str("&quot;"),
+                '\'' => escaped.push_str("&apos;"),
+                character => escaped.push(character),
+            }
+        }
+        escaped
+    }
+}
@@ -0,0 +1,150 @@
+//!
+//! The virtual machine unit test summary.
+//!
+
+use std::fmt;
+use std::time::Duration;
+
+use colored::Colorize;
+
+use zinc_const::UnitTestExitCode;
+
+///
+/// The unit test outcome.
+///
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    /// The test has passed.
+    Passed,
+    /// The test has failed with the message.
+    Failed(String),
+    /// The test is marked with the `ignore` attribute and has not been run.
+    Ignored,
+}
+
+///
+/// The unit test result.
+///
+#[derive(Debug, Clone)]
+pub struct Test {
+    /// The test name.
+    pub name: String,
+    /// The test outcome.
+    pub outcome: Outcome,
+    /// The test execution time.
+    pub duration: Duration,
+    /// The number of constraints synthesized by the test, if it has run successfully.
+    pub constraints: Option<usize>,
+}
+
+impl Test {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        name: String,
+        outcome: Outcome,
+        duration: Duration,
+        constraints: Option<usize>,
+    ) -> Self {
+        Self {
+            name,
+            outcome,
+            duration,
+            constraints,
+        }
+    }
+}
+
+///
+/// The unit test run summary.
+///
+#[derive(Debug, Clone)]
+pub struct Summary {
+    /// The application name.
+    pub name: String,
+    /// The results of the selected tests, ordered by the test names.
+    pub tests: Vec<Test>,
+    /// The number of tests which have not been selected by the filters.
+    pub filtered_out: usize,
+    /// The whole run time.
+    pub duration: Duration,
+}
+
+impl Summary {
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(name: String, tests: Vec<Test>, filtered_out: usize, duration: Duration) -> Self {
+        Self {
+            name,
+            tests,
+            filtered_out,
+            duration,
+        }
+    }
+
+    ///
+    /// The number of passed tests.
+    ///
+    pub fn passed(&self) -> usize {
+        self.count(|outcome| matches!(outcome, Outcome::Passed))
+    }
+
+    ///
+    /// The number of failed tests.
+    ///
+    pub fn failed(&self) -> usize {
+        self.count(|outcome| matches!(outcome, Outcome::Failed(_)))
+    }
+
+    ///
+    /// The number of ignored tests.
+    ///
+    pub fn ignored(&self) -> usize {
+        self.count(|outcome| matches!(outcome, Outcome::Ignored))
+    }
+
+    ///
+    /// Returns the process exit code, which is only successful if no test has failed.
+    ///
+    pub fn exit_code(&self) -> UnitTestExitCode {
+        if self.failed() > 0 {
+            UnitTestExitCode::Failed
+        } else {
+            UnitTestExitCode::Passed
+        }
+    }
+
+    ///
+    /// Counts the tests whose outcomes satisfy the `predicate`.
+    ///
+    fn count<P>(&self, predicate: P) -> usize
+    where
+        P: Fn(&Outcome) -> bool,
+    {
+        self.tests
+            .iter()
+            .filter(|test| predicate(&test.outcome))
+            .count()
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "test result: {}. {} passed; {} failed; {} ignored; {} filtered out; finished in {:.2}s",
+            if self.failed() == 0 {
+                "ok".green()
+            } else {
+                "FAILED".bright_red()
+            },
+            self.passed(),
+            self.failed(),
+            self.ignored(),
+            self.filtered_out,
+            self.duration.as_secs_f64(),
+        )
+    }
+}
@@ -0,0 +1,173 @@
+//!
+//! The virtual machine unit test runner tests.
+//!
+
+use std::collections::HashMap;
+use std::time::Duration;
+
+use crate::error::Error;
+
+use super::options::Options;
+use super::summary::Outcome;
+use super::summary::Summary;
+use super::summary::Test;
+use super::Runner;
+
+fn new_options(filters: &[&str], is_exact: bool) -> Options {
+    Options::new(
+        filters.iter().map(|filter| (*filter).to_owned()).collect(),
+        is_exact,
+        false,
+        None,
+    )
+}
+
+fn summary() -> Summary {
+    Summary::new(
+        "<app> & \"co\"".to_owned(),
+        vec![
+            Test::new(
+                "passes".to_owned(),
+                Outcome::Passed,
+                Duration::default(),
+                Some(42),
+            ),
+            Test::new(
+                "fails".to_owned(),
+                Outcome::Failed("1 < 2 && 'a' != \"b\"".to_owned()),
+                Duration::default(),
+                None,
+            ),
+            Test::new(
+                "ignored".to_owned(),
+                Outcome::Ignored,
+                Duration::default(),
+                None,
+            ),
+        ],
+        3,
+        Duration::default(),
+    )
+}
+
+#[test]
+fn ok_is_selected_no_filters() {
+    let options = new_options(&[], false);
+
+    assert!(options.is_selected("anything"));
+}
+
+#[test]
+fn ok_is_selected_substring() {
+    let options = new_options(&["add"], false);
+
+    assert!(options.is_selected("add"));
+    assert!(options.is_selected("test_add_overflow"));
+    assert!(!options.is_selected("sub"));
+}
+
+#[test]
+fn ok_is_selected_exact() {
+    let options = new_options(&["add"], true);
+
+    assert!(options.is_selected("add"));
+    assert!(!options.is_selected("test_add_overflow"));
+}
+
+#[test]
+fn ok_is_selected_several_filters() {
+    let options = new_options(&["add", "mul"], false);
+
+    assert!(options.is_selected("test_add"));
+    assert!(options.is_selected("test_mul"));
+    assert!(!options.is_selected("test_sub"));
+
+    let options = new_options(&["test_add", "test_mul"], true);
+
+    assert!(options.is_selected("test_add"));
+    assert!(options.is_selected("test_mul"));
+    assert!(!options.is_selected("test_add_overflow"));
+}
+
+#[test]
+fn ok_to_json() {
+    let json = summary().to_json();
+
+    assert_eq!(json["name"], "<app> & \"co\"");
+    assert_eq!(json["passed"], 1);
+    assert_eq!(json["failed"], 1);
+    assert_eq!(json["ignored"], 1);
+    assert_eq!(json["filtered_out"], 3);
+    assert_eq!(json["tests"][0]["outcome"], "passed");
+    assert_eq!(json["tests"][0]["constraints"], 42);
+    assert_eq!(json["tests"][1]["outcome"], "failed");
+    assert_eq!(json["tests"][1]["message"], "1 < 2 && 'a' != \"b\"");
+    assert_eq!(json["tests"][2]["outcome"], "ignored");
+    assert!(json["tests"][2]["message"].is_null());
+}
+
+#[test]
+fn ok_to_junit() {
+    let xml = summary().to_junit();
+
+    assert!(xml.contains(
+        r#"<testsuites name="&lt;app&gt; &amp; &quot;co&quot;" tests="3" failures="1" skipped="1" time="0.000">"#
+    ));
+    assert!(xml.contains(
+        r#"<testcase name="passes" classname="&lt;app&gt; &amp; &quot;co&quot;" time="0.000"><system-out>constraints: 42</system-out></testcase>"#
+    ));
+    assert!(xml.contains(
+        r#"<failure message="1 &lt; 2 &amp;&amp; &apos;a&apos; != &quot;b&quot;"/></testcase>"#
+    ));
+    assert!(xml.contains(r#"<testcase name="ignored" classname="&lt;app&gt; &amp; &quot;co&quot;" time="0.000"><skipped/></testcase>"#));
+}
+
+#[test]
+fn ok_ignored_does_not_stop_the_rest() {
+    let mut unit_tests = HashMap::new();
+    unit_tests.insert(
+        "a_ignored".to_owned(),
+        zinc_types::UnitTest::new(0, false, true, None),
+    );
+    unit_tests.insert(
+        "b_passes".to_owned(),
+        zinc_types::UnitTest::new(1, false, false, None),
+    );
+    unit_tests.insert(
+        "c_fails".to_owned(),
+        zinc_types::UnitTest::new(2, false, false, None),
+    );
+    unit_tests.insert(
+        "d_filtered".to_owned(),
+        zinc_types::UnitTest::new(3, false, false, None),
+    );
+
+    let summary = Runner::new(new_options(&["a_", "b_", "c_"], false))
+        .run("app".to_owned(), unit_tests, |unit_test| {
+            if unit_test.address == 2 {
+                Err(Error::RequireError("failure".to_owned()))
+            } else {
+                Ok(unit_test.address)
+            }
+        })
+        .expect(zinc_const::panic::TEST_DATA_VALID);
+
+    let outcomes: Vec<(&str, &Outcome)> = summary
+        .tests
+        .iter()
+        .map(|test| (test.name.as_str(), &test.outcome))
+        .collect();
+    assert_eq!(
+        outcomes,
+        vec![
+            ("a_ignored", &Outcome::Ignored),
+            ("b_passes", &Outcome::Passed),
+            (
+                "c_fails",
+                &Outcome::Failed("require error: failure".to_owned())
+            ),
+        ]
+    );
+    assert_eq!(summary.filtered_out, 1);
+    assert_eq!(summary.tests[1].constraints, Some(1));
+}
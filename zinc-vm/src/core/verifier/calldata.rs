@@ -0,0 +1,351 @@
+//!
+//! The Solidity verifier calldata encoder.
+//!
+
+use franklin_crypto::bellman::groth16::Proof;
+use franklin_crypto::bellman::pairing::bn256::Bn256;
+use franklin_crypto::bellman::pairing::bn256::Fq;
+use franklin_crypto::bellman::pairing::bn256::Fq2;
+use franklin_crypto::bellman::pairing::bn256::Fr;
+use franklin_crypto::bellman::pairing::bn256::G1Affine;
+use franklin_crypto::bellman::pairing::bn256::G2Affine;
+use franklin_crypto::bellman::pairing::ff::Field;
+use franklin_crypto::bellman::pairing::CurveAffine;
+
+use crate::core::facade::Facade;
+use crate::core::verifier;
+use crate::core::verifier::WORD_SIZE;
+use crate::error::VerificationError;
+
+///
+/// The `verifyProof(uint256[2],uint256[2][2],uint256[2],uint256[])` method call data.
+///
+#[derive(Debug, Clone)]
+pub struct Calldata {
+    /// The zero-knowledge proof.
+    pub proof: Proof<Bn256>,
+    /// The public input field elements.
+    pub public_input: Vec<Fr>,
+}
+
+impl Calldata {
+    /// The first four bytes of the `verifyProof(uint256[2],uint256[2][2],uint256[2],uint256[])`
+    /// signature Keccak-256 hash.
+    pub const SELECTOR: [u8; 4] = [0xc3, 0x2e, 0x37, 0x0e];
+
+    /// The number of words before the public input array data, that is, the `a`, `b`, and `c`
+    /// points followed by the public input array offset.
+    const HEAD_WORDS: usize = 2 + 4 + 2 + 1;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(
+        proof: Proof<Bn256>,
+        public_input: zinc_types::Value,
+    ) -> Result<Self, VerificationError> {
+        let public_input = Facade::public_input_flat::<Bn256>(public_input)?;
+
+        Ok(Self {
+            proof,
+            public_input,
+        })
+    }
+
+    ///
+    /// Encodes the method call data according to the Ethereum contract ABI.
+    ///
+    pub fn encode(&self) -> Vec<u8> {
+        let mut calldata = Vec::with_capacity(
+            Self::SELECTOR.len() + (Self::HEAD_WORDS + 1 + self.public_input.len()) * WORD_SIZE,
+        );
+        calldata.extend_from_slice(&Self::SELECTOR);
+
+        for coordinate in verifier::g1_coordinates(&self.proof.a).iter() {
+            calldata.extend(verifier::to_word(coordinate));
+        }
+        for coordinates in verifier::g2_coordinates(&self.proof.b).iter() {
+            for coordinate in coordinates.iter() {
+                calldata.extend(verifier::to_word(coordinate));
+            }
+        }
+        for coordinate in verifier::g1_coordinates(&self.proof.c).iter() {
+            calldata.extend(verifier::to_word(coordinate));
+        }
+
+        calldata.extend(Self::usize_to_word(Self::HEAD_WORDS * WORD_SIZE));
+        calldata.extend(Self::usize_to_word(self.public_input.len()));
+        for element in self.public_input.iter() {
+            calldata.extend(verifier::to_word(element));
+        }
+
+        calldata
+    }
+
+    ///
+    /// Decodes the method call data produced by `encode`.
+    ///
+    pub fn decode(calldata: &[u8]) -> Result<Self, VerificationError> {
+        if calldata.len() < Self::SELECTOR.len()
+            || calldata[..Self::SELECTOR.len()] != Self::SELECTOR
+        {
+            return Err(VerificationError::CalldataDecoding(
+                "the method selector mismatch".to_owned(),
+            ));
+        }
+        let words = &calldata[Self::SELECTOR.len()..];
+        if words.len() % WORD_SIZE != 0 || words.len() < (Self::HEAD_WORDS + 1) * WORD_SIZE {
+            return Err(VerificationError::CalldataDecoding(format!(
+                "invalid length {}",
+                calldata.len()
+            )));
+        }
+        let words: Vec<&[u8]> = words.chunks(WORD_SIZE).collect();
+
+        let offset = Self::word_to_usize(words[Self::HEAD_WORDS - 1])?;
+        if offset != Self::HEAD_WORDS * WORD_SIZE {
+            return Err(VerificationError::CalldataDecoding(format!(
+                "unexpected public input offset {}",
+                offset
+            )));
+        }
+        let length = Self::word_to_usize(words[Self::HEAD_WORDS])?;
+        if words.len() - (Self::HEAD_WORDS + 1) != length {
+            return Err(VerificationError::CalldataDecoding(format!(
+                "public input length {} does not match the data",
+                length
+            )));
+        }
+
+        let fq = |index: usize| -> Result<Fq, VerificationError> {
+            verifier::from_word(words[index]).ok_or_else(|| {
+                VerificationError::CalldataDecoding(format!("word {} is not in the field", index))
+            })
+        };
+
+        let a = Self::g1_point(fq(0)?, fq(1)?)?;
+        let b = Self::g2_point(
+            Fq2 {
+                c0: fq(3)?,
+                c1: fq(2)?,
+            },
+            Fq2 {
+                c0: fq(5)?,
+                c1: fq(4)?,
+            },
+        )?;
+        let c = Self::g1_point(fq(6)?, fq(7)?)?;
+
+        let public_input = words[Self::HEAD_WORDS + 1..]
+            .iter()
+            .enumerate()
+            .map(|(index, word)| {
+                verifier::from_word(word).ok_or_else(|| {
+                    VerificationError::CalldataDecoding(format!(
+                        "public input {} is not in the field",
+                        index
+                    ))
+                })
+            })
+            .collect::<Result<Vec<Fr>, VerificationError>>()?;
+
+        Ok(Self {
+            proof: Proof { a, b, c },
+            public_input,
+        })
+    }
+
+    ///
+    /// Encodes the calldata as a `0x`-prefixed hex string, as it is accepted by the Ethereum
+    /// JSON RPC.
+    ///
+    pub fn to_hex(&self) -> String {
+        format!("0x{}", hex::encode(self.encode()))
+    }
+
+    ///
+    /// Restores the G1 point, checking that it belongs to the curve.
+    ///
+    fn g1_point(x: Fq, y: Fq) -> Result<G1Affine, VerificationError> {
+        if x == Fq::zero() && y == Fq::zero() {
+            return Ok(G1Affine::zero());
+        }
+
+        G1Affine::from_xy_checked(x, y)
+            .map_err(|error| VerificationError::CalldataDecoding(error.to_string()))
+    }
+
+    ///
+    /// Restores the G2 point, checking that it belongs to the curve.
+    ///
+    fn g2_point(x: Fq2, y: Fq2) -> Result<G2Affine, VerificationError> {
+        if x == Fq2::zero() && y == Fq2::zero() {
+            return Ok(G2Affine::zero());
+        }
+
+        G2Affine::from_xy_checked(x, y)
+            .map_err(|error| VerificationError::CalldataDecoding(error.to_string()))
+    }
+
+    ///
+    /// Encodes the integer as a big-endian 256-bit word.
+    ///
+    fn usize_to_word(value: usize) -> Vec<u8> {
+        let mut word = vec![0; WORD_SIZE];
+        let bytes = (value as u64).to_be_bytes();
+        word[WORD_SIZE - bytes.len()..].copy_from_slice(&bytes);
+        word
+    }
+
+    ///
+    /// Decodes the integer from a big-endian 256-bit word.
+    ///
+    fn word_to_usize(word: &[u8]) -> Result<usize, VerificationError> {
+        let (high, low) = word.split_at(WORD_SIZE - std::mem::size_of::<u64>());
+        if high.iter().any(|byte| *byte != 0) {
+            return Err(VerificationError::CalldataDecoding(
+                "integer overflow".to_owned(),
+            ));
+        }
+
+        let mut bytes = [0; 8];
+        bytes.copy_from_slice(low);
+        Ok(u64::from_be_bytes(bytes) as usize)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use num::BigInt;
+
+    use franklin_crypto::bellman::groth16;
+    use franklin_crypto::bellman::groth16::Proof;
+    use franklin_crypto::bellman::pairing::bn256::Bn256;
+    use franklin_crypto::bellman::pairing::bn256::Fr;
+    use franklin_crypto::bellman::pairing::bn256::G1Affine;
+    use franklin_crypto::bellman::pairing::bn256::G2Affine;
+    use franklin_crypto::bellman::pairing::ff::Field;
+    use franklin_crypto::bellman::pairing::CurveAffine;
+
+    use crate::core::circuit::facade::Facade as CircuitFacade;
+    use crate::core::facade::Facade;
+
+    use super::Calldata;
+
+    /// Returns the circuit `fn main(x: field) -> (field, field) { (x * x, x + 1) }`.
+    fn circuit() -> zinc_types::Circuit {
+        zinc_types::Circuit::new(
+            "test".to_owned(),
+            0,
+            zinc_types::Type::Scalar(zinc_types::ScalarType::Field),
+            zinc_types::Type::Tuple(vec![
+                zinc_types::Type::Scalar(zinc_types::ScalarType::Field),
+                zinc_types::Type::Scalar(zinc_types::ScalarType::Field),
+            ]),
+            HashMap::new(),
+            vec![
+                zinc_types::Load::new(0, 1).into(),
+                zinc_types::Load::new(0, 1).into(),
+                zinc_types::Mul.into(),
+                zinc_types::Load::new(0, 1).into(),
+                zinc_types::Push::new_field(BigInt::from(1)).into(),
+                zinc_types::Add.into(),
+                zinc_types::Return::new(2).into(),
+            ],
+        )
+    }
+
+    #[test]
+    fn ok_known_vector() {
+        let calldata = Calldata {
+            proof: Proof {
+                a: G1Affine::one(),
+                b: G2Affine::one(),
+                c: G1Affine::zero(),
+            },
+            public_input: vec![Fr::one()],
+        };
+        let encoded = hex::encode(calldata.encode());
+
+        let expected = [
+            "c32e370e",
+            // a
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "0000000000000000000000000000000000000000000000000000000000000002",
+            // b
+            "198e9393920d483a7260bfb731fb5d25f1aa493335a9e71297e485b7aef312c2",
+            "1800deef121f1e76426a00665e5c4479674322d4f75edadd46debd5cd992f6ed",
+            "090689d0585ff075ec9e99ad690c3395bc4b313370b38ef355acdadcd122975b",
+            "12c85ea5db8c6deb4aab71808dcb408fe3d1e7690c43d37b4ce6cc0166fa7daa",
+            // c
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            "0000000000000000000000000000000000000000000000000000000000000000",
+            // the public input offset, length, and elements
+            "0000000000000000000000000000000000000000000000000000000000000120",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+        ]
+        .concat();
+
+        assert_eq!(encoded, expected);
+    }
+
+    #[test]
+    fn ok_verified_natively() {
+        let parameters = CircuitFacade::new(circuit())
+            .setup::<Bn256>()
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        let input = zinc_types::Value::from_flat_values(
+            zinc_types::Type::Scalar(zinc_types::ScalarType::Field),
+            &[BigInt::from(3)],
+        );
+        let (output, proof) = CircuitFacade::new(circuit())
+            .prove::<Bn256>(parameters.clone(), input)
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+        let calldata =
+            Calldata::new(proof.clone(), output.clone()).expect(zinc_const::panic::TEST_DATA_VALID);
+        assert_eq!(calldata.public_input.len(), 2);
+        let decoded = Calldata::decode(calldata.encode().as_slice())
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+        assert_eq!(decoded.proof, proof);
+        assert_eq!(decoded.public_input, calldata.public_input);
+
+        assert!(Facade::verify(parameters.vk.clone(), proof, output)
+            .expect(zinc_const::panic::TEST_DATA_VALID));
+        let prepared_verifying_key = groth16::prepare_verifying_key(&parameters.vk);
+        assert!(groth16::verify_proof(
+            &prepared_verifying_key,
+            &decoded.proof,
+            decoded.public_input.as_slice(),
+        )
+        .expect(zinc_const::panic::TEST_DATA_VALID));
+
+        let mut tampered = decoded.public_input;
+        tampered[1].add_assign(&Fr::one());
+        assert!(!groth16::verify_proof(
+            &prepared_verifying_key,
+            &decoded.proof,
+            tampered.as_slice(),
+        )
+        .expect(zinc_const::panic::TEST_DATA_VALID));
+    }
+
+    #[test]
+    fn error_selector_mismatch() {
+        let mut encoded = Calldata {
+            proof: Proof {
+                a: G1Affine::one(),
+                b: G2Affine::one(),
+                c: G1Affine::one(),
+            },
+            public_input: vec![],
+        }
+        .encode();
+        encoded[0] = 0;
+
+        assert!(Calldata::decode(encoded.as_slice()).is_err());
+    }
+}
@@ -0,0 +1,75 @@
+//!
+//! The Ethereum Groth16 verifier export.
+//!
+//! The points are encoded the way the Ethereum BN256 precompiled contracts expect them, that is,
+//! as big-endian 256-bit words, with the imaginary parts of the G2 point coordinates going first.
+//!
+
+pub mod calldata;
+pub mod solidity;
+
+use num::bigint::BigUint;
+
+use franklin_crypto::bellman::pairing::bn256::Fq;
+use franklin_crypto::bellman::pairing::bn256::G1Affine;
+use franklin_crypto::bellman::pairing::bn256::G2Affine;
+use franklin_crypto::bellman::pairing::ff::Field;
+use franklin_crypto::bellman::pairing::ff::PrimeField;
+use franklin_crypto::bellman::pairing::ff::PrimeFieldRepr;
+use franklin_crypto::bellman::pairing::CurveAffine;
+
+/// The size of an encoded field element in bytes.
+pub const WORD_SIZE: usize = 32;
+
+///
+/// Returns the G1 point coordinates `[x, y]`. The point at infinity is encoded as `[0, 0]`.
+///
+pub fn g1_coordinates(point: &G1Affine) -> [Fq; 2] {
+    if point.is_zero() {
+        return [Fq::zero(), Fq::zero()];
+    }
+
+    let (x, y) = point.into_xy_unchecked();
+    [x, y]
+}
+
+///
+/// Returns the G2 point coordinates `[[x.c1, x.c0], [y.c1, y.c0]]`. The point at infinity is
+/// encoded with zeros.
+///
+pub fn g2_coordinates(point: &G2Affine) -> [[Fq; 2]; 2] {
+    if point.is_zero() {
+        return [[Fq::zero(), Fq::zero()], [Fq::zero(), Fq::zero()]];
+    }
+
+    let (x, y) = point.into_xy_unchecked();
+    [[x.c1, x.c0], [y.c1, y.c0]]
+}
+
+///
+/// Writes the field element as a big-endian 256-bit word.
+///
+pub fn to_word<F: PrimeField>(element: &F) -> Vec<u8> {
+    let mut word = Vec::with_capacity(WORD_SIZE);
+    element
+        .into_repr()
+        .write_be(&mut word)
+        .expect(zinc_const::panic::DATA_CONVERSION);
+    word
+}
+
+///
+/// Reads the field element from a big-endian 256-bit word.
+///
+pub fn from_word<F: PrimeField>(word: &[u8]) -> Option<F> {
+    let mut repr = F::Repr::default();
+    repr.read_be(word).ok()?;
+    F::from_repr(repr).ok()
+}
+
+///
+/// Formats the field element as a decimal Solidity literal.
+///
+pub fn to_decimal<F: PrimeField>(element: &F) -> String {
+    BigUint::from_bytes_be(to_word(element).as_slice()).to_str_radix(10)
+}
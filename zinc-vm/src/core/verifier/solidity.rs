@@ -0,0 +1,289 @@
+//!
+//! The Solidity verifier contract generator.
+//!
+
+use std::fmt::Write;
+
+use franklin_crypto::bellman::groth16::VerifyingKey;
+use franklin_crypto::bellman::pairing::bn256::Bn256;
+use franklin_crypto::bellman::pairing::bn256::G1Affine;
+use franklin_crypto::bellman::pairing::bn256::G2Affine;
+
+use crate::core::verifier;
+
+///
+/// The Solidity verifier contract generator.
+///
+/// The generated contract is self-contained and checks the Groth16 proofs with the BN256
+/// precompiled contracts, so it can be deployed to any EVM network supporting EIP-196 and
+/// EIP-197. The `verifyProof` method accepts the calldata produced by the `Calldata` encoder.
+///
+pub struct Generator<'a> {
+    /// The verifying key, which is embedded into the contract.
+    verifying_key: &'a VerifyingKey<Bn256>,
+}
+
+impl<'a> Generator<'a> {
+    /// The Solidity source code preceding the verifying key.
+    const HEADER: &'static str = r#"// SPDX-License-Identifier: MIT
+//
+// The Groth16 zero-knowledge proof verifier.
+// Generated by the Zinc virtual machine. Do not edit manually.
+//
+
+pragma solidity >=0.6.0 <0.9.0;
+
+library Pairing {
+    // The BN256 base field modulus.
+    uint256 constant PRIME_Q = 21888242871839275222246405745257275088696311157297823662689037894645226208583;
+
+    struct G1Point {
+        uint256 X;
+        uint256 Y;
+    }
+
+    // The coordinates are encoded as `[imaginary, real]`.
+    struct G2Point {
+        uint256[2] X;
+        uint256[2] Y;
+    }
+
+    function negate(G1Point memory p) internal pure returns (G1Point memory) {
+        if (p.X == 0 && p.Y == 0) {
+            return G1Point(0, 0);
+        }
+        return G1Point(p.X, PRIME_Q - (p.Y % PRIME_Q));
+    }
+
+    function addition(G1Point memory p1, G1Point memory p2) internal view returns (G1Point memory r) {
+        uint256[4] memory input;
+        input[0] = p1.X;
+        input[1] = p1.Y;
+        input[2] = p2.X;
+        input[3] = p2.Y;
+        bool success;
+        assembly {
+            success := staticcall(sub(gas(), 2000), 6, input, 0x80, r, 0x40)
+        }
+        require(success, "pairing-add-failed");
+    }
+
+    function scalarMul(G1Point memory p, uint256 s) internal view returns (G1Point memory r) {
+        uint256[3] memory input;
+        input[0] = p.X;
+        input[1] = p.Y;
+        input[2] = s;
+        bool success;
+        assembly {
+            success := staticcall(sub(gas(), 2000), 7, input, 0x60, r, 0x40)
+        }
+        require(success, "pairing-mul-failed");
+    }
+
+    function pairing(G1Point[4] memory p1, G2Point[4] memory p2) internal view returns (bool) {
+        uint256[24] memory input;
+        for (uint256 i = 0; i < 4; i++) {
+            input[i * 6 + 0] = p1[i].X;
+            input[i * 6 + 1] = p1[i].Y;
+            input[i * 6 + 2] = p2[i].X[0];
+            input[i * 6 + 3] = p2[i].X[1];
+            input[i * 6 + 4] = p2[i].Y[0];
+            input[i * 6 + 5] = p2[i].Y[1];
+        }
+        uint256[1] memory out;
+        bool success;
+        assembly {
+            success := staticcall(sub(gas(), 2000), 8, input, 0x300, out, 0x20)
+        }
+        require(success, "pairing-opcode-failed");
+        return out[0] != 0;
+    }
+}
+"#;
+
+    /// The Solidity source code following the verifying key.
+    const FOOTER: &'static str = r#"
+    function verifyProof(
+        uint256[2] memory a,
+        uint256[2][2] memory b,
+        uint256[2] memory c,
+        uint256[] memory input
+    ) public view returns (bool) {
+        VerifyingKey memory vk = verifyingKey();
+        require(input.length + 1 == vk.ic.length, "verifier-bad-input");
+
+        Pairing.G1Point memory x = vk.ic[0];
+        for (uint256 i = 0; i < input.length; i++) {
+            require(input[i] < SNARK_SCALAR_FIELD, "verifier-gte-snark-scalar-field");
+            x = Pairing.addition(x, Pairing.scalarMul(vk.ic[i + 1], input[i]));
+        }
+
+        Pairing.G1Point[4] memory p1;
+        p1[0] = Pairing.negate(Pairing.G1Point(a[0], a[1]));
+        p1[1] = vk.alpha;
+        p1[2] = x;
+        p1[3] = Pairing.G1Point(c[0], c[1]);
+
+        Pairing.G2Point[4] memory p2;
+        p2[0] = Pairing.G2Point(b[0], b[1]);
+        p2[1] = vk.beta;
+        p2[2] = vk.gamma;
+        p2[3] = vk.delta;
+
+        return Pairing.pairing(p1, p2);
+    }
+}
+"#;
+
+    ///
+    /// A shortcut constructor.
+    ///
+    pub fn new(verifying_key: &'a VerifyingKey<Bn256>) -> Self {
+        Self { verifying_key }
+    }
+
+    ///
+    /// Generates the verifier contract named `contract_name`.
+    ///
+    pub fn generate(&self, contract_name: &str) -> String {
+        let mut code = String::with_capacity(
+            Self::HEADER.len() + Self::FOOTER.len() + 1024 + self.verifying_key.ic.len() * 192,
+        );
+
+        code.push_str(Self::HEADER);
+        let _ = writeln!(code);
+        let _ = writeln!(code, "contract {} {{", contract_name);
+        let _ = writeln!(code, "    // The BN256 scalar field modulus.");
+        let _ = writeln!(
+            code,
+            "    uint256 constant SNARK_SCALAR_FIELD = 21888242871839275222246405745257275088548364400416034343698204186575808495617;"
+        );
+        let _ = writeln!(code);
+        let _ = writeln!(code, "    struct VerifyingKey {{");
+        let _ = writeln!(code, "        Pairing.G1Point alpha;");
+        let _ = writeln!(code, "        Pairing.G2Point beta;");
+        let _ = writeln!(code, "        Pairing.G2Point gamma;");
+        let _ = writeln!(code, "        Pairing.G2Point delta;");
+        let _ = writeln!(code, "        Pairing.G1Point[] ic;");
+        let _ = writeln!(code, "    }}");
+        let _ = writeln!(code);
+        let _ = writeln!(
+            code,
+            "    function verifyingKey() internal pure returns (VerifyingKey memory vk) {{"
+        );
+        let _ = writeln!(
+            code,
+            "        vk.alpha = {};",
+            Self::g1_point(&self.verifying_key.alpha_g1)
+        );
+        let _ = writeln!(
+            code,
+            "        vk.beta = {};",
+            Self::g2_point(&self.verifying_key.beta_g2)
+        );
+        let _ = writeln!(
+            code,
+            "        vk.gamma = {};",
+            Self::g2_point(&self.verifying_key.gamma_g2)
+        );
+        let _ = writeln!(
+            code,
+            "        vk.delta = {};",
+            Self::g2_point(&self.verifying_key.delta_g2)
+        );
+        let _ = writeln!(
+            code,
+            "        vk.ic = new Pairing.G1Point[]({});",
+            self.verifying_key.ic.len()
+        );
+        for (index, point) in self.verifying_key.ic.iter().enumerate() {
+            let _ = writeln!(
+                code,
+                "        vk.ic[{}] = {};",
+                index,
+                Self::g1_point(point)
+            );
+        }
+        let _ = writeln!(code, "    }}");
+        code.push_str(Self::FOOTER);
+
+        code
+    }
+
+    ///
+    /// Formats the G1 point constructor expression.
+    ///
+    fn g1_point(point: &G1Affine) -> String {
+        let [x, y] = verifier::g1_coordinates(point);
+        format!(
+            "Pairing.G1Point({}, {})",
+            verifier::to_decimal(&x),
+            verifier::to_decimal(&y),
+        )
+    }
+
+    ///
+    /// Formats the G2 point constructor expression.
+    ///
+    fn g2_point(point: &G2Affine) -> String {
+        let [[x_1, x_0], [y_1, y_0]] = verifier::g2_coordinates(point);
+        format!(
+            "Pairing.G2Point([{}, {}], [{}, {}])",
+            verifier::to_decimal(&x_1),
+            verifier::to_decimal(&x_0),
+            verifier::to_decimal(&y_1),
+            verifier::to_decimal(&y_0),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use franklin_crypto::bellman::groth16::VerifyingKey;
+    use franklin_crypto::bellman::pairing::bn256::Bn256;
+    use franklin_crypto::bellman::pairing::bn256::G1Affine;
+    use franklin_crypto::bellman::pairing::bn256::G2Affine;
+    use franklin_crypto::bellman::pairing::CurveAffine;
+
+    use super::Generator;
+
+    /// The BN256 G2 generator in the Ethereum encoding.
+    const G2_GENERATOR: &str = "Pairing.G2Point([11559732032986387107991004021392285783925812861821192530917403151452391805634, 10857046999023057135944570762232829481370756359578518086990519993285655852781], [4082367875863433681332203403145435568316851327593401208105741076214120093531, 8495653923123431417604973247489272438418190587263600148770280649306958101930])";
+
+    fn generator_verifying_key(inputs: usize) -> VerifyingKey<Bn256> {
+        VerifyingKey {
+            alpha_g1: G1Affine::one(),
+            beta_g1: G1Affine::one(),
+            beta_g2: G2Affine::one(),
+            gamma_g2: G2Affine::one(),
+            delta_g1: G1Affine::one(),
+            delta_g2: G2Affine::one(),
+            ic: vec![G1Affine::one(); inputs + 1],
+        }
+    }
+
+    #[test]
+    fn ok_generator_points() {
+        let verifying_key = generator_verifying_key(2);
+        let code = Generator::new(&verifying_key).generate("Verifier");
+
+        assert!(code.contains("contract Verifier {"));
+        assert!(code.contains("        vk.alpha = Pairing.G1Point(1, 2);\n"));
+        assert!(code.contains(format!("        vk.beta = {};\n", G2_GENERATOR).as_str()));
+        assert!(code.contains(format!("        vk.gamma = {};\n", G2_GENERATOR).as_str()));
+        assert!(code.contains(format!("        vk.delta = {};\n", G2_GENERATOR).as_str()));
+        assert!(code.contains("        vk.ic = new Pairing.G1Point[](3);\n"));
+        assert!(code.contains("        vk.ic[2] = Pairing.G1Point(1, 2);\n"));
+        assert!(!code.contains("vk.ic[3]"));
+    }
+
+    #[test]
+    fn ok_point_at_infinity() {
+        let mut verifying_key = generator_verifying_key(0);
+        verifying_key.ic[0] = G1Affine::zero();
+        let code = Generator::new(&verifying_key).generate("CircuitVerifier");
+
+        assert!(code.contains("contract CircuitVerifier {"));
+        assert!(code.contains("        vk.ic[0] = Pairing.G1Point(0, 0);\n"));
+    }
+}
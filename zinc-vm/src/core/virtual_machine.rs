@@ -30,6 +30,9 @@ pub trait IVirtualMachine {
 
     fn load(&mut self, address: usize) -> Result<Cell<Self::E>, Error>;
     fn store(&mut self, address: usize, cell: Cell<Self::E>) -> Result<(), Error>;
+    fn reference(&mut self, address: usize) -> Result<usize, Error>;
+    fn load_indirect(&mut self, reference: usize) -> Result<Cell<Self::E>, Error>;
+    fn store_indirect(&mut self, reference: usize, cell: Cell<Self::E>) -> Result<(), Error>;
 
     // Operations with contract storage
 
@@ -48,6 +48,9 @@ pub enum VerificationError {
 
     #[error("failed to synthesize circuit: {0}")]
     SynthesisError(franklin_crypto::bellman::SynthesisError),
+
+    #[error("failed to decode the calldata: {0}")]
+    CalldataDecoding(String),
 }
 
 #[derive(Debug, Error)]
@@ -126,4 +129,16 @@ pub enum Error {
 
     #[error("contract method `{found}` does not exist")]
     MethodNotFound { found: String },
+
+    #[error("unit test `{found}` does not exist")]
+    UnitTestNotFound { found: String },
+
+    #[error("unit test thread pool error: {0}")]
+    UnitTestThreadPool(#[from] rayon::ThreadPoolBuildError),
+
+    #[error("debugger input/output error: {0}")]
+    DebuggerIo(std::io::Error),
+
+    #[error("the debugging session has been terminated")]
+    DebuggerTerminated,
 }
@@ -121,7 +121,11 @@ impl<E: IEngine> AllocatedLeaf<E> {
 
         let mut root_hash_bits = current_hash;
 
-        root_hash_bits.truncate(zinc_const::bitlength::SHA256_HASH - zinc_const::bitlength::BYTE);
+        // the hashes wider than a field element are truncated to fit into one
+        if root_hash_bits.len() > zinc_const::bitlength::FIELD {
+            root_hash_bits
+                .truncate(zinc_const::bitlength::SHA256_HASH - zinc_const::bitlength::BYTE);
+        }
 
         Ok(Scalar::<E>::from(AllocatedNum::<E>::pack_bits_to_element(
             cs.namespace(|| "pack root hash bits into AllocatedNum"),
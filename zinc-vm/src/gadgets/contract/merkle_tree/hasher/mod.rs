@@ -1,3 +1,4 @@
+pub mod rescue;
 pub mod sha256;
 
 use franklin_crypto::bellman::ConstraintSystem;
@@ -10,6 +11,14 @@ use crate::IEngine;
 pub trait IHasher<E: IEngine>: Default {
     fn hash_width(&self) -> usize;
 
+    ///
+    /// Computes the leaf value hash bits outside of the circuit.
+    ///
+    /// Must produce the same bits as `leaf_value_hash`, since the storages and the gadget
+    /// are parameterized with the same hasher.
+    ///
+    fn leaf_value_hash_native(&self, leaf_value: Vec<Scalar<E>>) -> Vec<bool>;
+
     fn leaf_value_hash<CS>(&self, cs: CS, leaf_value: &[Scalar<E>]) -> Result<Vec<Boolean>, Error>
     where
         CS: ConstraintSystem<E>;
@@ -0,0 +1,93 @@
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::circuit::boolean::Boolean;
+use franklin_crypto::circuit::num::AllocatedNum;
+use franklin_crypto::circuit::rescue;
+
+use crate::core::contract::storage::rescue as rescue_native;
+use crate::error::Error;
+use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
+use crate::gadgets::scalar::Scalar;
+use crate::IEngine;
+
+///
+/// The Rescue Merkle tree hasher.
+///
+/// Hashes the field elements directly, so it is much cheaper in the circuit than SHA-256.
+/// The hash is represented by the little-endian bits of the resulting field element.
+///
+#[derive(Default)]
+pub struct Hasher {}
+
+impl<E: IEngine> IMerkleTreeHasher<E> for Hasher {
+    fn hash_width(&self) -> usize {
+        zinc_const::bitlength::FIELD
+    }
+
+    fn leaf_value_hash_native(&self, leaf_value: Vec<Scalar<E>>) -> Vec<bool> {
+        rescue_native::leaf_value_hash::<E>(leaf_value)
+    }
+
+    fn leaf_value_hash<CS>(
+        &self,
+        mut cs: CS,
+        leaf_value: &[Scalar<E>],
+    ) -> Result<Vec<Boolean>, Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        let mut preimage = Vec::with_capacity(leaf_value.len());
+        for (index, field) in leaf_value.iter().enumerate() {
+            preimage.push(field.to_expression::<CS>().into_number(
+                cs.namespace(|| format!("{} field of leaf value to number", index)),
+            )?);
+        }
+        if preimage.is_empty() {
+            preimage.push(
+                Scalar::<E>::new_constant_usize(0, zinc_types::ScalarType::Field)
+                    .to_expression::<CS>()
+                    .into_number(cs.namespace(|| "empty leaf value to number"))?,
+            );
+        }
+
+        let digest = rescue::rescue_hash(
+            cs.namespace(|| "leaf_value_rescue"),
+            preimage.as_slice(),
+            E::rescue_params(),
+        )?;
+
+        Ok(digest[0].into_bits_le_strict(cs.namespace(|| "leaf value hash to bits"))?)
+    }
+
+    fn node_hash<CS>(
+        &self,
+        mut cs: CS,
+        left_node: &[Boolean],
+        right_node: &[Boolean],
+    ) -> Result<Vec<Boolean>, Error>
+    where
+        CS: ConstraintSystem<E>,
+    {
+        if left_node.len() != zinc_const::bitlength::FIELD
+            || right_node.len() != zinc_const::bitlength::FIELD
+        {
+            return Err(Error::RequireError("Incorrect node hash width".into()));
+        }
+
+        let left_node = AllocatedNum::pack_bits_to_element(
+            cs.namespace(|| "left node bits to number"),
+            left_node,
+        )?;
+        let right_node = AllocatedNum::pack_bits_to_element(
+            cs.namespace(|| "right node bits to number"),
+            right_node,
+        )?;
+
+        let digest = rescue::rescue_hash(
+            cs.namespace(|| "node_rescue"),
+            &[left_node, right_node],
+            E::rescue_params(),
+        )?;
+
+        Ok(digest[0].into_bits_le_strict(cs.namespace(|| "node hash to bits"))?)
+    }
+}
@@ -2,6 +2,7 @@ use franklin_crypto::bellman::ConstraintSystem;
 use franklin_crypto::circuit::boolean::Boolean;
 use franklin_crypto::circuit::sha256;
 
+use crate::core::contract::storage::sha256 as sha256_native;
 use crate::error::Error;
 use crate::gadgets::contract::merkle_tree::hasher::IHasher as IMerkleTreeHasher;
 use crate::gadgets::scalar::Scalar;
@@ -15,6 +16,16 @@ impl<E: IEngine> IMerkleTreeHasher<E> for Hasher {
         zinc_const::bitlength::SHA256_HASH
     }
 
+    fn leaf_value_hash_native(&self, leaf_value: Vec<Scalar<E>>) -> Vec<bool> {
+        let mut hash = Vec::with_capacity(zinc_const::bitlength::SHA256_HASH);
+        for byte in sha256_native::leaf_value_hash::<E>(leaf_value).into_iter() {
+            for index in (0..zinc_const::bitlength::BYTE).rev() {
+                hash.push(((byte >> index) & 1u8) == 1u8);
+            }
+        }
+        hash
+    }
+
     fn leaf_value_hash<CS>(
         &self,
         mut cs: CS,
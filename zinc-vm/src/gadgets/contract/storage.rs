@@ -1,11 +1,15 @@
 use std::borrow::BorrowMut;
 use std::marker::PhantomData;
 
+use num::bigint::ToBigInt;
+use num::BigInt;
+
 use franklin_crypto::bellman::ConstraintSystem;
 use franklin_crypto::bellman::SynthesisError;
 
 use crate::core::contract::storage::leaf::LeafOutput;
 use crate::core::contract::storage::leaf::LeafVariant;
+use crate::core::debugger::StorageFields;
 use crate::error::Error;
 use crate::gadgets;
 use crate::gadgets::contract::merkle_tree::allocated_leaf::AllocatedLeaf;
@@ -148,6 +152,58 @@ where
         zinc_types::Value::Contract(fields)
     }
 
+    ///
+    /// Reads the storage fields along with their types without synthesizing any constraints.
+    ///
+    pub fn fields(&self) -> Result<StorageFields, Error> {
+        let to_bigints = |scalars: &[Scalar<E>]| -> Vec<BigInt> {
+            scalars
+                .iter()
+                .map(|scalar| scalar.to_bigint().unwrap_or_default())
+                .collect()
+        };
+
+        let field_types = self.storage.types().to_owned();
+        let mut fields = Vec::with_capacity(field_types.len());
+        for (index, field) in field_types.into_iter().enumerate() {
+            let value = match self.storage.load(BigInt::from(index))?.leaf_values {
+                LeafVariant::Array(array) => zinc_types::Value::from_flat_values(
+                    field.r#type.clone(),
+                    to_bigints(array.as_slice()).as_slice(),
+                ),
+                LeafVariant::Map { data, .. } => {
+                    let (key_type, value_type) = match field.r#type {
+                        zinc_types::Type::Map {
+                            ref key_type,
+                            ref value_type,
+                        } => (key_type.as_ref(), value_type.as_ref()),
+                        _ => panic!(zinc_const::panic::VALIDATED_DURING_SEMANTIC_ANALYSIS),
+                    };
+
+                    let entries = data
+                        .iter()
+                        .map(|(key, value)| {
+                            (
+                                zinc_types::Value::from_flat_values(
+                                    key_type.to_owned(),
+                                    to_bigints(key.as_slice()).as_slice(),
+                                ),
+                                zinc_types::Value::from_flat_values(
+                                    value_type.to_owned(),
+                                    to_bigints(value.as_slice()).as_slice(),
+                                ),
+                            )
+                        })
+                        .collect();
+                    zinc_types::Value::Map(entries)
+                }
+            };
+            fields.push((field, value));
+        }
+
+        Ok(fields)
+    }
+
     pub fn root_hash(&self) -> Result<Scalar<E>, Error> {
         Ok(self.root_hash.clone())
     }
@@ -0,0 +1,133 @@
+//!
+//! The `std::crypto::blake2s` function call.
+//!
+
+use std::collections::HashMap;
+
+use num::BigInt;
+
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::circuit::blake2s;
+
+use crate::core::execution_state::ExecutionState;
+use crate::error::Error;
+use crate::error::MalformedBytecode;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::scalar::Scalar;
+use crate::instructions::call_library::INativeCallable;
+use crate::IEngine;
+
+pub struct Blake2s {
+    message_length: usize,
+}
+
+impl Blake2s {
+    /// The empty personalization, which makes the digest equal to the plain BLAKE2s-256 one.
+    const PERSONALIZATION: [u8; 8] = [0; 8];
+
+    pub fn new(message_length: usize) -> Result<Self, Error> {
+        if message_length % 8 == 0 {
+            Ok(Self { message_length })
+        } else {
+            Err(MalformedBytecode::InvalidArguments(format!(
+                "message length for blake2s must be a multiple of 8, got {}",
+                message_length
+            ))
+            .into())
+        }
+    }
+}
+
+impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Blake2s {
+    fn call<CS: ConstraintSystem<E>>(
+        &self,
+        mut cs: CS,
+        state: &mut ExecutionState<E>,
+        _storages: Option<HashMap<BigInt, &mut S>>,
+    ) -> Result<(), Error> {
+        let mut bits = Vec::new();
+        for i in 0..self.message_length {
+            let bit = state
+                .evaluation_stack
+                .pop()?
+                .try_into_value()?
+                .to_boolean(cs.namespace(|| format!("bit {}", i)))?;
+
+            bits.push(bit);
+        }
+        bits.reverse();
+
+        // the gadget expects the bytes in the little-endian bit order, unlike `sha256`
+        for byte in bits.chunks_mut(zinc_const::bitlength::BYTE) {
+            byte.reverse();
+        }
+
+        let mut digest_bits =
+            blake2s::blake2s(cs.namespace(|| "blake2s"), &bits, &Self::PERSONALIZATION)?;
+
+        assert_eq!(digest_bits.len(), zinc_const::bitlength::BLAKE2S_HASH);
+
+        for byte in digest_bits.chunks_mut(zinc_const::bitlength::BYTE) {
+            byte.reverse();
+        }
+
+        for bit in digest_bits {
+            let scalar = Scalar::from_boolean(cs.namespace(|| "from_boolean"), bit)?;
+            state.evaluation_stack.push(scalar.into())?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigInt;
+
+    use crate::tests::TestRunner;
+    use crate::tests::TestingError;
+
+    ///
+    /// The BLAKE2s-256 digest of `abc` from the RFC 7693 appendix.
+    ///
+    const ABC_DIGEST: &str = "508c5e8c327c14e2e1a72ba34eeb452f37458b209ed63a294d999b4c86675982";
+
+    fn bits(bytes: &[u8]) -> Vec<u8> {
+        bytes
+            .iter()
+            .flat_map(|byte| {
+                (0..zinc_const::bitlength::BYTE)
+                    .rev()
+                    .map(move |index| (byte >> index) & 1)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_blake2s() -> Result<(), TestingError> {
+        let message = bits(b"abc");
+        let digest = bits(
+            hex::decode(ABC_DIGEST)
+                .expect(zinc_const::panic::TEST_DATA_VALID)
+                .as_slice(),
+        );
+
+        let mut runner = TestRunner::new();
+        for bit in message.iter() {
+            runner = runner.push(zinc_types::Push::new(
+                BigInt::from(*bit),
+                zinc_types::ScalarType::Boolean,
+            ));
+        }
+
+        let expected: Vec<u8> = digest.into_iter().rev().collect();
+
+        runner
+            .push(zinc_types::CallLibrary::new(
+                zinc_types::LibraryFunctionIdentifier::CryptoBlake2s,
+                message.len(),
+                zinc_const::bitlength::BLAKE2S_HASH,
+            ))
+            .test(expected.as_slice())
+    }
+}
@@ -2,6 +2,9 @@
 //! The `std::crypto` module calls.
 //!
 
+pub mod blake2s;
 pub mod pedersen;
+pub mod poseidon;
+pub mod rescue;
 pub mod schnorr_verify;
 pub mod sha256;
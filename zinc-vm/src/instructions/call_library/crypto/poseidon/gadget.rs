@@ -0,0 +1,199 @@
+//!
+//! The Poseidon hash function gadget.
+//!
+
+use franklin_crypto::bellman::pairing::ff::Field;
+use franklin_crypto::bellman::pairing::Engine;
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::bellman::LinearCombination;
+use franklin_crypto::bellman::SynthesisError;
+use franklin_crypto::circuit::num::AllocatedNum;
+
+use crate::error::Error;
+use crate::gadgets::scalar::Scalar;
+use crate::instructions::call_library::crypto::poseidon::params;
+use crate::instructions::call_library::crypto::poseidon::params::Params;
+use crate::IEngine;
+
+///
+/// The permutation state element.
+///
+/// The round constant additions and the MDS matrix multiplications are linear, so the element
+/// is kept as a linear combination, and only the S-box results are allocated.
+///
+#[derive(Clone)]
+struct Element<E: Engine> {
+    /// The linear combination of the allocated variables.
+    lc: LinearCombination<E>,
+    /// The witness value.
+    value: Option<E::Fr>,
+}
+
+impl<E: Engine> Element<E> {
+    ///
+    /// Creates a zero element.
+    ///
+    pub fn zero() -> Self {
+        Self {
+            lc: LinearCombination::zero(),
+            value: Some(E::Fr::zero()),
+        }
+    }
+
+    ///
+    /// Adds the `other` element with the `coefficient`.
+    ///
+    pub fn add_with_coefficient(&mut self, other: &Self, coefficient: E::Fr) {
+        self.lc = self.lc.clone() + (coefficient, &other.lc);
+        self.value = match (self.value, other.value) {
+            (Some(mut value), Some(mut other)) => {
+                other.mul_assign(&coefficient);
+                value.add_assign(&other);
+                Some(value)
+            }
+            _ => None,
+        };
+    }
+
+    ///
+    /// Adds the `constant`.
+    ///
+    pub fn add_constant<CS: ConstraintSystem<E>>(&mut self, constant: E::Fr) {
+        self.lc = self.lc.clone() + (constant, CS::one());
+        if let Some(value) = self.value.as_mut() {
+            value.add_assign(&constant);
+        }
+    }
+
+    ///
+    /// Allocates the product of `self` and `other`.
+    ///
+    pub fn mul<CS: ConstraintSystem<E>>(
+        &self,
+        mut cs: CS,
+        other: &Self,
+    ) -> Result<Self, SynthesisError> {
+        let value = match (self.value, other.value) {
+            (Some(mut value), Some(other)) => {
+                value.mul_assign(&other);
+                Some(value)
+            }
+            _ => None,
+        };
+
+        let product = AllocatedNum::alloc(cs.namespace(|| "product"), || {
+            value.ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        cs.enforce(
+            || "multiplication",
+            |lc| lc + &self.lc,
+            |lc| lc + &other.lc,
+            |lc| lc + product.get_variable(),
+        );
+
+        Ok(Self {
+            lc: LinearCombination::zero() + product.get_variable(),
+            value,
+        })
+    }
+
+    ///
+    /// Allocates the `x^5` S-box result, which costs three constraints.
+    ///
+    pub fn sbox<CS: ConstraintSystem<E>>(&self, mut cs: CS) -> Result<Self, SynthesisError> {
+        let square = self.mul(cs.namespace(|| "square"), self)?;
+        let quad = square.mul(cs.namespace(|| "quad"), &square)?;
+        quad.mul(cs.namespace(|| "fifth"), self)
+    }
+}
+
+///
+/// Hashes the `preimage` in the circuit.
+///
+/// The witness is computed the same way as in the native `hash` function, including the
+/// preimage length in the capacity element.
+///
+pub fn hash<E, CS>(
+    mut cs: CS,
+    params: &Params<E>,
+    preimage: &[Scalar<E>],
+) -> Result<Scalar<E>, Error>
+where
+    E: IEngine,
+    CS: ConstraintSystem<E>,
+{
+    let mut state = vec![Element::<E>::zero(); params.width];
+    state[0].add_constant::<CS>(params::length_tag::<E>(preimage.len()));
+    for (chunk_index, chunk) in preimage.chunks(params.width - 1).enumerate() {
+        for (index, scalar) in chunk.iter().enumerate() {
+            let element = Element {
+                lc: scalar.to_linear_combination::<CS>(),
+                value: scalar.get_value(),
+            };
+            state[index + 1].add_with_coefficient(&element, E::Fr::one());
+        }
+
+        state = permute(
+            cs.namespace(|| format!("permutation {}", chunk_index)),
+            params,
+            state,
+        )?;
+    }
+
+    let output = &state[0];
+    let value = output.value;
+    let digest = AllocatedNum::alloc(cs.namespace(|| "digest"), || {
+        value.ok_or(SynthesisError::AssignmentMissing)
+    })?;
+    cs.enforce(
+        || "digest equality",
+        |lc| lc + &output.lc,
+        |lc| lc + CS::one(),
+        |lc| lc + digest.get_variable(),
+    );
+
+    Ok(Scalar::from(digest))
+}
+
+///
+/// Applies the Poseidon permutation to the `state` in the circuit.
+///
+fn permute<E, CS>(
+    mut cs: CS,
+    params: &Params<E>,
+    mut state: Vec<Element<E>>,
+) -> Result<Vec<Element<E>>, SynthesisError>
+where
+    E: Engine,
+    CS: ConstraintSystem<E>,
+{
+    for round in 0..params.rounds() {
+        let mut cs = cs.namespace(|| format!("round {}", round));
+
+        for (index, element) in state.iter_mut().enumerate() {
+            element.add_constant::<CS>(*params.round_constant(round, index));
+        }
+
+        if params.is_full_round(round) {
+            for (index, element) in state.iter_mut().enumerate() {
+                *element = element.sbox(cs.namespace(|| format!("sbox {}", index)))?;
+            }
+        } else {
+            state[0] = state[0].sbox(cs.namespace(|| "sbox 0"))?;
+        }
+
+        state = params
+            .mds_matrix
+            .iter()
+            .map(|row| {
+                let mut result = Element::zero();
+                for (coefficient, element) in row.iter().zip(state.iter()) {
+                    result.add_with_coefficient(element, *coefficient);
+                }
+                result
+            })
+            .collect();
+    }
+
+    Ok(state)
+}
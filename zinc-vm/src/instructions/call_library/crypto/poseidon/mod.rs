@@ -0,0 +1,183 @@
+//!
+//! The `std::crypto::poseidon` function call.
+//!
+
+pub mod gadget;
+pub mod native;
+pub mod params;
+
+use std::collections::HashMap;
+
+use num::BigInt;
+
+use franklin_crypto::bellman::ConstraintSystem;
+
+use crate::core::execution_state::ExecutionState;
+use crate::error::Error;
+use crate::error::MalformedBytecode;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::instructions::call_library::INativeCallable;
+use crate::IEngine;
+
+pub struct Poseidon {
+    preimage_length: usize,
+}
+
+impl Poseidon {
+    pub fn new(preimage_length: usize) -> Result<Self, Error> {
+        if preimage_length > 0 {
+            Ok(Self { preimage_length })
+        } else {
+            Err(MalformedBytecode::InvalidArguments(
+                "preimage length for poseidon must be greater than 0".into(),
+            )
+            .into())
+        }
+    }
+}
+
+impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Poseidon {
+    fn call<CS: ConstraintSystem<E>>(
+        &self,
+        mut cs: CS,
+        state: &mut ExecutionState<E>,
+        _storages: Option<HashMap<BigInt, &mut S>>,
+    ) -> Result<(), Error> {
+        let mut preimage = Vec::with_capacity(self.preimage_length);
+        for _ in 0..self.preimage_length {
+            preimage.push(state.evaluation_stack.pop()?.try_into_value()?);
+        }
+        preimage.reverse();
+
+        let digest = gadget::hash(
+            cs.namespace(|| "poseidon"),
+            E::poseidon_params(),
+            preimage.as_slice(),
+        )?;
+
+        state.evaluation_stack.push(digest.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use franklin_crypto::bellman::pairing::bn256::Bn256;
+    use franklin_crypto::bellman::pairing::bn256::Fr;
+    use franklin_crypto::bellman::pairing::ff::PrimeField;
+    use franklin_crypto::bellman::ConstraintSystem;
+    use franklin_crypto::circuit::num::AllocatedNum;
+    use franklin_crypto::circuit::test::TestConstraintSystem;
+
+    use crate::gadgets::scalar::Scalar;
+    use crate::IEngine;
+
+    use super::gadget;
+    use super::native;
+
+    fn vectors() -> Vec<(Vec<u64>, &'static str)> {
+        vec![
+            (
+                vec![1, 2],
+                "3587841099753357953233326007097742799211941019050730282074530241745813090888",
+            ),
+            (
+                vec![1],
+                "415600613235378359744539731387103727073144920510400543186315443896602800829",
+            ),
+            (
+                vec![1, 2, 3],
+                "19402550726884258468617566955162874160305138467209182509239401426334478293952",
+            ),
+            (
+                vec![0, 0],
+                "13297227346748348616792935042878212687827823120645919540615015187477210521233",
+            ),
+        ]
+    }
+
+    #[test]
+    fn native_test_vectors() {
+        for (preimage, expected) in vectors().into_iter() {
+            let preimage: Vec<Fr> = preimage
+                .into_iter()
+                .map(|value| {
+                    Fr::from_str(&value.to_string()).expect(zinc_const::panic::TEST_DATA_VALID)
+                })
+                .collect();
+
+            let digest = native::hash::<Bn256>(Bn256::poseidon_params(), preimage.as_slice());
+
+            assert_eq!(
+                digest,
+                Fr::from_str(expected).expect(zinc_const::panic::TEST_DATA_VALID),
+                "{:?}",
+                preimage
+            );
+        }
+    }
+
+    #[test]
+    fn native_different_lengths() {
+        let to_preimage = |values: Vec<u64>| -> Vec<Fr> {
+            values
+                .into_iter()
+                .map(|value| {
+                    Fr::from_str(&value.to_string()).expect(zinc_const::panic::TEST_DATA_VALID)
+                })
+                .collect()
+        };
+
+        let cases = vec![
+            (vec![0], vec![0, 0]),
+            (vec![1], vec![1, 0]),
+            (vec![1, 2, 3], vec![1, 2, 3, 0]),
+        ];
+
+        for (shorter, longer) in cases.into_iter() {
+            let shorter = to_preimage(shorter);
+            let longer = to_preimage(longer);
+
+            assert_ne!(
+                native::hash::<Bn256>(Bn256::poseidon_params(), shorter.as_slice()),
+                native::hash::<Bn256>(Bn256::poseidon_params(), longer.as_slice()),
+                "{:?} and {:?}",
+                shorter,
+                longer
+            );
+        }
+    }
+
+    #[test]
+    fn gadget_test_vectors() {
+        for (case, (preimage, expected)) in vectors().into_iter().enumerate() {
+            let mut cs = TestConstraintSystem::<Bn256>::new();
+
+            let preimage: Vec<Scalar<Bn256>> = preimage
+                .into_iter()
+                .enumerate()
+                .map(|(index, value)| {
+                    let value =
+                        Fr::from_str(&value.to_string()).expect(zinc_const::panic::TEST_DATA_VALID);
+                    let number =
+                        AllocatedNum::alloc(cs.namespace(|| format!("preimage {}", index)), || {
+                            Ok(value)
+                        })
+                        .expect(zinc_const::panic::TEST_DATA_VALID);
+                    Scalar::from(number)
+                })
+                .collect();
+
+            let digest = gadget::hash(
+                cs.namespace(|| "poseidon"),
+                Bn256::poseidon_params(),
+                preimage.as_slice(),
+            )
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+            assert!(cs.is_satisfied(), "case {}", case);
+            assert_eq!(digest.get_value(), Fr::from_str(expected), "case {}", case);
+        }
+    }
+}
@@ -0,0 +1,73 @@
+//!
+//! The Poseidon hash function native implementation.
+//!
+
+use franklin_crypto::bellman::pairing::ff::Field;
+use franklin_crypto::bellman::pairing::Engine;
+
+use crate::instructions::call_library::crypto::poseidon::params;
+use crate::instructions::call_library::crypto::poseidon::params::Params;
+
+///
+/// Hashes the `preimage` with the sponge, which absorbs `width - 1` elements per permutation.
+///
+/// The capacity element is initialized with the preimage length, so the zero padding of the
+/// last chunk is unambiguous, and the preimages of different lengths never collide.
+///
+pub fn hash<E: Engine>(params: &Params<E>, preimage: &[E::Fr]) -> E::Fr {
+    let mut state = vec![E::Fr::zero(); params.width];
+    state[0] = params::length_tag::<E>(preimage.len());
+    for chunk in preimage.chunks(params.width - 1) {
+        for (index, element) in chunk.iter().enumerate() {
+            state[index + 1].add_assign(element);
+        }
+        permute(params, &mut state);
+    }
+    state[0]
+}
+
+///
+/// Applies the Poseidon permutation to the `state`.
+///
+pub fn permute<E: Engine>(params: &Params<E>, state: &mut Vec<E::Fr>) {
+    for round in 0..params.rounds() {
+        for (index, element) in state.iter_mut().enumerate() {
+            element.add_assign(params.round_constant(round, index));
+        }
+
+        if params.is_full_round(round) {
+            for element in state.iter_mut() {
+                *element = sbox::<E>(element);
+            }
+        } else {
+            state[0] = sbox::<E>(&state[0]);
+        }
+
+        *state = params
+            .mds_matrix
+            .iter()
+            .map(|row| {
+                row.iter().zip(state.iter()).fold(
+                    E::Fr::zero(),
+                    |mut sum, (coefficient, element)| {
+                        let mut product = *coefficient;
+                        product.mul_assign(element);
+                        sum.add_assign(&product);
+                        sum
+                    },
+                )
+            })
+            .collect();
+    }
+}
+
+///
+/// Computes the `x^5` S-box.
+///
+pub fn sbox<E: Engine>(element: &E::Fr) -> E::Fr {
+    let mut result = *element;
+    result.square();
+    result.square();
+    result.mul_assign(element);
+    result
+}
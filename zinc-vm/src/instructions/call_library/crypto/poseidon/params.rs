@@ -0,0 +1,231 @@
+//!
+//! The Poseidon hash function parameters.
+//!
+
+use num::bigint::Sign;
+use num::BigInt;
+
+use franklin_crypto::bellman::pairing::ff::Field;
+use franklin_crypto::bellman::pairing::ff::PrimeField;
+use franklin_crypto::bellman::pairing::ff::PrimeFieldRepr;
+use franklin_crypto::bellman::pairing::Engine;
+
+use crate::gadgets::scalar::fr_bigint;
+
+///
+/// The Poseidon permutation parameters.
+///
+/// The round constants and the MDS matrix are generated with the Grain LFSR as described in
+/// the Poseidon paper, so the permutation matches the reference implementation and `circomlib`
+/// for the same width. The digests differ from the `circomlib` ones, since the sponge puts the
+/// preimage length into the capacity element.
+///
+#[derive(Debug)]
+pub struct Params<E: Engine> {
+    /// The permutation state width, that is, the rate plus the capacity of one element.
+    pub width: usize,
+    /// The number of full rounds, which are split equally around the partial ones.
+    pub full_rounds: usize,
+    /// The number of partial rounds, where the S-box is only applied to the first element.
+    pub partial_rounds: usize,
+    /// The round constants, `width` elements for each round.
+    pub round_constants: Vec<E::Fr>,
+    /// The `width` x `width` MDS matrix.
+    pub mds_matrix: Vec<Vec<E::Fr>>,
+}
+
+impl<E: Engine> Params<E> {
+    ///
+    /// Generates the parameters for the `x^5` S-box.
+    ///
+    pub fn new(width: usize, full_rounds: usize, partial_rounds: usize) -> Self {
+        let modulus = Self::modulus();
+
+        let mut grain = Grain::new(E::Fr::NUM_BITS as usize, width, full_rounds, partial_rounds);
+
+        let round_constants_count = (full_rounds + partial_rounds) * width;
+        let mut round_constants = Vec::with_capacity(round_constants_count);
+        while round_constants.len() < round_constants_count {
+            let value = grain.next_integer(E::Fr::NUM_BITS as usize);
+            if value < modulus {
+                round_constants.push(
+                    fr_bigint::bigint_to_fr::<E>(&value).expect(zinc_const::panic::DATA_CONVERSION),
+                );
+            }
+        }
+
+        let mds_matrix = loop {
+            let elements: Vec<E::Fr> = (0..width * 2)
+                .map(|_| {
+                    let value = grain.next_integer(E::Fr::NUM_BITS as usize) % &modulus;
+                    fr_bigint::bigint_to_fr::<E>(&value).expect(zinc_const::panic::DATA_CONVERSION)
+                })
+                .collect();
+
+            let is_unique = elements
+                .iter()
+                .enumerate()
+                .all(|(index, element)| !elements[index + 1..].contains(element));
+            if !is_unique {
+                continue;
+            }
+
+            let (xs, ys) = elements.split_at(width);
+            let matrix: Option<Vec<Vec<E::Fr>>> = xs
+                .iter()
+                .map(|x| {
+                    ys.iter()
+                        .map(|y| {
+                            let mut sum = *x;
+                            sum.add_assign(y);
+                            sum.inverse()
+                        })
+                        .collect()
+                })
+                .collect();
+
+            if let Some(matrix) = matrix {
+                break matrix;
+            }
+        };
+
+        Self {
+            width,
+            full_rounds,
+            partial_rounds,
+            round_constants,
+            mds_matrix,
+        }
+    }
+
+    ///
+    /// Checks whether the S-box is applied to the whole state in the `round`.
+    ///
+    pub fn is_full_round(&self, round: usize) -> bool {
+        let half = self.full_rounds / 2;
+        round < half || round >= half + self.partial_rounds
+    }
+
+    ///
+    /// The total number of rounds.
+    ///
+    pub fn rounds(&self) -> usize {
+        self.full_rounds + self.partial_rounds
+    }
+
+    ///
+    /// Returns the round constant for the state `element` in the `round`.
+    ///
+    pub fn round_constant(&self, round: usize, element: usize) -> &E::Fr {
+        &self.round_constants[round * self.width + element]
+    }
+
+    ///
+    /// Returns the field modulus as a big integer.
+    ///
+    fn modulus() -> BigInt {
+        let mut buffer = Vec::<u8>::new();
+        E::Fr::char()
+            .write_be(&mut buffer)
+            .expect(zinc_const::panic::DATA_CONVERSION);
+        BigInt::from_bytes_be(Sign::Plus, &buffer)
+    }
+}
+
+///
+/// Converts the preimage `length` into the initial capacity element, which separates the
+/// preimages of different lengths.
+///
+pub fn length_tag<E: Engine>(length: usize) -> E::Fr {
+    fr_bigint::bigint_to_fr::<E>(&BigInt::from(length)).expect(zinc_const::panic::DATA_CONVERSION)
+}
+
+///
+/// The Grain LFSR in the self-shrinking mode, which is used to generate the parameters.
+///
+struct Grain {
+    /// The 80-bit register.
+    state: Vec<bool>,
+}
+
+impl Grain {
+    /// The register size in bits.
+    const SIZE: usize = 80;
+
+    /// The number of the initial outputs, which are discarded.
+    const WARM_UP_ROUNDS: usize = 160;
+
+    ///
+    /// Initializes the register with the prime field and `x^alpha` S-box parameters.
+    ///
+    pub fn new(
+        field_bitlength: usize,
+        width: usize,
+        full_rounds: usize,
+        partial_rounds: usize,
+    ) -> Self {
+        let mut state = Vec::with_capacity(Self::SIZE);
+        Self::append(&mut state, 1, 2);
+        Self::append(&mut state, 0, 4);
+        Self::append(&mut state, field_bitlength, 12);
+        Self::append(&mut state, width, 12);
+        Self::append(&mut state, full_rounds, 10);
+        Self::append(&mut state, partial_rounds, 10);
+        Self::append(&mut state, (1 << 30) - 1, 30);
+
+        let mut grain = Self { state };
+        for _ in 0..Self::WARM_UP_ROUNDS {
+            grain.next_bit();
+        }
+        grain
+    }
+
+    ///
+    /// Returns the next `bitlength`-bit integer, taking the bits from the most significant one.
+    ///
+    pub fn next_integer(&mut self, bitlength: usize) -> BigInt {
+        let mut value = BigInt::from(0);
+        for _ in 0..bitlength {
+            value <<= 1;
+            if self.next_filtered_bit() {
+                value += 1;
+            }
+        }
+        value
+    }
+
+    ///
+    /// Returns the next bit, which is output only if the preceding control bit is set.
+    ///
+    fn next_filtered_bit(&mut self) -> bool {
+        loop {
+            let is_output = self.next_bit();
+            let bit = self.next_bit();
+            if is_output {
+                return bit;
+            }
+        }
+    }
+
+    ///
+    /// Shifts the register, returning the new bit.
+    ///
+    fn next_bit(&mut self) -> bool {
+        let bit = [62, 51, 38, 23, 13, 0]
+            .iter()
+            .fold(false, |bit, index| bit ^ self.state[*index]);
+        self.state.remove(0);
+        self.state.push(bit);
+        bit
+    }
+
+    ///
+    /// Appends the `bitlength` bits of `value` to the register, starting from the most
+    /// significant one.
+    ///
+    fn append(state: &mut Vec<bool>, value: usize, bitlength: usize) {
+        for index in (0..bitlength).rev() {
+            state.push((value >> index) & 1 == 1);
+        }
+    }
+}
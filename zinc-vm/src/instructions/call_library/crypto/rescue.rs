@@ -0,0 +1,158 @@
+//!
+//! The `std::crypto::rescue` function call.
+//!
+
+use std::collections::HashMap;
+
+use num::BigInt;
+
+use franklin_crypto::bellman::ConstraintSystem;
+use franklin_crypto::circuit::rescue;
+
+use crate::core::execution_state::ExecutionState;
+use crate::error::Error;
+use crate::error::MalformedBytecode;
+use crate::gadgets::contract::merkle_tree::IMerkleTree;
+use crate::gadgets::scalar::Scalar;
+use crate::instructions::call_library::INativeCallable;
+use crate::IEngine;
+
+pub struct Rescue {
+    preimage_length: usize,
+}
+
+impl Rescue {
+    pub fn new(preimage_length: usize) -> Result<Self, Error> {
+        if preimage_length > 0 {
+            Ok(Self { preimage_length })
+        } else {
+            Err(MalformedBytecode::InvalidArguments(
+                "preimage length for rescue must be greater than 0".into(),
+            )
+            .into())
+        }
+    }
+}
+
+impl<E: IEngine, S: IMerkleTree<E>> INativeCallable<E, S> for Rescue {
+    fn call<CS: ConstraintSystem<E>>(
+        &self,
+        mut cs: CS,
+        state: &mut ExecutionState<E>,
+        _storages: Option<HashMap<BigInt, &mut S>>,
+    ) -> Result<(), Error> {
+        let mut preimage = Vec::with_capacity(self.preimage_length);
+        for i in 0..self.preimage_length {
+            let number = state
+                .evaluation_stack
+                .pop()?
+                .try_into_value()?
+                .to_expression::<CS>()
+                .into_number(cs.namespace(|| format!("element {}", i)))?;
+
+            preimage.push(number);
+        }
+        preimage.reverse();
+
+        let digest = rescue::rescue_hash(
+            cs.namespace(|| "rescue"),
+            preimage.as_slice(),
+            E::rescue_params(),
+        )?;
+
+        state.evaluation_stack.push(Scalar::from(&digest[0]).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use franklin_crypto::bellman::pairing::bn256::Bn256;
+    use franklin_crypto::bellman::pairing::bn256::Fr;
+    use franklin_crypto::bellman::pairing::ff::PrimeField;
+    use franklin_crypto::bellman::ConstraintSystem;
+    use franklin_crypto::circuit::num::AllocatedNum;
+    use franklin_crypto::circuit::rescue;
+    use franklin_crypto::circuit::test::TestConstraintSystem;
+
+    use crate::core::contract::storage::rescue as rescue_native;
+    use crate::IEngine;
+
+    fn vectors() -> Vec<(Vec<u64>, &'static str)> {
+        vec![
+            (
+                vec![1, 2],
+                "1340855661233807637214204929874725498631667852650789023065226312666660941557",
+            ),
+            (
+                vec![1],
+                "17839948800463470375367295298288164705217875224669092628194007125332079452734",
+            ),
+            (
+                vec![1, 2, 3],
+                "18911719231950433925764619264373292826917977360504949353353192531561242070197",
+            ),
+            (
+                vec![0, 0],
+                "10216126243581318815586229657459670259359293128203180555184097302209429910466",
+            ),
+        ]
+    }
+
+    #[test]
+    fn native_test_vectors() {
+        for (preimage, expected) in vectors().into_iter() {
+            let preimage: Vec<Fr> = preimage
+                .into_iter()
+                .map(|value| {
+                    Fr::from_str(&value.to_string()).expect(zinc_const::panic::TEST_DATA_VALID)
+                })
+                .collect();
+
+            let digest = rescue_native::rescue::<Bn256>(preimage.as_slice());
+
+            assert_eq!(
+                digest,
+                Fr::from_str(expected).expect(zinc_const::panic::TEST_DATA_VALID),
+                "{:?}",
+                preimage
+            );
+        }
+    }
+
+    #[test]
+    fn gadget_test_vectors() {
+        for (case, (preimage, expected)) in vectors().into_iter().enumerate() {
+            let mut cs = TestConstraintSystem::<Bn256>::new();
+
+            let preimage: Vec<AllocatedNum<Bn256>> = preimage
+                .into_iter()
+                .enumerate()
+                .map(|(index, value)| {
+                    let value =
+                        Fr::from_str(&value.to_string()).expect(zinc_const::panic::TEST_DATA_VALID);
+                    AllocatedNum::alloc(cs.namespace(|| format!("preimage {}", index)), || {
+                        Ok(value)
+                    })
+                    .expect(zinc_const::panic::TEST_DATA_VALID)
+                })
+                .collect();
+
+            let digest = rescue::rescue_hash(
+                cs.namespace(|| "rescue"),
+                preimage.as_slice(),
+                Bn256::rescue_params(),
+            )
+            .expect(zinc_const::panic::TEST_DATA_VALID);
+
+            assert!(cs.is_satisfied(), "case {}", case);
+            assert_eq!(
+                digest[0].get_value(),
+                Fr::from_str(expected),
+                "case {}",
+                case
+            );
+        }
+    }
+}
@@ -6,6 +6,7 @@ use std::collections::HashMap;
 
 use num::BigInt;
 
+use franklin_crypto::alt_babyjubjub::JubjubEngine;
 use franklin_crypto::bellman::pairing::ff::PrimeField;
 use franklin_crypto::bellman::ConstraintSystem;
 use franklin_crypto::circuit::baby_eddsa::EddsaSignature;
@@ -115,7 +116,7 @@ pub fn verify_signature<E, CS>(
     mut cs: CS,
     message: &[Scalar<E>],
     signature: &EddsaSignature<E>,
-    params: &E::Params,
+    params: &<E as JubjubEngine>::Params,
 ) -> Result<Scalar<E>, Error>
 where
     E: IEngine,
@@ -37,7 +37,10 @@ use self::convert::from_bits_field::FromBitsField as ConvertFromBitsField;
 use self::convert::from_bits_signed::FromBitsSigned as ConvertFromBitsSigned;
 use self::convert::from_bits_unsigned::FromBitsUnsigned as ConvertFromBitsUnsigned;
 use self::convert::to_bits::ToBits as ConvertToBits;
+use self::crypto::blake2s::Blake2s as CryptoBlake2s;
 use self::crypto::pedersen::Pedersen as CryptoPedersen;
+use self::crypto::poseidon::Poseidon as CryptoPoseidon;
+use self::crypto::rescue::Rescue as CryptoRescue;
 use self::crypto::schnorr_verify::SchnorrSignatureVerify as CryptoSchnorrSignatureVerify;
 use self::crypto::sha256::Sha256 as CryptoSha256;
 use self::ff::invert::Inverse as FfInverse;
@@ -63,6 +66,15 @@ impl<VM: IVirtualMachine> IExecutable<VM> for CallLibrary {
             LibraryFunctionIdentifier::CryptoSchnorrSignatureVerify => {
                 vm.call_native(CryptoSchnorrSignatureVerify::new(self.input_size)?)
             }
+            LibraryFunctionIdentifier::CryptoBlake2s => {
+                vm.call_native(CryptoBlake2s::new(self.input_size)?)
+            }
+            LibraryFunctionIdentifier::CryptoPoseidon => {
+                vm.call_native(CryptoPoseidon::new(self.input_size)?)
+            }
+            LibraryFunctionIdentifier::CryptoRescue => {
+                vm.call_native(CryptoRescue::new(self.input_size)?)
+            }
 
             LibraryFunctionIdentifier::ConvertToBits => vm.call_native(ConvertToBits),
             LibraryFunctionIdentifier::ConvertFromBitsUnsigned => {
@@ -0,0 +1,22 @@
+//!
+//! The `LoadIndirect` instruction.
+//!
+
+use zinc_types::LoadIndirect;
+
+use crate::core::virtual_machine::IVirtualMachine;
+use crate::error::Error;
+use crate::instructions::IExecutable;
+
+impl<VM: IVirtualMachine> IExecutable<VM> for LoadIndirect {
+    fn execute(self, vm: &mut VM) -> Result<(), Error> {
+        let reference = vm.pop()?.try_into_value()?.get_constant_usize()?;
+
+        for i in 0..self.size {
+            let value = vm.load_indirect(reference + i)?;
+            vm.push(value)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,48 @@
+//!
+//! The `Reference` instruction.
+//!
+
+use zinc_types::Reference;
+
+use crate::core::execution_state::cell::Cell;
+use crate::core::virtual_machine::IVirtualMachine;
+use crate::error::Error;
+use crate::gadgets::scalar::Scalar;
+use crate::instructions::IExecutable;
+
+impl<VM: IVirtualMachine> IExecutable<VM> for Reference {
+    fn execute(self, vm: &mut VM) -> Result<(), Error> {
+        let reference = vm.reference(self.address)?;
+        vm.push(Cell::Value(Scalar::new_constant_usize(
+            reference,
+            zinc_types::ScalarType::Field,
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use num::BigInt;
+
+    use crate::tests::TestRunner;
+    use crate::tests::TestingError;
+
+    #[test]
+    fn test() -> Result<(), TestingError> {
+        TestRunner::new()
+            // call main
+            .push(zinc_types::Call::new(5, 0))
+            // fn set(value: &mut field)
+            .push(zinc_types::Load::new(0, 1))
+            .push(zinc_types::Push::new_field(BigInt::from(5)))
+            .push(zinc_types::StoreIndirect::new(1))
+            .push(zinc_types::Return::new(0))
+            // fn main
+            .push(zinc_types::Push::new_field(BigInt::from(42)))
+            .push(zinc_types::Store::new(0, 1))
+            .push(zinc_types::Reference::new(0, 1))
+            .push(zinc_types::Call::new(1, 1))
+            .push(zinc_types::Load::new(0, 1))
+            .test(&[5])
+    }
+}
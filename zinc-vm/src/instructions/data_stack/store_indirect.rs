@@ -0,0 +1,26 @@
+//!
+//! The `StoreIndirect` instruction.
+//!
+
+use zinc_types::StoreIndirect;
+
+use crate::core::virtual_machine::IVirtualMachine;
+use crate::error::Error;
+use crate::instructions::IExecutable;
+
+impl<VM: IVirtualMachine> IExecutable<VM> for StoreIndirect {
+    fn execute(self, vm: &mut VM) -> Result<(), Error> {
+        let mut values = Vec::with_capacity(self.size);
+        for _ in 0..self.size {
+            values.push(vm.pop()?);
+        }
+
+        let reference = vm.pop()?.try_into_value()?.get_constant_usize()?;
+
+        for (i, value) in values.into_iter().rev().enumerate() {
+            vm.store_indirect(reference + i, value)?;
+        }
+
+        Ok(())
+    }
+}
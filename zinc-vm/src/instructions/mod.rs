@@ -35,6 +35,11 @@ impl<VM: IVirtualMachine> IExecutable<VM> for Instruction {
             Self::LoadByIndex(inner) => inner.execute(vm),
             Self::Store(inner) => inner.execute(vm),
             Self::StoreByIndex(inner) => inner.execute(vm),
+            Self::Reference(inner) => inner.execute(vm),
+            Self::LoadIndirect(inner) => inner.execute(vm),
+            Self::LoadIndirectByIndex(inner) => inner.execute(vm),
+            Self::StoreIndirect(inner) => inner.execute(vm),
+            Self::StoreIndirectByIndex(inner) => inner.execute(vm),
 
             Self::StorageInit(inner) => inner.execute(vm),
             Self::StorageFetch(inner) => inner.execute(vm),
@@ -97,7 +97,7 @@ impl TestRunner {
             self.instructions,
         );
 
-        vm.run(circuit, Some(&[]), |_| {}, |_| Ok(()))
+        vm.run(circuit, Some(&[]), |_, _| {}, |_| Ok(()))
             .map_err(TestingError::Error)?;
 
         let cs = vm.constraint_system();
@@ -0,0 +1,89 @@
+//!
+//! The Zinc virtual machine `calldata` subcommand.
+//!
+
+use std::fs;
+use std::io;
+use std::io::Read;
+use std::path::PathBuf;
+
+use structopt::StructOpt;
+
+use franklin_crypto::bellman::groth16::Proof;
+use franklin_crypto::bellman::pairing::bn256::Bn256;
+
+use zinc_vm::ContractFacade;
+use zinc_vm::VerifierCalldata;
+
+use crate::arguments::command::IExecutable;
+use crate::error::Error;
+use crate::error::IErrorPath;
+
+///
+/// The Zinc virtual machine `calldata` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "calldata",
+    about = "Encodes the zero-knowledge proof read in hex from the standard input into the Solidity verifier calldata"
+)]
+pub struct Command {
+    /// The path to the binary bytecode file.
+    #[structopt(long = "binary")]
+    pub binary_path: PathBuf,
+
+    /// The path to the output JSON file.
+    #[structopt(long = "output")]
+    pub output_path: PathBuf,
+
+    /// The method name to encode the proof of, if the application is a contract.
+    #[structopt(long = "method")]
+    pub method: Option<String>,
+}
+
+impl IExecutable for Command {
+    type Error = Error;
+
+    fn execute(self) -> Result<i32, Self::Error> {
+        // Read the bytecode
+        let bytecode =
+            fs::read(&self.binary_path).error_with_path(|| self.binary_path.to_string_lossy())?;
+        let application = zinc_types::Application::try_from_slice(bytecode.as_slice())
+            .map_err(Error::ApplicationDecoding)?;
+
+        // Read the proof from the standard input
+        let mut proof_hex = String::new();
+        io::stdin()
+            .read_to_string(&mut proof_hex)
+            .error_with_path(|| "<stdin>")?;
+        let proof_bytes = hex::decode(proof_hex.trim())
+            .map_err(|error| Error::ProofDecoding(error.to_string()))?;
+        let proof = Proof::<Bn256>::read(proof_bytes.as_slice())
+            .map_err(|error| Error::ProofDecoding(error.to_string()))?;
+
+        // Read the public input from the output file
+        let output_json = fs::read_to_string(&self.output_path)
+            .error_with_path(|| self.output_path.to_string_lossy())?;
+        let output_json: serde_json::Value = serde_json::from_str(output_json.as_str())?;
+
+        let public_input = match application {
+            zinc_types::Application::Circuit(circuit) => {
+                zinc_types::Value::try_from_typed_json(output_json, circuit.output)?
+            }
+            zinc_types::Application::Contract(contract) => {
+                let method_name = self.method.ok_or(Error::MethodNameNotFound)?;
+                let contract = ContractFacade::new(contract);
+
+                let output_type = contract.output_type(method_name.as_str())?;
+                let output = zinc_types::Value::try_from_typed_json(output_json, output_type)?;
+                contract.public_input(method_name.as_str(), output)?
+            }
+            zinc_types::Application::Library(_library) => return Err(Error::CannotRunLibrary),
+        };
+
+        let calldata = VerifierCalldata::new(proof, public_input)?;
+        println!("{}", calldata.to_hex());
+
+        Ok(zinc_const::exit_code::SUCCESS as i32)
+    }
+}
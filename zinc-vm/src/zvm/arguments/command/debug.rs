@@ -0,0 +1,166 @@
+//!
+//! The Zinc virtual machine `debug` subcommand.
+//!
+
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+
+use structopt::StructOpt;
+
+use franklin_crypto::bellman::pairing::bn256::Bn256;
+
+use zinc_vm::CircuitFacade;
+use zinc_vm::ContractFacade;
+use zinc_vm::Debugger;
+use zinc_vm::DebuggerBreakpoint;
+use zinc_vm::LibraryFacade;
+
+use crate::arguments::command::IExecutable;
+use crate::error::Error;
+use crate::error::IErrorPath;
+use crate::input;
+
+///
+/// The Zinc virtual machine `debug` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "debug",
+    about = "Executes the bytecode or a unit test in the interactive debugger"
+)]
+pub struct Command {
+    /// The path to the binary bytecode file.
+    #[structopt(long = "binary")]
+    pub binary_path: PathBuf,
+
+    /// The path to the input JSON file, which is not required for unit tests.
+    #[structopt(long = "input")]
+    pub input_path: Option<PathBuf>,
+
+    /// The method name to call, if the application is a contract.
+    #[structopt(long = "method")]
+    pub method: Option<String>,
+
+    /// The unit test name to debug instead of the application entry.
+    #[structopt(long = "test")]
+    pub test: Option<String>,
+
+    /// The breakpoints in the `file:line` or `function` format.
+    #[structopt(short = "b", long = "break", number_of_values = 1)]
+    pub breakpoints: Vec<DebuggerBreakpoint>,
+}
+
+impl IExecutable for Command {
+    type Error = Error;
+
+    fn execute(self) -> Result<i32, Self::Error> {
+        // Read the bytecode
+        let bytecode =
+            fs::read(&self.binary_path).error_with_path(|| self.binary_path.to_string_lossy())?;
+        let application = zinc_types::Application::try_from_slice(bytecode.as_slice())
+            .map_err(Error::ApplicationDecoding)?;
+
+        let debugger = Debugger::new(
+            self.breakpoints,
+            Box::new(io::BufReader::new(io::stdin())),
+            Box::new(io::stderr()),
+        );
+
+        if let Some(name) = self.test {
+            let status = match application {
+                zinc_types::Application::Circuit(circuit) => {
+                    CircuitFacade::new(circuit).debug_test::<Bn256>(name.as_str(), debugger)
+                }
+                zinc_types::Application::Contract(contract) => {
+                    ContractFacade::new(contract).debug_test::<Bn256>(name.as_str(), debugger)
+                }
+                zinc_types::Application::Library(library) => {
+                    LibraryFacade::new(library).debug_test::<Bn256>(name.as_str(), debugger)
+                }
+            };
+
+            return match status {
+                Ok(status) => Ok(status as i32),
+                Err(zinc_vm::Error::DebuggerTerminated) => {
+                    Ok(zinc_const::exit_code::SUCCESS as i32)
+                }
+                Err(error) => Err(error.into()),
+            };
+        }
+
+        // Read the input file
+        let input_path = self.input_path.ok_or(Error::InputPathMissing)?;
+        let input_template =
+            fs::read_to_string(&input_path).error_with_path(|| input_path.to_string_lossy())?;
+        let input: zinc_types::InputBuild = serde_json::from_str(input_template.as_str())?;
+
+        let output = match application {
+            zinc_types::Application::Circuit(circuit) => match input {
+                zinc_types::InputBuild::Circuit { arguments } => {
+                    let input_type = circuit.input.clone();
+                    let arguments = zinc_types::Value::try_from_typed_json(arguments, input_type)?;
+
+                    CircuitFacade::new(circuit)
+                        .debug::<Bn256>(arguments, debugger)
+                        .map(|output| output.result)
+                }
+                zinc_types::InputBuild::Contract { .. } => {
+                    return Err(Error::InputDataInvalid {
+                        expected: "circuit".to_owned(),
+                        found: "contract".to_owned(),
+                    })
+                }
+                zinc_types::InputBuild::Library { .. } => {
+                    return Err(Error::InputDataInvalid {
+                        expected: "circuit".to_owned(),
+                        found: "library".to_owned(),
+                    })
+                }
+            },
+            zinc_types::Application::Contract(contract) => match input {
+                zinc_types::InputBuild::Circuit { .. } => {
+                    return Err(Error::InputDataInvalid {
+                        expected: "contract".to_owned(),
+                        found: "circuit".to_owned(),
+                    })
+                }
+                zinc_types::InputBuild::Contract {
+                    arguments,
+                    msg: transaction,
+                    storages,
+                } => {
+                    let method_name = self.method.ok_or(Error::MethodNameNotFound)?;
+                    let contract_input = input::contract(
+                        &contract,
+                        method_name,
+                        &arguments,
+                        storages,
+                        &transaction,
+                    )?;
+
+                    ContractFacade::new(contract)
+                        .debug::<Bn256>(contract_input, debugger)
+                        .map(|output| output.result)
+                }
+                zinc_types::InputBuild::Library { .. } => {
+                    return Err(Error::InputDataInvalid {
+                        expected: "contract".to_owned(),
+                        found: "library".to_owned(),
+                    })
+                }
+            },
+            zinc_types::Application::Library(_library) => return Err(Error::CannotRunLibrary),
+        };
+
+        match output {
+            Ok(output) => {
+                let output_json = serde_json::to_string_pretty(&output.into_json())? + "\n";
+                print!("{}", output_json);
+                Ok(zinc_const::exit_code::SUCCESS as i32)
+            }
+            Err(zinc_vm::Error::DebuggerTerminated) => Ok(zinc_const::exit_code::SUCCESS as i32),
+            Err(error) => Err(error.into()),
+        }
+    }
+}
@@ -0,0 +1,42 @@
+//!
+//! The Zinc virtual machine `disasm` subcommand.
+//!
+
+use std::fs;
+use std::path::PathBuf;
+
+use structopt::StructOpt;
+
+use crate::arguments::command::IExecutable;
+use crate::error::Error;
+use crate::error::IErrorPath;
+
+///
+/// The Zinc virtual machine `disasm` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "disasm",
+    about = "Prints the bytecode instructions in the human-readable form"
+)]
+pub struct Command {
+    /// The path to the binary bytecode file.
+    #[structopt(long = "binary")]
+    pub binary_path: PathBuf,
+}
+
+impl IExecutable for Command {
+    type Error = Error;
+
+    fn execute(self) -> Result<i32, Self::Error> {
+        // Read the bytecode
+        let bytecode =
+            fs::read(&self.binary_path).error_with_path(|| self.binary_path.to_string_lossy())?;
+        let application = zinc_types::Application::try_from_slice(bytecode.as_slice())
+            .map_err(Error::ApplicationDecoding)?;
+
+        print!("{}", zinc_types::Disassembly::new(&application));
+
+        Ok(zinc_const::exit_code::SUCCESS as i32)
+    }
+}
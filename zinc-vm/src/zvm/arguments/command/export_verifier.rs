@@ -0,0 +1,59 @@
+//!
+//! The Zinc virtual machine `export-verifier` subcommand.
+//!
+
+use std::fs;
+use std::path::PathBuf;
+
+use structopt::StructOpt;
+
+use franklin_crypto::bellman::groth16::VerifyingKey;
+use franklin_crypto::bellman::pairing::bn256::Bn256;
+
+use zinc_vm::SolidityVerifierGenerator;
+
+use crate::arguments::command::IExecutable;
+use crate::error::Error;
+use crate::error::IErrorPath;
+
+///
+/// The Zinc virtual machine `export-verifier` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "export-verifier",
+    about = "Generates the Solidity verifier contract from the verifying key"
+)]
+pub struct Command {
+    /// The path to the verifying key file.
+    #[structopt(long = "verifying-key")]
+    pub verifying_key_path: PathBuf,
+
+    /// The path to the Solidity contract file to write.
+    #[structopt(long = "output")]
+    pub output_path: PathBuf,
+
+    /// The Solidity contract name.
+    #[structopt(long = "contract-name", default_value = "Verifier")]
+    pub contract_name: String,
+}
+
+impl IExecutable for Command {
+    type Error = Error;
+
+    fn execute(self) -> Result<i32, Self::Error> {
+        // Read the verifying key
+        let verifying_key_file = fs::File::open(&self.verifying_key_path)
+            .error_with_path(|| self.verifying_key_path.to_string_lossy())?;
+        let verifying_key = VerifyingKey::<Bn256>::read(verifying_key_file)
+            .error_with_path(|| self.verifying_key_path.to_string_lossy())?;
+
+        // Write the verifier contract
+        let code =
+            SolidityVerifierGenerator::new(&verifying_key).generate(self.contract_name.as_str());
+        fs::write(&self.output_path, code)
+            .error_with_path(|| self.output_path.to_string_lossy())?;
+
+        Ok(zinc_const::exit_code::SUCCESS as i32)
+    }
+}
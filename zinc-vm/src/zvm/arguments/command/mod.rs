@@ -2,15 +2,31 @@
 //! The Zinc virtual machine subcommand.
 //!
 
+pub mod calldata;
+pub mod debug;
+pub mod disasm;
+pub mod export_verifier;
+pub mod profile;
+pub mod prove;
 pub mod run;
+pub mod setup;
 pub mod test;
+pub mod verify;
 
 use structopt::StructOpt;
 
 use crate::error::Error;
 
+use self::calldata::Command as CalldataCommand;
+use self::debug::Command as DebugCommand;
+use self::disasm::Command as DisasmCommand;
+use self::export_verifier::Command as ExportVerifierCommand;
+use self::profile::Command as ProfileCommand;
+use self::prove::Command as ProveCommand;
 use self::run::Command as RunCommand;
+use self::setup::Command as SetupCommand;
 use self::test::Command as TestCommand;
+use self::verify::Command as VerifyCommand;
 
 ///
 /// The generic trait used for commands.
@@ -33,8 +49,24 @@ pub trait IExecutable {
 pub enum Command {
     /// Executes the bytecode and prints its output.
     Run(RunCommand),
-    /// Executes a unit test.
+    /// Executes the unit tests.
     Test(TestCommand),
+    /// Generates a pair of proving and verifying keys.
+    Setup(SetupCommand),
+    /// Executes the bytecode and generates the zero-knowledge proof.
+    Prove(ProveCommand),
+    /// Verifies the zero-knowledge proof.
+    Verify(VerifyCommand),
+    /// Prints the bytecode instructions in the human-readable form.
+    Disasm(DisasmCommand),
+    /// Executes the bytecode and prints the constraint numbers per function and line.
+    Profile(ProfileCommand),
+    /// Executes the bytecode or a unit test in the interactive debugger.
+    Debug(DebugCommand),
+    /// Generates the Solidity verifier contract.
+    ExportVerifier(ExportVerifierCommand),
+    /// Encodes the zero-knowledge proof into the Solidity verifier calldata.
+    Calldata(CalldataCommand),
 }
 
 impl IExecutable for Command {
@@ -44,6 +76,14 @@ impl IExecutable for Command {
         match self {
             Command::Run(inner) => inner.execute(),
             Command::Test(inner) => inner.execute(),
+            Command::Setup(inner) => inner.execute(),
+            Command::Prove(inner) => inner.execute(),
+            Command::Verify(inner) => inner.execute(),
+            Command::Disasm(inner) => inner.execute(),
+            Command::Profile(inner) => inner.execute(),
+            Command::Debug(inner) => inner.execute(),
+            Command::ExportVerifier(inner) => inner.execute(),
+            Command::Calldata(inner) => inner.execute(),
         }
     }
 }
@@ -0,0 +1,125 @@
+//!
+//! The Zinc virtual machine `profile` subcommand.
+//!
+
+use std::fs;
+use std::path::PathBuf;
+
+use structopt::StructOpt;
+
+use franklin_crypto::bellman::pairing::bn256::Bn256;
+
+use zinc_vm::CircuitFacade;
+use zinc_vm::ContractFacade;
+
+use crate::arguments::command::IExecutable;
+use crate::error::Error;
+use crate::error::IErrorPath;
+use crate::input;
+
+///
+/// The Zinc virtual machine `profile` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "profile",
+    about = "Executes the bytecode and prints the constraint numbers per function and line"
+)]
+pub struct Command {
+    /// The path to the binary bytecode file.
+    #[structopt(long = "binary")]
+    pub binary_path: PathBuf,
+
+    /// The path to the input JSON file.
+    #[structopt(long = "input")]
+    pub input_path: PathBuf,
+
+    /// The path to the folded call stacks file, which can be turned into a flamegraph.
+    #[structopt(long = "folded")]
+    pub folded_path: Option<PathBuf>,
+
+    /// The method name to call, if the application is a contract.
+    #[structopt(long = "method")]
+    pub method: Option<String>,
+}
+
+impl IExecutable for Command {
+    type Error = Error;
+
+    fn execute(self) -> Result<i32, Self::Error> {
+        // Read the bytecode
+        let bytecode =
+            fs::read(&self.binary_path).error_with_path(|| self.binary_path.to_string_lossy())?;
+        let application = zinc_types::Application::try_from_slice(bytecode.as_slice())
+            .map_err(Error::ApplicationDecoding)?;
+
+        // Read the input file
+        let input_path = self.input_path;
+        let input_template =
+            fs::read_to_string(&input_path).error_with_path(|| input_path.to_string_lossy())?;
+        let input: zinc_types::InputBuild = serde_json::from_str(input_template.as_str())?;
+
+        let profiler = match application {
+            zinc_types::Application::Circuit(circuit) => match input {
+                zinc_types::InputBuild::Circuit { arguments } => {
+                    let input_type = circuit.input.clone();
+                    let arguments = zinc_types::Value::try_from_typed_json(arguments, input_type)?;
+
+                    CircuitFacade::new(circuit).profile::<Bn256>(arguments)?
+                }
+                zinc_types::InputBuild::Contract { .. } => {
+                    return Err(Error::InputDataInvalid {
+                        expected: "circuit".to_owned(),
+                        found: "contract".to_owned(),
+                    })
+                }
+                zinc_types::InputBuild::Library { .. } => {
+                    return Err(Error::InputDataInvalid {
+                        expected: "circuit".to_owned(),
+                        found: "library".to_owned(),
+                    })
+                }
+            },
+            zinc_types::Application::Contract(contract) => match input {
+                zinc_types::InputBuild::Circuit { .. } => {
+                    return Err(Error::InputDataInvalid {
+                        expected: "contract".to_owned(),
+                        found: "circuit".to_owned(),
+                    })
+                }
+                zinc_types::InputBuild::Contract {
+                    arguments,
+                    msg: transaction,
+                    storages,
+                } => {
+                    let method_name = self.method.ok_or(Error::MethodNameNotFound)?;
+                    let contract_input = input::contract(
+                        &contract,
+                        method_name,
+                        &arguments,
+                        storages,
+                        &transaction,
+                    )?;
+
+                    ContractFacade::new(contract).profile::<Bn256>(contract_input)?
+                }
+                zinc_types::InputBuild::Library { .. } => {
+                    return Err(Error::InputDataInvalid {
+                        expected: "contract".to_owned(),
+                        found: "library".to_owned(),
+                    })
+                }
+            },
+            zinc_types::Application::Library(_library) => return Err(Error::CannotRunLibrary),
+        };
+
+        if let Some(folded_path) = self.folded_path {
+            fs::write(&folded_path, profiler.folded_stacks())
+                .error_with_path(|| folded_path.to_string_lossy())?;
+        }
+
+        print!("{}", profiler);
+
+        Ok(zinc_const::exit_code::SUCCESS as i32)
+    }
+}
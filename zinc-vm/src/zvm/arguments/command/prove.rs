@@ -0,0 +1,140 @@
+//!
+//! The Zinc virtual machine `prove` subcommand.
+//!
+
+use std::fs;
+use std::path::PathBuf;
+
+use structopt::StructOpt;
+
+use franklin_crypto::bellman::groth16::Parameters;
+use franklin_crypto::bellman::pairing::bn256::Bn256;
+
+use zinc_vm::CircuitFacade;
+use zinc_vm::ContractFacade;
+
+use crate::arguments::command::IExecutable;
+use crate::error::Error;
+use crate::error::IErrorPath;
+use crate::input;
+
+///
+/// The Zinc virtual machine `prove` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "prove",
+    about = "Executes the bytecode, generates the zero-knowledge proof and prints it in hex"
+)]
+pub struct Command {
+    /// The path to the binary bytecode file.
+    #[structopt(long = "binary")]
+    pub binary_path: PathBuf,
+
+    /// The path to the proving key file.
+    #[structopt(long = "proving-key")]
+    pub proving_key_path: PathBuf,
+
+    /// The path to the input JSON file.
+    #[structopt(long = "input")]
+    pub input_path: PathBuf,
+
+    /// The path to the output JSON file.
+    #[structopt(long = "output")]
+    pub output_path: PathBuf,
+
+    /// The method name to prove, if the application is a contract.
+    #[structopt(long = "method")]
+    pub method: Option<String>,
+}
+
+impl IExecutable for Command {
+    type Error = Error;
+
+    fn execute(self) -> Result<i32, Self::Error> {
+        // Read the bytecode
+        let bytecode =
+            fs::read(&self.binary_path).error_with_path(|| self.binary_path.to_string_lossy())?;
+        let application = zinc_types::Application::try_from_slice(bytecode.as_slice())
+            .map_err(Error::ApplicationDecoding)?;
+
+        // Read the proving key
+        let proving_key_file = fs::File::open(&self.proving_key_path)
+            .error_with_path(|| self.proving_key_path.to_string_lossy())?;
+        let parameters = Parameters::<Bn256>::read(proving_key_file, true)
+            .error_with_path(|| self.proving_key_path.to_string_lossy())?;
+
+        // Read the input file
+        let input_template = fs::read_to_string(&self.input_path)
+            .error_with_path(|| self.input_path.to_string_lossy())?;
+        let input: zinc_types::InputBuild = serde_json::from_str(input_template.as_str())?;
+
+        let (output, proof) = match application {
+            zinc_types::Application::Circuit(circuit) => match input {
+                zinc_types::InputBuild::Circuit { arguments } => {
+                    let input_type = circuit.input.clone();
+                    let arguments = zinc_types::Value::try_from_typed_json(arguments, input_type)?;
+
+                    CircuitFacade::new(circuit).prove::<Bn256>(parameters, arguments)?
+                }
+                zinc_types::InputBuild::Contract { .. } => {
+                    return Err(Error::InputDataInvalid {
+                        expected: "circuit".to_owned(),
+                        found: "contract".to_owned(),
+                    })
+                }
+                zinc_types::InputBuild::Library { .. } => {
+                    return Err(Error::InputDataInvalid {
+                        expected: "circuit".to_owned(),
+                        found: "library".to_owned(),
+                    })
+                }
+            },
+            zinc_types::Application::Contract(contract) => match input {
+                zinc_types::InputBuild::Circuit { .. } => {
+                    return Err(Error::InputDataInvalid {
+                        expected: "contract".to_owned(),
+                        found: "circuit".to_owned(),
+                    })
+                }
+                zinc_types::InputBuild::Contract {
+                    arguments,
+                    msg: transaction,
+                    storages,
+                } => {
+                    let method_name = self.method.ok_or(Error::MethodNameNotFound)?;
+                    let contract_input = input::contract(
+                        &contract,
+                        method_name,
+                        &arguments,
+                        storages,
+                        &transaction,
+                    )?;
+
+                    ContractFacade::new(contract).prove::<Bn256>(parameters, contract_input)?
+                }
+                zinc_types::InputBuild::Library { .. } => {
+                    return Err(Error::InputDataInvalid {
+                        expected: "contract".to_owned(),
+                        found: "library".to_owned(),
+                    })
+                }
+            },
+            zinc_types::Application::Library(_library) => return Err(Error::CannotRunLibrary),
+        };
+
+        // Write the output file
+        let output_json = serde_json::to_string_pretty(&output.into_json())? + "\n";
+        fs::write(&self.output_path, &output_json)
+            .error_with_path(|| self.output_path.to_string_lossy())?;
+
+        // Print the proof in hex
+        let mut proof_bytes = Vec::new();
+        proof
+            .write(&mut proof_bytes)
+            .expect(zinc_const::panic::DATA_CONVERSION);
+        println!("{}", hex::encode(proof_bytes));
+
+        Ok(zinc_const::exit_code::SUCCESS as i32)
+    }
+}
@@ -3,23 +3,20 @@
 //!
 
 use std::collections::HashMap;
-use std::convert::TryFrom;
 use std::fs;
 use std::path::PathBuf;
 
-use num::BigInt;
-use num::Zero;
 use structopt::StructOpt;
 
 use franklin_crypto::bellman::pairing::bn256::Bn256;
 
 use zinc_vm::CircuitFacade;
 use zinc_vm::ContractFacade;
-use zinc_vm::ContractInput;
 
 use crate::arguments::command::IExecutable;
 use crate::error::Error;
 use crate::error::IErrorPath;
+use crate::input;
 
 ///
 /// The Zinc virtual machine `run` subcommand.
@@ -94,65 +91,15 @@ impl IExecutable for Command {
                     storages,
                 } => {
                     let method_name = self.method.ok_or(Error::MethodNameNotFound)?;
-                    let method = contract.methods.get(method_name.as_str()).cloned().ok_or(
-                        Error::MethodNotFound {
-                            name: method_name.clone(),
-                        },
-                    )?;
-
-                    let method_arguments = arguments.get(method_name.as_str()).cloned().ok_or(
-                        Error::MethodArgumentsNotFound {
-                            name: method_name.clone(),
-                        },
+                    let contract_input = input::contract(
+                        &contract,
+                        method_name,
+                        &arguments,
+                        storages,
+                        &transaction,
                     )?;
-                    let mut method_arguments =
-                        zinc_types::Value::try_from_typed_json(method_arguments, method.input)?;
-                    if method_name != zinc_const::contract::CONSTRUCTOR_IDENTIFIER {
-                        method_arguments.insert_contract_instance(BigInt::zero());
-                    }
-
-                    let mut input_storages = HashMap::with_capacity(storages.len());
-                    for (address, value) in storages.into_iter() {
-                        let address: zksync_types::Address = address["0x".len()..]
-                            .parse()
-                            .expect(zinc_const::panic::DATA_CONVERSION);
-
-                        let value = match value {
-                            serde_json::Value::Array(array) => {
-                                let mut storage_values = Vec::with_capacity(contract.storage.len());
-                                for (field, value) in
-                                    contract.storage.clone().into_iter().zip(array)
-                                {
-                                    storage_values.push(zinc_types::ContractFieldValue::new(
-                                        field.name,
-                                        zinc_types::Value::try_from_typed_json(
-                                            value,
-                                            field.r#type,
-                                        )?,
-                                        field.is_public,
-                                        field.is_implicit,
-                                    ));
-                                }
-                                zinc_types::Value::Contract(storage_values)
-                            }
-                            value => {
-                                return Err(Error::InvalidContractStorageFormat { found: value })
-                            }
-                        };
-                        input_storages.insert(address, value);
-                    }
 
-                    let output = ContractFacade::new(contract).run::<Bn256>(ContractInput::new(
-                        method_arguments,
-                        input_storages,
-                        method_name,
-                        zinc_types::TransactionMsg::try_from(&transaction).map_err(|error| {
-                            Error::InvalidTransaction {
-                                inner: error,
-                                found: transaction.clone(),
-                            }
-                        })?,
-                    ))?;
+                    let output = ContractFacade::new(contract).run::<Bn256>(contract_input)?;
 
                     let mut storages = HashMap::with_capacity(output.storages.len());
                     for (eth_address, value) in output.storages.into_iter() {
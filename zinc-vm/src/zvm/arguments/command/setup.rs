@@ -0,0 +1,84 @@
+//!
+//! The Zinc virtual machine `setup` subcommand.
+//!
+
+use std::fs;
+use std::path::PathBuf;
+
+use structopt::StructOpt;
+
+use franklin_crypto::bellman::pairing::bn256::Bn256;
+
+use zinc_vm::CircuitFacade;
+use zinc_vm::ContractFacade;
+
+use crate::arguments::command::IExecutable;
+use crate::error::Error;
+use crate::error::IErrorPath;
+
+///
+/// The Zinc virtual machine `setup` subcommand.
+///
+#[derive(Debug, StructOpt)]
+#[structopt(
+    name = "setup",
+    about = "Generates a pair of proving and verifying keys"
+)]
+pub struct Command {
+    /// The path to the binary bytecode file.
+    #[structopt(long = "binary")]
+    pub binary_path: PathBuf,
+
+    /// The path to the proving key file.
+    #[structopt(long = "proving-key")]
+    pub proving_key_path: PathBuf,
+
+    /// The path to the verifying key file.
+    #[structopt(long = "verifying-key")]
+    pub verifying_key_path: PathBuf,
+
+    /// The method name to do the setup for, if the application is a contract.
+    #[structopt(long = "method")]
+    pub method: Option<String>,
+}
+
+impl IExecutable for Command {
+    type Error = Error;
+
+    fn execute(self) -> Result<i32, Self::Error> {
+        // Read the bytecode
+        let bytecode =
+            fs::read(&self.binary_path).error_with_path(|| self.binary_path.to_string_lossy())?;
+        let application = zinc_types::Application::try_from_slice(bytecode.as_slice())
+            .map_err(Error::ApplicationDecoding)?;
+
+        // Generate the parameters
+        let parameters = match application {
+            zinc_types::Application::Circuit(circuit) => {
+                CircuitFacade::new(circuit).setup::<Bn256>()?
+            }
+            zinc_types::Application::Contract(contract) => {
+                let method_name = self.method.ok_or(Error::MethodNameNotFound)?;
+                ContractFacade::new(contract).setup::<Bn256>(method_name)?
+            }
+            zinc_types::Application::Library(_library) => return Err(Error::CannotRunLibrary),
+        };
+
+        // Write the proving key
+        let mut proving_key_file = fs::File::create(&self.proving_key_path)
+            .error_with_path(|| self.proving_key_path.to_string_lossy())?;
+        parameters
+            .write(&mut proving_key_file)
+            .error_with_path(|| self.proving_key_path.to_string_lossy())?;
+
+        // Write the verifying key
+        let mut verifying_key_file = fs::File::create(&self.verifying_key_path)
+            .error_with_path(|| self.verifying_key_path.to_string_lossy())?;
+        parameters
+            .vk
+            .write(&mut verifying_key_file)
+            .error_with_path(|| self.verifying_key_path.to_string_lossy())?;
+
+        Ok(zinc_const::exit_code::SUCCESS as i32)
+    }
+}
@@ -12,6 +12,7 @@ use franklin_crypto::bellman::pairing::bn256::Bn256;
 use zinc_vm::CircuitFacade;
 use zinc_vm::ContractFacade;
 use zinc_vm::LibraryFacade;
+use zinc_vm::UnitTestOptions;
 
 use crate::arguments::command::IExecutable;
 use crate::error::Error;
@@ -21,11 +22,35 @@ use crate::error::IErrorPath;
 /// The Zinc virtual machine `test` subcommand.
 ///
 #[derive(Debug, StructOpt)]
-#[structopt(name = "test", about = "Executes a unit test")]
+#[structopt(name = "test", about = "Executes the unit tests")]
 pub struct Command {
     /// The path to the binary bytecode file.
     #[structopt(long = "binary")]
     pub binary_path: PathBuf,
+
+    /// Runs only the tests whose names contain any of the filters.
+    #[structopt(name = "FILTER")]
+    pub filters: Vec<String>,
+
+    /// Makes the filters match the exact test names.
+    #[structopt(long = "exact")]
+    pub is_exact: bool,
+
+    /// Runs the tests marked with the `ignore` attribute as well.
+    #[structopt(long = "include-ignored")]
+    pub include_ignored: bool,
+
+    /// The number of threads to run the tests in. Defaults to the number of CPUs.
+    #[structopt(long = "test-threads")]
+    pub threads: Option<usize>,
+
+    /// The path to the JSON report file.
+    #[structopt(long = "json")]
+    pub json_path: Option<PathBuf>,
+
+    /// The path to the JUnit XML report file.
+    #[structopt(long = "junit")]
+    pub junit_path: Option<PathBuf>,
 }
 
 impl IExecutable for Command {
@@ -38,18 +63,37 @@ impl IExecutable for Command {
         let application = zinc_types::Application::try_from_slice(bytecode.as_slice())
             .map_err(Error::ApplicationDecoding)?;
 
-        let status = match application {
+        let options = UnitTestOptions::new(
+            self.filters,
+            self.is_exact,
+            self.include_ignored,
+            self.threads,
+        );
+
+        let summary = match application {
             zinc_types::Application::Circuit(circuit) => {
-                CircuitFacade::new(circuit).test::<Bn256>()?
+                CircuitFacade::new(circuit).test::<Bn256>(options)?
             }
             zinc_types::Application::Contract(contract) => {
-                ContractFacade::new(contract).test::<Bn256>()?
+                ContractFacade::new(contract).test::<Bn256>(options)?
             }
             zinc_types::Application::Library(library) => {
-                LibraryFacade::new(library).test::<Bn256>()?
+                LibraryFacade::new(library).test::<Bn256>(options)?
             }
         };
 
-        Ok(status as i32)
+        if let Some(json_path) = self.json_path {
+            let report = serde_json::to_string_pretty(&summary.to_json())?;
+            fs::write(&json_path, report).error_with_path(|| json_path.to_string_lossy())?;
+        }
+
+        if let Some(junit_path) = self.junit_path {
+            fs::write(&junit_path, summary.to_junit())
+                .error_with_path(|| junit_path.to_string_lossy())?;
+        }
+
+        println!("{}", summary);
+
+        Ok(summary.exit_code() as i32)
     }
 }
@@ -26,6 +26,10 @@ pub enum Error {
     #[error("failed to verify")]
     Verification(#[from] zinc_vm::VerificationError),
 
+    /// The proof hex decoding error.
+    #[error("failed to decode the proof: {0}")]
+    ProofDecoding(String),
+
     /// The JSON template file decoding error.
     #[error("failed to parse json: {0}")]
     JsonDecoding(#[from] serde_json::Error),
@@ -75,6 +79,10 @@ pub enum Error {
     /// The library cannot be run as a standalone application.
     #[error("libraries cannot be run as they have no entry points")]
     CannotRunLibrary,
+
+    /// The input file path is not specified.
+    #[error("the input file path is required unless a unit test is debugged")]
+    InputPathMissing,
 }
 
 ///
@@ -0,0 +1,82 @@
+//!
+//! The Zinc virtual machine binary input data tools.
+//!
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use num::BigInt;
+use num::Zero;
+
+use zinc_vm::ContractInput;
+
+use crate::error::Error;
+
+///
+/// Creates the contract method input from the input file sections.
+///
+pub fn contract(
+    contract: &zinc_types::Contract,
+    method_name: String,
+    arguments: &HashMap<String, serde_json::Value>,
+    storages: HashMap<String, serde_json::Value>,
+    transaction: &serde_json::Value,
+) -> Result<ContractInput, Error> {
+    let method = contract
+        .methods
+        .get(method_name.as_str())
+        .cloned()
+        .ok_or_else(|| Error::MethodNotFound {
+            name: method_name.clone(),
+        })?;
+
+    let method_arguments = arguments
+        .get(method_name.as_str())
+        .cloned()
+        .ok_or_else(|| Error::MethodArgumentsNotFound {
+            name: method_name.clone(),
+        })?;
+    let mut method_arguments =
+        zinc_types::Value::try_from_typed_json(method_arguments, method.input)?;
+    if method_name != zinc_const::contract::CONSTRUCTOR_IDENTIFIER {
+        method_arguments.insert_contract_instance(BigInt::zero());
+    }
+
+    let mut input_storages = HashMap::with_capacity(storages.len());
+    for (address, value) in storages.into_iter() {
+        let address: zksync_types::Address = address["0x".len()..]
+            .parse()
+            .expect(zinc_const::panic::DATA_CONVERSION);
+
+        let value = match value {
+            serde_json::Value::Array(array) => {
+                let mut storage_values = Vec::with_capacity(contract.storage.len());
+                for (field, value) in contract.storage.clone().into_iter().zip(array) {
+                    storage_values.push(zinc_types::ContractFieldValue::new(
+                        field.name,
+                        zinc_types::Value::try_from_typed_json(value, field.r#type)?,
+                        field.is_public,
+                        field.is_implicit,
+                    ));
+                }
+                zinc_types::Value::Contract(storage_values)
+            }
+            value => return Err(Error::InvalidContractStorageFormat { found: value }),
+        };
+        input_storages.insert(address, value);
+    }
+
+    let transaction = zinc_types::TransactionMsg::try_from(transaction).map_err(|error| {
+        Error::InvalidTransaction {
+            inner: error,
+            found: transaction.to_owned(),
+        }
+    })?;
+
+    Ok(ContractInput::new(
+        method_arguments,
+        input_storages,
+        method_name,
+        transaction,
+    ))
+}
@@ -4,6 +4,7 @@
 
 pub(crate) mod arguments;
 pub(crate) mod error;
+pub(crate) mod input;
 
 use std::process;
 